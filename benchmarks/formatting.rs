@@ -1,10 +1,22 @@
+use std::fmt::Write as _;
 use std::io;
 
 use criterion::Bencher;
 use time::format_description;
-use time::format_description::well_known::{Rfc2822, Rfc3339};
+use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use time::format_description::{CompiledFormat, OwnedFormatItem};
 use time::macros::{date, datetime, format_description as fd, offset, time};
 
+/// A description with several levels of nested compound, optional, and first items.
+fn nested_description() -> OwnedFormatItem {
+    format_description::parse_owned::<2>(
+        "[year]-[month]-[day] [first [[hour]:[minute][optional [:[second][optional \
+         [.[subsecond digits:3]]]]]][[hour]]] [first [[offset_hour sign:mandatory][optional \
+         [:[offset_minute omit_when_zero:true]]]][Z]]",
+    )
+    .expect("invalid format description")
+}
+
 setup_benchmark! {
     "Formatting",
 
@@ -41,6 +53,18 @@ setup_benchmark! {
         ben.iter(|| item!(datetime!(2021-01-02 03:04:05 -06:07)));
     }
 
+    fn format_iso8601(ben: &mut Bencher<'_>) {
+        macro_rules! item {
+            ($value:expr) => {
+                $value.format_into(&mut io::sink(), &Iso8601::DEFAULT)
+            }
+        }
+
+        ben.iter(|| item!(datetime!(2021-01-02 03:04:05 UTC)));
+        ben.iter(|| item!(datetime!(2021-01-02 03:04:05.123_456_789 UTC)));
+        ben.iter(|| item!(datetime!(2021-01-02 03:04:05.123_456_789 -01:02)));
+        ben.iter(|| item!(datetime!(2021-01-02 03:04:05.123_456_789 +01:02)));
+    }
 
     fn format_time(ben: &mut Bencher<'_>) {
         macro_rules! item {
@@ -123,6 +147,7 @@ setup_benchmark! {
         ben.iter(|| item!(fd!("[year base:iso_week sign:mandatory]")));
         ben.iter(|| item!(fd!("[year repr:last_two]")));
         ben.iter(|| item!(fd!("[year base:iso_week repr:last_two]")));
+        ben.iter(|| item!(fd!("[year]-[month]-[day]")));
     }
 
     fn display_date(ben: &mut Bencher<'_>) {
@@ -188,4 +213,28 @@ setup_benchmark! {
     fn display_odt(ben: &mut Bencher<'_>) {
         ben.iter(|| datetime!(1970-01-01 0:00 UTC).to_string());
     }
+
+    fn display_odt_with_format(ben: &mut Bencher<'_>) {
+        let mut output = String::with_capacity(64);
+        ben.iter(|| {
+            output.clear();
+            write!(output, "{}", datetime!(1970-01-01 0:00 UTC).display(&Rfc3339))
+        });
+    }
+
+    fn format_nested(ben: &mut Bencher<'_>) {
+        let format_description = nested_description();
+        ben.iter(|| {
+            datetime!(2021-01-02 03:04:05.123 +01:00)
+                .format_into(&mut io::sink(), &format_description)
+        });
+    }
+
+    fn format_nested_compiled(ben: &mut Bencher<'_>) {
+        let format_description = CompiledFormat::from(nested_description());
+        ben.iter(|| {
+            datetime!(2021-01-02 03:04:05.123 +01:00)
+                .format_into(&mut io::sink(), &format_description)
+        });
+    }
 }
@@ -1,7 +1,6 @@
 #![deny(
     anonymous_parameters,
     clippy::all,
-    illegal_floating_point_literal_pattern,
     late_bound_lifetime_arguments,
     path_statements,
     patterns_in_fns_without_body,
@@ -117,6 +116,7 @@ mods![
     mod parsing;
     mod primitive_date_time;
     mod rand;
+    mod serde;
     mod time;
     mod utc_offset;
     mod util;
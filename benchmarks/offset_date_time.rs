@@ -1,6 +1,6 @@
 use std::time::SystemTime;
 
-use criterion::Bencher;
+use criterion::{black_box, Bencher};
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
 use time::OffsetDateTime;
@@ -84,6 +84,28 @@ setup_benchmark! {
         ben.iter(|| datetime!(2018-12-31 23:00 -1).minute());
     }
 
+    fn to_calendar_date_time(ben: &mut Bencher<'_>) {
+        let datetime = datetime!(2019-12-31 23:00 -1);
+        ben.iter(|| black_box(datetime).to_calendar_date_time());
+    }
+
+    fn to_calendar_date_time_via_getters(ben: &mut Bencher<'_>) {
+        let datetime = datetime!(2019-12-31 23:00 -1);
+        ben.iter(|| {
+            let datetime = black_box(datetime);
+            (
+                (datetime.year(), datetime.month(), datetime.day()),
+                (
+                    datetime.hour(),
+                    datetime.minute(),
+                    datetime.second(),
+                    datetime.nanosecond(),
+                ),
+                datetime.offset(),
+            )
+        });
+    }
+
     fn second(ben: &mut Bencher<'_>) {
         ben.iter(|| datetime!(2019-01-01 0:00 UTC).second());
         ben.iter(|| datetime!(2018-12-31 23:00 -1).second());
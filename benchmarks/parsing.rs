@@ -1,6 +1,6 @@
 use criterion::Bencher;
-use time::format_description::well_known::{Rfc2822, Rfc3339};
-use time::format_description::{modifier, Component};
+use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use time::format_description::{self, modifier, CompiledFormat, Component, OwnedFormatItem};
 use time::parsing::Parsed;
 use time::OffsetDateTime;
 
@@ -15,6 +15,26 @@ macro_rules! component {
     }};
 }
 
+/// A mix of valid and invalid RFC3339 timestamps, as might be found in a column of a CSV file.
+fn parse_many_rows() -> Vec<&'static str> {
+    [
+        "2021-01-02T03:04:05Z",
+        "2021-01-02T03:04:05.123456789+01:02",
+        "2021-01-02 03:04:05",
+        "2021-01-02T03:04:05-01:02",
+    ]
+    .repeat(256)
+}
+
+/// A description of the rows in [`parse_many_rows`] with nested optional and first items.
+fn nested_description() -> OwnedFormatItem {
+    format_description::parse_owned::<2>(
+        "[year]-[month]-[day][first [T][ ]][hour]:[minute]:[second][optional [.[subsecond]]]\
+         [optional [[first [Z][[offset_hour]:[offset_minute]]]]]",
+    )
+    .expect("invalid format description")
+}
+
 setup_benchmark! {
     "Parsing",
 
@@ -216,9 +236,110 @@ setup_benchmark! {
         ben.iter(|| OffsetDateTime::parse("2021-01-02T03:04:05.123456789+01:02", &Rfc3339));
     }
 
+    fn parse_iso8601(ben: &mut Bencher<'_>) {
+        macro_rules! item {
+            ($input:literal) => {
+                OffsetDateTime::parse($input, &Iso8601::DEFAULT)
+            }
+        }
+
+        ben.iter(|| item!("2021-01-02T03:04:05Z"));
+        ben.iter(|| item!("2021-01-02T03:04:05.123456789Z"));
+        ben.iter(|| item!("2021-01-02T03:04:05.123456789-01:02"));
+        ben.iter(|| item!("2021-01-02T03:04:05.123456789+01:02"));
+        ben.iter(|| item!("+002021-01-02T03:04:05.123456789+01:02"));
+        ben.iter(|| item!("20210102T030405Z"));
+        ben.iter(|| item!("2021-W01-6T03:04:05Z"));
+        ben.iter(|| item!("2021-002T03:04:05Z"));
+    }
+
+    fn parse_description(ben: &mut Bencher<'_>) {
+        // We can't currently handle escaped line breaks in the format description macro.
+        let format_description = format_description::parse(
+            "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond] [offset_hour \
+            sign:mandatory]:[offset_minute]:[offset_second]",
+        ).expect("invalid format description");
+
+        ben.iter(|| {
+            OffsetDateTime::parse("2021-01-02 03:04:05.123456789 +01:02:03", &format_description)
+        });
+    }
+
     fn parse_rfc2822(ben: &mut Bencher<'_>) {
         ben.iter(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0000", &Rfc2822));
         ben.iter(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 +0607", &Rfc2822));
         ben.iter(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 -0607", &Rfc2822));
     }
+
+    fn parse_many(ben: &mut Bencher<'_>) {
+        let rows = parse_many_rows();
+        ben.iter(|| OffsetDateTime::parse_many(rows.iter().copied(), &Rfc3339));
+    }
+
+    fn parse_reusing(ben: &mut Bencher<'_>) {
+        let rows = parse_many_rows();
+        let mut parsed = Parsed::new();
+        ben.iter(|| {
+            for row in &rows {
+                let _ = OffsetDateTime::parse_reusing(row, &Rfc3339, &mut parsed);
+            }
+        });
+    }
+
+    fn parse_many_naive(ben: &mut Bencher<'_>) {
+        let rows = parse_many_rows();
+        ben.iter(|| {
+            let mut values = Vec::new();
+            let mut errors = Vec::new();
+            for (index, row) in rows.iter().enumerate() {
+                match OffsetDateTime::parse(row, &Rfc3339) {
+                    Ok(value) => values.push(value),
+                    Err(err) => errors.push((index, err)),
+                }
+            }
+            (values, errors)
+        });
+    }
+
+    fn parse_many_nested(ben: &mut Bencher<'_>) {
+        let rows = parse_many_rows();
+        let format_description = nested_description();
+        ben.iter(|| OffsetDateTime::parse_many(rows.iter().copied(), &format_description));
+    }
+
+    fn parse_many_nested_naive(ben: &mut Bencher<'_>) {
+        let rows = parse_many_rows();
+        let format_description = nested_description();
+        ben.iter(|| {
+            let mut values = Vec::new();
+            let mut errors = Vec::new();
+            for (index, row) in rows.iter().enumerate() {
+                match OffsetDateTime::parse(row, &format_description) {
+                    Ok(value) => values.push(value),
+                    Err(err) => errors.push((index, err)),
+                }
+            }
+            (values, errors)
+        });
+    }
+
+    fn parse_nested(ben: &mut Bencher<'_>) {
+        let rows = parse_many_rows();
+        let format_description = nested_description();
+        ben.iter(|| {
+            for row in &rows {
+                let _ = OffsetDateTime::parse(row, &format_description);
+            }
+        });
+    }
+
+    fn parse_nested_compiled(ben: &mut Bencher<'_>) {
+        let rows = parse_many_rows();
+        let format_description = CompiledFormat::from(nested_description());
+        ben.iter(|| {
+            for row in &rows {
+                let _ = OffsetDateTime::parse(row, &format_description);
+            }
+        });
+    }
 }
@@ -0,0 +1,43 @@
+use criterion::Bencher;
+use serde::{Deserialize, Serialize};
+use time::macros::datetime;
+use time::{OffsetDateTime, PrimitiveDateTime};
+
+/// A record with a date-time in each of the commonly used representations.
+#[derive(Serialize, Deserialize)]
+struct Record {
+    created: OffsetDateTime,
+    #[serde(with = "time::serde::rfc3339")]
+    updated: OffsetDateTime,
+    #[serde(with = "time::serde::iso8601")]
+    published: OffsetDateTime,
+    #[serde(with = "time::serde::timestamp")]
+    expires: OffsetDateTime,
+    local: PrimitiveDateTime,
+}
+
+const RECORD: Record = Record {
+    created: datetime!(2021-01-02 03:04:05.123_456_789 UTC),
+    updated: datetime!(2021-01-02 03:04:05.123_456_789 +01:02),
+    published: datetime!(2021-01-02 03:04:05.123_456_789 -01:02),
+    expires: datetime!(2021-01-02 03:04:05 UTC),
+    local: datetime!(2021-01-02 03:04:05.123_456_789),
+};
+
+setup_benchmark! {
+    "Serde",
+
+    fn json_round_trip(ben: &mut Bencher<'_>) {
+        ben.iter(|| {
+            let json = serde_json::to_vec(&RECORD).expect("serialization failed");
+            serde_json::from_slice::<Record>(&json).expect("deserialization failed")
+        });
+    }
+
+    fn binary_round_trip(ben: &mut Bencher<'_>) {
+        ben.iter(|| {
+            let bytes = bincode::serialize(&RECORD).expect("serialization failed");
+            bincode::deserialize::<Record>(&bytes).expect("deserialization failed")
+        });
+    }
+}
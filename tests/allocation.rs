@@ -0,0 +1,230 @@
+//! Checks on the number of allocations performed by formatting and parsing.
+//!
+//! Changes to these numbers are not necessarily wrong, but they should be deliberate. The
+//! benchmarks measure the resulting performance.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::io;
+
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use time::format_description::{self, FormatItem};
+use time::macros::{datetime, format_description as fd, time};
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+/// The system allocator, counting the allocations made on each thread.
+struct CountingAllocator;
+
+thread_local! {
+    /// The number of allocations made on the current thread.
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+// SAFETY: All allocation is delegated to the system allocator. Counting does not allocate.
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        // SAFETY: The caller upholds the requirements of `GlobalAlloc::alloc`.
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // SAFETY: The caller upholds the requirements of `GlobalAlloc::dealloc`.
+        unsafe { System.dealloc(ptr, layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        // SAFETY: The caller upholds the requirements of `GlobalAlloc::realloc`.
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+}
+
+/// Run the closure, returning the number of allocations (including reallocations) it made.
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    let value = f();
+    let after = ALLOCATIONS.with(Cell::get);
+    drop(value);
+    after - before
+}
+
+/// A format description equivalent to RFC 3339, as it would commonly be written by users.
+const DESCRIPTION: &[FormatItem<'_>] = fd!(
+    "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour \
+     sign:mandatory]:[offset_minute]"
+);
+
+time::serde::format_description!(datetime_format, OffsetDateTime, DESCRIPTION);
+time::serde::format_description!(time_format, Time, "[hour]:[minute]");
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Record {
+    #[serde(with = "datetime_format")]
+    datetime: OffsetDateTime,
+    #[serde(with = "time_format::option")]
+    time: Option<Time>,
+}
+
+#[test]
+fn format_into_does_not_allocate() {
+    let datetime = datetime!(2021-01-02 03:04:05.123_456_789 -01:02);
+
+    assert_eq!(
+        allocations(|| datetime.format_into(&mut io::sink(), &Rfc3339)),
+        0
+    );
+    assert_eq!(
+        allocations(|| datetime.format_into(&mut io::sink(), &Rfc2822)),
+        0
+    );
+    assert_eq!(
+        allocations(|| datetime.format_into(&mut io::sink(), &Iso8601::DEFAULT)),
+        0
+    );
+    assert_eq!(
+        allocations(|| datetime.format_into(&mut io::sink(), DESCRIPTION)),
+        0
+    );
+}
+
+#[test]
+fn display_does_not_allocate() {
+    use std::fmt::Write as _;
+
+    let datetime = datetime!(2021-01-02 03:04:05.123_456_789 -01:02);
+    let mut output = String::with_capacity(128);
+
+    assert_eq!(
+        allocations(|| write!(output, "{}", datetime.display(&Rfc3339))),
+        0
+    );
+    assert_eq!(
+        allocations(|| write!(output, " {}", datetime.display(DESCRIPTION))),
+        0
+    );
+    assert_eq!(
+        output,
+        "2021-01-02T03:04:05.123456789-01:02 2021-01-02T03:04:05.123456789-01:02"
+    );
+}
+
+#[test]
+fn format_to_array_does_not_allocate() {
+    let datetime = datetime!(2021-01-02 03:04:05.123_456_789 -01:02);
+
+    assert_eq!(allocations(|| datetime.format_to_array::<64>(&Rfc3339)), 0);
+    assert_eq!(allocations(|| datetime.format_to_array::<64>(DESCRIPTION)), 0);
+    assert_eq!(allocations(|| datetime.format_to_array::<8>(&Rfc3339)), 0);
+}
+
+#[test]
+fn format_into_slice_does_not_allocate() {
+    let datetime = datetime!(2021-01-02 03:04:05.123_456_789 -01:02);
+    let mut buf = [0; 64];
+
+    assert_eq!(
+        allocations(|| datetime.format_into_slice(&mut buf, &Rfc3339)),
+        0
+    );
+    assert_eq!(
+        allocations(|| datetime.format_into_slice(&mut buf, DESCRIPTION)),
+        0
+    );
+    assert_eq!(
+        allocations(|| datetime.format_into_slice(&mut buf[..8], &Rfc3339)),
+        0
+    );
+    assert_eq!(allocations(|| datetime.formatted_len(&Rfc3339)), 0);
+}
+
+#[test]
+fn parse_does_not_allocate() {
+    assert_eq!(
+        allocations(|| OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", &Rfc3339)),
+        0
+    );
+    assert_eq!(
+        allocations(|| OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 -0102", &Rfc2822)),
+        0
+    );
+    assert_eq!(
+        allocations(|| {
+            OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", &Iso8601::DEFAULT)
+        }),
+        0
+    );
+    assert_eq!(
+        allocations(|| {
+            OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", DESCRIPTION)
+        }),
+        0
+    );
+    assert_eq!(
+        allocations(|| PrimitiveDateTime::parse("2021-01-02T03:04:05", &Iso8601::DEFAULT)),
+        0
+    );
+}
+
+#[test]
+fn parse_error_does_not_allocate() {
+    assert_eq!(
+        allocations(|| OffsetDateTime::parse("2021-01-02T03:04:05", &Rfc3339)),
+        0
+    );
+    assert_eq!(
+        allocations(|| OffsetDateTime::parse("2021-02-30T03:04:05Z", &Rfc3339)),
+        0
+    );
+}
+
+#[test]
+fn format_allocates_output_only() {
+    let datetime = datetime!(2021-01-02 03:04:05.123_456_789 -01:02);
+    let mut output = Vec::with_capacity(128);
+
+    assert_eq!(
+        allocations(|| datetime.format_into(&mut output, &Rfc3339)),
+        0
+    );
+    assert_eq!(
+        allocations(|| datetime.format_into(&mut output, DESCRIPTION)),
+        0
+    );
+    assert_eq!(output.len(), 70);
+
+    // The returned string is grown in place as the output is written.
+    assert_eq!(allocations(|| datetime.format(&Rfc3339)), 4);
+}
+
+#[test]
+fn parse_owned_description_does_not_allocate() -> time::Result<()> {
+    let description = format_description::parse_owned::<2>("[year]-[month]-[day]")?;
+    assert_eq!(allocations(|| Date::parse("2021-01-02", &description)), 0);
+    Ok(())
+}
+
+#[test]
+fn serde_format_description_does_not_allocate() {
+    let record = Record {
+        datetime: datetime!(2021-01-02 03:04:05.123_456_789 -01:02),
+        time: Some(time!(12:34)),
+    };
+    let json = r#"{"datetime":"2021-01-02T03:04:05.123456789-01:02","time":"12:34"}"#;
+    let mut output = Vec::with_capacity(128);
+
+    assert_eq!(
+        allocations(|| serde_json::to_writer(&mut output, &record)),
+        0
+    );
+    assert_eq!(output, json.as_bytes());
+    assert_eq!(
+        allocations(|| serde_json::from_str::<Record>(json).map(drop)),
+        0
+    );
+    assert_eq!(serde_json::from_str::<Record>(json).ok(), Some(record));
+}
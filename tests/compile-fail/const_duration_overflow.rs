@@ -0,0 +1,13 @@
+use std::time::Duration as StdDuration;
+
+use time::Duration;
+
+const ADD: Duration = Duration::MAX.const_add(Duration::NANOSECOND);
+const SUB: Duration = Duration::MIN.const_sub(Duration::NANOSECOND);
+const MUL: Duration = Duration::MAX.const_mul(2);
+const NEG: Duration = Duration::MIN.const_neg();
+const FROM_STD: Duration = Duration::from_std_const(StdDuration::MAX);
+
+fn main() {
+    let _ = (ADD, SUB, MUL, NEG, FROM_STD);
+}
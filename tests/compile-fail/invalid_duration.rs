@@ -0,0 +1,16 @@
+use time::macros::duration;
+
+fn main() {
+    let _ = duration!();
+    let _ = duration!(1);
+    let _ = duration!(1 y);
+    let _ = duration!(1x);
+    let _ = duration!(30 m 2 h);
+    let _ = duration!(1 s 1 s);
+    let _ = duration!(0.5 ns);
+    let _ = duration!(9_223_372_036_854_775_808 s);
+    let _ = duration!(-9_223_372_036_854_775_809 s);
+    let _ = duration!(15_250_284_452_472 w);
+    let _ = duration!(1 s -);
+    let _ = duration!(- -1 s);
+}
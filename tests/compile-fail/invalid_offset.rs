@@ -9,4 +9,6 @@ fn main() {
     let _ = offset!(+0a);
     let _ = offset!(+0:0a);
     let _ = offset!(+0:00:0a);
+    let _ = offset!(+0:19:32.5);
+    let _ = offset!(-0:00:60);
 }
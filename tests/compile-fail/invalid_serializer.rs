@@ -12,5 +12,6 @@ serde::format_description!(my_format, OffsetDateTime "[year] [month]"); // missi
 serde::format_description!(my_format, OffsetDateTime : "[year] [month]"); // not a comma
 serde::format_description!(my_format, OffsetDateTime, "[bad]"); // bad component name
 serde::format_description!(my_format, OffsetDateTime, not_string); // string format wrong type
+serde::format_description!(my_format, Duration, "[hour]:[minute]"); // format string for duration
 
 fn main() {}
@@ -14,4 +14,8 @@ fn main() {
     let _ = time!(0);
     let _ = time!(0 pm);
     let _ = time!(1 am :);
+    let _ = time!(0:00:00.0000000001);
+    let _ = time!(0:00:59.9999999999);
+    let _ = time!(0:00:00.5e1);
+    let _ = time!(0:00:60.5);
 }
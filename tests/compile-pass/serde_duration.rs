@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Iso8601Duration;
+use time::Duration;
+
+const FORMAT: Iso8601Duration = Iso8601Duration::DEFAULT;
+time::serde::format_description!(my_format, Duration, FORMAT);
+
+#[derive(Serialize, Deserialize)]
+struct Config {
+    #[serde(with = "my_format")]
+    timeout: Duration,
+    #[serde(with = "my_format::option")]
+    retry_after: Option<Duration>,
+}
+
+fn main() {
+    let _ = Config {
+        timeout: Duration::ZERO,
+        retry_after: None,
+    };
+}
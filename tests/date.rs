@@ -1,6 +1,6 @@
-use core::i32;
 use std::cmp::Ordering;
 use std::collections::HashSet;
+use std::time::Duration as StdDuration;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, time};
@@ -35,6 +35,44 @@ fn weeks_in_year_exhaustive() {
     }
 }
 
+#[test]
+fn iso_weeks_in_year() {
+    use Weekday::*;
+
+    for year in -400..400 {
+        let weeks = Date::iso_weeks_in_year(year);
+        assert_eq!(weeks, util::weeks_in_year(year));
+        assert_eq!(
+            Date::from_iso_week_date(year, weeks, Sunday).map(Date::iso_week),
+            Ok(weeks)
+        );
+        let err = Date::from_iso_week_date(year, weeks + 1, Monday).unwrap_err();
+        assert_eq!(err.name(), "week");
+        assert!(err.is_conditional());
+    }
+
+    assert_eq!(Date::iso_weeks_in_year(2020), 53);
+    assert_eq!(Date::iso_weeks_in_year(2023), 52);
+    assert_eq!(
+        Date::from_iso_week_date(2020, 54, Monday)
+            .unwrap_err()
+            .to_string(),
+        "week must be in the range 1..=53 because ISO year 2020 has 53 weeks"
+    );
+    assert_eq!(
+        Date::from_iso_week_date(2025, 53, Thursday)
+            .unwrap_err()
+            .to_string(),
+        "week must be in the range 1..=52 because ISO year 2025 has 52 weeks"
+    );
+    assert_eq!(
+        Date::from_iso_week_date(2023, 0, Monday)
+            .unwrap_err()
+            .to_string(),
+        "week must be in the range 1..=52 because ISO year 2023 has 52 weeks"
+    );
+}
+
 // Test all dominical letters. For leap years, check the dates immediately preceding and after the
 // leap day.
 
@@ -439,6 +477,114 @@ fn from_iso_week_date() {
     assert_eq!(date!(-0001-W 52-6), date!(0000 - 01 - 01));
 }
 
+#[test]
+fn from_calendar_date_clamped() {
+    assert_eq!(
+        Date::from_calendar_date_clamped_with_flag(2021, Month::July, 15),
+        (date!(2021 - 07 - 15), false)
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped_with_flag(2024, Month::February, 31),
+        (date!(2024 - 02 - 29), true)
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped_with_flag(2023, Month::February, 29),
+        (date!(2023 - 02 - 28), true)
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped_with_flag(2023, Month::April, 31),
+        (date!(2023 - 04 - 30), true)
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped_with_flag(2023, Month::April, u8::MAX),
+        (date!(2023 - 04 - 30), true)
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped_with_flag(2023, Month::April, 0),
+        (date!(2023 - 04 - 01), true)
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped_with_flag(i32::MAX, Month::December, 31),
+        (Date::MAX, true)
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped_with_flag(i32::MIN, Month::January, 1),
+        (Date::MIN, true)
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped_with_flag(i32::MIN, Month::March, 40).0,
+        Date::from_calendar_date(Date::MIN.year(), Month::March, 31).unwrap()
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped(2024, Month::February, 30),
+        date!(2024 - 02 - 29)
+    );
+    assert_eq!(
+        Date::from_calendar_date_clamped(2024, Month::December, 31),
+        date!(2024 - 12 - 31)
+    );
+}
+
+#[test]
+fn conditional_range_error_message() {
+    use Weekday::*;
+    assert_eq!(
+        Date::from_calendar_date(2023, Month::February, 29)
+            .unwrap_err()
+            .to_string(),
+        "day must be in the range 1..=28 because February 2023 has 28 days"
+    );
+    assert_eq!(
+        Date::from_calendar_date(2024, Month::April, 31)
+            .unwrap_err()
+            .to_string(),
+        "day must be in the range 1..=30 because April 2024 has 30 days"
+    );
+    assert_eq!(
+        Date::from_ordinal_date(2023, 366).unwrap_err().to_string(),
+        "ordinal must be in the range 1..=365 because 2023 is not a leap year"
+    );
+    assert_eq!(
+        Date::from_ordinal_date(2024, 367).unwrap_err().to_string(),
+        "ordinal must be in the range 1..=366 because 2024 is a leap year"
+    );
+    assert_eq!(
+        Date::from_iso_week_date(2023, 53, Monday)
+            .unwrap_err()
+            .to_string(),
+        "week must be in the range 1..=52 because ISO year 2023 has 52 weeks"
+    );
+    assert_eq!(
+        date!(2023 - 02 - 18)
+            .replace_day(29)
+            .unwrap_err()
+            .to_string(),
+        "day must be in the range 1..=28 because February 2023 has 28 days"
+    );
+    assert_eq!(
+        date!(2024 - 02 - 29)
+            .replace_year(2023)
+            .unwrap_err()
+            .to_string(),
+        "day must be in the range 1..=28 because February 2023 has 28 days"
+    );
+    assert!(Date::from_ordinal_date(2023, 366)
+        .unwrap_err()
+        .is_conditional());
+
+    let err = Date::from_calendar_date(2023, Month::February, 29).unwrap_err();
+    assert_eq!(err.name(), "day");
+    assert_eq!(err.minimum(), 1);
+    assert_eq!(err.maximum(), 28);
+    assert_eq!(err.value(), 29);
+    let err = Date::from_ordinal_date(2023, 366).unwrap_err();
+    assert_eq!((err.minimum(), err.maximum(), err.value()), (1, 365, 366));
+    let err = Date::from_iso_week_date(2023, 53, Monday).unwrap_err();
+    assert_eq!((err.minimum(), err.maximum(), err.value()), (1, 52, 53));
+    let err = Date::from_calendar_date(2023, Month::January, 0).unwrap_err();
+    assert_eq!((err.minimum(), err.maximum(), err.value()), (1, 31, 0));
+}
+
 #[test]
 fn year() {
     assert_eq!(date!(2019 - 002).year(), 2019);
@@ -461,6 +607,72 @@ fn day() {
     assert_eq!(date!(2020 - 060).day(), 29);
 }
 
+#[test]
+fn month_boundaries_exhaustive() {
+    use Month::*;
+
+    // Cover both leap states, including the century rules.
+    for year in [1900, 2000, 2019, 2020] {
+        let leap = util::is_leap_year(year);
+        for (month, last_day) in [
+            (January, 31),
+            (February, if leap { 29 } else { 28 }),
+            (March, 31),
+            (April, 30),
+            (May, 31),
+            (June, 30),
+            (July, 31),
+            (August, 31),
+            (September, 30),
+            (October, 31),
+            (November, 30),
+            (December, 31),
+        ] {
+            for day in 1..=last_day {
+                let date = Date::from_calendar_date(year, month, day).unwrap();
+                assert_eq!(date.is_first_day_of_month(), day == 1, "{date}");
+                assert_eq!(date.is_last_day_of_month(), day == last_day, "{date}");
+                assert_eq!(
+                    date.is_first_day_of_year(),
+                    month == January && day == 1,
+                    "{date}"
+                );
+                assert_eq!(
+                    date.is_last_day_of_year(),
+                    month == December && day == 31,
+                    "{date}"
+                );
+                assert_eq!(date.is_leap_day(), month == February && day == 29, "{date}");
+            }
+        }
+    }
+}
+
+#[test]
+fn month_boundaries() {
+    assert!(date!(2019 - 02 - 28).is_last_day_of_month());
+    assert!(!date!(2020 - 02 - 28).is_last_day_of_month());
+    assert!(date!(2020 - 02 - 29).is_last_day_of_month());
+    assert!(!date!(2019 - 03 - 01).is_leap_day());
+    assert!(date!(2019 - 03 - 01).is_first_day_of_month());
+    assert!(date!(2020 - 12 - 31).is_last_day_of_year());
+    assert!(!date!(2020 - 12 - 30).is_last_day_of_year());
+
+    assert!(Date::MIN.is_first_day_of_month());
+    assert!(Date::MIN.is_first_day_of_year());
+    assert!(Date::MAX.is_last_day_of_month());
+    assert!(Date::MAX.is_last_day_of_year());
+
+    const BOUNDARIES: [bool; 5] = [
+        date!(2019 - 02 - 01).is_first_day_of_month(),
+        date!(2020 - 02 - 29).is_last_day_of_month(),
+        date!(2019 - 01 - 01).is_first_day_of_year(),
+        date!(2019 - 12 - 31).is_last_day_of_year(),
+        date!(2020 - 02 - 29).is_leap_day(),
+    ];
+    assert_eq!(BOUNDARIES, [true; 5]);
+}
+
 #[test]
 fn iso_week() {
     assert_eq!(date!(2019 - 01 - 01).iso_week(), 1);
@@ -470,6 +682,27 @@ fn iso_week() {
     assert_eq!(date!(2021 - 01 - 01).iso_week(), 53);
 }
 
+#[test]
+fn iso_year_week() {
+    assert_eq!(date!(2019 - 10 - 04).iso_year_week(), (2019, 40));
+    assert_eq!(date!(2019 - 12 - 29).iso_year_week(), (2019, 52));
+    assert_eq!(date!(2019 - 12 - 30).iso_year_week(), (2020, 1));
+    assert_eq!(date!(2016 - 01 - 03).iso_year_week(), (2015, 53));
+    assert_eq!(date!(2016 - 01 - 04).iso_year_week(), (2016, 1));
+    assert_eq!(date!(2021 - 01 - 01).iso_year_week(), (2020, 53));
+    assert_eq!(date!(2024 - 12 - 30).iso_year_week(), (2025, 1));
+    assert_eq!(date!(0000 - 01 - 01).iso_year_week(), (-1, 52));
+
+    for date in [
+        date!(2019 - 12 - 30),
+        date!(2021 - 01 - 01),
+        date!(2024 - 06 - 15),
+    ] {
+        let (year, week, _) = date.to_iso_week_date();
+        assert_eq!(date.iso_year_week(), (year, week));
+    }
+}
+
 #[test]
 fn to_calendar_date() {
     assert_eq!(
@@ -520,11 +753,28 @@ fn to_calendar_date() {
         date!(2019 - 12 - 02).to_calendar_date(),
         (2019, Month::December, 2)
     );
+
+    const CALENDAR_DATE: (i32, Month, u8) = date!(2020 - 02 - 29).to_calendar_date();
+    assert_eq!(CALENDAR_DATE, (2020, Month::February, 29));
+}
+
+#[test]
+fn month_day() {
+    assert_eq!(date!(2019 - 01 - 01).month_day(), (Month::January, 1));
+    assert_eq!(date!(2019 - 03 - 01).month_day(), (Month::March, 1));
+    assert_eq!(date!(2020 - 02 - 29).month_day(), (Month::February, 29));
+    assert_eq!(date!(2020 - 12 - 31).month_day(), (Month::December, 31));
+
+    const MONTH_DAY: (Month, u8) = date!(2019 - 12 - 31).month_day();
+    assert_eq!(MONTH_DAY, (Month::December, 31));
 }
 
 #[test]
 fn to_ordinal_date() {
     assert_eq!(date!(2019 - 01 - 01).to_ordinal_date(), (2019, 1));
+
+    const ORDINAL_DATE: (i32, u16) = date!(2020 - 12 - 31).to_ordinal_date();
+    assert_eq!(ORDINAL_DATE, (2020, 366));
 }
 
 #[test]
@@ -542,6 +792,9 @@ fn to_iso_week_date() {
     );
     assert_eq!(date!(2021 - 01 - 01).to_iso_week_date(), (2020, 53, Friday));
     assert_eq!(date!(0000 - 01 - 01).to_iso_week_date(), (-1, 52, Saturday));
+
+    const ISO_WEEK_DATE: (i32, u8, Weekday) = date!(2021 - 01 - 01).to_iso_week_date();
+    assert_eq!(ISO_WEEK_DATE, (2020, 53, Friday));
 }
 
 #[test]
@@ -630,6 +883,173 @@ fn from_julian_day() {
     assert!(Date::from_julian_day(i32::MAX).is_err());
 }
 
+#[test]
+fn to_julian_calendar() {
+    // The Gregorian calendar was adopted the day after 1582-10-04 in the Julian calendar.
+    assert_eq!(
+        date!(1582 - 10 - 14).to_julian_calendar(),
+        (1582, Month::October, 4)
+    );
+    assert_eq!(
+        date!(1582 - 10 - 15).to_julian_calendar(),
+        (1582, Month::October, 5)
+    );
+    // The gap grows from 12 to 13 days at the Julian leap day in 1900.
+    assert_eq!(
+        date!(1900 - 02 - 28).to_julian_calendar(),
+        (1900, Month::February, 16)
+    );
+    assert_eq!(
+        date!(1900 - 03 - 12).to_julian_calendar(),
+        (1900, Month::February, 28)
+    );
+    assert_eq!(
+        date!(1900 - 03 - 13).to_julian_calendar(),
+        (1900, Month::February, 29)
+    );
+    assert_eq!(
+        date!(1900 - 03 - 14).to_julian_calendar(),
+        (1900, Month::March, 1)
+    );
+    // 2000 is a leap year in both calendars, so the gap stays at 13 days.
+    assert_eq!(
+        date!(2000 - 03 - 13).to_julian_calendar(),
+        (2000, Month::February, 29)
+    );
+    assert_eq!(
+        date!(2000 - 03 - 14).to_julian_calendar(),
+        (2000, Month::March, 1)
+    );
+    assert_eq!(
+        date!(2100 - 03 - 13).to_julian_calendar(),
+        (2100, Month::February, 28)
+    );
+    assert_eq!(
+        date!(2100 - 03 - 14).to_julian_calendar(),
+        (2100, Month::February, 29)
+    );
+    // The calendars agree in the third century.
+    assert_eq!(
+        date!(0200 - 03 - 01).to_julian_calendar(),
+        (200, Month::March, 1)
+    );
+    assert_eq!(
+        date!(0000 - 01 - 01).to_julian_calendar(),
+        (0, Month::January, 3)
+    );
+    assert_eq!(
+        date!(-0001 - 12 - 30).to_julian_calendar(),
+        (0, Month::January, 1)
+    );
+}
+
+#[test]
+fn from_julian_calendar() {
+    assert_eq!(
+        Date::from_julian_calendar(1582, Month::October, 4),
+        Ok(date!(1582 - 10 - 14))
+    );
+    assert_eq!(
+        Date::from_julian_calendar(1582, Month::October, 5),
+        Ok(date!(1582 - 10 - 15))
+    );
+    assert_eq!(
+        Date::from_julian_calendar(1918, Month::January, 31),
+        Ok(date!(1918 - 02 - 13))
+    );
+    assert_eq!(
+        Date::from_julian_calendar(1900, Month::February, 29),
+        Ok(date!(1900 - 03 - 13))
+    );
+    assert_eq!(
+        Date::from_julian_calendar(2000, Month::February, 29),
+        Ok(date!(2000 - 03 - 13))
+    );
+    // The Julian period begins on 4713 BC (year -4712) January 1 in the Julian calendar.
+    assert_eq!(
+        Date::from_julian_calendar(-4712, Month::January, 1),
+        Date::from_julian_day(0)
+    );
+    assert!(Date::from_julian_calendar(1901, Month::February, 29).is_err());
+    assert!(Date::from_julian_calendar(1900, Month::April, 31).is_err());
+    assert!(Date::from_julian_calendar(1900, Month::January, 0).is_err());
+    assert!(Date::from_julian_calendar(1_000_000, Month::January, 1).is_err());
+    // Within the range of years, but the Gregorian date is not.
+    assert!(Date::from_julian_calendar(999_999, Month::December, 31).is_err());
+
+    for date in [
+        Date::MIN,
+        date!(-0001 - 02 - 28),
+        date!(0000 - 02 - 29),
+        date!(1582 - 10 - 15),
+        date!(2024 - 02 - 29),
+        Date::MAX,
+    ] {
+        let (year, month, day) = date.to_julian_calendar();
+        assert_eq!(Date::from_julian_calendar(year, month, day), Ok(date));
+    }
+}
+
+#[test]
+fn to_packed_u32() {
+    assert_eq!(date!(-999_999 - 01 - 01).to_packed_u32(), 0xE17B_8201);
+    assert_eq!(date!(-0001 - 12 - 31).to_packed_u32(), 0xFFFF_FF6D);
+    assert_eq!(date!(0000 - 01 - 01).to_packed_u32(), 0x0000_0001);
+    assert_eq!(date!(2000 - 12 - 31).to_packed_u32(), 0x000F_A16E);
+    assert_eq!(date!(2019 - 01 - 01).to_packed_u32(), 0x000F_C601);
+    assert_eq!(date!(+999_999 - 12 - 31).to_packed_u32(), 0x1E84_7F6D);
+}
+
+#[test]
+fn from_packed_u32() {
+    assert_eq!(
+        Date::from_packed_u32(0xE17B_8201),
+        Ok(date!(-999_999 - 01 - 01))
+    );
+    assert_eq!(
+        Date::from_packed_u32(0xFFFF_FF6D),
+        Ok(date!(-0001 - 12 - 31))
+    );
+    assert_eq!(
+        Date::from_packed_u32(0x0000_0001),
+        Ok(date!(0000 - 01 - 01))
+    );
+    assert_eq!(
+        Date::from_packed_u32(0x000F_A16E),
+        Ok(date!(2000 - 12 - 31))
+    );
+    assert_eq!(
+        Date::from_packed_u32(0x000F_C601),
+        Ok(date!(2019 - 01 - 01))
+    );
+    assert_eq!(
+        Date::from_packed_u32(0x1E84_7F6D),
+        Ok(date!(+999_999 - 12 - 31))
+    );
+
+    for date in [Date::MIN, date!(2020 - 02 - 29), Date::MAX] {
+        assert_eq!(Date::from_packed_u32(date.to_packed_u32()), Ok(date));
+    }
+
+    assert_eq!(Date::from_packed_u32(0).unwrap_err().name(), "ordinal");
+    assert_eq!(
+        Date::from_packed_u32(0x000F_C76E).unwrap_err().name(),
+        "ordinal"
+    );
+    assert_eq!(
+        Date::from_packed_u32(u32::MAX).unwrap_err().name(),
+        "ordinal"
+    );
+    assert_eq!(
+        Date::from_packed_u32(0x7FFF_FE01).unwrap_err().name(),
+        "year"
+    );
+    assert_eq!(
+        Date::from_packed_u32(0x8000_0001).unwrap_err().name(),
+        "year"
+    );
+}
+
 #[test]
 fn midnight() {
     assert_eq!(date!(1970 - 01 - 01).midnight(), datetime!(1970-01-01 0:00));
@@ -735,6 +1155,11 @@ fn sub_assign_std() {
 fn sub_self() {
     assert_eq!(date!(2019 - 01 - 06) - date!(2019 - 01 - 01), 5.days());
     assert_eq!(date!(2020 - 01 - 01) - date!(2019 - 12 - 31), 1.days());
+
+    let span = (Date::MAX.to_julian_day() - Date::MIN.to_julian_day()) as i64;
+    assert_eq!(Date::MAX - Date::MIN, span.days());
+    assert_eq!(Date::MIN - Date::MAX, (-span).days());
+    assert_eq!((Date::MAX - Date::MIN).whole_days(), span);
 }
 
 #[test]
@@ -757,6 +1182,35 @@ fn ord() {
     assert_eq!(second.cmp(&first), Ordering::Greater);
 }
 
+#[test]
+fn min_max_clamp() {
+    let (low, middle, high) = (
+        date!(2019 - 01 - 01),
+        date!(2019 - 06 - 15),
+        date!(2019 - 12 - 31),
+    );
+    assert_eq!(low.min(high), low);
+    assert_eq!(high.min(low), low);
+    assert_eq!(low.max(high), high);
+    assert_eq!(high.max(low), high);
+    assert_eq!(low.min(low), low);
+    assert_eq!(low.clamp(middle, high), middle);
+    assert_eq!(middle.clamp(low, high), middle);
+    assert_eq!(high.clamp(low, middle), middle);
+    assert_eq!(middle.clamp(middle, middle), middle);
+    assert_eq!(Date::MIN.min(Date::MAX), Date::MIN);
+    assert_eq!(Date::MIN.max(Date::MAX), Date::MAX);
+
+    const CLAMPED: Date = date!(2020 - 01 - 01).clamp(date!(2019 - 01 - 01), date!(2019 - 12 - 31));
+    assert_eq!(CLAMPED, date!(2019 - 12 - 31));
+}
+
+#[test]
+#[should_panic]
+fn clamp_panic() {
+    let _ = date!(2019 - 06 - 15).clamp(date!(2019 - 12 - 31), date!(2019 - 01 - 01));
+}
+
 #[test]
 fn regression_check() {
     let (year, week, weekday) = (date!(0063 - 365)).to_iso_week_date();
@@ -873,6 +1327,55 @@ fn checked_sub_duration() {
     assert_eq!(Date::MAX.checked_sub(Duration::MIN), None);
 }
 
+#[test]
+fn checked_add_std() {
+    assert_eq!(
+        date!(2021 - 10 - 25).checked_add_std(1.std_days()),
+        Some(date!(2021 - 10 - 26))
+    );
+    assert_eq!(
+        date!(2021 - 10 - 25).checked_add_std(47.std_hours()),
+        Some(date!(2021 - 10 - 26))
+    );
+    assert_eq!(Date::MAX.checked_add_std(23.std_hours()), Some(Date::MAX));
+    assert_eq!(Date::MAX.checked_add_std(1.std_days()), None);
+    assert_eq!(Date::MIN.checked_add_std(StdDuration::MAX), None);
+    // The number of days doesn't fit in an `i32`, but must not wrap around.
+    assert_eq!(Date::MIN.checked_add_std((1_u64 << 32).std_days()), None);
+}
+
+#[test]
+fn checked_sub_std() {
+    assert_eq!(
+        date!(2021 - 10 - 25).checked_sub_std(1.std_days()),
+        Some(date!(2021 - 10 - 24))
+    );
+    assert_eq!(
+        date!(2021 - 10 - 25).checked_sub_std(47.std_hours()),
+        Some(date!(2021 - 10 - 24))
+    );
+    assert_eq!(Date::MIN.checked_sub_std(23.std_hours()), Some(Date::MIN));
+    assert_eq!(Date::MIN.checked_sub_std(1.std_days()), None);
+    assert_eq!(Date::MAX.checked_sub_std(StdDuration::MAX), None);
+    assert_eq!(Date::MAX.checked_sub_std((1_u64 << 32).std_days()), None);
+}
+
+#[test]
+fn arithmetic_overflow_panic() {
+    let (max, min) = (Date::MAX, Date::MIN);
+    let (day, std_day) = (1.days(), 1.std_days());
+
+    assert_panic_with_operands!(max + day, max, day);
+    assert_panic_with_operands!(max + std_day, max, std_day);
+    assert_panic_with_operands!(min - day, min, day);
+    assert_panic_with_operands!(min - std_day, min, std_day);
+
+    let mut date = max;
+    assert_panic_with_operands!(date += day, max, day);
+    let mut date = min;
+    assert_panic_with_operands!(date -= std_day, min, std_day);
+}
+
 #[test]
 fn saturating_add_duration() {
     assert_eq!(
@@ -982,3 +1485,109 @@ fn replace_day() {
     assert!(date!(2022 - 02 - 18).replace_day(0).is_err()); // 0 isn't a valid day
     assert!(date!(2022 - 02 - 18).replace_day(30).is_err()); // 30 isn't a valid day in February
 }
+
+#[test]
+fn month_days() {
+    let days = date!(2024 - 02 - 14).month_days();
+    assert_eq!(days.len(), 29);
+    assert_eq!(
+        days.collect::<Vec<_>>(),
+        (1..=29)
+            .map(|day| Date::from_calendar_date(2024, Month::February, day).unwrap())
+            .collect::<Vec<_>>()
+    );
+
+    let mut days = date!(2023 - 02 - 01).month_days();
+    assert_eq!(days.len(), 28);
+    assert_eq!(days.next(), Some(date!(2023 - 02 - 01)));
+    assert_eq!(days.next_back(), Some(date!(2023 - 02 - 28)));
+    assert_eq!(days.len(), 26);
+
+    assert_eq!(date!(2019 - 12 - 31).month_days().len(), 31);
+    assert_eq!(
+        date!(2019 - 12 - 31).month_days().last(),
+        Some(date!(2019 - 12 - 31))
+    );
+    assert_eq!(Date::MIN.month_days().next(), Some(Date::MIN));
+    assert_eq!(Date::MAX.month_days().last(), Some(Date::MAX));
+}
+
+#[test]
+fn calendar_weeks() {
+    // May 2024 starts on a Wednesday and ends on a Friday.
+    let d = |day| Some(Date::from_calendar_date(2024, Month::May, day).unwrap());
+    let weeks = date!(2024 - 05 - 14).calendar_weeks(Weekday::Monday);
+    assert_eq!(weeks.len(), 5);
+    assert_eq!(
+        weeks.collect::<Vec<_>>(),
+        [
+            [None, None, d(1), d(2), d(3), d(4), d(5)],
+            [d(6), d(7), d(8), d(9), d(10), d(11), d(12)],
+            [d(13), d(14), d(15), d(16), d(17), d(18), d(19)],
+            [d(20), d(21), d(22), d(23), d(24), d(25), d(26)],
+            [d(27), d(28), d(29), d(30), d(31), None, None],
+        ]
+    );
+
+    let weeks = date!(2024 - 05 - 01).calendar_weeks(Weekday::Sunday);
+    assert_eq!(weeks.len(), 5);
+    assert_eq!(
+        weeks.collect::<Vec<_>>(),
+        [
+            [None, None, None, d(1), d(2), d(3), d(4)],
+            [d(5), d(6), d(7), d(8), d(9), d(10), d(11)],
+            [d(12), d(13), d(14), d(15), d(16), d(17), d(18)],
+            [d(19), d(20), d(21), d(22), d(23), d(24), d(25)],
+            [d(26), d(27), d(28), d(29), d(30), d(31), None],
+        ]
+    );
+
+    let weeks = date!(2024 - 05 - 31).calendar_weeks(Weekday::Thursday);
+    assert_eq!(weeks.len(), 6);
+    assert_eq!(
+        weeks.last(),
+        Some([d(30), d(31), None, None, None, None, None])
+    );
+
+    // February 2021 fits exactly in four weeks starting on Monday.
+    let weeks = date!(2021 - 02 - 10).calendar_weeks(Weekday::Monday);
+    assert_eq!(weeks.len(), 4);
+    assert!(weeks.flatten().all(|day| day.is_some()));
+
+    // Every day of the month appears exactly once, in order, in the column of its weekday.
+    for date in [
+        date!(2024 - 02 - 29),
+        date!(2023 - 02 - 28),
+        date!(2000 - 02 - 01),
+    ] {
+        for week_start in [Weekday::Monday, Weekday::Wednesday, Weekday::Sunday] {
+            let weeks = date.calendar_weeks(week_start).collect::<Vec<_>>();
+            for week in &weeks {
+                for (column, day) in (0..).zip(week) {
+                    if let Some(day) = day {
+                        assert_eq!(day.weekday(), week_start.nth_next(column));
+                    }
+                }
+            }
+            assert_eq!(
+                weeks.into_iter().flatten().flatten().collect::<Vec<_>>(),
+                date.month_days().collect::<Vec<_>>()
+            );
+        }
+    }
+}
+
+#[test]
+fn reference_ops() {
+    let date = date!(2021 - 01 - 02);
+    assert_ref_binop!(date, +, 5.days());
+    assert_ref_binop!(date, +, 5.std_days());
+    assert_ref_binop!(date, -, 5.days());
+    assert_ref_binop!(date, -, 5.std_days());
+    assert_ref_binop!(date, -, date!(2020 - 01 - 02));
+
+    assert_ref_op_assign!(date, +=, 5.days());
+    assert_ref_op_assign!(date, +=, 5.std_days());
+    assert_ref_op_assign!(date, -=, 5.days());
+    assert_ref_op_assign!(date, -=, 5.std_days());
+}
@@ -7,7 +7,7 @@ use time::ext::NumericalDuration;
 use time::format_description::{self, modifier, well_known, Component, FormatItem, OwnedFormatItem};
 use time::macros::{date, offset, time};
 use time::parsing::Parsed;
-use time::{Duration, Error, Instant, Month, Time, Weekday};
+use time::{Duration, Error, Instant, Month, Period, Time, Weekday};
 use time_macros::datetime;
 
 macro_rules! assert_cloned_eq {
@@ -34,6 +34,7 @@ fn clone() {
     assert_cloned_eq!(datetime!(2021-001 0:00 UTC));
     assert_cloned_eq!(Weekday::Monday);
     assert_cloned_eq!(Month::January);
+    assert_cloned_eq!(Period::Am);
     assert_cloned_eq!(Duration::ZERO);
     assert_cloned_eq!(instant);
     assert_cloned_eq!(IndeterminateOffset);
@@ -48,9 +49,17 @@ fn clone() {
     assert_cloned_eq!(error::InvalidVariant);
     assert_cloned_eq!(error::ParseFromDescription::InvalidComponent("foo"));
     assert_cloned_eq!(Component::OffsetSecond(modifier::OffsetSecond::default()));
+    assert_cloned_eq!(Component::Offset(modifier::Offset::default()));
     assert_cloned_eq!(well_known::Rfc2822);
+    assert_cloned_eq!(well_known::JsDate);
     assert_cloned_eq!(well_known::Rfc3339);
+    assert_cloned_eq!(well_known::Rfc3339NoZ);
+    assert_cloned_eq!(well_known::Rfc3339Lenient);
+    assert_cloned_eq!(well_known::Rfc7231);
+    assert_cloned_eq!(well_known::Systemd);
+    assert_cloned_eq!(well_known::Unix);
     assert_cloned_eq!(well_known::Iso8601::DEFAULT);
+    assert_cloned_eq!(well_known::Iso8601Duration::DEFAULT);
     assert_cloned_eq!(well_known::iso8601::FormattedComponents::None);
     assert_cloned_eq!(well_known::iso8601::DateKind::Calendar);
     assert_cloned_eq!(well_known::iso8601::TimePrecision::Hour {
@@ -63,6 +72,7 @@ fn clone() {
     assert_cloned_eq!(time::util::local_offset::Soundness::Sound);
 
     assert_cloned_eq!(modifier::Day::default());
+    assert_cloned_eq!(modifier::DayRepr::default());
     assert_cloned_eq!(modifier::MonthRepr::default());
     assert_cloned_eq!(modifier::Month::default());
     assert_cloned_eq!(modifier::Ordinal::default());
@@ -81,6 +91,8 @@ fn clone() {
     assert_cloned_eq!(modifier::OffsetHour::default());
     assert_cloned_eq!(modifier::OffsetMinute::default());
     assert_cloned_eq!(modifier::OffsetSecond::default());
+    assert_cloned_eq!(modifier::OffsetSeparator::default());
+    assert_cloned_eq!(modifier::Offset::default());
     assert_cloned_eq!(modifier::Padding::default());
 }
 
@@ -94,6 +106,7 @@ fn hash() {
     datetime!(2021-001 0:00 UTC).hash(&mut hasher);
     Weekday::Monday.hash(&mut hasher);
     Month::January.hash(&mut hasher);
+    Period::Am.hash(&mut hasher);
     Instant::now().hash(&mut hasher);
     Duration::ZERO.hash(&mut hasher);
     component_range_error().hash(&mut hasher);
@@ -134,6 +147,7 @@ fn debug() {
 
     debug_all! {
         Duration::ZERO;
+        Period::Am;
         IndeterminateOffset;
         ConversionRange;
         TryFromParsed::InsufficientInformation;
@@ -142,8 +156,15 @@ fn debug() {
         error::ParseFromDescription::InvalidComponent("foo");
         error::Format::InvalidComponent("foo");
         well_known::Rfc2822;
+        well_known::JsDate;
         well_known::Rfc3339;
+        well_known::Rfc3339NoZ;
+        well_known::Rfc3339Lenient;
+        well_known::Rfc7231;
+        well_known::Systemd;
+        well_known::Unix;
         well_known::Iso8601::DEFAULT;
+        well_known::Iso8601Duration::DEFAULT;
         well_known::iso8601::FormattedComponents::None;
         well_known::iso8601::DateKind::Calendar;
         well_known::iso8601::TimePrecision::Hour { decimal_digits: None };
@@ -154,6 +175,7 @@ fn debug() {
         time::util::local_offset::Soundness::Sound;
 
         modifier::Day::default();
+        modifier::DayRepr::default();
         modifier::MonthRepr::default();
         modifier::Month::default();
         modifier::Ordinal::default();
@@ -172,6 +194,8 @@ fn debug() {
         modifier::OffsetHour::default();
         modifier::OffsetMinute::default();
         modifier::OffsetSecond::default();
+        modifier::OffsetSeparator::default();
+        modifier::Offset::default();
         modifier::Padding::default();
 
         FormatItem::Literal(b"abcdef");
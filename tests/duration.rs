@@ -1,9 +1,8 @@
-use core::u64;
 use std::cmp::Ordering;
 use std::time::Duration as StdDuration;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
-use time::{error, Duration};
+use time::{error, Duration, Unit};
 
 #[test]
 fn unit_values() {
@@ -44,6 +43,17 @@ fn is_positive() {
     assert!(1.seconds().is_positive());
 }
 
+#[test]
+fn signum() {
+    assert_eq!((-1).seconds().signum(), -1);
+    assert_eq!((-1).nanoseconds().signum(), -1);
+    assert_eq!(0.seconds().signum(), 0);
+    assert_eq!(1.nanoseconds().signum(), 1);
+    assert_eq!(1.seconds().signum(), 1);
+    assert_eq!(Duration::MIN.signum(), -1);
+    assert_eq!(Duration::MAX.signum(), 1);
+}
+
 #[test]
 fn abs() {
     assert_eq!(1.seconds().abs(), 1.seconds());
@@ -87,6 +97,61 @@ fn new() {
     assert_panic!(Duration::new(i64::MIN, -1_000_000_000));
 }
 
+#[test]
+fn try_new() {
+    let seconds = [i64::MIN, -1, 0, 1, i64::MAX];
+    let nanoseconds = [
+        i32::MIN,
+        -1_000_000_000,
+        -999_999_999,
+        -1,
+        0,
+        1,
+        999_999_999,
+        1_000_000_000,
+        i32::MAX,
+    ];
+
+    for secs in seconds {
+        for nanos in nanoseconds {
+            let in_range = nanos.unsigned_abs() < 1_000_000_000;
+            let signs_agree = secs == 0 || nanos == 0 || (secs < 0) == (nanos < 0);
+            match Duration::try_new(secs, nanos) {
+                Ok(duration) => {
+                    assert!(in_range && signs_agree, "{secs}, {nanos}");
+                    assert_eq!(duration, Duration::new(secs, nanos));
+                    assert_eq!(duration.whole_seconds(), secs);
+                    assert_eq!(duration.subsec_nanoseconds(), nanos);
+                }
+                Err(err) => {
+                    assert!(!(in_range && signs_agree), "{secs}, {nanos}");
+                    assert_eq!(err.name(), "nanoseconds");
+                    assert_eq!(err.is_conditional(), secs != 0);
+                }
+            }
+        }
+    }
+
+    // `Duration::new` carries excess nanoseconds into seconds, but `Duration::try_new` does not.
+    assert_eq!(Duration::new(1, 1_500_000_000), 2.5.seconds());
+    assert!(Duration::try_new(1, 1_500_000_000).is_err());
+    assert_eq!(Duration::new(1, -500_000_000), 0.5.seconds());
+    assert!(Duration::try_new(1, -500_000_000).is_err());
+
+    assert_eq!(
+        Duration::try_new(1, -500_000_000).unwrap_err().to_string(),
+        "nanoseconds must be in the range 0..=999999999 because seconds is positive"
+    );
+    assert_eq!(
+        Duration::try_new(-1, 500_000_000).unwrap_err().to_string(),
+        "nanoseconds must be in the range -999999999..=0 because seconds is negative"
+    );
+    assert_eq!(
+        Duration::try_new(0, 1_000_000_000).unwrap_err().to_string(),
+        "nanoseconds must be in the range -999999999..=999999999"
+    );
+}
+
 #[test]
 fn weeks() {
     assert_eq!(Duration::weeks(1), 604_800.seconds());
@@ -337,6 +402,129 @@ fn nanoseconds() {
     assert_eq!(Duration::nanoseconds(-1), (-1).microseconds() / 1_000);
 }
 
+#[test]
+fn from_hms() {
+    assert_eq!(
+        Duration::from_hms(2, 30, 15),
+        Duration::hours(2) + Duration::minutes(30) + Duration::seconds(15)
+    );
+    assert_eq!(
+        Duration::from_hms(-2, -30, -15),
+        Duration::hours(-2) + Duration::minutes(-30) + Duration::seconds(-15)
+    );
+    assert_eq!(Duration::from_hms(0, 90, 0), 1.5.hours());
+    assert_eq!(Duration::from_hms(1, -90, 0), (-30).minutes());
+    assert_eq!(Duration::from_hms(-1, 60, 1), 1.seconds());
+    assert_eq!(
+        Duration::from_hms(0, 0, i64::MAX),
+        Duration::MAX.whole_seconds().seconds()
+    );
+    // Each component overflows on its own, but the total does not.
+    assert_eq!(
+        Duration::from_hms(-(i64::MAX / 1_800), i64::MAX / 30, 1),
+        1.seconds()
+    );
+    assert_panic!(Duration::hours(-(i64::MAX / 1_800)) + Duration::minutes(i64::MAX / 30));
+
+    assert_panic!(Duration::from_hms(i64::MAX, 0, 0));
+    assert_panic!(Duration::from_hms(0, i64::MIN, 0));
+}
+
+#[test]
+fn checked_from_hms() {
+    assert_eq!(Duration::checked_from_hms(1, 2, 3), Some(3_723.seconds()));
+    assert_eq!(Duration::checked_from_hms(1, -2, 3), Some(3_483.seconds()));
+    assert_eq!(
+        Duration::checked_from_hms(i64::MAX / 3_600, 0, 0),
+        Some((i64::MAX / 3_600).hours())
+    );
+    assert_eq!(Duration::checked_from_hms(i64::MAX / 3_600 + 1, 0, 0), None);
+    assert_eq!(Duration::checked_from_hms(0, 1, i64::MAX), None);
+    assert_eq!(Duration::checked_from_hms(0, -1, i64::MIN), None);
+    assert_eq!(
+        Duration::checked_from_hms(0, 1, i64::MAX - 60),
+        Some(Duration::MAX.whole_seconds().seconds())
+    );
+}
+
+#[test]
+fn from_hms_milli() {
+    assert_eq!(
+        Duration::from_hms_milli(1, 2, 3, 4),
+        1.hours() + 2.minutes() + 3.seconds() + 4.milliseconds()
+    );
+    assert_eq!(
+        Duration::from_hms_milli(0, 0, 1, -1_500),
+        (-500).milliseconds()
+    );
+    assert_eq!(Duration::from_hms_milli(0, 0, 0, 1_500), 1.5.seconds());
+    assert_eq!(
+        Duration::from_hms_milli(0, 0, i64::MAX, 999),
+        Duration::MAX - 999_999.nanoseconds()
+    );
+
+    assert_panic!(Duration::from_hms_milli(0, 0, i64::MAX, 1_000));
+    assert_eq!(
+        Duration::checked_from_hms_milli(0, 0, i64::MAX, 1_000),
+        None
+    );
+    assert_eq!(
+        Duration::checked_from_hms_milli(0, 0, i64::MIN, 999),
+        Some(Duration::new(i64::MIN + 1, -1_000_000))
+    );
+}
+
+#[test]
+fn from_hms_nano() {
+    assert_eq!(
+        Duration::from_hms_nano(1, 2, 3, 4),
+        1.hours() + 2.minutes() + 3.seconds() + 4.nanoseconds()
+    );
+    assert_eq!(
+        Duration::from_hms_nano(0, 0, -1, 1),
+        (-999_999_999).nanoseconds()
+    );
+    assert_eq!(
+        Duration::from_hms_nano(0, 0, 1, -1),
+        999_999_999.nanoseconds()
+    );
+    assert_eq!(
+        Duration::from_hms_nano(0, 0, i64::MAX, 999_999_999),
+        Duration::MAX
+    );
+    assert_eq!(
+        Duration::from_hms_nano(0, 0, i64::MIN, -999_999_999),
+        Duration::MIN
+    );
+
+    assert_panic!(Duration::from_hms_nano(0, 0, i64::MAX, 1_000_000_000));
+    assert_eq!(
+        Duration::checked_from_hms_nano(0, 0, i64::MIN, -1_000_000_000),
+        None
+    );
+}
+
+#[test]
+fn from_dhms() {
+    assert_eq!(
+        Duration::from_dhms(1, 2, 3, 4),
+        Duration::days(1) + Duration::hours(2) + Duration::minutes(3) + Duration::seconds(4)
+    );
+    assert_eq!(Duration::from_dhms(1, -24, 0, 1), 1.seconds());
+    assert_eq!(Duration::from_dhms(-1, 0, 0, 1), (-86_399).seconds());
+
+    assert_panic!(Duration::from_dhms(i64::MAX, 0, 0, 0));
+    assert_eq!(Duration::checked_from_dhms(i64::MIN, 0, 0, 0), None);
+    assert_eq!(
+        Duration::checked_from_dhms(i64::MAX / 86_400, 0, 0, 0),
+        Some((i64::MAX / 86_400).days())
+    );
+    assert_eq!(
+        Duration::checked_from_dhms(i64::MAX / 86_400, 24, 0, 0),
+        None
+    );
+}
+
 #[test]
 fn whole_nanoseconds() {
     assert_eq!(1.microseconds().whole_nanoseconds(), 1_000);
@@ -351,6 +539,63 @@ fn subsec_nanoseconds() {
     assert_eq!((-1.000_000_4).seconds().subsec_nanoseconds(), -400);
 }
 
+#[test]
+fn components() {
+    fn values(duration: Duration) -> Vec<u64> {
+        duration.components().map(|(value, _)| value).collect()
+    }
+
+    assert_eq!(
+        Duration::ZERO.components().collect::<Vec<_>>(),
+        [
+            (0, Unit::Week),
+            (0, Unit::Day),
+            (0, Unit::Hour),
+            (0, Unit::Minute),
+            (0, Unit::Second),
+            (0, Unit::Millisecond),
+            (0, Unit::Microsecond),
+            (0, Unit::Nanosecond),
+        ]
+    );
+    assert_eq!(
+        values(123_456_789.nanoseconds()),
+        [0, 0, 0, 0, 0, 123, 456, 789]
+    );
+    assert_eq!(values(999.nanoseconds()), [0, 0, 0, 0, 0, 0, 0, 999]);
+    assert_eq!(
+        values(3.weeks() + 6.days() + 23.hours() + 59.minutes() + 59.seconds()),
+        [3, 6, 23, 59, 59, 0, 0, 0]
+    );
+    assert_eq!(
+        values(Duration::new(694_861, 1_001_001)),
+        [1, 1, 1, 1, 1, 1, 1, 1]
+    );
+
+    // Negative durations produce the same magnitudes.
+    assert_eq!(
+        values(-Duration::new(694_861, 1_001_001)),
+        [1, 1, 1, 1, 1, 1, 1, 1]
+    );
+    assert_eq!(values((-1.5).seconds()), [0, 0, 0, 0, 1, 500, 0, 0]);
+    assert_eq!(
+        values(Duration::MIN),
+        [15_250_284_452_471, 3, 15, 30, 8, 999, 999, 999]
+    );
+
+    assert_eq!(
+        (2.days() + 3.hours())
+            .components()
+            .filter(|&(value, _)| value != 0)
+            .collect::<Vec<_>>(),
+        [(2, Unit::Day), (3, Unit::Hour)]
+    );
+    assert_eq!(
+        1.seconds().components().rev().nth(3),
+        Some((1, Unit::Second))
+    );
+}
+
 #[test]
 fn checked_add() {
     assert_eq!(5.seconds().checked_add(5.seconds()), Some(10.seconds()));
@@ -455,6 +700,65 @@ fn saturating_mul() {
     );
 }
 
+#[test]
+fn const_arithmetic() {
+    const TABLE: [Duration; 5] = [
+        Duration::SECOND.const_mul(3),
+        Duration::SECOND.const_add(Duration::MILLISECOND),
+        Duration::SECOND.const_sub(Duration::MILLISECOND.const_mul(1_500)),
+        Duration::SECOND.const_neg(),
+        Duration::from_std_const(StdDuration::new(1, 5)),
+    ];
+    assert_eq!(
+        TABLE,
+        [
+            Duration::SECOND * 3,
+            Duration::SECOND + Duration::MILLISECOND,
+            Duration::SECOND - Duration::MILLISECOND * 1_500,
+            -Duration::SECOND,
+            Duration::new(1, 5),
+        ]
+    );
+
+    assert_eq!(Duration::MAX.const_neg(), -Duration::MAX);
+    assert_eq!((-1).nanoseconds().const_neg(), 1.nanoseconds());
+    assert_eq!(
+        Duration::from_std_const(StdDuration::new(i64::MAX as u64, 999_999_999)),
+        Duration::MAX
+    );
+    assert_eq!(Duration::from_std_const(StdDuration::ZERO), Duration::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "overflow when adding durations")]
+fn const_add_overflow() {
+    let _ = Duration::MAX.const_add(Duration::NANOSECOND);
+}
+
+#[test]
+#[should_panic(expected = "overflow when subtracting durations")]
+fn const_sub_overflow() {
+    let _ = Duration::MIN.const_sub(Duration::NANOSECOND);
+}
+
+#[test]
+#[should_panic(expected = "overflow when multiplying duration")]
+fn const_mul_overflow() {
+    let _ = Duration::MAX.const_mul(2);
+}
+
+#[test]
+#[should_panic(expected = "overflow when negating duration")]
+fn const_neg_overflow() {
+    let _ = Duration::MIN.const_neg();
+}
+
+#[test]
+#[should_panic(expected = "std duration is too large to be represented")]
+fn from_std_const_overflow() {
+    let _ = Duration::from_std_const(StdDuration::MAX);
+}
+
 #[test]
 fn time_fn() {
     let (time, value) = Duration::time_fn(|| {
@@ -530,6 +834,162 @@ fn display() {
     );
 }
 
+#[test]
+fn sign_near_zero() {
+    for duration in [
+        (-1).nanoseconds(),
+        (-999_999_999).nanoseconds(),
+        (-1).seconds(),
+        (-1).seconds() - 1.nanoseconds(),
+        (-1).seconds() + 1.nanoseconds(),
+        (-61).seconds() + 1.nanoseconds(),
+    ] {
+        let abs = duration.abs();
+        assert!(duration.is_negative() && !duration.is_positive() && !duration.is_zero());
+        assert!(abs.is_positive() && !abs.is_negative());
+        assert_eq!(duration.signum(), -1);
+        assert_eq!(abs.signum(), 1);
+        assert_eq!(-abs, duration);
+
+        assert_eq!(duration.to_string(), format!("-{abs}"));
+        assert_eq!(format!("{duration:.3}"), format!("-{abs:.3}"));
+        assert_eq!(format!("{duration:#}"), format!("-{abs:#}"));
+        assert_eq!(format!("{duration:#.0}"), format!("-{abs:#.0}"));
+        assert!(!abs.to_string().starts_with('-'));
+        assert!(!format!("{abs:.3}").starts_with('-'));
+        assert!(!format!("{abs:#}").starts_with('-'));
+    }
+
+    assert!(Duration::MIN.to_string().starts_with('-'));
+    assert!(format!("{:.3}", Duration::MIN).starts_with('-'));
+    assert!(format!("{:#}", Duration::MIN).starts_with("-PT"));
+    assert_eq!(format!("{:#}", (-1).nanoseconds()), "-PT0.000000001S");
+    assert_eq!((-1).nanoseconds().to_string(), "-1ns");
+}
+
+#[test]
+fn display_iso8601() {
+    assert_eq!(format!("{:#}", Duration::ZERO), "PT0S");
+    assert_eq!(format!("{:#}", 90.minutes()), "PT1H30M");
+    assert_eq!(format!("{:#}", 48.hours()), "PT48H");
+    assert_eq!(format!("{:#}", 1.minutes()), "PT1M");
+    assert_eq!(format!("{:#}", 59.seconds()), "PT59S");
+    assert_eq!(format!("{:#}", 3_723.seconds()), "PT1H2M3S");
+    assert_eq!(
+        format!("{:#}", 3_600.seconds() + 5.milliseconds()),
+        "PT1H0.005S"
+    );
+    assert_eq!(format!("{:#}", 1.5.seconds()), "PT1.5S");
+    assert_eq!(format!("{:#}", 1.nanoseconds()), "PT0.000000001S");
+    assert_eq!(
+        format!("{:#}", 93_784_005_006_007.nanoseconds()),
+        "PT26H3M4.005006007S"
+    );
+
+    assert_eq!(format!("{:#}", (-90).minutes()), "-PT1H30M");
+    assert_eq!(format!("{:#}", (-1.5).seconds()), "-PT1.5S");
+    assert_eq!(format!("{:#}", (-1).nanoseconds()), "-PT0.000000001S");
+    assert_eq!(
+        format!("{:#}", Duration::MIN),
+        "-PT2562047788015215H30M8.999999999S"
+    );
+
+    assert_eq!(format!("{:#.3}", Duration::ZERO), "PT0.000S");
+    assert_eq!(format!("{:#.3}", 1.123_456_789.seconds()), "PT1.123S");
+    assert_eq!(format!("{:#.3}", 1.999_999.seconds()), "PT1.999S");
+    assert_eq!(format!("{:#.3}", 1.5.seconds()), "PT1.500S");
+    assert_eq!(format!("{:#.0}", 1.5.seconds()), "PT1S");
+    assert_eq!(format!("{:#.12}", 1.5.seconds()), "PT1.500000000S");
+    assert_eq!(format!("{:#.3}", 90.minutes()), "PT1H30M");
+    assert_eq!(
+        format!("{:#.3}", 90.minutes() + 1.microseconds()),
+        "PT1H30M"
+    );
+    assert_eq!(format!("{:#.3}", (-1.5).seconds()), "-PT1.500S");
+}
+
+#[test]
+fn parse_human() -> Result<(), error::Parse> {
+    assert_eq!(Duration::parse_human("0s")?, Duration::ZERO);
+    assert_eq!(Duration::parse_human("-0s")?, Duration::ZERO);
+    assert_eq!(Duration::parse_human("60d")?, 60.days());
+    assert_eq!(Duration::parse_human("-2d")?, (-48).hours());
+    assert_eq!(Duration::parse_human("2w")?, 14.days());
+    assert_eq!(Duration::parse_human("1w1d")?, 8.days());
+    assert_eq!(Duration::parse_human("10m")?, 10.minutes());
+    assert_eq!(Duration::parse_human("10ms")?, 10.milliseconds());
+    assert_eq!(Duration::parse_human("10µs")?, 10.microseconds());
+    assert_eq!(Duration::parse_human("10ns")?, 10.nanoseconds());
+    assert_eq!(Duration::parse_human("90s")?, 90.seconds());
+    assert_eq!(
+        Duration::parse_human("1d2h3m4s5ms6µs7ns")?,
+        93_784_005_006_007.nanoseconds()
+    );
+    assert_eq!(
+        Duration::parse_human("-1d2h3m4s5ms6µs7ns")?,
+        (-93_784_005_006_007).nanoseconds()
+    );
+    assert_eq!(
+        Duration::parse_human(&Duration::MAX.to_string())?,
+        Duration::MAX
+    );
+    assert_eq!(
+        Duration::parse_human(&Duration::MIN.to_string())?,
+        Duration::MIN
+    );
+    Ok(())
+}
+
+#[test]
+fn parse_human_err() {
+    use error::ParseFromDescription::InvalidComponent;
+
+    assert!(matches!(
+        Duration::parse_human(""),
+        Err(error::Parse::ParseFromDescription(InvalidComponent("value")))
+    ));
+    assert!(matches!(
+        Duration::parse_human("-"),
+        Err(error::Parse::ParseFromDescription(InvalidComponent("value")))
+    ));
+    assert!(matches!(
+        Duration::parse_human("s"),
+        Err(error::Parse::ParseFromDescription(InvalidComponent("value")))
+    ));
+    assert!(matches!(
+        Duration::parse_human("1"),
+        Err(error::Parse::ParseFromDescription(InvalidComponent("unit")))
+    ));
+    assert!(matches!(
+        Duration::parse_human("1y"),
+        Err(error::Parse::ParseFromDescription(InvalidComponent("unit")))
+    ));
+    assert!(matches!(
+        Duration::parse_human("1s2m"),
+        Err(error::Parse::ParseFromDescription(InvalidComponent("unit")))
+    ));
+    assert!(matches!(
+        Duration::parse_human("1s2s"),
+        Err(error::Parse::ParseFromDescription(InvalidComponent("unit")))
+    ));
+    assert!(matches!(
+        Duration::parse_human("1s "),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        Duration::parse_human("1s-2ms"),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        Duration::parse_human("15250284452472w"),
+        Err(error::Parse::ParseFromDescription(InvalidComponent("value")))
+    ));
+    assert!(matches!(
+        Duration::parse_human("9223372036854775808s"),
+        Err(error::Parse::ParseFromDescription(InvalidComponent("value")))
+    ));
+}
+
 #[test]
 fn try_from_std_duration() {
     assert_eq!(Duration::try_from(0.std_seconds()), Ok(0.seconds()));
@@ -689,6 +1149,30 @@ fn std_sub_assign_overflow() {
     duration -= 2.seconds();
 }
 
+#[test]
+fn arithmetic_overflow_panic() {
+    let (max, min) = (Duration::MAX, Duration::MIN);
+    let (nanosecond, std_second) = (1.nanoseconds(), 1.std_seconds());
+
+    assert_panic_with_operands!(max + nanosecond, max, nanosecond);
+    assert_panic_with_operands!(min - nanosecond, min, nanosecond);
+    assert_panic_with_operands!(max + std_second, max, std_second);
+    assert_panic_with_operands!(min - std_second, min, std_second);
+    assert_panic_with_operands!(std_second + max, std_second, max);
+
+    let mut duration = max;
+    assert_panic_with_operands!(duration += nanosecond, max, nanosecond);
+    let mut std_duration = std_second;
+    assert_panic_with_operands!(std_duration -= 2.seconds(), std_second, 2.seconds());
+
+    assert_panic_with_operands!(max * 2, max, 2);
+    assert_panic_with_operands!(2_u32 * min, min, 2_u32);
+    let mut duration = max;
+    assert_panic_with_operands!(duration *= -2_i8, max, -2_i8);
+    assert_panic_with_operands!(-min, min);
+    assert_panic_with_operands!(-&min, min);
+}
+
 #[test]
 fn mul_int() {
     assert_eq!(1.seconds() * 2, 2.seconds());
@@ -921,6 +1405,33 @@ fn ord() {
     );
 }
 
+#[test]
+fn min_max_clamp() {
+    let (low, middle, high) = ((-1).seconds(), 1.seconds(), 1.minutes());
+    assert_eq!(low.min(high), low);
+    assert_eq!(high.min(low), low);
+    assert_eq!(low.max(high), high);
+    assert_eq!(high.max(low), high);
+    assert_eq!(low.min(low), low);
+    assert_eq!(low.clamp(middle, high), middle);
+    assert_eq!(middle.clamp(low, high), middle);
+    assert_eq!(high.clamp(low, middle), middle);
+    assert_eq!(
+        (-1).nanoseconds().max((-2).nanoseconds()),
+        (-1).nanoseconds()
+    );
+    assert_eq!(Duration::MIN.min(Duration::MAX), Duration::MIN);
+
+    const CLAMPED: Duration = Duration::MAX.clamp(Duration::ZERO, Duration::SECOND);
+    assert_eq!(CLAMPED, Duration::SECOND);
+}
+
+#[test]
+#[should_panic]
+fn clamp_panic() {
+    let _ = Duration::ZERO.clamp(Duration::SECOND, Duration::MILLISECOND);
+}
+
 #[test]
 fn arithmetic_regression() {
     let added = 1.6.seconds() + 1.6.seconds();
@@ -934,7 +1445,7 @@ fn arithmetic_regression() {
 
 #[test]
 fn sum_iter_ref() {
-    let i = vec![1.6.seconds(), 1.6.seconds()];
+    let i = [1.6.seconds(), 1.6.seconds()];
     let sum = i.iter().sum::<Duration>();
     assert_eq!(sum, 3.2.seconds());
 }
@@ -945,3 +1456,70 @@ fn sum_iter() {
     let sum = i.into_iter().sum::<Duration>();
     assert_eq!(sum, 3.2.seconds());
 }
+
+#[test]
+#[allow(clippy::op_ref)]
+fn reference_ops() {
+    let (a, b) = (5.seconds(), 1.5.seconds());
+    assert_ref_binop!(a, +, b);
+    assert_ref_binop!(a, +, 1.std_seconds());
+    assert_ref_binop!(1.std_seconds(), +, a);
+    assert_ref_binop!(a, -, b);
+    assert_ref_binop!(a, -, 1.std_seconds());
+    assert_ref_binop!(10.std_seconds(), -, a);
+    assert_ref_binop!(a, *, 2_i8);
+    assert_ref_binop!(a, *, 2_i16);
+    assert_ref_binop!(a, *, 2_i32);
+    assert_ref_binop!(a, *, 2_u8);
+    assert_ref_binop!(a, *, 2_u16);
+    assert_ref_binop!(a, *, 2_u32);
+    assert_ref_binop!(a, *, 1.5_f32);
+    assert_ref_binop!(a, *, 1.5_f64);
+    assert_ref_binop!(2_i8, *, a);
+    assert_ref_binop!(2_i16, *, a);
+    assert_ref_binop!(2_i32, *, a);
+    assert_ref_binop!(2_u8, *, a);
+    assert_ref_binop!(2_u16, *, a);
+    assert_ref_binop!(2_u32, *, a);
+    assert_ref_binop!(1.5_f32, *, a);
+    assert_ref_binop!(1.5_f64, *, a);
+    assert_ref_binop!(a, /, 2_i8);
+    assert_ref_binop!(a, /, 2_i16);
+    assert_ref_binop!(a, /, 2_i32);
+    assert_ref_binop!(a, /, 2_u8);
+    assert_ref_binop!(a, /, 2_u16);
+    assert_ref_binop!(a, /, 2_u32);
+    assert_ref_binop!(a, /, 1.5_f32);
+    assert_ref_binop!(a, /, 1.5_f64);
+    assert_ref_binop!(a, /, b);
+    assert_ref_binop!(a, /, 2.std_seconds());
+    assert_ref_binop!(2.std_seconds(), /, a);
+    assert_eq!(-&a, -a);
+
+    assert_ref_op_assign!(a, +=, b);
+    assert_ref_op_assign!(a, +=, 1.std_seconds());
+    assert_ref_op_assign!(10.std_seconds(), +=, a);
+    assert_ref_op_assign!(a, -=, b);
+    assert_ref_op_assign!(a, -=, 1.std_seconds());
+    assert_ref_op_assign!(10.std_seconds(), -=, a);
+    assert_ref_op_assign!(a, *=, 2_i8);
+    assert_ref_op_assign!(a, *=, 2_i16);
+    assert_ref_op_assign!(a, *=, 2_i32);
+    assert_ref_op_assign!(a, *=, 2_u8);
+    assert_ref_op_assign!(a, *=, 2_u16);
+    assert_ref_op_assign!(a, *=, 2_u32);
+    assert_ref_op_assign!(a, *=, 1.5_f32);
+    assert_ref_op_assign!(a, *=, 1.5_f64);
+    assert_ref_op_assign!(a, /=, 2_i8);
+    assert_ref_op_assign!(a, /=, 2_i16);
+    assert_ref_op_assign!(a, /=, 2_i32);
+    assert_ref_op_assign!(a, /=, 2_u8);
+    assert_ref_op_assign!(a, /=, 2_u16);
+    assert_ref_op_assign!(a, /=, 2_u32);
+    assert_ref_op_assign!(a, /=, 1.5_f32);
+    assert_ref_op_assign!(a, /=, 1.5_f64);
+
+    let durations = [a, b];
+    assert_eq!(durations.iter().sum::<Duration>(), 6.5.seconds());
+    assert_panic_with_operands!(&Duration::MAX + &a, Duration::MAX, a);
+}
@@ -1,5 +1,5 @@
 use std::error::Error as _;
-use std::io;
+use std::{fmt, io};
 
 use time::error::{
     ComponentRange, ConversionRange, DifferentVariant, Error, Format, IndeterminateOffset,
@@ -36,7 +36,7 @@ fn component_range() -> ComponentRange {
 
 fn insufficient_type_information() -> Format {
     Time::MIDNIGHT
-        .format(&time::format_description::well_known::Rfc3339)
+        .format(&format_description::well_known::Rfc3339)
         .unwrap_err()
 }
 
@@ -74,6 +74,14 @@ fn display() {
         TryFromParsed::InsufficientInformation,
         Error::from(TryFromParsed::InsufficientInformation)
     );
+    assert_eq!(
+        TryFromParsed::ConflictingComponents {
+            a: "day",
+            b: "weekday"
+        }
+        .to_string(),
+        "the `day` and `weekday` components conflict with one another"
+    );
     assert_display_eq!(
         insufficient_type_information(),
         Error::from(insufficient_type_information())
@@ -82,6 +90,18 @@ fn display() {
         Format::InvalidComponent("a"),
         Error::from(Format::InvalidComponent("a"))
     );
+    assert_display_eq!(
+        Format::InvalidFormatDescription,
+        Error::from(Format::InvalidFormatDescription)
+    );
+    assert_eq!(
+        Format::InsufficientCapacity {
+            capacity: 16,
+            required: 20
+        }
+        .to_string(),
+        "The output requires 20 bytes, but only 16 are available."
+    );
     assert_display_eq!(
         ParseFromDescription::InvalidComponent("a"),
         Error::from(Parse::from(ParseFromDescription::InvalidComponent("a")))
@@ -108,6 +128,7 @@ fn display() {
         Error::from(invalid_format_description())
     );
     assert_display_eq!(io_error(), Format::from(io_error()));
+    assert_display_eq!(fmt::Error, Format::Fmt(fmt::Error));
     assert_display_eq!(DifferentVariant, Error::from(DifferentVariant));
     assert_display_eq!(InvalidVariant, Error::from(InvalidVariant));
 }
@@ -118,8 +139,24 @@ fn source() {
     assert_source!(Error::from(component_range()), ComponentRange);
     assert_source!(TryFromParsed::from(component_range()), ComponentRange);
     assert_source!(TryFromParsed::InsufficientInformation, None);
+    assert_source!(
+        TryFromParsed::ConflictingComponents {
+            a: "day",
+            b: "weekday"
+        },
+        None
+    );
     assert_source!(insufficient_type_information(), None);
     assert_source!(Format::InvalidComponent("a"), None);
+    assert_source!(Format::InvalidFormatDescription, None);
+    assert_source!(
+        Format::InsufficientCapacity {
+            capacity: 1,
+            required: 2
+        },
+        None
+    );
+    assert_source!(Format::Fmt(fmt::Error), fmt::Error);
     assert_source!(Error::from(insufficient_type_information()), Format);
     assert_source!(Error::from(IndeterminateOffset), IndeterminateOffset);
     assert_source!(
@@ -171,7 +208,7 @@ fn conversion() {
     assert!(ComponentRange::try_from(TryFromParsed::ComponentRange(component_range())).is_ok());
     assert!(TryFromParsed::try_from(Error::from(TryFromParsed::InsufficientInformation)).is_ok());
     assert!(TryFromParsed::try_from(Parse::from(TryFromParsed::InsufficientInformation)).is_ok());
-    assert!(std::io::Error::try_from(Format::from(io_error())).is_ok());
+    assert!(io::Error::try_from(Format::from(io_error())).is_ok());
 
     assert!(ComponentRange::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(ConversionRange::try_from(Error::from(IndeterminateOffset)).is_err());
@@ -186,5 +223,5 @@ fn conversion() {
     assert!(ComponentRange::try_from(TryFromParsed::InsufficientInformation).is_err());
     assert!(TryFromParsed::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(TryFromParsed::try_from(unexpected_trailing_characters()).is_err());
-    assert!(std::io::Error::try_from(insufficient_type_information()).is_err());
+    assert!(io::Error::try_from(insufficient_type_information()).is_err());
 }
@@ -110,3 +110,115 @@ mod numerical_std_duration {
         );
     }
 }
+
+mod retry_after {
+    use time::error;
+    use time::ext::{parse_retry_after, NumericalDuration, RetryAfter};
+    use time::macros::datetime;
+    use time::PrimitiveDateTime;
+
+    #[test]
+    fn parse() -> time::Result<()> {
+        // The examples from section 7.1.3 of RFC 7231.
+        assert_eq!(
+            RetryAfter::parse("Fri, 31 Dec 1999 23:59:59 GMT")?,
+            RetryAfter::Date(datetime!(1999-12-31 23:59:59 UTC))
+        );
+        assert_eq!(RetryAfter::parse("120")?, RetryAfter::Delay(2.minutes()));
+
+        assert_eq!(RetryAfter::parse("0")?, RetryAfter::Delay(0.seconds()));
+        assert_eq!(RetryAfter::parse("007")?, RetryAfter::Delay(7.seconds()));
+        assert_eq!(
+            RetryAfter::parse(" \t120\t ")?,
+            RetryAfter::Delay(2.minutes())
+        );
+        assert_eq!(
+            RetryAfter::parse("  Friday, 31-Dec-99 23:59:59 GMT ")?,
+            RetryAfter::Date(datetime!(1999-12-31 23:59:59 UTC))
+        );
+        assert_eq!(
+            RetryAfter::parse("Fri Dec 31 23:59:59 1999")?,
+            RetryAfter::Date(datetime!(1999-12-31 23:59:59 UTC))
+        );
+        assert_eq!(
+            RetryAfter::parse("9223372036854775807")?,
+            RetryAfter::Delay(i64::MAX.seconds())
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn parse_err() {
+        for value in [
+            "-120",
+            "+120",
+            "1.5",
+            "120 seconds",
+            "1 20",
+            "9223372036854775808",
+        ] {
+            assert!(matches!(
+                RetryAfter::parse(value),
+                Err(error::Parse::ParseFromDescription(
+                    error::ParseFromDescription::InvalidComponent("delta seconds")
+                ))
+            ));
+        }
+        assert!(matches!(
+            RetryAfter::parse(""),
+            Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidComponent("weekday")
+            ))
+        ));
+        assert!(matches!(
+            RetryAfter::parse("Fri, 31 Dec 1999 23:59:59 +0000"),
+            Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidLiteral { .. }
+            ))
+        ));
+        assert!(matches!(
+            RetryAfter::parse("Fri, 31 Dec 1999 23:59:59 GMT, 120"),
+            Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        ));
+    }
+
+    #[test]
+    fn resolve() -> time::Result<()> {
+        let now = datetime!(1999-12-31 23:57:59 UTC);
+        assert_eq!(
+            parse_retry_after("120", now)?,
+            datetime!(1999-12-31 23:59:59 UTC)
+        );
+        assert_eq!(parse_retry_after(" 0 ", now)?, now);
+        assert_eq!(
+            parse_retry_after("Fri, 31 Dec 1999 23:59:59 GMT", now)?,
+            datetime!(1999-12-31 23:59:59 UTC)
+        );
+        // A date is returned as-is, even if it is in the past.
+        assert_eq!(
+            parse_retry_after("Sun, 06 Nov 1994 08:49:37 GMT", now)?,
+            datetime!(1994-11-06 08:49:37 UTC)
+        );
+        // The offset of `now` is kept when adding a delay.
+        assert_eq!(
+            parse_retry_after("60", datetime!(2000-01-01 00:00 +01:00))?,
+            datetime!(2000-01-01 00:01 +01:00)
+        );
+
+        assert!(matches!(
+            parse_retry_after("-1", now),
+            Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidComponent("delta seconds")
+            ))
+        ));
+        assert!(matches!(
+            parse_retry_after("1", PrimitiveDateTime::MAX.assume_utc()),
+            Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidComponent("delta seconds")
+            ))
+        ));
+
+        Ok(())
+    }
+}
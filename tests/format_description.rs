@@ -1,4 +1,7 @@
-use time::format_description::{modifier, Component, FormatItem, OwnedFormatItem};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+use time::format_description::{self, modifier, Component, FormatItem, OwnedFormatItem};
 
 #[test]
 fn borrowed_format_item_component_conversions() {
@@ -66,3 +69,114 @@ fn owned_format_item_equality() {
         compound
     );
 }
+
+fn hash_of(item: &OwnedFormatItem) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    item.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn owned_format_item_normalized_equality() {
+    let merged = OwnedFormatItem::Literal(Box::new(*b"ab"));
+    let split = OwnedFormatItem::Compound(Box::new([
+        OwnedFormatItem::Literal(Box::new(*b"a")),
+        OwnedFormatItem::Literal(Box::new([])),
+        OwnedFormatItem::Compound(Box::new([OwnedFormatItem::Literal(Box::new(*b"b"))])),
+    ]));
+    assert_eq!(merged, split);
+    assert_eq!(hash_of(&merged), hash_of(&split));
+
+    let component = OwnedFormatItem::from(Component::Day(modifier::Day::default()));
+    let wrapped = OwnedFormatItem::Compound(Box::new([component.clone()]));
+    assert_eq!(component, wrapped);
+    assert_eq!(hash_of(&component), hash_of(&wrapped));
+    assert_eq!(
+        OwnedFormatItem::Optional(Box::new(merged.clone())),
+        OwnedFormatItem::Optional(Box::new(split.clone()))
+    );
+    assert_eq!(
+        OwnedFormatItem::First(Box::new([merged.clone(), component.clone()])),
+        OwnedFormatItem::First(Box::new([split, wrapped]))
+    );
+
+    assert_ne!(merged, component);
+    assert_ne!(OwnedFormatItem::Optional(Box::new(merged.clone())), merged);
+    assert_ne!(
+        OwnedFormatItem::First(Box::new([merged.clone(), component.clone()])),
+        OwnedFormatItem::First(Box::new([component, merged]))
+    );
+}
+
+#[test]
+fn display() {
+    assert_eq!(
+        Component::Year(modifier::Year::default()).to_string(),
+        "[year]"
+    );
+    assert_eq!(
+        Component::OffsetHour(modifier::OffsetHour::default()).to_string(),
+        "[offset_hour sign:mandatory]"
+    );
+    assert_eq!(
+        Component::Offset(modifier::Offset::default()).to_string(),
+        "[offset sign:mandatory]"
+    );
+    let mut month = modifier::Month::default();
+    month.repr = modifier::MonthRepr::CustomNames(&[""; 12]);
+    assert_eq!(Component::Month(month).to_string(), "[month repr:custom]");
+    assert_eq!(
+        FormatItem::Compound(&[
+            FormatItem::Literal(b"a[b]\\"),
+            FormatItem::Optional(&FormatItem::Literal(b"c")),
+            FormatItem::First(&[]),
+            FormatItem::First(&[FormatItem::Literal(b"d"), FormatItem::Literal(b"e")]),
+        ])
+        .to_string(),
+        r"a\[b\]\\[optional [c]][first ][first [d] [e]]"
+    );
+    assert_eq!(FormatItem::Literal(b"a\xFFb").to_string(), "a\u{fffd}b");
+}
+
+#[test]
+fn display_round_trip() -> Result<(), time::error::InvalidFormatDescription> {
+    for description in [
+        "",
+        "[year]-[month]-[day]",
+        "[day repr:ordinal padding:none]",
+        "[year padding:space repr:last_two base:iso_week sign:mandatory]",
+        "[month padding:none repr:short case_sensitive:false] [month repr:long]",
+        "[ordinal padding:space] [week_number padding:none repr:sunday] [week_number repr:monday]",
+        "[weekday repr:short one_indexed:false case_sensitive:false] [weekday repr:monday]",
+        "[hour repr:12 padding:none]:[minute padding:space]:[second] [period case:lower]",
+        "[period case_sensitive:false] [subsecond digits:3] [subsecond]",
+        "[offset_hour sign:mandatory padding:space z_when_utc:true]",
+        "[offset_minute padding:none omit_when_zero:true][offset_second padding:space]",
+        "[offset sign:mandatory z_when_utc:true separator:none] [offset]",
+        "[ignore count:5][unix_timestamp precision:nanosecond sign:mandatory]",
+        "[unix_timestamp precision:millisecond decimal:true]",
+        r"literal \[with\] \\escapes",
+        "[year][optional [-[month][optional [-[day]]]]]",
+        "[first [[hour]:[minute]] [[unix_timestamp]] []]",
+        "[first ]",
+        "[optional [[first [a] [[optional [b]]]]]]",
+        "[optional [ leading and trailing whitespace ]]",
+    ] {
+        let parsed = format_description::parse_owned::<2>(description)?;
+        let displayed = parsed.to_string();
+        assert_eq!(
+            format_description::parse_owned::<2>(&displayed)?,
+            parsed,
+            "{description:?} was displayed as {displayed:?}"
+        );
+    }
+
+    let borrowed = format_description::parse("[year]-[month repr:short] [[literal")?;
+    let displayed = OwnedFormatItem::from(&borrowed).to_string();
+    assert_eq!(displayed, r"[year]-[month repr:short] \[literal");
+    assert_eq!(
+        format_description::parse_owned::<2>(&displayed)?,
+        OwnedFormatItem::from(&borrowed)
+    );
+    Ok(())
+}
@@ -1,10 +1,17 @@
+use std::fmt::{self, Write as _};
 use std::io;
+use std::num::NonZeroU8;
 
+use time::ext::NumericalDuration;
 use time::format_description::well_known::iso8601::{DateKind, OffsetPrecision, TimePrecision};
-use time::format_description::well_known::{iso8601, Iso8601, Rfc2822, Rfc3339};
-use time::format_description::{self, FormatItem, OwnedFormatItem};
+use time::format_description::well_known::{
+    iso8601, Iso8601, Iso8601Duration, Iso8601Dynamic, JsDate, Rfc2822, Rfc3339, Rfc3339Lenient,
+    Rfc3339NoZ, Rfc7231, Systemd, Unix,
+};
+use time::format_description::{self, CompiledFormat, FormatItem, OwnedFormatItem};
+use time::formatting::Formattable;
 use time::macros::{date, datetime, format_description as fd, offset, time};
-use time::{OffsetDateTime, Time};
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time};
 
 #[test]
 fn rfc_2822() -> time::Result<()> {
@@ -21,10 +28,31 @@ fn rfc_2822() -> time::Result<()> {
         "Sat, 02 Jan 2021 03:04:05 -0607"
     );
 
+    assert_eq!(
+        datetime!(1900-01-01 00:00:00 UTC).format(&Rfc2822)?,
+        "Mon, 01 Jan 1900 00:00:00 +0000"
+    );
+    assert_eq!(
+        datetime!(9999-12-31 23:59:59 UTC).format(&Rfc2822)?,
+        "Fri, 31 Dec 9999 23:59:59 +0000"
+    );
+
     assert!(matches!(
         datetime!(1885-01-01 01:01:01 UTC).format(&Rfc2822),
         Err(time::error::Format::InvalidComponent("year"))
     ));
+    assert!(matches!(
+        datetime!(+10_000-01-01 00:00:00 UTC).format(&Rfc2822),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(+123_456-01-01 00:00:00 UTC).format(&Rfc2822),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(-0001-01-01 00:00:00 UTC).format(&Rfc2822),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
     assert!(matches!(
         datetime!(2000-01-01 00:00:00 +00:00:01).format(&Rfc2822),
         Err(time::error::Format::InvalidComponent("offset_second"))
@@ -33,6 +61,189 @@ fn rfc_2822() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn js_date() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2024-05-01 13:45:30 +02:00).format(&JsDate)?,
+        "Wed May 01 2024 13:45:30 GMT+0200"
+    );
+    assert_eq!(
+        OffsetDateTime::UNIX_EPOCH
+            .to_offset(offset!(-8))
+            .format(&JsDate)?,
+        "Wed Dec 31 1969 16:00:00 GMT-0800"
+    );
+    assert_eq!(
+        datetime!(2024-03-10 12:00:00 -02:30).format(&JsDate)?,
+        "Sun Mar 10 2024 12:00:00 GMT-0230"
+    );
+    assert_eq!(
+        datetime!(2024-05-01 13:45:30 -00:30).format(&JsDate)?,
+        "Wed May 01 2024 13:45:30 GMT-0030"
+    );
+    assert_eq!(
+        datetime!(0001-01-01 00:00:00.5 UTC).format(&JsDate)?,
+        "Mon Jan 01 0001 00:00:00 GMT+0000"
+    );
+
+    assert!(matches!(
+        datetime!(-0001-01-01 00:00:00 UTC).format(&JsDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2000-01-01 00:00:00 +00:00:01).format(&JsDate),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+
+    for datetime in [
+        datetime!(2024-05-01 13:45:30 +02:00),
+        datetime!(2024-03-10 12:00:00 -02:30),
+        datetime!(2024-05-01 13:45:30 -00:30),
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse(&datetime.format(&JsDate)?, &JsDate)?,
+            datetime
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn systemd() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2024-05-01 13:45:30.123_456 UTC).format(&Systemd)?,
+        "2024-05-01 13:45:30.123456 UTC"
+    );
+    assert_eq!(
+        datetime!(2024-05-01 13:45:30 UTC).format(&Systemd)?,
+        "2024-05-01 13:45:30.000000 UTC"
+    );
+    assert_eq!(
+        datetime!(2024-05-01 15:45:30.000_001_999 +02:00).format(&Systemd)?,
+        "2024-05-01 15:45:30.000001 +0200"
+    );
+    assert_eq!(
+        datetime!(2024-05-01 06:15:30.5 -07:30).format(&Systemd)?,
+        "2024-05-01 06:15:30.500000 -0730"
+    );
+    assert_eq!(
+        datetime!(2024-05-01 13:15:30 -00:30).format(&Systemd)?,
+        "2024-05-01 13:15:30.000000 -0030"
+    );
+
+    assert!(matches!(
+        datetime!(-0001-01-01 00:00:00 UTC).format(&Systemd),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2000-01-01 00:00:00 +00:00:01).format(&Systemd),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+
+    for datetime in [
+        datetime!(2024-05-01 13:45:30.123_456 UTC),
+        datetime!(2024-05-01 15:45:30 +02:00),
+        datetime!(0000-01-01 00:00:00.000_001 -00:30),
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse(&datetime.format(&Systemd)?, &Systemd)?,
+            datetime
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn rfc_7231() -> time::Result<()> {
+    assert_eq!(
+        datetime!(1994-11-06 08:49:37 UTC).format(&Rfc7231)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(2024-05-01 13:45:30.999 UTC).format(&Rfc7231)?,
+        "Wed, 01 May 2024 13:45:30 GMT"
+    );
+    assert_eq!(
+        datetime!(0001-01-01 00:00:00 UTC).format(&Rfc7231)?,
+        "Mon, 01 Jan 0001 00:00:00 GMT"
+    );
+
+    assert!(matches!(
+        datetime!(-0001-01-01 00:00:00 UTC).format(&Rfc7231),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(1994-11-06 09:49:37 +01:00).format(&Rfc7231),
+        Err(time::error::Format::InvalidComponent("offset"))
+    ));
+    assert!(matches!(
+        datetime!(1994-11-06 08:49:37 +00:00:01).format(&Rfc7231),
+        Err(time::error::Format::InvalidComponent("offset"))
+    ));
+
+    for datetime in [
+        datetime!(1994-11-06 08:49:37 UTC),
+        datetime!(2024-02-29 23:59:59 UTC),
+        datetime!(9999-12-31 00:00:00 UTC),
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse(&datetime.format(&Rfc7231)?, &Rfc7231)?,
+            datetime
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn unix() -> time::Result<()> {
+    assert_eq!(OffsetDateTime::UNIX_EPOCH.format(&Unix)?, "0");
+    assert_eq!(
+        datetime!(2024-05-01 12:51:30 UTC).format(&Unix)?,
+        "1714567890"
+    );
+    assert_eq!(
+        datetime!(2024-05-01 14:51:30.25 +02:00).format(&Unix)?,
+        "1714567890.25"
+    );
+    assert_eq!(
+        datetime!(2024-05-01 12:51:30.000_000_001 UTC).format(&Unix)?,
+        "1714567890.000000001"
+    );
+    assert_eq!(
+        datetime!(2024-05-01 12:51:30.123_456_789 UTC).format(&Unix)?,
+        "1714567890.123456789"
+    );
+    assert_eq!(datetime!(1969-12-31 23:59:59 UTC).format(&Unix)?, "-1");
+    assert_eq!(
+        datetime!(1969-12-31 23:59:59.75 UTC).format(&Unix)?,
+        "-0.25"
+    );
+    assert_eq!(
+        datetime!(-9999-01-01 0:00 UTC).format(&Unix)?,
+        "-377705116800"
+    );
+    assert_eq!(
+        datetime!(+999_999-12-31 23:59:59.999_999_999 UTC).format(&Unix)?,
+        "31494784780799.999999999"
+    );
+
+    for datetime in [
+        datetime!(2024-05-01 13:45:30.5 +02:00),
+        datetime!(1969-12-31 23:59:59.999 UTC),
+        datetime!(-0001-01-01 0:00:00.000_001 UTC),
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse(&datetime.format(&Unix)?, &Unix)?,
+            datetime
+        );
+    }
+
+    Ok(())
+}
+
 #[test]
 fn rfc_3339() -> time::Result<()> {
     assert_eq!(
@@ -84,10 +295,27 @@ fn rfc_3339() -> time::Result<()> {
         "2021-01-02T03:04:05.123456789+01:02"
     );
 
+    assert_eq!(
+        datetime!(0000-01-01 0:00 UTC).format(&Rfc3339)?,
+        "0000-01-01T00:00:00Z"
+    );
+    assert_eq!(
+        datetime!(9999-12-31 23:59:59 UTC).format(&Rfc3339)?,
+        "9999-12-31T23:59:59Z"
+    );
+
     assert!(matches!(
         datetime!(-0001-01-01 0:00 UTC).format(&Rfc3339),
         Err(time::error::Format::InvalidComponent("year"))
     ));
+    assert!(matches!(
+        datetime!(+10_000-01-01 0:00 UTC).format(&Rfc3339),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(+123_456-01-01 0:00 UTC).format(&Rfc3339),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
     assert!(matches!(
         datetime!(0000-01-01 0:00 +00:00:01).format(&Rfc3339),
         Err(time::error::Format::InvalidComponent("offset_second"))
@@ -96,6 +324,51 @@ fn rfc_3339() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn rfc_3339_no_z() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&Rfc3339NoZ)?,
+        "2021-01-02T03:04:05+00:00"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.123_456_789 UTC).format(&Rfc3339NoZ)?,
+        "2021-01-02T03:04:05.123456789+00:00"
+    );
+
+    for datetime in [
+        datetime!(2021-01-02 03:04:05.1 -01:02),
+        datetime!(2021-01-02 03:04:05.123_456_789 +01:02),
+        datetime!(0000-01-01 0:00 +23:59),
+        datetime!(9999-12-31 23:59:59 -00:01),
+    ] {
+        assert_eq!(datetime.format(&Rfc3339NoZ)?, datetime.format(&Rfc3339)?);
+    }
+
+    assert!(matches!(
+        datetime!(+10_000-01-01 0:00 UTC).format(&Rfc3339NoZ),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(0000-01-01 0:00 +00:00:01).format(&Rfc3339NoZ),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn rfc_3339_lenient() -> time::Result<()> {
+    for datetime in [
+        datetime!(2021-01-02 03:04:05 UTC),
+        datetime!(2021-01-02 03:04:05.1 -01:02),
+        datetime!(2021-01-02 03:04:05.123_456_789 +01:02),
+    ] {
+        assert_eq!(datetime.format(&Rfc3339Lenient)?, datetime.format(&Rfc3339)?);
+    }
+
+    Ok(())
+}
+
 #[test]
 fn iso_8601() -> time::Result<()> {
     macro_rules! assert_format_config {
@@ -109,12 +382,10 @@ fn iso_8601() -> time::Result<()> {
         };
     }
 
-    assert!(
-        std::panic::catch_unwind(|| {
-            let _ = datetime!(2021-01-02 03:04:05 UTC).format(&Iso8601::PARSING);
-        })
-        .is_err()
-    );
+    assert!(matches!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&Iso8601::PARSING),
+        Err(time::error::Format::InvalidFormatDescription)
+    ));
     assert_eq!(
         datetime!(-123_456-01-02 03:04:05 UTC).format(
             &Iso8601::<
@@ -169,6 +440,31 @@ fn iso_8601() -> time::Result<()> {
         )?,
         "2021-01-02T03:04:05.000000000+01"
     );
+    assert_eq!(
+        datetime!(1900-01-01 0:00 +0:19:32).format(
+            &Iso8601::<
+                {
+                    iso8601::Config::DEFAULT
+                        .set_offset_precision(OffsetPrecision::Second)
+                        .encode()
+                },
+            >
+        )?,
+        "1900-01-01T00:00:00.000000000+00:19:32"
+    );
+    assert_eq!(
+        datetime!(1900-01-01 0:00 -0:19:32).format(
+            &Iso8601::<
+                {
+                    iso8601::Config::DEFAULT
+                        .set_use_separators(false)
+                        .set_offset_precision(OffsetPrecision::Second)
+                        .encode()
+                },
+            >
+        )?,
+        "19000101T000000.000000000-001932"
+    );
     assert_format_config!("2021-01-02T03:04:05.000000000Z");
     assert_format_config!("20210102T030405.000000000Z", .set_use_separators(false));
     assert_format_config!("+002021-01-02T03:04:05.000000000Z", .set_year_is_six_digits(true));
@@ -210,6 +506,18 @@ fn iso_8601() -> time::Result<()> {
         datetime!(2021-01-02 03:04:05 +0:00:01).format(&Iso8601::DEFAULT),
         Err(time::error::Format::InvalidComponent("offset_second"))
     ));
+    assert!(matches!(
+        datetime!(1900-01-01 0:00 +0:19:32).format(
+            &Iso8601::<
+                {
+                    iso8601::Config::DEFAULT
+                        .set_offset_precision(OffsetPrecision::Hour)
+                        .encode()
+                },
+            >
+        ),
+        Err(time::error::Format::InvalidComponent("offset_minute"))
+    ));
     assert!(matches!(
         datetime!(2021-01-02 03:04:05 +0:01).format(
             &Iso8601::<
@@ -226,6 +534,399 @@ fn iso_8601() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn iso_8601_dynamic() {
+    let values = [
+        datetime!(2021-01-02 03:04:05 UTC),
+        datetime!(2021-12-31 23:59:59.123_456_789 +05:30),
+        datetime!(-0001-01-01 00:00:00.5 -01:00),
+        datetime!(+12_345-06-07 08:09:10 +01:00:30),
+    ];
+
+    macro_rules! assert_same_as_const {
+        ($config:expr) => {{
+            const CONFIG: iso8601::EncodedConfig = $config.encode();
+            let dynamic = Iso8601Dynamic::from_config($config);
+            assert_eq!(dynamic, Iso8601Dynamic::from(Iso8601::<CONFIG>));
+            assert_eq!(dynamic.config(), $config);
+
+            let format =
+                |value: Result<String, time::error::Format>| value.map_err(|err| err.to_string());
+            for value in values {
+                let (date, time, offset) = (value.date(), value.time(), value.offset());
+                let pdt = date.with_time(time);
+                assert_eq!(
+                    format(value.format(&dynamic)),
+                    format(value.format(&Iso8601::<CONFIG>))
+                );
+                assert_eq!(
+                    format(pdt.format(&dynamic)),
+                    format(pdt.format(&Iso8601::<CONFIG>))
+                );
+                assert_eq!(
+                    format(date.format(&dynamic)),
+                    format(date.format(&Iso8601::<CONFIG>))
+                );
+                assert_eq!(
+                    format(time.format(&dynamic)),
+                    format(time.format(&Iso8601::<CONFIG>))
+                );
+                assert_eq!(
+                    format(offset.format(&dynamic)),
+                    format(offset.format(&Iso8601::<CONFIG>))
+                );
+            }
+        }};
+    }
+
+    assert_same_as_const!(iso8601::Config::DEFAULT);
+    assert_same_as_const!(iso8601::Config::DEFAULT.set_use_separators(false));
+    assert_same_as_const!(iso8601::Config::DEFAULT.set_year_is_six_digits(true));
+    assert_same_as_const!(iso8601::Config::DEFAULT.set_date_kind(DateKind::Week));
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT
+            .set_date_kind(DateKind::Ordinal)
+            .set_use_separators(false)
+    );
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT.set_time_precision(TimePrecision::Hour {
+            decimal_digits: NonZeroU8::new(3),
+        })
+    );
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT.set_time_precision(TimePrecision::Minute {
+            decimal_digits: None,
+        })
+    );
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT
+            .set_time_precision(TimePrecision::Second {
+                decimal_digits: None,
+            })
+            .set_offset_precision(OffsetPrecision::Hour)
+    );
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT.set_formatted_components(iso8601::FormattedComponents::Date)
+    );
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT.set_formatted_components(iso8601::FormattedComponents::Time)
+    );
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT.set_formatted_components(iso8601::FormattedComponents::Offset)
+    );
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT
+            .set_formatted_components(iso8601::FormattedComponents::DateTime)
+            .set_use_separators(false)
+    );
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT
+            .set_formatted_components(iso8601::FormattedComponents::TimeOffset)
+            .set_use_separators(false)
+    );
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT.set_formatted_components(iso8601::FormattedComponents::None)
+    );
+
+    assert_eq!(
+        Iso8601Dynamic::DEFAULT,
+        Iso8601Dynamic::from(Iso8601::DEFAULT)
+    );
+}
+
+#[test]
+fn iso_8601_parsing_only() {
+    let assert_invalid_format_description = |res| {
+        assert!(matches!(
+            res,
+            Err(time::error::Format::InvalidFormatDescription)
+        ));
+    };
+    let dynamic = Iso8601Dynamic::from_config(
+        iso8601::Config::DEFAULT.set_formatted_components(iso8601::FormattedComponents::None),
+    );
+
+    let value = datetime!(2021-01-02 03:04:05 UTC);
+    assert_invalid_format_description(value.format(&Iso8601::PARSING));
+    assert_invalid_format_description(value.format(&dynamic));
+    // The error takes precedence over any missing information.
+    assert_invalid_format_description(Time::MIDNIGHT.format(&Iso8601::PARSING));
+    assert_invalid_format_description(Time::MIDNIGHT.format(&dynamic));
+    assert_invalid_format_description(
+        value
+            .format_into(&mut Vec::new(), &Iso8601::PARSING)
+            .map(|_| String::new()),
+    );
+    assert_invalid_format_description(
+        value
+            .format_to_array::<8>(&Iso8601::PARSING)
+            .map(|buf| buf.to_string()),
+    );
+
+    // An empty `First` has nothing to format and succeeds without output.
+    assert_eq!(
+        value
+            .format(&FormatItem::First(&[]))
+            .map_err(|err| err.to_string()),
+        Ok(String::new())
+    );
+}
+
+#[test]
+fn iso_8601_duration() -> time::Result<()> {
+    let format = Iso8601Duration::DEFAULT;
+    assert_eq!(Duration::ZERO.format(&format)?, "PT0S");
+    assert_eq!(1.seconds().format(&format)?, "PT1S");
+    assert_eq!(1.5.seconds().format(&format)?, "PT1.5S");
+    assert_eq!(1.nanoseconds().format(&format)?, "PT0.000000001S");
+    assert_eq!(90.minutes().format(&format)?, "PT1H30M");
+    assert_eq!(1.days().format(&format)?, "P1D");
+    assert_eq!(2.weeks().format(&format)?, "P14D");
+    assert_eq!(
+        (1.days() + 2.hours() + 3.minutes() + 4.seconds()).format(&format)?,
+        "P1DT2H3M4S"
+    );
+    assert_eq!((1.days() + 1.seconds()).format(&format)?, "P1DT1S");
+    assert_eq!((-90).minutes().format(&format)?, "-PT1H30M");
+    assert_eq!((-0.5).seconds().format(&format)?, "-PT0.5S");
+    assert_eq!(
+        Duration::MAX.format(&format)?,
+        "P106751991167300DT15H30M7.999999999S"
+    );
+    assert_eq!(
+        Duration::MIN.format(&format)?,
+        "-P106751991167300DT15H30M8.999999999S"
+    );
+
+    let format = Iso8601Duration::DEFAULT.set_use_days(false);
+    assert_eq!(26.hours().format(&format)?, "PT26H");
+    for duration in [
+        Duration::ZERO,
+        26.hours(),
+        (-1.5).seconds(),
+        (2.weeks() + 3.minutes() + 1.nanoseconds()),
+    ] {
+        assert_eq!(duration.format(&format)?, format!("{duration:#}"));
+    }
+
+    let format = Iso8601Duration::DEFAULT.set_decimal_digits(NonZeroU8::new(3));
+    assert_eq!(Duration::ZERO.format(&format)?, "PT0.000S");
+    assert_eq!(1.5.seconds().format(&format)?, "PT1.500S");
+    assert_eq!(1.000_999.seconds().format(&format)?, "PT1.000S");
+    assert_eq!(
+        (1.seconds() + 1.milliseconds()).format(&format)?,
+        "PT1.001S"
+    );
+    assert_eq!(1.hours().format(&format)?, "PT1H");
+    assert_eq!((1.hours() + 1.microseconds()).format(&format)?, "PT1H");
+    assert_eq!(
+        (1.hours() + 1.milliseconds()).format(&format)?,
+        "PT1H0.001S"
+    );
+
+    let mut buf = Vec::new();
+    assert_eq!(
+        90.minutes()
+            .format_into(&mut buf, &Iso8601Duration::DEFAULT)?,
+        7
+    );
+    assert_eq!(buf, b"PT1H30M");
+    let mut buf = String::new();
+    assert_eq!(
+        90.minutes()
+            .format_into_fmt(&mut buf, &Iso8601Duration::DEFAULT)?,
+        7
+    );
+    assert_eq!(buf, "PT1H30M");
+
+    Ok(())
+}
+
+#[test]
+#[should_panic]
+fn iso_8601_duration_too_many_digits() {
+    let _ = Iso8601Duration::DEFAULT.set_decimal_digits(NonZeroU8::new(10));
+}
+
+#[test]
+fn format_to_array() -> time::Result<()> {
+    let datetime = datetime!(2021-01-02 03:04:05.123_456_789 -01:02);
+    let expected = "2021-01-02T03:04:05.123456789-01:02";
+    assert_eq!(expected.len(), 35);
+
+    let exact = datetime.format_to_array::<35>(&Rfc3339)?;
+    assert_eq!(exact, expected);
+    assert_eq!(exact.as_bytes(), expected.as_bytes());
+    assert_eq!(exact.to_string(), expected);
+    assert_eq!(format!("{exact:?}"), format!("{expected:?}"));
+
+    assert!(matches!(
+        datetime.format_to_array::<34>(&Rfc3339),
+        Err(time::error::Format::InsufficientCapacity {
+            capacity: 34,
+            required: 35
+        })
+    ));
+    assert!(matches!(
+        datetime.format_to_array::<0>(&Rfc3339),
+        Err(time::error::Format::InsufficientCapacity {
+            capacity: 0,
+            required: 35
+        })
+    ));
+
+    let oversized = datetime.format_to_array::<128>(&Rfc3339)?;
+    assert_eq!(oversized, expected);
+    assert_eq!(oversized.len(), 35);
+    assert_eq!(oversized, datetime.format_to_array::<128>(&Rfc3339)?);
+
+    assert_eq!(
+        datetime
+            .date()
+            .format_to_array::<16>(fd!("[year]-[month]-[day]"))?,
+        "2021-01-02"
+    );
+    assert_eq!(
+        datetime
+            .time()
+            .format_to_array::<16>(fd!("[hour]:[minute]"))?,
+        "03:04"
+    );
+    assert_eq!(
+        PrimitiveDateTime::new(datetime.date(), datetime.time())
+            .format_to_array::<64>(fd!("[year]-[month]-[day] [hour]:[minute]"))?,
+        "2021-01-02 03:04"
+    );
+    assert!(matches!(
+        datetime.format_to_array::<64>(&FormatItem::Literal(b"\xff")),
+        Err(time::error::Format::StdIo(_))
+    ));
+    assert!(matches!(
+        Time::MIDNIGHT.format_to_array::<64>(fd!("[year]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn format_into_slice() -> time::Result<()> {
+    fn assert_sizes(
+        datetime: OffsetDateTime,
+        format: &(impl Formattable + ?Sized),
+        expected: &str,
+    ) -> time::Result<()> {
+        let len = expected.len();
+        assert_eq!(datetime.formatted_len(format)?, len);
+
+        let mut buf = [0; 128];
+        assert_eq!(datetime.format_into_slice(&mut buf[..len], format)?, len);
+        assert_eq!(&buf[..len], expected.as_bytes());
+
+        assert!(matches!(
+            datetime.format_into_slice(&mut buf[..len - 1], format),
+            Err(time::error::Format::InsufficientCapacity { capacity, required })
+                if capacity == len - 1 && required == len
+        ));
+
+        let mut buf = [b'x'; 128];
+        assert_eq!(datetime.format_into_slice(&mut buf, format)?, len);
+        assert_eq!(&buf[..len], expected.as_bytes());
+        assert!(buf[len..].iter().all(|&b| b == b'x'));
+        Ok(())
+    }
+
+    let datetime = datetime!(2021-01-02 03:04:05.123_456_789 -01:02);
+    assert_sizes(datetime, &Rfc3339, "2021-01-02T03:04:05.123456789-01:02")?;
+    assert_sizes(
+        datetime,
+        &Iso8601::DEFAULT,
+        "2021-01-02T03:04:05.123456789-01:02",
+    )?;
+    assert_sizes(
+        datetime,
+        fd!("[day] [month repr:short] [year] [hour]:[minute]:[second].[subsecond digits:3]"),
+        "02 Jan 2021 03:04:05.123",
+    )?;
+
+    assert_eq!(datetime.format_into_slice(&mut [], fd!(""))?, 0);
+    assert!(matches!(
+        datetime.format_into_slice(&mut [], &Rfc3339),
+        Err(time::error::Format::InsufficientCapacity {
+            capacity: 0,
+            required: 35
+        })
+    ));
+
+    let mut buf = [0; 16];
+    assert_eq!(
+        datetime
+            .date()
+            .format_into_slice(&mut buf, fd!("[year]-[month]-[day]"))?,
+        10
+    );
+    assert_eq!(&buf[..10], b"2021-01-02");
+    assert_eq!(
+        datetime.date().formatted_len(fd!("[year]-[month]-[day]"))?,
+        10
+    );
+    assert_eq!(
+        datetime
+            .time()
+            .format_into_slice(&mut buf, fd!("[hour]:[minute]"))?,
+        5
+    );
+    assert_eq!(&buf[..5], b"03:04");
+    assert_eq!(datetime.time().formatted_len(fd!("[hour]:[minute]"))?, 5);
+    let primitive = PrimitiveDateTime::new(datetime.date(), datetime.time());
+    assert_eq!(
+        primitive.format_into_slice(&mut buf, fd!("[year]-[month]-[day] [hour]:[minute]"))?,
+        16
+    );
+    assert_eq!(&buf, b"2021-01-02 03:04");
+    assert_eq!(
+        primitive.formatted_len(fd!("[year]-[month]-[day] [hour]:[minute]"))?,
+        16
+    );
+
+    // Bytes are written as-is, as with `format_into`.
+    assert_eq!(
+        datetime.format_into_slice(&mut buf, &FormatItem::Literal(b"a\xffb"))?,
+        3
+    );
+    assert_eq!(&buf[..3], b"a\xffb");
+
+    assert!(matches!(
+        Time::MIDNIGHT.format_into_slice(&mut buf, fd!("[year]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(matches!(
+        Time::MIDNIGHT.formatted_len(fd!("[year]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn well_known_convenience() -> time::Result<()> {
+    let datetime = datetime!(2021-01-02 03:04:05.123_456_789 -01:02);
+    assert_eq!(datetime.format_rfc3339()?, datetime.format(&Rfc3339)?);
+    assert_eq!(datetime.format_rfc2822()?, datetime.format(&Rfc2822)?);
+    assert_eq!(
+        datetime.format_iso8601()?,
+        datetime.format(&Iso8601::DEFAULT)?
+    );
+    assert_eq!(date!(2021 - 01 - 02).format_iso8601()?, "2021-01-02");
+
+    assert!(matches!(
+        datetime!(2021-01-02 03:04:05 +00:00:01).format_rfc3339(),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn format_time() -> time::Result<()> {
     let format_output = [
@@ -290,6 +991,15 @@ fn format_time() -> time::Result<()> {
         Time::MIDNIGHT.format(fd!("[hour repr:12][period case:lower]"))?,
         "12am"
     );
+    assert_eq!(
+        time!(15:45).format(fd!("[period] [hour repr:12 padding:none]:[minute]"))?,
+        "PM 3:45"
+    );
+    assert_eq!(
+        Time::MIDNIGHT.format(fd!("[period] [hour repr:12]"))?,
+        "AM 12"
+    );
+    assert_eq!(time!(12:00).format(fd!("[period] [hour repr:12]"))?, "PM 12");
     assert_eq!(Time::MIDNIGHT.format(fd!("[subsecond digits:1+]"))?, "0");
     assert_eq!(
         time!(0:00:00.01).format(fd!("[subsecond digits:1+]"))?,
@@ -329,19 +1039,19 @@ fn format_time() -> time::Result<()> {
 
 #[test]
 fn display_time() {
-    assert_eq!(time!(0:00).to_string(), "0:00:00.0");
+    assert_eq!(time!(0:00).to_string(), "00:00:00.0");
     assert_eq!(time!(23:59).to_string(), "23:59:00.0");
     assert_eq!(time!(23:59:59).to_string(), "23:59:59.0");
-    assert_eq!(time!(0:00:01).to_string(), "0:00:01.0");
-    assert_eq!(time!(0:00:00.1).to_string(), "0:00:00.1");
-    assert_eq!(time!(0:00:00.01).to_string(), "0:00:00.01");
-    assert_eq!(time!(0:00:00.001).to_string(), "0:00:00.001");
-    assert_eq!(time!(0:00:00.000_1).to_string(), "0:00:00.0001");
-    assert_eq!(time!(0:00:00.000_01).to_string(), "0:00:00.00001");
-    assert_eq!(time!(0:00:00.000_001).to_string(), "0:00:00.000001");
-    assert_eq!(time!(0:00:00.000_000_1).to_string(), "0:00:00.0000001");
-    assert_eq!(time!(0:00:00.000_000_01).to_string(), "0:00:00.00000001");
-    assert_eq!(time!(0:00:00.000_000_001).to_string(), "0:00:00.000000001");
+    assert_eq!(time!(0:00:01).to_string(), "00:00:01.0");
+    assert_eq!(time!(0:00:00.1).to_string(), "00:00:00.1");
+    assert_eq!(time!(0:00:00.01).to_string(), "00:00:00.01");
+    assert_eq!(time!(0:00:00.001).to_string(), "00:00:00.001");
+    assert_eq!(time!(0:00:00.000_1).to_string(), "00:00:00.0001");
+    assert_eq!(time!(0:00:00.000_01).to_string(), "00:00:00.00001");
+    assert_eq!(time!(0:00:00.000_001).to_string(), "00:00:00.000001");
+    assert_eq!(time!(0:00:00.000_000_1).to_string(), "00:00:00.0000001");
+    assert_eq!(time!(0:00:00.000_000_01).to_string(), "00:00:00.00000001");
+    assert_eq!(time!(0:00:00.000_000_001).to_string(), "00:00:00.000000001");
 }
 
 #[test]
@@ -392,6 +1102,60 @@ fn format_date() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn ordinal_day() -> time::Result<()> {
+    let suffixes = [
+        "st", "nd", "rd", "th", "th", "th", "th", "th", "th", "th", // 1-10
+        "th", "th", "th", "th", "th", "th", "th", "th", "th", "th", // 11-20
+        "st", "nd", "rd", "th", "th", "th", "th", "th", "th", "th", // 21-30
+        "st", // 31
+    ];
+    for (day, suffix) in (1..=31).zip(suffixes) {
+        let date = Date::from_calendar_date(2024, Month::January, day)?;
+        assert_eq!(
+            date.format(fd!("[day repr:ordinal padding:none]"))?,
+            format!("{day}{suffix}")
+        );
+    }
+
+    assert_eq!(
+        date!(2024 - 03 - 03).format(fd!(
+            "[month repr:long] [day repr:ordinal padding:none], [year]"
+        ))?,
+        "March 3rd, 2024"
+    );
+    assert_eq!(
+        date!(2024 - 03 - 03).format(fd!("[day repr:ordinal]"))?,
+        "03rd"
+    );
+    assert_eq!(
+        date!(2024 - 03 - 03).format(fd!("[day repr:ordinal padding:space]"))?,
+        " 3rd"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn month_and_weekday_names() -> time::Result<()> {
+    // Any seven consecutive days cover every weekday.
+    for month in 1..=12 {
+        let month = Month::try_from(month)?;
+        for day in 1..=7 {
+            let date = Date::from_calendar_date(2019, month, day)?;
+            assert_eq!(date.format(fd!("[month repr:long]"))?, month.name());
+            assert_eq!(date.format(fd!("[month repr:short]"))?, month.short_name());
+            assert_eq!(date.format(fd!("[weekday]"))?, date.weekday().name());
+            assert_eq!(
+                date.format(fd!("[weekday repr:short]"))?,
+                date.weekday().short_name()
+            );
+        }
+    }
+
+    Ok(())
+}
+
 #[test]
 fn display_date() {
     assert_eq!(date!(2019 - 01 - 01).to_string(), "2019-01-01");
@@ -453,6 +1217,164 @@ fn format_offset() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn format_signed_padding() -> time::Result<()> {
+    // When padding with spaces, the sign is placed directly before the digits.
+    assert_eq!(
+        date!(-0005 - 01 - 01).format(fd!("[year padding:space]"))?,
+        "   -5"
+    );
+    assert_eq!(
+        date!(-0045 - 01 - 01).format(fd!("[year padding:space]"))?,
+        "  -45"
+    );
+    assert_eq!(
+        date!(-0005 - 01 - 01).format(fd!("[year padding:zero]"))?,
+        "-0005"
+    );
+    assert_eq!(
+        date!(-0005 - 01 - 01).format(fd!("[year padding:none]"))?,
+        "-5"
+    );
+    assert_eq!(
+        date!(0005 - 01 - 01).format(fd!("[year padding:space sign:mandatory]"))?,
+        "   +5"
+    );
+    assert_eq!(
+        date!(-2021 - 01 - 01).format(fd!("[year padding:space]"))?,
+        "-2021"
+    );
+
+    assert_eq!(
+        offset!(-1).format(fd!("[offset_hour padding:space]"))?,
+        " -1"
+    );
+    assert_eq!(
+        offset!(-1).format(fd!("[offset_hour padding:zero]"))?,
+        "-01"
+    );
+    assert_eq!(offset!(-1).format(fd!("[offset_hour padding:none]"))?, "-1");
+    assert_eq!(
+        offset!(+1).format(fd!("[offset_hour padding:space sign:mandatory]"))?,
+        " +1"
+    );
+    assert_eq!(
+        offset!(-0:30).format(fd!("[offset_hour padding:space]:[offset_minute]"))?,
+        " -0:30"
+    );
+    assert_eq!(
+        offset!(-12).format(fd!("[offset_hour padding:space]"))?,
+        "-12"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn format_offset_short_forms() -> time::Result<()> {
+    let format_description = fd!(
+        "[offset_hour sign:mandatory z_when_utc:true]\
+         [optional [:[offset_minute omit_when_zero:true]]]"
+    );
+    let runtime_format_description = format_description::parse_owned::<2>(
+        "[offset_hour sign:mandatory z_when_utc:true]\
+         [optional [:[offset_minute omit_when_zero:true]]]",
+    )?;
+    let value_output = [
+        (offset!(UTC), "Z"),
+        (offset!(+02), "+02"),
+        (offset!(-02), "-02"),
+        (offset!(+02:30), "+02:30"),
+        (offset!(-02:30), "-02:30"),
+        (offset!(-00:30), "-00:30"),
+        (offset!(+00:00:01), "+00"),
+    ];
+
+    for (value, output) in value_output {
+        assert_eq!(value.format(format_description)?, output);
+        assert_eq!(value.format(&runtime_format_description)?, output);
+        assert_eq!(
+            value.format(&OwnedFormatItem::from(format_description))?,
+            output
+        );
+    }
+
+    // Each modifier is independent of the other and of optional items.
+    assert_eq!(
+        offset!(UTC).format(fd!("[offset_hour z_when_utc:true]"))?,
+        "Z"
+    );
+    assert_eq!(
+        offset!(UTC).format(fd!("[offset_hour z_when_utc:true]:[offset_minute]"))?,
+        "Z:00"
+    );
+    assert_eq!(
+        offset!(+02).format(fd!("[offset_hour]:[offset_minute omit_when_zero:true]"))?,
+        "02:"
+    );
+    assert_eq!(
+        offset!(UTC).format(fd!(
+            "[offset_hour][optional [:[offset_minute omit_when_zero:true]]]"
+        ))?,
+        "00"
+    );
+    assert_eq!(
+        datetime!(2023-05-10 13:30 +2).format(fd!(
+            "[hour]:[minute][optional [ [offset_hour]:[offset_minute omit_when_zero:true]]]"
+        ))?,
+        "13:30"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn format_offset_combined() -> time::Result<()> {
+    let colon = fd!("[offset sign:mandatory z_when_utc:true]");
+    let no_colon = fd!("[offset sign:mandatory z_when_utc:true separator:none]");
+    let value_output = [
+        (offset!(UTC), "Z", "Z"),
+        (offset!(+09:30), "+09:30", "+0930"),
+        (offset!(-09:30), "-09:30", "-0930"),
+        (offset!(-00:30), "-00:30", "-0030"),
+        (offset!(+05), "+05:00", "+0500"),
+        (offset!(-12), "-12:00", "-1200"),
+        (offset!(+00:00:01), "+00:00", "+0000"),
+    ];
+
+    for (value, with_colon, without_colon) in value_output {
+        assert_eq!(value.format(colon)?, with_colon);
+        assert_eq!(value.format(no_colon)?, without_colon);
+        assert_eq!(value.format(&OwnedFormatItem::from(colon))?, with_colon);
+        assert_eq!(
+            value.format(&format_description::parse_owned::<2>(
+                "[offset sign:mandatory z_when_utc:true separator:none]"
+            )?)?,
+            without_colon
+        );
+    }
+
+    assert_eq!(offset!(UTC).format(fd!("[offset]"))?, "00:00");
+    assert_eq!(offset!(+01:02).format(fd!("[offset]"))?, "01:02");
+    assert_eq!(offset!(-01:02).format(fd!("[offset]"))?, "-01:02");
+    assert_eq!(
+        offset!(UTC).format(&FormatItem::Component(
+            format_description::Component::Offset(format_description::modifier::Offset::default())
+        ))?,
+        "+00:00"
+    );
+    assert_eq!(
+        datetime!(2023-05-10 13:30 -2).format(fd!("[hour]:[minute][offset z_when_utc:true]"))?,
+        "13:30-02:00"
+    );
+    assert!(matches!(
+        Time::MIDNIGHT.format(fd!("[offset]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn display_offset() {
     assert_eq!(offset!(UTC).to_string(), "+00:00:00");
@@ -464,6 +1386,20 @@ fn display_offset() {
     assert_eq!(offset!(-23:59).to_string(), "-23:59:00");
     assert_eq!(offset!(+23:59:59).to_string(), "+23:59:59");
     assert_eq!(offset!(-23:59:59).to_string(), "-23:59:59");
+
+    assert_eq!(format!("{:.0}", offset!(UTC)), "+00:00");
+    assert_eq!(format!("{:.0}", offset!(+1)), "+01:00");
+    assert_eq!(format!("{:.0}", offset!(-1:30)), "-01:30");
+    assert_eq!(format!("{:.0}", offset!(+0:00:01)), "+00:00:01");
+    assert_eq!(format!("{:.0}", offset!(-0:00:01)), "-00:00:01");
+    assert_eq!(format!("{:.3}", offset!(-23:59:59)), "-23:59:59");
+
+    assert_eq!(format!("{:#}", offset!(UTC)), "+0000");
+    assert_eq!(format!("{:#}", offset!(+1)), "+0100");
+    assert_eq!(format!("{:#}", offset!(-1:30)), "-0130");
+    assert_eq!(format!("{:#}", offset!(+0:00:01)), "+000001");
+    assert_eq!(format!("{:#}", offset!(-0:17:30)), "-001730");
+    assert_eq!(format!("{:#.0}", offset!(-23:59:59)), "-235959");
 }
 
 #[test]
@@ -496,11 +1432,11 @@ fn format_pdt() -> time::Result<()> {
 fn display_pdt() {
     assert_eq!(
         datetime!(1970-01-01 0:00).to_string(),
-        String::from("1970-01-01 0:00:00.0")
+        String::from("1970-01-01 00:00:00.0")
     );
     assert_eq!(
         datetime!(1970-01-01 0:00:01).to_string(),
-        String::from("1970-01-01 0:00:01.0")
+        String::from("1970-01-01 00:00:01.0")
     );
 }
 
@@ -537,7 +1473,7 @@ fn format_odt() -> time::Result<()> {
 fn display_odt() {
     assert_eq!(
         datetime!(1970-01-01 0:00 UTC).to_string(),
-        "1970-01-01 0:00:00.0 +00:00:00"
+        "1970-01-01 00:00:00.0 +00:00:00"
     );
 }
 
@@ -553,9 +1489,22 @@ fn insufficient_type_information() {
     assert_insufficient_type_information(Time::MIDNIGHT.format(&Rfc3339));
     assert_insufficient_type_information(date!(2021 - 001).format(&Rfc3339));
     assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&Rfc3339));
+    assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&Rfc3339NoZ));
     assert_insufficient_type_information(Time::MIDNIGHT.format(&Rfc2822));
     assert_insufficient_type_information(date!(2021 - 001).format(&Rfc2822));
     assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&Rfc2822));
+    assert_insufficient_type_information(Time::MIDNIGHT.format(&JsDate));
+    assert_insufficient_type_information(date!(2021 - 001).format(&JsDate));
+    assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&JsDate));
+    assert_insufficient_type_information(Time::MIDNIGHT.format(&Systemd));
+    assert_insufficient_type_information(date!(2021 - 001).format(&Systemd));
+    assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&Systemd));
+    assert_insufficient_type_information(Time::MIDNIGHT.format(&Rfc7231));
+    assert_insufficient_type_information(date!(2021 - 001).format(&Rfc7231));
+    assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&Rfc7231));
+    assert_insufficient_type_information(Time::MIDNIGHT.format(&Unix));
+    assert_insufficient_type_information(date!(2021 - 001).format(&Unix));
+    assert_insufficient_type_information(datetime!(2021 - 001 0:00).format(&Unix));
     assert_insufficient_type_information(
         Time::MIDNIGHT.format(&FormatItem::First(&[FormatItem::Compound(fd!("[year]"))])),
     );
@@ -564,6 +1513,72 @@ fn insufficient_type_information() {
     assert_insufficient_type_information(datetime!(2021-001 0:00).format(&Iso8601::DEFAULT));
 }
 
+#[test]
+fn insufficient_type_information_missing() {
+    use time::error::MissingInformation::{self, Date as MissingDate, Offset, Time as MissingTime};
+
+    fn missing(res: Result<String, time::error::Format>) -> Option<MissingInformation> {
+        match res {
+            Err(time::error::Format::InsufficientTypeInformation { missing, .. }) => Some(missing),
+            _ => None,
+        }
+    }
+
+    let date = date!(2021-001);
+    let time = Time::MIDNIGHT;
+    let pdt = datetime!(2021-001 0:00);
+
+    assert_eq!(missing(date.format(fd!("[hour]"))), Some(MissingTime));
+    assert_eq!(missing(date.format(fd!("[offset_hour]"))), Some(Offset));
+    assert_eq!(
+        missing(date.format(fd!("[unix_timestamp]"))),
+        Some(MissingTime)
+    );
+    assert_eq!(missing(date.format(&Rfc3339)), Some(MissingTime));
+    assert_eq!(missing(date.format(&Rfc2822)), Some(MissingTime));
+    assert_eq!(missing(date.format(&JsDate)), Some(MissingTime));
+    assert_eq!(missing(date.format(&Systemd)), Some(MissingTime));
+    assert_eq!(missing(date.format(&Unix)), Some(MissingTime));
+    assert_eq!(missing(date.format(&Iso8601::DEFAULT)), Some(MissingTime));
+
+    assert_eq!(missing(time.format(fd!("[year]"))), Some(MissingDate));
+    assert_eq!(missing(time.format(fd!("[offset_minute]"))), Some(Offset));
+    assert_eq!(
+        missing(time.format(fd!("[unix_timestamp]"))),
+        Some(MissingDate)
+    );
+    assert_eq!(missing(time.format(&Rfc3339)), Some(MissingDate));
+    assert_eq!(missing(time.format(&Rfc2822)), Some(MissingDate));
+    assert_eq!(missing(time.format(&JsDate)), Some(MissingDate));
+    assert_eq!(missing(time.format(&Systemd)), Some(MissingDate));
+    assert_eq!(missing(time.format(&Unix)), Some(MissingDate));
+    assert_eq!(missing(time.format(&Iso8601::DEFAULT)), Some(MissingDate));
+
+    assert_eq!(missing(pdt.format(fd!("[offset_second]"))), Some(Offset));
+    assert_eq!(missing(pdt.format(fd!("[unix_timestamp]"))), Some(Offset));
+    assert_eq!(missing(pdt.format(&Rfc3339)), Some(Offset));
+    assert_eq!(missing(pdt.format(&Rfc3339NoZ)), Some(Offset));
+    assert_eq!(missing(pdt.format(&Rfc2822)), Some(Offset));
+    assert_eq!(missing(pdt.format(&JsDate)), Some(Offset));
+    assert_eq!(missing(pdt.format(&Systemd)), Some(Offset));
+    assert_eq!(missing(pdt.format(&Unix)), Some(Offset));
+    assert_eq!(missing(pdt.format(&Iso8601::DEFAULT)), Some(Offset));
+    assert_eq!(
+        missing(pdt.format(fd!(version = 2, "[first [[offset_hour]][[offset_minute]]]"))),
+        Some(Offset)
+    );
+    assert_eq!(
+        missing(pdt.format(fd!(version = 2, "[optional [[year] [offset_hour]]]"))),
+        Some(Offset)
+    );
+
+    assert_eq!(
+        time.format(fd!("[year]")).unwrap_err().to_string(),
+        "The type being formatted does not contain sufficient information to format a component: \
+         a date is required."
+    );
+}
+
 #[test]
 fn failed_write() -> time::Result<()> {
     macro_rules! assert_err {
@@ -596,7 +1611,28 @@ fn failed_write() -> time::Result<()> {
     assert_err!(OffsetDateTime::UNIX_EPOCH, Rfc3339);
     assert_err!(datetime!(2021-001 0:00:00.1 UTC), Rfc3339);
     assert_err!(datetime!(2021-001 0:00 +0:01), Rfc3339);
+    assert_err!(OffsetDateTime::UNIX_EPOCH, Rfc3339NoZ);
     assert_err!(OffsetDateTime::UNIX_EPOCH, Rfc2822);
+    assert_err!(OffsetDateTime::UNIX_EPOCH, JsDate);
+    assert_err!(OffsetDateTime::UNIX_EPOCH, Systemd);
+    assert_err!(datetime!(2024-05-01 13:45:30 -07:00), Systemd);
+    assert_err!(OffsetDateTime::UNIX_EPOCH, Rfc7231);
+    assert_err!(
+        Time::MIDNIGHT,
+        FormatItem::First(&[
+            FormatItem::Compound(fd!("[year]")),
+            FormatItem::Compound(fd!("[hour]:[minute]"))
+        ])
+    );
+    assert_err!(
+        Time::MIDNIGHT,
+        CompiledFormat::from(FormatItem::First(&[
+            FormatItem::Compound(fd!("[year]")),
+            FormatItem::Compound(fd!("[hour]:[minute]"))
+        ]))
+    );
+    assert_err!(datetime!(2024-05-01 12:51:30.25 UTC), Unix);
+    assert_err!(datetime!(1969-12-31 23:59:59.5 UTC), Unix);
     assert_err!(OffsetDateTime::UNIX_EPOCH, Iso8601::DEFAULT);
     assert_err!(datetime!(2021-001 0:00 +0:01), Iso8601::DEFAULT);
     assert_err!(
@@ -729,6 +1765,156 @@ fn first() -> time::Result<()> {
         "00"
     );
 
+    // Items that cannot be formatted are skipped, without writing anything they formatted before
+    // failing.
+    let fallback = FormatItem::First(&[
+        FormatItem::Compound(fd!("[hour]:[year]")),
+        FormatItem::Compound(fd!("[hour]h")),
+        FormatItem::Compound(fd!("[minute]m")),
+    ]);
+    assert_eq!(Time::MIDNIGHT.format(&fallback)?, "00h");
+    assert_eq!(
+        Time::MIDNIGHT.format(&OwnedFormatItem::from(fallback.clone()))?,
+        "00h"
+    );
+    assert_eq!(
+        Time::MIDNIGHT.format(&CompiledFormat::from(fallback.clone()))?,
+        "00h"
+    );
+    let mut buf = Vec::new();
+    assert_eq!(Time::MIDNIGHT.format_into(&mut buf, &fallback)?, 3);
+    assert_eq!(buf, b"00h");
+    assert_eq!(datetime!(2021-01-02 03:04).format(&fallback)?, "03:2021");
+
+    // If no item can be formatted, the error of the last one is returned.
+    let none = FormatItem::First(&[
+        FormatItem::Compound(fd!("[year]")),
+        FormatItem::Compound(fd!("[offset_hour]")),
+    ]);
+    for result in [
+        Time::MIDNIGHT.format(&none),
+        Time::MIDNIGHT.format(&OwnedFormatItem::from(none.clone())),
+        Time::MIDNIGHT.format(&CompiledFormat::from(none)),
+    ] {
+        assert!(matches!(
+            result,
+            Err(time::error::Format::InsufficientTypeInformation {
+                missing: time::error::MissingInformation::Offset,
+                ..
+            })
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn first_deeply_nested() -> time::Result<()> {
+    // Nesting as deeply as the default parse limits permit must not make formatting take time
+    // exponential in the depth.
+    let mut item = OwnedFormatItem::First(Box::new([
+        OwnedFormatItem::from(fd!("[year]")),
+        OwnedFormatItem::from(fd!("[hour]")),
+    ]));
+    for _ in 0..32 {
+        item = OwnedFormatItem::First(Box::new([item, OwnedFormatItem::from(fd!("[minute]"))]));
+    }
+    assert_eq!(Time::MIDNIGHT.format(&item)?, "00");
+    assert_eq!(Time::MIDNIGHT.format(&CompiledFormat::from(&item))?, "00");
+    assert!(matches!(
+        offset!(UTC).format(&item),
+        Err(time::error::Format::InsufficientTypeInformation {
+            missing: time::error::MissingInformation::Time,
+            ..
+        })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn compiled_format() -> time::Result<()> {
+    let descriptions: [&[FormatItem<'_>]; 12] = [
+        fd!(version = 2, ""),
+        fd!(version = 2, "literal only"),
+        fd!(
+            version = 2,
+            "[year]-[month]-[day]T[hour]:[minute]:[second].[subsecond][offset_hour \
+             sign:mandatory]:[offset_minute]"
+        ),
+        fd!(
+            version = 2,
+            "[weekday], [day] [month repr:short] [year] [hour]:[minute][optional \
+             [:[second][optional [.[subsecond digits:3]]]]]"
+        ),
+        fd!(
+            version = 2,
+            "[offset_hour z_when_utc:true][optional [:[offset_minute omit_when_zero:true]]]"
+        ),
+        fd!(
+            version = 2,
+            "[optional [[first [[offset_hour][offset_minute omit_when_zero:true]][Z]]]] after"
+        ),
+        fd!(
+            version = 2,
+            "[optional [<[optional [[offset_minute omit_when_zero:true]]]>]]"
+        ),
+        fd!(
+            version = 2,
+            "[optional [[first [[year]][[offset_minute omit_when_zero:true]]]]]"
+        ),
+        fd!(
+            version = 2,
+            "[first [[year]-[month]-[day]][[year][ordinal]]] [hour]"
+        ),
+        fd!(
+            version = 2,
+            "[first [[hour]:[minute]][[hour]h]][optional [ [first [UTC][[offset_hour]]]]]"
+        ),
+        fd!(
+            version = 2,
+            r"[period case:lower] [hour repr:12 padding:none]\[[minute]\]"
+        ),
+        fd!(
+            version = 2,
+            "[unix_timestamp precision:millisecond][ignore count:3]"
+        ),
+    ];
+
+    let odt_values = [
+        datetime!(2023-05-10 12:34:56.789 +05:30),
+        datetime!(2023-05-10 12:34:56.789 -02:00),
+        datetime!(1999-12-31 23:59:59 UTC),
+    ];
+
+    for description in descriptions {
+        let owned = OwnedFormatItem::from(description);
+        let compiled = CompiledFormat::from(description);
+        assert_eq!(compiled, CompiledFormat::from(&owned));
+
+        for odt in odt_values {
+            assert_eq!(odt.format(&compiled)?, odt.format(&owned)?);
+            assert_eq!(odt.format(&compiled)?, odt.format(description)?);
+        }
+        let pdt = odt_values[0].date().with_time(odt_values[0].time());
+        let date = odt_values[0].date();
+        let time = odt_values[0].time();
+        assert_eq!(pdt.format(&compiled).ok(), pdt.format(description).ok());
+        assert_eq!(date.format(&compiled).ok(), date.format(description).ok());
+        assert_eq!(time.format(&compiled).ok(), time.format(description).ok());
+    }
+
+    assert_eq!(
+        Time::MIDNIGHT.format(&CompiledFormat::from(FormatItem::First(&[])))?,
+        ""
+    );
+    assert_eq!(
+        Time::MIDNIGHT.format(&CompiledFormat::from(OwnedFormatItem::Optional(Box::new(
+            OwnedFormatItem::First(Box::new([]))
+        ))))?,
+        ""
+    );
+
     Ok(())
 }
 
@@ -765,5 +1951,175 @@ fn unix_timestamp() -> time::Result<()> {
         "-1"
     );
 
+    assert_eq!(
+        dt.format(&fd!("[unix_timestamp decimal:true]"))?,
+        "1234567890"
+    );
+    assert_eq!(
+        dt.format(&fd!("[unix_timestamp precision:millisecond decimal:true]"))?,
+        "1234567890.123"
+    );
+    assert_eq!(
+        dt.format(&fd!("[unix_timestamp precision:microsecond decimal:true]"))?,
+        "1234567890.123456"
+    );
+    assert_eq!(
+        dt.format(&fd!("[unix_timestamp precision:nanosecond decimal:true]"))?,
+        "1234567890.123456789"
+    );
+    assert_eq!(
+        datetime!(2023-11-14 22:13:20.000_000_001 UTC).format(&fd!(
+            "[unix_timestamp precision:nanosecond sign:mandatory decimal:true]"
+        ))?,
+        "+1700000000.000000001"
+    );
+
+    let dt = datetime!(1969-12-31 23:59:58.5 UTC);
+    assert_eq!(
+        dt.format(&fd!("[unix_timestamp precision:millisecond]"))?,
+        "-1500"
+    );
+    assert_eq!(
+        dt.format(&fd!("[unix_timestamp precision:millisecond decimal:true]"))?,
+        "-1.500"
+    );
+    assert_eq!(
+        datetime!(1900-01-01 0:00 UTC).format(&fd!("[unix_timestamp precision:millisecond]"))?,
+        "-2208988800000"
+    );
+    assert_eq!(
+        datetime!(9999-12-31 23:59:59.999 UTC)
+            .format(&fd!("[unix_timestamp precision:millisecond]"))?,
+        "253402300799999"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn format_into_fmt() {
+    struct Wrapper<T>(T, &'static [FormatItem<'static>]);
+
+    macro_rules! impl_display {
+        ($($t:ty),+) => {$(
+            impl fmt::Display for Wrapper<$t> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    self.0.format_into_fmt(f, self.1).map(|_| ()).map_err(|_| fmt::Error)
+                }
+            }
+        )+};
+    }
+    impl_display!(Date, Time, PrimitiveDateTime, OffsetDateTime);
+
+    assert_eq!(
+        format!("{}", Wrapper(date!(2021-01-02), fd!("[year]-[month]-[day]"))),
+        "2021-01-02"
+    );
+    assert_eq!(
+        format!("[{}]", Wrapper(time!(3:04:05), fd!("[hour]:[minute]:[second]"))),
+        "[03:04:05]"
+    );
+    assert_eq!(
+        Wrapper(
+            datetime!(2021-01-02 03:04:05),
+            fd!("[year]-[month]-[day] [hour]:[minute]:[second]")
+        )
+        .to_string(),
+        "2021-01-02 03:04:05"
+    );
+    assert_eq!(
+        Wrapper(
+            datetime!(2021-01-02 03:04:05 +06:07),
+            fd!("[year]-[month]-[day] [hour]:[minute] [offset_hour sign:mandatory]")
+        )
+        .to_string(),
+        "2021-01-02 03:04 +06"
+    );
+
+    let mut s = String::new();
+    assert!(write!(s, "{}", Wrapper(date!(2021-01-02), fd!("[hour]"))).is_err());
+}
+
+#[test]
+fn display() {
+    let datetime = datetime!(2021-01-02 03:04:05 +06:07);
+
+    assert_eq!(
+        format!("{}", date!(2021-01-02).display(fd!("[year]-[month]-[day]"))),
+        "2021-01-02"
+    );
+    assert_eq!(
+        format!("[{}]", time!(3:04:05).display(fd!("[hour]:[minute]:[second]"))),
+        "[03:04:05]"
+    );
+    assert_eq!(
+        PrimitiveDateTime::new(datetime.date(), datetime.time())
+            .display(fd!("[year]-[month]-[day] [hour]:[minute]"))
+            .to_string(),
+        "2021-01-02 03:04"
+    );
+    assert_eq!(
+        datetime.display(&Rfc3339).to_string(),
+        "2021-01-02T03:04:05+06:07"
+    );
+    assert_eq!(
+        offset!(-1:02).display(fd!("[offset_hour]:[offset_minute]")).to_string(),
+        "-01:02"
+    );
+
+    let formatted = date!(2021-01-02).display(fd!("[year] [hour]"));
+    assert!(formatted.take_error().is_none());
+    assert!(write!(String::new(), "{formatted}").is_err());
+    assert!(matches!(
+        formatted.take_error(),
+        Some(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(formatted.take_error().is_none());
+    assert_eq!(
+        format!("{:?}", time!(0:00).display(&Rfc3339)),
+        "Formatted { date: None, time: Some(00:00:00.0), offset: None, .. }"
+    );
+}
+
+#[test]
+fn format_into_fmt_write() -> time::Result<()> {
+    /// A writer that accepts a limited number of bytes before failing.
+    struct Limited(usize);
+
+    impl fmt::Write for Limited {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 = self.0.checked_sub(s.len()).ok_or(fmt::Error)?;
+            Ok(())
+        }
+    }
+
+    let mut s = String::new();
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format_into_fmt(&mut s, &Rfc3339)?,
+        20
+    );
+    assert_eq!(
+        offset!(-1:02).format_into_fmt(&mut s, fd!(" [offset_hour]:[offset_minute]"))?,
+        7
+    );
+    assert_eq!(s, "2021-01-02T03:04:05Z -01:02");
+    assert!(matches!(
+        time!(3:04).format_into_fmt(&mut s, &FormatItem::Literal(b" \xff")),
+        Err(time::error::Format::StdIo(_))
+    ));
+    assert_eq!(
+        time!(3:04).format(&FormatItem::Literal(b"a\xffb"))?,
+        "a\u{fffd}b"
+    );
+
+    assert!(matches!(
+        date!(2021-01-02).format_into_fmt(&mut Limited(4), fd!("[year]-[month]")),
+        Err(time::error::Format::Fmt(fmt::Error))
+    ));
+    assert!(matches!(
+        date!(2021-01-02).format_into_fmt(&mut Limited(7), fd!("[year]-[month]")),
+        Ok(7)
+    ));
+
     Ok(())
 }
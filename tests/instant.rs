@@ -234,3 +234,25 @@ fn borrow() {
         <Instant as Borrow<StdInstant>>::borrow(&now)
     );
 }
+
+#[test]
+fn reference_ops() {
+    let instant = Instant::now();
+    let std_instant = StdInstant::now();
+    assert_ref_binop!(instant, +, 5.seconds());
+    assert_ref_binop!(instant, +, 5.std_seconds());
+    assert_ref_binop!(std_instant, +, 5.seconds());
+    assert_ref_binop!(instant, -, 5.seconds());
+    assert_ref_binop!(instant, -, 5.std_seconds());
+    assert_ref_binop!(std_instant, -, 5.seconds());
+    assert_ref_binop!(instant, -, Instant::now());
+    assert_ref_binop!(instant, -, std_instant);
+    assert_ref_binop!(std_instant, -, instant);
+
+    assert_ref_op_assign!(instant, +=, 5.seconds());
+    assert_ref_op_assign!(instant, +=, 5.std_seconds());
+    assert_ref_op_assign!(std_instant, +=, 5.seconds());
+    assert_ref_op_assign!(instant, -=, 5.seconds());
+    assert_ref_op_assign!(instant, -=, 5.std_seconds());
+    assert_ref_op_assign!(std_instant, -=, 5.seconds());
+}
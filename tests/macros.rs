@@ -2,8 +2,9 @@ use core::num::NonZeroU16;
 
 use time::format_description::modifier::*;
 use time::format_description::{Component, FormatItem};
-use time::macros::{date, format_description, time};
-use time::{Date, Time};
+use time::ext::{NumericalDuration, NumericalStdDuration};
+use time::macros::{date, datetime, duration, format_description, offset, time};
+use time::{Date, Duration, Month, PrimitiveDateTime, Result, Time, UtcOffset};
 
 #[test]
 fn nontrivial_string() {
@@ -210,6 +211,17 @@ fn format_description_coverage() {
             })))
         ]
     );
+    assert_eq!(
+        format_description!("[day repr:numerical][day repr:ordinal]"),
+        &[
+            FormatItem::Component(Component::Day(modifier!(Day {
+                repr: DayRepr::Numerical,
+            }))),
+            FormatItem::Component(Component::Day(modifier!(Day {
+                repr: DayRepr::Ordinal,
+            })))
+        ]
+    );
     assert_eq!(
         format_description!(
             "[offset_minute padding:space][offset_minute padding:zero][offset_minute padding:none]"
@@ -309,6 +321,15 @@ fn format_description_coverage() {
             }
         )))]
     );
+    assert_eq!(
+        format_description!("[unix_timestamp precision:millisecond decimal:true]"),
+        &[FormatItem::Component(Component::UnixTimestamp(modifier!(
+            UnixTimestamp {
+                precision: UnixTimestampPrecision::Millisecond,
+                decimal: true,
+            }
+        )))]
+    );
 }
 
 #[test]
@@ -323,4 +344,86 @@ fn date_coverage() {
 fn time_coverage() {
     assert_eq!(time!(12 AM), Time::MIDNIGHT);
     assert_eq!(Ok(time!(12 PM)), Time::from_hms(12, 0, 0));
+    assert_eq!(Ok(time!(0:00:00.5)), Time::from_hms_milli(0, 0, 0, 500));
+    assert_eq!(Ok(time!(0:00:00.000_001)), Time::from_hms_micro(0, 0, 0, 1));
+    assert_eq!(
+        Ok(time!(23:59:59.999_999_999)),
+        Time::from_hms_nano(23, 59, 59, 999_999_999)
+    );
+    assert_eq!(
+        Ok(time!(1:02:03.123456789)),
+        Time::from_hms_nano(1, 2, 3, 123_456_789)
+    );
+    assert_eq!(
+        Ok(time!(1:02:03.000000001)),
+        Time::from_hms_nano(1, 2, 3, 1)
+    );
+}
+
+#[test]
+fn offset_coverage() {
+    assert_eq!(Ok(offset!(+00:19:32)), UtcOffset::from_hms(0, 19, 32));
+    assert_eq!(Ok(offset!(-00:19:32)), UtcOffset::from_hms(0, -19, -32));
+    assert_eq!(Ok(offset!(+23:59:59)), UtcOffset::from_hms(23, 59, 59));
+    assert_eq!(Ok(offset!(-0:00:01)), UtcOffset::from_hms(0, 0, -1));
+}
+
+#[test]
+fn datetime_coverage() -> Result<()> {
+    let amsterdam = UtcOffset::from_hms(0, 19, 32)?;
+    let datetime = datetime!(1937-07-01 0:00 +00:19:32);
+    assert_eq!(
+        datetime,
+        Date::from_calendar_date(1937, Month::July, 1)?
+            .midnight()
+            .assume_offset(amsterdam)
+    );
+    assert_eq!(datetime.offset(), amsterdam);
+    assert_eq!(
+        datetime!(2000-01-01 12:34:56.789_012_345),
+        PrimitiveDateTime::new(
+            Date::from_calendar_date(2000, Month::January, 1)?,
+            Time::from_hms_nano(12, 34, 56, 789_012_345)?
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn duration_coverage() {
+    const DURATION: Duration = duration!(2 h 30 m);
+    assert_eq!(DURATION, 2.hours() + 30.minutes());
+
+    assert_eq!(duration!(0 s), Duration::ZERO);
+    assert_eq!(duration!(1 w), 1.weeks());
+    assert_eq!(duration!(2 d), 2.days());
+    assert_eq!(duration!(250 ms), 250.milliseconds());
+    assert_eq!(duration!(7 us), 7.microseconds());
+    assert_eq!(duration!(9 ns), 9.nanoseconds());
+    assert_eq!(
+        duration!(1 w 2 d 3 h 4 m 5 s 6 ms 7 us 8 ns),
+        1.weeks()
+            + 2.days()
+            + 3.hours()
+            + 4.minutes()
+            + 5.seconds()
+            + 6.milliseconds()
+            + 7.microseconds()
+            + 8.nanoseconds()
+    );
+    assert_eq!(duration!(1h 30m), duration!(1 h 30 m));
+    assert_eq!(duration!(1_000 ms), 1.seconds());
+    assert_eq!(duration!(1.5 h), 1.5.hours());
+    assert_eq!(duration!(0.000_000_001 s), 1.nanoseconds());
+    assert_eq!(duration!(1.25d), 30.hours());
+    assert_eq!(duration!(-1 m 30 s), (-90).seconds());
+    assert_eq!(
+        duration!(9_223_372_036_854_775_807 s 999_999_999 ns),
+        Duration::MAX
+    );
+    assert_eq!(
+        duration!(-9_223_372_036_854_775_808 s 999_999_999 ns),
+        Duration::MIN
+    );
+    assert_eq!(duration!(2 m).unsigned_abs(), 2.std_minutes());
 }
@@ -2,7 +2,6 @@
     anonymous_parameters,
     clippy::all,
     clippy::undocumented_unsafe_blocks,
-    illegal_floating_point_literal_pattern,
     late_bound_lifetime_arguments,
     path_statements,
     patterns_in_fns_without_body,
@@ -64,7 +63,7 @@ fn run_with_all_features() -> Result<(), Box<dyn std::error::Error>> {
     impl std::error::Error for Error {}
 
     let status = std::process::Command::new("cargo")
-        .args(&["test", "--all-features"])
+        .args(["test", "--all-features"])
         .status()?;
 
     return if status.success() {
@@ -131,6 +130,83 @@ require_all_features! {
         }
     }
 
+    /// Assert that the given expression panics at the call site, with a message containing the
+    /// `Debug` representation of each provided operand.
+    macro_rules! assert_panic_with_operands {
+        ($e:expr, $($operand:expr),+ $(,)?) => {{
+            let (message, location) = crate::catch_panic(|| {
+                let _ = $e;
+            });
+            let expected_location = format!("{}:{}:", file!(), line!());
+            assert!(
+                location.starts_with(&expected_location),
+                "panicked at {location}, expected {expected_location}"
+            );
+            $(
+                let operand = format!("{:?}", $operand);
+                assert!(message.contains(&operand), "{message:?} does not contain {operand:?}");
+            )+
+        }};
+    }
+
+    /// Assert that applying the binary operator to references of the operands, in every
+    /// combination, gives the same result as applying it to the values.
+    macro_rules! assert_ref_binop {
+        ($lhs:expr, $op:tt, $rhs:expr) => {{
+            let (lhs, rhs) = ($lhs, $rhs);
+            let expected = lhs $op rhs;
+            assert_eq!(&lhs $op rhs, expected);
+            assert_eq!(lhs $op &rhs, expected);
+            assert_eq!(&lhs $op &rhs, expected);
+        }};
+    }
+
+    /// Assert that the assignment operator gives the same result with a reference to the operand
+    /// as it does with the value.
+    macro_rules! assert_ref_op_assign {
+        ($lhs:expr, $op:tt, $rhs:expr) => {{
+            let rhs = $rhs;
+            let mut expected = $lhs;
+            expected $op rhs;
+            let mut value = $lhs;
+            value $op &rhs;
+            assert_eq!(value, expected);
+        }};
+    }
+
+    /// Run the closure, returning the message and location of the panic it causes.
+    fn catch_panic(f: impl FnOnce()) -> (String, String) {
+        use std::cell::RefCell;
+        use std::panic::{self, AssertUnwindSafe};
+        use std::sync::Once;
+
+        thread_local! {
+            static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+        }
+
+        static INSTALL_HOOK: Once = Once::new();
+        INSTALL_HOOK.call_once(|| {
+            let previous_hook = panic::take_hook();
+            panic::set_hook(Box::new(move |info| {
+                LOCATION.with(|location| {
+                    *location.borrow_mut() = info.location().map(ToString::to_string);
+                });
+                previous_hook(info);
+            }));
+        });
+
+        let payload = panic::catch_unwind(AssertUnwindSafe(f)).expect_err("expected a panic");
+        let message = match payload.downcast::<String>() {
+            Ok(message) => *message,
+            Err(payload) => payload
+                .downcast::<&str>()
+                .map_or_else(|_| String::new(), |message| (*message).to_owned()),
+        };
+        let location = LOCATION.with(|location| location.borrow_mut().take());
+        (message, location.unwrap_or_default())
+    }
+
+    mod allocation;
     mod date;
     mod derives;
     mod duration;
@@ -142,6 +218,7 @@ require_all_features! {
     mod macros;
     mod meta;
     mod month;
+    mod now;
     mod offset_date_time;
     mod parse_format_description;
     mod parsed;
@@ -163,5 +240,6 @@ require_all_features! {
         let tests = trybuild::TestCases::new();
         // Path is relative from `time/Cargo.toml`.
         tests.compile_fail("../tests/compile-fail/*.rs");
+        tests.pass("../tests/compile-pass/*.rs");
     }
 }
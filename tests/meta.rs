@@ -14,9 +14,9 @@ use quickcheck::Arbitrary;
 use rand::distributions::{Distribution, Standard};
 use serde::{Deserialize, Serialize};
 use time::format_description::well_known::iso8601;
-use time::format_description::{modifier, well_known, Component, FormatItem};
-use time::formatting::Formattable;
-use time::parsing::{Parsable, Parsed};
+use time::format_description::{modifier, well_known, Component, FormatItem, ParseLimits};
+use time::formatting::{ArrayBuf, Formattable, Formatted};
+use time::parsing::{Parsable, Parsed, ParsedComponentValue};
 use time::{
     error, ext, Date, Duration, Error, Instant, Month, OffsetDateTime, PrimitiveDateTime, Time,
     UtcOffset, Weekday,
@@ -48,44 +48,59 @@ fn alignment() {
     assert_alignment!(modifier::Day, 1);
     assert_alignment!(modifier::Hour, 1);
     assert_alignment!(modifier::Minute, 1);
-    assert_alignment!(modifier::Month, 1);
+    assert_alignment!(modifier::Month, 8);
     assert_alignment!(modifier::OffsetHour, 1);
     assert_alignment!(modifier::OffsetMinute, 1);
     assert_alignment!(modifier::OffsetSecond, 1);
+    assert_alignment!(modifier::Offset, 1);
     assert_alignment!(modifier::Ordinal, 1);
     assert_alignment!(modifier::Period, 1);
     assert_alignment!(modifier::Second, 1);
     assert_alignment!(modifier::Subsecond, 1);
     assert_alignment!(modifier::WeekNumber, 1);
-    assert_alignment!(modifier::Weekday, 1);
+    assert_alignment!(modifier::Weekday, 8);
     assert_alignment!(modifier::Year, 1);
     assert_alignment!(well_known::Rfc2822, 1);
+    assert_alignment!(well_known::JsDate, 1);
     assert_alignment!(well_known::Rfc3339, 1);
+    assert_alignment!(well_known::Rfc3339NoZ, 1);
+    assert_alignment!(well_known::Rfc3339Lenient, 1);
+    assert_alignment!(well_known::Rfc7231, 1);
+    assert_alignment!(well_known::Systemd, 1);
+    assert_alignment!(well_known::Unix, 1);
+    assert_alignment!(well_known::Iso8601Duration, 1);
     assert_alignment!(
         well_known::Iso8601<{ iso8601::Config::DEFAULT.encode() }>,
         1
     );
+    assert_alignment!(well_known::Iso8601Dynamic, 1);
     assert_alignment!(iso8601::Config, 1);
     assert_alignment!(iso8601::DateKind, 1);
     assert_alignment!(iso8601::FormattedComponents, 1);
     assert_alignment!(iso8601::OffsetPrecision, 1);
     assert_alignment!(iso8601::TimePrecision, 1);
-    assert_alignment!(Parsed, 8);
+    assert_alignment!(Parsed, 16);
+    assert_alignment!(ParsedComponentValue, 16);
+    assert_alignment!(ArrayBuf<32>, 8);
     assert_alignment!(Month, 1);
     assert_alignment!(Weekday, 1);
     assert_alignment!(Error, 8);
     assert_alignment!(error::Format, 8);
     assert_alignment!(error::InvalidFormatDescription, 8);
+    assert_alignment!(error::MissingComponents, 2);
     assert_alignment!(error::Parse, 8);
     assert_alignment!(error::ParseFromDescription, 8);
     assert_alignment!(error::TryFromParsed, 8);
-    assert_alignment!(Component, 2);
+    assert_alignment!(Component, 8);
     assert_alignment!(FormatItem<'_>, 8);
-    assert_alignment!(modifier::MonthRepr, 1);
+    assert_alignment!(ParseLimits, 8);
+    assert_alignment!(modifier::DayRepr, 1);
+    assert_alignment!(modifier::MonthRepr, 8);
+    assert_alignment!(modifier::OffsetSeparator, 1);
     assert_alignment!(modifier::Padding, 1);
     assert_alignment!(modifier::SubsecondDigits, 1);
     assert_alignment!(modifier::WeekNumberRepr, 1);
-    assert_alignment!(modifier::WeekdayRepr, 1);
+    assert_alignment!(modifier::WeekdayRepr, 8);
     assert_alignment!(modifier::YearRepr, 1);
 }
 
@@ -122,48 +137,63 @@ fn size() {
     assert_size!(error::ConversionRange, 0, 1);
     assert_size!(error::DifferentVariant, 0, 1);
     assert_size!(error::IndeterminateOffset, 0, 1);
-    assert_size!(modifier::Day, 1, 1);
+    assert_size!(modifier::Day, 2, 2);
     assert_size!(modifier::Hour, 2, 2);
     assert_size!(modifier::Minute, 1, 1);
-    assert_size!(modifier::Month, 3, 3);
-    assert_size!(modifier::OffsetHour, 2, 2);
-    assert_size!(modifier::OffsetMinute, 1, 1);
+    assert_size!(modifier::Month, 24, 24);
+    assert_size!(modifier::OffsetHour, 3, 3);
+    assert_size!(modifier::OffsetMinute, 2, 2);
     assert_size!(modifier::OffsetSecond, 1, 1);
+    assert_size!(modifier::Offset, 3, 3);
     assert_size!(modifier::Ordinal, 1, 1);
     assert_size!(modifier::Period, 2, 2);
     assert_size!(modifier::Second, 1, 1);
     assert_size!(modifier::Subsecond, 1, 1);
     assert_size!(modifier::WeekNumber, 2, 2);
-    assert_size!(modifier::Weekday, 3, 3);
+    assert_size!(modifier::Weekday, 24, 24);
     assert_size!(modifier::Year, 4, 4);
     assert_size!(well_known::Rfc2822, 0, 1);
+    assert_size!(well_known::JsDate, 0, 1);
     assert_size!(well_known::Rfc3339, 0, 1);
+    assert_size!(well_known::Rfc3339NoZ, 0, 1);
+    assert_size!(well_known::Rfc3339Lenient, 0, 1);
+    assert_size!(well_known::Rfc7231, 0, 1);
+    assert_size!(well_known::Systemd, 0, 1);
+    assert_size!(well_known::Unix, 0, 1);
+    assert_size!(well_known::Iso8601Duration, 2, 2);
     assert_size!(
         well_known::Iso8601<{ iso8601::Config::DEFAULT.encode() }>,
         0,
         1
     );
-    assert_size!(iso8601::Config, 7, 7);
+    assert_size!(well_known::Iso8601Dynamic, 8, 8);
+    assert_size!(iso8601::Config, 8, 8);
     assert_size!(iso8601::DateKind, 1, 1);
     assert_size!(iso8601::FormattedComponents, 1, 1);
     assert_size!(iso8601::OffsetPrecision, 1, 1);
     assert_size!(iso8601::TimePrecision, 2, 2);
-    assert_size!(Parsed, 56, 56);
+    assert_size!(Parsed, 64, 64);
+    assert_size!(ParsedComponentValue, 32, 32);
+    assert_size!(ArrayBuf<32>, 40, 48);
     assert_size!(Month, 1, 1);
     assert_size!(Weekday, 1, 1);
     assert_size!(Error, 56, 56);
     assert_size!(error::Format, 24, 24);
     assert_size!(error::InvalidFormatDescription, 48, 48);
+    assert_size!(error::MissingComponents, 2, 4);
     assert_size!(error::Parse, 48, 48);
     assert_size!(error::ParseFromDescription, 16, 24);
     assert_size!(error::TryFromParsed, 48, 48);
-    assert_size!(Component, 6, 6); // TODO Size is 4 starting with rustc 1.71.
-    assert_size!(FormatItem<'_>, 24, 24);
-    assert_size!(modifier::MonthRepr, 1, 1);
+    assert_size!(Component, 32, 32);
+    assert_size!(FormatItem<'_>, 32, 32);
+    assert_size!(ParseLimits, 16, 24);
+    assert_size!(modifier::DayRepr, 1, 1);
+    assert_size!(modifier::MonthRepr, 16, 16);
+    assert_size!(modifier::OffsetSeparator, 1, 1);
     assert_size!(modifier::Padding, 1, 1);
     assert_size!(modifier::SubsecondDigits, 1, 1);
     assert_size!(modifier::WeekNumberRepr, 1, 1);
-    assert_size!(modifier::WeekdayRepr, 1, 1);
+    assert_size!(modifier::WeekdayRepr, 16, 16);
     assert_size!(modifier::YearRepr, 1, 1);
 }
 
@@ -579,6 +609,19 @@ assert_impl! { modifier::OffsetSecond:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::Offset:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Offset>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::Ordinal:
     Clone,
     Debug,
@@ -684,6 +727,62 @@ assert_impl! { well_known::Rfc2822:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { well_known::JsDate:
+    Clone,
+    Debug,
+    PartialEq<well_known::JsDate>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::Rfc7231:
+    Clone,
+    Debug,
+    PartialEq<well_known::Rfc7231>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::Systemd:
+    Clone,
+    Debug,
+    PartialEq<well_known::Systemd>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::Unix:
+    Clone,
+    Debug,
+    PartialEq<well_known::Unix>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { well_known::Rfc3339:
     Clone,
     Debug,
@@ -698,6 +797,34 @@ assert_impl! { well_known::Rfc3339:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { well_known::Rfc3339NoZ:
+    Clone,
+    Debug,
+    PartialEq<well_known::Rfc3339NoZ>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::Rfc3339Lenient:
+    Clone,
+    Debug,
+    PartialEq<well_known::Rfc3339Lenient>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { well_known::Iso8601::<{ iso8601::Config::DEFAULT.encode() }>:
     Clone,
     Debug,
@@ -712,8 +839,54 @@ assert_impl! { well_known::Iso8601::<{ iso8601::Config::DEFAULT.encode() }>:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { well_known::Iso8601Dynamic:
+    Clone,
+    Debug,
+    PartialEq<well_known::Iso8601Dynamic>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    From<well_known::Iso8601<{ iso8601::Config::DEFAULT.encode() }>>,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { well_known::Iso8601Duration:
+    Clone,
+    Debug,
+    Default,
+    Hash,
+    PartialEq<well_known::Iso8601Duration>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { ParseLimits:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<ParseLimits>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { iso8601::Config:
+    Clone,
     Debug,
+    PartialEq<iso8601::Config>,
+    Copy,
+    Eq,
     RefUnwindSafe,
     Send,
     Sync,
@@ -778,6 +951,41 @@ assert_impl! { Parsed:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { ParsedComponentValue:
+    Clone,
+    Debug,
+    Hash,
+    PartialEq<ParsedComponentValue>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { ArrayBuf<32>:
+    AsRef<str>,
+    AsRef<[u8]>,
+    Clone,
+    Debug,
+    Display,
+    PartialEq<ArrayBuf<32>>,
+    PartialEq<str>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { Formatted<'static, well_known::Rfc3339>:
+    Debug,
+    Display,
+    Send,
+    Unpin,
+}
 assert_impl! { @'a; Month:
     Arbitrary,
     Clone,
@@ -804,6 +1012,7 @@ assert_impl! { @'a; Weekday:
     Hash,
     PartialEq<Weekday>,
     Serialize,
+    TryFrom<u8, Error = error::ComponentRange>,
     Copy,
     Eq,
     RefUnwindSafe,
@@ -903,9 +1112,24 @@ assert_impl! { error::TryFromParsed:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { error::MissingComponents:
+    Clone,
+    Debug,
+    Display,
+    PartialEq<error::MissingComponents>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { @'a; Component:
     Clone,
     Debug,
+    Display,
+    Hash,
     PartialEq<Component>,
     PartialEq<FormatItem<'a>>,
     TryFrom<FormatItem<'a>, Error = error::DifferentVariant>,
@@ -920,6 +1144,8 @@ assert_impl! { @'a; Component:
 assert_impl! { @'a; FormatItem<'_>:
     Clone,
     Debug,
+    Display,
+    Hash,
     From<&'a [FormatItem<'a>]>,
     From<Component>,
     PartialEq<&'a [FormatItem<'a>]>,
@@ -938,10 +1164,25 @@ assert_impl! { @'a; &[FormatItem<'_>]:
     PartialEq<FormatItem<'a>>,
     TryFrom<FormatItem<'a>, Error = error::DifferentVariant>,
 }
+assert_impl! { modifier::DayRepr:
+    Clone,
+    Debug,
+    Default,
+    Hash,
+    PartialEq<modifier::DayRepr>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::MonthRepr:
     Clone,
     Debug,
     Default,
+    Hash,
     PartialEq<modifier::MonthRepr>,
     Copy,
     Eq,
@@ -951,6 +1192,19 @@ assert_impl! { modifier::MonthRepr:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::OffsetSeparator:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::OffsetSeparator>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::Padding:
     Clone,
     Debug,
@@ -32,6 +32,38 @@ fn next() {
     assert_eq!(December.next(), January);
 }
 
+#[test]
+fn name() {
+    assert_eq!(January.name(), "January");
+    assert_eq!(February.name(), "February");
+    assert_eq!(March.name(), "March");
+    assert_eq!(April.name(), "April");
+    assert_eq!(May.name(), "May");
+    assert_eq!(June.name(), "June");
+    assert_eq!(July.name(), "July");
+    assert_eq!(August.name(), "August");
+    assert_eq!(September.name(), "September");
+    assert_eq!(October.name(), "October");
+    assert_eq!(November.name(), "November");
+    assert_eq!(December.name(), "December");
+}
+
+#[test]
+fn short_name() {
+    assert_eq!(January.short_name(), "Jan");
+    assert_eq!(February.short_name(), "Feb");
+    assert_eq!(March.short_name(), "Mar");
+    assert_eq!(April.short_name(), "Apr");
+    assert_eq!(May.short_name(), "May");
+    assert_eq!(June.short_name(), "Jun");
+    assert_eq!(July.short_name(), "Jul");
+    assert_eq!(August.short_name(), "Aug");
+    assert_eq!(September.short_name(), "Sep");
+    assert_eq!(October.short_name(), "Oct");
+    assert_eq!(November.short_name(), "Nov");
+    assert_eq!(December.short_name(), "Dec");
+}
+
 #[test]
 fn display() {
     assert_eq!(January.to_string(), "January");
@@ -98,3 +130,96 @@ fn try_from_u8() {
     assert_eq!(Month::try_from(12), Ok(December));
     assert!(matches!(Month::try_from(13), Err(err) if err.name() == "month"));
 }
+
+#[test]
+fn number() {
+    let months = [
+        January, February, March, April, May, June, July, August, September, October, November,
+        December,
+    ];
+    for (month, number) in months.into_iter().zip(1..) {
+        assert_eq!(month.number(), number);
+        assert_eq!(u8::from(month), number);
+        assert_eq!(Month::from_number(number), Ok(month));
+        assert_eq!(Month::try_from(number), Ok(month));
+    }
+
+    // Every value that is accepted round trips, and every value outside the range is rejected.
+    for number in 0..=u8::MAX {
+        let expected = (1..=12).contains(&number).then_some(number);
+        assert_eq!(Month::from_number(number).ok().map(Month::number), expected);
+        assert_eq!(Month::try_from(number).ok().map(u8::from), expected);
+    }
+
+    // All conversions are usable in const contexts.
+    const JULY: u8 = July.number();
+    const DECEMBER: Result<Month, time::error::ComponentRange> = Month::from_number(12);
+    assert_eq!(JULY, 7);
+    assert_eq!(DECEMBER, Ok(December));
+    assert!(matches!(Month::from_number(0), Err(err) if err.name() == "month"));
+}
+
+#[test]
+fn overflowing_previous() {
+    assert_eq!(January.overflowing_previous(), (December, true));
+    assert_eq!(February.overflowing_previous(), (January, false));
+    assert_eq!(July.overflowing_previous(), (June, false));
+    assert_eq!(December.overflowing_previous(), (November, false));
+}
+
+#[test]
+fn overflowing_next() {
+    assert_eq!(January.overflowing_next(), (February, false));
+    assert_eq!(June.overflowing_next(), (July, false));
+    assert_eq!(November.overflowing_next(), (December, false));
+    assert_eq!(December.overflowing_next(), (January, true));
+}
+
+#[test]
+fn nth_next_with_years() {
+    for number in 1..=12 {
+        let start = Month::try_from(number).expect("month is in range");
+        let (mut month, mut years) = (start, 0);
+        for n in 0..=100 {
+            assert_eq!(start.nth_next_with_years(n), (month, years));
+            assert_eq!(start.nth_with_years(n as i32), (month, years as i32));
+            let (next, overflowed) = month.overflowing_next();
+            month = next;
+            years += u32::from(overflowed);
+        }
+    }
+
+    assert_eq!(January.nth_next_with_years(11), (December, 0));
+    assert_eq!(January.nth_next_with_years(12), (January, 1));
+    assert_eq!(December.nth_next_with_years(1), (January, 1));
+    assert_eq!(
+        January.nth_next_with_years(u32::MAX),
+        (April, u32::MAX / 12)
+    );
+    assert_eq!(
+        December.nth_next_with_years(u32::MAX),
+        (March, u32::MAX / 12 + 1)
+    );
+}
+
+#[test]
+fn nth_with_years() {
+    for number in 1..=12 {
+        let start = Month::try_from(number).expect("month is in range");
+        let (mut month, mut years) = (start, 0);
+        for n in 0..=100 {
+            assert_eq!(start.nth_with_years(-n), (month, years));
+            let (previous, overflowed) = month.overflowing_previous();
+            month = previous;
+            years -= i32::from(overflowed);
+        }
+    }
+
+    assert_eq!(January.nth_with_years(-1), (December, -1));
+    assert_eq!(December.nth_with_years(-11), (January, 0));
+    assert_eq!(December.nth_with_years(-12), (December, -1));
+    assert_eq!(January.nth_with_years(i32::MAX), (August, i32::MAX / 12));
+    assert_eq!(December.nth_with_years(i32::MAX), (July, i32::MAX / 12 + 1));
+    assert_eq!(January.nth_with_years(i32::MIN), (May, i32::MIN / 12 - 1));
+    assert_eq!(December.nth_with_years(i32::MIN), (April, i32::MIN / 12));
+}
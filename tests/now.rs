@@ -0,0 +1,59 @@
+use std::collections::HashSet;
+use std::thread;
+
+use time::now::HybridClock;
+use time::{Duration, OffsetDateTime};
+
+#[test]
+fn strictly_increasing() {
+    let clock = HybridClock::new();
+    let mut previous = clock.next();
+    for _ in 0..10_000 {
+        let next = clock.next();
+        assert!(next > previous);
+        previous = next;
+    }
+}
+
+#[test]
+fn strictly_increasing_across_threads() {
+    const THREADS: usize = 8;
+    const CALLS: usize = 10_000;
+
+    let clock = HybridClock::new();
+    let values = thread::scope(|scope| {
+        let handles = (0..THREADS)
+            .map(|_| scope.spawn(|| (0..CALLS).map(|_| clock.next()).collect::<Vec<_>>()))
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .map(|handle| handle.join().expect("thread panicked"))
+            .collect::<Vec<_>>()
+    });
+
+    for values in &values {
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+    let unique = values.iter().flatten().collect::<HashSet<_>>();
+    assert_eq!(unique.len(), THREADS * CALLS);
+}
+
+#[test]
+fn bounded_divergence() {
+    const CALLS: i64 = 10_000;
+
+    let clock = HybridClock::new();
+    for _ in 0..CALLS {
+        let before = OffsetDateTime::now_utc();
+        let value = clock.next();
+        let after = OffsetDateTime::now_utc();
+        assert!(value >= before);
+        assert!(value <= after + Duration::nanoseconds(CALLS));
+    }
+}
+
+#[test]
+fn default() {
+    let clock = HybridClock::default();
+    assert!(clock.next() < clock.next());
+}
@@ -93,6 +93,72 @@ fn from_unix_timestamp_nanos() {
     assert!(OffsetDateTime::from_unix_timestamp_nanos(i128::MAX).is_err());
 }
 
+#[test]
+fn from_julian_date() {
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_451_545.0),
+        Ok(datetime!(2000-01-01 12:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_440_587.5),
+        Ok(OffsetDateTime::UNIX_EPOCH)
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_400_000.5),
+        Ok(datetime!(1858-11-17 0:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(0.0),
+        Ok(datetime!(-4713-11-24 12:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(2_451_545.25),
+        Ok(datetime!(2000-01-01 18:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(-363_521_074.5),
+        Ok(datetime!(-999_999-01-01 0:00 UTC))
+    );
+
+    // Rounded to the nearest microsecond.
+    assert_eq!(
+        OffsetDateTime::from_julian_date(0.5 + 0.4 / 86_400_000_000.),
+        Ok(datetime!(-4713-11-25 0:00 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(0.5 + 0.6 / 86_400_000_000.),
+        Ok(datetime!(-4713-11-25 0:00:00.000_001 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(0.5 - 0.6 / 86_400_000_000.),
+        Ok(datetime!(-4713-11-24 23:59:59.999_999 UTC))
+    );
+    assert_eq!(
+        OffsetDateTime::from_julian_date(0.5 - 0.4 / 86_400_000_000.),
+        Ok(datetime!(-4713-11-25 0:00 UTC))
+    );
+
+    // Sputnik 1 launched at JD 2436116.3115.
+    let sputnik = OffsetDateTime::from_julian_date(2_436_116.311_5).expect("in range");
+    assert_eq!(sputnik.date(), date!(1957 - 10 - 04));
+    assert_eq!((sputnik.hour(), sputnik.minute()), (19, 28));
+
+    let max = Date::MAX.to_julian_day() as f64 + 0.5;
+    assert_eq!(
+        OffsetDateTime::from_julian_date(max - 0.25),
+        Ok(datetime!(+999_999-12-31 18:00 UTC))
+    );
+    assert!(OffsetDateTime::from_julian_date(max).is_err());
+    assert!(OffsetDateTime::from_julian_date(-363_521_074.5 - 0.25).is_err());
+    assert!(OffsetDateTime::from_julian_date(f64::NAN).is_err());
+    assert!(OffsetDateTime::from_julian_date(f64::INFINITY).is_err());
+    assert!(OffsetDateTime::from_julian_date(f64::NEG_INFINITY).is_err());
+    assert_eq!(
+        OffsetDateTime::from_julian_date(1e12).map_err(|err| err.name()),
+        Err("julian_date")
+    );
+}
+
 #[test]
 fn offset() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).offset(), offset!(UTC));
@@ -146,6 +212,67 @@ fn time() {
     );
 }
 
+#[test]
+fn from_parts() {
+    assert_eq!(
+        OffsetDateTime::from_parts(date!(2019 - 01 - 01), time!(0:00), offset!(-1)),
+        datetime!(2019-01-01 0:00 -1),
+    );
+    assert_eq!(
+        OffsetDateTime::from_parts(date!(2019 - 01 - 01), time!(0:00), offset!(-1)),
+        datetime!(2019-01-01 1:00 UTC),
+    );
+}
+
+#[test]
+fn into_parts() {
+    assert_eq!(
+        datetime!(2019-01-01 12:34:56.789 +1:02:03).into_parts(),
+        (
+            date!(2019 - 01 - 01),
+            time!(12:34:56.789),
+            offset!(+1:02:03)
+        ),
+    );
+    assert_eq!(
+        datetime!(2019-01-01 0:00 UTC)
+            .to_offset(offset!(-1))
+            .into_parts(),
+        (date!(2018 - 12 - 31), time!(23:00), offset!(-1)),
+    );
+
+    for datetime in [
+        OffsetDateTime::UNIX_EPOCH,
+        datetime!(2019-01-01 12:34:56.789 +1:02:03),
+        datetime!(2020-02-29 23:59:59.999_999_999 -23:59:59),
+        PrimitiveDateTime::MIN.assume_offset(offset!(+23:59:59)),
+        PrimitiveDateTime::MAX.assume_offset(offset!(-23:59:59)),
+    ] {
+        let (date, time, offset) = datetime.into_parts();
+        let roundtrip = OffsetDateTime::from_parts(date, time, offset);
+        assert_eq!(roundtrip, datetime);
+        assert_eq!(roundtrip.offset(), datetime.offset());
+    }
+}
+
+#[test]
+fn to_calendar_date_time() {
+    assert_eq!(
+        datetime!(2019-01-02 12:34:56.789 +1:02:03).to_calendar_date_time(),
+        (
+            (2019, Month::January, 2),
+            (12, 34, 56, 789_000_000),
+            offset!(+1:02:03)
+        ),
+    );
+    assert_eq!(
+        datetime!(2019-01-01 0:00 UTC)
+            .to_offset(offset!(-1))
+            .to_calendar_date_time(),
+        ((2018, Month::December, 31), (23, 0, 0, 0), offset!(-1)),
+    );
+}
+
 #[test]
 fn year() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).year(), 2019);
@@ -189,6 +316,32 @@ fn ordinal() {
     );
 }
 
+#[test]
+fn month_boundaries() {
+    let dt = datetime!(2019-12-31 23:00 UTC);
+    assert!(dt.is_last_day_of_month());
+    assert!(dt.is_last_day_of_year());
+    assert!(!dt.is_first_day_of_year());
+    // The date in the stored offset is used.
+    let dt = dt.to_offset(offset!(+1));
+    assert!(!dt.is_last_day_of_month());
+    assert!(!dt.is_last_day_of_year());
+    assert!(dt.is_first_day_of_month());
+    assert!(dt.is_first_day_of_year());
+
+    assert!(datetime!(2020-02-29 0:00 UTC).is_leap_day());
+    assert!(
+        !datetime!(2020-02-29 0:00 UTC)
+            .to_offset(offset!(-1))
+            .is_leap_day()
+    );
+    assert!(
+        datetime!(2020-02-28 23:00 UTC)
+            .to_offset(offset!(+1))
+            .is_last_day_of_month()
+    );
+}
+
 #[test]
 fn iso_week() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).iso_week(), 1);
@@ -197,6 +350,20 @@ fn iso_week() {
     assert_eq!(datetime!(2021-01-01 0:00 UTC).iso_week(), 53);
 }
 
+#[test]
+fn iso_year_week() {
+    assert_eq!(datetime!(2019-10-04 0:00 UTC).iso_year_week(), (2019, 40));
+    assert_eq!(datetime!(2019-12-30 0:00 UTC).iso_year_week(), (2020, 1));
+    assert_eq!(datetime!(2021-01-01 0:00 UTC).iso_year_week(), (2020, 53));
+    assert_eq!(datetime!(2021-01-04 0:30 +1).iso_year_week(), (2021, 1));
+    assert_eq!(
+        datetime!(2021-01-04 0:30 +1)
+            .to_offset(offset!(UTC))
+            .iso_year_week(),
+        (2020, 53)
+    );
+}
+
 #[test]
 fn sunday_based_week() {
     assert_eq!(datetime!(2019-01-01 0:00 UTC).sunday_based_week(), 0);
@@ -271,6 +438,46 @@ fn to_julian_day() {
     assert_eq!(datetime!(2019-12-31 0:00 UTC).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn to_julian_date() {
+    assert_eq!(
+        datetime!(2000-01-01 12:00 UTC).to_julian_date(),
+        2_451_545.0
+    );
+    assert_eq!(datetime!(2000-01-01 0:00 UTC).to_julian_date(), 2_451_544.5);
+    assert_eq!(OffsetDateTime::UNIX_EPOCH.to_julian_date(), 2_440_587.5);
+    assert_eq!(datetime!(1858-11-17 0:00 UTC).to_julian_date(), 2_400_000.5);
+    assert_eq!(datetime!(-4713-11-24 12:00 UTC).to_julian_date(), 0.0);
+    assert_eq!(datetime!(-4713-11-24 0:00 UTC).to_julian_date(), -0.5);
+    assert_eq!(
+        datetime!(-999_999-01-01 0:00 UTC).to_julian_date(),
+        -363_521_074.5
+    );
+
+    // The value is always in UTC.
+    assert_eq!(datetime!(2000-01-01 13:00 +1).to_julian_date(), 2_451_545.0);
+    assert_eq!(datetime!(2000-01-01 6:00 -6).to_julian_date(), 2_451_545.0);
+    assert_eq!(datetime!(2000-01-01 18:00 +6).to_julian_date(), 2_451_545.0);
+    assert_eq!(datetime!(2000-01-01 12:00 +6).to_julian_day(), 2_451_545);
+    assert_eq!(
+        datetime!(2000-01-01 12:00 +6).to_julian_date(),
+        2_451_544.75
+    );
+
+    let sputnik = datetime!(1957-10-04 19:28:34 UTC).to_julian_date();
+    assert!((sputnik - 2_436_116.311_5).abs() < 1e-4);
+
+    // Round trips are accurate to within the precision of an `f64`.
+    let datetime = datetime!(2024-05-01 12:51:30.123_456 UTC);
+    let round_trip = OffsetDateTime::from_julian_date(datetime.to_julian_date()).expect("in range");
+    assert!((round_trip - datetime).abs() < 50.microseconds());
+    let datetime = datetime!(-4713-11-24 12:51:30.123_456 UTC);
+    assert_eq!(
+        OffsetDateTime::from_julian_date(datetime.to_julian_date()),
+        Ok(datetime)
+    );
+}
+
 #[test]
 fn to_hms() {
     assert_eq!(datetime!(2020-01-01 1:02:03 UTC).to_hms(), (1, 2, 3));
@@ -559,6 +766,84 @@ fn ord() {
     assert!(t2 > t1);
 }
 
+#[test]
+fn min_max_clamp() {
+    let low = datetime!(2019-01-01 0:00 UTC);
+    let middle = datetime!(2019-01-01 12:00 UTC);
+    let high = datetime!(2019-01-02 0:00 UTC);
+    assert_eq!(low.min(high), low);
+    assert_eq!(high.min(low), low);
+    assert_eq!(low.max(high), high);
+    assert_eq!(high.max(low), high);
+    assert_eq!(low.clamp(middle, high), middle);
+    assert_eq!(middle.clamp(low, high), middle);
+    assert_eq!(high.clamp(low, middle), middle);
+
+    // The offset is not considered when comparing.
+    assert_eq!(
+        datetime!(2019-01-01 12:00 +13).min(middle),
+        datetime!(2019-01-01 12:00 +13)
+    );
+    assert_eq!(
+        datetime!(2019-01-01 12:00 -13).max(middle),
+        datetime!(2019-01-01 12:00 -13)
+    );
+    assert_eq!(
+        datetime!(2019-01-02 1:00 +1).clamp(low, middle),
+        datetime!(2019-01-01 12:00 UTC)
+    );
+
+    // When both represent the same instant, `min` and `clamp` return `self` while `max` returns
+    // `other`, matching the methods of `Ord`.
+    let utc = datetime!(2019-01-01 12:00 UTC);
+    let plus_one = datetime!(2019-01-01 13:00 +1);
+    let minus_one = datetime!(2019-01-01 11:00 -1);
+    assert_eq!(utc.min(plus_one).offset(), offset!(UTC));
+    assert_eq!(plus_one.min(utc).offset(), offset!(+1));
+    assert_eq!(utc.max(plus_one).offset(), offset!(+1));
+    assert_eq!(plus_one.max(utc).offset(), offset!(UTC));
+    for (a, b) in [(utc, plus_one), (plus_one, utc), (minus_one, utc)] {
+        assert_eq!(a.min(b).offset(), Ord::min(a, b).offset());
+        assert_eq!(a.max(b).offset(), Ord::max(a, b).offset());
+        assert_eq!(a.clamp(b, high).offset(), Ord::clamp(a, b, high).offset());
+    }
+    assert_eq!(plus_one.clamp(utc, minus_one).offset(), offset!(+1));
+    assert_eq!(plus_one.clamp(minus_one, high).offset(), offset!(+1));
+    assert_eq!(plus_one.clamp(low, minus_one).offset(), offset!(+1));
+
+    const CLAMPED: OffsetDateTime = datetime!(2020-01-01 0:00 UTC)
+        .clamp(datetime!(2019-01-01 0:00 +1), datetime!(2019-12-31 0:00 -1));
+    assert_eq!(CLAMPED, datetime!(2019-12-31 0:00 -1));
+    assert_eq!(CLAMPED.offset(), offset!(-1));
+}
+
+#[test]
+#[should_panic]
+fn clamp_panic() {
+    let _ = datetime!(2019-01-01 0:00 UTC).clamp(
+        datetime!(2019-01-01 1:00 +1),
+        datetime!(2018-12-31 23:59 UTC),
+    );
+}
+
+#[test]
+fn is_before_after() {
+    let earlier = datetime!(2019-01-01 0:00 UTC);
+    let later = datetime!(2019-01-01 0:00 -1);
+    assert!(earlier.is_before(later));
+    assert!(!earlier.is_after(later));
+    assert!(later.is_after(earlier));
+    assert!(!later.is_before(earlier));
+
+    let same_instant = datetime!(2019-01-01 1:00 +1);
+    assert!(!earlier.is_before(same_instant));
+    assert!(!earlier.is_after(same_instant));
+    assert!(!earlier.is_before(earlier));
+    assert!(!earlier.is_after(earlier));
+
+    const _: () = assert!(datetime!(2019-01-01 0:00 +1).is_before(datetime!(2019-01-01 0:00 UTC)));
+}
+
 #[test]
 fn hash() {
     use std::collections::hash_map::DefaultHasher;
@@ -889,6 +1174,12 @@ fn sub_self() {
             - datetime!(-999_999-01-01 0:00 +23:59:59),
         Duration::new(63_113_872_550_397, 999_999_999),
     );
+
+    let max = PrimitiveDateTime::MAX.assume_offset(offset!(-23:59:59));
+    let min = PrimitiveDateTime::MIN.assume_offset(offset!(+23:59:59));
+    let span = (PrimitiveDateTime::MAX - PrimitiveDateTime::MIN) + 2.days() - 2.seconds();
+    assert_eq!(max - min, span);
+    assert_eq!(min - max, -span);
 }
 
 #[test]
@@ -1181,6 +1472,195 @@ fn checked_sub_duration() {
     );
 }
 
+#[test]
+fn checked_add_std() {
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45 UTC).checked_add_std(5.std_nanoseconds()),
+        Some(datetime!(2021 - 10 - 25 14:01:53.450_000_005 UTC))
+    );
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45 UTC).checked_add_std(2.std_days()),
+        Some(datetime!(2021 - 10 - 27 14:01:53.45 UTC))
+    );
+    assert_eq!(
+        datetime!(+999_999 - 12 - 31 23:59:59.999_999_999 UTC).checked_add_std(1.std_nanoseconds()),
+        None
+    );
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45 UTC).checked_add_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_std() {
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45 UTC).checked_sub_std(5.std_nanoseconds()),
+        Some(datetime!(2021 - 10 - 25 14:01:53.449_999_995 UTC))
+    );
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45 UTC).checked_sub_std(2.std_days()),
+        Some(datetime!(2021 - 10 - 23 14:01:53.45 UTC))
+    );
+    assert_eq!(
+        datetime!(-999_999 - 01 - 01 0:00 UTC).checked_sub_std(1.std_nanoseconds()),
+        None
+    );
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45 UTC).checked_sub_std(StdDuration::MAX),
+        None
+    );
+}
+
+#[test]
+fn checked_add_days_wall_clock() {
+    assert_eq!(
+        datetime!(2024 - 02 - 28 23:59:59.5 +1).checked_add_days_wall_clock(1),
+        Some(datetime!(2024 - 02 - 29 23:59:59.5 +1))
+    );
+    assert_eq!(
+        datetime!(2024 - 02 - 28 12:00 +1).checked_add_days_wall_clock(-59),
+        Some(datetime!(2023 - 12 - 31 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2024 - 02 - 28 12:00 +1).checked_add_days_wall_clock(0),
+        Some(datetime!(2024 - 02 - 28 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2024 - 02 - 28 12:00 +1)
+            .checked_add_days_wall_clock(1)
+            .map(|value| value.offset()),
+        Some(offset!(+1))
+    );
+    assert_eq!(
+        Date::MAX
+            .midnight()
+            .assume_utc()
+            .checked_add_days_wall_clock(1),
+        None
+    );
+    assert_eq!(
+        Date::MIN
+            .midnight()
+            .assume_utc()
+            .checked_add_days_wall_clock(-1),
+        None
+    );
+    assert_eq!(
+        datetime!(2024 - 02 - 28 12:00 +1).checked_add_days_wall_clock(i64::MAX),
+        None
+    );
+    assert_eq!(
+        datetime!(2024 - 02 - 28 12:00 +1).checked_add_days_wall_clock(i64::MIN),
+        None
+    );
+}
+
+#[test]
+fn checked_add_months_wall_clock() {
+    assert_eq!(
+        datetime!(2024 - 01 - 15 12:00 +1).checked_add_months_wall_clock(1),
+        Some(datetime!(2024 - 02 - 15 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2024 - 01 - 31 12:00 +1).checked_add_months_wall_clock(1),
+        Some(datetime!(2024 - 02 - 29 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2023 - 01 - 31 12:00 +1).checked_add_months_wall_clock(1),
+        Some(datetime!(2023 - 02 - 28 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2024 - 03 - 31 12:00 +1).checked_add_months_wall_clock(-1),
+        Some(datetime!(2024 - 02 - 29 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2024 - 12 - 31 12:00 +1).checked_add_months_wall_clock(1),
+        Some(datetime!(2025 - 01 - 31 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2024 - 01 - 01 12:00 +1).checked_add_months_wall_clock(-1),
+        Some(datetime!(2023 - 12 - 01 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2024 - 02 - 29 12:00 +1).checked_add_months_wall_clock(12),
+        Some(datetime!(2025 - 02 - 28 12:00 +1))
+    );
+    assert_eq!(
+        datetime!(2024 - 02 - 29 12:00 +1).checked_add_months_wall_clock(-48),
+        Some(datetime!(2020 - 02 - 29 12:00 +1))
+    );
+    assert_eq!(
+        Date::MAX
+            .midnight()
+            .assume_utc()
+            .checked_add_months_wall_clock(1),
+        None
+    );
+    assert_eq!(
+        Date::MIN
+            .midnight()
+            .assume_utc()
+            .checked_add_months_wall_clock(-1),
+        None
+    );
+    assert_eq!(
+        datetime!(2024 - 02 - 28 12:00 +1).checked_add_months_wall_clock(i64::MAX),
+        None
+    );
+    assert_eq!(
+        datetime!(2024 - 02 - 28 12:00 +1).checked_add_months_wall_clock(i64::MIN),
+        None
+    );
+}
+
+#[test]
+fn wall_clock_arithmetic_across_offset_transition() {
+    // A stand-in for a time zone database: US Eastern time, which switches from UTC-5 to UTC-4 at
+    // 2:00 on 10 March 2024.
+    let resolve = |datetime: OffsetDateTime| {
+        let offset = if datetime.date() < date!(2024 - 03 - 10) {
+            offset!(-5)
+        } else {
+            offset!(-4)
+        };
+        datetime.replace_offset(offset)
+    };
+
+    let start = datetime!(2024 - 03 - 09 12:00 -5);
+
+    let wall_clock = resolve(start.checked_add_days_wall_clock(1).unwrap());
+    assert_eq!(wall_clock, datetime!(2024 - 03 - 10 12:00 -4));
+    assert_eq!(wall_clock - start, 23.hours());
+
+    let elapsed = start.checked_add(1.days()).unwrap().to_offset(offset!(-4));
+    assert_eq!(elapsed, datetime!(2024 - 03 - 10 13:00 -4));
+    assert_eq!(elapsed - start, 24.hours());
+
+    assert_ne!(wall_clock, elapsed);
+
+    let wall_clock = resolve(start.checked_add_months_wall_clock(1).unwrap());
+    assert_eq!(wall_clock, datetime!(2024 - 04 - 09 12:00 -4));
+    assert_eq!(wall_clock - start, 31.days() - 1.hours());
+}
+
+#[test]
+fn arithmetic_overflow_panic() {
+    let max = datetime!(+999_999 - 12 - 31 23:59:59.999_999_999 UTC);
+    let min = datetime!(-999_999 - 01 - 01 0:00 UTC);
+    let (nanosecond, std_nanosecond) = (1.nanoseconds(), 1.std_nanoseconds());
+
+    assert_panic_with_operands!(max + nanosecond, max, nanosecond);
+    assert_panic_with_operands!(max + std_nanosecond, max, std_nanosecond);
+    assert_panic_with_operands!(min - nanosecond, min, nanosecond);
+    assert_panic_with_operands!(min - std_nanosecond, min, std_nanosecond);
+
+    let mut datetime = max;
+    assert_panic_with_operands!(datetime += nanosecond, max, nanosecond);
+    let mut datetime = min;
+    assert_panic_with_operands!(datetime -= std_nanosecond, min, std_nanosecond);
+}
+
 #[test]
 fn saturating_add_duration() {
     assert_eq!(
@@ -1248,3 +1728,24 @@ fn saturating_sub_duration() {
         datetime!(+999999 - 12 - 31 23:59:59.999_999_999 +10)
     );
 }
+
+#[test]
+fn reference_ops() {
+    let datetime = datetime!(2021-01-02 03:04:05 +01:00);
+    assert_ref_binop!(datetime, +, 5.hours());
+    assert_ref_binop!(datetime, +, 5.std_hours());
+    assert_ref_binop!(datetime, -, 5.hours());
+    assert_ref_binop!(datetime, -, 5.std_hours());
+    assert_ref_binop!(datetime, -, datetime!(2020-01-02 0:00 -01:00));
+    assert_ref_binop!(datetime, -, SystemTime::UNIX_EPOCH);
+    assert_ref_binop!(SystemTime::UNIX_EPOCH, -, datetime);
+    assert_ref_binop!(SystemTime::UNIX_EPOCH, +, 5.hours());
+    assert_ref_binop!(SystemTime::UNIX_EPOCH, -, 5.hours());
+
+    assert_ref_op_assign!(datetime, +=, 5.hours());
+    assert_ref_op_assign!(datetime, +=, 5.std_hours());
+    assert_ref_op_assign!(datetime, -=, 5.hours());
+    assert_ref_op_assign!(datetime, -=, 5.std_hours());
+    assert_ref_op_assign!(SystemTime::UNIX_EPOCH, +=, 5.hours());
+    assert_ref_op_assign!(SystemTime::UNIX_EPOCH, -=, 5.hours());
+}
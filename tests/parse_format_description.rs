@@ -2,7 +2,7 @@ use core::num::NonZeroU16;
 
 use time::error::InvalidFormatDescription;
 use time::format_description::modifier::*;
-use time::format_description::{self, Component, FormatItem, OwnedFormatItem};
+use time::format_description::{self, Component, FormatItem, OwnedFormatItem, ParseLimits};
 
 mod iterator {
     use super::*;
@@ -27,6 +27,15 @@ mod iterator {
             .copied()
     }
 
+    pub(super) fn day_repr() -> impl Iterator<Item = (DayRepr, &'static str)> {
+        [
+            (DayRepr::Numerical, "repr:numerical"),
+            (DayRepr::Ordinal, "repr:ordinal"),
+        ]
+        .iter()
+        .copied()
+    }
+
     pub(super) fn month_repr() -> impl Iterator<Item = (MonthRepr, &'static str)> {
         [
             (MonthRepr::Numerical, "repr:numerical"),
@@ -235,6 +244,45 @@ fn simple_component() {
             })
         ))])
     );
+    assert_eq!(
+        format_description::parse("[offset_hour z_when_utc:true]"),
+        Ok(vec![FormatItem::Component(Component::OffsetHour(
+            modifier!(OffsetHour {
+                sign_is_mandatory: false,
+                padding: Padding::Zero,
+                z_when_utc: true,
+            })
+        ))])
+    );
+    assert_eq!(
+        format_description::parse("[offset]"),
+        Ok(vec![FormatItem::Component(Component::Offset(modifier!(
+            Offset {
+                sign_is_mandatory: false,
+                z_when_utc: false,
+                separator: OffsetSeparator::Colon,
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[offset sign:mandatory z_when_utc:true separator:none]"),
+        Ok(vec![FormatItem::Component(Component::Offset(modifier!(
+            Offset {
+                sign_is_mandatory: true,
+                z_when_utc: true,
+                separator: OffsetSeparator::None,
+            }
+        )))])
+    );
+    assert_eq!(
+        format_description::parse("[offset_minute omit_when_zero:true]"),
+        Ok(vec![FormatItem::Component(Component::OffsetMinute(
+            modifier!(OffsetMinute {
+                padding: Padding::Zero,
+                omit_when_zero: true,
+            })
+        ))])
+    );
     assert_eq!(
         format_description::parse("[offset_second]"),
         Ok(vec![FormatItem::Component(Component::OffsetSecond(
@@ -342,6 +390,8 @@ fn errors() {
         "[day padding:invalid]", InvalidModifier { value, index: 13, .. } if value == "invalid",
         "[ignore]", MissingRequiredModifier { name: "count", index: 1, .. },
         "[ignore count:70000]", InvalidModifier { value, index: 14, .. } if value == "70000",
+        "[offset separator:dash]", InvalidModifier { value, index: 18, .. } if value == "dash",
+        "[offset padding:none]", InvalidModifier { value, index: 8, .. } if value == "padding",
     }
 }
 
@@ -354,6 +404,14 @@ fn component_with_modifiers() {
                 Day { padding }
             )))])
         );
+        for (repr, repr_str) in iterator::day_repr() {
+            assert_eq!(
+                format_description::parse(&format!("[day {padding_str} {repr_str}]")),
+                Ok(vec![FormatItem::Component(Component::Day(modifier!(
+                    Day { padding, repr }
+                )))])
+            );
+        }
         assert_eq!(
             format_description::parse(&format!("[minute {padding_str}]")),
             Ok(vec![FormatItem::Component(Component::Minute(modifier!(
@@ -791,6 +849,123 @@ fn nested_error() {
     ));
 }
 
+/// Nest `inner` within `depth` levels of the provided kind of nested item.
+fn nested(kind: &str, depth: usize, inner: &str) -> String {
+    format!(
+        "{}{inner}{}",
+        format!("[{kind} [").repeat(depth),
+        "]]".repeat(depth)
+    )
+}
+
+#[test]
+fn limits() {
+    use InvalidFormatDescription::*;
+
+    assert_eq!(ParseLimits::default(), ParseLimits::DEFAULT);
+
+    for kind in ["optional", "first"] {
+        assert!(format_description::parse_owned::<2>(&nested(kind, 32, "[year]")).is_ok());
+        assert!(matches!(
+            format_description::parse_owned::<2>(&nested(kind, 33, "[year]")),
+            Err(NestingTooDeep {
+                max_depth: 32,
+                index,
+                ..
+            }) if index == 32 * (kind.len() + 3) + kind.len() + 2
+        ));
+        // Pathologically deep nesting results in an error rather than a stack overflow.
+        assert!(matches!(
+            format_description::parse_owned::<2>(&nested(kind, 1000, "")),
+            Err(NestingTooDeep { max_depth: 32, .. })
+        ));
+        assert!(matches!(
+            format_description::parse_borrowed::<2>(&nested(kind, 1000, "")),
+            Err(NestingTooDeep { max_depth: 32, .. })
+        ));
+        assert!(matches!(
+            format_description::parse(&nested(kind, 1000, "")),
+            Err(NestingTooDeep { max_depth: 32, .. })
+        ));
+    }
+    assert!(matches!(
+        format_description::parse_owned::<2>(&format!(
+            "[first [a] [{}]]",
+            nested("optional", 40, "")
+        )),
+        Err(NestingTooDeep { max_depth: 32, .. })
+    ));
+
+    // More than 255 levels of brackets.
+    let limits = ParseLimits::DEFAULT.set_max_depth(200);
+    assert!(
+        format_description::parse_owned_with_limits::<2>(
+            &nested("optional", 200, "[year]"),
+            limits
+        )
+        .is_ok()
+    );
+
+    let limits = ParseLimits::DEFAULT.set_max_depth(0);
+    assert!(format_description::parse_owned_with_limits::<2>("[year]-[month]", limits).is_ok());
+    assert!(matches!(
+        format_description::parse_owned_with_limits::<2>("[year][optional [x]]", limits),
+        Err(NestingTooDeep {
+            max_depth: 0,
+            index: 16,
+            ..
+        })
+    ));
+
+    let limits = ParseLimits::DEFAULT.set_max_items(3);
+    assert!(format_description::parse_owned_with_limits::<2>("[year]-[month]", limits).is_ok());
+    assert!(format_description::parse_borrowed_with_limits::<2>("[year]-[month]", limits).is_ok());
+    assert!(matches!(
+        format_description::parse_owned_with_limits::<2>("[year]-[month]-", limits),
+        Err(TooManyItems {
+            max_items: 3,
+            index: 14,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_borrowed_with_limits::<2>("[year]-[month]-", limits),
+        Err(TooManyItems {
+            max_items: 3,
+            index: 14,
+            ..
+        })
+    ));
+    // Items within nested format descriptions are counted, as are the nested items themselves.
+    assert!(matches!(
+        format_description::parse_owned_with_limits::<2>("[optional [[year]-[month]]]", limits),
+        Err(TooManyItems {
+            max_items: 3,
+            index: 18,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_owned_with_limits::<2>("[first [a] [b] [c] [d]]", limits),
+        Err(TooManyItems {
+            max_items: 3,
+            index: 16,
+            ..
+        })
+    ));
+
+    assert!(matches!(
+        format_description::parse_owned::<2>(&"[year]-".repeat(2049)),
+        Err(TooManyItems {
+            max_items: 4096,
+            index: 14336,
+            ..
+        })
+    ));
+    // A single long literal is only one item.
+    assert!(format_description::parse_owned::<2>(&"a".repeat(1 << 20)).is_ok());
+}
+
 #[test]
 fn error_display() {
     assert_eq!(
@@ -833,6 +1008,24 @@ fn error_display() {
             .to_string(),
         "missing required modifier `count` for component at byte index 1"
     );
+    assert_eq!(
+        format_description::parse_owned_with_limits::<2>(
+            "[optional [[optional [x]]]]",
+            ParseLimits::DEFAULT.set_max_depth(1)
+        )
+        .unwrap_err()
+        .to_string(),
+        "nested format description exceeds the maximum depth of 1 at byte index 21"
+    );
+    assert_eq!(
+        format_description::parse_owned_with_limits::<2>(
+            "[year][month]",
+            ParseLimits::DEFAULT.set_max_items(1)
+        )
+        .unwrap_err()
+        .to_string(),
+        "format description exceeds the maximum of 1 items at byte index 6"
+    );
 }
 
 #[test]
@@ -2,8 +2,9 @@ use std::num::{NonZeroU16, NonZeroU8};
 
 use time::format_description::modifier::WeekNumberRepr;
 use time::format_description::{Component, FormatItem};
+use time::macros::{date, datetime, format_description, offset, time};
 use time::parsing::Parsed;
-use time::{error, Month, Time, Weekday};
+use time::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 #[test]
 fn getters_setters() {
@@ -127,6 +128,175 @@ fn builder_methods() {
     }
 }
 
+#[test]
+fn or_defaults_from() {
+    let five = NonZeroU8::new(5).expect("valid value");
+    let ten = NonZeroU8::new(10).expect("valid value");
+
+    let parsed = Parsed::new()
+        .with_month(Month::May)
+        .and_then(|parsed| parsed.with_minute(30))
+        .and_then(|parsed| parsed.with_offset_hour(-5))
+        .expect("valid values");
+    let defaults = Parsed::new()
+        .with_year(2023)
+        .and_then(|parsed| parsed.with_month(Month::January))
+        .and_then(|parsed| parsed.with_day(ten))
+        .and_then(|parsed| parsed.with_hour_24(12))
+        .and_then(|parsed| parsed.with_minute(0))
+        .and_then(|parsed| parsed.with_hour_12(five))
+        .and_then(|parsed| parsed.with_offset_hour(1))
+        .and_then(|parsed| parsed.with_offset_minute_signed(30))
+        .expect("valid values");
+    let parsed = parsed.or_defaults_from(&defaults);
+
+    assert_eq!(parsed.year(), Some(2023));
+    assert_eq!(parsed.month(), Some(Month::May));
+    assert_eq!(parsed.day(), Some(ten));
+    assert_eq!(parsed.hour_24(), Some(12));
+    assert_eq!(parsed.hour_12(), Some(five));
+    assert_eq!(parsed.minute(), Some(30));
+    assert_eq!(parsed.second(), None);
+    assert_eq!(parsed.offset_hour(), Some(-5));
+    assert_eq!(parsed.offset_minute_signed(), Some(30));
+    assert_eq!(parsed.weekday(), None);
+
+    // Merging with an empty value changes nothing.
+    let merged = parsed.or_defaults_from(&Parsed::new());
+    assert_eq!(merged.year(), Some(2023));
+    assert_eq!(merged.minute(), Some(30));
+    assert_eq!(
+        Parsed::new().or_defaults_from(&parsed).month(),
+        Some(Month::May)
+    );
+}
+
+#[test]
+fn or_defaults_from_offset_sign() -> time::Result<()> {
+    let mut defaults = Parsed::new();
+    defaults.parse_items(
+        b"-00:30",
+        format_description!("[offset_hour]:[offset_minute]"),
+    )?;
+    let parsed = Parsed::new().or_defaults_from(&defaults);
+    assert_eq!(UtcOffset::try_from(parsed)?, offset!(-0:30));
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"+00", format_description!("[offset_hour]"))?;
+    let parsed = parsed.or_defaults_from(&defaults);
+    assert_eq!(UtcOffset::try_from(parsed)?, offset!(+0:30));
+    Ok(())
+}
+
+#[test]
+fn with_defaults() -> time::Result<()> {
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"13:30", format_description!("[hour]:[minute]"))?;
+    let parsed = parsed
+        .with_date(date!(2023 - 05 - 10))
+        .with_offset(offset!(UTC));
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!(2023-05-10 13:30 UTC)
+    );
+
+    // Components that are present are never overwritten.
+    let mut parsed = Parsed::new();
+    parsed.parse_items(
+        b"2020-02-29 01:02:03.4 -05:00",
+        format_description!(
+            "[year]-[month]-[day] [hour]:[minute]:[second].[subsecond] \
+             [offset_hour]:[offset_minute]"
+        ),
+    )?;
+    let parsed = parsed
+        .with_date(date!(2023 - 05 - 10))
+        .with_time(time!(13:30))
+        .with_offset(offset!(+1));
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!(2020-02-29 01:02:03.4 -5)
+    );
+
+    // Only the missing date components are filled in.
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"12-25", format_description!("[month]-[day]"))?;
+    let parsed = parsed.with_date(date!(2023 - 05 - 10));
+    assert_eq!(Date::try_from(parsed)?, date!(2023 - 12 - 25));
+
+    // A date that can already be constructed is left alone, even if it uses other components.
+    let mut parsed = Parsed::new();
+    parsed.parse_items(
+        b"2020-W01-1",
+        format_description!("[year base:iso_week]-W[week_number]-[weekday repr:monday]"),
+    )?;
+    let parsed = parsed.with_date(date!(2023 - 05 - 10));
+    assert_eq!(parsed.year(), None);
+    assert_eq!(Date::try_from(parsed)?, date!(2019 - 12 - 30));
+
+    // A 12-hour value is not overridden by the default 24-hour value.
+    let parsed = Parsed::new()
+        .with_hour_12(NonZeroU8::new(3).expect("valid value"))
+        .and_then(|parsed| parsed.with_hour_12_is_pm(true))
+        .and_then(|parsed| parsed.with_minute(15))
+        .expect("valid values")
+        .with_time(time!(8:00:05));
+    assert_eq!(Time::try_from(parsed)?, time!(15:15));
+
+    let parsed = Parsed::new()
+        .with_minute(45)
+        .expect("valid value")
+        .with_time(time!(8:00:05.5));
+    assert_eq!(Time::try_from(parsed)?, time!(8:45:05.5));
+
+    let parsed = Parsed::new()
+        .with_offset_hour(3)
+        .expect("valid value")
+        .with_offset(offset!(-1:30));
+    assert_eq!(UtcOffset::try_from(parsed)?, offset!(+3));
+
+    let parsed = Parsed::new()
+        .with_date(date!(2023 - 05 - 10))
+        .with_time(time!(13:30));
+    assert_eq!(
+        PrimitiveDateTime::try_from(parsed)?,
+        datetime!(2023-05-10 13:30)
+    );
+    assert!(matches!(
+        OffsetDateTime::try_from(parsed),
+        Err(error::TryFromParsed::InsufficientInformation)
+    ));
+    assert_eq!(
+        OffsetDateTime::try_from(parsed.with_offset(offset!(-1:30)))?,
+        datetime!(2023-05-10 13:30 -1:30)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn clear() -> time::Result<()> {
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_items(
+        b"2023-05-10 13:30 +01",
+        format_description!("[year]-[month]-[day] [hour]:[minute] [offset_hour]"),
+    )?;
+    assert!(remaining.is_empty());
+    assert_eq!(parsed.year(), Some(2023));
+    assert_eq!(parsed.offset_hour(), Some(1));
+
+    parsed.clear();
+    assert_eq!(parsed.year(), None);
+    assert_eq!(parsed.month(), None);
+    assert_eq!(parsed.day(), None);
+    assert_eq!(parsed.hour_24(), None);
+    assert_eq!(parsed.minute(), None);
+    assert_eq!(parsed.offset_hour(), None);
+    assert!(Date::try_from(parsed).is_err());
+
+    Ok(())
+}
+
 #[test]
 fn single_item_parse() {
     assert!(Time::parse("a", &FormatItem::Literal(b"a")).is_err());
@@ -184,3 +354,199 @@ fn component_err() {
         b"00"
     );
 }
+
+#[test]
+fn period_before_hour() -> time::Result<()> {
+    let twelve = NonZeroU8::new(12).expect("valid value");
+    let three = NonZeroU8::new(3).expect("valid value");
+
+    let parsed = Parsed::new()
+        .with_hour_12_is_pm(true)
+        .and_then(|parsed| parsed.with_hour_12(three))
+        .and_then(|parsed| parsed.with_minute(45))
+        .expect("valid values");
+    assert_eq!(Time::try_from(parsed)?, Time::from_hms(15, 45, 0)?);
+
+    let parsed = Parsed::new()
+        .with_hour_12_is_pm(false)
+        .and_then(|parsed| parsed.with_hour_12(twelve))
+        .expect("valid values");
+    assert_eq!(Time::try_from(parsed)?, Time::MIDNIGHT);
+
+    let parsed = Parsed::new()
+        .with_hour_12_is_pm(true)
+        .and_then(|parsed| parsed.with_hour_12(twelve))
+        .expect("valid values");
+    assert_eq!(Time::try_from(parsed)?, Time::from_hms(12, 0, 0)?);
+
+    Ok(())
+}
+
+#[test]
+fn conflicting_components() -> time::Result<()> {
+    fn conflict<T>(a: &'static str, b: &'static str) -> Result<T, error::TryFromParsed> {
+        Err(error::TryFromParsed::ConflictingComponents { a, b })
+    }
+
+    // Agreeing components are accepted.
+    let mut parsed = Parsed::new();
+    parsed.parse_items(
+        b"Wednesday 2023-05-10 (130, W19, 23w19)",
+        format_description!(
+            "[weekday] [year]-[month]-[day] ([ordinal], W[week_number], \
+             [year repr:last_two base:iso_week]w[week_number repr:monday])"
+        ),
+    )?;
+    assert_eq!(Date::try_from(parsed), Ok(date!(2023 - 05 - 10)));
+
+    // Components that are not present are not checked.
+    let parsed = Parsed::new()
+        .with_year(2023)
+        .and_then(|parsed| parsed.with_ordinal(NonZeroU16::new(130)?))
+        .and_then(|parsed| parsed.with_weekday(Weekday::Wednesday))
+        .expect("valid values");
+    assert_eq!(Date::try_from(parsed), Ok(date!(2023 - 05 - 10)));
+
+    // Disagreeing components are rejected, naming the component used to construct the date.
+    let mut parsed = Parsed::new();
+    parsed.parse_items(
+        b"Monday 2023-05-10",
+        format_description!("[weekday] [year]-[month]-[day]"),
+    )?;
+    assert_eq!(Date::try_from(parsed), conflict("day", "weekday"));
+    assert!(matches!(
+        PrimitiveDateTime::try_from(parsed.with_time(time!(0:00))),
+        Err(error::TryFromParsed::ConflictingComponents { .. })
+    ));
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(
+        b"2023-131 05-10",
+        format_description!("[year]-[ordinal] [month]-[day]"),
+    )?;
+    assert_eq!(Date::try_from(parsed), conflict("ordinal", "day"));
+    assert_eq!(
+        Date::try_from(
+            parsed
+                .with_ordinal(NonZeroU16::new(130).expect("valid value"))
+                .expect("valid value")
+        ),
+        Ok(date!(2023 - 05 - 10))
+    );
+
+    let parsed = Parsed::new()
+        .with_iso_year(2020)
+        .and_then(|parsed| parsed.with_iso_week_number(NonZeroU8::new(1)?))
+        .and_then(|parsed| parsed.with_weekday(Weekday::Monday))
+        .and_then(|parsed| parsed.with_year_last_two(20))
+        .expect("valid values");
+    assert_eq!(
+        Date::try_from(parsed),
+        conflict("iso_week_number", "year_last_two")
+    );
+    assert_eq!(
+        Date::try_from(parsed.with_year_last_two(19).expect("valid value")),
+        Ok(date!(2019 - 12 - 30))
+    );
+
+    // The hour may be given in both 12-hour and 24-hour forms.
+    let parsed = Parsed::new()
+        .with_hour_24(15)
+        .and_then(|parsed| parsed.with_hour_12(NonZeroU8::new(3)?))
+        .and_then(|parsed| parsed.with_hour_12_is_pm(true))
+        .and_then(|parsed| parsed.with_minute(0))
+        .expect("valid values");
+    assert_eq!(Time::try_from(parsed), Ok(time!(15:00)));
+    assert_eq!(
+        Time::try_from(
+            parsed
+                .with_hour_12(NonZeroU8::new(4).expect("valid value"))
+                .expect("valid value")
+        ),
+        conflict("hour_24", "hour_12")
+    );
+    assert_eq!(
+        Time::try_from(parsed.with_hour_12_is_pm(false).expect("valid value")),
+        conflict("hour_24", "hour_12_is_pm")
+    );
+
+    // Conflicts can be permitted, in which case only some components are used.
+    let mut parsed = Parsed::new();
+    parsed.parse_items(
+        b"Monday 2023-05-10 03PM 23:00",
+        format_description!(
+            "[weekday] [year]-[month]-[day] [hour repr:12][period] [hour]:[minute]"
+        ),
+    )?;
+    assert!(!parsed.conflicts_allowed());
+    parsed.set_conflicts_allowed(true);
+    assert!(parsed.conflicts_allowed());
+    assert_eq!(
+        PrimitiveDateTime::try_from(parsed),
+        Ok(datetime!(2023-05-10 23:00))
+    );
+
+    // Filling in a missing year does not introduce a conflicting month and day.
+    let parsed = Parsed::new()
+        .with_ordinal(NonZeroU16::new(130).expect("valid value"))
+        .expect("valid value")
+        .with_date(date!(2023 - 01 - 01));
+    assert_eq!(parsed.month(), None);
+    assert_eq!(Date::try_from(parsed), Ok(date!(2023 - 05 - 10)));
+
+    Ok(())
+}
+
+#[test]
+fn was_parsed() -> time::Result<()> {
+    let format = format_description!("[hour]:[minute][optional [ [offset_hour]:[offset_minute]]]");
+
+    let mut parsed = Parsed::new();
+    assert!(!parsed.date_was_parsed());
+    assert!(!parsed.time_was_parsed());
+    assert!(!parsed.offset_was_parsed());
+
+    parsed.parse_items(b"12:30 +02:00", format)?;
+    assert!(!parsed.date_was_parsed());
+    assert!(parsed.time_was_parsed());
+    assert!(parsed.offset_was_parsed());
+
+    // A partially matching optional item is discarded, along with its flag.
+    for input in [b"12:30".as_slice(), b"12:30 +02"] {
+        let mut parsed = Parsed::new();
+        parsed.parse_items(input, format)?;
+        assert!(parsed.time_was_parsed());
+        assert!(!parsed.offset_was_parsed());
+        // Filling in a default does not mark the offset as parsed.
+        let parsed = parsed.with_offset(offset!(UTC));
+        assert!(!parsed.offset_was_parsed());
+        assert!(!parsed.with_date(date!(2023 - 05 - 10)).date_was_parsed());
+    }
+
+    let format = format_description!(
+        version = 2,
+        "[first [[year]-[month]-[day] [offset_hour]][[year]-[month]-[day]]]"
+    );
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2023-05-10 +02", format)?;
+    assert!(parsed.date_was_parsed());
+    assert!(parsed.offset_was_parsed());
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"2023-05-10", format)?;
+    assert!(parsed.date_was_parsed());
+    assert!(!parsed.offset_was_parsed());
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"1683722400", format_description!("[unix_timestamp]"))?;
+    assert!(parsed.date_was_parsed());
+    assert!(parsed.time_was_parsed());
+    assert!(parsed.offset_was_parsed());
+
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"x", format_description!("[ignore count:1]"))?;
+    assert!(!parsed.date_was_parsed());
+    assert!(!parsed.time_was_parsed());
+    assert!(!parsed.offset_was_parsed());
+
+    Ok(())
+}
@@ -1,13 +1,18 @@
 use std::num::{NonZeroU16, NonZeroU8};
 
+use time::ext::NumericalDuration;
 use time::format_description::modifier::Ignore;
-use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
-use time::format_description::{modifier, Component, FormatItem, OwnedFormatItem};
+use time::format_description::well_known::iso8601::{self, TimePrecision};
+use time::format_description::well_known::{
+    Iso8601, Iso8601Duration, Iso8601Dynamic, JsDate, Rfc2822, Rfc3339, Rfc3339Lenient,
+    Rfc3339NoZ, Rfc7231, Systemd, Unix,
+};
+use time::format_description::{modifier, CompiledFormat, Component, FormatItem, OwnedFormatItem};
 use time::macros::{date, datetime, offset, time};
-use time::parsing::Parsed;
+use time::parsing::{Parsable, Parsed};
 use time::{
-    error, format_description as fd, Date, Month, OffsetDateTime, PrimitiveDateTime, Time,
-    UtcOffset, Weekday,
+    error, format_description as fd, Date, Duration, Month, OffsetDateTime, PrimitiveDateTime,
+    Time, UtcOffset, Weekday,
 };
 
 macro_rules! invalid_literal {
@@ -47,6 +52,14 @@ fn rfc_2822() -> time::Result<()> {
         OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 -0607", &Rfc2822)?,
         datetime!(2021-01-02 03:04:05 -06:07),
     );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 -0007", &Rfc2822)?,
+        datetime!(2021-01-02 03:04:05 -00:07),
+    );
+    assert_eq!(
+        UtcOffset::parse("Sat, 02 Jan 2021 03:04:05 -0007", &Rfc2822)?,
+        offset!(-00:07),
+    );
     assert_eq!(
         OffsetDateTime::parse("Fri, 31 Dec 2021 23:59:60 Z", &Rfc2822)?,
         datetime!(2021-12-31 23:59:59.999_999_999 UTC),
@@ -213,6 +226,24 @@ fn rfc_2822_err() {
         OffsetDateTime::parse("Mon, 02 Jan 2021 03:04:05 -060", &Rfc2822),
         invalid_component!("offset minute")
     ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 02 Jan 2021 03:04:05 Z", &Rfc2822),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ConflictingComponents {
+                a: "day",
+                b: "weekday"
+            }
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("Sun, 02 Jan 2021 03:04:05 Z", &Rfc2822),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ConflictingComponents {
+                a: "day",
+                b: "weekday"
+            }
+        ))
+    ));
     assert!(matches!(
         OffsetDateTime::parse("Fri, 31 Dec 2021 23:59:61 Z", &Rfc2822),
         Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
@@ -224,265 +255,687 @@ fn rfc_2822_err() {
             if component.name() == "second" && component.is_conditional()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("Fri, 30 Dec 2021 23:59:60 Z", &Rfc2822),
+        OffsetDateTime::parse("Thu, 30 Dec 2021 23:59:60 Z", &Rfc2822),
         Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
             if component.name() == "second" && component.is_conditional()
     ));
 }
 
 #[test]
-fn rfc_3339() -> time::Result<()> {
-    assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05 UTC),
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2021-12-31T23:59:60Z", &Rfc3339)?,
-        datetime!(2021-12-31 23:59:59.999_999_999 UTC),
-    );
-    assert_eq!(
-        OffsetDateTime::parse("2015-07-01T00:59:60+01:00", &Rfc3339)?,
-        datetime!(2015-06-30 23:59:59.999_999_999 UTC),
-    );
+fn js_date() -> time::Result<()> {
+    // Output of `Date.prototype.toString()` in V8 with various values of `TZ`.
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.1Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.1 UTC),
+        OffsetDateTime::parse(
+            "Wed May 01 2024 13:45:30 GMT+0200 (Central European Summer Time)",
+            &JsDate
+        )?,
+        datetime!(2024-05-01 13:45:30 +02:00),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.12Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.12 UTC),
+        OffsetDateTime::parse(
+            "Wed Dec 31 1969 16:00:00 GMT-0800 (Pacific Standard Time)",
+            &JsDate
+        )?,
+        OffsetDateTime::UNIX_EPOCH,
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123 UTC),
+        OffsetDateTime::parse(
+            "Thu Jan 01 1970 05:30:00 GMT+0530 (India Standard Time)",
+            &JsDate
+        )?,
+        OffsetDateTime::UNIX_EPOCH,
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.1234Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_4 UTC),
+        OffsetDateTime::parse(
+            "Sun Mar 10 2024 12:00:00 GMT-0230 (Newfoundland Daylight Time)",
+            &JsDate
+        )?,
+        datetime!(2024-03-10 12:00:00 -02:30),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.12345Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_45 UTC),
+        OffsetDateTime::parse(
+            "Thu Jan 01 1970 00:00:00 GMT+0000 (Coordinated Universal Time)",
+            &JsDate
+        )?,
+        OffsetDateTime::UNIX_EPOCH,
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123456Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456 UTC),
+        OffsetDateTime::parse("Wed May 01 2024 13:45:30 GMT+0200", &JsDate)?,
+        datetime!(2024-05-01 13:45:30 +02:00),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.1234567Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_7 UTC),
+        OffsetDateTime::parse("Wed May 01 2024 13:45:30 GMT-0030 ()", &JsDate)?,
+        datetime!(2024-05-01 13:45:30 -00:30),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.12345678Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_78 UTC),
+        Date::parse("Wed May 01 2024 13:45:30 GMT+0200", &JsDate)?,
+        date!(2024 - 05 - 01),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123456789Z", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_789 UTC),
+        Time::parse("Wed May 01 2024 13:45:30 GMT+0200", &JsDate)?,
+        time!(13:45:30),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_789 -01:02),
+        UtcOffset::parse("Wed May 01 2024 13:45:30 GMT-0230", &JsDate)?,
+        offset!(-02:30),
     );
+
+    Ok(())
+}
+
+#[test]
+fn js_date_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("Wen May 01 2024 13:45:30 GMT+0200", &JsDate),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed, May 01 2024 13:45:30 GMT+0200", &JsDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed Mai 01 2024 13:45:30 GMT+0200", &JsDate),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed May 1 2024 13:45:30 GMT+0200", &JsDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed May 01 24 13:45:30 GMT+0200", &JsDate),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed May 01 2024 13:45 GMT+0200", &JsDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed May 01 2024 13:45:30 +0200", &JsDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed May 01 2024 13:45:30 GMT", &JsDate),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed May 01 2024 13:45:30 GMT+02", &JsDate),
+        invalid_component!("offset minute")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed May 01 2024 13:45:30 GMT+0200 (CEST", &JsDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Wed May 01 2024 13:45:30 GMT+0200 (CEST) ", &JsDate),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Fri Feb 30 2024 13:45:30 GMT+0200", &JsDate),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "day"
+    ));
+}
+
+#[test]
+fn systemd() -> time::Result<()> {
+    // As printed by `journalctl -o short-full --utc` and `systemctl show -p ActiveEnterTimestamp`.
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123456789+01:02", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123_456_789 +01:02),
+        OffsetDateTime::parse("2024-05-01 13:45:30.123456 UTC", &Systemd)?,
+        datetime!(2024-05-01 13:45:30.123_456 UTC),
     );
     assert_eq!(
-        OffsetDateTime::parse("2021-01-02T03:04:05.123-00:01", &Rfc3339)?,
-        datetime!(2021-01-02 03:04:05.123 -00:01),
+        OffsetDateTime::parse("2024-05-01 13:45:30 UTC", &Systemd)?,
+        datetime!(2024-05-01 13:45:30 UTC),
     );
-
     assert_eq!(
-        Date::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
-        date!(2021 - 01 - 02),
+        OffsetDateTime::parse("2024-05-01 15:45:30.000001 +0200", &Systemd)?,
+        datetime!(2024-05-01 15:45:30.000_001 +02:00),
     );
     assert_eq!(
-        Date::parse("2021-01-02T03:04:05.123+01:02", &Rfc3339)?,
-        date!(2021 - 01 - 02),
+        OffsetDateTime::parse("2024-05-01 06:45:30 -0700", &Systemd)?,
+        datetime!(2024-05-01 06:45:30 -07:00),
     );
     assert_eq!(
-        Date::parse("2021-01-02T03:04:05.123-01:02", &Rfc3339)?,
-        date!(2021 - 01 - 02),
+        OffsetDateTime::parse("2024-05-01 13:45:30.000000 +0000", &Systemd)?,
+        datetime!(2024-05-01 13:45:30 UTC),
     );
-
     assert_eq!(
-        UtcOffset::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
-        offset!(UTC),
+        OffsetDateTime::parse("2024-05-01 13:15:30 -0030", &Systemd)?,
+        datetime!(2024-05-01 13:15:30 -00:30),
     );
     assert_eq!(
-        UtcOffset::parse("2021-01-02T03:04:05.123+01:02", &Rfc3339)?,
-        offset!(+01:02),
+        Date::parse("2024-05-01 13:45:30.123456 UTC", &Systemd)?,
+        date!(2024 - 05 - 01),
     );
     assert_eq!(
-        UtcOffset::parse("2021-01-02T03:04:05.123-01:02", &Rfc3339)?,
-        offset!(-01:02),
+        Time::parse("2024-05-01 13:45:30.123456 UTC", &Systemd)?,
+        time!(13:45:30.123_456),
     );
     assert_eq!(
-        UtcOffset::parse("2021-01-02T03:04:05.123-00:01", &Rfc3339)?,
-        offset!(-00:01),
+        UtcOffset::parse("2024-05-01 06:45:30 -0700", &Systemd)?,
+        offset!(-7),
     );
 
     Ok(())
 }
 
 #[test]
-fn rfc_3339_err() {
+fn systemd_err() {
     assert!(matches!(
-        PrimitiveDateTime::parse("x", &Rfc3339),
+        OffsetDateTime::parse("24-05-01 13:45:30 UTC", &Systemd),
         invalid_component!("year")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021x", &Rfc3339),
-        invalid_literal!()
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021-x", &Rfc3339),
-        invalid_component!("month")
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021-0", &Rfc3339),
+        OffsetDateTime::parse("2024-13-01 13:45:30 UTC", &Systemd),
         invalid_component!("month")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01x", &Rfc3339),
+        OffsetDateTime::parse("2024-05-01T13:45:30 UTC", &Systemd),
         invalid_literal!()
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-0", &Rfc3339),
-        invalid_component!("day")
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01x", &Rfc3339),
+        OffsetDateTime::parse("2024-05-01 13:45 UTC", &Systemd),
         invalid_literal!()
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T0", &Rfc3339),
-        invalid_component!("hour")
+        OffsetDateTime::parse("2024-05-01 13:45:30.12345 UTC", &Systemd),
+        invalid_component!("subsecond")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00x", &Rfc3339),
+        OffsetDateTime::parse("2024-05-01 13:45:30.1234567 UTC", &Systemd),
         invalid_literal!()
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:0", &Rfc3339),
-        invalid_component!("minute")
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        OffsetDateTime::parse("2024-05-01 13:45:30", &Systemd),
         invalid_literal!()
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
-        invalid_component!("second")
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
-        invalid_component!("subsecond")
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
-        invalid_component!("offset hour")
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
+        OffsetDateTime::parse("2024-05-01 13:45:30 GMT", &Systemd),
         invalid_component!("offset hour")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
-        invalid_literal!()
-    ));
-    assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-01T00:00:00+00:0", &Rfc3339),
+        OffsetDateTime::parse("2024-05-01 13:45:30 +02:00", &Systemd),
         invalid_component!("offset minute")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-13-01T00:00:00Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "month"
+        OffsetDateTime::parse("2024-05-01 13:45:30 CEST", &Systemd),
+        invalid_component!("offset hour")
     ));
     assert!(matches!(
-        PrimitiveDateTime::parse("2021-01-02T03:04:60Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+        OffsetDateTime::parse("2024-02-30 13:45:30 UTC", &Systemd),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "day"
     ));
+}
 
-    // Conversions to offset-unaware types do not perform special treatment for leap seconds
-    // even if the input could refer to one.
+#[test]
+fn rfc_7231() -> time::Result<()> {
+    // The examples from section 7.1.1.1 of the RFC.
+    assert_eq!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &Rfc7231)?,
+        datetime!(1994-11-06 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &Rfc7231)?,
+        datetime!(1994-11-06 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &Rfc7231)?,
+        datetime!(1994-11-06 08:49:37 UTC),
+    );
+
+    assert_eq!(
+        OffsetDateTime::parse("Wed, 01 May 2024 13:45:30 GMT", &Rfc7231)?,
+        datetime!(2024-05-01 13:45:30 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Wednesday, 01-May-24 13:45:30 GMT", &Rfc7231)?,
+        datetime!(2024-05-01 13:45:30 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Thursday, 30-Dec-49 00:00:00 GMT", &Rfc7231)?,
+        datetime!(2049-12-30 00:00:00 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Thursday, 01-Jan-70 00:00:00 GMT", &Rfc7231)?,
+        OffsetDateTime::UNIX_EPOCH,
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Fri Dec 31 23:59:59 1999", &Rfc7231)?,
+        datetime!(1999-12-31 23:59:59 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Fri, 31 Dec 2021 23:59:60 GMT", &Rfc7231)?,
+        datetime!(2021-12-31 23:59:59.999_999_999 UTC),
+    );
+    assert_eq!(
+        Date::parse("Sun Nov  6 08:49:37 1994", &Rfc7231)?,
+        date!(1994 - 11 - 06),
+    );
+    assert_eq!(
+        Time::parse("Sunday, 06-Nov-94 08:49:37 GMT", &Rfc7231)?,
+        time!(08:49:37),
+    );
+    assert_eq!(
+        UtcOffset::parse("Sun, 06 Nov 1994 08:49:37 GMT", &Rfc7231)?,
+        UtcOffset::UTC,
+    );
+
+    Ok(())
+}
+
+#[test]
+fn rfc_7231_err() {
     assert!(matches!(
-        PrimitiveDateTime::parse("2022-01-01T00:59:60+01:00", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+        OffsetDateTime::parse("sun, 06 Nov 1994 08:49:37 GMT", &Rfc7231),
+        invalid_component!("weekday")
     ));
     assert!(matches!(
-        Time::parse("2021-12-31T23:04:60Z", &Rfc3339),
-        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+        OffsetDateTime::parse("Sun, 06 nov 1994 08:49:37 GMT", &Rfc7231),
+        invalid_component!("month")
     ));
-
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-02T03:04:05Z ", &Rfc3339),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        OffsetDateTime::parse("Sun, 6 Nov 1994 08:49:37 GMT", &Rfc7231),
+        invalid_component!("day")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("x", &Rfc3339),
+        OffsetDateTime::parse("Sun, 06 Nov 94 08:49:37 GMT", &Rfc7231),
         invalid_component!("year")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021x", &Rfc3339),
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 +0000", &Rfc7231),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-x", &Rfc3339),
-        invalid_component!("month")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-0", &Rfc3339),
-        invalid_component!("month")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01x", &Rfc3339),
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 UTC", &Rfc7231),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-0", &Rfc3339),
-        invalid_component!("day")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01x", &Rfc3339),
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49 GMT", &Rfc7231),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T0", &Rfc3339),
-        invalid_component!("hour")
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00x", &Rfc3339),
+        OffsetDateTime::parse("Sun,06 Nov 1994 08:49:37 GMT", &Rfc7231),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:0", &Rfc3339),
-        invalid_component!("minute")
+        OffsetDateTime::parse("Sunday 06-Nov-94 08:49:37 GMT", &Rfc7231),
+        invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        OffsetDateTime::parse("Sunday, 06 Nov 1994 08:49:37 GMT", &Rfc7231),
         invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
-        invalid_component!("second")
+        OffsetDateTime::parse("Sunday, 06-Nov-1994 08:49:37 GMT", &Rfc7231),
+        invalid_literal!()
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
-        invalid_component!("subsecond")
+        OffsetDateTime::parse("Sun Nov 6 08:49:37 1994", &Rfc7231),
+        invalid_component!("day")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
-        invalid_component!("offset hour")
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994 GMT", &Rfc7231),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
-        invalid_component!("offset hour")
+        OffsetDateTime::parse("Mon, 06 Nov 1994 08:49:37 GMT", &Rfc7231),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ConflictingComponents {
+                a: "day",
+                b: "weekday"
+            }
+        ))
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
-        invalid_literal!()
+        OffsetDateTime::parse("Wed, 30 Feb 2024 08:49:37 GMT", &Rfc7231),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "day"
+    ));
+}
+
+#[test]
+fn unix() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("0", &Unix)?,
+        OffsetDateTime::UNIX_EPOCH
+    );
+    assert_eq!(
+        OffsetDateTime::parse("1714567890", &Unix)?,
+        datetime!(2024-05-01 12:51:30 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("+1714567890", &Unix)?,
+        datetime!(2024-05-01 12:51:30 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("1714567890.25", &Unix)?,
+        datetime!(2024-05-01 12:51:30.25 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("1714567890.000000001", &Unix)?,
+        datetime!(2024-05-01 12:51:30.000_000_001 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("1714567890.250", &Unix)?,
+        datetime!(2024-05-01 12:51:30.25 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("-1", &Unix)?,
+        datetime!(1969-12-31 23:59:59 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("-0.25", &Unix)?,
+        datetime!(1969-12-31 23:59:59.75 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("-377705116800", &Unix)?,
+        datetime!(-9999-01-01 0:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("1714567890.25", &Unix)?.offset(),
+        offset!(UTC)
+    );
+    Ok(())
+}
+
+#[test]
+fn unix_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("", &Unix),
+        invalid_component!("unix_timestamp")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("-", &Unix),
+        invalid_component!("unix_timestamp")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse(".5", &Unix),
+        invalid_component!("unix_timestamp")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("1714567890.", &Unix),
+        invalid_component!("unix_timestamp")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("1714567890.1234567891", &Unix),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("1714567890Z", &Unix),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("99999999999999", &Unix),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "timestamp"
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("-99999999999999", &Unix),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "timestamp"
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("1714567890", &Unix),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+}
+
+#[test]
+fn rfc_3339() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-12-31T23:59:60Z", &Rfc3339)?,
+        datetime!(2021-12-31 23:59:59.999_999_999 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2015-07-01T00:59:60+01:00", &Rfc3339)?,
+        datetime!(2015-06-30 23:59:59.999_999_999 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.1 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.12Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.12 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1234Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_4 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.12345Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_45 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.1234567Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_7 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.12345678Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_78 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456789Z", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_789 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_789 -01:02),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456789+01:02", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123_456_789 +01:02),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05.123-00:01", &Rfc3339)?,
+        datetime!(2021-01-02 03:04:05.123 -00:01),
+    );
+
+    assert_eq!(
+        Date::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
+        date!(2021 - 01 - 02),
+    );
+    assert_eq!(
+        Date::parse("2021-01-02T03:04:05.123+01:02", &Rfc3339)?,
+        date!(2021 - 01 - 02),
+    );
+    assert_eq!(
+        Date::parse("2021-01-02T03:04:05.123-01:02", &Rfc3339)?,
+        date!(2021 - 01 - 02),
+    );
+
+    assert_eq!(
+        UtcOffset::parse("2021-01-02T03:04:05Z", &Rfc3339)?,
+        offset!(UTC),
+    );
+    assert_eq!(
+        UtcOffset::parse("2021-01-02T03:04:05.123+01:02", &Rfc3339)?,
+        offset!(+01:02),
+    );
+    assert_eq!(
+        UtcOffset::parse("2021-01-02T03:04:05.123-01:02", &Rfc3339)?,
+        offset!(-01:02),
+    );
+    assert_eq!(
+        UtcOffset::parse("2021-01-02T03:04:05.123-00:01", &Rfc3339)?,
+        offset!(-00:01),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn rfc_3339_err() {
+    assert!(matches!(
+        PrimitiveDateTime::parse("x", &Rfc3339),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-x", &Rfc3339),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-0", &Rfc3339),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-0", &Rfc3339),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T0", &Rfc3339),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:0", &Rfc3339),
+        invalid_component!("minute")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
+        invalid_component!("second")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
+        invalid_component!("subsecond")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-01T00:00:00+00:0", &Rfc3339),
+        invalid_component!("offset minute")
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-13-01T00:00:00Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "month"
+    ));
+    assert!(matches!(
+        PrimitiveDateTime::parse("2021-01-02T03:04:60Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+    ));
+
+    // Conversions to offset-unaware types do not perform special treatment for leap seconds
+    // even if the input could refer to one.
+    assert!(matches!(
+        PrimitiveDateTime::parse("2022-01-01T00:59:60+01:00", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+    ));
+    assert!(matches!(
+        Time::parse("2021-12-31T23:04:60Z", &Rfc3339),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component))) if component.name() == "second"
+    ));
+
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z ", &Rfc3339),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("x", &Rfc3339),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-x", &Rfc3339),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-0", &Rfc3339),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-0", &Rfc3339),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T0", &Rfc3339),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:0", &Rfc3339),
+        invalid_component!("minute")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00x", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:0", &Rfc3339),
+        invalid_component!("second")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00.x", &Rfc3339),
+        invalid_component!("subsecond")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00x", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+0", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-01T00:00:00+00x", &Rfc3339),
+        invalid_literal!()
     ));
     assert!(matches!(
         OffsetDateTime::parse("2021-01-01T00:00:00+00:0", &Rfc3339),
@@ -518,6 +971,95 @@ fn rfc_3339_err() {
     ));
 }
 
+#[test]
+fn rfc_3339_no_z() -> time::Result<()> {
+    for input in [
+        "2021-01-02T03:04:05Z",
+        "2021-01-02T03:04:05+00:00",
+        "2021-01-02T03:04:05.123-01:02",
+        "2021-12-31T23:59:60Z",
+        "2021-01-02T03:04:05",
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse(input, &Rfc3339NoZ),
+            OffsetDateTime::parse(input, &Rfc3339)
+        );
+        assert_eq!(
+            PrimitiveDateTime::parse(input, &Rfc3339NoZ),
+            PrimitiveDateTime::parse(input, &Rfc3339)
+        );
+    }
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc3339NoZ)?,
+        datetime!(2021-01-02 03:04:05 UTC)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn rfc_3339_lenient() -> time::Result<()> {
+    // Accepted by both the strict and lenient formats.
+    for input in [
+        "2021-01-02T03:04:05.123-01:02",
+        "2021-01-02t03:04:05.123-01:02",
+        "2021-01-02T03:04:05.123z",
+        "2021-12-31T23:59:60Z",
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse(input, &Rfc3339Lenient),
+            OffsetDateTime::parse(input, &Rfc3339)
+        );
+        assert!(OffsetDateTime::parse(input, &Rfc3339).is_ok());
+    }
+
+    // Only accepted by the lenient format.
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02 03:04:05.123-01:02", &Rfc3339Lenient)?,
+        datetime!(2021-01-02 03:04:05.123 -01:02)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05,123-01:02", &Rfc3339Lenient)?,
+        datetime!(2021-01-02 03:04:05.123 -01:02)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02 03:04:05,123z", &Rfc3339Lenient)?,
+        datetime!(2021-01-02 03:04:05.123 UTC)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2021-01-02 03:04:05,123Z", &Rfc3339Lenient)?,
+        datetime!(2021-01-02 03:04:05.123)
+    );
+    assert_eq!(
+        Date::parse("2021-01-02 03:04:05Z", &Rfc3339Lenient)?,
+        date!(2021-01-02)
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02 03:04:05Z", &Rfc3339),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05,123Z", &Rfc3339),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        Date::parse("2021-01-02 03:04:05Z", &Rfc3339),
+        invalid_literal!()
+    ));
+
+    // Relaxations beyond those listed are still rejected.
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02_03:04:05Z", &Rfc3339Lenient),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02 03:04:05,Z", &Rfc3339Lenient),
+        invalid_component!("subsecond")
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn iso_8601() {
     assert_eq!(
@@ -553,67 +1095,488 @@ fn iso_8601() {
         Ok(datetime!(2021-01-02 03:00:00 UTC))
     );
     assert_eq!(
-        OffsetDateTime::parse("20210102T0304Z", &Iso8601::DEFAULT),
-        Ok(datetime!(2021-01-02 03:04:00 UTC))
+        OffsetDateTime::parse("20210102T0304Z", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-01-02 03:04:00 UTC))
+    );
+    assert_eq!(
+        UtcOffset::parse("+0304", &Iso8601::DEFAULT),
+        Ok(offset!(+03:04))
+    );
+    assert_eq!(
+        UtcOffset::parse("-03", &Iso8601::DEFAULT),
+        Ok(offset!(-03:00))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-01-02 03:04:05 +01:00))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20210102T030405-01", &Iso8601::DEFAULT),
+        Ok(datetime!(2021-01-02 03:04:05 -01:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2022-07-22T12:52:50.349409", &Iso8601::DEFAULT),
+        Ok(datetime!(2022-07-22 12:52:50.349409000))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("1900-01-01T00:00:00+00:19:32", &Iso8601::DEFAULT),
+        Ok(datetime!(1900-01-01 0:00 +00:19:32))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("19000101T000000-001932", &Iso8601::DEFAULT),
+        Ok(datetime!(1900-01-01 0:00 -00:19:32))
+    );
+    assert_eq!(
+        UtcOffset::parse("+00:19:32", &Iso8601::DEFAULT),
+        Ok(offset!(+00:19:32))
+    );
+}
+
+#[test]
+fn iso_8601_error() {
+    assert!(matches!(
+        OffsetDateTime::parse("20210102T03:04Z", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("20210102T03.", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-0102", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-x", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-Wx", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-W012", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-W01-x", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:x", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04x", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01:", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01:00:", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01:00:60", &Iso8601::DEFAULT),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "offset second"
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+0", &Iso8601::DEFAULT),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("01:02", &Iso8601::DEFAULT),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+}
+
+#[test]
+fn iso_8601_reduced_precision() -> time::Result<()> {
+    const STRICT: iso8601::EncodedConfig = iso8601::Config::DEFAULT
+        .set_strict_time_precision(true)
+        .encode();
+
+    macro_rules! assert_roundtrip {
+        ($precision:ident, $formatted:literal, $parsed:expr) => {{
+            const CONFIG: iso8601::EncodedConfig = iso8601::Config::DEFAULT
+                .set_time_precision(TimePrecision::$precision {
+                    decimal_digits: None,
+                })
+                .encode();
+            let formatted = datetime!(2024-05-01 13:45:30.5 UTC).format(&Iso8601::<CONFIG>)?;
+            assert_eq!(formatted, $formatted);
+            assert_eq!(OffsetDateTime::parse(&formatted, &Iso8601::<CONFIG>)?, $parsed);
+        }};
+    }
+
+    assert_roundtrip!(Hour, "2024-05-01T13Z", datetime!(2024-05-01 13:00 UTC));
+    assert_roundtrip!(Minute, "2024-05-01T13:45Z", datetime!(2024-05-01 13:45 UTC));
+    assert_roundtrip!(
+        Second,
+        "2024-05-01T13:45:30Z",
+        datetime!(2024-05-01 13:45:30 UTC)
+    );
+
+    assert_eq!(
+        PrimitiveDateTime::parse("2024-05-01T13", &Iso8601::DEFAULT)?,
+        datetime!(2024-05-01 13:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2024-05-01T13:45", &Iso8601::DEFAULT)?,
+        datetime!(2024-05-01 13:45)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("20240501T13", &Iso8601::DEFAULT)?,
+        datetime!(2024-05-01 13:00)
+    );
+    assert_eq!(Time::parse("13", &Iso8601::DEFAULT)?, time!(13:00));
+    assert_eq!(Time::parse("13:45", &Iso8601::DEFAULT)?, time!(13:45));
+    assert_eq!(
+        PrimitiveDateTime::parse("2024-05-01T13:45:30", &Iso8601::<STRICT>)?,
+        datetime!(2024-05-01 13:45:30)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2024-05-01T13:45:30.5", &Iso8601::<STRICT>)?,
+        datetime!(2024-05-01 13:45:30.5)
+    );
+
+    // Dangling separators are never permitted.
+    assert!(PrimitiveDateTime::parse("2024-05-01T13:", &Iso8601::DEFAULT).is_err());
+    assert!(PrimitiveDateTime::parse("2024-05-01T13:45:", &Iso8601::DEFAULT).is_err());
+    assert!(OffsetDateTime::parse("2024-05-01T13:Z", &Iso8601::DEFAULT).is_err());
+    assert!(Time::parse("13:", &Iso8601::DEFAULT).is_err());
+    assert!(Time::parse("13:45:", &Iso8601::DEFAULT).is_err());
+
+    // Reduced precision is rejected when the configuration is strict.
+    for input in ["13", "13.5", "13:45", "13:45.5", "1345"] {
+        assert!(Time::parse(input, &Iso8601::<STRICT>).is_err());
+        assert!(Time::parse(input, &Iso8601::DEFAULT).is_ok());
+    }
+    assert!(PrimitiveDateTime::parse("2024-05-01T13", &Iso8601::<STRICT>).is_err());
+    assert!(PrimitiveDateTime::parse("2024-05-01T13:45", &Iso8601::<STRICT>).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_dynamic() {
+    let inputs = [
+        "2021-01-02T03:04:05Z",
+        "2021-002T03:04:05.123+01:00",
+        "2021-W01-2T03:04:05-0100",
+        "20210102T030405.1Z",
+        "-002021-01-02T03:04:05+01:00",
+        "2021-01-02T03.5Z",
+        "2021-01-02T03:04Z",
+        "T03:04:05",
+        "03:04:05+01",
+        "+01:00",
+        "2021-01-02",
+        "2021-13-02T03:04:05Z",
+        "2021-01-02T03:04:05+01:00:30",
+        "",
+    ];
+
+    macro_rules! assert_same_as_const {
+        ($config:expr) => {{
+            const CONFIG: iso8601::EncodedConfig = $config.encode();
+            let dynamic = Iso8601Dynamic::from_config($config);
+            for input in inputs {
+                assert_eq!(
+                    OffsetDateTime::parse(input, &dynamic),
+                    OffsetDateTime::parse(input, &Iso8601::<CONFIG>),
+                    "{input}"
+                );
+                assert_eq!(
+                    PrimitiveDateTime::parse(input, &dynamic),
+                    PrimitiveDateTime::parse(input, &Iso8601::<CONFIG>),
+                    "{input}"
+                );
+                assert_eq!(
+                    Date::parse(input, &dynamic),
+                    Date::parse(input, &Iso8601::<CONFIG>),
+                    "{input}"
+                );
+                assert_eq!(
+                    Time::parse(input, &dynamic),
+                    Time::parse(input, &Iso8601::<CONFIG>),
+                    "{input}"
+                );
+                assert_eq!(
+                    UtcOffset::parse(input, &dynamic),
+                    UtcOffset::parse(input, &Iso8601::<CONFIG>),
+                    "{input}"
+                );
+            }
+        }};
+    }
+
+    assert_same_as_const!(iso8601::Config::DEFAULT);
+    assert_same_as_const!(iso8601::Config::DEFAULT.set_strict_time_precision(true));
+    assert_same_as_const!(
+        iso8601::Config::DEFAULT
+            .set_formatted_components(iso8601::FormattedComponents::None)
+            .set_use_separators(false)
+            .set_time_precision(TimePrecision::Hour {
+                decimal_digits: None,
+            })
+    );
+
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Iso8601Dynamic::DEFAULT),
+        Ok(datetime!(2021-01-02 03:04:05 UTC))
+    );
+}
+
+#[test]
+fn iso_8601_duration() -> time::Result<()> {
+    let format = Iso8601Duration::DEFAULT;
+    let parse = |input| Duration::parse(input, &format);
+    assert_eq!(parse("PT0S")?, Duration::ZERO);
+    assert_eq!(parse("P0D")?, Duration::ZERO);
+    assert_eq!(parse("-PT0S")?, Duration::ZERO);
+    assert_eq!(parse("PT1H30M")?, 90.minutes());
+    assert_eq!(parse("+PT1H30M")?, 90.minutes());
+    assert_eq!(parse("-PT1H30M")?, (-90).minutes());
+    assert_eq!(parse("P2W")?, 2.weeks());
+    assert_eq!(parse("P1W2D")?, 9.days());
+    assert_eq!(
+        parse("P1DT2H3M4.5S")?,
+        1.days() + 2.hours() + 3.minutes() + 4.5.seconds()
+    );
+    assert_eq!(parse("PT36H")?, 36.hours());
+    assert_eq!(parse("PT0.5S")?, 500.milliseconds());
+    assert_eq!(parse("PT0,5S")?, 500.milliseconds());
+    assert_eq!(parse("PT1.5H")?, 90.minutes());
+    assert_eq!(parse("P0.5W")?, 84.hours());
+    assert_eq!(parse("PT1.0000000019S")?, 1.000_000_001.seconds());
+    assert_eq!(
+        parse("PT0.000000001999999999999999999999S")?,
+        1.nanoseconds()
     );
+    assert_eq!(parse("PT01M")?, 1.minutes());
     assert_eq!(
-        UtcOffset::parse("+0304", &Iso8601::DEFAULT),
-        Ok(offset!(+03:04))
+        parse("P106751991167300DT15H30M7.999999999S")?,
+        Duration::MAX
     );
     assert_eq!(
-        PrimitiveDateTime::parse("2022-07-22T12:52:50.349409", &Iso8601::DEFAULT),
-        Ok(datetime!(2022-07-22 12:52:50.349409000))
+        parse("-P106751991167300DT15H30M8.999999999S")?,
+        Duration::MIN
     );
+
+    // Formatting and parsing round trip.
+    for duration in [
+        Duration::ZERO,
+        1.nanoseconds(),
+        (-1.5).seconds(),
+        90.minutes(),
+        26.hours(),
+        2.weeks() + 3.minutes() + 1.nanoseconds(),
+        Duration::MAX,
+        Duration::MIN,
+    ] {
+        for format in [
+            Iso8601Duration::DEFAULT,
+            Iso8601Duration::DEFAULT.set_use_days(false),
+            Iso8601Duration::DEFAULT.set_decimal_digits(NonZeroU8::new(9)),
+        ] {
+            assert_eq!(
+                Duration::parse(&duration.format(&format)?, &format)?,
+                duration
+            );
+        }
+    }
+
+    Ok(())
 }
 
 #[test]
-fn iso_8601_error() {
-    assert!(matches!(
-        OffsetDateTime::parse("20210102T03:04Z", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("20210102T03.", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-0102", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-01-x", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
-    ));
-    assert!(matches!(
-        OffsetDateTime::parse("2021-Wx", &Iso8601::DEFAULT),
+fn iso_8601_duration_err() {
+    let parse = |input| Duration::parse(input, &Iso8601Duration::DEFAULT);
+    assert!(matches!(parse(""), invalid_literal!()));
+    assert!(matches!(parse("T1H"), invalid_literal!()));
+    assert!(matches!(parse("p1d"), invalid_literal!()));
+    assert!(matches!(parse("P"), invalid_component!("value")));
+    assert!(matches!(parse("PT"), invalid_component!("value")));
+    assert!(matches!(parse("P1DT"), invalid_component!("value")));
+    assert!(matches!(parse("PT.5S"), invalid_component!("value")));
+    assert!(matches!(parse("PT1.S"), invalid_component!("value")));
+    assert!(matches!(parse("--PT1S"), invalid_literal!()));
+    // Years and months have no fixed length.
+    assert!(matches!(parse("P1Y"), invalid_component!("unit")));
+    assert!(matches!(parse("P1M"), invalid_component!("unit")));
+    // Units must be in the correct part and in descending order.
+    assert!(matches!(parse("P1H"), invalid_component!("unit")));
+    assert!(matches!(parse("PT1D"), invalid_component!("unit")));
+    assert!(matches!(parse("PT1S1M"), invalid_component!("unit")));
+    assert!(matches!(parse("PT1M1M"), invalid_component!("unit")));
+    assert!(matches!(parse("P1D2W"), invalid_component!("unit")));
+    assert!(matches!(parse("PT1Hx"), invalid_component!("value")));
+    // Only the last component may be fractional.
+    assert!(matches!(
+        parse("PT1.5H30M"),
         Err(error::Parse::UnexpectedTrailingCharacters { .. })
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-W012", &Iso8601::DEFAULT),
+        parse("P1.5DT1H"),
         Err(error::Parse::UnexpectedTrailingCharacters { .. })
     ));
+    // Overflow is an error rather than a panic.
     assert!(matches!(
-        OffsetDateTime::parse("2021-W01-x", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        parse("P106751991167300DT15H30M8S"),
+        invalid_component!("value")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-02T03:x", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        parse("-P106751991167300DT15H30M9S"),
+        invalid_component!("value")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-02T03:04x", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        parse("PT999999999999999999999999999999999999999H"),
+        invalid_component!("value")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("2021-01-02T03:04:", &Iso8601::DEFAULT),
-        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+        parse("PT9999999999999999999999999999999999999999S"),
+        invalid_component!("value")
     ));
+}
+
+#[test]
+fn well_known_convenience() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse_rfc3339("2021-01-02T03:04:05.123456789-01:02")?,
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", &Rfc3339)?
+    );
+    assert_eq!(
+        OffsetDateTime::parse_rfc2822("Sat, 02 Jan 2021 03:04:05 -0102")?,
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 -0102", &Rfc2822)?
+    );
+    assert_eq!(
+        OffsetDateTime::parse_iso8601("2021-01-02T03:04:05.123456789-01:02")?,
+        OffsetDateTime::parse("2021-01-02T03:04:05.123456789-01:02", &Iso8601::DEFAULT)?
+    );
+    assert_eq!(Date::parse_iso8601("2021-01-02")?, date!(2021 - 01 - 02));
+    assert_eq!(Date::parse_iso8601("2021-002")?, date!(2021 - 01 - 02));
+    assert_eq!(Date::parse_iso8601("2020-W53-6")?, date!(2021 - 01 - 02));
+
+    assert!(OffsetDateTime::parse_rfc3339("2021-01-02T03:04:05").is_err());
+    assert!(OffsetDateTime::parse_rfc2822("2021-01-02T03:04:05Z").is_err());
+    assert_eq!(
+        Date::parse_iso8601("2021-01-02T03:04:05Z")?,
+        Date::parse("2021-01-02T03:04:05Z", &Iso8601::DEFAULT)?
+    );
+    assert!(Date::parse_iso8601("2021-01-32").is_err());
+
+    Ok(())
+}
+
+#[test]
+fn well_known_component_range() {
+    /// Parse only the type containing the component, going through `Parsed`.
+    fn parse_component(input: &str, format: &impl Parsable, name: &str) -> Result<(), error::Parse> {
+        match name {
+            "month" | "day" => Date::parse(input, format).map(drop),
+            "offset hour" | "offset minute" => UtcOffset::parse(input, format).map(drop),
+            _ => Time::parse(input, format).map(drop),
+        }
+    }
+
+    let cases = [
+        (
+            "2024-13-01T00:00:00Z",
+            None,
+            "month",
+            Month::try_from(13).err(),
+        ),
+        (
+            "2024-01-32T00:00:00Z",
+            Some("Wed, 32 Jan 2024 00:00:00 +0000"),
+            "day",
+            Date::from_calendar_date(2024, Month::January, 32).err(),
+        ),
+        (
+            "2024-01-01T25:00:00Z",
+            Some("Mon, 01 Jan 2024 25:00:00 +0000"),
+            "hour",
+            Time::from_hms(25, 0, 0).err(),
+        ),
+        (
+            "2024-01-01T00:61:00Z",
+            Some("Mon, 01 Jan 2024 00:61:00 +0000"),
+            "minute",
+            Time::from_hms(0, 61, 0).err(),
+        ),
+        (
+            "2024-01-01T00:00:61Z",
+            Some("Mon, 01 Jan 2024 00:00:61 +0000"),
+            "second",
+            Time::from_hms(0, 0, 61).err(),
+        ),
+        (
+            "2024-01-01T00:00:00+01:60",
+            Some("Mon, 01 Jan 2024 00:00:00 +0160"),
+            "offset minute",
+            None,
+        ),
+        (
+            "2024-01-01T00:00:00+24:00",
+            Some("Mon, 01 Jan 2024 00:00:00 +2400"),
+            "offset hour",
+            None,
+        ),
+    ];
+
+    for (input, rfc_2822_input, name, expected) in cases {
+        let mut results = vec![
+            OffsetDateTime::parse(input, &Rfc3339).map(drop),
+            parse_component(input, &Rfc3339, name),
+            OffsetDateTime::parse(input, &Iso8601::DEFAULT).map(drop),
+            parse_component(input, &Iso8601::DEFAULT, name),
+        ];
+        if let Some(input) = rfc_2822_input {
+            results.push(OffsetDateTime::parse(input, &Rfc2822).map(drop));
+            results.push(parse_component(input, &Rfc2822, name));
+        }
+
+        for result in results {
+            match result {
+                Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(
+                    component,
+                ))) => {
+                    assert_eq!(component.name(), name);
+                    if let Some(expected) = expected {
+                        assert_eq!(component, expected);
+                    }
+                }
+                result => panic!("expected {name} to be out of range in {input}, got {result:?}"),
+            }
+        }
+    }
+}
+
+#[test]
+fn iso_8601_basic_time_not_date() -> time::Result<()> {
+    // The leading digits could be a year and an out-of-range month, but are a valid time.
+    assert_eq!(Time::parse("123456", &Iso8601::DEFAULT)?, time!(12:34:56));
+    assert_eq!(Time::parse("203456", &Iso8601::DEFAULT)?, time!(20:34:56));
+    assert_eq!(
+        PrimitiveDateTime::parse("20240101T123456", &Iso8601::DEFAULT)?,
+        datetime!(2024-01-01 12:34:56)
+    );
     assert!(matches!(
-        OffsetDateTime::parse("01:02", &Iso8601::DEFAULT),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
-        ))
+        Date::parse("2024-13-01", &Iso8601::DEFAULT),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "month"
     ));
+    Ok(())
 }
 
 #[test]
@@ -639,6 +1602,24 @@ fn parse_time() -> time::Result<()> {
         (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
         (fd::parse("[hour repr:12] [period]")?, "12 AM", time!(12 AM)),
         (fd::parse("[hour repr:12] [period]")?, "12 PM", time!(12 PM)),
+        (fd::parse("[period] [hour repr:12]")?, "PM 01", time!(1 PM)),
+        (
+            fd::parse("[period] [hour repr:12 padding:none]:[minute]")?,
+            "PM 3:45",
+            time!(3:45 PM),
+        ),
+        (
+            fd::parse("[period] [hour repr:12]:[minute]:[second]")?,
+            "AM 01:02:03",
+            time!(1:02:03 AM),
+        ),
+        (fd::parse("[period] [hour repr:12]")?, "AM 12", time!(0:00)),
+        (fd::parse("[period] [hour repr:12]")?, "PM 12", time!(12:00)),
+        (
+            fd::parse("[period case:lower][hour repr:12]:[minute]")?,
+            "am12:30",
+            time!(0:30),
+        ),
     ];
 
     for (format_description, input, output) in &format_input_output {
@@ -663,18 +1644,18 @@ fn parse_time() -> time::Result<()> {
 fn parse_time_err() -> time::Result<()> {
     assert!(matches!(
         Time::try_from(Parsed::new()),
-        Err(error::TryFromParsed::InsufficientInformation { .. })
+        Err(error::TryFromParsed::InsufficientInformation)
     ));
     assert!(matches!(
         Time::parse("", &fd::parse("")?),
         Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
+            error::TryFromParsed::InsufficientInformation
         ))
     ));
     assert!(matches!(
         Time::parse("12", &fd::parse("[hour]")?),
         Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
+            error::TryFromParsed::InsufficientInformation
         ))
     ));
     assert!(matches!(
@@ -785,37 +1766,37 @@ fn parse_date() -> time::Result<()> {
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2021-W00-6",
+            "2021-W00-7",
             date!(2021 - 01 - 02),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2023-W01-1",
+            "2023-W01-2",
             date!(2023 - 01 - 02),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2022-W00-7",
+            "2022-W01-1",
             date!(2022 - 01 - 02),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2026-W00-5",
+            "2026-W00-6",
             date!(2026 - 01 - 02),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2025-W00-4",
+            "2025-W00-5",
             date!(2025 - 01 - 02),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2019-W00-3",
+            "2019-W00-4",
             date!(2019 - 01 - 02),
         ),
         (
             fd::parse("[year]-W[week_number repr:sunday]-[weekday repr:sunday]")?,
-            "2018-W01-2",
+            "2018-W00-3",
             date!(2018 - 01 - 02),
         ),
         (
@@ -823,7 +1804,7 @@ fn parse_date() -> time::Result<()> {
                 "[year padding:space]-W[week_number repr:sunday padding:none]-[weekday \
                  repr:sunday]",
             )?,
-            " 2018-W01-2",
+            " 2018-W0-3",
             date!(2018 - 01 - 02),
         ),
     ];
@@ -843,12 +1824,12 @@ fn parse_date() -> time::Result<()> {
 fn parse_date_err() -> time::Result<()> {
     assert!(matches!(
         Date::try_from(Parsed::new()),
-        Err(error::TryFromParsed::InsufficientInformation { .. })
+        Err(error::TryFromParsed::InsufficientInformation)
     ));
     assert!(matches!(
         Date::parse("", &fd::parse("")?),
         Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
+            error::TryFromParsed::InsufficientInformation
         ))
     ));
     assert!(matches!(
@@ -900,37 +1881,596 @@ fn parse_date_err() -> time::Result<()> {
         )) if component.name() == "ordinal"
     ));
     assert!(matches!(
-        Date::parse("Ja", &fd::parse("[month repr:short]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("month")
-        ))
+        Date::parse("Ja", &fd::parse("[month repr:short]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("month")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("  2a21", &fd::parse("[year padding:space]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_ordinal_day() -> time::Result<()> {
+    let format = fd::parse("[year]-[month]-[day repr:ordinal padding:none]")?;
+    for day in 1..=31 {
+        let date = Date::from_calendar_date(2024, Month::January, day)?;
+        assert_eq!(Date::parse(&date.format(&format)?, &format)?, date);
+    }
+    assert_eq!(
+        Date::parse(
+            "2024-03-03rd",
+            &fd::parse("[year]-[month]-[day repr:ordinal]")?
+        )?,
+        date!(2024 - 03 - 03)
+    );
+
+    for input in [
+        "2024-01-3th",
+        "2024-01-11st",
+        "2024-01-12nd",
+        "2024-01-13rd",
+        "2024-01-3",
+        "2024-01-3RD",
+    ] {
+        assert!(matches!(
+            Date::parse(input, &format),
+            invalid_component!("day")
+        ));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn custom_names() -> time::Result<()> {
+    const MONTHS: [&str; 12] = [
+        "Januar",
+        "Februar",
+        "März",
+        "April",
+        "Mai",
+        "Juni",
+        "Juli",
+        "August",
+        "September",
+        "Oktober",
+        "November",
+        "Dezember",
+    ];
+    const WEEKDAYS: [&str; 7] = [
+        "Montag",
+        "Dienstag",
+        "Mittwoch",
+        "Donnerstag",
+        "Freitag",
+        "Samstag",
+        "Sonntag",
+    ];
+
+    let mut month = modifier::Month::default();
+    month.repr = modifier::MonthRepr::CustomNames(&MONTHS);
+    month.case_sensitive = false;
+    let mut weekday = modifier::Weekday::default();
+    weekday.repr = modifier::WeekdayRepr::CustomNames(&WEEKDAYS);
+    weekday.case_sensitive = false;
+    let format = FormatItem::Compound(&[
+        FormatItem::Component(Component::Weekday(weekday)),
+        FormatItem::Literal(b", "),
+        FormatItem::Component(Component::Day(modifier::Day::default())),
+        FormatItem::Literal(b". "),
+        FormatItem::Component(Component::Month(month)),
+        FormatItem::Literal(b" "),
+        FormatItem::Component(Component::Year(modifier::Year::default())),
+    ]);
+
+    assert_eq!(
+        date!(2024 - 03 - 07).format(&format)?,
+        "Donnerstag, 07. März 2024"
+    );
+    // Any seven consecutive days cover every weekday.
+    for month in 1..=12 {
+        let month = Month::try_from(month)?;
+        for day in 1..=7 {
+            let date = Date::from_calendar_date(2024, month, day)?;
+            assert_eq!(Date::parse(&date.format(&format)?, &format)?, date);
+        }
+    }
+    assert_eq!(
+        Date::parse("SONNTAG, 01. DEZEMBER 2024", &format)?,
+        date!(2024 - 12 - 01)
+    );
+    assert!(matches!(
+        Date::parse("Sunday, 01. Dezember 2024", &format),
+        invalid_component!("weekday")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_component_value() -> time::Result<()> {
+    use time::parsing::ParsedComponentValue as Value;
+
+    /// Parse the value of the sole component in the description, returning the remaining input as
+    /// a string.
+    fn parse(
+        description: &str,
+        input: &'static str,
+    ) -> time::Result<Result<(Value, &'static str), error::ParseFromDescription>> {
+        match fd::parse(description)?.as_slice() {
+            [FormatItem::Component(component)] => Ok(component
+                .parse_value(input.as_bytes())
+                .map(|(value, remaining)| (value, std::str::from_utf8(remaining).unwrap()))),
+            _ => unreachable!("expected a single component"),
+        }
+    }
+
+    assert_eq!(
+        parse("[month repr:long]", "March 3rd")?,
+        Ok((Value::Month(Month::March), " 3rd"))
+    );
+    assert_eq!(
+        parse("[month repr:short case_sensitive:false]", "mar")?,
+        Ok((Value::Month(Month::March), ""))
+    );
+    assert_eq!(
+        parse("[day repr:ordinal padding:none]", "3rd of")?,
+        Ok((Value::Day(NonZeroU8::new(3).unwrap()), " of"))
+    );
+    assert_eq!(
+        parse("[weekday]", "Tuesday")?,
+        Ok((Value::Weekday(Weekday::Tuesday), ""))
+    );
+    assert_eq!(
+        parse("[year repr:last_two]", "2024")?,
+        Ok((Value::Year(20), "24"))
+    );
+    assert_eq!(
+        parse("[hour repr:12]", "11 PM")?,
+        Ok((Value::Hour(11), " PM"))
+    );
+    assert_eq!(
+        parse("[period]", "PM")?,
+        Ok((Value::Period(time::Period::Pm), ""))
+    );
+    assert_eq!(
+        parse("[offset]", "-0030 ")?,
+        Ok((
+            Value::Offset {
+                hour: 0,
+                minute: 30,
+                is_negative: true
+            },
+            " "
+        ))
+    );
+    assert_eq!(
+        parse("[offset z_when_utc:true]", "Z:00")?,
+        Ok((
+            Value::Offset {
+                hour: 0,
+                minute: 0,
+                is_negative: false
+            },
+            ":00"
+        ))
+    );
+    assert_eq!(
+        parse("[offset_hour]", "-00:30")?,
+        Ok((
+            Value::OffsetHour {
+                value: 0,
+                is_negative: true
+            },
+            ":30"
+        ))
+    );
+    assert_eq!(
+        parse("[offset_hour z_when_utc:true]", "Z")?,
+        Ok((
+            Value::OffsetHour {
+                value: 0,
+                is_negative: false
+            },
+            ""
+        ))
+    );
+    assert_eq!(
+        parse("[offset_minute]", "30")?,
+        Ok((Value::OffsetMinute(30), ""))
+    );
+    assert_eq!(parse("[ignore count:2]", "abc")?, Ok((Value::Ignore, "c")));
+    assert_eq!(
+        parse("[unix_timestamp precision:millisecond]", "-1500")?,
+        Ok((Value::UnixTimestamp(-1_500_000_000), ""))
+    );
+
+    // The number of digits consumed depends on the modifier. The value is always in nanoseconds.
+    assert_eq!(
+        parse("[subsecond digits:3]", "1234")?,
+        Ok((Value::Subsecond(123_000_000), "4"))
+    );
+    assert_eq!(
+        parse("[subsecond digits:1+]", "1234")?,
+        Ok((Value::Subsecond(123_400_000), ""))
+    );
+    assert_eq!(
+        parse("[subsecond digits:6]", "12345")?,
+        Err(error::ParseFromDescription::InvalidComponent("subsecond"))
+    );
+
+    // Failures are reported for the component, with the input left untouched.
+    assert_eq!(
+        parse("[month repr:long]", "Mar")?,
+        Err(error::ParseFromDescription::InvalidComponent("month"))
+    );
+    assert_eq!(
+        parse("[day]", "3rd")?,
+        Err(error::ParseFromDescription::InvalidComponent("day"))
+    );
+    assert_eq!(
+        parse("[hour repr:12]", "00")?,
+        Err(error::ParseFromDescription::InvalidComponent("hour"))
+    );
+    assert_eq!(
+        parse("[week_number]", "00")?,
+        Err(error::ParseFromDescription::InvalidComponent("week number"))
+    );
+    assert_eq!(
+        parse("[offset_hour sign:mandatory]", "05")?,
+        Err(error::ParseFromDescription::InvalidComponent("offset hour"))
+    );
+    assert_eq!(
+        parse("[ignore count:4]", "abc")?,
+        Err(error::ParseFromDescription::InvalidComponent("ignore"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn padding_round_trip() -> time::Result<()> {
+    let dates = [
+        date!(2021 - 01 - 02),
+        date!(2021 - 12 - 31),
+        date!(0005 - 03 - 04),
+        date!(0000 - 01 - 01),
+        date!(-0005 - 03 - 04),
+        date!(-0045 - 10 - 10),
+        date!(-2021 - 12 - 31),
+    ];
+    let times = [
+        time!(0:00),
+        time!(1:02:03),
+        time!(12:00),
+        time!(13:04:05),
+        time!(23:59:59),
+    ];
+    let offsets = [
+        offset!(UTC),
+        offset!(+1:02:03),
+        offset!(+12:34:56),
+        offset!(-1:02:03),
+        offset!(-12:34:56),
+        offset!(-0:30),
+        offset!(-0:00:05),
+    ];
+
+    for padding in ["space", "zero", "none"] {
+        for format in [
+            format!("[year padding:{padding}]-[month padding:{padding}]-[day padding:{padding}]"),
+            format!("[year padding:{padding}]-[ordinal padding:{padding}]"),
+            format!(
+                "[year base:iso_week padding:{padding}]-W[week_number padding:{padding}]-[weekday \
+                 repr:monday]"
+            ),
+            format!(
+                "[year padding:{padding}] [week_number repr:sunday padding:{padding}] [weekday]"
+            ),
+            format!(
+                "[year padding:{padding}] [week_number repr:monday padding:{padding}] [weekday]"
+            ),
+        ] {
+            let format = fd::parse(&format)?;
+            for date in dates {
+                let formatted = date.format(&format)?;
+                assert_eq!(Date::parse(&formatted, &format)?, date, "{formatted:?}");
+            }
+        }
+
+        for format in [
+            format!(
+                "[hour padding:{padding}]:[minute padding:{padding}]:[second padding:{padding}]"
+            ),
+            format!(
+                "[hour repr:12 padding:{padding}]:[minute padding:{padding}]:[second \
+                 padding:{padding}] [period]"
+            ),
+        ] {
+            let format = fd::parse(&format)?;
+            for time in times {
+                let formatted = time.format(&format)?;
+                assert_eq!(Time::parse(&formatted, &format)?, time, "{formatted:?}");
+            }
+        }
+
+        let format = fd::parse_owned::<2>(&format!(
+            "[offset_hour padding:{padding}]:[offset_minute padding:{padding}]:[offset_second \
+             padding:{padding}]"
+        ))?;
+        for offset in offsets {
+            let formatted = offset.format(&format)?;
+            assert_eq!(
+                UtcOffset::parse(&formatted, &format)?,
+                offset,
+                "{formatted:?}"
+            );
+        }
+    }
+
+    // The sign may also precede space padding.
+    assert_eq!(
+        Date::parse(
+            "-   5-03-04",
+            &fd::parse("[year padding:space]-[month]-[day]")?
+        )?,
+        date!(-0005 - 03 - 04)
+    );
+    assert_eq!(
+        UtcOffset::parse(
+            "- 1:02",
+            &fd::parse("[offset_hour padding:space]:[offset_minute]")?
+        )?,
+        offset!(-1:02)
+    );
+    // The padding must not exceed the width.
+    assert!(matches!(
+        Date::parse("    -5", &fd::parse("[year padding:space]")?),
+        invalid_component!("year")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_offset() -> time::Result<()> {
+    // Regression check for #522.
+    assert_eq!(
+        UtcOffset::parse(
+            "-00:01",
+            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]")?,
+        ),
+        Ok(offset!(-00:01)),
+    );
+    assert_eq!(
+        UtcOffset::parse(
+            "-00:00:01",
+            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]:[offset_second]")?,
+        ),
+        Ok(offset!(-00:00:01)),
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_offset_short_forms() -> time::Result<()> {
+    let format_description = fd::parse_owned::<2>(
+        "[offset_hour z_when_utc:true][optional [:[offset_minute omit_when_zero:true]]]",
+    )?;
+    let input_value = [
+        ("Z", offset!(UTC)),
+        ("+00", offset!(UTC)),
+        ("+00:00", offset!(UTC)),
+        ("+02", offset!(+02)),
+        ("-02", offset!(-02)),
+        ("+02:00", offset!(+02)),
+        ("+02:30", offset!(+02:30)),
+        ("-02:30", offset!(-02:30)),
+        ("-00:30", offset!(-00:30)),
+    ];
+
+    for (input, value) in input_value {
+        assert_eq!(UtcOffset::parse(input, &format_description), Ok(value));
+    }
+
+    assert_eq!(
+        UtcOffset::parse(
+            "+02:",
+            &fd::parse("[offset_hour]:[offset_minute omit_when_zero:true]")?
+        ),
+        Ok(offset!(+02))
+    );
+    assert_eq!(
+        UtcOffset::parse("Z", &fd::parse("[offset_hour]")?),
+        invalid_component!("offset hour")
+    );
+    assert_eq!(
+        UtcOffset::parse("+02:", &fd::parse("[offset_hour]:[offset_minute]")?),
+        invalid_component!("offset minute")
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_offset_combined() -> time::Result<()> {
+    let colon = fd::parse_owned::<2>("[offset sign:mandatory z_when_utc:true]")?;
+    let no_colon = fd::parse_owned::<2>("[offset sign:mandatory z_when_utc:true separator:none]")?;
+    let input_value = [
+        ("Z", offset!(UTC)),
+        ("+00:00", offset!(UTC)),
+        ("-0000", offset!(UTC)),
+        ("+09:30", offset!(+09:30)),
+        ("+0930", offset!(+09:30)),
+        ("-09:30", offset!(-09:30)),
+        ("-0930", offset!(-09:30)),
+        ("-00:30", offset!(-00:30)),
+        ("+05:00", offset!(+05)),
+        ("-1200", offset!(-12)),
+    ];
+
+    // Either separator is accepted, regardless of which is used for formatting.
+    for (input, value) in input_value {
+        assert_eq!(UtcOffset::parse(input, &colon), Ok(value));
+        assert_eq!(UtcOffset::parse(input, &no_colon), Ok(value));
+    }
+
+    // Formatting and parsing with the same description is lossless for whole minutes.
+    for value in [
+        offset!(UTC),
+        offset!(+09:30),
+        offset!(-09:30),
+        offset!(-00:30),
+        offset!(+23:59),
+        offset!(-23:59),
+    ] {
+        assert_eq!(UtcOffset::parse(&value.format(&colon)?, &colon), Ok(value));
+        assert_eq!(
+            UtcOffset::parse(&value.format(&no_colon)?, &no_colon),
+            Ok(value)
+        );
+    }
+
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2023-05-10T12:34:56Z",
+            &fd::parse("[year]-[month]-[day]T[hour]:[minute]:[second][offset z_when_utc:true]")?
+        ),
+        Ok(datetime!(2023-05-10 12:34:56 UTC))
+    );
+    assert_eq!(
+        UtcOffset::parse("09:30", &fd::parse("[offset]")?),
+        Ok(offset!(+09:30))
+    );
+
+    assert_eq!(
+        UtcOffset::parse("Z", &fd::parse("[offset]")?),
+        invalid_component!("offset")
+    );
+    assert_eq!(
+        UtcOffset::parse("09:30", &colon),
+        invalid_component!("offset")
+    );
+    for input in ["+09", "+09:", "+09:3", "+9:30", "+09-30"] {
+        assert_eq!(
+            UtcOffset::parse(input, &colon),
+            invalid_component!("offset"),
+            "{input}"
+        );
+    }
+    assert!(matches!(
+        UtcOffset::parse("+09:30:00", &colon),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
     ));
     assert!(matches!(
-        Date::parse("  2a21", &fd::parse("[year padding:space]")?),
-        Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
-        ))
+        UtcOffset::parse("+09:75", &colon),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(component)))
+            if component.name() == "offset minute"
     ));
 
     Ok(())
 }
 
 #[test]
-fn parse_offset() -> time::Result<()> {
-    // Regression check for #522.
+fn compiled_format() -> time::Result<()> {
+    let corpus: &[(&str, &[&str])] = &[
+        (
+            "[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]][offset_hour \
+             z_when_utc:true][optional [:[offset_minute omit_when_zero:true]]]",
+            &[
+                "2023-05-10T12:34:56Z",
+                "2023-05-10T12:34:56.789+05:30",
+                "2023-05-10T12:34:56.+05",
+                "2023-05-10T12:34:56-02:",
+                "2023-05-10T12:34:56",
+                "2023-13-10T12:34:56Z",
+            ],
+        ),
+        (
+            "[first [[year]-[month]-[day]][[year]-[ordinal]][[year] [month repr:short] [day]]]\
+             [optional [ [hour]:[minute]]]",
+            &[
+                "2023-05-10",
+                "2023-130",
+                "2023 May 10 12:34",
+                "2023-05-10 12:34",
+                "2023-05-10 12:",
+                "2023/05/10",
+                "",
+            ],
+        ),
+        (
+            "[optional [[first [[weekday repr:short], ][]]]][day] [month repr:short] [year] \
+             [hour]:[minute][optional [:[second]]] [first [GMT][UT][[offset_hour][offset_minute]]]",
+            &[
+                "Wed, 10 May 2023 12:34:56 GMT",
+                "10 May 2023 12:34 +0530",
+                "Thu, 10 May 2023 12:34 UT",
+                "Wed 10 May 2023 12:34 UT",
+                "10 May 2023 12:34 EST",
+            ],
+        ),
+        (
+            "[hour]:[minute][offset z_when_utc:true]",
+            &["12:34Z", "12:34+05:30", "12:34-0530", "12:34+05", "12:34"],
+        ),
+        (
+            "[optional [<[optional [[hour]]]>]][minute]",
+            &["<12>34", "<>34", "<1234", "34", "<"],
+        ),
+        (
+            "[first [[hour]h[minute]][[hour]:[minute]]][ignore count:2]",
+            &["12h34xx", "12:34xx", "12h34", "12-34xx"],
+        ),
+    ];
+
+    for (description, inputs) in corpus {
+        let owned = fd::parse_owned::<2>(description)?;
+        let compiled = CompiledFormat::from(&owned);
+
+        for input in *inputs {
+            let mut expected = Parsed::new();
+            let mut actual = Parsed::new();
+            assert_eq!(
+                actual.parse_item(input.as_bytes(), &compiled),
+                expected.parse_item(input.as_bytes(), &owned),
+                "{input}"
+            );
+            assert_eq!(Date::try_from(actual), Date::try_from(expected));
+            assert_eq!(Time::try_from(actual), Time::try_from(expected));
+            assert_eq!(UtcOffset::try_from(actual), UtcOffset::try_from(expected));
+            assert_eq!(actual.weekday(), expected.weekday());
+
+            assert_eq!(
+                OffsetDateTime::parse(input, &compiled),
+                OffsetDateTime::parse(input, &owned)
+            );
+            assert_eq!(
+                PrimitiveDateTime::parse(input, &compiled),
+                PrimitiveDateTime::parse(input, &owned)
+            );
+            assert_eq!(Date::parse(input, &compiled), Date::parse(input, &owned));
+        }
+    }
+
     assert_eq!(
-        UtcOffset::parse(
-            "-00:01",
-            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]")?,
+        OffsetDateTime::parse(
+            "2023-05-10T12:34:56.789+05:30",
+            &CompiledFormat::from(&fd::parse_owned::<2>(corpus[0].0)?)
         ),
-        Ok(offset!(-00:01)),
+        Ok(datetime!(2023-05-10 12:34:56.789 +05:30))
     );
     assert_eq!(
-        UtcOffset::parse(
-            "-00:00:01",
-            &fd::parse("[offset_hour sign:mandatory]:[offset_minute]:[offset_second]")?,
-        ),
-        Ok(offset!(-00:00:01)),
+        Time::parse("", &CompiledFormat::from(FormatItem::First(&[]))),
+        Time::parse("", &FormatItem::First(&[]))
     );
 
     Ok(())
@@ -941,7 +2481,7 @@ fn parse_offset_err() -> time::Result<()> {
     assert!(matches!(
         UtcOffset::parse("", &fd::parse("")?),
         Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
+            error::TryFromParsed::InsufficientInformation
         ))
     ));
     assert_eq!(
@@ -971,7 +2511,7 @@ fn parse_primitive_date_time_err() -> time::Result<()> {
     assert!(matches!(
         PrimitiveDateTime::parse("", &fd::parse("")?),
         Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
+            error::TryFromParsed::InsufficientInformation
         ))
     ));
     assert!(matches!(
@@ -989,7 +2529,7 @@ fn parse_offset_date_time_err() -> time::Result<()> {
     assert!(matches!(
         OffsetDateTime::parse("", &fd::parse("")?),
         Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation { .. }
+            error::TryFromParsed::InsufficientInformation
         ))
     ));
     assert!(matches!(
@@ -1008,6 +2548,144 @@ fn parse_offset_date_time_err() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn from_str() {
+    assert_eq!("2021-01-02".parse(), Ok(date!(2021 - 01 - 02)));
+    assert_eq!("-0001-01-02".parse(), Ok(date!(-0001 - 01 - 02)));
+    assert_eq!("+10000-01-02".parse(), Ok(date!(+10000 - 01 - 02)));
+    assert_eq!("00:00:00.0".parse(), Ok(time!(0:00)));
+    assert_eq!("23:59:59.999999999".parse(), Ok(time!(23:59:59.999_999_999)));
+    assert_eq!(
+        "2021-01-02 03:04:05.6".parse(),
+        Ok(datetime!(2021-01-02 3:04:05.6))
+    );
+    assert_eq!(
+        "2021-01-02 03:04:05.6 -01:02:03".parse::<OffsetDateTime>(),
+        Ok(datetime!(2021-01-02 3:04:05.6 -01:02:03))
+    );
+
+    for date in [Date::MIN, Date::MAX] {
+        assert_eq!(date.to_string().parse(), Ok(date));
+        assert_eq!(date.midnight().to_string().parse(), Ok(date.midnight()));
+        assert_eq!(
+            date.midnight().assume_utc().to_string().parse(),
+            Ok(date.midnight().assume_utc())
+        );
+    }
+
+    assert!("2021-1-2".parse::<Date>().is_err());
+    assert!("2021-01-02 ".parse::<Date>().is_err());
+    assert!("0:00:00.0".parse::<Time>().is_err());
+    assert!("00:00:00.".parse::<Time>().is_err());
+    assert!("2021-01-02T03:04:05".parse::<PrimitiveDateTime>().is_err());
+    assert!("2021-01-02 03:04:05 +01".parse::<OffsetDateTime>().is_err());
+    assert!("2021-01-02 03:04:05".parse::<OffsetDateTime>().is_err());
+}
+
+#[test]
+fn weekday_numeric() -> time::Result<()> {
+    // The first day of the week, the digit it is represented by, and digits that are out of range.
+    let conventions = [
+        (
+            "[weekday repr:sunday one_indexed:false]",
+            Weekday::Sunday,
+            0,
+            ["7", "8", "9"],
+        ),
+        (
+            "[weekday repr:sunday one_indexed:true]",
+            Weekday::Sunday,
+            1,
+            ["0", "8", "9"],
+        ),
+        (
+            "[weekday repr:monday one_indexed:false]",
+            Weekday::Monday,
+            0,
+            ["7", "8", "9"],
+        ),
+        (
+            "[weekday repr:monday one_indexed:true]",
+            Weekday::Monday,
+            1,
+            ["0", "8", "9"],
+        ),
+    ];
+
+    for (description, first_day, first_digit, invalid) in conventions {
+        let description = fd::parse(description)?;
+        for days in 0..7 {
+            let mut parsed = Parsed::new();
+            let input = (first_digit + days).to_string();
+            assert!(parsed
+                .parse_items(input.as_bytes(), &description)?
+                .is_empty());
+            assert_eq!(parsed.weekday(), Some(first_day.nth_next(days)));
+        }
+        for input in invalid {
+            assert!(Parsed::new()
+                .parse_items(input.as_bytes(), &description)
+                .is_err());
+        }
+    }
+
+    // 2023-01-01 is a Sunday, so the first Sunday-based week starts on it while the first
+    // Monday-based week starts the day after.
+    let cases = [
+        (
+            "[year] [week_number repr:sunday] [weekday repr:sunday one_indexed:false]",
+            "2023 01 0",
+            date!(2023 - 01 - 01),
+        ),
+        (
+            "[year] [week_number repr:sunday] [weekday repr:sunday one_indexed:true]",
+            "2023 01 7",
+            date!(2023 - 01 - 07),
+        ),
+        (
+            "[year] [week_number repr:monday] [weekday repr:monday one_indexed:false]",
+            "2023 01 0",
+            date!(2023 - 01 - 02),
+        ),
+        (
+            "[year] [week_number repr:monday] [weekday repr:monday one_indexed:true]",
+            "2023 01 7",
+            date!(2023 - 01 - 08),
+        ),
+        (
+            "[year] [week_number repr:monday] [weekday repr:sunday one_indexed:false]",
+            "2023 01 0",
+            date!(2023 - 01 - 08),
+        ),
+    ];
+    for (description, input, expected) in cases {
+        assert_eq!(Date::parse(input, &fd::parse(description)?)?, expected);
+    }
+
+    // Every combination of week numbering and weekday convention round-trips, covering each
+    // weekday that January 1 can fall on.
+    for week_number in ["sunday", "monday"] {
+        for weekday in ["sunday", "monday"] {
+            for one_indexed in [false, true] {
+                let description = fd::parse_owned::<2>(&format!(
+                    "[year] [week_number repr:{week_number}] [weekday repr:{weekday} \
+                     one_indexed:{one_indexed}]"
+                ))?;
+                let mut date = date!(2017 - 12 - 25);
+                while date < date!(2025 - 01 - 08) {
+                    assert_eq!(
+                        Date::parse(&date.format(&description)?, &description)?,
+                        date
+                    );
+                    date = date.next_day().expect("date should be in range");
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
 #[test]
 fn parse_components() -> time::Result<()> {
     macro_rules! parse_component {
@@ -1522,6 +3200,65 @@ fn parse_unix_timestamp() -> time::Result<()> {
         )?,
         datetime!(2009-02-13 23:31:30.123456789 UTC)
     );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1234567890.123",
+            &fd::parse("[unix_timestamp precision:millisecond decimal:true]")?
+        )?,
+        datetime!(2009-02-13 23:31:30.123 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1234567890.123456",
+            &fd::parse("[unix_timestamp precision:microsecond decimal:true]")?
+        )?,
+        datetime!(2009-02-13 23:31:30.123456 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "1700000000.123456789",
+            &fd::parse("[unix_timestamp precision:nanosecond decimal:true]")?
+        )?,
+        datetime!(2023-11-14 22:13:20.123456789 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "-1.500",
+            &fd::parse("[unix_timestamp precision:millisecond decimal:true]")?
+        )?,
+        datetime!(1969-12-31 23:59:58.5 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "-2208988800000",
+            &fd::parse("[unix_timestamp precision:millisecond]")?
+        )?,
+        datetime!(1900-01-01 0:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "253402300799999",
+            &fd::parse("[unix_timestamp precision:millisecond]")?
+        )?,
+        datetime!(9999-12-31 23:59:59.999 UTC)
+    );
+
+    for (precision, timestamp) in [
+        ("second", datetime!(1969-07-20 20:17:40 UTC)),
+        ("millisecond", datetime!(1969-07-20 20:17:40.123 UTC)),
+        ("microsecond", datetime!(1969-07-20 20:17:40.123456 UTC)),
+        ("nanosecond", datetime!(1969-07-20 20:17:40.123456789 UTC)),
+    ] {
+        for decimal in ["false", "true"] {
+            let format = fd::parse_owned::<2>(&format!(
+                "[unix_timestamp precision:{precision} decimal:{decimal}]"
+            ))?;
+            assert_eq!(
+                OffsetDateTime::parse(&timestamp.format(&format)?, &format)?,
+                timestamp
+            );
+        }
+    }
 
     Ok(())
 }
@@ -1559,5 +3296,269 @@ fn parse_unix_timestamp_err() -> time::Result<()> {
         ))
     );
 
+    let format = fd::parse("[unix_timestamp precision:millisecond decimal:true]")?;
+    for input in [
+        "1234567890",
+        "1234567890.",
+        "1234567890.12",
+        "1234567890,123",
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse(input, &format),
+            Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::InvalidComponent("unix_timestamp")
+            ))
+        );
+    }
+    assert!(matches!(
+        OffsetDateTime::parse("1234567890.1234", &format),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+
+    // Timestamps near the limits of `i64` milliseconds are far outside the supported range of
+    // dates, and must be rejected rather than overflowing.
+    assert!(matches!(
+        OffsetDateTime::parse(
+            &i64::MAX.to_string(),
+            &fd::parse("[unix_timestamp precision:millisecond]")?
+        ),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    let mut parsed = Parsed::new();
+    parsed.set_unix_timestamp_nanos(i128::from(i64::MAX) * 1_000_000);
+    assert!(matches!(
+        OffsetDateTime::try_from(parsed),
+        Err(error::TryFromParsed::ComponentRange(_))
+    ));
+    parsed.set_unix_timestamp_nanos(i128::from(i64::MIN) * 1_000_000);
+    assert!(matches!(
+        OffsetDateTime::try_from(parsed),
+        Err(error::TryFromParsed::ComponentRange(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_many() -> time::Result<()> {
+    let rows = [
+        "2021-01-02T03:04:05Z",
+        "2021-01-02T03:04:05",
+        "2021-13-02T03:04:05Z",
+        "2021-01-02T03:04:05.123+01:02",
+    ];
+    let result = OffsetDateTime::parse_many(rows, &Rfc3339);
+    assert_eq!(
+        result.values,
+        [
+            datetime!(2021-01-02 03:04:05 UTC),
+            datetime!(2021-01-02 03:04:05.123 +01:02),
+        ]
+    );
+    assert_eq!(
+        result.errors,
+        [
+            (1, OffsetDateTime::parse(rows[1], &Rfc3339).unwrap_err()),
+            (2, OffsetDateTime::parse(rows[2], &Rfc3339).unwrap_err()),
+        ]
+    );
+
+    let format = fd::parse("[year]-[month]-[day] [hour]:[minute] [offset_hour sign:mandatory]")?;
+    let rows = "2021-01-02 03:04 +01\n2021-01-02 03:04\n\n2021-01-03 03:04 -02";
+    let result = OffsetDateTime::parse_many(rows.lines(), &format);
+    assert_eq!(
+        result.values,
+        [
+            datetime!(2021-01-02 3:04 +01),
+            datetime!(2021-01-03 3:04 -02),
+        ]
+    );
+    let error_indices = result.errors.iter().map(|&(index, _)| index);
+    assert_eq!(error_indices.collect::<Vec<_>>(), [1, 2]);
+
+    let result = OffsetDateTime::parse_many(core::iter::empty(), &Rfc3339);
+    assert!(result.values.is_empty());
+    assert!(result.errors.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn parse_reusing() -> time::Result<()> {
+    let mut parsed = Parsed::new();
+    for input in [
+        "2021-01-02T03:04:05Z",
+        "2021-01-02T03:04:05.123+01:02",
+        "2021-13-02T03:04:05Z",
+        "2021-01-02T03:04:05",
+        "2021-01-02T03:04:05Zabc",
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse_reusing(input, &Rfc3339, &mut parsed),
+            OffsetDateTime::parse(input, &Rfc3339)
+        );
+    }
+
+    // Nothing from the previous input may be used to fill in the missing offset.
+    let format =
+        fd::parse_owned::<2>("[year]-[month]-[day] [hour]:[minute][optional [ [offset_hour]]]")?;
+    assert_eq!(
+        OffsetDateTime::parse_reusing("2021-01-02 03:04 +01", &format, &mut parsed)?,
+        datetime!(2021-01-02 3:04 +01)
+    );
+    assert_eq!(parsed.offset_hour(), Some(1));
+    assert!(matches!(
+        OffsetDateTime::parse_reusing("2021-01-03 03:04", &format, &mut parsed),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+    assert_eq!(parsed.offset_hour(), None);
+    assert_eq!(parsed.day().map(NonZeroU8::get), Some(3));
+
+    Ok(())
+}
+
+#[test]
+fn parse_with_default_offset() -> time::Result<()> {
+    let default = offset!(+01:02:03);
+
+    let format = fd::parse_owned::<2>(
+        "[year]-[month]-[day] [hour]:[minute][optional [ [offset_hour]:[offset_minute]]]",
+    )?;
+    assert_eq!(
+        OffsetDateTime::parse_with_default_offset("2021-01-02 03:04", &format, default)?,
+        datetime!(2021-01-02 3:04 +01:02:03)
+    );
+    assert_eq!(
+        OffsetDateTime::parse_with_default_offset("2021-01-02 03:04 -05:30", &format, default)?,
+        datetime!(2021-01-02 3:04 -05:30)
+    );
+    assert_eq!(
+        OffsetDateTime::parse_with_default_offset("2021-01-02 03:04 +00:00", &format, default)?,
+        datetime!(2021-01-02 3:04 UTC)
+    );
+    assert!(matches!(
+        OffsetDateTime::parse_with_default_offset("2021-01-02 03:04 +01", &format, default),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+
+    // A partially parsed offset is not completed using the default.
+    let format = fd::parse_owned::<2>("[year]-[month]-[day] [hour]:[minute] [offset_minute]")?;
+    assert!(matches!(
+        OffsetDateTime::parse_with_default_offset("2021-01-02 03:04 30", &format, default),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+
+    for format in [
+        Iso8601Dynamic::DEFAULT,
+        Iso8601Dynamic::from_config(
+            iso8601::Config::DEFAULT
+                .set_formatted_components(iso8601::FormattedComponents::DateTime),
+        ),
+    ] {
+        assert_eq!(
+            OffsetDateTime::parse_with_default_offset("2021-01-02T03:04:05", &format, default)?,
+            datetime!(2021-01-02 3:04:05 +01:02:03)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_with_default_offset("2021-01-02T03:04:05Z", &format, default)?,
+            datetime!(2021-01-02 3:04:05 UTC)
+        );
+        assert_eq!(
+            OffsetDateTime::parse_with_default_offset("20210102T0304-06", &format, default)?,
+            datetime!(2021-01-02 3:04 -6)
+        );
+    }
+    assert_eq!(
+        OffsetDateTime::parse_with_default_offset("2021-01-02T03:04", &Iso8601::DEFAULT, default)?,
+        datetime!(2021-01-02 3:04 +01:02:03)
+    );
+    // The date and time are still required.
+    assert!(matches!(
+        OffsetDateTime::parse_with_default_offset("2021-01-02", &Iso8601::DEFAULT, default),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn missing_components() -> time::Result<()> {
+    let missing = |input: &str, format: &[FormatItem<'_>]| {
+        let mut parsed = Parsed::new();
+        assert!(matches!(
+            OffsetDateTime::parse_reusing(input, &format, &mut parsed),
+            Err(error::Parse::TryFromParsed(
+                error::TryFromParsed::InsufficientInformation
+            ))
+        ));
+        parsed
+            .missing_date_components()
+            .union(parsed.missing_time_components())
+            .union(parsed.missing_offset_components())
+            .iter()
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        missing("01-02 03:04 +05", &fd::parse("[month]-[day] [hour]:[minute] [offset_hour]")?),
+        ["year"]
+    );
+    assert_eq!(
+        missing("032 03:04 +05", &fd::parse("[ordinal] [hour]:[minute] [offset_hour]")?),
+        ["year"]
+    );
+    assert_eq!(
+        missing(
+            "05 03:04 +05",
+            &fd::parse("[week_number repr:iso] [hour]:[minute] [offset_hour]")?
+        ),
+        ["iso_year", "weekday"]
+    );
+    assert_eq!(
+        missing(
+            "2021-01-02 03:04 +05",
+            &fd::parse("[year]-[month]-[day] [hour repr:12]:[minute] [offset_hour]")?
+        ),
+        ["hour_12_is_pm"]
+    );
+    assert_eq!(
+        missing("2021-01-02 03:04", &fd::parse("[year]-[month]-[day] [hour]:[minute]")?),
+        ["offset_hour"]
+    );
+    assert_eq!(
+        missing("", &[]),
+        ["year", "month", "day", "hour_24", "minute", "offset_hour"]
+    );
+
+    let mut parsed = Parsed::new();
+    let format = fd::parse("[year] [hour]:[minute]")?;
+    assert!(OffsetDateTime::parse_reusing("2021 03:04", &format, &mut parsed).is_err());
+    let missing = parsed.missing_date_components();
+    assert!(missing.contains("month"));
+    assert!(!missing.contains("year"));
+    assert!(!missing.is_empty());
+    assert_eq!(missing.to_string(), "`month`, `day`");
+    assert!(parsed.missing_time_components().is_empty());
+
+    // An hour on the 12-hour clock alone is sufficient.
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"03 PM", &fd::parse("[hour repr:12] [period]")?)?;
+    assert_eq!(Time::try_from(parsed)?, time!(15:00));
+    assert!(parsed.missing_time_components().is_empty());
+
+    // Fully specified values have nothing missing, even if they are invalid.
+    let mut parsed = Parsed::new();
+    let format = fd::parse("[year]-[month]-[day] [hour]:[minute] [offset_hour]")?;
+    assert!(OffsetDateTime::parse_reusing("2021-02-30 03:04 +05", &format, &mut parsed).is_err());
+    assert!(parsed.missing_date_components().is_empty());
+    assert!(parsed.missing_time_components().is_empty());
+    assert!(parsed.missing_offset_components().is_empty());
+
     Ok(())
 }
@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
-use time::{Duration, Month, PrimitiveDateTime, Result, Weekday};
+use time::{Date, Duration, Month, PrimitiveDateTime, Result, Weekday};
 
 #[test]
 fn new() {
@@ -12,6 +12,24 @@ fn new() {
     );
 }
 
+#[test]
+fn from_julian_date() {
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(2_451_545.0),
+        Ok(datetime!(2000-01-01 12:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(2_451_544.75),
+        Ok(datetime!(2000-01-01 6:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_julian_date(0.0),
+        Ok(datetime!(-4713-11-24 12:00))
+    );
+    assert!(PrimitiveDateTime::from_julian_date(f64::NAN).is_err());
+    assert!(PrimitiveDateTime::from_julian_date(-1e12).is_err());
+}
+
 #[test]
 fn date() {
     assert_eq!(datetime!(2019-01-01 0:00).date(), date!(2019 - 01 - 01));
@@ -22,6 +40,74 @@ fn time() {
     assert_eq!(datetime!(2019-01-01 0:00).time(), time!(0:00));
 }
 
+#[test]
+fn into_parts() {
+    assert_eq!(
+        datetime!(2019-01-01 12:34:56.789).into_parts(),
+        (date!(2019 - 01 - 01), time!(12:34:56.789)),
+    );
+
+    for datetime in [
+        PrimitiveDateTime::MIN,
+        datetime!(2020-02-29 23:59:59.999_999_999),
+        PrimitiveDateTime::MAX,
+    ] {
+        let (date, time) = datetime.into_parts();
+        assert_eq!(PrimitiveDateTime::from_parts(date, time), datetime);
+        assert_eq!(PrimitiveDateTime::new(date, time), datetime);
+        assert_eq!(date.with_time(time), datetime);
+    }
+}
+
+#[test]
+fn to_packed() {
+    assert_eq!(
+        datetime!(2019-01-01 1:02:03.004_005_006).to_packed(),
+        (0x000F_C601, 3_723_004_005_006),
+    );
+    assert_eq!(
+        datetime!(-0001-12-31 23:59:59.999_999_999).to_packed(),
+        (0xFFFF_FF6D, 86_399_999_999_999),
+    );
+}
+
+#[test]
+fn from_packed() {
+    assert_eq!(
+        PrimitiveDateTime::from_packed((0x000F_C601, 3_723_004_005_006)),
+        Ok(datetime!(2019-01-01 1:02:03.004_005_006)),
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_packed((0xFFFF_FF6D, 86_399_999_999_999)),
+        Ok(datetime!(-0001-12-31 23:59:59.999_999_999)),
+    );
+
+    for datetime in [PrimitiveDateTime::MIN, PrimitiveDateTime::MAX] {
+        assert_eq!(
+            PrimitiveDateTime::from_packed(datetime.to_packed()),
+            Ok(datetime)
+        );
+    }
+
+    assert!(PrimitiveDateTime::from_packed((0, 0)).is_err());
+    assert!(PrimitiveDateTime::from_packed((0x000F_C601, 86_400_000_000_000)).is_err());
+}
+
+#[test]
+fn to_calendar_date_time() {
+    assert_eq!(
+        datetime!(2019-01-02 12:34:56.789).to_calendar_date_time(),
+        ((2019, Month::January, 2), (12, 34, 56, 789_000_000)),
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.to_calendar_date_time(),
+        (
+            (Date::MAX.year(), Month::December, 31),
+            (23, 59, 59, 999_999_999)
+        ),
+    );
+}
+
 #[test]
 fn year() {
     assert_eq!(datetime!(2019-01-01 0:00).year(), 2019);
@@ -47,6 +133,19 @@ fn ordinal() {
     assert_eq!(datetime!(2019-12-31 0:00).ordinal(), 365);
 }
 
+#[test]
+fn month_boundaries() {
+    assert!(datetime!(2019-02-01 0:00).is_first_day_of_month());
+    assert!(!datetime!(2019-01-31 23:59).is_first_day_of_month());
+    assert!(datetime!(2019-02-28 23:59).is_last_day_of_month());
+    assert!(!datetime!(2020-02-28 23:59).is_last_day_of_month());
+    assert!(datetime!(2020-01-01 0:00).is_first_day_of_year());
+    assert!(datetime!(2020-12-31 0:00).is_last_day_of_year());
+    assert!(!datetime!(2019-12-30 0:00).is_last_day_of_year());
+    assert!(datetime!(2020-02-29 12:00).is_leap_day());
+    assert!(!datetime!(2019-02-28 12:00).is_leap_day());
+}
+
 #[test]
 fn iso_week() {
     assert_eq!(datetime!(2019-01-01 0:00).iso_week(), 1);
@@ -56,6 +155,14 @@ fn iso_week() {
     assert_eq!(datetime!(2021-01-01 0:00).iso_week(), 53);
 }
 
+#[test]
+fn iso_year_week() {
+    assert_eq!(datetime!(2019-10-04 0:00).iso_year_week(), (2019, 40));
+    assert_eq!(datetime!(2019-12-30 23:59).iso_year_week(), (2020, 1));
+    assert_eq!(datetime!(2016-01-03 0:00).iso_year_week(), (2015, 53));
+    assert_eq!(datetime!(2021-01-01 0:00).iso_year_week(), (2020, 53));
+}
+
 #[test]
 fn sunday_based_week() {
     assert_eq!(datetime!(2019-01-01 0:00).sunday_based_week(), 0);
@@ -136,6 +243,18 @@ fn to_julian_day() {
     assert_eq!(datetime!(2019-12-31 0:00).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn to_julian_date() {
+    assert_eq!(datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.0);
+    assert_eq!(datetime!(2000-01-01 0:00).to_julian_date(), 2_451_544.5);
+    assert_eq!(datetime!(2000-01-01 18:00).to_julian_date(), 2_451_545.25);
+    assert_eq!(datetime!(-4713-11-24 12:00).to_julian_date(), 0.0);
+    assert_eq!(
+        datetime!(2000-01-01 12:00).to_julian_date(),
+        datetime!(2000-01-01 12:00 UTC).to_julian_date()
+    );
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(datetime!(2020-01-01 1:02:03).as_hms(), (1, 2, 3));
@@ -223,6 +342,50 @@ fn assume_offset() {
     );
 }
 
+#[test]
+fn assume_offset_checked() {
+    assert_eq!(
+        datetime!(2019-01-01 0:00).assume_offset_checked(offset!(-1)),
+        Some(datetime!(2019-01-01 0:00 -1)),
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.assume_offset_checked(offset!(UTC)),
+        Some(PrimitiveDateTime::MIN.assume_utc()),
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.assume_offset_checked(offset!(UTC)),
+        Some(PrimitiveDateTime::MAX.assume_utc()),
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.assume_offset_checked(offset!(-23:59:59)),
+        Some(PrimitiveDateTime::MIN.assume_offset(offset!(-23:59:59))),
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.assume_offset_checked(offset!(+23:59:59)),
+        Some(PrimitiveDateTime::MAX.assume_offset(offset!(+23:59:59))),
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.assume_offset_checked(offset!(+0:00:01)),
+        None
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.assume_offset_checked(offset!(+23:59:59)),
+        None
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.assume_offset_checked(offset!(-0:00:01)),
+        None
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.assume_offset_checked(offset!(-23:59:59)),
+        None
+    );
+
+    // The unchecked form succeeds, but the value cannot be converted to UTC.
+    let datetime = PrimitiveDateTime::MAX.assume_offset(offset!(-23:59:59));
+    assert_panic!(datetime.to_offset(offset!(UTC)));
+}
+
 #[test]
 fn assume_utc() {
     assert_eq!(
@@ -541,6 +704,10 @@ fn sub_datetime() {
         datetime!(2019-12-31 0:00) - datetime!(2020-01-01 0:00),
         (-1).days()
     );
+
+    let span = (Date::MAX - Date::MIN) + (time!(23:59:59.999_999_999) - time!(0:00));
+    assert_eq!(PrimitiveDateTime::MAX - PrimitiveDateTime::MIN, span);
+    assert_eq!(PrimitiveDateTime::MIN - PrimitiveDateTime::MAX, -span);
 }
 
 #[test]
@@ -608,6 +775,82 @@ fn ord() {
     );
 }
 
+#[test]
+fn eq_date() {
+    assert_eq!(datetime!(2019-01-01 0:00), date!(2019 - 01 - 01));
+    assert_eq!(date!(2019 - 01 - 01), datetime!(2019-01-01 0:00));
+    assert_ne!(
+        datetime!(2019-01-01 0:00:00.000_000_001),
+        date!(2019 - 01 - 01)
+    );
+    assert_ne!(
+        date!(2019 - 01 - 01),
+        datetime!(2019-01-01 0:00:00.000_000_001)
+    );
+    assert_ne!(datetime!(2019-01-02 0:00), date!(2019 - 01 - 01));
+    assert_ne!(date!(2019 - 01 - 02), datetime!(2019-01-01 0:00));
+}
+
+#[test]
+fn ord_date() {
+    use Ordering::*;
+    let cases = [
+        (datetime!(2019-01-01 0:00), date!(2019 - 01 - 01), Equal),
+        (
+            datetime!(2019-01-01 0:00:00.000_000_001),
+            date!(2019 - 01 - 01),
+            Greater,
+        ),
+        (
+            datetime!(2019-01-01 23:59:59.999_999_999),
+            date!(2019 - 01 - 02),
+            Less,
+        ),
+        (datetime!(2019-01-02 0:00), date!(2019 - 01 - 01), Greater),
+        (datetime!(2018-12-31 12:00), date!(2019 - 01 - 01), Less),
+        (PrimitiveDateTime::MIN, Date::MIN, Equal),
+        (PrimitiveDateTime::MAX, Date::MAX, Greater),
+    ];
+
+    for (datetime, date, ordering) in cases {
+        assert_eq!(datetime.partial_cmp(&date), Some(ordering));
+        assert_eq!(date.partial_cmp(&datetime), Some(ordering.reverse()));
+        assert_eq!(datetime.partial_cmp(&date.midnight()), Some(ordering));
+        assert_eq!(datetime == date, ordering == Equal);
+        assert_eq!(date == datetime, ordering == Equal);
+    }
+}
+
+#[test]
+fn min_max_clamp() {
+    let low = datetime!(2019-01-01 0:00);
+    let middle = datetime!(2019-01-01 12:00);
+    let high = datetime!(2019-01-02 0:00);
+    assert_eq!(low.min(high), low);
+    assert_eq!(high.min(low), low);
+    assert_eq!(low.max(high), high);
+    assert_eq!(high.max(low), high);
+    assert_eq!(low.min(low), low);
+    assert_eq!(low.clamp(middle, high), middle);
+    assert_eq!(middle.clamp(low, high), middle);
+    assert_eq!(high.clamp(low, middle), middle);
+    assert_eq!(
+        datetime!(2019-01-01 23:59).min(datetime!(2019-01-02 0:00)),
+        datetime!(2019-01-01 23:59)
+    );
+
+    const CLAMPED: PrimitiveDateTime =
+        datetime!(2020-01-01 0:00).clamp(datetime!(2019-01-01 0:00), datetime!(2019-12-31 0:00));
+    assert_eq!(CLAMPED, datetime!(2019-12-31 0:00));
+}
+
+#[test]
+#[should_panic]
+fn clamp_panic() {
+    let _ =
+        datetime!(2019-01-01 0:00).clamp(datetime!(2019-01-02 0:00), datetime!(2019-01-01 0:00));
+}
+
 #[test]
 fn checked_add_duration() {
     // Successful addition
@@ -738,6 +981,55 @@ fn checked_sub_duration() {
     );
 }
 
+#[test]
+fn checked_add_std() {
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45).checked_add_std(5.std_nanoseconds()),
+        Some(datetime!(2021 - 10 - 25 14:01:53.450_000_005))
+    );
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45).checked_add_std(2.std_days()),
+        Some(datetime!(2021 - 10 - 27 14:01:53.45))
+    );
+    assert_eq!(
+        PrimitiveDateTime::MAX.checked_add_std(1.std_nanoseconds()),
+        None
+    );
+}
+
+#[test]
+fn checked_sub_std() {
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45).checked_sub_std(5.std_nanoseconds()),
+        Some(datetime!(2021 - 10 - 25 14:01:53.449_999_995))
+    );
+    assert_eq!(
+        datetime!(2021 - 10 - 25 14:01:53.45).checked_sub_std(2.std_days()),
+        Some(datetime!(2021 - 10 - 23 14:01:53.45))
+    );
+    assert_eq!(
+        PrimitiveDateTime::MIN.checked_sub_std(1.std_nanoseconds()),
+        None
+    );
+}
+
+#[test]
+fn arithmetic_overflow_panic() {
+    let max = PrimitiveDateTime::MAX;
+    let min = PrimitiveDateTime::MIN;
+    let (nanosecond, std_nanosecond) = (1.nanoseconds(), 1.std_nanoseconds());
+
+    assert_panic_with_operands!(max + nanosecond, max, nanosecond);
+    assert_panic_with_operands!(max + std_nanosecond, max, std_nanosecond);
+    assert_panic_with_operands!(min - nanosecond, min, nanosecond);
+    assert_panic_with_operands!(min - std_nanosecond, min, std_nanosecond);
+
+    let mut datetime = max;
+    assert_panic_with_operands!(datetime += nanosecond, max, nanosecond);
+    let mut datetime = min;
+    assert_panic_with_operands!(datetime -= std_nanosecond, min, std_nanosecond);
+}
+
 #[test]
 fn saturating_add_duration() {
     assert_eq!(
@@ -805,3 +1097,18 @@ fn saturating_sub_duration() {
         PrimitiveDateTime::MAX
     );
 }
+
+#[test]
+fn reference_ops() {
+    let datetime = datetime!(2021-01-02 03:04:05);
+    assert_ref_binop!(datetime, +, 5.hours());
+    assert_ref_binop!(datetime, +, 5.std_hours());
+    assert_ref_binop!(datetime, -, 5.hours());
+    assert_ref_binop!(datetime, -, 5.std_hours());
+    assert_ref_binop!(datetime, -, datetime!(2020-01-02 0:00));
+
+    assert_ref_op_assign!(datetime, +=, 5.hours());
+    assert_ref_op_assign!(datetime, +=, 5.std_hours());
+    assert_ref_op_assign!(datetime, -=, 5.hours());
+    assert_ref_op_assign!(datetime, -=, 5.std_hours());
+}
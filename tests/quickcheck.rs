@@ -1,5 +1,14 @@
-use quickcheck::{Arbitrary, TestResult};
+use std::fmt;
+use std::fmt::Write as _;
+
+use quickcheck::{Arbitrary, Gen, TestResult};
 use quickcheck_macros::quickcheck;
+use time::format_description::display;
+use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use time::formatting::Formattable;
+use time::macros::time;
+use time::parsing::Parsable;
+use time::util::days_in_year_month;
 use time::Weekday::*;
 use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
@@ -64,16 +73,38 @@ fn duration_roundtrip(d: Duration) -> bool {
     Duration::new(d.whole_seconds(), d.subsec_nanoseconds()) == d
 }
 
+#[quickcheck]
+fn duration_display_roundtrip(d: Duration) -> bool {
+    Duration::parse_human(&d.to_string()) == Ok(d)
+}
+
 #[quickcheck]
 fn time_roundtrip(t: Time) -> bool {
     Time::from_hms_nano(t.hour(), t.minute(), t.second(), t.nanosecond()) == Ok(t)
 }
 
+#[quickcheck]
+fn time_12_hour_roundtrip(t: Time) -> bool {
+    let (hour, period) = t.hour_12();
+    Time::from_hms_12(hour, t.minute(), t.second(), period)
+        .and_then(|time| time.replace_nanosecond(t.nanosecond()))
+        == Ok(t)
+}
+
 #[quickcheck]
 fn primitive_date_time_roundtrip(a: PrimitiveDateTime) -> bool {
     PrimitiveDateTime::new(a.date(), a.time()) == a
 }
 
+#[quickcheck]
+fn primitive_date_time_to_calendar_date_time(a: PrimitiveDateTime) -> bool {
+    a.to_calendar_date_time()
+        == (
+            (a.year(), a.month(), a.day()),
+            (a.hour(), a.minute(), a.second(), a.nanosecond()),
+        )
+}
+
 #[quickcheck]
 fn utc_offset_roundtrip(o: UtcOffset) -> bool {
     let (hours, minutes, seconds) = o.as_hms();
@@ -91,6 +122,16 @@ fn offset_date_time_roundtrip(a: OffsetDateTime) -> TestResult {
     TestResult::from_bool(PrimitiveDateTime::new(a.date(), a.time()).assume_offset(a.offset()) == a)
 }
 
+#[quickcheck]
+fn offset_date_time_to_calendar_date_time(a: OffsetDateTime) -> bool {
+    a.to_calendar_date_time()
+        == (
+            (a.year(), a.month(), a.day()),
+            (a.hour(), a.minute(), a.second(), a.nanosecond()),
+            a.offset(),
+        )
+}
+
 #[quickcheck]
 fn unix_timestamp_roundtrip(odt: OffsetDateTime) -> TestResult {
     match odt.date() {
@@ -333,3 +374,611 @@ test_shrink!(
     offset_date_time_can_shrink_nanosecond,
     nanosecond()
 );
+
+/// Obtain one of the boundary values or an arbitrary value between the minimum and maximum
+/// inclusive, with equal probability. Boundary values outside of the range are clamped.
+fn boundary_or_between(g: &mut Gen, boundaries: &[i64], min: i64, max: i64) -> i64 {
+    if bool::arbitrary(g) {
+        g.choose(boundaries)
+            .map_or(min, |&value| value.clamp(min, max))
+    } else {
+        i64::arbitrary(g).rem_euclid(max - min + 1) + min
+    }
+}
+
+/// The components of a [`Representable`] value, in the order they are shrunk.
+type Parts = (i32, u8, u8, u8, u8, u8, u32, i16);
+
+/// An `OffsetDateTime` in the range that the well-known formats can represent: a year of at least
+/// `MIN_YEAR` and at most 9999, and an offset that is a whole number of minutes.
+///
+/// Boundary years, days, times, subseconds and offsets are generated far more often than they
+/// would be by chance. Shrinking moves each component towards zero independently, so
+/// counterexamples end up with few non-zero components.
+#[derive(Debug, Clone, Copy)]
+struct Representable<const MIN_YEAR: i32>(OffsetDateTime);
+
+impl<const MIN_YEAR: i32> Representable<MIN_YEAR> {
+    fn from_parts(
+        (year, month, day, hour, minute, second, nanosecond, offset_minutes): Parts,
+    ) -> Option<Self> {
+        if !(MIN_YEAR..=9999).contains(&year) {
+            return None;
+        }
+        let date = Date::from_calendar_date(year, Month::try_from(month).ok()?, day).ok()?;
+        let offset = UtcOffset::from_whole_seconds(i32::from(offset_minutes) * 60).ok()?;
+        Some(Self(
+            date.with_hms_nano(hour, minute, second, nanosecond)
+                .ok()?
+                .assume_offset(offset),
+        ))
+    }
+
+    fn parts(self) -> Parts {
+        let Self(value) = self;
+        (
+            value.year(),
+            value.month().into(),
+            value.day(),
+            value.hour(),
+            value.minute(),
+            value.second(),
+            value.nanosecond(),
+            value.offset().whole_minutes(),
+        )
+    }
+}
+
+impl<const MIN_YEAR: i32> Arbitrary for Representable<MIN_YEAR> {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let year = boundary_or_between(
+            g,
+            &[0, 1, 1899, 1900, 1969, 1970, 1999, 2000, 2038, 9998, 9999],
+            MIN_YEAR.into(),
+            9999,
+        ) as i32;
+        let month = boundary_or_between(g, &[1, 2, 12], 1, 12) as u8;
+        let days = Month::try_from(month).map_or(28, |month| days_in_year_month(year, month));
+        let day = boundary_or_between(g, &[1, 28, 29, 30, 31], 1, days.into()) as u8;
+        let hour = boundary_or_between(g, &[0, 23], 0, 23) as u8;
+        let minute = boundary_or_between(g, &[0, 59], 0, 59) as u8;
+        let second = boundary_or_between(g, &[0, 59], 0, 59) as u8;
+        let nanosecond = boundary_or_between(
+            g,
+            &[
+                0,
+                1,
+                9,
+                10,
+                999,
+                1_000,
+                999_999,
+                1_000_000,
+                100_000_000,
+                500_000_000,
+                999_999_999,
+            ],
+            0,
+            999_999_999,
+        ) as u32;
+        let offset_minutes =
+            boundary_or_between(g, &[0, 1, -1, 60, -60, 1_439, -1_439], -1_439, 1_439) as i16;
+
+        Self::from_parts((
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            nanosecond,
+            offset_minutes,
+        ))
+        .expect("generated components should be valid")
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.parts().shrink().filter_map(Self::from_parts))
+    }
+}
+
+/// The last second of the value's month in UTC, expressed in the value's offset. This is the only
+/// point at which a leap second may be given. `None` if the result cannot be represented.
+fn leap_second_stand_in(value: OffsetDateTime) -> Option<OffsetDateTime> {
+    let last_day = value
+        .date()
+        .replace_day(days_in_year_month(value.year(), value.month()))
+        .ok()?;
+    let stand_in = PrimitiveDateTime::new(last_day, time!(23:59:59))
+        .assume_utc()
+        .to_offset(value.offset());
+    (stand_in.year() <= 9999).then_some(stand_in)
+}
+
+/// The first `digits` decimal digits of `numerator / denominator`, which must be less than one.
+fn fraction(mut numerator: u64, denominator: u64, digits: u8) -> String {
+    (0..digits)
+        .map(|_| {
+            numerator *= 10;
+            let digit = numerator / denominator;
+            numerator %= denominator;
+            char::from(b'0' + digit as u8)
+        })
+        .collect()
+}
+
+/// The sign, hours and minutes of an offset.
+fn offset_components(offset: UtcOffset) -> (char, i16, i16) {
+    let minutes = offset.whole_minutes();
+    let sign = if minutes < 0 { '-' } else { '+' };
+    (sign, minutes.abs() / 60, minutes.abs() % 60)
+}
+
+/// A string that is valid RFC 3339, using the syntactic freedom that the format permits.
+#[derive(Clone, Copy)]
+struct Rfc3339String {
+    value: Representable<0>,
+    /// The `T` and `Z` are lowercase.
+    lowercase: bool,
+    /// A zero offset is written as `Z` rather than numerically.
+    utc_as_z: bool,
+    /// A numeric zero offset is written as `-00:00`.
+    negative_utc: bool,
+    /// The number of subsecond digits, which may exceed nine.
+    subsecond_digits: u8,
+    /// The time is the last second of the month in UTC, written as a leap second.
+    leap_second: bool,
+}
+
+impl Rfc3339String {
+    fn render(&self) -> String {
+        let (value, second) = match self.leap_second.then(|| leap_second_stand_in(self.value.0)) {
+            Some(Some(stand_in)) => (stand_in, 60),
+            _ => (self.value.0, self.value.0.second()),
+        };
+        let mut s = format!(
+            "{:04}-{:02}-{:02}{}{:02}:{:02}:{:02}",
+            value.year(),
+            value.month() as u8,
+            value.day(),
+            if self.lowercase { 't' } else { 'T' },
+            value.hour(),
+            value.minute(),
+            second,
+        );
+        if self.subsecond_digits != 0 {
+            s.push('.');
+            s.push_str(&fraction(
+                value.nanosecond().into(),
+                1_000_000_000,
+                self.subsecond_digits,
+            ));
+        }
+        let (sign, hours, minutes) = offset_components(value.offset());
+        if value.offset().is_utc() && self.utc_as_z {
+            s.push(if self.lowercase { 'z' } else { 'Z' });
+        } else {
+            let sign = if value.offset().is_utc() && self.negative_utc {
+                '-'
+            } else {
+                sign
+            };
+            let _ = write!(s, "{sign}{hours:02}:{minutes:02}");
+        }
+        s
+    }
+}
+
+impl fmt::Debug for Rfc3339String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.render(), f)
+    }
+}
+
+impl Arbitrary for Rfc3339String {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self {
+            value: <_>::arbitrary(g),
+            lowercase: <_>::arbitrary(g),
+            utc_as_z: <_>::arbitrary(g),
+            negative_utc: <_>::arbitrary(g),
+            subsecond_digits: u8::arbitrary(g) % 13,
+            leap_second: <_>::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let this = *self;
+        let syntax = (
+            self.lowercase,
+            self.utc_as_z,
+            self.negative_utc,
+            self.subsecond_digits,
+            self.leap_second,
+        );
+        Box::new(
+            self.value
+                .shrink()
+                .map(move |value| Self { value, ..this })
+                .chain(syntax.shrink().map(
+                    move |(lowercase, utc_as_z, negative_utc, subsecond_digits, leap_second)| {
+                        Self {
+                            lowercase,
+                            utc_as_z,
+                            negative_utc,
+                            subsecond_digits,
+                            leap_second,
+                            ..this
+                        }
+                    },
+                )),
+        )
+    }
+}
+
+/// The obsolete zone names that the parser accepts, along with their offset in hours.
+const RFC2822_ZONE_NAMES: [(&str, i16); 10] = [
+    ("UT", 0),
+    ("GMT", 0),
+    ("Z", 0),
+    ("EST", -5),
+    ("EDT", -4),
+    ("CST", -6),
+    ("CDT", -5),
+    ("MST", -7),
+    ("MDT", -6),
+    ("PST", -8),
+];
+
+/// A string that is valid RFC 2822, using the syntactic freedom that the format permits.
+#[derive(Clone, Copy)]
+struct Rfc2822String {
+    value: Representable<1900>,
+    /// A comment follows the weekday.
+    comment: bool,
+    /// The day and month are separated by folding whitespace.
+    folding_whitespace: bool,
+    /// The day is not padded to two digits.
+    unpadded_day: bool,
+    /// The year is written with two digits where possible.
+    two_digit_year: bool,
+    /// The seconds are omitted, truncating the value.
+    omit_second: bool,
+    /// The offset is written as one of the obsolete zone names where possible.
+    zone_name: bool,
+    /// A numeric zero offset is written as `-0000`.
+    negative_utc: bool,
+    /// The time is the last second of the month in UTC, written as a leap second.
+    leap_second: bool,
+}
+
+impl Rfc2822String {
+    fn render(&self) -> String {
+        let leap_second = self.leap_second && !self.omit_second;
+        let (value, second) = match leap_second.then(|| leap_second_stand_in(self.value.0)) {
+            Some(Some(stand_in)) => (stand_in, 60),
+            _ => (self.value.0, self.value.0.second()),
+        };
+        let mut s = format!("{:.3},", value.weekday().to_string());
+        if self.comment {
+            s.push_str(" (a (nested) comment)");
+        }
+        if self.unpadded_day {
+            let _ = write!(s, " {}", value.day());
+        } else {
+            let _ = write!(s, " {:02}", value.day());
+        }
+        s.push_str(if self.folding_whitespace {
+            "\r\n\t"
+        } else {
+            " "
+        });
+        let _ = write!(s, "{:.3} ", value.month().to_string());
+        if self.two_digit_year && (1950..2050).contains(&value.year()) {
+            let _ = write!(s, "{:02} ", value.year() % 100);
+        } else {
+            let _ = write!(s, "{:04} ", value.year());
+        }
+        let _ = write!(s, "{:02}:{:02}", value.hour(), value.minute());
+        if !self.omit_second {
+            let _ = write!(s, ":{second:02}");
+        }
+        s.push(' ');
+
+        let (sign, hours, minutes) = offset_components(value.offset());
+        let zone_name = RFC2822_ZONE_NAMES.iter().find(|&&(_, zone_hours)| {
+            minutes == 0 && i16::from(value.offset().whole_hours()) == zone_hours
+        });
+        match zone_name {
+            Some((name, _)) if self.zone_name => s.push_str(name),
+            _ => {
+                let sign = if value.offset().is_utc() && self.negative_utc {
+                    '-'
+                } else {
+                    sign
+                };
+                let _ = write!(s, "{sign}{hours:02}{minutes:02}");
+            }
+        }
+        s
+    }
+}
+
+impl fmt::Debug for Rfc2822String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.render(), f)
+    }
+}
+
+impl Arbitrary for Rfc2822String {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self {
+            value: <_>::arbitrary(g),
+            comment: <_>::arbitrary(g),
+            folding_whitespace: <_>::arbitrary(g),
+            unpadded_day: <_>::arbitrary(g),
+            two_digit_year: <_>::arbitrary(g),
+            omit_second: <_>::arbitrary(g),
+            zone_name: <_>::arbitrary(g),
+            negative_utc: <_>::arbitrary(g),
+            leap_second: <_>::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let this = *self;
+        let syntax = (
+            (self.comment, self.folding_whitespace, self.unpadded_day),
+            (self.two_digit_year, self.omit_second, self.zone_name),
+            (self.negative_utc, self.leap_second),
+        );
+        Box::new(
+            self.value
+                .shrink()
+                .map(move |value| Self { value, ..this })
+                .chain(syntax.shrink().map(
+                    move |(
+                        (comment, folding_whitespace, unpadded_day),
+                        (two_digit_year, omit_second, zone_name),
+                        (negative_utc, leap_second),
+                    )| Self {
+                        comment,
+                        folding_whitespace,
+                        unpadded_day,
+                        two_digit_year,
+                        omit_second,
+                        zone_name,
+                        negative_utc,
+                        leap_second,
+                        ..this
+                    },
+                )),
+        )
+    }
+}
+
+/// A string that is valid ISO 8601, using the syntactic freedom that the format permits.
+#[derive(Clone, Copy)]
+struct Iso8601String {
+    value: Representable<0>,
+    /// The basic format is used, omitting separators.
+    basic: bool,
+    /// Years are written with six digits and a sign.
+    six_digit_year: bool,
+    /// The date is a calendar date (0), ordinal date (1) or week date (2).
+    date_kind: u8,
+    /// The last time component is the second (0), minute (1) or hour (2).
+    time_precision: u8,
+    /// The number of decimal digits of the last time component, which may exceed nine.
+    decimal_digits: u8,
+    /// The decimal sign is a comma rather than a period.
+    decimal_comma: bool,
+    /// A zero offset is written as `Z` rather than numerically.
+    utc_as_z: bool,
+}
+
+impl Iso8601String {
+    fn render(&self) -> String {
+        let value = self.value.0;
+        let separator = if self.basic { "" } else { "-" };
+        let year = |year: i32| {
+            if self.six_digit_year || !(0..=9999).contains(&year) {
+                let sign = if year < 0 { '-' } else { '+' };
+                format!("{sign}{:06}", year.unsigned_abs())
+            } else {
+                format!("{year:04}")
+            }
+        };
+
+        let mut s = match self.date_kind {
+            0 => format!(
+                "{}{separator}{:02}{separator}{:02}",
+                year(value.year()),
+                value.month() as u8,
+                value.day()
+            ),
+            1 => format!("{}{separator}{:03}", year(value.year()), value.ordinal()),
+            _ => {
+                let (iso_year, week, weekday) = value.to_iso_week_date();
+                format!(
+                    "{}{separator}W{week:02}{separator}{}",
+                    year(iso_year),
+                    weekday.number_from_monday()
+                )
+            }
+        };
+
+        let separator = if self.basic { "" } else { ":" };
+        let (hour, minute, second, nanosecond) = value.to_hms_nano();
+        let nanoseconds_past_minute = u64::from(second) * 1_000_000_000 + u64::from(nanosecond);
+        let (numerator, denominator) = match self.time_precision {
+            0 => {
+                let _ = write!(s, "T{hour:02}{separator}{minute:02}{separator}{second:02}");
+                (nanosecond.into(), 1_000_000_000)
+            }
+            1 => {
+                let _ = write!(s, "T{hour:02}{separator}{minute:02}");
+                (nanoseconds_past_minute, 60_000_000_000)
+            }
+            _ => {
+                let _ = write!(s, "T{hour:02}");
+                (
+                    u64::from(minute) * 60_000_000_000 + nanoseconds_past_minute,
+                    3_600_000_000_000,
+                )
+            }
+        };
+        if self.decimal_digits != 0 {
+            s.push(if self.decimal_comma { ',' } else { '.' });
+            s.push_str(&fraction(numerator, denominator, self.decimal_digits));
+        }
+
+        if value.offset().is_utc() && self.utc_as_z {
+            s.push('Z');
+        } else {
+            let (sign, hours, minutes) = offset_components(value.offset());
+            let _ = write!(s, "{sign}{hours:02}{separator}{minutes:02}");
+        }
+        s
+    }
+}
+
+impl fmt::Debug for Iso8601String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.render(), f)
+    }
+}
+
+impl Arbitrary for Iso8601String {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self {
+            value: <_>::arbitrary(g),
+            basic: <_>::arbitrary(g),
+            six_digit_year: <_>::arbitrary(g),
+            date_kind: u8::arbitrary(g) % 3,
+            time_precision: u8::arbitrary(g) % 3,
+            decimal_digits: u8::arbitrary(g) % 13,
+            decimal_comma: <_>::arbitrary(g),
+            utc_as_z: <_>::arbitrary(g),
+        }
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let this = *self;
+        let syntax = (
+            self.basic,
+            self.six_digit_year,
+            self.date_kind,
+            self.time_precision,
+            self.decimal_digits,
+            self.decimal_comma,
+            self.utc_as_z,
+        );
+        Box::new(
+            self.value
+                .shrink()
+                .map(move |value| Self { value, ..this })
+                .chain(syntax.shrink().map(
+                    move |(
+                        basic,
+                        six_digit_year,
+                        date_kind,
+                        time_precision,
+                        decimal_digits,
+                        decimal_comma,
+                        utc_as_z,
+                    )| Self {
+                        basic,
+                        six_digit_year,
+                        date_kind,
+                        time_precision,
+                        decimal_digits,
+                        decimal_comma,
+                        utc_as_z,
+                        ..this
+                    },
+                )),
+        )
+    }
+}
+
+/// Format the value and parse the result.
+fn format_then_parse(
+    value: OffsetDateTime,
+    format: &(impl Formattable + Parsable + ?Sized),
+) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(&value.format(format).ok()?, format).ok()
+}
+
+/// Parse the string, then check that formatting and parsing the result is lossless.
+fn parse_is_stable(s: &str, format: &(impl Formattable + Parsable + ?Sized)) -> bool {
+    OffsetDateTime::parse(s, format).map_or(false, |value| {
+        format_then_parse(value, format) == Some(value)
+    })
+}
+
+#[quickcheck]
+fn rfc3339_roundtrip(value: Representable<0>) -> bool {
+    format_then_parse(value.0, &Rfc3339) == Some(value.0)
+}
+
+#[quickcheck]
+fn rfc2822_roundtrip(value: Representable<1900>) -> bool {
+    // RFC 2822 has no subsecond component.
+    format_then_parse(value.0, &Rfc2822) == value.0.replace_nanosecond(0).ok()
+}
+
+#[quickcheck]
+fn iso8601_roundtrip(value: Representable<0>) -> bool {
+    format_then_parse(value.0, &Iso8601::DEFAULT) == Some(value.0)
+}
+
+#[quickcheck]
+fn date_display_roundtrip(d: Date) -> bool {
+    let s = d.to_string();
+    d.format(display::DATE).ok().as_ref() == Some(&s) && s.parse() == Ok(d)
+}
+
+#[quickcheck]
+fn time_display_roundtrip(t: Time) -> bool {
+    let s = t.to_string();
+    t.format(display::TIME).ok().as_ref() == Some(&s) && s.parse() == Ok(t)
+}
+
+#[quickcheck]
+fn utc_offset_display_roundtrip(o: UtcOffset) -> bool {
+    let s = o.to_string();
+    o.format(display::UTC_OFFSET).ok().as_ref() == Some(&s) && s.parse() == Ok(o)
+}
+
+#[quickcheck]
+fn primitive_date_time_display_roundtrip(a: PrimitiveDateTime) -> bool {
+    let s = a.to_string();
+    a.format(display::PRIMITIVE_DATE_TIME).ok().as_ref() == Some(&s) && s.parse() == Ok(a)
+}
+
+#[quickcheck]
+fn offset_date_time_display_roundtrip(a: OffsetDateTime) -> bool {
+    let s = a.to_string();
+    a.format(display::OFFSET_DATE_TIME).ok().as_ref() == Some(&s)
+        && s.parse::<OffsetDateTime>().map(|b| (b, b.offset())) == Ok((a, a.offset()))
+}
+
+#[quickcheck]
+fn rfc3339_parse_is_stable(s: Rfc3339String) -> bool {
+    parse_is_stable(&s.render(), &Rfc3339)
+}
+
+#[quickcheck]
+fn rfc2822_parse_is_stable(s: Rfc2822String) -> bool {
+    // A leap second is parsed as the preceding nanosecond, which RFC 2822 is unable to format.
+    OffsetDateTime::parse(&s.render(), &Rfc2822).map_or(false, |value| {
+        format_then_parse(value, &Rfc2822) == value.replace_nanosecond(0).ok()
+            && (value.nanosecond() == 0 || s.leap_second)
+    })
+}
+
+#[quickcheck]
+fn iso8601_parse_is_stable(s: Iso8601String) -> bool {
+    parse_is_stable(&s.render(), &Iso8601::DEFAULT)
+}
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Token};
+use time::macros::date;
+use time::serde::date::timestamp;
+use time::Date;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TestDate {
+    #[serde(with = "timestamp")]
+    date: Date,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TestDateOption {
+    #[serde(with = "timestamp::option")]
+    date: Option<Date>,
+}
+
+fn date_tokens(timestamp: i64) -> [Token; 4] {
+    [
+        Token::Struct {
+            name: "TestDate",
+            len: 1,
+        },
+        Token::Str("date"),
+        Token::I64(timestamp),
+        Token::StructEnd,
+    ]
+}
+
+#[test]
+fn serialize_date_timestamp() {
+    assert_tokens(
+        &TestDate {
+            date: date!(2000-01-01),
+        }
+        .compact(),
+        &date_tokens(946_684_800),
+    );
+    assert_tokens(
+        &TestDate {
+            date: date!(1969-12-31),
+        }
+        .compact(),
+        &date_tokens(-86_400),
+    );
+    assert_tokens(
+        &TestDate {
+            date: date!(1970-01-01),
+        }
+        .compact(),
+        &date_tokens(0),
+    );
+}
+
+#[test]
+fn deserialize_date_timestamp_truncates() {
+    // 1999-12-31T23:59:59Z
+    assert_de_tokens(
+        &TestDate {
+            date: date!(1999-12-31),
+        },
+        &date_tokens(946_684_799),
+    );
+    // 2000-01-01T00:00:00Z
+    assert_de_tokens(
+        &TestDate {
+            date: date!(2000-01-01),
+        },
+        &date_tokens(946_684_800),
+    );
+    // 2000-01-01T23:59:59Z
+    assert_de_tokens(
+        &TestDate {
+            date: date!(2000-01-01),
+        },
+        &date_tokens(946_771_199),
+    );
+    // 1969-12-31T23:59:59Z
+    assert_de_tokens(
+        &TestDate {
+            date: date!(1969-12-31),
+        },
+        &date_tokens(-1),
+    );
+    // 1969-12-31T00:00:00Z
+    assert_de_tokens(
+        &TestDate {
+            date: date!(1969-12-31),
+        },
+        &date_tokens(-86_400),
+    );
+    // 1969-12-30T23:59:59Z
+    assert_de_tokens(
+        &TestDate {
+            date: date!(1969-12-30),
+        },
+        &date_tokens(-86_401),
+    );
+}
+
+#[test]
+fn date_timestamp_error() {
+    assert_de_tokens_error::<TestDate>(
+        &[
+            Token::Struct {
+                name: "TestDate",
+                len: 1,
+            },
+            Token::Str("date"),
+            Token::Str("bad"),
+            Token::StructEnd,
+        ],
+        "invalid type: string \"bad\", expected i64",
+    );
+    assert_de_tokens_error::<TestDate>(
+        &date_tokens(i64::MAX),
+        &format!(
+            "invalid value: integer `{}`, expected a value in the range -31619087596800..=31494784780799",
+            i64::MAX
+        ),
+    );
+}
+
+#[test]
+fn serialize_date_timestamp_option() {
+    assert_tokens(
+        &TestDateOption {
+            date: Some(date!(2000-01-01)),
+        }
+        .compact(),
+        &[
+            Token::Struct {
+                name: "TestDateOption",
+                len: 1,
+            },
+            Token::Str("date"),
+            Token::Some,
+            Token::I64(946_684_800),
+            Token::StructEnd,
+        ],
+    );
+    assert_tokens(
+        &TestDateOption { date: None }.compact(),
+        &[
+            Token::Struct {
+                name: "TestDateOption",
+                len: 1,
+            },
+            Token::Str("date"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+    assert_de_tokens(
+        &TestDateOption {
+            date: Some(date!(1969-12-31)),
+        },
+        &[
+            Token::Struct {
+                name: "TestDateOption",
+                len: 1,
+            },
+            Token::Str("date"),
+            Token::Some,
+            Token::I64(-1),
+            Token::StructEnd,
+        ],
+    );
+}
@@ -0,0 +1,130 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{
+    assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Configure, Token,
+};
+use time::format_description::well_known::{Rfc2822, Rfc3339};
+use time::macros::{datetime, time};
+use time::serde::timestamp::Timestamp;
+use time::{Date, OffsetDateTime};
+
+#[derive(Debug, PartialEq, Eq)]
+struct CreatedAt(OffsetDateTime);
+time::serde::delegate_well_known!(CreatedAt => OffsetDateTime, Rfc3339);
+
+#[derive(Debug, PartialEq, Eq)]
+struct ExpiresAt(OffsetDateTime);
+time::serde::delegate_well_known!(ExpiresAt => OffsetDateTime, Rfc2822);
+
+#[derive(Debug, PartialEq, Eq)]
+struct SeenAt(OffsetDateTime);
+time::serde::delegate_well_known!(SeenAt => OffsetDateTime, Timestamp);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct Test {
+    created_at: CreatedAt,
+    updated_at: Option<CreatedAt>,
+    expires_at: ExpiresAt,
+    seen_at: Vec<SeenAt>,
+}
+
+#[test]
+fn serialize_deserialize() {
+    let value = Test {
+        created_at: CreatedAt(datetime!(2000-01-01 00:00:00 UTC)),
+        updated_at: Some(CreatedAt(datetime!(2000-01-02 00:00:00 +01:00))),
+        expires_at: ExpiresAt(datetime!(2000-01-03 00:00:00 UTC)),
+        seen_at: vec![SeenAt(datetime!(2000-01-01 00:00:00 UTC))],
+    };
+    assert_tokens(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 4,
+            },
+            Token::Str("created_at"),
+            Token::String("2000-01-01T00:00:00Z"),
+            Token::Str("updated_at"),
+            Token::Some,
+            Token::String("2000-01-02T00:00:00+01:00"),
+            Token::Str("expires_at"),
+            Token::String("Mon, 03 Jan 2000 00:00:00 +0000"),
+            Token::Str("seen_at"),
+            Token::Seq { len: Some(1) },
+            Token::I64(946_684_800),
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+
+    let value = Test {
+        created_at: CreatedAt(datetime!(2000-01-01 00:00:00 UTC)),
+        updated_at: None,
+        expires_at: ExpiresAt(datetime!(2000-01-03 00:00:00 UTC)),
+        seen_at: Vec::new(),
+    };
+    assert_tokens(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "Test",
+                len: 4,
+            },
+            Token::Str("created_at"),
+            Token::String("2000-01-01T00:00:00Z"),
+            Token::Str("updated_at"),
+            Token::None,
+            Token::Str("expires_at"),
+            Token::String("Mon, 03 Jan 2000 00:00:00 +0000"),
+            Token::Str("seen_at"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn json() -> serde_json::Result<()> {
+    let json = r#"{"created_at":"2000-01-01T00:00:00Z","updated_at":null,"expires_at":"Mon, 03 Jan 2000 00:00:00 +0000","seen_at":[0]}"#;
+    let value: Test = serde_json::from_str(json)?;
+    assert_eq!(
+        value,
+        Test {
+            created_at: CreatedAt(datetime!(2000-01-01 00:00:00 UTC)),
+            updated_at: None,
+            expires_at: ExpiresAt(datetime!(2000-01-03 00:00:00 UTC)),
+            seen_at: vec![SeenAt(OffsetDateTime::UNIX_EPOCH)],
+        }
+    );
+    assert_eq!(serde_json::to_string(&value)?, json);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    assert_de_tokens_error::<CreatedAt>(
+        &[Token::Str("2000-01-01")],
+        "a character literal was not valid",
+    );
+    assert_de_tokens_error::<Option<CreatedAt>>(
+        &[Token::Some, Token::Str("2000-01-01T00:00:00")],
+        "the 'offset hour' component could not be parsed",
+    );
+    assert_ser_tokens_error::<CreatedAt>(
+        &CreatedAt(datetime!(-0001-01-01 00:00:00 UTC)),
+        &[],
+        "The year component cannot be formatted into the requested format.",
+    );
+    assert_de_tokens_error::<SeenAt>(
+        &[Token::I64(i64::MAX)],
+        &format!(
+            "invalid value: integer `9223372036854775807`, expected a value in the range {}..={}",
+            Date::MIN.midnight().assume_utc().unix_timestamp(),
+            Date::MAX
+                .with_time(time!(23:59:59))
+                .assume_utc()
+                .unix_timestamp(),
+        ),
+    );
+}
@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+use time::ext::NumericalDuration;
+use time::serde::duration;
+use time::Duration;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Durations {
+    #[serde(with = "duration::seconds")]
+    seconds: Duration,
+    #[serde(with = "duration::milliseconds")]
+    milliseconds: Duration,
+    #[serde(with = "duration::microseconds")]
+    microseconds: Duration,
+    #[serde(with = "duration::nanoseconds")]
+    nanoseconds: Duration,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct OptionalDurations {
+    #[serde(with = "duration::seconds::option")]
+    seconds: Option<Duration>,
+    #[serde(with = "duration::milliseconds::option")]
+    milliseconds: Option<Duration>,
+    #[serde(with = "duration::nanoseconds")]
+    nanoseconds: Option<Duration>,
+}
+
+impl Durations {
+    fn all(duration: Duration) -> Self {
+        Self {
+            seconds: duration,
+            milliseconds: duration,
+            microseconds: duration,
+            nanoseconds: duration,
+        }
+    }
+}
+
+#[test]
+fn seconds() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Seconds(#[serde(with = "duration::seconds")] Duration);
+
+    assert_tokens(
+        &Seconds(Duration::MIN.whole_seconds().seconds()),
+        &[
+            Token::NewtypeStruct { name: "Seconds" },
+            Token::I64(i64::MIN),
+        ],
+    );
+    assert_tokens(
+        &Seconds(Duration::MAX.whole_seconds().seconds()),
+        &[
+            Token::NewtypeStruct { name: "Seconds" },
+            Token::I64(i64::MAX),
+        ],
+    );
+    assert_tokens(
+        &Seconds((-5).seconds()),
+        &[Token::NewtypeStruct { name: "Seconds" }, Token::I64(-5)],
+    );
+    assert_de_tokens_error::<Seconds>(
+        &[Token::NewtypeStruct { name: "Seconds" }, Token::Str("5")],
+        r#"invalid type: string "5", expected i64"#,
+    );
+}
+
+#[test]
+fn json() -> serde_json::Result<()> {
+    let value = Durations::all((-1_234_567_891).nanoseconds());
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        r#"{"seconds":-1,"milliseconds":-1234,"microseconds":-1234567,"nanoseconds":-1234567891}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<Durations>(&json)?,
+        Durations {
+            seconds: (-1).seconds(),
+            milliseconds: (-1_234).milliseconds(),
+            microseconds: (-1_234_567).microseconds(),
+            nanoseconds: (-1_234_567_891).nanoseconds(),
+        }
+    );
+
+    let zero = Durations::all(Duration::ZERO);
+    assert_eq!(
+        serde_json::from_str::<Durations>(&serde_json::to_string(&zero)?)?,
+        zero
+    );
+    Ok(())
+}
+
+#[test]
+fn json_boundaries() -> serde_json::Result<()> {
+    for duration in [Duration::MIN, Duration::MAX] {
+        let seconds = duration.whole_seconds();
+        let value = Durations::all(duration);
+        let json = serde_json::to_string(&value)?;
+        assert_eq!(
+            serde_json::from_str::<Durations>(&json)?,
+            Durations {
+                seconds: seconds.seconds(),
+                milliseconds: Duration::new(
+                    seconds,
+                    i32::from(duration.subsec_milliseconds()) * 1_000_000
+                ),
+                microseconds: Duration::new(seconds, duration.subsec_microseconds() * 1_000),
+                nanoseconds: duration,
+            }
+        );
+    }
+
+    // Values beyond the range of an `i64` are written in full.
+    assert_eq!(
+        serde_json::to_string(&Durations::all(Duration::MAX))?,
+        concat!(
+            r#"{"seconds":9223372036854775807,"milliseconds":9223372036854775807999,"#,
+            r#""microseconds":9223372036854775807999999,"#,
+            r#""nanoseconds":9223372036854775807999999999}"#
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn json_out_of_range() {
+    for json in [
+        r#"{"seconds":0,"milliseconds":9223372036854775808000,"microseconds":0,"nanoseconds":0}"#,
+        r#"{"seconds":0,"milliseconds":0,"microseconds":-9223372036854775809000000,"nanoseconds":0}"#,
+        r#"{"seconds":0,"milliseconds":0,"microseconds":0,"nanoseconds":9223372036854775808000000000}"#,
+    ] {
+        let err = serde_json::from_str::<Durations>(json).unwrap_err();
+        assert!(
+            err.to_string().contains("out of range"),
+            "unexpected error: {err}"
+        );
+    }
+    assert!(
+        serde_json::from_str::<Durations>(
+            r#"{"seconds":9223372036854775808,"milliseconds":0,"microseconds":0,"nanoseconds":0}"#
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn option() -> serde_json::Result<()> {
+    let value = OptionalDurations {
+        seconds: Some(90.seconds()),
+        milliseconds: Some((-1.5).seconds()),
+        nanoseconds: None,
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        r#"{"seconds":90,"milliseconds":-1500,"nanoseconds":null}"#
+    );
+    assert_eq!(serde_json::from_str::<OptionalDurations>(&json)?, value);
+    Ok(())
+}
+
+#[test]
+fn bincode() -> bincode::Result<()> {
+    for duration in [
+        Duration::MIN,
+        Duration::MAX,
+        Duration::ZERO,
+        (-1).nanoseconds(),
+        i64::MIN.milliseconds(),
+        i64::MAX.milliseconds(),
+    ] {
+        let value = Durations::all(duration);
+        let bytes = bincode::serialize(&value)?;
+        assert_eq!(
+            bincode::deserialize::<(i64, i128, i128, i128)>(&bytes)?,
+            (
+                duration.whole_seconds(),
+                duration.whole_milliseconds(),
+                duration.whole_microseconds(),
+                duration.whole_nanoseconds(),
+            )
+        );
+        assert_eq!(
+            bincode::deserialize::<Durations>(&bytes)?.nanoseconds,
+            duration
+        );
+    }
+
+    let value = OptionalDurations {
+        seconds: None,
+        milliseconds: Some(i64::MIN.milliseconds()),
+        nanoseconds: Some(Duration::MAX),
+    };
+    assert_eq!(
+        bincode::deserialize::<OptionalDurations>(&bincode::serialize(&value)?)?,
+        value
+    );
+    Ok(())
+}
@@ -2,9 +2,14 @@ use serde::{Deserialize, Serialize};
 use serde_test::{
     assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Configure, Token,
 };
+use time::ext::NumericalDuration;
+use time::format_description::well_known::iso8601::{
+    Config, DateKind, OffsetPrecision, TimePrecision,
+};
+use time::format_description::well_known::Iso8601Dynamic;
 use time::macros::datetime;
 use time::serde::iso8601;
-use time::OffsetDateTime;
+use time::{Duration, OffsetDateTime, PrimitiveDateTime};
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
 struct Test {
@@ -89,3 +94,165 @@ fn deserialize_error() {
         "the 'year' component could not be parsed",
     );
 }
+
+#[test]
+fn deserialize_any_config() -> Result<(), Box<dyn std::error::Error>> {
+    #[derive(Deserialize)]
+    struct Plain {
+        #[serde(with = "iso8601")]
+        dt: OffsetDateTime,
+    }
+
+    // Representable exactly under every time precision used below.
+    let values = [
+        datetime!(2021-01-02 03:30:00 +06:00),
+        datetime!(2021-01-02 03:30:00 -06:00),
+        datetime!(-0001-12-31 23:30:00 UTC),
+        datetime!(+12345-06-07 08:30:00 +01:00),
+    ];
+    let time_precisions = [
+        TimePrecision::Hour {
+            decimal_digits: core::num::NonZeroU8::new(1),
+        },
+        TimePrecision::Minute {
+            decimal_digits: None,
+        },
+        TimePrecision::Minute {
+            decimal_digits: core::num::NonZeroU8::new(3),
+        },
+        TimePrecision::Second {
+            decimal_digits: None,
+        },
+        TimePrecision::Second {
+            decimal_digits: core::num::NonZeroU8::new(9),
+        },
+    ];
+
+    for value in values {
+        for use_separators in [true, false] {
+            for year_is_six_digits in [true, false] {
+                for date_kind in [DateKind::Calendar, DateKind::Week, DateKind::Ordinal] {
+                    for time_precision in time_precisions {
+                        for offset_precision in [
+                            OffsetPrecision::Hour,
+                            OffsetPrecision::Minute,
+                            OffsetPrecision::Second,
+                        ] {
+                            let config = Config::DEFAULT
+                                .set_use_separators(use_separators)
+                                .set_year_is_six_digits(year_is_six_digits)
+                                .set_date_kind(date_kind)
+                                .set_time_precision(time_precision)
+                                .set_offset_precision(offset_precision);
+                            // Not every value can be formatted with every configuration.
+                            let Ok(formatted) = value.format(&Iso8601Dynamic::from_config(config))
+                            else {
+                                continue;
+                            };
+
+                            let json = format!(r#"{{"dt":"{formatted}"}}"#);
+                            let parsed: Plain = serde_json::from_str(&json)
+                                .map_err(|err| format!("{formatted}: {err}"))?;
+                            assert_eq!(parsed.dt, value, "{formatted}");
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn primitive() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Test {
+        #[serde(with = "iso8601::primitive")]
+        dt: PrimitiveDateTime,
+        #[serde(with = "iso8601::primitive::option")]
+        option_dt: Option<PrimitiveDateTime>,
+    }
+
+    let value = Test {
+        dt: datetime!(2000-01-01 12:34:56.789),
+        option_dt: None,
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        r#"{"dt":"+002000-01-01T12:34:56.789000000Z","option_dt":null}"#
+    );
+    assert_eq!(serde_json::from_str::<Test>(&json)?, value);
+
+    assert_eq!(
+        serde_json::from_str::<Test>(
+            r#"{"dt":"2000-01-01T12:34:56Z","option_dt":"2000-01-01T12:34:56+00"}"#
+        )?,
+        Test {
+            dt: datetime!(2000-01-01 12:34:56),
+            option_dt: Some(datetime!(2000-01-01 12:34:56)),
+        }
+    );
+
+    let err = serde_json::from_str::<Test>(
+        r#"{"dt":"2000-01-01T12:34:56Z","option_dt":"2000-01-01T12:34:56+0530"}"#,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().starts_with(
+            "invalid value: a non-UTC offset, expected an ISO 8601-formatted \
+             `PrimitiveDateTime` with a UTC offset"
+        ),
+        "unexpected error: {err}"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn duration() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Test {
+        #[serde(with = "iso8601::duration")]
+        duration: Duration,
+        #[serde(with = "iso8601::duration::option")]
+        option_duration: Option<Duration>,
+        #[serde(with = "iso8601::duration")]
+        durations: Vec<Duration>,
+    }
+
+    let value = Test {
+        duration: 1.days() + 90.minutes(),
+        option_duration: None,
+        durations: vec![(-1.5).seconds(), Duration::ZERO],
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        r#"{"duration":"P1DT1H30M","option_duration":null,"durations":["-PT1.5S","PT0S"]}"#
+    );
+    assert_eq!(serde_json::from_str::<Test>(&json)?, value);
+
+    assert_eq!(
+        serde_json::from_str::<Test>(
+            r#"{"duration":"P2W","option_duration":"PT0,5H","durations":[]}"#
+        )?,
+        Test {
+            duration: 2.weeks(),
+            option_duration: Some(30.minutes()),
+            durations: vec![],
+        }
+    );
+
+    let err =
+        serde_json::from_str::<Test>(r#"{"duration":"P1Y","option_duration":null,"durations":[]}"#)
+            .unwrap_err();
+    assert!(
+        err.to_string()
+            .starts_with("the 'unit' component could not be parsed"),
+        "unexpected error: {err}"
+    );
+
+    Ok(())
+}
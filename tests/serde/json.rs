@@ -103,10 +103,18 @@ fn offset_datetime_json() -> Result<(), Box<dyn Error>> {
         dt
     );
 
+    #[cfg(not(feature = "serde-well-known-default"))]
     assert_eq!(
         serialize(dt.readable())?,
         "\"2022-05-20 12:40:20.0 +00:00:00\""
     );
+    #[cfg(feature = "serde-well-known-default")]
+    assert_eq!(serialize(dt.readable())?, "\"2022-05-20T12:40:20Z\"");
+    #[cfg(feature = "serde-well-known-default")]
+    assert_eq!(
+        deserialize::<OffsetDateTime>("\"2022-05-20T12:40:20Z\"", Readable)?,
+        dt
+    );
     assert_eq!(
         deserialize::<OffsetDateTime>("\"2022-05-20 12:40:20.0 +00:00:00\"", Readable)?,
         dt
@@ -1,15 +1,22 @@
 #[rustfmt::skip] // Tries to remove the leading `::`, which breaks compilation.
 use ::serde::{Deserialize, Serialize};
+use std::sync::{Mutex, PoisonError};
+
 use serde_test::{
     assert_de_tokens, assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Configure,
     Token,
 };
-use time::format_description::well_known::{iso8601, Iso8601};
-use time::format_description::FormatItem;
+use time::error::InvalidFormatDescription;
+use time::ext::NumericalDuration;
+use time::format_description::well_known::{
+    iso8601, Iso8601, Iso8601Duration, Iso8601Dynamic, Unix,
+};
+use time::format_description::{self, FormatItem, OwnedFormatItem};
 use time::macros::{date, datetime, offset, time};
-use time::{serde, Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+use time::{serde, Date, Duration, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 
 // Not used in the tests, but ensures that the macro compiles.
+#[allow(dead_code)]
 const ISO_FORMAT: Iso8601<{ iso8601::Config::DEFAULT.encode() }> =
     Iso8601::<{ iso8601::Config::DEFAULT.encode() }>;
 time::serde::format_description!(my_format, OffsetDateTime, ISO_FORMAT);
@@ -144,6 +151,126 @@ fn custom_serialize_error() {
     );
 }
 
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct TestVec {
+    #[serde(with = "time_format_alt::vec")]
+    times: Vec<Time>,
+    #[serde(with = "time_format_alt::option_vec")]
+    maybe_times: Option<Vec<Time>>,
+}
+
+#[test]
+fn vec() {
+    assert_tokens(
+        &TestVec {
+            times: vec![time!(1:02), time!(3:04)],
+            maybe_times: Some(vec![time!(5:06)]),
+        },
+        &[
+            Token::Struct {
+                name: "TestVec",
+                len: 2,
+            },
+            Token::Str("times"),
+            Token::Seq { len: Some(2) },
+            Token::Str("01:02"),
+            Token::Str("03:04"),
+            Token::SeqEnd,
+            Token::Str("maybe_times"),
+            Token::Some,
+            Token::Seq { len: Some(1) },
+            Token::Str("05:06"),
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+    assert_tokens(
+        &TestVec {
+            times: Vec::new(),
+            maybe_times: None,
+        },
+        &[
+            Token::Struct {
+                name: "TestVec",
+                len: 2,
+            },
+            Token::Str("times"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("maybe_times"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+    assert_tokens(
+        &TestVec {
+            times: Vec::new(),
+            maybe_times: Some(Vec::new()),
+        },
+        &[
+            Token::Struct {
+                name: "TestVec",
+                len: 2,
+            },
+            Token::Str("times"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("maybe_times"),
+            Token::Some,
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn vec_error() {
+    // The index of the element that failed to parse is reported.
+    assert_de_tokens_error::<TestVec>(
+        &[
+            Token::Struct {
+                name: "TestVec",
+                len: 2,
+            },
+            Token::Str("times"),
+            Token::Seq { len: Some(2) },
+            Token::Str("01:02"),
+            Token::Str("03:4"),
+        ],
+        "invalid value at index 1: the 'minute' component could not be parsed",
+    );
+    assert_de_tokens_error::<TestVec>(
+        &[
+            Token::Struct {
+                name: "TestVec",
+                len: 2,
+            },
+            Token::Str("times"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::Str("maybe_times"),
+            Token::Some,
+            Token::Seq { len: Some(1) },
+            Token::Bool(false),
+        ],
+        "invalid type: boolean `false`, expected a(n) `Time` in the format \"TIME_FORMAT_ALT\" at \
+         index 0",
+    );
+    assert_de_tokens_error::<TestVec>(
+        &[
+            Token::Struct {
+                name: "TestVec",
+                len: 2,
+            },
+            Token::Str("times"),
+            Token::Str("01:02"),
+        ],
+        "invalid type: string \"01:02\", expected a sequence of `Time` in the \
+         format \"TIME_FORMAT_ALT\"",
+    );
+}
+
 // This format string has offset_hour and offset_minute, but is for formatting PrimitiveDateTime.
 serde::format_description!(
     primitive_date_time_format_bad,
@@ -172,7 +299,8 @@ fn custom_serialize_bad_type_error() {
             },
             Token::Str("dt"),
         ],
-        "The type being formatted does not contain sufficient information to format a component.",
+        "The type being formatted does not contain sufficient information to format a component: \
+         a UTC offset is required.",
     );
 }
 
@@ -283,3 +411,281 @@ fn nested() {
         ],
     );
 }
+
+/// The format is only known at runtime, such as when loaded from configuration.
+fn runtime_time_format() -> String {
+    ["[hour]", "[minute]"].join(":")
+}
+
+/// Parse the format description on first use, caching it for subsequent calls.
+fn cached(
+    cache: &Mutex<Option<&'static OwnedFormatItem>>,
+    description: &str,
+) -> Result<&'static OwnedFormatItem, InvalidFormatDescription> {
+    let mut cache = cache.lock().unwrap_or_else(PoisonError::into_inner);
+    let format = match *cache {
+        Some(format) => format,
+        None => {
+            let format = format_description::parse_owned::<2>(description)?;
+            let format = &*Box::leak(Box::new(format));
+            *cache = Some(format);
+            format
+        }
+    };
+    drop(cache);
+    Ok(format)
+}
+
+fn lazy_time_description() -> Result<&'static OwnedFormatItem, InvalidFormatDescription> {
+    static FORMAT: Mutex<Option<&'static OwnedFormatItem>> = Mutex::new(None);
+    cached(&FORMAT, &runtime_time_format())
+}
+
+fn lazy_invalid_description() -> Result<&'static OwnedFormatItem, InvalidFormatDescription> {
+    static FORMAT: Mutex<Option<&'static OwnedFormatItem>> = Mutex::new(None);
+    cached(&FORMAT, "[hour]:[bad]")
+}
+
+mod lazy {
+    use super::*;
+
+    pub(super) const FORMAT: &[FormatItem<'_>] =
+        time::macros::format_description!("[hour]:[minute]:[second]");
+}
+
+serde::format_description!(lazy_time_format, Time, lazy lazy_time_description);
+serde::format_description!(lazy_invalid_format, Time, lazy lazy_invalid_description);
+serde::format_description!(lazy_path_format, Time, lazy::FORMAT);
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct TestLazy {
+    #[serde(with = "lazy_time_format")]
+    time: Time,
+    #[serde(with = "lazy_time_format::option")]
+    maybe_time: Option<Time>,
+    #[serde(with = "lazy_path_format")]
+    hour: Time,
+}
+
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+struct TestLazyInvalid {
+    #[serde(with = "lazy_invalid_format::option")]
+    time: Option<Time>,
+}
+
+#[test]
+fn lazy() {
+    assert_tokens(
+        &TestLazy {
+            time: time!(12:34),
+            maybe_time: Some(time!(1:02)),
+            hour: time!(5:00),
+        },
+        &[
+            Token::Struct {
+                name: "TestLazy",
+                len: 3,
+            },
+            Token::Str("time"),
+            Token::BorrowedStr("12:34"),
+            Token::Str("maybe_time"),
+            Token::Some,
+            Token::BorrowedStr("01:02"),
+            Token::Str("hour"),
+            Token::BorrowedStr("05:00:00"),
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn lazy_error() {
+    let message = format_description::parse_owned::<2>("[hour]:[bad]")
+        .expect_err("format description should be invalid")
+        .to_string();
+
+    assert_ser_tokens_error(
+        &TestLazyInvalid {
+            time: Some(time!(12:34)),
+        },
+        &[
+            Token::Struct {
+                name: "TestLazyInvalid",
+                len: 1,
+            },
+            Token::Str("time"),
+        ],
+        &message,
+    );
+    assert_de_tokens_error::<TestLazyInvalid>(
+        &[
+            Token::Struct {
+                name: "TestLazyInvalid",
+                len: 1,
+            },
+            Token::Str("time"),
+            Token::Some,
+            Token::BorrowedStr("12:34"),
+        ],
+        &message,
+    );
+    // No format description is needed when there is no value.
+    assert_tokens(
+        &TestLazyInvalid { time: None },
+        &[
+            Token::Struct {
+                name: "TestLazyInvalid",
+                len: 1,
+            },
+            Token::Str("time"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+fn lazy_iso8601_description() -> Result<&'static Iso8601Dynamic, core::convert::Infallible> {
+    static FORMAT: Iso8601Dynamic = Iso8601Dynamic::from_config(
+        iso8601::Config::DEFAULT
+            .set_year_is_six_digits(true)
+            .set_time_precision(iso8601::TimePrecision::Second {
+                decimal_digits: None,
+            }),
+    );
+    Ok(&FORMAT)
+}
+
+serde::format_description!(lazy_iso8601_format, OffsetDateTime, lazy lazy_iso8601_description);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct TestLazyIso8601 {
+    #[serde(with = "lazy_iso8601_format")]
+    dt: OffsetDateTime,
+}
+
+#[test]
+fn lazy_iso8601_dynamic() {
+    assert_tokens(
+        &TestLazyIso8601 {
+            dt: datetime!(2021-01-02 03:04:05 +06:07),
+        },
+        &[
+            Token::Struct {
+                name: "TestLazyIso8601",
+                len: 1,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("+002021-01-02T03:04:05+06:07"),
+            Token::StructEnd,
+        ],
+    );
+}
+
+const DURATION_FORMAT: Iso8601Duration = Iso8601Duration::DEFAULT.set_use_days(false);
+serde::format_description!(duration_format, Duration, DURATION_FORMAT);
+
+fn lazy_duration_description() -> Result<&'static Iso8601Duration, core::convert::Infallible> {
+    static FORMAT: Iso8601Duration = Iso8601Duration::DEFAULT;
+    Ok(&FORMAT)
+}
+
+serde::format_description!(lazy_duration_format, Duration, lazy lazy_duration_description);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct TestDuration {
+    #[serde(with = "duration_format")]
+    duration: Duration,
+    #[serde(with = "duration_format::option")]
+    maybe_duration: Option<Duration>,
+    #[serde(with = "lazy_duration_format::vec")]
+    durations: Vec<Duration>,
+}
+
+#[test]
+fn duration() {
+    assert_tokens(
+        &TestDuration {
+            duration: 26.hours() + 30.minutes(),
+            maybe_duration: Some((-1.5).seconds()),
+            durations: vec![26.hours(), 2.weeks()],
+        },
+        &[
+            Token::Struct {
+                name: "TestDuration",
+                len: 3,
+            },
+            Token::Str("duration"),
+            Token::BorrowedStr("PT26H30M"),
+            Token::Str("maybe_duration"),
+            Token::Some,
+            Token::BorrowedStr("-PT1.5S"),
+            Token::Str("durations"),
+            Token::Seq { len: Some(2) },
+            Token::BorrowedStr("P1DT2H"),
+            Token::BorrowedStr("P14D"),
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+    assert_tokens(
+        &TestDuration {
+            duration: Duration::ZERO,
+            maybe_duration: None,
+            durations: Vec::new(),
+        },
+        &[
+            Token::Struct {
+                name: "TestDuration",
+                len: 3,
+            },
+            Token::Str("duration"),
+            Token::BorrowedStr("PT0S"),
+            Token::Str("maybe_duration"),
+            Token::None,
+            Token::Str("durations"),
+            Token::Seq { len: Some(0) },
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+    assert_de_tokens_error::<TestDuration>(
+        &[
+            Token::Struct {
+                name: "TestDuration",
+                len: 3,
+            },
+            Token::Str("duration"),
+            Token::BorrowedStr("P1Y"),
+        ],
+        &Duration::parse("P1Y", &DURATION_FORMAT)
+            .expect_err("years should be rejected")
+            .to_string(),
+    );
+}
+
+const UNIX_FORMAT: Unix = Unix;
+serde::format_description!(unix_format, OffsetDateTime, UNIX_FORMAT);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq, Eq)]
+struct TestUnix {
+    #[serde(with = "unix_format")]
+    dt: OffsetDateTime,
+}
+
+#[test]
+fn unix() {
+    assert_tokens(
+        &TestUnix {
+            dt: datetime!(2024-05-01 12:51:30.25 UTC),
+        },
+        &[
+            Token::Struct {
+                name: "TestUnix",
+                len: 1,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("1714567890.25"),
+            Token::StructEnd,
+        ],
+    );
+}
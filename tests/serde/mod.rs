@@ -1,14 +1,23 @@
 use serde_test::{assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token, assert_de_tokens};
+#[cfg(feature = "serde-well-known-default")]
+use serde_test::assert_ser_tokens_error;
 use time::macros::{date, datetime, offset, time};
 use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
+mod date;
+mod delegate;
+mod duration;
 mod error_conditions;
 mod iso8601;
 mod json;
 mod macros;
+mod option_default;
 mod rfc2822;
+mod representation;
 mod rfc3339;
+mod system_time_compat;
 mod timestamps;
+mod weekday;
 
 #[test]
 fn time() {
@@ -451,12 +460,14 @@ fn offset_date_time() {
             Token::TupleEnd,
         ],
     );
+    #[cfg(not(feature = "serde-well-known-default"))]
     assert_tokens(
         &datetime!(-9999-001 0:00 UTC)
             .to_offset(offset!(+23:58:59))
             .readable(),
         &[Token::BorrowedStr("-9999-01-01 23:58:59.0 +23:58:59")],
     );
+    #[cfg(not(feature = "serde-well-known-default"))]
     assert_tokens(
         &datetime!(+9999-365 23:58:59.123_456_789 UTC)
             .to_offset(offset!(-23:58:59))
@@ -570,6 +581,98 @@ fn offset_date_time_error() {
     );
 }
 
+#[cfg(feature = "serde-well-known-default")]
+#[test]
+fn offset_date_time_well_known_default() {
+    let value = datetime!(2021-01-02 03:04:05.123_456_789 -01:30);
+    assert_tokens(
+        &value.readable(),
+        &[Token::BorrowedStr("2021-01-02T03:04:05.123456789-01:30")],
+    );
+    assert_tokens(
+        &datetime!(2021-01-02 03:04:05 UTC).readable(),
+        &[Token::BorrowedStr("2021-01-02T03:04:05Z")],
+    );
+
+    // The previous representation is accepted, so existing values can be read.
+    assert_de_tokens(
+        &value.readable(),
+        &[Token::BorrowedStr("2021-01-02 03:04:05.123456789 -01:30:00")],
+    );
+    assert_de_tokens(
+        &datetime!(-9999-001 0:00 UTC)
+            .to_offset(offset!(+23:58:59))
+            .readable(),
+        &[Token::BorrowedStr("-9999-01-01 23:58:59.0 +23:58:59")],
+    );
+
+    // Errors are reported for the representation the input was meant to be in.
+    assert_de_tokens_error::<Readable<OffsetDateTime>>(
+        &[Token::BorrowedStr("2021-01-02T03:04:05")],
+        "the 'offset hour' component could not be parsed",
+    );
+    assert_de_tokens_error::<Readable<OffsetDateTime>>(
+        &[Token::BorrowedStr("2021-01-02 03:04:60.0 +00:00:00")],
+        "second must be in the range 0..=59",
+    );
+
+    // Values that RFC 3339 cannot represent fail to serialize rather than falling back.
+    assert_ser_tokens_error(
+        &datetime!(2021-01-02 03:04:05 +01:02:03).readable(),
+        &[],
+        "failed formatting `OffsetDateTime`",
+    );
+    assert_ser_tokens_error(
+        &datetime!(-0001-01-01 0:00 UTC).readable(),
+        &[],
+        "failed formatting `OffsetDateTime`",
+    );
+
+    // Only `OffsetDateTime` is affected, and the compact representation is unchanged.
+    assert_tokens(
+        &date!(2021-01-02).readable(),
+        &[Token::BorrowedStr("2021-01-02")],
+    );
+    assert_tokens(
+        &time!(03:04:05.6).readable(),
+        &[Token::BorrowedStr("03:04:05.6")],
+    );
+    assert_tokens(
+        &datetime!(2021-01-02 03:04:05).readable(),
+        &[Token::BorrowedStr("2021-01-02 03:04:05.0")],
+    );
+    assert_tokens(
+        &datetime!(2021-01-02 03:04:05 UTC).compact(),
+        &[
+            Token::Tuple { len: 9 },
+            Token::I32(2021),
+            Token::U16(2),
+            Token::U8(3),
+            Token::U8(4),
+            Token::U8(5),
+            Token::U32(0),
+            Token::I8(0),
+            Token::I8(0),
+            Token::I8(0),
+            Token::TupleEnd,
+        ],
+    );
+}
+
+#[cfg(not(feature = "serde-well-known-default"))]
+#[test]
+fn offset_date_time_default() {
+    assert_tokens(
+        &datetime!(2021-01-02 03:04:05.123_456_789 -01:30).readable(),
+        &[Token::BorrowedStr("2021-01-02 03:04:05.123456789 -01:30:00")],
+    );
+    // RFC 3339 is only accepted with the `serde-well-known-default` feature.
+    assert_de_tokens_error::<Readable<OffsetDateTime>>(
+        &[Token::BorrowedStr("2021-01-02T03:04:05Z")],
+        "a character literal was not valid",
+    );
+}
+
 #[test]
 fn offset_date_time_partial() {
     assert_de_tokens_error::<Compact<OffsetDateTime>>(
@@ -886,6 +989,83 @@ fn duration() {
     );
 }
 
+#[test]
+fn duration_sign() {
+    for (duration, repr) in [
+        (Duration::new(0, -1), "-0.000000001"),
+        (Duration::new(0, -999_999_999), "-0.999999999"),
+        (Duration::new(-1, 0), "-1.000000000"),
+        (Duration::new(-1, -1), "-1.000000001"),
+        (Duration::new(0, 1), "0.000000001"),
+        (Duration::new(1, 1), "1.000000001"),
+    ] {
+        assert_tokens(&duration.readable(), &[Token::BorrowedStr(repr)]);
+        assert_tokens(
+            &duration.compact(),
+            &[
+                Token::Tuple { len: 2 },
+                Token::I64(duration.whole_seconds()),
+                Token::I32(duration.subsec_nanoseconds()),
+                Token::TupleEnd,
+            ],
+        );
+    }
+}
+
+#[test]
+fn readable_matches_display() -> serde_json::Result<()> {
+    let datetime = datetime!(2021-01-02 03:04:05.6 -01:02:03);
+    assert_eq!(
+        serde_json::to_string(&datetime.date())?,
+        format!("\"{}\"", datetime.date())
+    );
+    assert_eq!(
+        serde_json::to_string(&datetime.time())?,
+        format!("\"{}\"", datetime.time())
+    );
+    assert_eq!(
+        serde_json::to_string(&datetime.offset())?,
+        format!("\"{}\"", datetime.offset())
+    );
+    assert_eq!(
+        serde_json::to_string(&PrimitiveDateTime::new(datetime.date(), datetime.time()))?,
+        format!("\"{}\"", PrimitiveDateTime::new(datetime.date(), datetime.time()))
+    );
+    #[cfg(not(feature = "serde-well-known-default"))]
+    assert_eq!(
+        serde_json::to_string(&datetime)?,
+        format!("\"{datetime}\"")
+    );
+    assert_eq!(serde_json::to_string(&Time::MIDNIGHT)?, "\"00:00:00.0\"");
+    Ok(())
+}
+
+#[test]
+fn duration_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+    for duration in [
+        Duration::ZERO,
+        Duration::MIN,
+        Duration::MAX,
+        Duration::new(0, -1),
+        Duration::new(0, -500_000_000),
+        Duration::new(-5, 0),
+        Duration::new(-5, -1),
+        Duration::new(0, 1),
+    ] {
+        let json = serde_json::to_string(&duration)?;
+        assert_eq!(json.starts_with("\"-"), duration.is_negative());
+        assert_eq!(serde_json::from_str::<Duration>(&json)?, duration);
+
+        let bytes = bincode::serialize(&duration)?;
+        assert_eq!(
+            bincode::deserialize::<(i64, i32)>(&bytes)?,
+            (duration.whole_seconds(), duration.subsec_nanoseconds())
+        );
+        assert_eq!(bincode::deserialize::<Duration>(&bytes)?, duration);
+    }
+    Ok(())
+}
+
 #[test]
 fn duration_error() {
     assert_de_tokens_error::<Readable<Duration>>(
@@ -900,6 +1080,14 @@ fn duration_error() {
         &[Token::BorrowedStr("0.x")],
         r#"invalid value: string "x", expected nanoseconds"#,
     );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("-0.-1")],
+        r#"invalid value: string "-1", expected nanoseconds"#,
+    );
+    assert_de_tokens_error::<Readable<Duration>>(
+        &[Token::BorrowedStr("0.+1")],
+        r#"invalid value: string "+1", expected nanoseconds"#,
+    );
     assert_de_tokens_error::<Readable<Duration>>(
         &[Token::Bool(false)],
         "invalid type: boolean `false`, expected a `Duration`",
@@ -979,6 +1167,46 @@ fn weekday_error() {
     );
 }
 
+#[test]
+fn weekday_lenient() -> serde_json::Result<()> {
+    for token in [
+        Token::Str("Wednesday"),
+        Token::Str("wednesday"),
+        Token::Str("WED"),
+        Token::Str("wed"),
+        Token::U8(3),
+        Token::U64(3),
+        Token::I64(3),
+    ] {
+        assert_de_tokens(&Weekday::Wednesday.readable(), &[token]);
+        assert_de_tokens(&Weekday::Wednesday.compact(), &[token]);
+    }
+
+    assert_eq!(serde_json::from_str::<Weekday>("3")?, Weekday::Wednesday);
+    assert_eq!(serde_json::from_str::<Weekday>(r#""sun""#)?, Weekday::Sunday);
+    Ok(())
+}
+
+#[test]
+fn weekday_lenient_error() {
+    assert_de_tokens_error::<Readable<Weekday>>(
+        &[Token::U64(8)],
+        "invalid value: integer `8`, expected a value in the range 1..=7",
+    );
+    assert_de_tokens_error::<Readable<Weekday>>(
+        &[Token::U64(u64::MAX)],
+        "invalid value: integer `18446744073709551615`, expected a value in the range 1..=7",
+    );
+    assert_de_tokens_error::<Readable<Weekday>>(
+        &[Token::I64(-1)],
+        "invalid value: integer `-1`, expected a value in the range 1..=7",
+    );
+    assert_de_tokens_error::<Readable<Weekday>>(
+        &[Token::Str("Wedn")],
+        r#"invalid value: string "Wedn", expected a `Weekday`"#,
+    );
+}
+
 #[test]
 fn month() {
     use Month::*;
@@ -1028,3 +1256,39 @@ fn month_error() {
         "invalid type: boolean `false`, expected a `Month`",
     );
 }
+
+#[test]
+fn month_lenient() -> serde_json::Result<()> {
+    for token in [
+        Token::Str("September"),
+        Token::Str("SEPTEMBER"),
+        Token::Str("Sep"),
+        Token::Str("sep"),
+        Token::U8(9),
+        Token::U64(9),
+        Token::I64(9),
+    ] {
+        assert_de_tokens(&Month::September.readable(), &[token]);
+        assert_de_tokens(&Month::September.compact(), &[token]);
+    }
+
+    assert_eq!(serde_json::from_str::<Month>("12")?, Month::December);
+    assert_eq!(serde_json::from_str::<Month>(r#""may""#)?, Month::May);
+    Ok(())
+}
+
+#[test]
+fn month_lenient_error() {
+    assert_de_tokens_error::<Readable<Month>>(
+        &[Token::U64(13)],
+        "invalid value: integer `13`, expected a value in the range 1..=12",
+    );
+    assert_de_tokens_error::<Readable<Month>>(
+        &[Token::I64(-12)],
+        "invalid value: integer `-12`, expected a value in the range 1..=12",
+    );
+    assert_de_tokens_error::<Readable<Month>>(
+        &[Token::Str("Sept")],
+        r#"invalid value: string "Sept", expected a `Month`"#,
+    );
+}
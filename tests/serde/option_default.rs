@@ -0,0 +1,267 @@
+use serde::{Deserialize, Serialize};
+use time::macros::{date, datetime};
+use time::serde::{rfc3339, timestamp};
+use time::{Date, Duration, OffsetDateTime, PrimitiveDateTime};
+
+time::serde::format_description!(
+    custom_format,
+    OffsetDateTime,
+    "[year]-[month]-[day] [hour]:[minute]:[second] [offset_hour sign:mandatory]:[offset_minute]"
+);
+
+/// A field that may be missing, `null`, or a value. Missing fields use the default of `None`.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Defaulted {
+    #[serde(default, with = "custom_format::option")]
+    custom: Option<OffsetDateTime>,
+    #[serde(default, with = "rfc3339::option")]
+    rfc3339: Option<OffsetDateTime>,
+    #[serde(default, with = "timestamp")]
+    timestamp: Option<PrimitiveDateTime>,
+}
+
+/// A field that distinguishes being missing from being `null`.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ThreeState {
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "custom_format::option::serialize_or_default",
+        deserialize_with = "custom_format::option::deserialize_or_default"
+    )]
+    custom: Option<Option<OffsetDateTime>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "rfc3339::option::serialize_or_default",
+        deserialize_with = "rfc3339::option::deserialize_or_default"
+    )]
+    rfc3339: Option<Option<OffsetDateTime>>,
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "timestamp::serialize_or_default",
+        deserialize_with = "timestamp::deserialize_or_default"
+    )]
+    timestamp: Option<Option<PrimitiveDateTime>>,
+}
+
+/// The same as [`ThreeState`], but without skipping any fields, as is required for formats that are
+/// not self-describing.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct ThreeStateNoSkip {
+    #[serde(
+        default,
+        serialize_with = "custom_format::option::serialize_or_default",
+        deserialize_with = "custom_format::option::deserialize_or_default"
+    )]
+    custom: Option<Option<OffsetDateTime>>,
+    #[serde(
+        default,
+        serialize_with = "rfc3339::option::serialize_or_default",
+        deserialize_with = "rfc3339::option::deserialize_or_default"
+    )]
+    rfc3339: Option<Option<OffsetDateTime>>,
+    #[serde(
+        default,
+        serialize_with = "timestamp::serialize_or_default",
+        deserialize_with = "timestamp::deserialize_or_default"
+    )]
+    timestamp: Option<Option<PrimitiveDateTime>>,
+}
+
+/// A field that is omitted entirely when it is `None`, using the `is_none` re-exported by each
+/// `option` module.
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Omitted {
+    #[serde(
+        default,
+        with = "custom_format::option",
+        skip_serializing_if = "custom_format::option::is_none"
+    )]
+    custom: Option<OffsetDateTime>,
+    #[serde(
+        default,
+        with = "rfc3339::option",
+        skip_serializing_if = "rfc3339::option::is_none"
+    )]
+    rfc3339: Option<OffsetDateTime>,
+    #[serde(
+        default,
+        with = "timestamp",
+        skip_serializing_if = "timestamp::is_none"
+    )]
+    timestamp: Option<PrimitiveDateTime>,
+    #[serde(
+        default,
+        with = "time::serde::binary::date::option",
+        skip_serializing_if = "time::serde::binary::date::option::is_none"
+    )]
+    date: Option<Date>,
+    #[serde(
+        default,
+        with = "time::serde::duration::seconds::option",
+        skip_serializing_if = "time::serde::duration::seconds::option::is_none"
+    )]
+    duration: Option<Duration>,
+}
+
+const VALUE_JSON: &str = concat!(
+    r#"{"custom":"2021-01-02 03:04:05 +06:07","#,
+    r#""rfc3339":"2021-01-02T03:04:05+06:07","#,
+    r#""timestamp":1609556645}"#,
+);
+const NULL_JSON: &str = r#"{"custom":null,"rfc3339":null,"timestamp":null}"#;
+
+#[test]
+fn defaulted_json() -> serde_json::Result<()> {
+    let value = Defaulted {
+        custom: Some(datetime!(2021-01-02 03:04:05 +06:07)),
+        rfc3339: Some(datetime!(2021-01-02 03:04:05 +06:07)),
+        timestamp: Some(datetime!(2021-01-02 03:04:05)),
+    };
+    let none = Defaulted {
+        custom: None,
+        rfc3339: None,
+        timestamp: None,
+    };
+
+    assert_eq!(serde_json::to_string(&value)?, VALUE_JSON);
+    assert_eq!(serde_json::from_str::<Defaulted>(VALUE_JSON)?, value);
+    assert_eq!(serde_json::to_string(&none)?, NULL_JSON);
+    assert_eq!(serde_json::from_str::<Defaulted>(NULL_JSON)?, none);
+    assert_eq!(serde_json::from_str::<Defaulted>("{}")?, none);
+
+    Ok(())
+}
+
+#[test]
+fn three_state_json() -> serde_json::Result<()> {
+    let value = ThreeState {
+        custom: Some(Some(datetime!(2021-01-02 03:04:05 +06:07))),
+        rfc3339: Some(Some(datetime!(2021-01-02 03:04:05 +06:07))),
+        timestamp: Some(Some(datetime!(2021-01-02 03:04:05))),
+    };
+    let null = ThreeState {
+        custom: Some(None),
+        rfc3339: Some(None),
+        timestamp: Some(None),
+    };
+    let missing = ThreeState {
+        custom: None,
+        rfc3339: None,
+        timestamp: None,
+    };
+
+    assert_eq!(serde_json::to_string(&value)?, VALUE_JSON);
+    assert_eq!(serde_json::from_str::<ThreeState>(VALUE_JSON)?, value);
+    assert_eq!(serde_json::to_string(&null)?, NULL_JSON);
+    assert_eq!(serde_json::from_str::<ThreeState>(NULL_JSON)?, null);
+    assert_eq!(serde_json::to_string(&missing)?, "{}");
+    assert_eq!(serde_json::from_str::<ThreeState>("{}")?, missing);
+
+    // Without skipping, a missing value is written as `null`.
+    let missing = ThreeStateNoSkip {
+        custom: None,
+        rfc3339: None,
+        timestamp: None,
+    };
+    assert_eq!(serde_json::to_string(&missing)?, NULL_JSON);
+
+    Ok(())
+}
+
+#[test]
+fn omitted_json() -> serde_json::Result<()> {
+    let value = Omitted {
+        custom: Some(datetime!(2021-01-02 03:04:05 +06:07)),
+        rfc3339: Some(datetime!(2021-01-02 03:04:05 +06:07)),
+        timestamp: Some(datetime!(2021-01-02 03:04:05)),
+        date: Some(date!(2021-01-02)),
+        duration: Some(Duration::seconds(5)),
+    };
+    let none = Omitted {
+        custom: None,
+        rfc3339: None,
+        timestamp: None,
+        date: None,
+        duration: None,
+    };
+    let value_json = concat!(
+        r#"{"custom":"2021-01-02 03:04:05 +06:07","#,
+        r#""rfc3339":"2021-01-02T03:04:05+06:07","#,
+        r#""timestamp":1609556645,"#,
+        r#""date":[2021,2],"#,
+        r#""duration":5}"#,
+    );
+    let null_json =
+        r#"{"custom":null,"rfc3339":null,"timestamp":null,"date":null,"duration":null}"#;
+
+    assert_eq!(serde_json::to_string(&value)?, value_json);
+    assert_eq!(serde_json::from_str::<Omitted>(value_json)?, value);
+    assert_eq!(serde_json::to_string(&none)?, "{}");
+    assert_eq!(serde_json::from_str::<Omitted>("{}")?, none);
+    assert_eq!(serde_json::from_str::<Omitted>(null_json)?, none);
+
+    Ok(())
+}
+
+#[test]
+fn defaulted_bincode() -> bincode::Result<()> {
+    let value = Defaulted {
+        custom: Some(datetime!(2021-01-02 03:04:05 +06:07)),
+        rfc3339: Some(datetime!(2021-01-02 03:04:05 +06:07)),
+        timestamp: Some(datetime!(2021-01-02 03:04:05)),
+    };
+    let none = Defaulted {
+        custom: None,
+        rfc3339: None,
+        timestamp: None,
+    };
+
+    assert_eq!(
+        bincode::deserialize::<Defaulted>(&bincode::serialize(&value)?)?,
+        value
+    );
+    assert_eq!(
+        bincode::deserialize::<Defaulted>(&bincode::serialize(&none)?)?,
+        none
+    );
+
+    Ok(())
+}
+
+#[test]
+fn three_state_bincode() -> bincode::Result<()> {
+    let value = ThreeStateNoSkip {
+        custom: Some(Some(datetime!(2021-01-02 03:04:05 +06:07))),
+        rfc3339: Some(Some(datetime!(2021-01-02 03:04:05 +06:07))),
+        timestamp: Some(Some(datetime!(2021-01-02 03:04:05))),
+    };
+    let null = ThreeStateNoSkip {
+        custom: Some(None),
+        rfc3339: Some(None),
+        timestamp: Some(None),
+    };
+    let missing = ThreeStateNoSkip {
+        custom: None,
+        rfc3339: None,
+        timestamp: None,
+    };
+
+    assert_eq!(
+        bincode::deserialize::<ThreeStateNoSkip>(&bincode::serialize(&value)?)?,
+        value
+    );
+    assert_eq!(
+        bincode::deserialize::<ThreeStateNoSkip>(&bincode::serialize(&null)?)?,
+        null
+    );
+    // bincode has no notion of a missing field, so `None` is read back as `Some(None)`.
+    assert_eq!(
+        bincode::deserialize::<ThreeStateNoSkip>(&bincode::serialize(&missing)?)?,
+        null
+    );
+
+    Ok(())
+}
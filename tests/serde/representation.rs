@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use time::macros::{date, datetime, offset, time};
+use time::serde::{binary, human};
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Binary {
+    #[serde(with = "binary::date")]
+    date: Date,
+    #[serde(with = "binary::duration")]
+    duration: Duration,
+    #[serde(with = "binary::offset_date_time")]
+    offset_date_time: OffsetDateTime,
+    #[serde(with = "binary::primitive_date_time")]
+    primitive_date_time: PrimitiveDateTime,
+    #[serde(with = "binary::time")]
+    time: Time,
+    #[serde(with = "binary::utc_offset")]
+    utc_offset: UtcOffset,
+    #[serde(with = "binary::weekday")]
+    weekday: Weekday,
+    #[serde(with = "binary::month")]
+    month: Month,
+    #[serde(with = "binary::offset_date_time::option")]
+    some: Option<OffsetDateTime>,
+    #[serde(with = "binary::offset_date_time::option")]
+    none: Option<OffsetDateTime>,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct Human {
+    #[serde(with = "human::date")]
+    date: Date,
+    #[serde(with = "human::duration")]
+    duration: Duration,
+    #[serde(with = "human::offset_date_time")]
+    offset_date_time: OffsetDateTime,
+    #[serde(with = "human::primitive_date_time")]
+    primitive_date_time: PrimitiveDateTime,
+    #[serde(with = "human::time")]
+    time: Time,
+    #[serde(with = "human::utc_offset")]
+    utc_offset: UtcOffset,
+    #[serde(with = "human::weekday")]
+    weekday: Weekday,
+    #[serde(with = "human::month")]
+    month: Month,
+    #[serde(with = "human::offset_date_time::option")]
+    some: Option<OffsetDateTime>,
+    #[serde(with = "human::offset_date_time::option")]
+    none: Option<OffsetDateTime>,
+}
+
+/// RFC 3339, which is the human-readable representation with the `serde-well-known-default`
+/// feature, cannot represent an offset with seconds.
+#[cfg(not(feature = "serde-well-known-default"))]
+const OFFSET_DATE_TIME: OffsetDateTime = datetime!(2023-01-02 03:04:05.6 +01:02:03);
+#[cfg(feature = "serde-well-known-default")]
+const OFFSET_DATE_TIME: OffsetDateTime = datetime!(2023-01-02 03:04:05.6 +01:02);
+
+macro_rules! value {
+    ($ty:ident) => {
+        $ty {
+            date: date!(2023 - 01 - 02),
+            duration: Duration::new(-1, -500_000_000),
+            offset_date_time: OFFSET_DATE_TIME,
+            primitive_date_time: datetime!(2023-01-02 03:04:05.6),
+            time: time!(03:04:05.6),
+            utc_offset: offset!(-01:02:03),
+            weekday: Weekday::Monday,
+            month: Month::January,
+            some: Some(datetime!(2023-01-02 03:04:05 UTC)),
+            none: None,
+        }
+    };
+}
+
+#[test]
+fn binary_json() -> serde_json::Result<()> {
+    let json = serde_json::to_string(&value!(Binary))?;
+    #[cfg(not(feature = "serde-well-known-default"))]
+    assert_eq!(
+        json,
+        "{\"date\":[2023,2],\"duration\":[-1,-500000000],\"offset_date_time\":[2023,2,3,4,5,\
+         600000000,1,2,3],\"primitive_date_time\":[2023,2,3,4,5,600000000],\"time\":[3,4,5,\
+         600000000],\"utc_offset\":[-1,-2,-3],\"weekday\":1,\"month\":1,\"some\":[2023,2,3,4,5,0,\
+         0,0,0],\"none\":null}"
+    );
+    #[cfg(feature = "serde-well-known-default")]
+    assert_eq!(
+        json,
+        "{\"date\":[2023,2],\"duration\":[-1,-500000000],\"offset_date_time\":[2023,2,3,4,5,\
+         600000000,1,2,0],\"primitive_date_time\":[2023,2,3,4,5,600000000],\"time\":[3,4,5,\
+         600000000],\"utc_offset\":[-1,-2,-3],\"weekday\":1,\"month\":1,\"some\":[2023,2,3,4,5,0,\
+         0,0,0],\"none\":null}"
+    );
+    assert_eq!(serde_json::from_str::<Binary>(&json)?, value!(Binary));
+
+    // The human-readable representation is not accepted.
+    assert!(serde_json::from_str::<Binary>(&serde_json::to_string(&value!(Human))?).is_err());
+    Ok(())
+}
+
+#[test]
+fn binary_bincode() -> bincode::Result<()> {
+    let bytes = bincode::serialize(&value!(Binary))?;
+    assert_eq!(bincode::deserialize::<Binary>(&bytes)?, value!(Binary));
+
+    // Identical to the default representation for a non-human-readable format.
+    let default = bincode::serialize(&(
+        date!(2023 - 01 - 02),
+        Duration::new(-1, -500_000_000),
+        OFFSET_DATE_TIME,
+        datetime!(2023-01-02 03:04:05.6),
+        time!(03:04:05.6),
+        offset!(-01:02:03),
+        Weekday::Monday,
+        Month::January,
+        Some(datetime!(2023-01-02 03:04:05 UTC)),
+        None::<OffsetDateTime>,
+    ))?;
+    assert_eq!(bytes, default);
+    Ok(())
+}
+
+#[test]
+fn human_json() -> serde_json::Result<()> {
+    let json = serde_json::to_string(&value!(Human))?;
+    #[cfg(not(feature = "serde-well-known-default"))]
+    assert_eq!(
+        json,
+        "{\"date\":\"2023-01-02\",\"duration\":\"-1.500000000\",\"offset_date_time\":\"2023-01-02 \
+         03:04:05.6 +01:02:03\",\"primitive_date_time\":\"2023-01-02 03:04:05.6\",\"time\":\"03:\
+         04:05.6\",\"utc_offset\":\"-01:02:03\",\"weekday\":\"Monday\",\"month\":\"January\",\
+         \"some\":\"2023-01-02 03:04:05.0 +00:00:00\",\"none\":null}"
+    );
+    #[cfg(feature = "serde-well-known-default")]
+    assert_eq!(
+        json,
+        "{\"date\":\"2023-01-02\",\"duration\":\"-1.500000000\",\"offset_date_time\":\"2023-01-\
+         02T03:04:05.6+01:02\",\"primitive_date_time\":\"2023-01-02 03:04:05.6\",\"time\":\"03:\
+         04:05.6\",\"utc_offset\":\"-01:02:03\",\"weekday\":\"Monday\",\"month\":\"January\",\
+         \"some\":\"2023-01-02T03:04:05Z\",\"none\":null}"
+    );
+    assert_eq!(serde_json::from_str::<Human>(&json)?, value!(Human));
+
+    // Identical to the default representation for a human-readable format.
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&json)?,
+        serde_json::json!({
+            "date": date!(2023 - 01 - 02),
+            "duration": Duration::new(-1, -500_000_000),
+            "offset_date_time": OFFSET_DATE_TIME,
+            "primitive_date_time": datetime!(2023-01-02 03:04:05.6),
+            "time": time!(03:04:05.6),
+            "utc_offset": offset!(-01:02:03),
+            "weekday": Weekday::Monday,
+            "month": Month::January,
+            "some": Some(datetime!(2023-01-02 03:04:05 UTC)),
+            "none": None::<OffsetDateTime>,
+        })
+    );
+
+    // The compact representation is not accepted.
+    assert!(serde_json::from_str::<Human>(&serde_json::to_string(&value!(Binary))?).is_err());
+    Ok(())
+}
+
+#[test]
+fn human_bincode() -> bincode::Result<()> {
+    let bytes = bincode::serialize(&value!(Human))?;
+    assert_eq!(bincode::deserialize::<Human>(&bytes)?, value!(Human));
+
+    let date = bincode::serialize(&Some("2023-01-02"))?;
+    assert_eq!(
+        bincode::deserialize::<HumanDate>(&date)?,
+        HumanDate(Some(date!(2023 - 01 - 02)))
+    );
+    Ok(())
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct HumanDate(#[serde(with = "human::date::option")] Option<Date>);
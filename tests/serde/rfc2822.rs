@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
-use serde_test::{assert_tokens, Configure, Token};
+use serde_test::{assert_de_tokens_error, assert_tokens, Configure, Token};
 use time::serde::rfc2822;
-use time::OffsetDateTime;
+use time::{OffsetDateTime, PrimitiveDateTime};
 use time_macros::datetime;
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
@@ -54,3 +54,205 @@ fn parse_json() -> serde_json::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn vec() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct Headers {
+        #[serde(with = "rfc2822::vec")]
+        dates: Vec<OffsetDateTime>,
+    }
+
+    let value = Headers {
+        dates: vec![
+            datetime!(2000-01-01 00:00:00 UTC),
+            datetime!(2003-07-01 10:52:37 +02:00),
+        ],
+    };
+    let json = r#"{"dates":["Sat, 01 Jan 2000 00:00:00 +0000","Tue, 01 Jul 2003 10:52:37 +0200"]}"#;
+    assert_eq!(serde_json::to_string(&value)?, json);
+    assert_eq!(serde_json::from_str::<Headers>(json)?, value);
+    assert_eq!(
+        serde_json::from_str::<Headers>(r#"{"dates":[]}"#)?,
+        Headers { dates: vec![] }
+    );
+    assert!(serde_json::from_str::<Headers>(r#"{"dates":["Sat, 01 Jan 2000"]}"#).is_err());
+
+    Ok(())
+}
+
+/// `Date` header values as they appear in mbox archives, alongside the instant each denotes.
+const MBOX_DATES: &[(&str, OffsetDateTime)] = &[
+    (
+        "Tue, 1 Jul 2003 10:52:37 +0200",
+        datetime!(2003-07-01 10:52:37 +02:00),
+    ),
+    (
+        "Mon, 2 Jun 2003 09:23:45 GMT",
+        datetime!(2003-06-02 09:23:45 UTC),
+    ),
+    (
+        "Fri, 21 Nov 1997 09:55:06 -0600 (MDT)",
+        datetime!(1997-11-21 09:55:06 -06:00),
+    ),
+    (
+        "Thu, 13 Feb 1969 23:32 -0330 (Newfoundland Time)",
+        datetime!(1969-02-13 23:32:00 -03:30),
+    ),
+    (
+        "Wed, 17 Jan 2007 14:03:22 -0500 (EST)",
+        datetime!(2007-01-17 14:03:22 -05:00),
+    ),
+    (
+        "Sun, 06 Nov 1994 08:49:37 GMT",
+        datetime!(1994-11-06 08:49:37 UTC),
+    ),
+    ("21 Nov 97 09:55:06 GMT", datetime!(1997-11-21 09:55:06 UTC)),
+    (
+        "5 Mar 2012 17:01:44 +0100",
+        datetime!(2012-03-05 17:01:44 +01:00),
+    ),
+    (
+        "Thu, 4 Sep 2008 16:18:30 +0100 (BST)",
+        datetime!(2008-09-04 16:18:30 +01:00),
+    ),
+    (
+        "Sun, 30 Oct 2016 01:59:59 EDT",
+        datetime!(2016-10-30 01:59:59 -04:00),
+    ),
+    (
+        "Fri, 30 Dec 2021 13:00:00 +0000",
+        datetime!(2021-12-30 13:00:00 UTC),
+    ),
+    (
+        "Tue, 1 Jul (a comment) 2003 10:52:37 +0200 ",
+        datetime!(2003-07-01 10:52:37 +02:00),
+    ),
+];
+
+#[test]
+fn lenient() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct Lenient {
+        #[serde(with = "rfc2822::lenient")]
+        dt: OffsetDateTime,
+        #[serde(with = "rfc2822::lenient::option")]
+        option_dt: Option<OffsetDateTime>,
+    }
+
+    for &(input, expected) in MBOX_DATES {
+        let json = format!(r#"{{"dt": "{input}", "option_dt": "{input}"}}"#);
+        assert_eq!(
+            serde_json::from_str::<Lenient>(&json)?,
+            Lenient {
+                dt: expected,
+                option_dt: Some(expected),
+            },
+            "{input}"
+        );
+    }
+
+    assert_eq!(
+        serde_json::from_str::<Lenient>(
+            r#"{"dt": "Sat, 01 Jan 2000 00:00:00 +0000", "option_dt": null}"#
+        )?,
+        Lenient {
+            dt: datetime!(2000-01-01 00:00:00 UTC),
+            option_dt: None,
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&Lenient {
+            dt: datetime!(1997-11-21 09:55:06 -06:00),
+            option_dt: Some(datetime!(2003-06-02 09:23:45 UTC)),
+        })?,
+        r#"{"dt":"Fri, 21 Nov 1997 09:55:06 -0600","option_dt":"Mon, 02 Jun 2003 09:23:45 +0000"}"#
+    );
+    assert_de_tokens_error::<Lenient>(
+        &[
+            Token::Struct {
+                name: "Lenient",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("21 Nov 1997 09:55:06 -0600 trailing"),
+            Token::StructEnd,
+        ],
+        "the 'weekday' component could not be parsed",
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lenient_vec() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct Headers {
+        #[serde(with = "rfc2822::lenient::vec")]
+        dates: Vec<OffsetDateTime>,
+    }
+
+    let value = Headers {
+        dates: MBOX_DATES.iter().map(|&(_, expected)| expected).collect(),
+    };
+    let inputs = MBOX_DATES
+        .iter()
+        .map(|&(input, _)| input)
+        .collect::<Vec<_>>();
+    let json = serde_json::to_string(&serde_json::json!({ "dates": inputs }))?;
+    assert_eq!(serde_json::from_str::<Headers>(&json)?, value);
+
+    // Values are always written in their canonical form.
+    let canonical = serde_json::to_string(&value)?;
+    assert!(canonical.contains(r#""Thu, 13 Feb 1969 23:32:00 -0330""#));
+    assert_eq!(serde_json::from_str::<Headers>(&canonical)?, value);
+
+    assert!(serde_json::from_str::<Headers>(r#"{"dates":["21 Nov 97"]}"#).is_err());
+
+    Ok(())
+}
+
+#[test]
+fn primitive() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Test {
+        #[serde(with = "rfc2822::primitive")]
+        dt: PrimitiveDateTime,
+        #[serde(with = "rfc2822::primitive::option")]
+        option_dt: Option<PrimitiveDateTime>,
+    }
+
+    let value = Test {
+        dt: datetime!(2000-01-01 12:34:56),
+        option_dt: Some(datetime!(2023-01-02 03:04:05)),
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        r#"{"dt":"Sat, 01 Jan 2000 12:34:56 +0000","option_dt":"Mon, 02 Jan 2023 03:04:05 +0000"}"#
+    );
+    assert_eq!(serde_json::from_str::<Test>(&json)?, value);
+
+    let value = Test {
+        dt: datetime!(2000-01-01 00:00),
+        option_dt: None,
+    };
+    assert_eq!(
+        serde_json::from_str::<Test>(&serde_json::to_string(&value)?)?,
+        value
+    );
+
+    let err = serde_json::from_str::<Test>(
+        r#"{"dt":"Sat, 01 Jan 2000 12:34:56 +0100","option_dt":null}"#,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().starts_with(
+            "invalid value: a non-UTC offset, expected an RFC2822-formatted `PrimitiveDateTime` \
+             with a UTC offset"
+        ),
+        "unexpected error: {err}"
+    );
+
+    Ok(())
+}
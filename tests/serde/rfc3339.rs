@@ -3,8 +3,8 @@ use serde_test::{
     assert_de_tokens_error, assert_ser_tokens_error, assert_tokens, Configure, Token,
 };
 use time::macros::datetime;
-use time::serde::rfc3339;
-use time::OffsetDateTime;
+use time::serde::{rfc3339, rfc3339_no_z};
+use time::{OffsetDateTime, PrimitiveDateTime};
 
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
 struct Test {
@@ -139,3 +139,201 @@ fn issue_479() -> serde_json::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn no_z() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct NoZ {
+        #[serde(with = "rfc3339_no_z")]
+        dt: OffsetDateTime,
+        #[serde(with = "rfc3339_no_z::option")]
+        option_dt: Option<OffsetDateTime>,
+    }
+
+    let value = NoZ {
+        dt: datetime!(2000-01-01 00:00:00 UTC),
+        option_dt: Some(datetime!(2000-01-01 00:00:00 -01:30)),
+    };
+    assert_tokens(
+        &value.compact(),
+        &[
+            Token::Struct {
+                name: "NoZ",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("2000-01-01T00:00:00+00:00"),
+            Token::Str("option_dt"),
+            Token::Some,
+            Token::BorrowedStr("2000-01-01T00:00:00-01:30"),
+            Token::StructEnd,
+        ],
+    );
+    assert_eq!(
+        serde_json::from_str::<NoZ>(
+            r#"{"dt": "2000-01-01T00:00:00Z", "option_dt": "2000-01-01T00:00:00+00:00"}"#
+        )?,
+        NoZ {
+            dt: datetime!(2000-01-01 00:00:00 UTC),
+            option_dt: Some(datetime!(2000-01-01 00:00:00 UTC)),
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&NoZ {
+            dt: datetime!(2000-01-01 00:00:00.5 UTC),
+            option_dt: None,
+        })?,
+        r#"{"dt":"2000-01-01T00:00:00.5+00:00","option_dt":null}"#
+    );
+    assert_de_tokens_error::<NoZ>(
+        &[
+            Token::Struct {
+                name: "NoZ",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("bad"),
+            Token::StructEnd,
+        ],
+        "the 'year' component could not be parsed",
+    );
+
+    Ok(())
+}
+
+#[test]
+fn lenient() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct Lenient {
+        #[serde(with = "rfc3339::lenient")]
+        dt: OffsetDateTime,
+        #[serde(with = "rfc3339::lenient::option")]
+        option_dt: Option<OffsetDateTime>,
+    }
+
+    #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+    struct Strict {
+        #[serde(with = "rfc3339")]
+        dt: OffsetDateTime,
+        #[serde(with = "rfc3339::option")]
+        option_dt: Option<OffsetDateTime>,
+    }
+
+    for (input, expected) in [
+        ("2021-01-02T03:04:05Z", datetime!(2021-01-02 03:04:05 UTC)),
+        (
+            "+002021-01-02T03:04:05Z",
+            datetime!(2021-01-02 03:04:05 UTC),
+        ),
+        (
+            "2021-01-02T03:04:05,5Z",
+            datetime!(2021-01-02 03:04:05.5 UTC),
+        ),
+        (
+            "2021-01-02T03:04:05+0100",
+            datetime!(2021-01-02 03:04:05 +01:00),
+        ),
+        ("20210102T030405Z", datetime!(2021-01-02 03:04:05 UTC)),
+    ] {
+        let json = format!(r#"{{"dt": "{input}", "option_dt": "{input}"}}"#);
+        assert_eq!(
+            serde_json::from_str::<Lenient>(&json)?,
+            Lenient {
+                dt: expected,
+                option_dt: Some(expected),
+            }
+        );
+        if input != "2021-01-02T03:04:05Z" {
+            assert!(serde_json::from_str::<Strict>(&json).is_err());
+        }
+    }
+
+    assert_eq!(
+        serde_json::from_str::<Lenient>(r#"{"dt": "2021-01-02T03:04:05Z", "option_dt": null}"#)?,
+        Lenient {
+            dt: datetime!(2021-01-02 03:04:05 UTC),
+            option_dt: None,
+        }
+    );
+    assert_eq!(
+        serde_json::to_string(&Lenient {
+            dt: datetime!(2021-01-02 03:04:05.5 +01:00),
+            option_dt: Some(datetime!(2021-01-02 03:04:05 UTC)),
+        })?,
+        r#"{"dt":"2021-01-02T03:04:05.5+01:00","option_dt":"2021-01-02T03:04:05Z"}"#
+    );
+    assert_de_tokens_error::<Lenient>(
+        &[
+            Token::Struct {
+                name: "Lenient",
+                len: 2,
+            },
+            Token::Str("dt"),
+            Token::BorrowedStr("bad"),
+            Token::StructEnd,
+        ],
+        "the 'year' component could not be parsed",
+    );
+
+    Ok(())
+}
+
+#[test]
+fn primitive() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Test {
+        #[serde(with = "rfc3339::primitive")]
+        dt: PrimitiveDateTime,
+        #[serde(with = "rfc3339::primitive::option")]
+        option_dt: Option<PrimitiveDateTime>,
+    }
+
+    let value = Test {
+        dt: datetime!(2000-01-01 12:34:56.789),
+        option_dt: Some(datetime!(2023-01-02 03:04:05)),
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        r#"{"dt":"2000-01-01T12:34:56.789Z","option_dt":"2023-01-02T03:04:05Z"}"#
+    );
+    assert_eq!(serde_json::from_str::<Test>(&json)?, value);
+
+    let value = Test {
+        dt: datetime!(2000-01-01 00:00),
+        option_dt: None,
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(json, r#"{"dt":"2000-01-01T00:00:00Z","option_dt":null}"#);
+    assert_eq!(serde_json::from_str::<Test>(&json)?, value);
+
+    // A zero offset need not be written as `Z`.
+    assert_eq!(
+        serde_json::from_str::<Test>(
+            r#"{"dt":"2000-01-01T00:00:00+00:00","option_dt":"2000-01-01T00:00:00-00:00"}"#
+        )?,
+        Test {
+            dt: datetime!(2000-01-01 00:00),
+            option_dt: Some(datetime!(2000-01-01 00:00)),
+        }
+    );
+
+    for json in [
+        r#"{"dt":"2000-01-01T00:00:00+01:00","option_dt":null}"#,
+        r#"{"dt":"2000-01-01T00:00:00Z","option_dt":"2000-01-01T00:00:00-05:00"}"#,
+    ] {
+        let err = serde_json::from_str::<Test>(json).unwrap_err();
+        assert!(
+            err.to_string().starts_with(
+                "invalid value: a non-UTC offset, expected an RFC3339-formatted \
+                 `PrimitiveDateTime` with a UTC offset"
+            ),
+            "unexpected error: {err}"
+        );
+    }
+    assert!(
+        serde_json::from_str::<Test>(r#"{"dt":"2000-01-01T00:00:00","option_dt":null}"#).is_err()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,285 @@
+use std::time::{Duration as StdDuration, SystemTime};
+
+use serde::{Deserialize, Serialize};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, assert_tokens, Token};
+use time::macros::datetime;
+use time::serde::system_time_compat;
+use time::OffsetDateTime;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TestSystemTime {
+    #[serde(with = "system_time_compat")]
+    at: OffsetDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TestSystemTimeOption {
+    #[serde(with = "system_time_compat::option")]
+    at: Option<OffsetDateTime>,
+}
+
+/// Output of `serde_json` for `std::time::SystemTime` values, captured from serde's own
+/// implementation.
+const STD_FIXTURES: [(&str, OffsetDateTime); 4] = [
+    (
+        r#"{"secs_since_epoch":0,"nanos_since_epoch":0}"#,
+        datetime!(1970-01-01 0:00 UTC),
+    ),
+    (
+        r#"{"secs_since_epoch":1714567890,"nanos_since_epoch":123}"#,
+        datetime!(2024-05-01 12:51:30.000_000_123 UTC),
+    ),
+    (
+        r#"{"secs_since_epoch":1714567890,"nanos_since_epoch":999999999}"#,
+        datetime!(2024-05-01 12:51:30.999_999_999 UTC),
+    ),
+    (
+        r#"{"secs_since_epoch":253402300799,"nanos_since_epoch":0}"#,
+        datetime!(9999-12-31 23:59:59 UTC),
+    ),
+];
+
+fn tokens(secs: Token, nanos: u32) -> [Token; 6] {
+    [
+        Token::Struct {
+            name: "SystemTime",
+            len: 2,
+        },
+        Token::Str("secs_since_epoch"),
+        secs,
+        Token::Str("nanos_since_epoch"),
+        Token::U32(nanos),
+        Token::StructEnd,
+    ]
+}
+
+fn field_tokens(secs: Token, nanos: u32) -> Vec<Token> {
+    let mut tokens = vec![
+        Token::Struct {
+            name: "TestSystemTime",
+            len: 1,
+        },
+        Token::Str("at"),
+    ];
+    tokens.extend(self::tokens(secs, nanos));
+    tokens.push(Token::StructEnd);
+    tokens
+}
+
+#[test]
+fn serialize_system_time_compat() {
+    assert_tokens(
+        &TestSystemTime {
+            at: datetime!(2024-05-01 12:51:30.000_000_123 UTC),
+        },
+        &field_tokens(Token::U64(1_714_567_890), 123),
+    );
+    assert_tokens(
+        &TestSystemTime {
+            at: datetime!(1970-01-01 0:00 UTC),
+        },
+        &field_tokens(Token::U64(0), 0),
+    );
+    // The offset does not affect the instant.
+    assert_de_tokens(
+        &TestSystemTime {
+            at: datetime!(2024-05-01 14:51:30.000_000_123 +2),
+        },
+        &field_tokens(Token::U64(1_714_567_890), 123),
+    );
+}
+
+#[test]
+fn serialize_system_time_compat_before_epoch() {
+    assert_tokens(
+        &TestSystemTime {
+            at: datetime!(1969-12-31 23:59:59.75 UTC),
+        },
+        &field_tokens(Token::I64(-1), 750_000_000),
+    );
+    assert_tokens(
+        &TestSystemTime {
+            at: datetime!(1969-12-31 23:59:59 UTC),
+        },
+        &field_tokens(Token::I64(-1), 0),
+    );
+    assert_tokens(
+        &TestSystemTime {
+            at: datetime!(1969-12-31 0:00 UTC),
+        },
+        &field_tokens(Token::I64(-86_400), 0),
+    );
+}
+
+#[test]
+fn deserialize_system_time_compat() {
+    // A sequence is accepted, as it is by serde.
+    assert_de_tokens(
+        &TestSystemTime {
+            at: datetime!(2024-05-01 12:51:30.000_000_123 UTC),
+        },
+        &[
+            Token::Struct {
+                name: "TestSystemTime",
+                len: 1,
+            },
+            Token::Str("at"),
+            Token::Seq { len: Some(2) },
+            Token::U64(1_714_567_890),
+            Token::U32(123),
+            Token::SeqEnd,
+            Token::StructEnd,
+        ],
+    );
+    // Excess nanoseconds are carried into the seconds, as they are by serde.
+    assert_de_tokens(
+        &TestSystemTime {
+            at: datetime!(1970-01-01 0:00:02.5 UTC),
+        },
+        &field_tokens(Token::U64(1), 1_500_000_000),
+    );
+}
+
+#[test]
+fn system_time_compat_error() {
+    assert_de_tokens_error::<TestSystemTime>(
+        &[
+            Token::Struct {
+                name: "TestSystemTime",
+                len: 1,
+            },
+            Token::Str("at"),
+            Token::Struct {
+                name: "SystemTime",
+                len: 2,
+            },
+            Token::Str("secs_since_epoch"),
+            Token::U64(0),
+            Token::StructEnd,
+        ],
+        "missing field `nanos_since_epoch`",
+    );
+    assert_de_tokens_error::<TestSystemTime>(
+        &[
+            Token::Struct {
+                name: "TestSystemTime",
+                len: 1,
+            },
+            Token::Str("at"),
+            Token::Struct {
+                name: "SystemTime",
+                len: 2,
+            },
+            Token::Str("secs_since_epoch"),
+            Token::U64(0),
+            Token::Str("secs_since_epoch"),
+        ],
+        "duplicate field `secs_since_epoch`",
+    );
+    assert_de_tokens_error::<TestSystemTime>(
+        &[
+            Token::Struct {
+                name: "TestSystemTime",
+                len: 1,
+            },
+            Token::Str("at"),
+            Token::Struct {
+                name: "SystemTime",
+                len: 2,
+            },
+            Token::Str("secs"),
+        ],
+        "unknown field `secs`, expected `secs_since_epoch` or `nanos_since_epoch`",
+    );
+    assert_de_tokens_error::<TestSystemTime>(
+        &field_tokens(Token::U64(u64::MAX), 0)[..5],
+        &format!(
+            "invalid value: integer `{}`, expected an integer number of seconds",
+            u64::MAX
+        ),
+    );
+    // Beyond the largest representable value, even with `large-dates`.
+    assert!(
+        serde_json::from_str::<TestSystemTime>(
+            r#"{"at":{"secs_since_epoch":1000000000000000,"nanos_since_epoch":0}}"#
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn system_time_compat_option() {
+    assert_tokens(
+        &TestSystemTimeOption {
+            at: Some(datetime!(2024-05-01 12:51:30.000_000_123 UTC)),
+        },
+        &[
+            Token::Struct {
+                name: "TestSystemTimeOption",
+                len: 1,
+            },
+            Token::Str("at"),
+            Token::Some,
+            Token::Struct {
+                name: "SystemTime",
+                len: 2,
+            },
+            Token::Str("secs_since_epoch"),
+            Token::U64(1_714_567_890),
+            Token::Str("nanos_since_epoch"),
+            Token::U32(123),
+            Token::StructEnd,
+            Token::StructEnd,
+        ],
+    );
+    assert_tokens(
+        &TestSystemTimeOption { at: None },
+        &[
+            Token::Struct {
+                name: "TestSystemTimeOption",
+                len: 1,
+            },
+            Token::Str("at"),
+            Token::None,
+            Token::StructEnd,
+        ],
+    );
+}
+
+#[test]
+fn system_time_compat_std_fixtures() -> serde_json::Result<()> {
+    #[derive(Serialize, Deserialize)]
+    struct Std {
+        at: SystemTime,
+    }
+
+    for (fixture, expected) in STD_FIXTURES {
+        let json = format!(r#"{{"at":{fixture}}}"#);
+
+        let value: TestSystemTime = serde_json::from_str(&json)?;
+        assert_eq!(value.at, expected);
+        assert_eq!(serde_json::to_string(&value)?, json);
+
+        // The fixtures match serde's current output, and serde can read ours.
+        let std_value = SystemTime::from(expected);
+        assert_eq!(serde_json::to_string(&Std { at: std_value })?, json);
+        assert_eq!(serde_json::from_str::<Std>(&json)?.at, std_value);
+    }
+
+    // serde refuses to serialize a `SystemTime` before the epoch, so there is nothing to match.
+    let before_epoch = SystemTime::UNIX_EPOCH - StdDuration::from_millis(250);
+    assert!(serde_json::to_string(&Std { at: before_epoch }).is_err());
+    let json = serde_json::to_string(&TestSystemTime {
+        at: OffsetDateTime::from(before_epoch),
+    })?;
+    assert_eq!(
+        json,
+        r#"{"at":{"secs_since_epoch":-1,"nanos_since_epoch":750000000}}"#
+    );
+    assert_eq!(
+        serde_json::from_str::<TestSystemTime>(&json)?.at,
+        OffsetDateTime::from(before_epoch)
+    );
+
+    Ok(())
+}
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
-use serde_test::{assert_de_tokens_error, assert_de_tokens, assert_tokens, Configure, Token};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_ser_tokens, assert_tokens, Compact, Configure,
+    Readable, Token,
+};
 use time::macros::datetime;
 use time::serde::timestamp;
 use time::{OffsetDateTime,PrimitiveDateTime};
@@ -637,3 +640,183 @@ fn serialize_timestamp_primitive_vec_millis() {
         "invalid type: string \"bad\", expected i64",
     );
 }
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TestUnits {
+    #[serde(with = "timestamp::milliseconds")]
+    milliseconds: OffsetDateTime,
+    #[serde(with = "timestamp::microseconds")]
+    microseconds: OffsetDateTime,
+    #[serde(with = "timestamp::nanoseconds")]
+    nanoseconds: PrimitiveDateTime,
+}
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct TestUnitsOption {
+    #[serde(with = "timestamp::milliseconds::option")]
+    milliseconds: Option<OffsetDateTime>,
+    #[serde(with = "timestamp::microseconds::option")]
+    microseconds: Option<OffsetDateTime>,
+    #[serde(with = "timestamp::nanoseconds::option")]
+    nanoseconds: Option<OffsetDateTime>,
+}
+
+#[test]
+fn milliseconds_tokens() {
+    #[derive(Serialize, Deserialize, Debug, PartialEq)]
+    struct Millis(#[serde(with = "timestamp::milliseconds")] OffsetDateTime);
+
+    assert_ser_tokens(
+        &Millis(datetime!(2024-05-01 12:51:30.123_999 UTC)).compact(),
+        &[
+            Token::NewtypeStruct { name: "Millis" },
+            Token::I64(1_714_567_890_123),
+        ],
+    );
+    assert_tokens(
+        &Millis(datetime!(1969-12-31 23:59:59.999 UTC)).compact(),
+        &[Token::NewtypeStruct { name: "Millis" }, Token::I64(-1)],
+    );
+    assert_de_tokens(
+        &Millis(datetime!(2024-05-01 12:51:30.123_5 UTC)).readable(),
+        &[
+            Token::NewtypeStruct { name: "Millis" },
+            Token::F64(1_714_567_890_123.5),
+        ],
+    );
+    assert_de_tokens(
+        &Millis(datetime!(1970-01-01 00:00:00.000_000_002 UTC)).readable(),
+        &[Token::NewtypeStruct { name: "Millis" }, Token::F64(0.000_001_5)],
+    );
+    assert_de_tokens(
+        &Millis(datetime!(1969-12-31 23:59:59.999_999_998 UTC)).readable(),
+        &[
+            Token::NewtypeStruct { name: "Millis" },
+            Token::F64(-0.000_001_5),
+        ],
+    );
+    assert_de_tokens(
+        &Millis(datetime!(2024-05-01 12:51:30 UTC)).readable(),
+        &[
+            Token::NewtypeStruct { name: "Millis" },
+            Token::U64(1_714_567_890_000),
+        ],
+    );
+    assert_de_tokens_error::<Readable<Millis>>(
+        &[Token::NewtypeStruct { name: "Millis" }, Token::F64(f64::NAN)],
+        "invalid value: floating point `NaN`, expected a Unix timestamp in milliseconds in the \
+         range -31619087596800000..=31494784780799999",
+    );
+    assert_de_tokens_error::<Compact<Millis>>(
+        &[Token::NewtypeStruct { name: "Millis" }, Token::I64(i64::MAX)],
+        "invalid value: integer `9223372036854775807`, expected a Unix timestamp in milliseconds \
+         in the range -31619087596800000..=31494784780799999",
+    );
+    assert_de_tokens_error::<Compact<Millis>>(
+        &[Token::NewtypeStruct { name: "Millis" }, Token::Str("bad")],
+        "invalid type: string \"bad\", expected a Unix timestamp in milliseconds in the range \
+         -31619087596800000..=31494784780799999",
+    );
+}
+
+#[test]
+fn units_json() -> serde_json::Result<()> {
+    let value = TestUnits {
+        milliseconds: datetime!(2024-05-01 12:51:30.123_456_789 UTC),
+        microseconds: datetime!(2024-05-01 14:51:30.123_456_789 +02:00),
+        nanoseconds: datetime!(2024-05-01 12:51:30.123_456_789),
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"milliseconds":1714567890123,"microseconds":1714567890123456,"#,
+            r#""nanoseconds":1714567890123456789}"#
+        )
+    );
+    assert_eq!(
+        serde_json::from_str::<TestUnits>(&json)?,
+        TestUnits {
+            milliseconds: datetime!(2024-05-01 12:51:30.123 UTC),
+            microseconds: datetime!(2024-05-01 12:51:30.123_456 UTC),
+            nanoseconds: datetime!(2024-05-01 12:51:30.123_456_789),
+        }
+    );
+
+    assert_eq!(
+        serde_json::from_str::<TestUnits>(
+            r#"{"milliseconds":-1.25,"microseconds":1e6,"nanoseconds":-1.5}"#
+        )?,
+        TestUnits {
+            milliseconds: datetime!(1969-12-31 23:59:59.998_75 UTC),
+            microseconds: datetime!(1970-01-01 00:00:01 UTC),
+            nanoseconds: datetime!(1969-12-31 23:59:59.999_999_998),
+        }
+    );
+
+    let value = TestUnitsOption {
+        milliseconds: Some(datetime!(2024-05-01 12:51:30.5 UTC)),
+        microseconds: None,
+        nanoseconds: Some(OffsetDateTime::UNIX_EPOCH),
+    };
+    let json = serde_json::to_string(&value)?;
+    assert_eq!(
+        json,
+        r#"{"milliseconds":1714567890500,"microseconds":null,"nanoseconds":0}"#
+    );
+    assert_eq!(serde_json::from_str::<TestUnitsOption>(&json)?, value);
+    Ok(())
+}
+
+#[test]
+fn units_out_of_range() {
+    for json in [
+        r#"{"milliseconds":31494784780800000,"microseconds":0,"nanoseconds":0}"#,
+        r#"{"milliseconds":0,"microseconds":-1e300,"nanoseconds":0}"#,
+        r#"{"milliseconds":0,"microseconds":0,"nanoseconds":-31619087596800000000000001}"#,
+    ] {
+        let err = serde_json::from_str::<TestUnits>(json).unwrap_err();
+        assert!(
+            err.to_string().contains("expected a Unix timestamp in"),
+            "unexpected error: {err}"
+        );
+    }
+}
+
+#[test]
+fn units_bincode() -> bincode::Result<()> {
+    for datetime in [
+        PrimitiveDateTime::MIN,
+        PrimitiveDateTime::MAX,
+        datetime!(1969-12-31 23:59:59.999_999_999),
+        datetime!(2024-05-01 12:51:30.123_456_789),
+    ] {
+        let value = TestUnits {
+            milliseconds: datetime.assume_utc(),
+            microseconds: datetime.assume_utc(),
+            nanoseconds: datetime,
+        };
+        let bytes = bincode::serialize(&value)?;
+        let nanoseconds = datetime.assume_utc().unix_timestamp_nanos();
+        assert_eq!(
+            bincode::deserialize::<(i64, i128, i128)>(&bytes)?,
+            (
+                (nanoseconds / 1_000_000) as i64,
+                nanoseconds / 1_000,
+                nanoseconds
+            )
+        );
+        assert_eq!(bincode::deserialize::<TestUnits>(&bytes)?.nanoseconds, datetime);
+    }
+
+    let value = TestUnitsOption {
+        milliseconds: None,
+        microseconds: Some(datetime!(2024-05-01 12:51:30.123_456 UTC)),
+        nanoseconds: Some(PrimitiveDateTime::MAX.assume_utc()),
+    };
+    assert_eq!(
+        bincode::deserialize::<TestUnitsOption>(&bincode::serialize(&value)?)?,
+        value
+    );
+    Ok(())
+}
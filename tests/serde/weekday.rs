@@ -0,0 +1,82 @@
+use serde::{Deserialize, Serialize};
+use serde_test::{
+    assert_de_tokens, assert_de_tokens_error, assert_tokens, Configure, Readable, Token,
+};
+use time::serde::weekday::number_days_from_sunday;
+use time::Weekday;
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct FromSunday(#[serde(with = "number_days_from_sunday")] Weekday);
+
+#[derive(Serialize, Deserialize, Debug, PartialEq)]
+struct OptionFromSunday(#[serde(with = "number_days_from_sunday::option")] Option<Weekday>);
+
+#[test]
+fn from_sunday() {
+    for (weekday, number) in [
+        (Weekday::Sunday, 0),
+        (Weekday::Monday, 1),
+        (Weekday::Wednesday, 3),
+        (Weekday::Saturday, 6),
+    ] {
+        assert_tokens(
+            &FromSunday(weekday).compact(),
+            &[
+                Token::NewtypeStruct { name: "FromSunday" },
+                Token::U8(number),
+            ],
+        );
+    }
+    assert_de_tokens(
+        &FromSunday(Weekday::Sunday).readable(),
+        &[
+            Token::NewtypeStruct { name: "FromSunday" },
+            Token::Str("SUN"),
+        ],
+    );
+    assert_de_tokens(
+        &FromSunday(Weekday::Sunday).readable(),
+        &[Token::NewtypeStruct { name: "FromSunday" }, Token::I64(0)],
+    );
+}
+
+#[test]
+fn from_sunday_error() {
+    assert_de_tokens_error::<Readable<FromSunday>>(
+        &[Token::NewtypeStruct { name: "FromSunday" }, Token::U8(7)],
+        "invalid value: integer `7`, expected a value in the range 0..=6",
+    );
+    assert_de_tokens_error::<Readable<FromSunday>>(
+        &[Token::NewtypeStruct { name: "FromSunday" }, Token::I64(-1)],
+        "invalid value: integer `-1`, expected a value in the range 0..=6",
+    );
+    assert_de_tokens_error::<Readable<FromSunday>>(
+        &[
+            Token::NewtypeStruct { name: "FromSunday" },
+            Token::Str("Funday"),
+        ],
+        r#"invalid value: string "Funday", expected a `Weekday`"#,
+    );
+}
+
+#[test]
+fn from_sunday_json() -> serde_json::Result<()> {
+    assert_eq!(serde_json::to_string(&FromSunday(Weekday::Sunday))?, "0");
+    assert_eq!(
+        serde_json::from_str::<FromSunday>(r#""saturday""#)?,
+        FromSunday(Weekday::Saturday)
+    );
+    assert_eq!(
+        serde_json::to_string(&OptionFromSunday(Some(Weekday::Friday)))?,
+        "5"
+    );
+    assert_eq!(
+        serde_json::from_str::<OptionFromSunday>("null")?,
+        OptionFromSunday(None)
+    );
+    assert_eq!(
+        serde_json::from_str::<OptionFromSunday>("5")?,
+        OptionFromSunday(Some(Weekday::Friday))
+    );
+    Ok(())
+}
@@ -4,6 +4,7 @@ use time::macros::datetime;
 use time::OffsetDateTime;
 
 #[test]
+#[allow(deprecated)] // Exercises the deprecated `timestamp::option` module.
 fn success() {
     #[derive(Debug, PartialEq, Serialize, Deserialize)]
     struct Full(
@@ -195,6 +196,7 @@ fn rfc3339_error() {
 }
 
 #[test]
+#[allow(deprecated)] // Exercises the deprecated `timestamp::option` module.
 fn timestamp_error() {
     #[derive(Serialize, Deserialize)]
     #[serde(transparent)]
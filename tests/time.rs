@@ -1,6 +1,6 @@
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::time;
-use time::{Result, Time};
+use time::{Period, Result, Time};
 
 #[test]
 fn from_hms() -> Result<()> {
@@ -16,6 +16,36 @@ fn from_hms() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn from_hms_clamped() {
+    assert_eq!(
+        Time::from_hms_clamped_with_flag(1, 2, 3),
+        (time!(1:02:03), false)
+    );
+    assert_eq!(
+        Time::from_hms_clamped_with_flag(23, 59, 59),
+        (time!(23:59:59), false)
+    );
+    assert_eq!(
+        Time::from_hms_clamped_with_flag(24, 0, 0),
+        (time!(23:00), true)
+    );
+    assert_eq!(
+        Time::from_hms_clamped_with_flag(0, 60, 0),
+        (time!(0:59), true)
+    );
+    assert_eq!(
+        Time::from_hms_clamped_with_flag(0, 0, 60),
+        (time!(0:00:59), true)
+    );
+    assert_eq!(
+        Time::from_hms_clamped_with_flag(u8::MAX, u8::MAX, u8::MAX),
+        (time!(23:59:59), true)
+    );
+    assert_eq!(Time::from_hms_clamped(12, 30, 0), time!(12:30));
+    assert_eq!(Time::from_hms_clamped(12, 75, 99), time!(12:59:59));
+}
+
 #[test]
 fn from_hms_milli() -> Result<()> {
     let time = Time::from_hms_milli(1, 2, 3, 4)?;
@@ -63,6 +93,29 @@ fn from_hms_nano() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn from_hms_12() -> Result<()> {
+    assert_eq!(Time::from_hms_12(12, 0, 0, Period::Am)?, time!(0:00));
+    assert_eq!(Time::from_hms_12(12, 0, 0, Period::Pm)?, time!(12:00));
+    assert_eq!(Time::from_hms_12(1, 2, 3, Period::Am)?, time!(1:02:03));
+    assert_eq!(Time::from_hms_12(11, 59, 59, Period::Pm)?, time!(23:59:59));
+
+    for hour in 1..=12 {
+        let am = Time::from_hms_12(hour, 0, 0, Period::Am)?;
+        let pm = Time::from_hms_12(hour, 0, 0, Period::Pm)?;
+        assert_eq!(am.hour() % 12, hour % 12);
+        assert_eq!(pm.hour(), am.hour() + 12);
+        assert_eq!(am.hour_12(), (hour, Period::Am));
+        assert_eq!(pm.hour_12(), (hour, Period::Pm));
+    }
+
+    assert!(Time::from_hms_12(0, 0, 0, Period::Am).is_err());
+    assert!(Time::from_hms_12(13, 0, 0, Period::Pm).is_err());
+    assert!(Time::from_hms_12(1, 60, 0, Period::Am).is_err());
+    assert!(Time::from_hms_12(1, 0, 60, Period::Pm).is_err());
+    Ok(())
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(time!(1:02:03).as_hms(), (1, 2, 3));
@@ -86,6 +139,51 @@ fn as_hms_nano() {
     );
 }
 
+#[test]
+fn to_packed_u64() {
+    assert_eq!(Time::MIDNIGHT.to_packed_u64(), 0);
+    assert_eq!(time!(0:00:00.000_000_001).to_packed_u64(), 1);
+    assert_eq!(
+        time!(1:02:03.004_005_006).to_packed_u64(),
+        3_723_004_005_006
+    );
+    assert_eq!(time!(12:00).to_packed_u64(), 43_200_000_000_000);
+    assert_eq!(
+        time!(23:59:59.999_999_999).to_packed_u64(),
+        86_399_999_999_999
+    );
+}
+
+#[test]
+fn from_packed_u64() -> Result<()> {
+    assert_eq!(Time::from_packed_u64(0)?, Time::MIDNIGHT);
+    assert_eq!(Time::from_packed_u64(1)?, time!(0:00:00.000_000_001));
+    assert_eq!(
+        Time::from_packed_u64(3_723_004_005_006)?,
+        time!(1:02:03.004_005_006)
+    );
+    assert_eq!(Time::from_packed_u64(43_200_000_000_000)?, time!(12:00));
+    assert_eq!(
+        Time::from_packed_u64(86_399_999_999_999)?,
+        time!(23:59:59.999_999_999)
+    );
+
+    for hour in 0..24 {
+        let time = Time::from_hms_nano(hour, 59, 59, 999_999_999)?;
+        assert_eq!(Time::from_packed_u64(time.to_packed_u64())?, time);
+    }
+
+    assert_eq!(
+        Time::from_packed_u64(86_400_000_000_000)
+            .unwrap_err()
+            .name(),
+        "packed"
+    );
+    assert!(Time::from_packed_u64(u64::MAX).is_err());
+
+    Ok(())
+}
+
 #[test]
 fn hour() -> Result<()> {
     for hour in 0..24 {
@@ -95,6 +193,34 @@ fn hour() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn hour_12() -> Result<()> {
+    for hour in 0..24 {
+        let time = Time::from_hms(hour, 59, 59)?;
+        let (hour_12, period) = time.hour_12();
+        assert!((1..=12).contains(&hour_12));
+        assert_eq!(period, time.period());
+        assert_eq!(Time::from_hms_12(hour_12, 59, 59, period)?, time);
+    }
+    assert_eq!(time!(0:00).hour_12(), (12, Period::Am));
+    assert_eq!(time!(1:00).hour_12(), (1, Period::Am));
+    assert_eq!(time!(11:59).hour_12(), (11, Period::Am));
+    assert_eq!(time!(12:00).hour_12(), (12, Period::Pm));
+    assert_eq!(time!(13:00).hour_12(), (1, Period::Pm));
+    assert_eq!(time!(23:59).hour_12(), (11, Period::Pm));
+    Ok(())
+}
+
+#[test]
+fn period() -> Result<()> {
+    for hour in 0..12 {
+        assert_eq!(Time::from_hms(hour, 0, 0)?.period(), Period::Am);
+        assert_eq!(Time::from_hms(hour + 12, 0, 0)?.period(), Period::Pm);
+    }
+    assert_eq!(time!(11:59:59.999_999_999).period(), Period::Am);
+    Ok(())
+}
+
 #[test]
 fn minute() -> Result<()> {
     for minute in 0..60 {
@@ -341,6 +467,30 @@ fn ordering() {
     assert_eq!(time!(0:00), time!(0:00));
 }
 
+#[test]
+fn min_max_clamp() {
+    let (low, middle, high) = (time!(1:00), time!(12:30), time!(23:59:59.999_999_999));
+    assert_eq!(low.min(high), low);
+    assert_eq!(high.min(low), low);
+    assert_eq!(low.max(high), high);
+    assert_eq!(high.max(low), high);
+    assert_eq!(low.min(low), low);
+    assert_eq!(low.clamp(middle, high), middle);
+    assert_eq!(middle.clamp(low, high), middle);
+    assert_eq!(high.clamp(low, middle), middle);
+    assert_eq!(time!(0:00:00.1).min(time!(0:00:00.2)), time!(0:00:00.1));
+    assert_eq!(time!(0:00:01).max(time!(0:00:00.9)), time!(0:00:01));
+
+    const CLAMPED: Time = time!(0:00).clamp(time!(8:00), time!(17:00));
+    assert_eq!(CLAMPED, time!(8:00));
+}
+
+#[test]
+#[should_panic]
+fn clamp_panic() {
+    let _ = time!(12:00).clamp(time!(17:00), time!(8:00));
+}
+
 #[test]
 fn issue_481() {
     assert_eq!(time!(0:00) - time!(01:00:00.1), (-3600.1).seconds());
@@ -349,3 +499,18 @@ fn issue_481() {
         (-86_399.999_999_999).seconds()
     );
 }
+
+#[test]
+fn reference_ops() {
+    let time = time!(12:00);
+    assert_ref_binop!(time, +, 5.hours());
+    assert_ref_binop!(time, +, 5.std_hours());
+    assert_ref_binop!(time, -, 5.hours());
+    assert_ref_binop!(time, -, 5.std_hours());
+    assert_ref_binop!(time, -, time!(1:30));
+
+    assert_ref_op_assign!(time, +=, 5.hours());
+    assert_ref_op_assign!(time, +=, 5.std_hours());
+    assert_ref_op_assign!(time, -=, 5.hours());
+    assert_ref_op_assign!(time, -=, 5.std_hours());
+}
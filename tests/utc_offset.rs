@@ -1,5 +1,7 @@
-use time::macros::offset;
-use time::{OffsetDateTime, Result, UtcOffset};
+use serde_test::{assert_de_tokens, assert_de_tokens_error, Compact, Configure, Readable, Token};
+use time::format_description::well_known::Rfc3339;
+use time::macros::{format_description, offset};
+use time::{error, OffsetDateTime, Result, UtcOffset};
 
 #[test]
 fn utc_is_zero() {
@@ -23,6 +25,69 @@ fn from_hms() -> Result<()> {
     Ok(())
 }
 
+#[test]
+fn min_max() {
+    assert_eq!(UtcOffset::MIN, offset!(-23:59:59));
+    assert_eq!(UtcOffset::MAX, offset!(+23:59:59));
+    assert_eq!(UtcOffset::MIN, -UtcOffset::MAX);
+    assert_eq!(UtcOffset::from_whole_seconds(-86_399), Ok(UtcOffset::MIN));
+    assert_eq!(UtcOffset::from_whole_seconds(86_399), Ok(UtcOffset::MAX));
+    assert!(UtcOffset::MIN.is_negative());
+    assert!(UtcOffset::MAX.is_positive());
+}
+
+#[test]
+fn from_hms_strict() {
+    assert_eq!(UtcOffset::from_hms_strict(0, 0, 0), Ok(offset!(UTC)));
+    assert_eq!(UtcOffset::from_hms_strict(0, 0, 1), Ok(offset!(+0:00:01)));
+    assert_eq!(UtcOffset::from_hms_strict(0, 0, -1), Ok(offset!(-0:00:01)));
+    assert_eq!(UtcOffset::from_hms_strict(0, -30, 0), Ok(offset!(-0:30)));
+    assert_eq!(UtcOffset::from_hms_strict(-5, -30, 0), Ok(offset!(-5:30)));
+    assert_eq!(
+        UtcOffset::from_hms_strict(-5, 0, -30),
+        Ok(offset!(-5:00:30))
+    );
+    assert_eq!(UtcOffset::from_hms_strict(5, 30, 0), Ok(offset!(+5:30)));
+    assert_eq!(UtcOffset::from_hms_strict(23, 59, 59), Ok(UtcOffset::MAX));
+    assert_eq!(
+        UtcOffset::from_hms_strict(-23, -59, -59),
+        Ok(UtcOffset::MIN)
+    );
+}
+
+#[test]
+fn from_hms_strict_sign_mismatch() {
+    let component = |hours, minutes, seconds| {
+        let err = UtcOffset::from_hms_strict(hours, minutes, seconds).unwrap_err();
+        assert!(err.is_conditional());
+        err.name()
+    };
+    assert_eq!(component(-5, 30, 0), "minutes");
+    assert_eq!(component(5, -30, 0), "minutes");
+    assert_eq!(component(-5, 30, 30), "minutes");
+    assert_eq!(component(-5, 0, 30), "seconds");
+    assert_eq!(component(5, 0, -30), "seconds");
+    assert_eq!(component(-5, -30, 30), "seconds");
+    assert_eq!(component(5, 30, -30), "seconds");
+    assert_eq!(component(0, -30, 30), "seconds");
+    assert_eq!(component(0, 30, -30), "seconds");
+}
+
+#[test]
+fn from_hms_strict_out_of_range() {
+    let component = |hours, minutes, seconds| {
+        let err = UtcOffset::from_hms_strict(hours, minutes, seconds).unwrap_err();
+        assert!(!err.is_conditional());
+        err.name()
+    };
+    assert_eq!(component(24, 0, 0), "hours");
+    assert_eq!(component(-24, 0, 0), "hours");
+    assert_eq!(component(0, 60, 0), "minutes");
+    assert_eq!(component(0, -60, 0), "minutes");
+    assert_eq!(component(0, 0, 60), "seconds");
+    assert_eq!(component(0, 0, -60), "seconds");
+}
+
 #[test]
 fn from_whole_seconds() {
     assert_eq!(UtcOffset::from_whole_seconds(0), Ok(offset!(UTC)));
@@ -40,6 +105,90 @@ fn from_whole_seconds() {
         UtcOffset::from_whole_seconds(-86_399),
         Ok(offset!(-23:59:59))
     );
+    assert_eq!(
+        UtcOffset::from_whole_seconds(86_400).unwrap_err().name(),
+        "seconds"
+    );
+    assert_eq!(
+        UtcOffset::from_whole_seconds(-86_400).unwrap_err().name(),
+        "seconds"
+    );
+    assert_eq!(
+        UtcOffset::from_whole_seconds(-86_401).unwrap_err().name(),
+        "seconds"
+    );
+}
+
+#[test]
+fn range_is_consistent() -> Result<()> {
+    let format =
+        format_description!("[offset_hour sign:mandatory]:[offset_minute]:[offset_second]");
+
+    // The bound itself is accepted by every entry point.
+    for (offset, hms, seconds, text) in [
+        (UtcOffset::MAX, (23, 59, 59), 86_399, "+23:59:59"),
+        (UtcOffset::MIN, (-23, -59, -59), -86_399, "-23:59:59"),
+    ] {
+        let (hours, minutes, secs) = hms;
+        assert_eq!(offset.as_hms(), hms);
+        assert_eq!(offset.whole_seconds(), seconds);
+        assert_eq!(UtcOffset::from_hms(hours, minutes, secs), Ok(offset));
+        assert_eq!(UtcOffset::from_hms_strict(hours, minutes, secs), Ok(offset));
+        assert_eq!(UtcOffset::from_whole_seconds(seconds), Ok(offset));
+        assert_eq!(UtcOffset::parse(text, format), Ok(offset));
+        assert_eq!(offset.format(format)?, text);
+        assert_de_tokens(
+            &offset.compact(),
+            &[
+                Token::Tuple { len: 3 },
+                Token::I8(hours),
+                Token::I8(minutes),
+                Token::I8(secs),
+                Token::TupleEnd,
+            ],
+        );
+        assert_de_tokens(&offset.readable(), &[Token::BorrowedStr(text)]);
+    }
+
+    // One second beyond the bound is rejected by every entry point, always naming the hours.
+    for (hours, seconds, text) in [(24, 86_400, "+24:00:00"), (-24, -86_400, "-24:00:00")] {
+        for err in [
+            UtcOffset::from_hms(hours, 0, 0).unwrap_err(),
+            UtcOffset::from_hms_strict(hours, 0, 0).unwrap_err(),
+        ] {
+            assert_eq!(err.name(), "hours");
+            assert!(!err.is_conditional());
+        }
+        let err = UtcOffset::from_whole_seconds(seconds).unwrap_err();
+        assert_eq!(err.name(), "seconds");
+        assert!(!err.is_conditional());
+        assert!(matches!(
+            UtcOffset::parse(text, format),
+            Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(err)))
+                if err.name() == "offset hour"
+        ));
+        assert!(matches!(
+            OffsetDateTime::parse(&format!("2000-01-01T00:00:00{}", &text[..6]), &Rfc3339),
+            Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(err)))
+                if err.name() == "offset hour"
+        ));
+        assert_de_tokens_error::<Compact<UtcOffset>>(
+            &[
+                Token::Tuple { len: 3 },
+                Token::I8(hours),
+                Token::I8(0),
+                Token::I8(0),
+                Token::TupleEnd,
+            ],
+            &format!("invalid value: integer `{hours}`, expected a value in the range -23..=23"),
+        );
+        assert_de_tokens_error::<Readable<UtcOffset>>(
+            &[Token::BorrowedStr(text)],
+            "offset hour must be in the range -23..=23",
+        );
+    }
+
+    Ok(())
 }
 
 #[test]
@@ -194,3 +343,76 @@ fn local_offset_error_when_multithreaded() {
     .join()
     .expect("failed to join thread");
 }
+
+#[test]
+fn reference_ops() {
+    assert_eq!(-&offset!(+1:02:03), offset!(-1:02:03));
+}
+
+#[test]
+fn from_str() {
+    for offset in [
+        offset!(UTC),
+        offset!(+1),
+        offset!(-1:30),
+        offset!(+0:00:01),
+        offset!(-0:17:30),
+        offset!(+23:59:59),
+        offset!(-23:59:59),
+    ] {
+        assert_eq!(offset.to_string().parse(), Ok(offset));
+        assert_eq!(format!("{offset:.0}").parse(), Ok(offset));
+        assert_eq!(format!("{offset:#}").parse(), Ok(offset));
+    }
+
+    assert_eq!("-00:30".parse(), Ok(offset!(-0:30)));
+    assert_eq!("+023000".parse(), Ok(offset!(+2:30)));
+    assert_eq!("+02:00:00".parse(), Ok(offset!(+2)));
+
+    assert!(matches!(
+        "02:00".parse::<UtcOffset>(),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("offset hour")
+        ))
+    ));
+    assert!(matches!(
+        "+2:00".parse::<UtcOffset>(),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("offset hour")
+        ))
+    ));
+    assert!(matches!(
+        "+02:0".parse::<UtcOffset>(),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("offset minute")
+        ))
+    ));
+    assert!(matches!(
+        "+02:0030".parse::<UtcOffset>(),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidLiteral { .. }
+        ))
+    ));
+    assert!(matches!(
+        "+0200:30".parse::<UtcOffset>(),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("offset second")
+        ))
+    ));
+    assert!(matches!(
+        "+02:00:30Z".parse::<UtcOffset>(),
+        Err(error::Parse::UnexpectedTrailingCharacters { .. })
+    ));
+    assert!(matches!(
+        "+02:60".parse::<UtcOffset>(),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(err)
+        )) if err.name() == "offset minute"
+    ));
+    assert!(matches!(
+        "+24:00".parse::<UtcOffset>(),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(err)
+        )) if err.name() == "offset hour"
+    ));
+}
@@ -1,3 +1,4 @@
+use time::macros::date;
 use time::{util, Month};
 
 #[test]
@@ -92,3 +93,21 @@ fn local_offset_soundness() {
     unsafe { set_soundness(Soundness::Sound) };
     assert_eq!(get_soundness(), Soundness::Sound);
 }
+
+#[test]
+fn days_of_month() -> time::Result<()> {
+    let days = util::days_of_month(2020, Month::February)?;
+    assert_eq!(days.len(), 29);
+    assert_eq!(days.last(), Some(date!(2020 - 02 - 29)));
+
+    let days = util::days_of_month(2019, Month::February)?;
+    assert_eq!(days.len(), 28);
+    assert_eq!(days.last(), Some(date!(2019 - 02 - 28)));
+
+    let mut days = util::days_of_month(2019, Month::December)?;
+    assert_eq!(days.next(), Some(date!(2019 - 12 - 01)));
+    assert_eq!(days.len(), 30);
+
+    assert!(util::days_of_month(1_000_000, Month::January).is_err());
+    Ok(())
+}
@@ -90,6 +90,145 @@ fn number_days_from_sunday() {
     assert_eq!(Saturday.number_days_from_sunday(), 6);
 }
 
+#[test]
+fn from_number() {
+    for weekday in [
+        Monday, Tuesday, Wednesday, Thursday, Friday, Saturday, Sunday,
+    ] {
+        assert_eq!(
+            Weekday::from_number_from_monday(weekday.number_from_monday()),
+            Ok(weekday)
+        );
+        assert_eq!(
+            Weekday::from_number_from_sunday(weekday.number_from_sunday()),
+            Ok(weekday)
+        );
+        assert_eq!(
+            Weekday::from_number_days_from_monday(weekday.number_days_from_monday()),
+            Ok(weekday)
+        );
+        assert_eq!(
+            Weekday::from_number_days_from_sunday(weekday.number_days_from_sunday()),
+            Ok(weekday)
+        );
+    }
+
+    assert_eq!(Weekday::from_number_from_monday(1), Ok(Monday));
+    assert_eq!(Weekday::from_number_from_sunday(1), Ok(Sunday));
+    assert_eq!(Weekday::from_number_days_from_monday(0), Ok(Monday));
+    assert_eq!(Weekday::from_number_days_from_sunday(0), Ok(Sunday));
+
+    for number in [0, 8, u8::MAX] {
+        assert!(Weekday::from_number_from_monday(number).is_err());
+        assert!(Weekday::from_number_from_sunday(number).is_err());
+    }
+    for number in [7, u8::MAX] {
+        assert!(Weekday::from_number_days_from_monday(number).is_err());
+        assert!(Weekday::from_number_days_from_sunday(number).is_err());
+    }
+    assert_eq!(
+        Weekday::from_number_days_from_sunday(7)
+            .unwrap_err()
+            .to_string(),
+        "weekday must be in the range 0..=6"
+    );
+}
+
+#[test]
+fn conventions() {
+    // (weekday, one-based from Monday, one-based from Sunday, zero-based from Monday, zero-based
+    // from Sunday)
+    let table = [
+        (Monday, 1, 2, 0, 1),
+        (Tuesday, 2, 3, 1, 2),
+        (Wednesday, 3, 4, 2, 3),
+        (Thursday, 4, 5, 3, 4),
+        (Friday, 5, 6, 4, 5),
+        (Saturday, 6, 7, 5, 6),
+        (Sunday, 7, 1, 6, 0),
+    ];
+
+    for (weekday, monday_one, sunday_one, monday_zero, sunday_zero) in table {
+        assert_eq!(weekday.number_from_monday(), monday_one);
+        assert_eq!(weekday.number_from_sunday(), sunday_one);
+        assert_eq!(weekday.number_days_from_monday(), monday_zero);
+        assert_eq!(weekday.number_days_from_sunday(), sunday_zero);
+        assert_eq!(u8::from(weekday), monday_one);
+
+        assert_eq!(Weekday::from_number_from_monday(monday_one), Ok(weekday));
+        assert_eq!(Weekday::from_number_from_sunday(sunday_one), Ok(weekday));
+        assert_eq!(
+            Weekday::from_number_days_from_monday(monday_zero),
+            Ok(weekday)
+        );
+        assert_eq!(
+            Weekday::from_number_days_from_sunday(sunday_zero),
+            Ok(weekday)
+        );
+        assert_eq!(Weekday::try_from(monday_one), Ok(weekday));
+    }
+
+    // Every value that is accepted round trips, and every value outside the range is rejected.
+    for number in 0..=u8::MAX {
+        let one_based = (1..=7).contains(&number).then_some(number);
+        let zero_based = (number < 7).then_some(number);
+        assert_eq!(
+            Weekday::from_number_from_monday(number)
+                .ok()
+                .map(Weekday::number_from_monday),
+            one_based
+        );
+        assert_eq!(
+            Weekday::from_number_from_sunday(number)
+                .ok()
+                .map(Weekday::number_from_sunday),
+            one_based
+        );
+        assert_eq!(
+            Weekday::from_number_days_from_monday(number)
+                .ok()
+                .map(Weekday::number_days_from_monday),
+            zero_based
+        );
+        assert_eq!(
+            Weekday::from_number_days_from_sunday(number)
+                .ok()
+                .map(Weekday::number_days_from_sunday),
+            zero_based
+        );
+        assert_eq!(Weekday::try_from(number).ok().map(u8::from), one_based);
+    }
+
+    // All conversions are usable in const contexts.
+    const SUNDAY: u8 = Sunday.number_days_from_sunday();
+    const SATURDAY: Result<Weekday, time::error::ComponentRange> =
+        Weekday::from_number_from_sunday(7);
+    assert_eq!(SUNDAY, 0);
+    assert_eq!(SATURDAY, Ok(Saturday));
+}
+
+#[test]
+fn name() {
+    assert_eq!(Monday.name(), "Monday");
+    assert_eq!(Tuesday.name(), "Tuesday");
+    assert_eq!(Wednesday.name(), "Wednesday");
+    assert_eq!(Thursday.name(), "Thursday");
+    assert_eq!(Friday.name(), "Friday");
+    assert_eq!(Saturday.name(), "Saturday");
+    assert_eq!(Sunday.name(), "Sunday");
+}
+
+#[test]
+fn short_name() {
+    assert_eq!(Monday.short_name(), "Mon");
+    assert_eq!(Tuesday.short_name(), "Tue");
+    assert_eq!(Wednesday.short_name(), "Wed");
+    assert_eq!(Thursday.short_name(), "Thu");
+    assert_eq!(Friday.short_name(), "Fri");
+    assert_eq!(Saturday.short_name(), "Sat");
+    assert_eq!(Sunday.short_name(), "Sun");
+}
+
 #[test]
 fn display() {
     assert_eq!(Monday.to_string(), "Monday");
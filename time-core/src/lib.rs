@@ -11,7 +11,6 @@
     clippy::obfuscated_if_else,
     clippy::std_instead_of_core,
     clippy::undocumented_unsafe_blocks,
-    illegal_floating_point_literal_pattern,
     late_bound_lifetime_arguments,
     path_statements,
     patterns_in_fns_without_body,
@@ -0,0 +1,167 @@
+use std::iter::Peekable;
+
+use proc_macro::{token_stream, Span, TokenTree};
+use time_core::convert::*;
+
+use crate::helpers::consume_punct;
+use crate::to_tokens::ToTokenTree;
+use crate::Error;
+
+/// The units that may be used, from largest to smallest, along with the number of nanoseconds in
+/// each.
+const UNITS: [(&str, u64); 8] = [
+    ("w", Nanosecond.per(Week)),
+    ("d", Nanosecond.per(Day)),
+    ("h", Nanosecond.per(Hour)),
+    ("m", Nanosecond.per(Minute)),
+    ("s", Nanosecond.per(Second) as _),
+    ("ms", Nanosecond.per(Millisecond) as _),
+    ("us", Nanosecond.per(Microsecond) as _),
+    ("ns", 1),
+];
+
+/// The message used when a unit is missing or not recognized.
+const EXPECTED_UNIT: &str = "expected one of `w`, `d`, `h`, `m`, `s`, `ms`, `us`, or `ns`";
+
+pub(crate) struct Duration {
+    pub(crate) seconds: i64,
+    pub(crate) nanoseconds: i32,
+}
+
+pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Duration, Error> {
+    /// Consume a value and its unit, which may be either a suffix of the literal (`30m`) or a
+    /// separate identifier (`30 m`). Returns the index of the unit in [`UNITS`] and the value in
+    /// nanoseconds. The literal is parsed exactly rather than as a float, so that a value that
+    /// cannot be represented in whole nanoseconds is rejected rather than silently rounded.
+    fn consume_component(
+        chars: &mut Peekable<token_stream::IntoIter>,
+    ) -> Result<(Span, usize, u128), Error> {
+        let (span, literal) = match chars.next() {
+            Some(TokenTree::Literal(literal)) => (literal.span(), literal.to_string()),
+            Some(tree) => return Err(Error::UnexpectedToken { tree }),
+            None => return Err(Error::UnexpectedEndOfInput),
+        };
+
+        let number_len = literal
+            .find(|c: char| !c.is_ascii_digit() && c != '_' && c != '.')
+            .unwrap_or(literal.len());
+        let (number, suffix) = literal.split_at(number_len);
+
+        let (unit_span, unit) = if suffix.is_empty() {
+            match chars.next() {
+                Some(TokenTree::Ident(ident)) => (ident.span(), ident.to_string()),
+                Some(tree) => {
+                    return Err(Error::Custom {
+                        message: EXPECTED_UNIT.into(),
+                        span_start: Some(tree.span()),
+                        span_end: Some(tree.span()),
+                    });
+                }
+                None => {
+                    return Err(Error::MissingComponent {
+                        name: "unit",
+                        span_start: Some(span),
+                        span_end: Some(span),
+                    });
+                }
+            }
+        } else {
+            (span, suffix.to_string())
+        };
+        let Some(unit_idx) = UNITS.iter().position(|&(name, _)| name == unit) else {
+            return Err(Error::Custom {
+                message: EXPECTED_UNIT.into(),
+                span_start: Some(unit_span),
+                span_end: Some(unit_span),
+            });
+        };
+        let unit_nanos = UNITS[unit_idx].1 as u128;
+
+        let invalid_value = || Error::InvalidComponent {
+            name: "value",
+            value: number.to_string(),
+            span_start: Some(span),
+            span_end: Some(span),
+        };
+
+        let number = number.replace('_', "");
+        let (whole, fraction) = number.split_once('.').unwrap_or((&number, ""));
+        if whole.is_empty() || fraction.contains('.') || fraction.len() > 20 {
+            return Err(invalid_value());
+        }
+        let whole = whole.parse::<u128>().map_err(|_| invalid_value())?;
+        let fraction_nanos = if fraction.is_empty() {
+            0
+        } else {
+            let scale = 10_u128.pow(fraction.len() as _);
+            let scaled = fraction.parse::<u128>().map_err(|_| invalid_value())? * unit_nanos;
+            if scaled % scale != 0 {
+                return Err(Error::Custom {
+                    message: "value cannot be represented exactly in nanoseconds".into(),
+                    span_start: Some(span),
+                    span_end: Some(unit_span),
+                });
+            }
+            scaled / scale
+        };
+
+        whole
+            .checked_mul(unit_nanos)
+            .and_then(|nanos| nanos.checked_add(fraction_nanos))
+            .map(|nanos| (unit_span, unit_idx, nanos))
+            .ok_or_else(invalid_value)
+    }
+
+    let start_span = chars.peek().map_or_else(Span::mixed_site, TokenTree::span);
+    let is_negative = consume_punct('-', chars).is_ok();
+
+    let mut total_nanos = 0_u128;
+    let mut previous_unit_idx = None;
+    let mut end_span = start_span;
+    while previous_unit_idx.is_none() || chars.peek().is_some() {
+        let (unit_span, unit_idx, nanos) = consume_component(chars)?;
+        if previous_unit_idx.map_or(false, |previous| unit_idx <= previous) {
+            return Err(Error::Custom {
+                message: "units must be in descending order and not repeated".into(),
+                span_start: Some(unit_span),
+                span_end: Some(unit_span),
+            });
+        }
+        previous_unit_idx = Some(unit_idx);
+        end_span = unit_span;
+        total_nanos = total_nanos.saturating_add(nanos);
+    }
+
+    let nanos_per_second = Nanosecond.per(Second) as u128;
+    let seconds = i128::try_from(total_nanos / nanos_per_second).unwrap_or(i128::MAX);
+    let nanoseconds = (total_nanos % nanos_per_second) as i32;
+    let (seconds, nanoseconds) = if is_negative {
+        (-seconds, -nanoseconds)
+    } else {
+        (seconds, nanoseconds)
+    };
+
+    match i64::try_from(seconds) {
+        Ok(seconds) => Ok(Duration {
+            seconds,
+            nanoseconds,
+        }),
+        Err(_) => Err(Error::Custom {
+            message: "duration is out of range".into(),
+            span_start: Some(start_span),
+            span_end: Some(end_span),
+        }),
+    }
+}
+
+impl ToTokenTree for Duration {
+    fn into_token_tree(self) -> TokenTree {
+        quote_group! {{
+            const DURATION: ::time::Duration = ::time::Duration::new(
+                #(self.seconds),
+                #(self.nanoseconds),
+            );
+            DURATION
+        }}
+    }
+}
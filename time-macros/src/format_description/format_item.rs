@@ -211,6 +211,7 @@ component_definition! {
     pub(super) enum Component {
         Day = "day" {
             padding = "padding": Option<Padding> => padding,
+            repr = "repr": Option<DayRepr> => repr,
         },
         Hour = "hour" {
             padding = "padding": Option<Padding> => padding,
@@ -228,12 +229,19 @@ component_definition! {
             repr = "repr": Option<MonthRepr> => repr,
             case_sensitive = "case_sensitive": Option<MonthCaseSensitive> => case_sensitive,
         },
+        Offset = "offset" {
+            sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+            z_when_utc = "z_when_utc": Option<OffsetHourZWhenUtc> => z_when_utc,
+            separator = "separator": Option<OffsetSeparator> => separator,
+        },
         OffsetHour = "offset_hour" {
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
             padding = "padding": Option<Padding> => padding,
+            z_when_utc = "z_when_utc": Option<OffsetHourZWhenUtc> => z_when_utc,
         },
         OffsetMinute = "offset_minute" {
             padding = "padding": Option<Padding> => padding,
+            omit_when_zero = "omit_when_zero": Option<OffsetMinuteOmitWhenZero> => omit_when_zero,
         },
         OffsetSecond = "offset_second" {
             padding = "padding": Option<Padding> => padding,
@@ -254,6 +262,7 @@ component_definition! {
         UnixTimestamp = "unix_timestamp" {
             precision = "precision": Option<UnixTimestampPrecision> => precision,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+            decimal = "decimal": Option<UnixTimestampDecimal> => decimal,
         },
         Weekday = "weekday" {
             repr = "repr": Option<WeekdayRepr> => repr,
@@ -327,6 +336,12 @@ macro_rules! modifier {
 }
 
 modifier! {
+    enum DayRepr {
+        #[default]
+        Numerical = b"numerical",
+        Ordinal = b"ordinal",
+    }
+
     enum HourBase(bool) {
         Twelve(true) = b"12",
         #[default]
@@ -346,6 +361,24 @@ modifier! {
         Short = b"short",
     }
 
+    enum OffsetHourZWhenUtc(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
+    enum OffsetMinuteOmitWhenZero(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
+    enum OffsetSeparator {
+        #[default]
+        Colon = b"colon",
+        None = b"none",
+    }
+
     enum Padding {
         Space = b"space",
         #[default]
@@ -385,6 +418,12 @@ modifier! {
         OneOrMore = b"1+",
     }
 
+    enum UnixTimestampDecimal(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum UnixTimestampPrecision {
         #[default]
         Second = b"second",
@@ -44,6 +44,7 @@ declare_component! {
     OffsetHour
     OffsetMinute
     OffsetSecond
+    Offset
     Ignore
     UnixTimestamp
 }
@@ -68,9 +68,17 @@ macro_rules! to_tokens {
     }
 }
 
+to_tokens! {
+    pub(crate) enum DayRepr {
+        Numerical,
+        Ordinal,
+    }
+}
+
 to_tokens! {
     pub(crate) struct Day {
         pub(crate) padding: Padding,
+        pub(crate) repr: DayRepr,
     }
 }
 
@@ -195,12 +203,14 @@ to_tokens! {
     pub(crate) struct OffsetHour {
         pub(crate) sign_is_mandatory: bool,
         pub(crate) padding: Padding,
+        pub(crate) z_when_utc: bool,
     }
 }
 
 to_tokens! {
     pub(crate) struct OffsetMinute {
         pub(crate) padding: Padding,
+        pub(crate) omit_when_zero: bool,
     }
 }
 
@@ -210,6 +220,21 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) enum OffsetSeparator {
+        Colon,
+        None,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Offset {
+        pub(crate) sign_is_mandatory: bool,
+        pub(crate) z_when_utc: bool,
+        pub(crate) separator: OffsetSeparator,
+    }
+}
+
 to_tokens! {
     pub(crate) enum Padding {
         Space,
@@ -243,5 +268,6 @@ to_tokens! {
     pub(crate) struct UnixTimestamp {
         pub(crate) precision: UnixTimestampPrecision,
         pub(crate) sign_is_mandatory: bool,
+        pub(crate) decimal: bool,
     }
 }
@@ -1,7 +1,6 @@
 #![deny(
     anonymous_parameters,
     clippy::all,
-    illegal_floating_point_literal_pattern,
     late_bound_lifetime_arguments,
     path_statements,
     patterns_in_fns_without_body,
@@ -33,6 +32,7 @@
     clippy::option_if_let_else, // suggests terrible code
 )]
 
+#[cfg_attr(not(any(feature = "formatting", feature = "parsing")), allow(unused_macros))]
 macro_rules! bug {
     () => { compile_error!("provide an error message to help fix a possible bug") };
     ($descr:literal $($rest:tt)?) => {
@@ -45,6 +45,7 @@ mod quote;
 
 mod date;
 mod datetime;
+mod duration;
 mod error;
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 mod format_description;
@@ -82,7 +83,7 @@ macro_rules! impl_macros {
     )*};
 }
 
-impl_macros![date datetime offset time];
+impl_macros![date datetime duration offset time];
 
 #[cfg(any(feature = "formatting", feature = "parsing"))]
 enum FormatDescriptionVersion {
@@ -221,14 +222,26 @@ pub fn serde_format_description(input: TokenStream) -> TokenStream {
             Some(tree) => Err(Error::UnexpectedToken { tree }),
             None => Err(Error::UnexpectedEndOfInput),
         }?;
+        let is_duration = formattable.to_string() == "Duration";
 
         // Another comma
         helpers::consume_punct(',', &mut tokens)?;
 
-        // We now have two options. The user can either provide a format description as a string or
-        // they can provide a path to a format description. If the latter, all remaining tokens are
-        // assumed to be part of the path.
+        // We now have three options. The user can either provide a format description as a
+        // string, a path to a function that lazily provides a format description, or a path to a
+        // format description. If one of the latter two, all remaining tokens are assumed to be part
+        // of the path.
         let (format, format_description_display) = match tokens.peek() {
+            // A format string cannot describe a `Duration`, which requires an `Iso8601Duration`.
+            Some(TokenTree::Literal(literal)) if is_duration => {
+                return Err(Error::Custom {
+                    message: "`Duration` requires a path to an `Iso8601Duration`, not a format \
+                              string"
+                        .into(),
+                    span_start: Some(literal.span()),
+                    span_end: Some(literal.span()),
+                });
+            }
             // string literal
             Some(TokenTree::Literal(_)) => {
                 let (span, format_string) = helpers::get_string_literal(tokens)?;
@@ -240,26 +253,41 @@ pub fn serde_format_description(input: TokenStream) -> TokenStream {
                     ITEMS
                 };
 
-                (items, String::from_utf8_lossy(&format_string).into_owned())
+                (
+                    serde_format_description::Format::Const(items),
+                    String::from_utf8_lossy(&format_string).into_owned(),
+                )
+            }
+            // `lazy` followed by a path
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "lazy" => {
+                let lazy = tokens.next();
+                match tokens.peek() {
+                    // The path begins with `lazy::`, so it was not the keyword.
+                    Some(TokenTree::Punct(punct)) if punct.as_char() == ':' => {
+                        let tokens = lazy.into_iter().chain(tokens).collect::<TokenStream>();
+                        let tokens_string = tokens.to_string();
+                        (
+                            serde_format_description::Format::Const(path_hack(tokens)),
+                            tokens_string,
+                        )
+                    }
+                    Some(_) => {
+                        let tokens = tokens.collect::<TokenStream>();
+                        let tokens_string = tokens.to_string();
+                        (
+                            serde_format_description::Format::Lazy(path_hack(tokens)),
+                            tokens_string,
+                        )
+                    }
+                    None => return Err(Error::UnexpectedEndOfInput),
+                }
             }
             // path
             Some(_) => {
                 let tokens = tokens.collect::<TokenStream>();
                 let tokens_string = tokens.to_string();
                 (
-                    quote! {{
-                        // We can't just do `super::path` because the path could be an absolute
-                        // path. In that case, we'd be generating `super::::path`, which is invalid.
-                        // Even if we took that into account, it's not possible to know if it's an
-                        // external crate, which would just require emitting `path` directly. By
-                        // taking this approach, we can leave it to the compiler to do the actual
-                        // resolution.
-                        mod __path_hack {
-                            pub(super) use super::super::*;
-                            pub(super) use #S(tokens) as FORMAT;
-                        }
-                        __path_hack::FORMAT
-                    }},
+                    serde_format_description::Format::Const(path_hack(tokens)),
                     tokens_string,
                 )
             }
@@ -275,3 +303,20 @@ pub fn serde_format_description(input: TokenStream) -> TokenStream {
     })()
     .unwrap_or_else(|err: Error| err.to_compile_error_standalone())
 }
+
+/// Resolve a path provided to `serde_format_description!` from inside the generated module.
+#[cfg(all(feature = "serde", any(feature = "formatting", feature = "parsing")))]
+fn path_hack(path: TokenStream) -> TokenStream {
+    quote! {{
+        // We can't just do `super::path` because the path could be an absolute path. In that case,
+        // we'd be generating `super::::path`, which is invalid. Even if we took that into account,
+        // it's not possible to know if it's an external crate, which would just require emitting
+        // `path` directly. By taking this approach, we can leave it to the compiler to do the
+        // actual resolution.
+        mod __path_hack {
+            pub(super) use super::super::*;
+            pub(super) use #S(path) as FORMAT;
+        }
+        __path_hack::FORMAT
+    }}
+}
@@ -63,6 +63,7 @@ macro_rules! quote_inner {
     ($ts:ident >> $($tail:tt)*) => { sym!($ts '>' '>'); quote_inner!($ts $($tail)*); };
     ($ts:ident > $($tail:tt)*) => { sym!($ts '>'); quote_inner!($ts $($tail)*); };
     ($ts:ident -> $($tail:tt)*) => { sym!($ts '-' '>'); quote_inner!($ts $($tail)*); };
+    ($ts:ident => $($tail:tt)*) => { sym!($ts '=' '>'); quote_inner!($ts $($tail)*); };
     ($ts:ident ? $($tail:tt)*) => { sym!($ts '?'); quote_inner!($ts $($tail)*); };
     ($ts:ident ! $($tail:tt)*) => { sym!($ts '!'); quote_inner!($ts $($tail)*); };
     ($ts:ident | $($tail:tt)*) => { sym!($ts '|'); quote_inner!($ts $($tail)*); };
@@ -70,6 +71,13 @@ macro_rules! quote_inner {
     ($ts:ident + $($tail:tt)*) => { sym!($ts '+'); quote_inner!($ts $($tail)*); };
 
     // Identifier
+    ($ts:ident _ $($tail:tt)*) => {
+        $ts.extend([::proc_macro::TokenTree::from(::proc_macro::Ident::new(
+            "_",
+            ::proc_macro::Span::mixed_site(),
+        ))]);
+        quote_inner!($ts $($tail)*);
+    };
     ($ts:ident $i:ident $($tail:tt)*) => {
         $ts.extend([::proc_macro::TokenTree::from(::proc_macro::Ident::new(
             &stringify!($i),
@@ -1,13 +1,83 @@
 use proc_macro::{Ident, TokenStream, TokenTree};
 
+/// The format description provided to the macro.
+pub(crate) enum Format {
+    /// An expression evaluating to a format description, usable in a `const` context.
+    Const(TokenStream),
+    /// An expression evaluating to a function that returns a `Result` containing a `'static`
+    /// reference to a format description. The function is called every time a value is serialized
+    /// or deserialized.
+    Lazy(TokenStream),
+}
+
 pub(crate) fn build(
     mod_name: Ident,
     ty: TokenTree,
-    format: TokenStream,
+    format: Format,
     format_description_display: String,
 ) -> TokenStream {
     let ty_s = &*ty.to_string();
 
+    let fd_traits = match (cfg!(feature = "formatting"), cfg!(feature = "parsing")) {
+        (false, false) => {
+            bug!("serde_format_description::build called without formatting or parsing enabled")
+        }
+        (false, true) => quote! { ::time::parsing::Parsable },
+        (true, false) => quote! { ::time::formatting::Formattable },
+        (true, true) => quote! { ::time::formatting::Formattable + ::time::parsing::Parsable },
+    };
+
+    // `Duration` does not implement `Formattable` or `Parsable`, and is instead formatted and
+    // parsed using the concrete `Iso8601Duration`. The type of the format description, along with
+    // the generics of the implementations using it, differ accordingly.
+    let is_duration = ty_s == "Duration";
+    let (description_ty, description_ty_unsized, impl_generics, format_ty, where_clause) =
+        if is_duration {
+            let iso8601_duration =
+                quote! { ::time::format_description::well_known::Iso8601Duration };
+            (
+                iso8601_duration.clone(),
+                iso8601_duration.clone(),
+                quote!(),
+                iso8601_duration,
+                quote!(),
+            )
+        } else {
+            (
+                quote! { impl #S(fd_traits.clone()) },
+                quote! { (impl #S(fd_traits) + ?Sized) },
+                quote! { <F> },
+                quote! { F },
+                quote! { where F: ::time::formatting::Formattable + ?Sized, },
+            )
+        };
+
+    // The function providing the format description, along with the expressions obtaining it when
+    // serializing and deserializing.
+    let (description, description_ser, description_de) = match format {
+        Format::Const(format) => (
+            quote! {
+                const fn description() -> #S(description_ty) {
+                    #S(format)
+                }
+            },
+            quote! { &description() },
+            quote! { &description() },
+        ),
+        Format::Lazy(format) => (
+            quote! {
+                fn description() -> Result<
+                    &'static #S(description_ty_unsized),
+                    impl ::core::fmt::Display,
+                > {
+                    (#S(format))()
+                }
+            },
+            quote! { description().map_err(<S::Error as ::serde::ser::Error>::custom)? },
+            quote! { description().map_err(E::custom)? },
+        ),
+    };
+
     let visitor = if cfg!(feature = "parsing") {
         quote! {
             struct Visitor;
@@ -32,7 +102,7 @@ pub(crate) fn build(
                     self,
                     value: &str
                 ) -> Result<__TimeSerdeType, E> {
-                    __TimeSerdeType::parse(value, &description()).map_err(E::custom)
+                    __TimeSerdeType::parse(value, #S(description_de.clone())).map_err(E::custom)
                 }
             }
 
@@ -56,7 +126,7 @@ pub(crate) fn build(
                     deserializer: D
                 ) -> Result<Option<__TimeSerdeType>, D::Error> {
                     deserializer
-                        .deserialize_any(Visitor)
+                        .deserialize_str(Visitor)
                         .map(Some)
                 }
 
@@ -71,6 +141,222 @@ pub(crate) fn build(
         quote!()
     };
 
+    // An element of a sequence, along with its index for use in error messages.
+    let element = if cfg!(feature = "parsing") {
+        quote! {
+            struct Element {
+                index: usize,
+            }
+
+            impl<'a> ::serde::de::DeserializeSeed<'a> for Element {
+                type Value = __TimeSerdeType;
+
+                fn deserialize<D: ::serde::de::Deserializer<'a>>(
+                    self,
+                    deserializer: D
+                ) -> Result<__TimeSerdeType, D::Error> {
+                    deserializer.deserialize_str(self)
+                }
+            }
+
+            impl<'a> ::serde::de::Visitor<'a> for Element {
+                type Value = __TimeSerdeType;
+
+                fn expecting(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    write!(
+                        f,
+                        concat!(
+                            "a(n) `",
+                            #(ty_s),
+                            "` in the format \"{}\" at index {}",
+                        ),
+                        #(format_description_display.as_str()),
+                        self.index
+                    )
+                }
+
+                fn visit_str<E: ::serde::de::Error>(
+                    self,
+                    value: &str
+                ) -> Result<__TimeSerdeType, E> {
+                    __TimeSerdeType::parse(value, #S(description_de)).map_err(|err| {
+                        E::custom(format_args!("invalid value at index {}: {}", self.index, err))
+                    })
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let seq_visitor = if cfg!(feature = "parsing") {
+        quote! {
+            struct SeqVisitor;
+
+            impl<'a> ::serde::de::Visitor<'a> for SeqVisitor {
+                type Value = ::std::vec::Vec<__TimeSerdeType>;
+
+                fn expecting(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    write!(
+                        f,
+                        concat!(
+                            "a sequence of `",
+                            #(ty_s),
+                            "` in the format \"{}\"",
+                        ),
+                        #(format_description_display.as_str())
+                    )
+                }
+
+                fn visit_seq<A: ::serde::de::SeqAccess<'a>>(
+                    self,
+                    mut seq: A
+                ) -> Result<::std::vec::Vec<__TimeSerdeType>, A::Error> {
+                    let mut values = ::std::vec::Vec::new();
+                    while let Some(value) = seq.next_element_seed(Element {
+                        index: values.len(),
+                    })? {
+                        values.push(value);
+                    }
+                    Ok(values)
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let option_seq_visitor = if cfg!(feature = "parsing") {
+        quote! {
+            struct OptionSeqVisitor;
+
+            impl<'a> ::serde::de::Visitor<'a> for OptionSeqVisitor {
+                type Value = Option<::std::vec::Vec<__TimeSerdeType>>;
+
+                fn expecting(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    write!(
+                        f,
+                        concat!(
+                            "an optional sequence of `",
+                            #(ty_s),
+                            "` in the format \"{}\"",
+                        ),
+                        #(format_description_display.as_str())
+                    )
+                }
+
+                fn visit_some<D: ::serde::de::Deserializer<'a>>(
+                    self,
+                    deserializer: D
+                ) -> Result<Option<::std::vec::Vec<__TimeSerdeType>>, D::Error> {
+                    deserializer
+                        .deserialize_seq(SeqVisitor)
+                        .map(Some)
+                }
+
+                fn visit_none<E: ::serde::de::Error>(
+                    self
+                ) -> Result<Option<::std::vec::Vec<__TimeSerdeType>>, E> {
+                    Ok(None)
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    // Values are serialized via `collect_str`, writing the formatted output directly to the
+    // serializer without an intermediate `String`. As `Display` cannot return a meaningful error,
+    // any error other than one from the serializer itself is stored and returned afterwards.
+    let formatted = if cfg!(feature = "formatting") {
+        let display = quote! {
+            struct Display<'a, F: ?Sized> {
+                value: &'a __TimeSerdeType,
+                description: &'a F,
+                error: &'a ::core::cell::Cell<Option<::time::error::Format>>,
+            }
+
+            impl #S(impl_generics.clone()) ::core::fmt::Display
+                for Display<'_, #S(format_ty.clone())>
+            #S(where_clause.clone())
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self.value.format_into_fmt(f, self.description) {
+                        Ok(_) => Ok(()),
+                        Err(::time::error::Format::Fmt(err)) => Err(err),
+                        Err(err) => {
+                            self.error.set(Some(err));
+                            Ok(())
+                        }
+                    }
+                }
+            }
+        };
+
+        quote! {
+            #S(display)
+
+            struct Formatted<'a, F: ?Sized> {
+                value: &'a __TimeSerdeType,
+                description: &'a F,
+            }
+
+            impl #S(impl_generics.clone()) ::serde::Serialize
+                for Formatted<'_, #S(format_ty.clone())>
+            #S(where_clause.clone())
+            {
+                fn serialize<S: ::serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    let error = ::core::cell::Cell::new(None);
+                    let result = serializer.collect_str(&Display {
+                        value: self.value,
+                        description: self.description,
+                        error: &error,
+                    });
+                    match error.take() {
+                        Some(err) => Err(err.into_invalid_serde_value::<S>()),
+                        None => result,
+                    }
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let formatted_seq = if cfg!(feature = "formatting") {
+        quote! {
+            struct FormattedSeq<'a, F: ?Sized> {
+                values: &'a [__TimeSerdeType],
+                description: &'a F,
+            }
+
+            impl #S(impl_generics) ::serde::Serialize
+                for FormattedSeq<'_, #S(format_ty)>
+            #S(where_clause)
+            {
+                fn serialize<S: ::serde::Serializer>(
+                    &self,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    use ::serde::ser::SerializeSeq;
+                    let mut seq = serializer.serialize_seq(Some(self.values.len()))?;
+                    for value in self.values {
+                        seq.serialize_element(&Formatted {
+                            value,
+                            description: self.description,
+                        })?;
+                    }
+                    seq.end()
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
     let serialize_primary = if cfg!(feature = "formatting") {
         quote! {
             pub fn serialize<S: ::serde::Serializer>(
@@ -78,10 +364,11 @@ pub(crate) fn build(
                 serializer: S,
             ) -> Result<S::Ok, S::Error> {
                 use ::serde::Serialize;
-                datetime
-                    .format(&description())
-                    .map_err(::time::error::Format::into_invalid_serde_value::<S>)?
-                    .serialize(serializer)
+                Formatted {
+                    value: datetime,
+                    description: #S(description_ser.clone()),
+                }
+                .serialize(serializer)
             }
         }
     } else {
@@ -94,7 +381,7 @@ pub(crate) fn build(
                 deserializer: D
             ) -> Result<__TimeSerdeType, D::Error> {
                 use ::serde::Deserialize;
-                deserializer.deserialize_any(Visitor)
+                deserializer.deserialize_str(Visitor)
             }
         }
     } else {
@@ -107,11 +394,20 @@ pub(crate) fn build(
                 option: &Option<__TimeSerdeType>,
                 serializer: S,
             ) -> Result<S::Ok, S::Error> {
-                use ::serde::Serialize;
-                option.map(|datetime| datetime.format(&description()))
-                    .transpose()
-                    .map_err(::time::error::Format::into_invalid_serde_value::<S>)?
-                    .serialize(serializer)
+                match option {
+                    Some(datetime) => serializer.serialize_some(&Formatted {
+                        value: datetime,
+                        description: #S(description_ser.clone()),
+                    }),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            pub fn serialize_or_default<S: ::serde::Serializer>(
+                option: &Option<Option<__TimeSerdeType>>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                serialize(&option.flatten(), serializer)
             }
         }
     } else {
@@ -126,47 +422,163 @@ pub(crate) fn build(
                 use ::serde::Deserialize;
                 deserializer.deserialize_option(OptionVisitor)
             }
+
+            pub fn deserialize_or_default<'a, D: ::serde::Deserializer<'a>>(
+                deserializer: D
+            ) -> Result<Option<Option<__TimeSerdeType>>, D::Error> {
+                deserialize(deserializer).map(Some)
+            }
         }
     } else {
         quote!()
     };
 
-    let deserialize_option_imports = if cfg!(feature = "parsing") {
+    let serialize_vec = if cfg!(feature = "formatting") {
         quote! {
-            use super::{OptionVisitor, Visitor};
+            pub fn serialize<S: ::serde::Serializer>(
+                values: &[__TimeSerdeType],
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                use ::serde::Serialize;
+                FormattedSeq {
+                    values,
+                    description: #S(description_ser.clone()),
+                }
+                .serialize(serializer)
+            }
         }
     } else {
         quote!()
     };
 
-    let fd_traits = match (cfg!(feature = "formatting"), cfg!(feature = "parsing")) {
-        (false, false) => {
-            bug!("serde_format_description::build called without formatting or parsing enabled")
+    let deserialize_vec = if cfg!(feature = "parsing") {
+        quote! {
+            pub fn deserialize<'a, D: ::serde::Deserializer<'a>>(
+                deserializer: D
+            ) -> Result<::std::vec::Vec<__TimeSerdeType>, D::Error> {
+                deserializer.deserialize_seq(SeqVisitor)
+            }
         }
-        (false, true) => quote! { ::time::parsing::Parsable },
-        (true, false) => quote! { ::time::formatting::Formattable },
-        (true, true) => quote! { ::time::formatting::Formattable + ::time::parsing::Parsable },
+    } else {
+        quote!()
+    };
+
+    let serialize_option_vec = if cfg!(feature = "formatting") {
+        quote! {
+            pub fn serialize<S: ::serde::Serializer>(
+                option: &Option<::std::vec::Vec<__TimeSerdeType>>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                match option {
+                    Some(values) => serializer.serialize_some(&FormattedSeq {
+                        values,
+                        description: #S(description_ser),
+                    }),
+                    None => serializer.serialize_none(),
+                }
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let deserialize_option_vec = if cfg!(feature = "parsing") {
+        quote! {
+            pub fn deserialize<'a, D: ::serde::Deserializer<'a>>(
+                deserializer: D
+            ) -> Result<Option<::std::vec::Vec<__TimeSerdeType>>, D::Error> {
+                deserializer.deserialize_option(OptionSeqVisitor)
+            }
+        }
+    } else {
+        quote!()
+    };
+
+    let serialize_vec_imports = if cfg!(feature = "formatting") {
+        quote! {
+            use super::{description, FormattedSeq};
+        }
+    } else {
+        quote!()
+    };
+
+    let deserialize_vec_imports = if cfg!(feature = "parsing") {
+        quote! {
+            use super::SeqVisitor;
+        }
+    } else {
+        quote!()
+    };
+
+    let deserialize_option_vec_imports = if cfg!(feature = "parsing") {
+        quote! {
+            use super::OptionSeqVisitor;
+        }
+    } else {
+        quote!()
+    };
+
+    let serialize_option_imports = if cfg!(feature = "formatting") {
+        quote! {
+            use super::{description, Formatted};
+        }
+    } else {
+        quote!()
+    };
+
+    let deserialize_option_imports = if cfg!(feature = "parsing") {
+        quote! {
+            use super::{OptionVisitor, Visitor};
+        }
+    } else {
+        quote!()
     };
 
     quote! {
         mod #(mod_name) {
             use ::time::#(ty) as __TimeSerdeType;
 
-            const fn description() -> impl #S(fd_traits) {
-                #S(format)
-            }
+            #S(description)
 
+            #S(formatted)
+            #S(formatted_seq)
             #S(visitor)
+            #S(element)
+            #S(seq_visitor)
+            #S(option_seq_visitor)
             #S(serialize_primary)
             #S(deserialize_primary)
 
             pub(super) mod option {
-                use super::{description, __TimeSerdeType};
+                pub use ::time::serde::is_none;
+
+                use super::__TimeSerdeType;
+                #S(serialize_option_imports)
                 #S(deserialize_option_imports)
 
                 #S(serialize_option)
                 #S(deserialize_option)
             }
+
+            pub(super) mod vec {
+                use super::__TimeSerdeType;
+                #S(serialize_vec_imports.clone())
+                #S(deserialize_vec_imports)
+
+                #S(serialize_vec)
+                #S(deserialize_vec)
+            }
+
+            pub(super) mod option_vec {
+                pub use ::time::serde::is_none;
+
+                use super::__TimeSerdeType;
+                #S(serialize_vec_imports)
+                #S(deserialize_option_vec_imports)
+
+                #S(serialize_option_vec)
+                #S(deserialize_option_vec)
+            }
         }
     }
 }
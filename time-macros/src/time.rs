@@ -31,28 +31,58 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Time
         }
     }
 
+    /// Consume the second, which may have up to nine fractional digits. The literal is parsed
+    /// exactly rather than as a float, so that no digits are silently rounded away. The range is
+    /// checked here so that the error can include the fractional part.
+    fn consume_second(chars: &mut Peekable<token_stream::IntoIter>) -> Result<(u8, u32), Error> {
+        let (span, digits) = match chars.next() {
+            Some(TokenTree::Literal(literal)) => (literal.span(), literal.to_string()),
+            Some(tree) => return Err(Error::UnexpectedToken { tree }),
+            None => return Err(Error::UnexpectedEndOfInput),
+        };
+
+        let value = digits.replace('_', "");
+        let (whole, fraction) = value.split_once('.').unwrap_or((&value, ""));
+        let second = whole.parse::<u8>().ok();
+        let nanosecond = if fraction.len() <= 9 && fraction.bytes().all(|b| b.is_ascii_digit()) {
+            format!("{fraction:0<9}").parse::<u32>().ok()
+        } else {
+            None
+        };
+
+        match (second, nanosecond) {
+            (Some(second), Some(nanosecond)) if second < Second.per(Minute) => {
+                Ok((second, nanosecond))
+            }
+            _ => Err(Error::InvalidComponent {
+                name: "second",
+                value: digits,
+                span_start: Some(span),
+                span_end: Some(span),
+            }),
+        }
+    }
+
     let (hour_span, hour) = consume_number("hour", chars)?;
 
-    let ((minute_span, minute), (second_span, second), (period_span, period)) =
+    let ((minute_span, minute), (second, nanosecond), (period_span, period)) =
         match consume_period(chars) {
             // Nothing but the 12-hour clock hour and AM/PM
-            (period_span @ Some(_), period) => (
-                (Span::mixed_site(), 0),
-                (Span::mixed_site(), 0.),
-                (period_span, period),
-            ),
+            (period_span @ Some(_), period) => {
+                ((Span::mixed_site(), 0), (0, 0), (period_span, period))
+            }
             (None, _) => {
                 consume_punct(':', chars)?;
                 let (minute_span, minute) = consume_number::<u8>("minute", chars)?;
-                let (second_span, second): (_, f64) = if consume_punct(':', chars).is_ok() {
-                    consume_number("second", chars)?
+                let (second, nanosecond) = if consume_punct(':', chars).is_ok() {
+                    consume_second(chars)?
                 } else {
-                    (Span::mixed_site(), 0.)
+                    (0, 0)
                 };
                 let (period_span, period) = consume_period(chars);
                 (
                     (minute_span, minute),
-                    (second_span, second),
+                    (second, nanosecond),
                     (period_span, period),
                 )
             }
@@ -87,19 +117,12 @@ pub(crate) fn parse(chars: &mut Peekable<token_stream::IntoIter>) -> Result<Time
             span_start: Some(minute_span),
             span_end: Some(minute_span),
         })
-    } else if second >= Second.per(Minute) as _ {
-        Err(Error::InvalidComponent {
-            name: "second",
-            value: second.to_string(),
-            span_start: Some(second_span),
-            span_end: Some(second_span),
-        })
     } else {
         Ok(Time {
             hour,
             minute,
-            second: second.trunc() as _,
-            nanosecond: (second.fract() * Nanosecond.per(Second) as f64).round() as _,
+            second,
+            nanosecond,
         })
     }
 }
@@ -1,14 +1,23 @@
 //! The [`Date`] struct and its associated `impl`s.
 
+use core::cmp::Ordering;
 use core::fmt;
 use core::ops::{Add, Sub};
+#[cfg(feature = "parsing")]
+use core::str::FromStr;
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(all(feature = "formatting-core", feature = "std"))]
 use std::io;
 
 use crate::convert::*;
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use crate::format_description::well_known::iso8601::{Config, EncodedConfig, FormattedComponents};
+#[cfg(any(all(feature = "formatting-core", feature = "alloc"), feature = "parsing"))]
+use crate::format_description::well_known::Iso8601;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{ArrayBuf, Discard, FmtWriter, Formattable, Formatted, SliceWriter};
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::util::{days_in_year, days_in_year_month, is_leap_year, weeks_in_year};
@@ -27,6 +36,12 @@ pub(crate) const MAX_YEAR: i32 = if cfg!(feature = "large-dates") {
     9999
 };
 
+/// The ISO 8601 configuration used by [`Date::format_iso8601`].
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+const ISO8601_DATE: EncodedConfig = Config::DEFAULT
+    .set_formatted_components(FormattedComponents::Date)
+    .encode();
+
 /// Date in the proleptic Gregorian calendar.
 ///
 /// By default, years between ±9999 inclusive are representable. This can be expanded to ±999,999
@@ -92,7 +107,10 @@ impl Date {
         ];
 
         ensure_value_in_range!(year in MIN_YEAR => MAX_YEAR);
-        ensure_value_in_range!(day conditionally in 1 => days_in_year_month(year, month));
+        ensure_value_in_range!(
+            day in 1 => days_in_year_month(year, month),
+            given error::ConditionalRange::DaysInMonth { year, month }
+        );
 
         Ok(Self::__from_ordinal_date_unchecked(
             year,
@@ -101,6 +119,79 @@ impl Date {
         ))
     }
 
+    /// Create a `Date` from the year, month, and day, clamping any out-of-range value to the
+    /// nearest valid one. The year is clamped to the supported range, after which the day is
+    /// clamped to the number of days in the month.
+    ///
+    /// Unlike [`Date::from_calendar_date`], this never fails. This is intended for handling user
+    /// input, where a day may be left unchanged while the month is changed.
+    ///
+    /// ```rust
+    /// # use time::{Date, Month};
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     Date::from_calendar_date_clamped(2024, Month::February, 31),
+    ///     date!(2024 - 02 - 29)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_calendar_date_clamped(2023, Month::February, 31),
+    ///     date!(2023 - 02 - 28)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_calendar_date_clamped(2024, Month::March, 0),
+    ///     date!(2024 - 03 - 01)
+    /// );
+    /// assert_eq!(Date::from_calendar_date_clamped(i32::MAX, Month::December, 31), Date::MAX);
+    /// ```
+    pub const fn from_calendar_date_clamped(year: i32, month: Month, day: u8) -> Self {
+        Self::from_calendar_date_clamped_with_flag(year, month, day).0
+    }
+
+    /// Create a `Date` from the year, month, and day, clamping any out-of-range value to the
+    /// nearest valid one. The returned boolean is `true` if any value was clamped.
+    ///
+    /// The clamping behavior is the same as [`Date::from_calendar_date_clamped`].
+    ///
+    /// ```rust
+    /// # use time::{Date, Month};
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     Date::from_calendar_date_clamped_with_flag(2024, Month::February, 31),
+    ///     (date!(2024 - 02 - 29), true)
+    /// );
+    /// assert_eq!(
+    ///     Date::from_calendar_date_clamped_with_flag(2024, Month::February, 29),
+    ///     (date!(2024 - 02 - 29), false)
+    /// );
+    /// ```
+    pub const fn from_calendar_date_clamped_with_flag(
+        year: i32,
+        month: Month,
+        day: u8,
+    ) -> (Self, bool) {
+        let clamped_year = if year < MIN_YEAR {
+            MIN_YEAR
+        } else if year > MAX_YEAR {
+            MAX_YEAR
+        } else {
+            year
+        };
+        let days_in_month = days_in_year_month(clamped_year, month);
+        let clamped_day = if day < 1 {
+            1
+        } else if day > days_in_month {
+            days_in_month
+        } else {
+            day
+        };
+
+        let date = match Self::from_calendar_date(clamped_year, month, clamped_day) {
+            Ok(date) => date,
+            Err(_) => bug!("clamped values are always in range"),
+        };
+        (date, clamped_year != year || clamped_day != day)
+    }
+
     /// Attempt to create a `Date` from the year and ordinal day number.
     ///
     /// ```rust
@@ -115,7 +206,10 @@ impl Date {
     /// ```
     pub const fn from_ordinal_date(year: i32, ordinal: u16) -> Result<Self, error::ComponentRange> {
         ensure_value_in_range!(year in MIN_YEAR => MAX_YEAR);
-        ensure_value_in_range!(ordinal conditionally in 1 => days_in_year(year));
+        ensure_value_in_range!(
+            ordinal in 1 => days_in_year(year),
+            given error::ConditionalRange::DaysInYear(year)
+        );
         Ok(Self::__from_ordinal_date_unchecked(year, ordinal))
     }
 
@@ -138,7 +232,10 @@ impl Date {
         weekday: Weekday,
     ) -> Result<Self, error::ComponentRange> {
         ensure_value_in_range!(year in MIN_YEAR => MAX_YEAR);
-        ensure_value_in_range!(week conditionally in 1 => weeks_in_year(year));
+        ensure_value_in_range!(
+            week in 1 => weeks_in_year(year),
+            given error::ConditionalRange::WeeksInYear(year)
+        );
 
         let adj_year = year - 1;
         let raw = 365 * adj_year + div_floor!(adj_year, 4) - div_floor!(adj_year, 100)
@@ -179,7 +276,6 @@ impl Date {
     /// assert_eq!(Date::from_julian_day(2_458_485), Ok(date!(2019 - 01 - 01)));
     /// assert_eq!(Date::from_julian_day(2_458_849), Ok(date!(2019 - 12 - 31)));
     /// ```
-    #[doc(alias = "from_julian_date")]
     pub const fn from_julian_day(julian_day: i32) -> Result<Self, error::ComponentRange> {
         ensure_value_in_range!(
             julian_day in Self::MIN.to_julian_day() => Self::MAX.to_julian_day()
@@ -225,6 +321,52 @@ impl Date {
 
         Self::__from_ordinal_date_unchecked(year, ordinal)
     }
+
+    /// Create a `Date` from the year, month, and day in the Julian calendar.
+    ///
+    /// All other methods use the proleptic Gregorian calendar. The Julian calendar has a leap year
+    /// every four years without exception, so it drifts by three days every 400 years; a Julian
+    /// date is 13 days behind the Gregorian date between 1900-03-01 and 2100-02-28. This is
+    /// unrelated to the Julian day used by [`Date::from_julian_day`].
+    ///
+    /// ```rust
+    /// # use time::{Date, Month};
+    /// # use time_macros::date;
+    /// // The first day of the Gregorian calendar followed 1582-10-04 in the Julian calendar.
+    /// assert_eq!(
+    ///     Date::from_julian_calendar(1582, Month::October, 5),
+    ///     Ok(date!(1582 - 10 - 15))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_julian_calendar(1900, Month::February, 29), // 1900 is a Julian leap year.
+    ///     Ok(date!(1900 - 03 - 13))
+    /// );
+    /// assert!(Date::from_julian_calendar(1901, Month::February, 29).is_err());
+    /// ```
+    pub const fn from_julian_calendar(
+        year: i32,
+        month: Month,
+        day: u8,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(year in MIN_YEAR => MAX_YEAR);
+        ensure_value_in_range!(
+            day in 1 => match month {
+                Month::February if year % 4 == 0 => 29,
+                _ => days_in_year_month(year, month),
+            },
+            given error::ConditionalRange::DaysInMonth { year, month }
+        );
+
+        // Count years from March, placing the leap day at the end of the year.
+        let (year, month) = match month {
+            Month::January | Month::February => (year - 1, month as i32 + 9),
+            _ => (year, month as i32 - 3),
+        };
+
+        Self::from_julian_day(
+            day as i32 + (153 * month + 2) / 5 + 365 * year + div_floor!(year, 4) + 1_721_117,
+        )
+    }
     // endregion constructors
 
     // region: getters
@@ -266,9 +408,16 @@ impl Date {
     }
 
     /// Get the month and day. This is more efficient than fetching the components individually.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019 - 01 - 01).month_day(), (Month::January, 1));
+    /// assert_eq!(date!(2020 - 12 - 31).month_day(), (Month::December, 31));
+    /// ```
     // For whatever reason, rustc has difficulty optimizing this function. It's significantly faster
     // to write the statements out by hand.
-    pub(crate) const fn month_day(self) -> (Month, u8) {
+    pub const fn month_day(self) -> (Month, u8) {
         /// The number of days up to and including the given month. Common years
         /// are first, followed by leap years.
         const CUMULATIVE_DAYS_IN_MONTH_COMMON_LEAP: [[u16; 11]; 2] = [
@@ -319,15 +468,79 @@ impl Date {
         (self.value & 0x1FF) as _
     }
 
-    /// Get the ISO 8601 year and week number.
-    pub(crate) const fn iso_year_week(self) -> (i32, u8) {
-        let (year, ordinal) = self.to_ordinal_date();
+    /// Check if the date is the first day of its month.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert!(date!(2019 - 02 - 01).is_first_day_of_month());
+    /// assert!(!date!(2019 - 01 - 31).is_first_day_of_month());
+    /// ```
+    pub const fn is_first_day_of_month(self) -> bool {
+        self.day() == 1
+    }
 
-        match ((ordinal + 10 - self.weekday().number_from_monday() as u16) / 7) as _ {
-            0 => (year - 1, weeks_in_year(year - 1)),
-            53 if weeks_in_year(year) == 52 => (year + 1, 1),
-            week => (year, week),
-        }
+    /// Check if the date is the last day of its month. This takes leap years into account.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert!(date!(2019 - 02 - 28).is_last_day_of_month());
+    /// assert!(!date!(2020 - 02 - 28).is_last_day_of_month());
+    /// assert!(date!(2020 - 02 - 29).is_last_day_of_month());
+    /// ```
+    pub const fn is_last_day_of_month(self) -> bool {
+        let (month, day) = self.month_day();
+        day == days_in_year_month(self.year(), month)
+    }
+
+    /// Check if the date is the first day of its year.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert!(date!(2020 - 01 - 01).is_first_day_of_year());
+    /// assert!(!date!(2019 - 12 - 31).is_first_day_of_year());
+    /// ```
+    pub const fn is_first_day_of_year(self) -> bool {
+        self.ordinal() == 1
+    }
+
+    /// Check if the date is the last day of its year.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert!(date!(2019 - 12 - 31).is_last_day_of_year());
+    /// assert!(date!(2020 - 12 - 31).is_last_day_of_year());
+    /// assert!(!date!(2020 - 01 - 01).is_last_day_of_year());
+    /// ```
+    pub const fn is_last_day_of_year(self) -> bool {
+        self.ordinal() == days_in_year(self.year())
+    }
+
+    /// Check if the date is 29 February.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert!(date!(2020 - 02 - 29).is_leap_day());
+    /// assert!(!date!(2020 - 03 - 01).is_leap_day());
+    /// assert!(!date!(2019 - 03 - 01).is_leap_day());
+    /// ```
+    pub const fn is_leap_day(self) -> bool {
+        // 29 February is the 60th day of a leap year. In a common year, the 60th day is 1 March.
+        self.ordinal() == 60 && is_leap_year(self.year())
+    }
+
+    /// Get the number of weeks in the given ISO year, which is either 52 or 53. This is the largest
+    /// week accepted by [`Date::from_iso_week_date`] for that year.
+    ///
+    /// This is equivalent to [`util::weeks_in_year`](crate::util::weeks_in_year).
+    ///
+    /// ```rust
+    /// # use time::{Date, Weekday};
+    /// assert_eq!(Date::iso_weeks_in_year(2020), 53);
+    /// assert_eq!(Date::iso_weeks_in_year(2023), 52);
+    /// assert!(Date::from_iso_week_date(2023, 53, Weekday::Monday).is_err());
+    /// ```
+    pub const fn iso_weeks_in_year(year: i32) -> u8 {
+        weeks_in_year(year)
     }
 
     /// Get the ISO week number.
@@ -346,6 +559,27 @@ impl Date {
         self.iso_year_week().1
     }
 
+    /// Get the ISO 8601 year and week number.
+    ///
+    /// Near the start and end of a calendar year, the ISO year may differ from [`Date::year`]. The
+    /// week number is only meaningful alongside the ISO year, so the two are returned together.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019 - 10 - 04).iso_year_week(), (2019, 40));
+    /// assert_eq!(date!(2019 - 12 - 30).iso_year_week(), (2020, 1));
+    /// assert_eq!(date!(2021 - 01 - 01).iso_year_week(), (2020, 53));
+    /// ```
+    pub const fn iso_year_week(self) -> (i32, u8) {
+        let (year, ordinal) = self.to_ordinal_date();
+
+        match ((ordinal + 10 - self.weekday().number_from_monday() as u16) / 7) as _ {
+            0 => (year - 1, weeks_in_year(year - 1)),
+            53 if weeks_in_year(year) == 52 => (year + 1, 1),
+            week => (year, week),
+        }
+    }
+
     /// Get the week number where week 1 begins on the first Sunday.
     ///
     /// The returned value will always be in the range `0..=53`.
@@ -419,14 +653,8 @@ impl Date {
     /// assert_eq!(date!(2021 - 01 - 01).to_iso_week_date(), (2020, 53, Friday));
     /// ```
     pub const fn to_iso_week_date(self) -> (i32, u8, Weekday) {
-        let (year, ordinal) = self.to_ordinal_date();
-        let weekday = self.weekday();
-
-        match ((ordinal + 10 - self.weekday().number_from_monday() as u16) / 7) as _ {
-            0 => (year - 1, weeks_in_year(year - 1), weekday),
-            53 if weeks_in_year(year) == 52 => (year + 1, 1, weekday),
-            week => (year, week, weekday),
-        }
+        let (year, week) = self.iso_year_week();
+        (year, week, self.weekday())
     }
 
     /// Get the weekday.
@@ -531,6 +759,12 @@ impl Date {
 
     /// Get the Julian day for the date.
     ///
+    /// This is the Julian day number of the date: the whole number of days since the Julian period
+    /// began. By astronomical convention each Julian day begins at noon UTC, so the value returned
+    /// is the Julian date at noon on this date, and the Julian date at midnight is half a day less.
+    /// See [`OffsetDateTime::to_julian_date`](crate::OffsetDateTime::to_julian_date) for the
+    /// fractional value at an arbitrary time of day.
+    ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
     /// freely available [here](https://www.researchgate.net/publication/316558298_Date_Algorithms).
     ///
@@ -549,8 +783,77 @@ impl Date {
             + div_floor!(year, 400)
             + 1_721_425
     }
+
+    /// Get the year, month, and day of the date in the Julian calendar.
+    ///
+    /// This is the inverse of [`Date::from_julian_calendar`]; see there for how the calendars
+    /// differ.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(1582 - 10 - 15).to_julian_calendar(),
+    ///     (1582, Month::October, 5)
+    /// );
+    /// assert_eq!(
+    ///     date!(2000 - 01 - 14).to_julian_calendar(),
+    ///     (2000, Month::January, 1)
+    /// );
+    /// ```
+    pub const fn to_julian_calendar(self) -> (i32, Month, u8) {
+        // Days since 0000-03-01 in the Julian calendar, with years counted from March.
+        let days = self.to_julian_day() - 1_721_118;
+        let year = div_floor!(4 * days + 3, 1461);
+        let day_of_year = days - div_floor!(1461 * year, 4);
+        let month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * month + 2) / 5 + 1) as u8;
+
+        let (year, month) = if month < 10 {
+            (year, month + 3)
+        } else {
+            (year + 1, month - 9)
+        };
+        match Month::from_number(month as _) {
+            Ok(month) => (year, month, day),
+            Err(_) => bug!("month is always in range"),
+        }
+    }
     // endregion getters
 
+    // region: packed encoding
+    /// Encode the date as a `u32`, for use in fixed-width storage.
+    ///
+    /// The year is stored in the upper 23 bits as a two's complement integer, and the ordinal day
+    /// in the lower 9 bits; the value is equal to `((year << 9) | ordinal) as u32`. This layout is
+    /// stable and will not change. Values for non-negative years sort in chronological order.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019 - 01 - 01).to_packed_u32(), 0x000F_C601);
+    /// assert_eq!(date!(-0001 - 12 - 31).to_packed_u32(), 0xFFFF_FF6D);
+    /// ```
+    pub const fn to_packed_u32(self) -> u32 {
+        ((self.year() << 9) | self.ordinal() as i32) as _
+    }
+
+    /// Attempt to decode a date that was encoded with [`Date::to_packed_u32`].
+    ///
+    /// An error is returned if the year is out of range or the ordinal day is not valid in that
+    /// year.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(Date::from_packed_u32(0x000F_C601), Ok(date!(2019 - 01 - 01)));
+    /// assert!(Date::from_packed_u32(0x000F_C600).is_err()); // There is no day zero.
+    /// assert!(Date::from_packed_u32(0x000F_C76E).is_err()); // 2019 is not a leap year.
+    /// ```
+    pub const fn from_packed_u32(packed: u32) -> Result<Self, error::ComponentRange> {
+        Self::from_ordinal_date((packed as i32) >> 9, (packed & 0x1FF) as _)
+    }
+    // endregion packed encoding
+
     // region: checked arithmetic
     /// Computes `self + duration`, returning `None` if an overflow occurred.
     ///
@@ -641,6 +944,84 @@ impl Date {
             None
         }
     }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::date;
+    /// assert_eq!(Date::MAX.checked_add_std(1.std_days()), None);
+    /// assert_eq!(
+    ///     date!(2020 - 12 - 31).checked_add_std(2.std_days()),
+    ///     Some(date!(2021 - 01 - 02))
+    /// );
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function only takes whole days into account.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::date;
+    /// assert_eq!(Date::MAX.checked_add_std(23.std_hours()), Some(Date::MAX));
+    /// assert_eq!(
+    ///     date!(2020 - 12 - 31).checked_add_std(47.std_hours()),
+    ///     Some(date!(2021 - 01 - 01))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        let whole_days = duration.as_secs() / Second.per(Day) as u64;
+        if whole_days > i32::MAX as u64 {
+            return None;
+        }
+
+        let julian_day = const_try_opt!(self.to_julian_day().checked_add(whole_days as _));
+        if let Ok(date) = Self::from_julian_day(julian_day) {
+            Some(date)
+        } else {
+            None
+        }
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::date;
+    /// assert_eq!(Date::MIN.checked_sub_std(1.std_days()), None);
+    /// assert_eq!(
+    ///     date!(2020 - 12 - 31).checked_sub_std(2.std_days()),
+    ///     Some(date!(2020 - 12 - 29))
+    /// );
+    /// ```
+    ///
+    /// # Note
+    ///
+    /// This function only takes whole days into account.
+    ///
+    /// ```rust
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::date;
+    /// assert_eq!(Date::MIN.checked_sub_std(23.std_hours()), Some(Date::MIN));
+    /// assert_eq!(
+    ///     date!(2020 - 12 - 31).checked_sub_std(47.std_hours()),
+    ///     Some(date!(2020 - 12 - 30))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        let whole_days = duration.as_secs() / Second.per(Day) as u64;
+        if whole_days > i32::MAX as u64 {
+            return None;
+        }
+
+        let julian_day = const_try_opt!(self.to_julian_day().checked_sub(whole_days as _));
+        if let Ok(date) = Self::from_julian_day(julian_day) {
+            Some(date)
+        } else {
+            None
+        }
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -725,6 +1106,79 @@ impl Date {
     }
     // region: saturating arithmetic
 
+    // region: comparison
+    /// Compare two `Date`s. This is equivalent to [`Ord::cmp`], but usable in `const` contexts.
+    pub(crate) const fn cmp_const(self, other: Self) -> Ordering {
+        const_cmp!(self.value => other.value)
+    }
+
+    /// Returns the earlier of the two dates. Unlike [`Ord::min`], this is usable in `const`
+    /// contexts.
+    ///
+    /// If the two values are equal, `self` is returned.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// let (low, high) = (date!(2019-01-01), date!(2019-12-31));
+    /// assert_eq!(low.min(high), low);
+    /// assert_eq!(high.min(low), low);
+    /// ```
+    pub const fn min(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the later of the two dates. Unlike [`Ord::max`], this is usable in `const`
+    /// contexts.
+    ///
+    /// If the two values are equal, `other` is returned, as with [`Ord::max`].
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// let (low, high) = (date!(2019-01-01), date!(2019-12-31));
+    /// assert_eq!(low.max(high), high);
+    /// assert_eq!(high.max(low), high);
+    /// ```
+    pub const fn max(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restrict the date to the range `min..=max`. Unlike [`Ord::clamp`], this is usable in
+    /// `const` contexts.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// let (low, middle, high) = (date!(2019-01-01), date!(2019-06-15), date!(2019-12-31));
+    /// assert_eq!(low.clamp(middle, high), middle);
+    /// assert_eq!(middle.clamp(low, high), middle);
+    /// assert_eq!(high.clamp(low, middle), middle);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `min` is greater than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            min.cmp_const(max).is_le(),
+            "`min` must be less than or equal to `max`"
+        );
+        if self.cmp_const(min).is_lt() {
+            min
+        } else if self.cmp_const(max).is_gt() {
+            max
+        } else {
+            self
+        }
+    }
+    // endregion comparison
+
     // region: replacement
     /// Replace the year. The month and day will be unchanged.
     ///
@@ -756,7 +1210,10 @@ impl Date {
                 value: 29,
                 minimum: 1,
                 maximum: 28,
-                conditional_range: true,
+                conditional_range: error::ConditionalRange::DaysInMonth {
+                    year,
+                    month: Month::February,
+                },
             }),
             // We're going from a common year to a leap year. Shift dates in March and later by
             // one day.
@@ -804,7 +1261,11 @@ impl Date {
         // Days 1-28 are present in every month, so we can skip checking.
         if day == 0 || day >= 29 {
             ensure_value_in_range!(
-                day conditionally in 1 => days_in_year_month(self.year(), self.month())
+                day in 1 => days_in_year_month(self.year(), self.month()),
+                given error::ConditionalRange::DaysInMonth {
+                    year: self.year(),
+                    month: self.month(),
+                }
             );
         }
 
@@ -924,10 +1385,82 @@ impl Date {
 }
 // endregion attach time
 
+// region: calendar
+/// Methods to iterate over the days of a month, such as when rendering a calendar.
+impl Date {
+    /// Get an iterator over every day in the month containing this date, in order.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// let days = date!(2024 - 02 - 14).month_days();
+    /// assert_eq!(days.len(), 29);
+    /// assert_eq!(
+    ///     days.collect::<Vec<_>>()[..3],
+    ///     [date!(2024 - 02 - 01), date!(2024 - 02 - 02), date!(2024 - 02 - 03)]
+    /// );
+    /// ```
+    pub fn month_days(self) -> impl DoubleEndedIterator<Item = Self> + ExactSizeIterator {
+        let year = self.year();
+        let first = self.ordinal() - self.day() as u16 + 1;
+        let last = first + days_in_year_month(year, self.month()) as u16 - 1;
+        (first..=last).map(move |ordinal| Self::__from_ordinal_date_unchecked(year, ordinal))
+    }
+
+    /// Get an iterator over the weeks of the month containing this date, with each week starting
+    /// on `week_start`. Days of a week that fall outside the month are `None`.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// # use time_macros::date;
+    /// let mut weeks = date!(2024 - 05 - 14).calendar_weeks(Weekday::Monday);
+    /// assert_eq!(weeks.len(), 5);
+    /// assert_eq!(
+    ///     weeks.next(),
+    ///     Some([
+    ///         None,
+    ///         None,
+    ///         Some(date!(2024 - 05 - 01)),
+    ///         Some(date!(2024 - 05 - 02)),
+    ///         Some(date!(2024 - 05 - 03)),
+    ///         Some(date!(2024 - 05 - 04)),
+    ///         Some(date!(2024 - 05 - 05)),
+    ///     ])
+    /// );
+    /// ```
+    pub fn calendar_weeks(
+        self,
+        week_start: Weekday,
+    ) -> impl DoubleEndedIterator<Item = [Option<Self>; 7]> + ExactSizeIterator {
+        let year = self.year();
+        let first = self.ordinal() - self.day() as u16 + 1;
+        let days = days_in_year_month(year, self.month()) as u16;
+        // The number of days in the first week that precede the first of the month.
+        let padding = ((Self::__from_ordinal_date_unchecked(year, first)
+            .weekday()
+            .number_days_from_monday()
+            + 7
+            - week_start.number_days_from_monday())
+            % 7) as u16;
+
+        (0..(padding + days + 6) / 7).map(move |week| {
+            core::array::from_fn(|day| {
+                let index = (week * 7 + day as u16).checked_sub(padding)?;
+                if index < days {
+                    Some(Self::__from_ordinal_date_unchecked(year, first + index))
+                } else {
+                    None
+                }
+            })
+        })
+    }
+}
+// endregion calendar
+
 // region: formatting & parsing
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl Date {
     /// Format the `Date` using the provided [format description](crate::format_description).
+    #[cfg(feature = "std")]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -936,6 +1469,99 @@ impl Date {
         format.format_into(output, Some(self), None, None)
     }
 
+    /// Format the `Date` using the provided [format description](crate::format_description),
+    /// writing to any [`fmt::Write`], such as a [`fmt::Formatter`]. This allows a format
+    /// description to be reused from a `Display` implementation, or in `no_std` environments,
+    /// without allocating.
+    ///
+    /// ```rust
+    /// # use core::fmt;
+    /// # use time::Date;
+    /// # use time_macros::{date, format_description};
+    /// struct Wrapper(Date);
+    ///
+    /// impl fmt::Display for Wrapper {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         self.0
+    ///             .format_into_fmt(f, format_description!("[year]-[month]-[day]"))
+    ///             .map(|_| ())
+    ///             .map_err(|_| fmt::Error)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Wrapper(date!(2020 - 01 - 02)).to_string(), "2020-01-02");
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut FmtWriter(output), Some(self), None, None)
+    }
+
+    /// Pair the `Date` with a [format description](crate::format_description), returning a value
+    /// that is formatted directly into the output when displayed. See [`Formatted`] for details.
+    ///
+    /// ```rust
+    /// # use time_macros::{date, format_description};
+    /// let date = date!(2020-01-02);
+    /// assert_eq!(
+    ///     format!("on {}", date.display(format_description!("[year]-[month]-[day]"))),
+    ///     "on 2020-01-02"
+    /// );
+    /// ```
+    pub const fn display<F: Formattable + ?Sized>(self, format: &F) -> Formatted<'_, F> {
+        Formatted::new(format, Some(self), None, None)
+    }
+
+    /// Format the `Date` using the provided [format description](crate::format_description),
+    /// storing the output in a fixed-capacity [`ArrayBuf`] rather than allocating.
+    ///
+    /// ```rust
+    /// # use time_macros::{date, format_description};
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// assert_eq!(date!(2020 - 01 - 02).format_to_array::<10>(format)?, "2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_array<const N: usize>(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<ArrayBuf<N>, error::Format> {
+        ArrayBuf::format(format, Some(self), None, None)
+    }
+
+    /// Format the `Date` using the provided [format description](crate::format_description),
+    /// writing to a caller-provided byte slice. The number of bytes written is returned.
+    ///
+    /// If the output does not fit, [`error::Format::InsufficientCapacity`] is returned with the
+    /// number of bytes required, and the contents of the buffer are unspecified. The required
+    /// size can be determined in advance with [`Date::formatted_len`].
+    ///
+    /// ```rust
+    /// # use time_macros::{date, format_description};
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// let mut buf = [0; 16];
+    /// let len = date!(2020 - 01 - 02).format_into_slice(&mut buf, format)?;
+    /// assert_eq!(&buf[..len], b"2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        buf: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        SliceWriter::format(format, buf, Some(self), None, None)
+    }
+
+    /// Determine the number of bytes that formatting the `Date` using the provided [format
+    /// description](crate::format_description) would produce, without writing it anywhere.
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut Discard, Some(self), None, None)
+    }
+
     /// Format the `Date` using the provided [format description](crate::format_description).
     ///
     /// ```rust
@@ -945,9 +1571,23 @@ impl Date {
     /// assert_eq!(date!(2020 - 01 - 02).format(&format)?, "2020-01-02");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
         format.format(Some(self), None, None)
     }
+
+    /// Format the `Date` using the well-known [ISO 8601 format](Iso8601), including only the
+    /// date.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2020 - 01 - 02).format_iso8601()?, "2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_iso8601(self) -> Result<String, error::Format> {
+        self.format(&Iso8601::<ISO8601_DATE>)
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -968,8 +1608,29 @@ impl Date {
     ) -> Result<Self, error::Parse> {
         description.parse_date(input.as_bytes())
     }
+
+    /// Parse a `Date` from the input using the well-known [ISO 8601 format](Iso8601). As with
+    /// `Date::parse(input, &Iso8601::DEFAULT)`, any time and offset in the input are ignored.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(Date::parse_iso8601("2020-01-02")?, date!(2020 - 01 - 02));
+    /// assert_eq!(Date::parse_iso8601("2020-W01-4")?, date!(2020 - 01 - 02));
+    /// assert_eq!(
+    ///     Date::parse_iso8601("2020-01-02T03:04:05Z")?,
+    ///     date!(2020 - 01 - 02)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_iso8601(input: &str) -> Result<Self, error::Parse> {
+        Self::parse(input, &Iso8601::DEFAULT)
+    }
 }
 
+/// Write the date as `YYYY-MM-DD`, such as `2023-01-02`. Years with more than four digits are
+/// prefixed with their sign. This format is stable and is described by
+/// [`format_description::display::DATE`](crate::format_description::display::DATE).
 impl fmt::Display for Date {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if cfg!(feature = "large-dates") && self.year().abs() >= 10_000 {
@@ -993,6 +1654,23 @@ impl fmt::Display for Date {
     }
 }
 
+/// Parse a date in the format produced by its [`Display`](fmt::Display) implementation.
+///
+/// ```rust
+/// # use time::Date;
+/// # use time_macros::date;
+/// assert_eq!("2023-01-02".parse(), Ok(date!(2023 - 01 - 02)));
+/// assert!("2023-1-2".parse::<Date>().is_err());
+/// ```
+#[cfg(feature = "parsing")]
+impl FromStr for Date {
+    type Err = error::Parse;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, crate::format_description::display::DATE)
+    }
+}
+
 impl fmt::Debug for Date {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
         fmt::Display::fmt(self, f)
@@ -1004,20 +1682,34 @@ impl fmt::Debug for Date {
 impl Add<Duration> for Date {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, duration: Duration) -> Self::Output {
-        self.checked_add(duration)
-            .expect("overflow adding duration to date")
+        match self.checked_add(duration) {
+            Some(date) => date,
+            None => crate::arithmetic_overflow(
+                "overflow adding duration to date",
+                &self,
+                '+',
+                &duration,
+            ),
+        }
     }
 }
 
 impl Add<StdDuration> for Date {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, duration: StdDuration) -> Self::Output {
-        Self::from_julian_day(
-            self.to_julian_day() + (duration.as_secs() / Second.per(Day) as u64) as i32,
-        )
-        .expect("overflow adding duration to date")
+        match self.checked_add_std(duration) {
+            Some(date) => date,
+            None => crate::arithmetic_overflow(
+                "overflow adding duration to date",
+                &self,
+                '+',
+                &duration,
+            ),
+        }
     }
 }
 
@@ -1026,25 +1718,42 @@ impl_add_assign!(Date: Duration, StdDuration);
 impl Sub<Duration> for Date {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, duration: Duration) -> Self::Output {
-        self.checked_sub(duration)
-            .expect("overflow subtracting duration from date")
+        match self.checked_sub(duration) {
+            Some(date) => date,
+            None => crate::arithmetic_overflow(
+                "overflow subtracting duration from date",
+                &self,
+                '-',
+                &duration,
+            ),
+        }
     }
 }
 
 impl Sub<StdDuration> for Date {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, duration: StdDuration) -> Self::Output {
-        Self::from_julian_day(
-            self.to_julian_day() - (duration.as_secs() / Second.per(Day) as u64) as i32,
-        )
-        .expect("overflow subtracting duration from date")
+        match self.checked_sub_std(duration) {
+            Some(date) => date,
+            None => crate::arithmetic_overflow(
+                "overflow subtracting duration from date",
+                &self,
+                '-',
+                &duration,
+            ),
+        }
     }
 }
 
 impl_sub_assign!(Date: Duration, StdDuration);
 
+/// The difference between any two `Date`s is always representable as a [`Duration`], so this never
+/// panics, even for [`Date::MAX`] `-` [`Date::MIN`]. Use [`Duration::whole_days`] to obtain the
+/// number of days.
 impl Sub for Date {
     type Output = Duration;
 
@@ -1052,4 +1761,9 @@ impl Sub for Date {
         Duration::days((self.to_julian_day() - other.to_julian_day()) as _)
     }
 }
+
+impl_forward_ref_binop!(Add add for Date: Duration, StdDuration);
+impl_forward_ref_binop!(Sub sub for Date: Duration, StdDuration, Date);
+impl_forward_ref_op_assign!(AddAssign add_assign for Date: Duration, StdDuration);
+impl_forward_ref_op_assign!(SubAssign sub_assign for Date: Duration, StdDuration);
 // endregion trait impls
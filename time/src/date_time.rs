@@ -11,15 +11,17 @@ use core::hash::{Hash, Hasher};
 use core::mem::size_of;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(all(feature = "formatting-core", feature = "std"))]
 use std::io;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
 use crate::convert::*;
 use crate::date::{MAX_YEAR, MIN_YEAR};
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{ArrayBuf, Discard, FmtWriter, Formattable, Formatted, SliceWriter};
 #[cfg(feature = "parsing")]
 use crate::parsing::{Parsable, Parsed};
 use crate::{error, util, Date, Duration, Month, Time, UtcOffset, Weekday};
@@ -85,9 +87,11 @@ mod sealed {
 
     // Traits to indicate whether a `MaybeOffset` has a memory offset type of `UtcOffset` or not.
 
+    #[allow(dead_code)]
     pub trait HasMemoryOffset: MaybeOffset<MemoryOffsetType = UtcOffset> {}
     impl<T: MaybeOffset<MemoryOffsetType = UtcOffset>> HasMemoryOffset for T {}
 
+    #[allow(dead_code)]
     pub trait NoMemoryOffset: MaybeOffset<MemoryOffsetType = ()> {}
     impl<T: MaybeOffset<MemoryOffsetType = ()>> NoMemoryOffset for T {}
 
@@ -296,6 +300,56 @@ impl<O: MaybeOffset> DateTime<O> {
             offset: maybe_offset_from_offset::<O>(UtcOffset::UTC),
         })
     }
+
+    pub fn from_julian_date(julian_date: f64) -> Result<Self, error::ComponentRange> {
+        /// The Julian date at midnight UTC at the start of [`Date::MIN`].
+        const MIN_JULIAN_DATE: f64 = Date::MIN.to_julian_day() as f64 - 0.5;
+        /// The Julian date at midnight UTC at the end of [`Date::MAX`].
+        const MAX_JULIAN_DATE: f64 = Date::MAX.to_julian_day() as f64 + 0.5;
+
+        let err = error::ComponentRange {
+            name: "julian_date",
+            minimum: MIN_JULIAN_DATE as i64 - 1,
+            maximum: MAX_JULIAN_DATE as _,
+            value: julian_date as _,
+            conditional_range: error::ConditionalRange::No,
+        };
+        if !(MIN_JULIAN_DATE..MAX_JULIAN_DATE).contains(&julian_date) {
+            return Err(err);
+        }
+
+        // Each Julian day begins at noon, so shift by half a day to begin at midnight instead.
+        let julian_date = julian_date + 0.5;
+        let mut julian_day = julian_date as i64;
+        if julian_day as f64 > julian_date {
+            julian_day -= 1;
+        }
+        // Removing the whole days is exact, so the fraction retains all available precision.
+        let micros = (julian_date - julian_day as f64) * Microsecond.per(Day) as f64;
+        // The value is never negative, so truncating after adding one half rounds to the nearest
+        // microsecond.
+        let mut micros = (micros + 0.5) as u64;
+        if micros == Microsecond.per(Day) {
+            julian_day += 1;
+            micros = 0;
+        }
+        if julian_day > Date::MAX.to_julian_day() as i64 {
+            return Err(err);
+        }
+
+        let seconds_within_day = (micros / Microsecond.per(Second) as u64) as u32;
+        Ok(Self {
+            date: Date::from_julian_day_unchecked(julian_day as _),
+            time: Time::__from_hms_nanos_unchecked(
+                (seconds_within_day / Second.per(Hour) as u32) as _,
+                ((seconds_within_day % Second.per(Hour) as u32) / Second.per(Minute) as u32) as _,
+                (seconds_within_day % Second.per(Minute) as u32) as _,
+                (micros % Microsecond.per(Second) as u64) as u32
+                    * Nanosecond.per(Microsecond) as u32,
+            ),
+            offset: maybe_offset_from_offset::<O>(UtcOffset::UTC),
+        })
+    }
     // endregion constructors
 
     // region: now
@@ -370,10 +424,34 @@ impl<O: MaybeOffset> DateTime<O> {
         self.date.ordinal()
     }
 
+    pub const fn is_first_day_of_month(self) -> bool {
+        self.date.is_first_day_of_month()
+    }
+
+    pub const fn is_last_day_of_month(self) -> bool {
+        self.date.is_last_day_of_month()
+    }
+
+    pub const fn is_first_day_of_year(self) -> bool {
+        self.date.is_first_day_of_year()
+    }
+
+    pub const fn is_last_day_of_year(self) -> bool {
+        self.date.is_last_day_of_year()
+    }
+
+    pub const fn is_leap_day(self) -> bool {
+        self.date.is_leap_day()
+    }
+
     pub const fn iso_week(self) -> u8 {
         self.date.iso_week()
     }
 
+    pub const fn iso_year_week(self) -> (i32, u8) {
+        self.date.iso_year_week()
+    }
+
     pub const fn sunday_based_week(self) -> u8 {
         self.date.sunday_based_week()
     }
@@ -401,6 +479,21 @@ impl<O: MaybeOffset> DateTime<O> {
     pub const fn to_julian_day(self) -> i32 {
         self.date.to_julian_day()
     }
+
+    pub fn to_julian_date(self) -> f64 {
+        let offset = match maybe_offset_as_offset_opt::<O>(self.offset) {
+            Some(offset) => offset.whole_seconds() as i64,
+            None => 0,
+        };
+        let seconds = self.hour() as i64 * Second.per(Hour) as i64
+            + self.minute() as i64 * Second.per(Minute) as i64
+            + self.second() as i64
+            - offset;
+        let nanos = seconds * Nanosecond.per(Second) as i64 + self.nanosecond() as i64;
+
+        // Each Julian day begins at noon, so midnight is halfway through the previous one.
+        self.to_julian_day() as f64 - 0.5 + nanos as f64 / Nanosecond.per(Day) as f64
+    }
     // endregion date getters
 
     // region: time getters
@@ -555,7 +648,7 @@ impl<O: MaybeOffset> DateTime<O> {
         cascade!(ordinal => year);
 
         debug_assert!(ordinal > 0);
-        debug_assert!(ordinal <= crate::util::days_in_year(year) as i16);
+        debug_assert!(ordinal <= util::days_in_year(year) as i16);
 
         (
             year,
@@ -600,6 +693,36 @@ impl<O: MaybeOffset> DateTime<O> {
             offset: self.offset,
         })
     }
+
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        let (is_next_day, time) = self.time.adjusting_add_std(duration);
+        let date = const_try_opt!(self.date.checked_add_std(duration));
+
+        Some(Self {
+            date: if is_next_day {
+                const_try_opt!(date.next_day())
+            } else {
+                date
+            },
+            time,
+            offset: self.offset,
+        })
+    }
+
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        let (is_previous_day, time) = self.time.adjusting_sub_std(duration);
+        let date = const_try_opt!(self.date.checked_sub_std(duration));
+
+        Some(Self {
+            date: if is_previous_day {
+                const_try_opt!(date.previous_day())
+            } else {
+                date
+            },
+            time,
+            offset: self.offset,
+        })
+    }
     // endregion checked arithmetic
 
     // region: saturating arithmetic
@@ -640,6 +763,23 @@ impl<O: MaybeOffset> DateTime<O> {
     }
     // endregion saturating arithmetic
 
+    // region: comparison
+    /// Compare two `DateTime`s by the instant they represent. This is equivalent to [`Ord::cmp`],
+    /// but usable in `const` contexts.
+    pub(crate) const fn cmp_const(self, other: Self) -> Ordering {
+        let (year, ordinal, time) = self.to_offset_raw(UtcOffset::UTC);
+        let (other_year, other_ordinal, other_time) = other.to_offset_raw(UtcOffset::UTC);
+        const_cmp!(
+            year => other_year,
+            ordinal => other_ordinal,
+            time.hour() => other_time.hour(),
+            time.minute() => other_time.minute(),
+            time.second() => other_time.second(),
+            time.nanosecond() => other_time.nanosecond(),
+        )
+    }
+    // endregion comparison
+
     // region: replacement
     pub const fn replace_time(self, time: Time) -> Self {
         Self {
@@ -762,7 +902,8 @@ impl<O: MaybeOffset> DateTime<O> {
     // endregion replacement
 
     // region: formatting & parsing
-    #[cfg(feature = "formatting")]
+    #[cfg(feature = "formatting-core")]
+    #[cfg(feature = "std")]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -776,7 +917,73 @@ impl<O: MaybeOffset> DateTime<O> {
         )
     }
 
-    #[cfg(feature = "formatting")]
+    #[cfg(feature = "formatting-core")]
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into(
+            &mut FmtWriter(output),
+            Some(self.date),
+            Some(self.time),
+            maybe_offset_as_offset_opt::<O>(self.offset),
+        )
+    }
+
+    #[cfg(feature = "formatting-core")]
+    pub const fn display<F: Formattable + ?Sized>(self, format: &F) -> Formatted<'_, F> {
+        Formatted::new(
+            format,
+            Some(self.date),
+            Some(self.time),
+            maybe_offset_as_offset_opt::<O>(self.offset),
+        )
+    }
+
+    #[cfg(feature = "formatting-core")]
+    pub fn format_to_array<const N: usize>(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<ArrayBuf<N>, error::Format> {
+        ArrayBuf::format(
+            format,
+            Some(self.date),
+            Some(self.time),
+            maybe_offset_as_offset_opt::<O>(self.offset),
+        )
+    }
+
+    #[cfg(feature = "formatting-core")]
+    pub fn format_into_slice(
+        self,
+        buf: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        SliceWriter::format(
+            format,
+            buf,
+            Some(self.date),
+            Some(self.time),
+            maybe_offset_as_offset_opt::<O>(self.offset),
+        )
+    }
+
+    #[cfg(feature = "formatting-core")]
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into(
+            &mut Discard,
+            Some(self.date),
+            Some(self.time),
+            maybe_offset_as_offset_opt::<O>(self.offset),
+        )
+    }
+
+    #[cfg(feature = "formatting-core")]
+    #[cfg(feature = "alloc")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
         format.format(
             Some(self.date),
@@ -927,39 +1134,40 @@ impl<O: MaybeOffset> Hash for DateTime<O> {
 impl<O: MaybeOffset> Add<Duration> for DateTime<O> {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, duration: Duration) -> Self {
-        self.checked_add(duration)
-            .expect("resulting value is out of range")
+        match self.checked_add(duration) {
+            Some(datetime) => datetime,
+            None => {
+                crate::arithmetic_overflow("resulting value is out of range", &self, '+', &duration)
+            }
+        }
     }
 }
 
 impl<O: MaybeOffset> Add<StdDuration> for DateTime<O> {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, duration: StdDuration) -> Self::Output {
-        let (is_next_day, time) = self.time.adjusting_add_std(duration);
-
-        Self {
-            date: if is_next_day {
-                (self.date + duration)
-                    .next_day()
-                    .expect("resulting value is out of range")
-            } else {
-                self.date + duration
-            },
-            time,
-            offset: self.offset,
+        match self.checked_add_std(duration) {
+            Some(datetime) => datetime,
+            None => {
+                crate::arithmetic_overflow("resulting value is out of range", &self, '+', &duration)
+            }
         }
     }
 }
 
 impl<O: MaybeOffset> AddAssign<Duration> for DateTime<O> {
+    #[track_caller]
     fn add_assign(&mut self, rhs: Duration) {
         *self = *self + rhs;
     }
 }
 
 impl<O: MaybeOffset> AddAssign<StdDuration> for DateTime<O> {
+    #[track_caller]
     fn add_assign(&mut self, rhs: StdDuration) {
         *self = *self + rhs;
     }
@@ -968,39 +1176,40 @@ impl<O: MaybeOffset> AddAssign<StdDuration> for DateTime<O> {
 impl<O: MaybeOffset> Sub<Duration> for DateTime<O> {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, duration: Duration) -> Self {
-        self.checked_sub(duration)
-            .expect("resulting value is out of range")
+        match self.checked_sub(duration) {
+            Some(datetime) => datetime,
+            None => {
+                crate::arithmetic_overflow("resulting value is out of range", &self, '-', &duration)
+            }
+        }
     }
 }
 
 impl<O: MaybeOffset> Sub<StdDuration> for DateTime<O> {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, duration: StdDuration) -> Self::Output {
-        let (is_previous_day, time) = self.time.adjusting_sub_std(duration);
-
-        Self {
-            date: if is_previous_day {
-                (self.date - duration)
-                    .previous_day()
-                    .expect("resulting value is out of range")
-            } else {
-                self.date - duration
-            },
-            time,
-            offset: self.offset,
+        match self.checked_sub_std(duration) {
+            Some(datetime) => datetime,
+            None => {
+                crate::arithmetic_overflow("resulting value is out of range", &self, '-', &duration)
+            }
         }
     }
 }
 
 impl<O: MaybeOffset> SubAssign<Duration> for DateTime<O> {
+    #[track_caller]
     fn sub_assign(&mut self, rhs: Duration) {
         *self = *self - rhs;
     }
 }
 
 impl<O: MaybeOffset> SubAssign<StdDuration> for DateTime<O> {
+    #[track_caller]
     fn sub_assign(&mut self, rhs: StdDuration) {
         *self = *self - rhs;
     }
@@ -1010,6 +1219,9 @@ impl<O: MaybeOffset> Sub<Self> for DateTime<O> {
     type Output = Duration;
 
     fn sub(self, rhs: Self) -> Self::Output {
+        // Even with `large-dates` enabled, the span between the minimum and maximum values is
+        // roughly 2 million years, which is far smaller than the range of `Duration`. None of the
+        // operations below can overflow.
         let base = (self.date - rhs.date) + (self.time - rhs.time);
 
         match (
@@ -1062,6 +1274,11 @@ impl Sub<Duration> for SystemTime {
 
 impl_sub_assign!(SystemTime: #[cfg(feature = "std")] Duration);
 
+impl_forward_ref_binop!(Add add for SystemTime: #[cfg(feature = "std")] Duration);
+impl_forward_ref_binop!(Sub sub for SystemTime: #[cfg(feature = "std")] Duration);
+impl_forward_ref_op_assign!(AddAssign add_assign for SystemTime: #[cfg(feature = "std")] Duration);
+impl_forward_ref_op_assign!(SubAssign sub_assign for SystemTime: #[cfg(feature = "std")] Duration);
+
 #[cfg(feature = "std")]
 impl Sub<SystemTime> for DateTime<offset_kind::Fixed> {
     type Output = Duration;
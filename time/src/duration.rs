@@ -6,26 +6,30 @@ use core::iter::Sum;
 use core::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
 use core::time::Duration as StdDuration;
 
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(all(feature = "formatting-core", feature = "std"))]
+use std::io;
+
 use crate::convert::*;
 use crate::error;
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
+use crate::format_description::well_known::Iso8601Duration;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{format_iso8601_duration, FmtWriter};
 #[cfg(feature = "std")]
 use crate::Instant;
 
 /// By explicitly inserting this enum where padding is expected, the compiler is able to better
 /// perform niche value optimization.
 #[repr(u32)]
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
 pub(crate) enum Padding {
     #[allow(clippy::missing_docs_in_private_items)]
+    #[default]
     Optimize,
 }
 
-impl Default for Padding {
-    fn default() -> Self {
-        Self::Optimize
-    }
-}
-
 /// A span of time with nanosecond precision.
 ///
 /// Each `Duration` is composed of a whole number of seconds and a fractional part represented in
@@ -42,6 +46,48 @@ pub struct Duration {
     padding: Padding,
 }
 
+/// A unit of time that a [`Duration`] can be split into, as returned by
+/// [`Duration::components`].
+#[allow(clippy::missing_docs_in_private_items)] // variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Unit {
+    Week,
+    Day,
+    Hour,
+    Minute,
+    Second,
+    Millisecond,
+    Microsecond,
+    Nanosecond,
+}
+
+/// The components of a [`Duration`] as written in the ISO 8601 duration format. These are shared by
+/// the alternate `Display` output and the [`Iso8601Duration`] format.
+pub(crate) struct Iso8601Components {
+    /// Whole days, which are zero if the hours were not split into days.
+    pub(crate) days: u64,
+    /// Whole hours.
+    pub(crate) hours: u64,
+    /// Whole minutes within the hour.
+    pub(crate) minutes: u64,
+    /// Whole seconds within the minute.
+    pub(crate) seconds: u64,
+    /// The fractional part of the seconds, written with exactly `digits` digits.
+    pub(crate) fraction: u32,
+    /// The number of decimal digits of the seconds.
+    pub(crate) digits: u8,
+}
+
+impl Iso8601Components {
+    /// Whether the seconds are written. They are always written if no other component is, so that
+    /// a zero duration is written as `PT0S`.
+    pub(crate) const fn has_seconds(&self) -> bool {
+        self.seconds != 0
+            || self.fraction != 0
+            || (self.days == 0 && self.hours == 0 && self.minutes == 0)
+    }
+}
+
 impl fmt::Debug for Duration {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Duration")
@@ -271,6 +317,8 @@ impl Duration {
     /// assert!(!1.seconds().is_negative());
     /// ```
     pub const fn is_negative(self) -> bool {
+        // The seconds and nanoseconds never have opposite signs (see `new_unchecked`), so either
+        // one being negative suffices. The same holds for `is_positive`.
         self.seconds < 0 || self.nanoseconds < 0
     }
 
@@ -285,6 +333,25 @@ impl Duration {
     pub const fn is_positive(self) -> bool {
         self.seconds > 0 || self.nanoseconds > 0
     }
+
+    /// Get the sign of the duration: `-1` if it is negative, `0` if it is zero, and `1` if it is
+    /// positive.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!((-1).nanoseconds().signum(), -1);
+    /// assert_eq!(0.seconds().signum(), 0);
+    /// assert_eq!(1.nanoseconds().signum(), 1);
+    /// ```
+    pub const fn signum(self) -> i8 {
+        if self.is_negative() {
+            -1
+        } else if self.is_positive() {
+            1
+        } else {
+            0
+        }
+    }
     // endregion is_{sign}
 
     // region: abs
@@ -337,13 +404,16 @@ impl Duration {
     }
 
     /// Create a new `Duration` with the provided seconds and nanoseconds. If nanoseconds is at
-    /// least ±10<sup>9</sup>, it will wrap to the number of seconds.
+    /// least ±10<sup>9</sup>, it will wrap to the number of seconds. If seconds and nanoseconds
+    /// have opposite signs, the two are summed, so `Duration::new(1, -500_000_000)` is half a
+    /// second. To reject these inputs instead, use [`Duration::try_new`].
     ///
     /// ```rust
     /// # use time::{Duration, ext::NumericalDuration};
     /// assert_eq!(Duration::new(1, 0), 1.seconds());
     /// assert_eq!(Duration::new(-1, 0), (-1).seconds());
     /// assert_eq!(Duration::new(1, 2_000_000_000), 3.seconds());
+    /// assert_eq!(Duration::new(1, -500_000_000), 500.milliseconds());
     /// ```
     pub const fn new(mut seconds: i64, mut nanoseconds: i32) -> Self {
         seconds = expect_opt!(
@@ -365,6 +435,34 @@ impl Duration {
         Self::new_unchecked(seconds, nanoseconds)
     }
 
+    /// Create a new `Duration` with the provided seconds and nanoseconds, returning an error if
+    /// they are inconsistent.
+    ///
+    /// Unlike [`Duration::new`], nanoseconds are not normalized. They must be less than
+    /// 10<sup>9</sup> in magnitude and must not have the opposite sign of seconds.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::try_new(1, 500_000_000), Ok(1.5.seconds()));
+    /// assert_eq!(Duration::try_new(-1, -500_000_000), Ok((-1.5).seconds()));
+    /// assert_eq!(Duration::try_new(0, -500_000_000), Ok((-0.5).seconds()));
+    /// assert!(Duration::try_new(1, -500_000_000).is_err()); // mixed signs
+    /// assert!(Duration::try_new(1, 1_000_000_000).is_err()); // out of range
+    /// ```
+    pub const fn try_new(seconds: i64, nanoseconds: i32) -> Result<Self, error::ComponentRange> {
+        let max = Nanosecond.per(Second) as i32 - 1;
+        let (minimum, maximum, conditional_range) = if seconds > 0 {
+            (0, max, error::ConditionalRange::SignOfSeconds(false))
+        } else if seconds < 0 {
+            (-max, 0, error::ConditionalRange::SignOfSeconds(true))
+        } else {
+            (-max, max, error::ConditionalRange::No)
+        };
+        ensure_value_in_range!(nanoseconds in minimum => maximum, given conditional_range);
+
+        Ok(Self::new_unchecked(seconds, nanoseconds))
+    }
+
     /// Create a new `Duration` with the given number of weeks. Equivalent to
     /// `Duration::seconds(weeks * 604_800)`.
     ///
@@ -647,6 +745,183 @@ impl Duration {
         )
     }
 
+    /// Create a new `Duration` from the given number of hours, minutes, and seconds. The
+    /// components are summed, so they may have mixed signs and need not be less than their
+    /// respective maximum on a clock.
+    ///
+    /// This is equivalent to `Duration::hours(hours) + Duration::minutes(minutes) +
+    /// Duration::seconds(seconds)`, but only panics if the total cannot be represented.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::from_hms(2, 30, 15), 2.hours() + 30.minutes() + 15.seconds());
+    /// assert_eq!(Duration::from_hms(1, -30, 0), 30.minutes());
+    /// assert_eq!(Duration::from_hms(-1, 0, 90), (-3_510).seconds());
+    /// ```
+    pub const fn from_hms(hours: i64, minutes: i64, seconds: i64) -> Self {
+        expect_opt!(
+            Self::checked_from_hms(hours, minutes, seconds),
+            "overflow constructing `time::Duration`"
+        )
+    }
+
+    /// Create a new `Duration` from the given number of hours, minutes, and seconds, returning
+    /// `None` if the total cannot be represented. See [`Duration::from_hms`] for details.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::checked_from_hms(2, 30, 15), Some(9_015.seconds()));
+    /// assert_eq!(Duration::checked_from_hms(i64::MAX, 0, 0), None);
+    /// ```
+    pub const fn checked_from_hms(hours: i64, minutes: i64, seconds: i64) -> Option<Self> {
+        Self::checked_from_components(0, hours, minutes, seconds, 0)
+    }
+
+    /// Create a new `Duration` from the given number of hours, minutes, seconds, and
+    /// milliseconds. The components are summed, so they may have mixed signs and need not be less
+    /// than their respective maximum on a clock.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(
+    ///     Duration::from_hms_milli(0, 1, 2, 500),
+    ///     1.minutes() + 2.seconds() + 500.milliseconds()
+    /// );
+    /// assert_eq!(Duration::from_hms_milli(0, 0, 1, -1_500), (-0.5).seconds());
+    /// ```
+    pub const fn from_hms_milli(hours: i64, minutes: i64, seconds: i64, milliseconds: i64) -> Self {
+        expect_opt!(
+            Self::checked_from_hms_milli(hours, minutes, seconds, milliseconds),
+            "overflow constructing `time::Duration`"
+        )
+    }
+
+    /// Create a new `Duration` from the given number of hours, minutes, seconds, and
+    /// milliseconds, returning `None` if the total cannot be represented. See
+    /// [`Duration::from_hms_milli`] for details.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::checked_from_hms_milli(0, 0, 1, 500), Some(1.5.seconds()));
+    /// assert_eq!(Duration::checked_from_hms_milli(0, 0, i64::MAX, 1_000), None);
+    /// ```
+    pub const fn checked_from_hms_milli(
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        milliseconds: i64,
+    ) -> Option<Self> {
+        Self::checked_from_components(
+            0,
+            hours,
+            minutes,
+            seconds,
+            milliseconds as i128 * Nanosecond.per(Millisecond) as i128,
+        )
+    }
+
+    /// Create a new `Duration` from the given number of hours, minutes, seconds, and nanoseconds.
+    /// The components are summed, so they may have mixed signs and need not be less than their
+    /// respective maximum on a clock.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(
+    ///     Duration::from_hms_nano(1, 2, 3, 4),
+    ///     1.hours() + 2.minutes() + 3.seconds() + 4.nanoseconds()
+    /// );
+    /// assert_eq!(Duration::from_hms_nano(0, 0, -1, 1), (-999_999_999).nanoseconds());
+    /// ```
+    pub const fn from_hms_nano(hours: i64, minutes: i64, seconds: i64, nanoseconds: i64) -> Self {
+        expect_opt!(
+            Self::checked_from_hms_nano(hours, minutes, seconds, nanoseconds),
+            "overflow constructing `time::Duration`"
+        )
+    }
+
+    /// Create a new `Duration` from the given number of hours, minutes, seconds, and
+    /// nanoseconds, returning `None` if the total cannot be represented. See
+    /// [`Duration::from_hms_nano`] for details.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::checked_from_hms_nano(0, 0, 1, 1), Some(1_000_000_001.nanoseconds()));
+    /// assert_eq!(Duration::checked_from_hms_nano(0, 0, i64::MIN, -1_000_000_000), None);
+    /// ```
+    pub const fn checked_from_hms_nano(
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        nanoseconds: i64,
+    ) -> Option<Self> {
+        Self::checked_from_components(0, hours, minutes, seconds, nanoseconds as i128)
+    }
+
+    /// Create a new `Duration` from the given number of days, hours, minutes, and seconds. The
+    /// components are summed, so they may have mixed signs and need not be less than their
+    /// respective maximum on a clock.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(
+    ///     Duration::from_dhms(1, 2, 3, 4),
+    ///     1.days() + 2.hours() + 3.minutes() + 4.seconds()
+    /// );
+    /// assert_eq!(Duration::from_dhms(1, -24, 0, 1), 1.seconds());
+    /// ```
+    pub const fn from_dhms(days: i64, hours: i64, minutes: i64, seconds: i64) -> Self {
+        expect_opt!(
+            Self::checked_from_dhms(days, hours, minutes, seconds),
+            "overflow constructing `time::Duration`"
+        )
+    }
+
+    /// Create a new `Duration` from the given number of days, hours, minutes, and seconds,
+    /// returning `None` if the total cannot be represented. See [`Duration::from_dhms`] for
+    /// details.
+    ///
+    /// ```rust
+    /// # use time::{Duration, ext::NumericalDuration};
+    /// assert_eq!(Duration::checked_from_dhms(1, 0, 0, -1), Some(86_399.seconds()));
+    /// assert_eq!(Duration::checked_from_dhms(i64::MAX, 0, 0, 0), None);
+    /// ```
+    pub const fn checked_from_dhms(
+        days: i64,
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+    ) -> Option<Self> {
+        Self::checked_from_components(days, hours, minutes, seconds, 0)
+    }
+
+    /// Sum the provided components into a single `Duration`, returning `None` if the total cannot
+    /// be represented.
+    ///
+    /// The total is accumulated as an `i128` number of nanoseconds, which cannot overflow for any
+    /// input. Only the final result needs to be checked.
+    const fn checked_from_components(
+        days: i64,
+        hours: i64,
+        minutes: i64,
+        seconds: i64,
+        nanoseconds: i128,
+    ) -> Option<Self> {
+        let seconds = days as i128 * Second.per(Day) as i128
+            + hours as i128 * Second.per(Hour) as i128
+            + minutes as i128 * Second.per(Minute) as i128
+            + seconds as i128;
+        let nanoseconds = seconds * Nanosecond.per(Second) as i128 + nanoseconds;
+
+        let seconds = nanoseconds / Nanosecond.per(Second) as i128;
+        if seconds > i64::MAX as i128 || seconds < i64::MIN as i128 {
+            return None;
+        }
+        Some(Self::new_unchecked(
+            seconds as _,
+            (nanoseconds % Nanosecond.per(Second) as i128) as _,
+        ))
+    }
+
     /// Create a new `Duration` with the given number of nanoseconds.
     ///
     /// As the input range cannot be fully mapped to the output, this should only be used where it's
@@ -831,6 +1106,63 @@ impl Duration {
     pub const fn subsec_nanoseconds(self) -> i32 {
         self.nanoseconds
     }
+
+    /// Get the magnitude of each component of the duration, from weeks down to nanoseconds. Zero
+    /// components are included; filter them out if they are not wanted. The sign of the duration
+    /// is not included, and can be obtained with [`is_negative`](Self::is_negative).
+    ///
+    /// As with the `Display` implementation, a week is exactly seven days, a day is exactly 24
+    /// hours, and a minute is exactly 60 seconds.
+    ///
+    /// ```rust
+    /// # use time::{Duration, Unit};
+    /// # use time::ext::NumericalDuration;
+    /// let duration = -(2.days() + 3.hours() + 500.milliseconds());
+    /// assert!(duration.is_negative());
+    /// assert_eq!(
+    ///     duration
+    ///         .components()
+    ///         .filter(|&(value, _)| value != 0)
+    ///         .collect::<Vec<_>>(),
+    ///     [(2, Unit::Day), (3, Unit::Hour), (500, Unit::Millisecond)]
+    /// );
+    /// assert_eq!(Duration::ZERO.components().len(), 8);
+    /// ```
+    pub fn components(self) -> impl DoubleEndedIterator<Item = (u64, Unit)> + ExactSizeIterator {
+        let seconds = self.seconds.unsigned_abs();
+        let nanoseconds = self.nanoseconds.unsigned_abs();
+
+        [
+            (seconds / Second.per(Week) as u64, Unit::Week),
+            (
+                seconds / Second.per(Day) as u64 % Day.per(Week) as u64,
+                Unit::Day,
+            ),
+            (
+                seconds / Second.per(Hour) as u64 % Hour.per(Day) as u64,
+                Unit::Hour,
+            ),
+            (
+                seconds / Second.per(Minute) as u64 % Minute.per(Hour) as u64,
+                Unit::Minute,
+            ),
+            (seconds % Second.per(Minute) as u64, Unit::Second),
+            (
+                (nanoseconds / Nanosecond.per(Millisecond)) as u64,
+                Unit::Millisecond,
+            ),
+            (
+                (nanoseconds / Nanosecond.per(Microsecond) as u32
+                    % Microsecond.per(Millisecond) as u32) as u64,
+                Unit::Microsecond,
+            ),
+            (
+                (nanoseconds % Nanosecond.per(Microsecond) as u32) as u64,
+                Unit::Nanosecond,
+            ),
+        ]
+        .into_iter()
+    }
     // endregion getters
 
     // region: checked arithmetic
@@ -1040,6 +1372,178 @@ impl Duration {
     }
     // endregion saturating arithmetic
 
+    // region: const arithmetic
+    /// Computes `self + rhs`, panicking if an overflow occurred.
+    ///
+    /// This is equivalent to the `+` operator, which should be preferred at runtime, but is usable
+    /// in `const` contexts. An overflow in a constant is a compile-time error.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// const TIMEOUT: Duration = Duration::SECOND.const_add(Duration::MILLISECOND);
+    /// assert_eq!(TIMEOUT, Duration::milliseconds(1_001));
+    /// ```
+    #[track_caller]
+    pub const fn const_add(self, rhs: Self) -> Self {
+        match self.checked_add(rhs) {
+            Some(duration) => duration,
+            None => panic!("overflow when adding durations"),
+        }
+    }
+
+    /// Computes `self - rhs`, panicking if an overflow occurred.
+    ///
+    /// This is equivalent to the `-` operator, which should be preferred at runtime, but is usable
+    /// in `const` contexts. An overflow in a constant is a compile-time error.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// const TIMEOUT: Duration = Duration::SECOND.const_sub(Duration::MILLISECOND);
+    /// assert_eq!(TIMEOUT, Duration::milliseconds(999));
+    /// ```
+    #[track_caller]
+    pub const fn const_sub(self, rhs: Self) -> Self {
+        match self.checked_sub(rhs) {
+            Some(duration) => duration,
+            None => panic!("overflow when subtracting durations"),
+        }
+    }
+
+    /// Computes `self * rhs`, panicking if an overflow occurred.
+    ///
+    /// This is equivalent to the `*` operator, which should be preferred at runtime, but is usable
+    /// in `const` contexts. An overflow in a constant is a compile-time error.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// const TIMEOUT: Duration = Duration::SECOND.const_mul(3);
+    /// assert_eq!(TIMEOUT, Duration::seconds(3));
+    /// ```
+    #[track_caller]
+    pub const fn const_mul(self, rhs: i32) -> Self {
+        match self.checked_mul(rhs) {
+            Some(duration) => duration,
+            None => panic!("overflow when multiplying duration"),
+        }
+    }
+
+    /// Computes `-self`, panicking if an overflow occurred. The only value that overflows is
+    /// [`Duration::MIN`].
+    ///
+    /// This is equivalent to the unary `-` operator, which should be preferred at runtime, but is
+    /// usable in `const` contexts. An overflow in a constant is a compile-time error.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// const BACKWARDS: Duration = Duration::SECOND.const_neg();
+    /// assert_eq!(BACKWARDS, Duration::seconds(-1));
+    /// ```
+    #[track_caller]
+    pub const fn const_neg(self) -> Self {
+        match self.seconds.checked_neg() {
+            Some(seconds) => Self::new_unchecked(seconds, -self.nanoseconds),
+            None => panic!("overflow when negating duration"),
+        }
+    }
+
+    /// Convert a [`std::time::Duration`] to a `Duration`, panicking if it is too large to be
+    /// represented.
+    ///
+    /// This is equivalent to the [`TryFrom`] implementation, which should be preferred at runtime,
+    /// but is usable in `const` contexts. A value out of range in a constant is a compile-time
+    /// error.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// use core::time::Duration as StdDuration;
+    /// const TIMEOUT: Duration = Duration::from_std_const(StdDuration::from_millis(1_500));
+    /// assert_eq!(TIMEOUT, Duration::milliseconds(1_500));
+    /// ```
+    #[track_caller]
+    pub const fn from_std_const(duration: StdDuration) -> Self {
+        if duration.as_secs() > i64::MAX as u64 {
+            panic!("std duration is too large to be represented");
+        }
+        Self::new_unchecked(duration.as_secs() as _, duration.subsec_nanos() as _)
+    }
+    // endregion const arithmetic
+
+    // region: comparison
+    /// Compare two `Duration`s. This is equivalent to [`Ord::cmp`], but usable in `const` contexts.
+    pub(crate) const fn cmp_const(self, other: Self) -> Ordering {
+        const_cmp!(
+            self.seconds => other.seconds,
+            self.nanoseconds => other.nanoseconds,
+        )
+    }
+
+    /// Returns the shorter of the two durations. Unlike [`Ord::min`], this is usable in `const`
+    /// contexts.
+    ///
+    /// If the two values are equal, `self` is returned.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// let (low, high) = ((-1).seconds(), 1.minutes());
+    /// assert_eq!(low.min(high), low);
+    /// assert_eq!(high.min(low), low);
+    /// ```
+    pub const fn min(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the longer of the two durations. Unlike [`Ord::max`], this is usable in `const`
+    /// contexts.
+    ///
+    /// If the two values are equal, `other` is returned, as with [`Ord::max`].
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// let (low, high) = ((-1).seconds(), 1.minutes());
+    /// assert_eq!(low.max(high), high);
+    /// assert_eq!(high.max(low), high);
+    /// ```
+    pub const fn max(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restrict the duration to the range `min..=max`. Unlike [`Ord::clamp`], this is usable in
+    /// `const` contexts.
+    ///
+    /// ```rust
+    /// # use time::ext::NumericalDuration;
+    /// let (low, middle, high) = ((-1).seconds(), 1.seconds(), 1.minutes());
+    /// assert_eq!(low.clamp(middle, high), middle);
+    /// assert_eq!(middle.clamp(low, high), middle);
+    /// assert_eq!(high.clamp(low, middle), middle);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `min` is greater than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            min.cmp_const(max).is_le(),
+            "`min` must be less than or equal to `max`"
+        );
+        if self.cmp_const(min).is_lt() {
+            min
+        } else if self.cmp_const(max).is_gt() {
+            max
+        } else {
+            self
+        }
+    }
+    // endregion comparison
+
     /// Runs a closure, returning the duration of time it took to run. The return value of the
     /// closure is provided in the second part of the tuple.
     #[cfg(feature = "std")]
@@ -1050,6 +1554,133 @@ impl Duration {
 
         (end - start, return_value)
     }
+
+    /// Split the absolute value of the duration into the components written in the ISO 8601
+    /// duration format. Whole days are only split from the hours if `use_days` is set. The number
+    /// of decimal digits of the seconds, at most nine, is fixed if provided, and otherwise is as
+    /// many as needed, truncating any excess precision.
+    pub(crate) const fn iso8601_components(
+        self,
+        use_days: bool,
+        decimal_digits: Option<u8>,
+    ) -> Iso8601Components {
+        let seconds = self.seconds.unsigned_abs();
+        let nanoseconds = self.nanoseconds.unsigned_abs();
+
+        let (days, hours) = if use_days {
+            (
+                seconds / Second.per(Day) as u64,
+                seconds / Second.per(Hour) as u64 % Hour.per(Day) as u64,
+            )
+        } else {
+            (0, seconds / Second.per(Hour) as u64)
+        };
+
+        let (fraction, digits) = match decimal_digits {
+            Some(digits) => (nanoseconds / 10_u32.pow(9 - digits as u32), digits),
+            None if nanoseconds == 0 => (0, 0),
+            None => {
+                let mut fraction = nanoseconds;
+                let mut digits = 9;
+                while fraction % 10 == 0 {
+                    fraction /= 10;
+                    digits -= 1;
+                }
+                (fraction, digits)
+            }
+        };
+
+        Iso8601Components {
+            days,
+            hours,
+            minutes: seconds / Second.per(Minute) as u64 % Minute.per(Hour) as u64,
+            seconds: seconds % Second.per(Minute) as u64,
+            fraction,
+            digits,
+        }
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Duration {
+    /// Parse a `Duration` from its human-readable representation. Any output of the `Display`
+    /// implementation without a precision specifier is accepted.
+    ///
+    /// The input is a sequence of integers, each immediately followed by one of the units `w`, `d`,
+    /// `h`, `m`, `s`, `ms`, `µs`, or `ns`. Units must be in descending order of size and may not be
+    /// repeated. A leading `-` negates the entire duration. As with the `Display` implementation, a
+    /// week is exactly seven days, a day is exactly 24 hours, and a minute is exactly 60 seconds.
+    ///
+    /// ```rust
+    /// # use time::Duration;
+    /// # use time::ext::NumericalDuration;
+    /// assert_eq!(Duration::parse_human("1d2h3m")?, 1.days() + 2.hours() + 3.minutes());
+    /// assert_eq!(Duration::parse_human("-1s500ms")?, (-1.5).seconds());
+    /// assert_eq!(Duration::parse_human("2w")?, 14.days());
+    ///
+    /// let duration = Duration::new(123_456, 789_011_223);
+    /// assert_eq!(Duration::parse_human(&duration.to_string())?, duration);
+    /// # Ok::<_, time::error::Parse>(())
+    /// ```
+    pub fn parse_human(input: &str) -> Result<Self, error::Parse> {
+        crate::parsing::duration::parse_human(input.as_bytes())
+    }
+
+    /// Parse a `Duration` from the input using the well-known [ISO 8601 duration
+    /// format](Iso8601Duration).
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Iso8601Duration;
+    /// # use time::Duration;
+    /// # use time::ext::NumericalDuration;
+    /// let format = Iso8601Duration::DEFAULT;
+    /// assert_eq!(Duration::parse("P1DT2H", &format)?, 26.hours());
+    /// assert_eq!(Duration::parse("-PT0.5S", &format)?, (-0.5).seconds());
+    /// # Ok::<_, time::error::Parse>(())
+    /// ```
+    pub fn parse(input: &str, format: &Iso8601Duration) -> Result<Self, error::Parse> {
+        format.parse_duration(input.as_bytes())
+    }
+}
+
+#[cfg(feature = "formatting-core")]
+impl Duration {
+    /// Format the `Duration` using the well-known [ISO 8601 duration format](Iso8601Duration).
+    #[cfg(feature = "std")]
+    pub fn format_into(
+        self,
+        output: &mut impl io::Write,
+        format: &Iso8601Duration,
+    ) -> Result<usize, error::Format> {
+        format_iso8601_duration(output, self, format)
+    }
+
+    /// Format the `Duration` using the well-known [ISO 8601 duration format](Iso8601Duration),
+    /// writing to any [`fmt::Write`].
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &Iso8601Duration,
+    ) -> Result<usize, error::Format> {
+        format_iso8601_duration(&mut FmtWriter(output), self, format)
+    }
+
+    /// Format the `Duration` using the well-known [ISO 8601 duration format](Iso8601Duration).
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Iso8601Duration;
+    /// # use time::ext::NumericalDuration;
+    /// let format = Iso8601Duration::DEFAULT;
+    /// assert_eq!(90.minutes().format(&format)?, "PT1H30M");
+    /// assert_eq!((-2).weeks().format(&format)?, "-P14D");
+    /// # Ok::<_, time::error::Format>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format(self, format: &Iso8601Duration) -> Result<String, error::Format> {
+        let mut buf = String::new();
+        self.format_into_fmt(&mut buf, format)?;
+        Ok(buf)
+    }
 }
 
 // region: trait impls
@@ -1065,6 +1696,18 @@ impl Duration {
 /// println!("{duration:.3}");
 /// ```
 ///
+/// The alternate flag produces an ISO 8601 duration using hours, minutes, and seconds, such as
+/// `PT1H30M`. Days are not used, as an ISO 8601 day is not necessarily 24 hours. Fractional seconds
+/// are written without trailing zeros; `.N` instead truncates them to exactly `N` digits (at most
+/// nine). Width is not supported.
+///
+/// ```
+/// # use time::ext::NumericalDuration;
+/// assert_eq!(format!("{:#}", 5_400.seconds()), "PT1H30M");
+/// assert_eq!(format!("{:#}", (-1.5).seconds()), "-PT1.5S");
+/// assert_eq!(format!("{:#.3}", 1.123_456.seconds()), "PT1.123S");
+/// ```
+///
 /// For the purposes of this implementation, a day is exactly 24 hours and a minute is exactly 60
 /// seconds.
 impl fmt::Display for Duration {
@@ -1073,7 +1716,32 @@ impl fmt::Display for Duration {
             f.write_str("-")?;
         }
 
-        if let Some(_precision) = f.precision() {
+        if f.alternate() {
+            // ISO 8601 representation, without days.
+
+            let components = self
+                .iso8601_components(false, f.precision().map(|precision| precision.min(9) as _));
+
+            f.write_str("PT")?;
+            if components.hours != 0 {
+                write!(f, "{}H", components.hours)?;
+            }
+            if components.minutes != 0 {
+                write!(f, "{}M", components.minutes)?;
+            }
+            if components.has_seconds() {
+                write!(f, "{}", components.seconds)?;
+                if components.digits != 0 {
+                    write!(
+                        f,
+                        ".{:0width$}",
+                        components.fraction,
+                        width = components.digits as usize
+                    )?;
+                }
+                f.write_str("S")?;
+            }
+        } else if let Some(_precision) = f.precision() {
             // Concise, rounded representation.
 
             if self.is_zero() {
@@ -1178,84 +1846,143 @@ impl TryFrom<Duration> for StdDuration {
 impl Add for Duration {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, rhs: Self) -> Self::Output {
-        self.checked_add(rhs)
-            .expect("overflow when adding durations")
+        match self.checked_add(rhs) {
+            Some(duration) => duration,
+            None => crate::arithmetic_overflow("overflow when adding durations", &self, '+', &rhs),
+        }
     }
 }
 
 impl Add<StdDuration> for Duration {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, std_duration: StdDuration) -> Self::Output {
-        self + Self::try_from(std_duration)
-            .expect("overflow converting `std::time::Duration` to `time::Duration`")
+        match Self::try_from(std_duration)
+            .ok()
+            .and_then(|rhs| self.checked_add(rhs))
+        {
+            Some(duration) => duration,
+            None => crate::arithmetic_overflow(
+                "overflow when adding durations",
+                &self,
+                '+',
+                &std_duration,
+            ),
+        }
     }
 }
 
 impl Add<Duration> for StdDuration {
     type Output = Duration;
 
+    #[track_caller]
     fn add(self, rhs: Duration) -> Self::Output {
-        rhs + self
+        match Duration::try_from(self)
+            .ok()
+            .and_then(|lhs| lhs.checked_add(rhs))
+        {
+            Some(duration) => duration,
+            None => crate::arithmetic_overflow("overflow when adding durations", &self, '+', &rhs),
+        }
     }
 }
 
 impl_add_assign!(Duration: Self, StdDuration);
 
 impl AddAssign<Duration> for StdDuration {
+    #[track_caller]
     fn add_assign(&mut self, rhs: Duration) {
-        *self = (*self + rhs).try_into().expect(
-            "Cannot represent a resulting duration in std. Try `let x = x + rhs;`, which will \
-             change the type.",
-        );
+        match (*self + rhs).try_into() {
+            Ok(duration) => *self = duration,
+            Err(_) => crate::arithmetic_overflow(
+                "Cannot represent a resulting duration in std. Try `let x = x + rhs;`, which will \
+                 change the type.",
+                self,
+                '+',
+                &rhs,
+            ),
+        }
     }
 }
 
 impl Neg for Duration {
     type Output = Self;
 
+    #[track_caller]
     fn neg(self) -> Self::Output {
-        Self::new_unchecked(-self.seconds, -self.nanoseconds)
+        match self.seconds.checked_neg() {
+            Some(seconds) => Self::new_unchecked(seconds, -self.nanoseconds),
+            None => crate::unary_overflow("overflow when negating duration", '-', &self),
+        }
     }
 }
 
 impl Sub for Duration {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, rhs: Self) -> Self::Output {
-        self.checked_sub(rhs)
-            .expect("overflow when subtracting durations")
+        match self.checked_sub(rhs) {
+            Some(duration) => duration,
+            None => {
+                crate::arithmetic_overflow("overflow when subtracting durations", &self, '-', &rhs)
+            }
+        }
     }
 }
 
 impl Sub<StdDuration> for Duration {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, rhs: StdDuration) -> Self::Output {
-        self - Self::try_from(rhs)
-            .expect("overflow converting `std::time::Duration` to `time::Duration`")
+        match Self::try_from(rhs)
+            .ok()
+            .and_then(|rhs| self.checked_sub(rhs))
+        {
+            Some(duration) => duration,
+            None => {
+                crate::arithmetic_overflow("overflow when subtracting durations", &self, '-', &rhs)
+            }
+        }
     }
 }
 
 impl Sub<Duration> for StdDuration {
     type Output = Duration;
 
+    #[track_caller]
     fn sub(self, rhs: Duration) -> Self::Output {
-        Duration::try_from(self)
-            .expect("overflow converting `std::time::Duration` to `time::Duration`")
-            - rhs
+        match Duration::try_from(self)
+            .ok()
+            .and_then(|lhs| lhs.checked_sub(rhs))
+        {
+            Some(duration) => duration,
+            None => {
+                crate::arithmetic_overflow("overflow when subtracting durations", &self, '-', &rhs)
+            }
+        }
     }
 }
 
 impl_sub_assign!(Duration: Self, StdDuration);
 
 impl SubAssign<Duration> for StdDuration {
+    #[track_caller]
     fn sub_assign(&mut self, rhs: Duration) {
-        *self = (*self - rhs).try_into().expect(
-            "Cannot represent a resulting duration in std. Try `let x = x - rhs;`, which will \
-             change the type.",
-        );
+        match (*self - rhs).try_into() {
+            Ok(duration) => *self = duration,
+            Err(_) => crate::arithmetic_overflow(
+                "Cannot represent a resulting duration in std. Try `let x = x - rhs;`, which will \
+                 change the type.",
+                self,
+                '-',
+                &rhs,
+            ),
+        }
     }
 }
 
@@ -1265,18 +1992,28 @@ macro_rules! duration_mul_div_int {
         impl Mul<$type> for Duration {
             type Output = Self;
 
+            #[track_caller]
             fn mul(self, rhs: $type) -> Self::Output {
-                Self::nanoseconds_i128(
-                    self.whole_nanoseconds()
-                        .checked_mul(rhs as _)
-                        .expect("overflow when multiplying duration")
-                )
+                match self
+                    .whole_nanoseconds()
+                    .checked_mul(rhs as _)
+                    .and_then(|nanoseconds| Self::checked_from_components(0, 0, 0, 0, nanoseconds))
+                {
+                    Some(duration) => duration,
+                    None => crate::arithmetic_overflow(
+                        "overflow when multiplying duration",
+                        &self,
+                        '*',
+                        &rhs,
+                    ),
+                }
             }
         }
 
         impl Mul<Duration> for $type {
             type Output = Duration;
 
+            #[track_caller]
             fn mul(self, rhs: Duration) -> Self::Output {
                 rhs * self
             }
@@ -1289,6 +2026,8 @@ macro_rules! duration_mul_div_int {
                 Self::nanoseconds_i128(self.whole_nanoseconds() / rhs as i128)
             }
         }
+
+        impl_forward_ref_binop!(Mul mul for $type: Duration);
     )+};
 }
 duration_mul_div_int![i8, i16, i32, u8, u16, u32];
@@ -1412,4 +2151,27 @@ impl<'a> Sum<&'a Self> for Duration {
         iter.copied().sum()
     }
 }
+
+impl_forward_ref_binop!(Add add for Duration: Duration, StdDuration);
+impl_forward_ref_binop!(Add add for StdDuration: Duration);
+impl_forward_ref_binop!(Sub sub for Duration: Duration, StdDuration);
+impl_forward_ref_binop!(Sub sub for StdDuration: Duration);
+impl_forward_ref_binop!(Mul mul for Duration: i8, i16, i32, u8, u16, u32, f32, f64);
+impl_forward_ref_binop!(Mul mul for f32: Duration);
+impl_forward_ref_binop!(Mul mul for f64: Duration);
+impl_forward_ref_binop!(Div div for Duration:
+    i8, i16, i32, u8, u16, u32, f32, f64, Duration, StdDuration,
+);
+impl_forward_ref_binop!(Div div for StdDuration: Duration);
+impl_forward_ref_unop!(Neg neg for Duration);
+impl_forward_ref_op_assign!(AddAssign add_assign for Duration: Duration, StdDuration);
+impl_forward_ref_op_assign!(AddAssign add_assign for StdDuration: Duration);
+impl_forward_ref_op_assign!(SubAssign sub_assign for Duration: Duration, StdDuration);
+impl_forward_ref_op_assign!(SubAssign sub_assign for StdDuration: Duration);
+impl_forward_ref_op_assign!(MulAssign mul_assign for Duration:
+    i8, i16, i32, u8, u16, u32, f32, f64,
+);
+impl_forward_ref_op_assign!(DivAssign div_assign for Duration:
+    i8, i16, i32, u8, u16, u32, f32, f64,
+);
 // endregion trait impls
@@ -2,7 +2,7 @@
 
 use core::fmt;
 
-use crate::error;
+use crate::{error, Month};
 
 /// An error type indicating that a component provided to a method was out of range, causing a
 /// failure.
@@ -17,9 +17,31 @@ pub struct ComponentRange {
     pub(crate) maximum: i64,
     /// Value that was provided.
     pub(crate) value: i64,
-    /// The minimum and/or maximum value is conditional on the value of other
-    /// parameters.
-    pub(crate) conditional_range: bool,
+    /// Whether the minimum and/or maximum value is conditional on the value of other
+    /// parameters, and if so, which ones.
+    pub(crate) conditional_range: ConditionalRange,
+}
+
+/// Whether the range of a [`ComponentRange`] depends on the value of other parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ConditionalRange {
+    /// The range is fixed.
+    No,
+    /// The range depends on other parameters, but they are not known.
+    Yes,
+    /// The range is the number of days in the given month of the given year.
+    DaysInMonth {
+        /// The year of the month.
+        year: i32,
+        /// The month whose length bounds the range.
+        month: Month,
+    },
+    /// The range is the number of days in the given year.
+    DaysInYear(i32),
+    /// The range is the number of ISO weeks in the given ISO year.
+    WeeksInYear(i32),
+    /// The value must not have the opposite sign of the seconds, which are negative if `true`.
+    SignOfSeconds(bool),
 }
 
 impl ComponentRange {
@@ -28,10 +50,32 @@ impl ComponentRange {
         self.name
     }
 
+    /// Obtain the minimum permitted value of the component, inclusive.
+    ///
+    /// If the range is [conditional](Self::is_conditional), this is the minimum computed from
+    /// the values of the other components that were provided.
+    pub const fn minimum(self) -> i64 {
+        self.minimum
+    }
+
+    /// Obtain the maximum permitted value of the component, inclusive.
+    ///
+    /// If the range is [conditional](Self::is_conditional), this is the maximum computed from
+    /// the values of the other components that were provided. For example, the maximum day of
+    /// February 2023 is 28.
+    pub const fn maximum(self) -> i64 {
+        self.maximum
+    }
+
+    /// Obtain the value of the component that was out of range.
+    pub const fn value(self) -> i64 {
+        self.value
+    }
+
     /// Whether the value's permitted range is conditional, i.e. whether an input with this
     /// value could have succeeded if the values of other components were different.
     pub const fn is_conditional(self) -> bool {
-        self.conditional_range
+        !matches!(self.conditional_range, ConditionalRange::No)
     }
 }
 
@@ -43,11 +87,26 @@ impl fmt::Display for ComponentRange {
             self.name, self.minimum, self.maximum
         )?;
 
-        if self.conditional_range {
-            f.write_str(", given values of other parameters")?;
+        match self.conditional_range {
+            ConditionalRange::No => Ok(()),
+            ConditionalRange::Yes => f.write_str(", given values of other parameters"),
+            ConditionalRange::DaysInMonth { year, month } => {
+                write!(f, " because {month} {year} has {} days", self.maximum)
+            }
+            ConditionalRange::DaysInYear(year) => write!(
+                f,
+                " because {year} is {}a leap year",
+                if self.maximum == 366 { "" } else { "not " }
+            ),
+            ConditionalRange::WeeksInYear(year) => {
+                write!(f, " because ISO year {year} has {} weeks", self.maximum)
+            }
+            ConditionalRange::SignOfSeconds(negative) => write!(
+                f,
+                " because seconds is {}",
+                if negative { "negative" } else { "positive" }
+            ),
         }
-
-        Ok(())
     }
 }
 
@@ -1,6 +1,7 @@
 //! Error formatting a struct
 
 use core::fmt;
+#[cfg(feature = "std")]
 use std::io;
 
 use crate::error;
@@ -12,37 +13,99 @@ use crate::error;
 pub enum Format {
     /// The type being formatted does not contain sufficient information to format a component.
     #[non_exhaustive]
-    InsufficientTypeInformation,
+    InsufficientTypeInformation {
+        /// The information that was required, but not present.
+        missing: MissingInformation,
+    },
     /// The component named has a value that cannot be formatted into the requested format.
     ///
     /// This variant is only returned when using well-known formats.
     InvalidComponent(&'static str),
+    /// The format description cannot be used for formatting.
+    ///
+    /// This variant is only returned when using a configuration of
+    /// [`Iso8601`](crate::format_description::well_known::Iso8601) or
+    /// [`Iso8601Dynamic`](crate::format_description::well_known::Iso8601Dynamic) that is only
+    /// intended for parsing, such as [`Iso8601::PARSING`].
+    ///
+    /// [`Iso8601::PARSING`]: crate::format_description::well_known::Iso8601::PARSING
+    InvalidFormatDescription,
     /// A value of `std::io::Error` was returned internally.
+    #[cfg(feature = "std")]
     StdIo(io::Error),
+    /// Writing to a [`fmt::Write`] failed.
+    ///
+    /// This variant is only returned when formatting via a `format_into_fmt` method, or when the
+    /// output is not valid UTF-8 and the `std` feature is not enabled.
+    Fmt(fmt::Error),
+    /// The output did not fit within the fixed capacity of the buffer.
+    ///
+    /// This variant is only returned when formatting to an
+    /// [`ArrayBuf`](crate::formatting::ArrayBuf) or via a `format_into_slice` method.
+    InsufficientCapacity {
+        /// The number of bytes available.
+        capacity: usize,
+        /// The number of bytes needed to hold the output.
+        required: usize,
+    },
+}
+
+/// The information required to format a component that a value may not contain.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingInformation {
+    /// A calendar date.
+    Date,
+    /// A clock time.
+    Time,
+    /// A UTC offset.
+    Offset,
+}
+
+impl fmt::Display for MissingInformation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Date => f.write_str("a date"),
+            Self::Time => f.write_str("a time"),
+            Self::Offset => f.write_str("a UTC offset"),
+        }
+    }
 }
 
 impl fmt::Display for Format {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::InsufficientTypeInformation => f.write_str(
+            Self::InsufficientTypeInformation { missing } => write!(
+                f,
                 "The type being formatted does not contain sufficient information to format a \
-                 component.",
+                 component: {missing} is required."
             ),
             Self::InvalidComponent(component) => write!(
                 f,
                 "The {component} component cannot be formatted into the requested format."
             ),
+            Self::InvalidFormatDescription => {
+                f.write_str("The format description cannot be used for formatting.")
+            }
+            #[cfg(feature = "std")]
             Self::StdIo(err) => err.fmt(f),
+            Self::Fmt(err) => err.fmt(f),
+            Self::InsufficientCapacity { capacity, required } => write!(
+                f,
+                "The output requires {required} bytes, but only {capacity} are available."
+            ),
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl From<io::Error> for Format {
     fn from(err: io::Error) -> Self {
         Self::StdIo(err)
     }
 }
 
+#[cfg(feature = "std")]
 impl TryFrom<Format> for io::Error {
     type Error = error::DifferentVariant;
 
@@ -58,8 +121,12 @@ impl TryFrom<Format> for io::Error {
 impl std::error::Error for Format {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match *self {
-            Self::InsufficientTypeInformation | Self::InvalidComponent(_) => None,
+            Self::InsufficientTypeInformation { .. }
+            | Self::InvalidComponent(_)
+            | Self::InvalidFormatDescription
+            | Self::InsufficientCapacity { .. } => None,
             Self::StdIo(ref err) => Some(err),
+            Self::Fmt(ref err) => Some(err),
         }
     }
 }
@@ -63,6 +63,22 @@ pub enum InvalidFormatDescription {
         /// The zero-based index the error occurred at.
         index: usize,
     },
+    /// A nested format description was more deeply nested than permitted.
+    #[non_exhaustive]
+    NestingTooDeep {
+        /// The maximum depth that is permitted.
+        max_depth: usize,
+        /// The zero-based index of the opening bracket of the nested format description.
+        index: usize,
+    },
+    /// The format description contained more items than permitted.
+    #[non_exhaustive]
+    TooManyItems {
+        /// The maximum number of items that is permitted.
+        max_items: usize,
+        /// The zero-based index the first item beyond the limit starts at.
+        index: usize,
+    },
 }
 
 impl From<InvalidFormatDescription> for crate::Error {
@@ -120,6 +136,20 @@ impl fmt::Display for InvalidFormatDescription {
                     "{what} is not supported in {context} at byte index {index}"
                 )
             }
+            NestingTooDeep { max_depth, index } => {
+                write!(
+                    f,
+                    "nested format description exceeds the maximum depth of {max_depth} at byte \
+                     index {index}"
+                )
+            }
+            TooManyItems { max_items, index } => {
+                write!(
+                    f,
+                    "format description exceeds the maximum of {max_items} items at byte index \
+                     {index}"
+                )
+            }
         }
     }
 }
@@ -3,11 +3,11 @@
 mod component_range;
 mod conversion_range;
 mod different_variant;
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 mod format;
 #[cfg(feature = "local-offset")]
 mod indeterminate_offset;
-#[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+#[cfg(all(any(feature = "formatting-core", feature = "parsing"), feature = "alloc"))]
 mod invalid_format_description;
 mod invalid_variant;
 #[cfg(feature = "parsing")]
@@ -20,13 +20,14 @@ mod try_from_parsed;
 use core::fmt;
 
 pub use component_range::ComponentRange;
+pub(crate) use component_range::ConditionalRange;
 pub use conversion_range::ConversionRange;
 pub use different_variant::DifferentVariant;
-#[cfg(feature = "formatting")]
-pub use format::Format;
+#[cfg(feature = "formatting-core")]
+pub use format::{Format, MissingInformation};
 #[cfg(feature = "local-offset")]
 pub use indeterminate_offset::IndeterminateOffset;
-#[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+#[cfg(all(any(feature = "formatting-core", feature = "parsing"), feature = "alloc"))]
 pub use invalid_format_description::InvalidFormatDescription;
 pub use invalid_variant::InvalidVariant;
 #[cfg(feature = "parsing")]
@@ -34,7 +35,7 @@ pub use parse::Parse;
 #[cfg(feature = "parsing")]
 pub use parse_from_description::ParseFromDescription;
 #[cfg(feature = "parsing")]
-pub use try_from_parsed::TryFromParsed;
+pub use try_from_parsed::{MissingComponents, TryFromParsed};
 
 /// A unified error type for anything returned by a method in the time crate.
 ///
@@ -49,7 +50,7 @@ pub enum Error {
     ComponentRange(ComponentRange),
     #[cfg(feature = "local-offset")]
     IndeterminateOffset(IndeterminateOffset),
-    #[cfg(feature = "formatting")]
+    #[cfg(feature = "formatting-core")]
     Format(Format),
     #[cfg(feature = "parsing")]
     ParseFromDescription(ParseFromDescription),
@@ -58,7 +59,7 @@ pub enum Error {
     UnexpectedTrailingCharacters,
     #[cfg(feature = "parsing")]
     TryFromParsed(TryFromParsed),
-    #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+    #[cfg(all(any(feature = "formatting-core", feature = "parsing"), feature = "alloc"))]
     InvalidFormatDescription(InvalidFormatDescription),
     DifferentVariant(DifferentVariant),
     InvalidVariant(InvalidVariant),
@@ -71,7 +72,7 @@ impl fmt::Display for Error {
             Self::ComponentRange(e) => e.fmt(f),
             #[cfg(feature = "local-offset")]
             Self::IndeterminateOffset(e) => e.fmt(f),
-            #[cfg(feature = "formatting")]
+            #[cfg(feature = "formatting-core")]
             Self::Format(e) => e.fmt(f),
             #[cfg(feature = "parsing")]
             Self::ParseFromDescription(e) => e.fmt(f),
@@ -79,7 +80,7 @@ impl fmt::Display for Error {
             Self::UnexpectedTrailingCharacters => f.write_str("unexpected trailing characters"),
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(e) => e.fmt(f),
-            #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+            #[cfg(all(any(feature = "formatting-core", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(e) => e.fmt(f),
             Self::DifferentVariant(e) => e.fmt(f),
             Self::InvalidVariant(e) => e.fmt(f),
@@ -95,7 +96,7 @@ impl std::error::Error for Error {
             Self::ComponentRange(err) => Some(err),
             #[cfg(feature = "local-offset")]
             Self::IndeterminateOffset(err) => Some(err),
-            #[cfg(feature = "formatting")]
+            #[cfg(feature = "formatting-core")]
             Self::Format(err) => Some(err),
             #[cfg(feature = "parsing")]
             Self::ParseFromDescription(err) => Some(err),
@@ -103,7 +104,7 @@ impl std::error::Error for Error {
             Self::UnexpectedTrailingCharacters => None,
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(err) => Some(err),
-            #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+            #[cfg(all(any(feature = "formatting-core", feature = "parsing"), feature = "alloc"))]
             Self::InvalidFormatDescription(err) => Some(err),
             Self::DifferentVariant(err) => Some(err),
             Self::InvalidVariant(err) => Some(err),
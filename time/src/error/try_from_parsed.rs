@@ -9,10 +9,148 @@ use crate::error;
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TryFromParsed {
     /// The [`Parsed`](crate::parsing::Parsed) did not include enough information to construct the
-    /// type.
+    /// type. The [`Parsed`](crate::parsing::Parsed) methods such as
+    /// [`missing_date_components`](crate::parsing::Parsed::missing_date_components) report which
+    /// components were absent.
     InsufficientInformation,
     /// Some component contained an invalid value for the type.
     ComponentRange(error::ComponentRange),
+    /// Two components describing the same value in different ways disagree with one another, such
+    /// as a weekday that does not match the calendar date.
+    ConflictingComponents {
+        /// The name of the component that was used to construct the value.
+        a: &'static str,
+        /// The name of the component that disagrees with it.
+        b: &'static str,
+    },
+}
+
+/// The names of the components that may be reported as missing, in the order of their bits in
+/// [`MissingComponents`].
+const COMPONENT_NAMES: [&str; 10] = [
+    "year",
+    "month",
+    "day",
+    "iso_year",
+    "weekday",
+    "hour_24",
+    "hour_12",
+    "hour_12_is_pm",
+    "minute",
+    "offset_hour",
+];
+
+/// The components of a [`Parsed`](crate::parsing::Parsed) that are required to construct a value,
+/// but are not present.
+///
+/// Each component is named after the corresponding method on `Parsed`, such as `year` or
+/// `offset_hour`. When a value can be constructed from several combinations of components, such as
+/// a date from either the calendar date or the ordinal date, the components of the combination
+/// that is closest to being complete are reported.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MissingComponents {
+    /// One bit for each entry of [`COMPONENT_NAMES`].
+    bits: u16,
+}
+
+impl MissingComponents {
+    /// No components are missing.
+    pub(crate) const NONE: Self = Self { bits: 0 };
+
+    /// Create a value with only the component at the provided index of [`COMPONENT_NAMES`].
+    const fn from_index(idx: u8) -> Self {
+        Self { bits: 1 << idx }
+    }
+
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const YEAR: Self = Self::from_index(0);
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const MONTH: Self = Self::from_index(1);
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const DAY: Self = Self::from_index(2);
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const ISO_YEAR: Self = Self::from_index(3);
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const WEEKDAY: Self = Self::from_index(4);
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const HOUR_24: Self = Self::from_index(5);
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const HOUR_12: Self = Self::from_index(6);
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const HOUR_12_IS_PM: Self = Self::from_index(7);
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const MINUTE: Self = Self::from_index(8);
+    #[allow(clippy::missing_docs_in_private_items)]
+    pub(crate) const OFFSET_HOUR: Self = Self::from_index(9);
+
+    /// Combine the missing components of both values.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// let parsed = Parsed::new();
+    /// let missing = parsed
+    ///     .missing_date_components()
+    ///     .union(parsed.missing_time_components());
+    /// assert!(missing.iter().eq(["year", "month", "day", "hour_24", "minute"]));
+    /// ```
+    pub const fn union(self, other: Self) -> Self {
+        Self {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Include `component` if `is_missing` is true.
+    pub(crate) const fn union_if(self, component: Self, is_missing: bool) -> Self {
+        if is_missing {
+            self.union(component)
+        } else {
+            self
+        }
+    }
+
+    /// Whether no components are missing.
+    pub const fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Whether the component with the provided name is missing.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// let missing = Parsed::new().missing_time_components();
+    /// assert!(missing.contains("minute"));
+    /// assert!(!missing.contains("second"));
+    /// ```
+    pub fn contains(self, component: &str) -> bool {
+        self.iter().any(|name| name == component)
+    }
+
+    /// Obtain an iterator over the names of the missing components.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// let missing = Parsed::new().missing_time_components();
+    /// assert!(missing.iter().eq(["hour_24", "minute"]));
+    /// ```
+    pub fn iter(self) -> impl Iterator<Item = &'static str> {
+        COMPONENT_NAMES
+            .iter()
+            .enumerate()
+            .filter(move |&(idx, _)| self.bits & (1 << idx) != 0)
+            .map(|(_, &name)| name)
+    }
+}
+
+impl fmt::Display for MissingComponents {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, name) in self.iter().enumerate() {
+            if idx != 0 {
+                f.write_str(", ")?;
+            }
+            write!(f, "`{name}`")?;
+        }
+        Ok(())
+    }
 }
 
 impl fmt::Display for TryFromParsed {
@@ -22,6 +160,12 @@ impl fmt::Display for TryFromParsed {
                 "the `Parsed` struct did not include enough information to construct the type",
             ),
             Self::ComponentRange(err) => err.fmt(f),
+            Self::ConflictingComponents { a, b } => {
+                write!(
+                    f,
+                    "the `{a}` and `{b}` components conflict with one another"
+                )
+            }
         }
     }
 }
@@ -47,7 +191,7 @@ impl TryFrom<TryFromParsed> for error::ComponentRange {
 impl std::error::Error for TryFromParsed {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
-            Self::InsufficientInformation => None,
+            Self::InsufficientInformation | Self::ConflictingComponents { .. } => None,
             Self::ComponentRange(err) => Some(err),
         }
     }
@@ -1,9 +1,13 @@
-//! Extension traits.
+//! Extension traits, and helpers for common compositions of the crate's types.
 
 use core::time::Duration as StdDuration;
 
 use crate::convert::*;
+#[cfg(feature = "parsing")]
+use crate::format_description::well_known::Rfc7231;
 use crate::Duration;
+#[cfg(feature = "parsing")]
+use crate::{error, OffsetDateTime};
 
 /// Sealed trait to prevent downstream implementations.
 mod sealed {
@@ -278,3 +282,87 @@ impl NumericalStdDuration for f64 {
     }
 }
 // endregion NumericalStdDuration
+
+// region: Retry-After
+/// The value of an HTTP `Retry-After` header, as described in
+/// [RFC 7231](https://datatracker.ietf.org/doc/html/rfc7231#section-7.1.3).
+///
+/// The header is either a number of seconds to wait or an HTTP date. To obtain the time after
+/// which to retry regardless of the form used, see [`parse_retry_after`].
+#[cfg(feature = "parsing")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetryAfter {
+    /// The number of seconds to wait, which is never negative.
+    Delay(Duration),
+    /// The time after which to retry, which always has a UTC offset.
+    Date(OffsetDateTime),
+}
+
+#[cfg(feature = "parsing")]
+impl RetryAfter {
+    /// Parse the value of a `Retry-After` header, keeping the form that was used.
+    ///
+    /// Leading and trailing whitespace is ignored. A value beginning with a digit or sign is
+    /// parsed as a number of seconds, which must consist only of digits; anything else is parsed
+    /// as [`Rfc7231`].
+    ///
+    /// ```rust
+    /// # use time::ext::{NumericalDuration, RetryAfter};
+    /// # use time_macros::datetime;
+    /// assert_eq!(RetryAfter::parse("120")?, RetryAfter::Delay(2.minutes()));
+    /// assert_eq!(
+    ///     RetryAfter::parse("Fri, 31 Dec 1999 23:59:59 GMT")?,
+    ///     RetryAfter::Date(datetime!(1999-12-31 23:59:59 UTC))
+    /// );
+    /// assert!(RetryAfter::parse("-120").is_err());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(value: &str) -> Result<Self, error::Parse> {
+        use crate::error::ParseFromDescription::InvalidComponent;
+
+        let value = value.trim_matches([' ', '\t']);
+        if !value.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+') {
+            return OffsetDateTime::parse(value, &Rfc7231).map(Self::Date);
+        }
+
+        if !value.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Err(InvalidComponent("delta seconds").into());
+        }
+        match value.parse() {
+            Ok(seconds) => Ok(Self::Delay(Duration::seconds(seconds))),
+            Err(_) => Err(InvalidComponent("delta seconds").into()),
+        }
+    }
+}
+
+/// Parse the value of an HTTP `Retry-After` header, returning the time after which to retry.
+///
+/// A delay is added to `now`, while a date is returned as-is. See [`RetryAfter::parse`] for the
+/// accepted input. An error is also returned if adding the delay to `now` overflows.
+///
+/// ```rust
+/// # use time::ext::parse_retry_after;
+/// # use time_macros::datetime;
+/// let now = datetime!(1999-12-31 23:57:59 UTC);
+/// assert_eq!(
+///     parse_retry_after(" 120 ", now)?,
+///     datetime!(1999-12-31 23:59:59 UTC)
+/// );
+/// assert_eq!(
+///     parse_retry_after("Fri, 31 Dec 1999 23:59:59 GMT", now)?,
+///     datetime!(1999-12-31 23:59:59 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[cfg(feature = "parsing")]
+pub fn parse_retry_after(value: &str, now: OffsetDateTime) -> Result<OffsetDateTime, error::Parse> {
+    use crate::error::ParseFromDescription::InvalidComponent;
+
+    match RetryAfter::parse(value)? {
+        RetryAfter::Delay(delay) => now
+            .checked_add(delay)
+            .ok_or_else(|| InvalidComponent("delta seconds").into()),
+        RetryAfter::Date(date) => Ok(date),
+    }
+}
+// endregion Retry-After
@@ -2,8 +2,7 @@
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
-#[cfg(feature = "alloc")]
-use core::fmt;
+use core::{fmt, str};
 
 use crate::error;
 use crate::format_description::Component;
@@ -11,7 +10,7 @@ use crate::format_description::Component;
 /// A complete description of how to format and parse a type.
 #[non_exhaustive]
 #[cfg_attr(not(feature = "alloc"), derive(Debug))]
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone, PartialEq, Eq, Hash)]
 pub enum BorrowedFormatItem<'a> {
     /// Bytes that are formatted as-is.
     ///
@@ -29,8 +28,9 @@ pub enum BorrowedFormatItem<'a> {
     /// This variant has no effect on formatting, as the value is guaranteed to be present.
     Optional(&'a Self),
     /// A series of `FormatItem`s where, when parsing, the first successful parse is used. When
-    /// formatting, the first element of the slice is used.  An empty slice is a no-op when
-    /// formatting or parsing.
+    /// formatting, the first element of the slice that can be formatted is used; if none can be,
+    /// the error of the last one is returned. An empty slice is a no-op when formatting or
+    /// parsing.
     First(&'a [Self]),
 }
 
@@ -47,6 +47,66 @@ impl fmt::Debug for BorrowedFormatItem<'_> {
     }
 }
 
+impl fmt::Display for BorrowedFormatItem<'_> {
+    /// Write the item in the syntax accepted by version 2 of
+    /// [`parse_owned`](crate::format_description::parse_owned). Parsing the output results in an
+    /// item that is equal to this one once converted to an
+    /// [`OwnedFormatItem`](crate::format_description::OwnedFormatItem).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Literal(literal) => write_literal(f, literal),
+            Self::Component(component) => fmt::Display::fmt(&component, f),
+            Self::Compound(items) => items.iter().try_for_each(|item| fmt::Display::fmt(item, f)),
+            Self::Optional(item) => write!(f, "[optional [{item}]]"),
+            Self::First(items) => {
+                f.write_str("[first ")?;
+                for (idx, item) in items.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "[{item}]")?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
+/// Write a literal as it would appear in a format description, escaping any brackets and
+/// backslashes. Invalid UTF-8 is replaced with U+FFFD, as it cannot be represented.
+pub(super) fn write_literal(f: &mut fmt::Formatter<'_>, mut literal: &[u8]) -> fmt::Result {
+    loop {
+        let (valid, rest) = match str::from_utf8(literal) {
+            Ok(valid) => (valid, None),
+            Err(err) => {
+                let (valid, invalid) = literal.split_at(err.valid_up_to());
+                let invalid_len = err.error_len().unwrap_or(invalid.len());
+                (
+                    str::from_utf8(valid).unwrap_or_default(),
+                    Some(&invalid[invalid_len..]),
+                )
+            }
+        };
+
+        let mut last_idx = 0;
+        for (idx, special) in valid.match_indices(['\\', '[', ']']) {
+            f.write_str(&valid[last_idx..idx])?;
+            f.write_str("\\")?;
+            f.write_str(special)?;
+            last_idx = idx + 1;
+        }
+        f.write_str(&valid[last_idx..])?;
+
+        match rest {
+            Some(rest) => {
+                f.write_str("\u{fffd}")?;
+                literal = rest;
+            }
+            None => return Ok(()),
+        }
+    }
+}
+
 impl From<Component> for BorrowedFormatItem<'_> {
     fn from(component: Component) -> Self {
         Self::Component(component)
@@ -0,0 +1,208 @@
+//! A format description lowered to a flat sequence of operations.
+
+use alloc::boxed::Box;
+use alloc::vec::Vec;
+use core::mem;
+
+use crate::format_description::item_view::{AsItemView, ItemView};
+use crate::format_description::{Component, FormatItem, OwnedFormatItem};
+
+/// A single operation of a [`CompiledFormat`].
+///
+/// Sections (optional items and alternatives) are stored inline, with the number of operations
+/// they span recorded in the operation that opens them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum FormatOp {
+    /// Bytes that are formatted as-is. Adjacent literals are merged.
+    Literal(Box<[u8]>),
+    /// A single non-literal item.
+    Component(Component),
+    /// An optional section spanning the next `len` operations.
+    Optional {
+        /// The number of operations in the section.
+        len: usize,
+        /// The components that are formatted as part of the section. If any of these is omitted,
+        /// the entire section is omitted when formatting.
+        components: Box<[Component]>,
+    },
+    /// A series of alternatives spanning the next `len` operations. Each alternative begins with
+    /// [`FormatOp::Alternative`].
+    First {
+        /// The number of operations in all alternatives, including their
+        /// [`FormatOp::Alternative`] markers.
+        len: usize,
+    },
+    /// A single alternative of [`FormatOp::First`] spanning the next `len` operations.
+    Alternative {
+        /// The number of operations in the alternative.
+        len: usize,
+    },
+}
+
+/// A format description compiled to a flat sequence of operations.
+///
+/// Formatting and parsing with a [`FormatItem`] or [`OwnedFormatItem`] walks the tree of items
+/// each time. A `CompiledFormat` flattens compound items and merges adjacent literals ahead of
+/// time, so it is the recommended type when the same description is used repeatedly, such as in a
+/// hot loop. The output of formatting and the result of parsing are identical to those of the
+/// description it was created from.
+///
+/// ```rust
+/// # use time::format_description::CompiledFormat;
+/// # use time::macros::{datetime, format_description};
+/// let format = CompiledFormat::from(format_description!(
+///     "[year]-[month]-[day] [hour]:[minute][optional [:[second]]]"
+/// ));
+/// assert_eq!(
+///     datetime!(2023-05-10 12:34:56).format(&format)?,
+///     "2023-05-10 12:34:56"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompiledFormat {
+    /// The operations, in the order they are performed.
+    pub(crate) ops: Box<[FormatOp]>,
+}
+
+impl CompiledFormat {
+    /// Compile the provided items, which are treated as a single compound item.
+    fn compile<T: AsItemView>(items: &[T]) -> Self {
+        let mut compiler = Compiler {
+            ops: Vec::new(),
+            literal: Vec::new(),
+        };
+        for item in items {
+            compiler.item(item);
+        }
+        compiler.flush_literal();
+        Self {
+            ops: compiler.ops.into_boxed_slice(),
+        }
+    }
+}
+
+/// State used while lowering format items into a [`CompiledFormat`].
+struct Compiler {
+    /// The operations emitted so far.
+    ops: Vec<FormatOp>,
+    /// Literal bytes that have not yet been emitted.
+    literal: Vec<u8>,
+}
+
+impl Compiler {
+    /// Emit any pending literal bytes as a single operation.
+    fn flush_literal(&mut self) {
+        if !self.literal.is_empty() {
+            let literal = mem::take(&mut self.literal).into_boxed_slice();
+            self.ops.push(FormatOp::Literal(literal));
+        }
+    }
+
+    /// Emit a section opened by `op`, whose contents are emitted by `f`. The index of `op` and the
+    /// number of operations in the section are returned so that `op` can be updated.
+    fn section(&mut self, op: FormatOp, f: impl FnOnce(&mut Self)) -> (usize, usize) {
+        self.flush_literal();
+        let start = self.ops.len();
+        self.ops.push(op);
+        f(self);
+        self.flush_literal();
+        (start, self.ops.len() - start - 1)
+    }
+
+    /// Lower a single item.
+    fn item<T: AsItemView>(&mut self, item: &T) {
+        match item.view() {
+            ItemView::Literal(literal) => self.literal.extend_from_slice(literal),
+            ItemView::Component(component) => {
+                self.flush_literal();
+                self.ops.push(FormatOp::Component(component));
+            }
+            ItemView::Compound(items) => {
+                for item in items {
+                    self.item(item);
+                }
+            }
+            ItemView::Optional(item) => {
+                let op = FormatOp::Optional {
+                    len: 0,
+                    components: Box::new([]),
+                };
+                let (start, len) = self.section(op, |this| this.item(item));
+                let mut components = Vec::new();
+                formatted_components(item, &mut components);
+                self.ops[start] = FormatOp::Optional {
+                    len,
+                    components: components.into_boxed_slice(),
+                };
+            }
+            ItemView::First(items) => {
+                let (start, len) = self.section(FormatOp::First { len: 0 }, |this| {
+                    for item in items {
+                        let (start, len) =
+                            this.section(FormatOp::Alternative { len: 0 }, |this| this.item(item));
+                        this.ops[start] = FormatOp::Alternative { len };
+                    }
+                });
+                self.ops[start] = FormatOp::First { len };
+            }
+        }
+    }
+}
+
+/// Collect the components that are formatted as part of the item. Components within a nested
+/// optional item or an alternative other than the first are never formatted as part of it.
+fn formatted_components<T: AsItemView>(item: &T, components: &mut Vec<Component>) {
+    match item.view() {
+        ItemView::Literal(_) | ItemView::Optional(_) => {}
+        ItemView::Component(component) => components.push(component),
+        ItemView::Compound(items) => {
+            for item in items {
+                formatted_components(item, components);
+            }
+        }
+        ItemView::First(items) => {
+            if let Some(item) = items.first() {
+                formatted_components(item, components);
+            }
+        }
+    }
+}
+
+// region: conversions
+impl From<FormatItem<'_>> for CompiledFormat {
+    fn from(item: FormatItem<'_>) -> Self {
+        Self::compile(&[item])
+    }
+}
+
+impl From<&FormatItem<'_>> for CompiledFormat {
+    fn from(item: &FormatItem<'_>) -> Self {
+        Self::compile(core::slice::from_ref(item))
+    }
+}
+
+impl<'a, T: AsRef<[FormatItem<'a>]> + ?Sized> From<&T> for CompiledFormat {
+    fn from(items: &T) -> Self {
+        Self::compile(items.as_ref())
+    }
+}
+
+impl From<OwnedFormatItem> for CompiledFormat {
+    fn from(item: OwnedFormatItem) -> Self {
+        (&item).into()
+    }
+}
+
+impl From<&OwnedFormatItem> for CompiledFormat {
+    fn from(item: &OwnedFormatItem) -> Self {
+        Self::compile(core::slice::from_ref(item))
+    }
+}
+
+impl From<Vec<OwnedFormatItem>> for CompiledFormat {
+    fn from(items: Vec<OwnedFormatItem>) -> Self {
+        Self::compile(&items)
+    }
+}
+// endregion conversions
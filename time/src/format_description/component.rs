@@ -1,10 +1,25 @@
 //! Part of a format description.
 
+use core::fmt;
+#[cfg(feature = "parsing")]
+use core::num::NonZeroU8;
+
+#[cfg(feature = "parsing")]
+use crate::error;
 use crate::format_description::modifier;
+#[cfg(feature = "parsing")]
+use crate::parsing::component::{
+    parse_day, parse_hour, parse_ignore, parse_minute, parse_month, parse_offset,
+    parse_offset_hour, parse_offset_minute, parse_offset_second, parse_ordinal, parse_period,
+    parse_second, parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday,
+    parse_year,
+};
+#[cfg(feature = "parsing")]
+use crate::parsing::{ParsedComponentValue, ParsedItem};
 
 /// A component of a larger format description.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Component {
     /// Day of the month.
     Day(modifier::Day),
@@ -34,8 +49,340 @@ pub enum Component {
     OffsetMinute(modifier::OffsetMinute),
     /// Second within the minute of the UTC offset.
     OffsetSecond(modifier::OffsetSecond),
+    /// Hour and minute of the UTC offset.
+    Offset(modifier::Offset),
     /// A number of bytes to ignore when parsing. This has no effect on formatting.
     Ignore(modifier::Ignore),
     /// A Unix timestamp.
     UnixTimestamp(modifier::UnixTimestamp),
 }
+
+#[cfg(feature = "parsing")]
+impl Component {
+    /// Parse the value of this component from the start of the input. On success, the value is
+    /// returned along with the remaining input.
+    ///
+    /// This accepts exactly what parsing a format description containing only this component would
+    /// accept, but does not require a [`Parsed`](crate::parsing::Parsed) struct or a target type.
+    ///
+    /// ```rust
+    /// # use time::format_description::{modifier, Component};
+    /// # use time::parsing::ParsedComponentValue;
+    /// # use time::Month;
+    /// let mut month = modifier::Month::default();
+    /// month.repr = modifier::MonthRepr::Long;
+    /// assert_eq!(
+    ///     Component::Month(month).parse_value(b"January 1st")?,
+    ///     (ParsedComponentValue::Month(Month::January), &b" 1st"[..])
+    /// );
+    /// assert!(Component::Month(month).parse_value(b"Jan").is_err());
+    /// # Ok::<_, time::error::ParseFromDescription>(())
+    /// ```
+    pub fn parse_value(
+        self,
+        input: &[u8],
+    ) -> Result<(ParsedComponentValue, &[u8]), error::ParseFromDescription> {
+        use error::ParseFromDescription::InvalidComponent;
+
+        let ParsedItem(remaining, value) = match self {
+            Self::Day(modifiers) => parse_day(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::Day))
+                .ok_or(InvalidComponent("day"))?,
+            Self::Month(modifiers) => parse_month(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::Month))
+                .ok_or(InvalidComponent("month"))?,
+            Self::Ordinal(modifiers) => parse_ordinal(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::Ordinal))
+                .ok_or(InvalidComponent("ordinal"))?,
+            Self::Weekday(modifiers) => parse_weekday(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::Weekday))
+                .ok_or(InvalidComponent("weekday"))?,
+            Self::WeekNumber(modifiers) => parse_week_number(input, modifiers)
+                .and_then(|item| {
+                    item.flat_map(|value| match modifiers.repr {
+                        modifier::WeekNumberRepr::Iso => NonZeroU8::new(value).map(NonZeroU8::get),
+                        modifier::WeekNumberRepr::Sunday | modifier::WeekNumberRepr::Monday => {
+                            Some(value)
+                        }
+                    })
+                })
+                .map(|item| item.map(ParsedComponentValue::WeekNumber))
+                .ok_or(InvalidComponent("week number"))?,
+            Self::Year(modifiers) => parse_year(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::Year))
+                .ok_or(InvalidComponent("year"))?,
+            Self::Hour(modifiers) => parse_hour(input, modifiers)
+                .and_then(|item| {
+                    item.flat_map(|value| {
+                        if modifiers.is_12_hour_clock {
+                            NonZeroU8::new(value).map(NonZeroU8::get)
+                        } else {
+                            Some(value)
+                        }
+                    })
+                })
+                .map(|item| item.map(ParsedComponentValue::Hour))
+                .ok_or(InvalidComponent("hour"))?,
+            Self::Minute(modifiers) => parse_minute(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::Minute))
+                .ok_or(InvalidComponent("minute"))?,
+            Self::Period(modifiers) => parse_period(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::Period))
+                .ok_or(InvalidComponent("period"))?,
+            Self::Second(modifiers) => parse_second(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::Second))
+                .ok_or(InvalidComponent("second"))?,
+            Self::Subsecond(modifiers) => parse_subsecond(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::Subsecond))
+                .ok_or(InvalidComponent("subsecond"))?,
+            Self::OffsetHour(modifiers) => parse_offset_hour(input, modifiers)
+                .map(|item| {
+                    item.map(|(value, is_negative)| ParsedComponentValue::OffsetHour {
+                        value,
+                        is_negative,
+                    })
+                })
+                .ok_or(InvalidComponent("offset hour"))?,
+            Self::OffsetMinute(modifiers) => parse_offset_minute(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::OffsetMinute))
+                .ok_or(InvalidComponent("offset minute"))?,
+            Self::OffsetSecond(modifiers) => parse_offset_second(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::OffsetSecond))
+                .ok_or(InvalidComponent("offset second"))?,
+            Self::Offset(modifiers) => parse_offset(input, modifiers)
+                .map(|item| {
+                    item.map(|(hour, minute, is_negative)| ParsedComponentValue::Offset {
+                        hour,
+                        minute,
+                        is_negative,
+                    })
+                })
+                .ok_or(InvalidComponent("offset"))?,
+            Self::Ignore(modifiers) => parse_ignore(input, modifiers)
+                .map(|item| item.map(|()| ParsedComponentValue::Ignore))
+                .ok_or(InvalidComponent("ignore"))?,
+            Self::UnixTimestamp(modifiers) => parse_unix_timestamp(input, modifiers)
+                .map(|item| item.map(ParsedComponentValue::UnixTimestamp))
+                .ok_or(InvalidComponent("unix_timestamp"))?,
+        };
+        Ok((value, remaining))
+    }
+}
+
+impl fmt::Display for Component {
+    /// Write the component in the syntax accepted by
+    /// [`parse_owned`](crate::format_description::parse_owned). Modifiers that have their default
+    /// value are omitted.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::Day(modifier::Day { padding, repr }) => {
+                f.write_str("[day")?;
+                write_padding(f, padding)?;
+                match repr {
+                    modifier::DayRepr::Numerical => {}
+                    modifier::DayRepr::Ordinal => f.write_str(" repr:ordinal")?,
+                }
+            }
+            Self::Month(modifier::Month {
+                padding,
+                repr,
+                case_sensitive,
+            }) => {
+                f.write_str("[month")?;
+                write_padding(f, padding)?;
+                match repr {
+                    modifier::MonthRepr::Numerical => {}
+                    modifier::MonthRepr::Long => f.write_str(" repr:long")?,
+                    modifier::MonthRepr::Short => f.write_str(" repr:short")?,
+                    modifier::MonthRepr::CustomNames(_) => f.write_str(" repr:custom")?,
+                }
+                if !case_sensitive {
+                    f.write_str(" case_sensitive:false")?;
+                }
+            }
+            Self::Ordinal(modifier::Ordinal { padding }) => {
+                f.write_str("[ordinal")?;
+                write_padding(f, padding)?;
+            }
+            Self::Weekday(modifier::Weekday {
+                repr,
+                one_indexed,
+                case_sensitive,
+            }) => {
+                f.write_str("[weekday")?;
+                match repr {
+                    modifier::WeekdayRepr::Short => f.write_str(" repr:short")?,
+                    modifier::WeekdayRepr::Long => {}
+                    modifier::WeekdayRepr::Sunday => f.write_str(" repr:sunday")?,
+                    modifier::WeekdayRepr::Monday => f.write_str(" repr:monday")?,
+                    modifier::WeekdayRepr::CustomNames(_) => f.write_str(" repr:custom")?,
+                }
+                if !one_indexed {
+                    f.write_str(" one_indexed:false")?;
+                }
+                if !case_sensitive {
+                    f.write_str(" case_sensitive:false")?;
+                }
+            }
+            Self::WeekNumber(modifier::WeekNumber { padding, repr }) => {
+                f.write_str("[week_number")?;
+                write_padding(f, padding)?;
+                match repr {
+                    modifier::WeekNumberRepr::Iso => {}
+                    modifier::WeekNumberRepr::Sunday => f.write_str(" repr:sunday")?,
+                    modifier::WeekNumberRepr::Monday => f.write_str(" repr:monday")?,
+                }
+            }
+            Self::Year(modifier::Year {
+                padding,
+                repr,
+                iso_week_based,
+                sign_is_mandatory,
+            }) => {
+                f.write_str("[year")?;
+                write_padding(f, padding)?;
+                match repr {
+                    modifier::YearRepr::Full => {}
+                    modifier::YearRepr::LastTwo => f.write_str(" repr:last_two")?,
+                }
+                if iso_week_based {
+                    f.write_str(" base:iso_week")?;
+                }
+                write_sign(f, sign_is_mandatory)?;
+            }
+            Self::Hour(modifier::Hour {
+                padding,
+                is_12_hour_clock,
+            }) => {
+                f.write_str("[hour")?;
+                write_padding(f, padding)?;
+                if is_12_hour_clock {
+                    f.write_str(" repr:12")?;
+                }
+            }
+            Self::Minute(modifier::Minute { padding }) => {
+                f.write_str("[minute")?;
+                write_padding(f, padding)?;
+            }
+            Self::Period(modifier::Period {
+                is_uppercase,
+                case_sensitive,
+            }) => {
+                f.write_str("[period")?;
+                if !is_uppercase {
+                    f.write_str(" case:lower")?;
+                }
+                if !case_sensitive {
+                    f.write_str(" case_sensitive:false")?;
+                }
+            }
+            Self::Second(modifier::Second { padding }) => {
+                f.write_str("[second")?;
+                write_padding(f, padding)?;
+            }
+            Self::Subsecond(modifier::Subsecond { digits }) => {
+                f.write_str("[subsecond")?;
+                let digits = match digits {
+                    modifier::SubsecondDigits::One => "1",
+                    modifier::SubsecondDigits::Two => "2",
+                    modifier::SubsecondDigits::Three => "3",
+                    modifier::SubsecondDigits::Four => "4",
+                    modifier::SubsecondDigits::Five => "5",
+                    modifier::SubsecondDigits::Six => "6",
+                    modifier::SubsecondDigits::Seven => "7",
+                    modifier::SubsecondDigits::Eight => "8",
+                    modifier::SubsecondDigits::Nine => "9",
+                    modifier::SubsecondDigits::OneOrMore => "",
+                };
+                if !digits.is_empty() {
+                    write!(f, " digits:{digits}")?;
+                }
+            }
+            Self::OffsetHour(modifier::OffsetHour {
+                sign_is_mandatory,
+                padding,
+                z_when_utc,
+            }) => {
+                f.write_str("[offset_hour")?;
+                write_sign(f, sign_is_mandatory)?;
+                write_padding(f, padding)?;
+                if z_when_utc {
+                    f.write_str(" z_when_utc:true")?;
+                }
+            }
+            Self::OffsetMinute(modifier::OffsetMinute {
+                padding,
+                omit_when_zero,
+            }) => {
+                f.write_str("[offset_minute")?;
+                write_padding(f, padding)?;
+                if omit_when_zero {
+                    f.write_str(" omit_when_zero:true")?;
+                }
+            }
+            Self::OffsetSecond(modifier::OffsetSecond { padding }) => {
+                f.write_str("[offset_second")?;
+                write_padding(f, padding)?;
+            }
+            Self::Offset(modifier::Offset {
+                sign_is_mandatory,
+                z_when_utc,
+                separator,
+            }) => {
+                f.write_str("[offset")?;
+                write_sign(f, sign_is_mandatory)?;
+                if z_when_utc {
+                    f.write_str(" z_when_utc:true")?;
+                }
+                match separator {
+                    modifier::OffsetSeparator::Colon => {}
+                    modifier::OffsetSeparator::None => f.write_str(" separator:none")?,
+                }
+            }
+            Self::Ignore(modifier::Ignore { count }) => write!(f, "[ignore count:{count}")?,
+            Self::UnixTimestamp(modifier::UnixTimestamp {
+                precision,
+                sign_is_mandatory,
+                decimal,
+            }) => {
+                f.write_str("[unix_timestamp")?;
+                match precision {
+                    modifier::UnixTimestampPrecision::Second => {}
+                    modifier::UnixTimestampPrecision::Millisecond => {
+                        f.write_str(" precision:millisecond")?;
+                    }
+                    modifier::UnixTimestampPrecision::Microsecond => {
+                        f.write_str(" precision:microsecond")?;
+                    }
+                    modifier::UnixTimestampPrecision::Nanosecond => {
+                        f.write_str(" precision:nanosecond")?;
+                    }
+                }
+                write_sign(f, sign_is_mandatory)?;
+                if decimal {
+                    f.write_str(" decimal:true")?;
+                }
+            }
+        }
+        f.write_str("]")
+    }
+}
+
+/// Write the `padding` modifier if it is not the default of zero-padding.
+fn write_padding(f: &mut fmt::Formatter<'_>, padding: modifier::Padding) -> fmt::Result {
+    match padding {
+        modifier::Padding::Space => f.write_str(" padding:space"),
+        modifier::Padding::Zero => Ok(()),
+        modifier::Padding::None => f.write_str(" padding:none"),
+    }
+}
+
+/// Write the `sign` modifier if the sign is mandatory. When parsing a format description, the sign
+/// is automatic unless otherwise specified.
+fn write_sign(f: &mut fmt::Formatter<'_>, sign_is_mandatory: bool) -> fmt::Result {
+    if sign_is_mandatory {
+        f.write_str(" sign:mandatory")
+    } else {
+        Ok(())
+    }
+}
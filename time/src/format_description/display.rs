@@ -0,0 +1,79 @@
+//! Format descriptions matching the [`Display`](core::fmt::Display) implementations.
+//!
+//! Each constant describes exactly the output of the corresponding type's `Display`
+//! implementation, which is also the human-readable serde representation. These formats are
+//! stable: formatting a value with the description produces the same string as `to_string`, and
+//! parsing that string with the description (or with the type's `FromStr` implementation)
+//! returns the original value.
+//!
+//! When parsing, the fractional second of a [`TIME`] may be omitted. It is always present when
+//! formatting.
+//!
+#![cfg_attr(feature = "parsing", doc = "```rust")]
+#![cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+//! # use time::{format_description::display, OffsetDateTime};
+//! # use time_macros::datetime;
+//! let datetime = datetime!(2023-01-02 3:04:05.6 +1);
+//! assert_eq!(datetime.to_string(), "2023-01-02 03:04:05.6 +01:00:00");
+//! assert_eq!(
+//!     OffsetDateTime::parse("2023-01-02 03:04:05.6 +01:00:00", display::OFFSET_DATE_TIME)?,
+//!     datetime
+//! );
+//! # Ok::<_, time::Error>(())
+//! ```
+
+use crate::format_description::{modifier, Component, FormatItem};
+
+/// The format of a [`Date`](crate::Date), such as `2023-01-02`.
+///
+/// Years with more than four digits, which require the `large-dates` feature, are prefixed with
+/// their sign.
+pub const DATE: &[FormatItem<'_>] = &[
+    FormatItem::Component(Component::Year(modifier::Year::default())),
+    FormatItem::Literal(b"-"),
+    FormatItem::Component(Component::Month(modifier::Month::default())),
+    FormatItem::Literal(b"-"),
+    FormatItem::Component(Component::Day(modifier::Day::default())),
+];
+
+/// The format of a [`Time`](crate::Time), such as `03:04:05.6`.
+///
+/// The fractional second has as many digits as needed, with at least one.
+pub const TIME: &[FormatItem<'_>] = &[
+    FormatItem::Component(Component::Hour(<modifier::Hour>::default())),
+    FormatItem::Literal(b":"),
+    FormatItem::Component(Component::Minute(<modifier::Minute>::default())),
+    FormatItem::Literal(b":"),
+    FormatItem::Component(Component::Second(<modifier::Second>::default())),
+    FormatItem::Optional(&FormatItem::Compound(&[
+        FormatItem::Literal(b"."),
+        FormatItem::Component(Component::Subsecond(<modifier::Subsecond>::default())),
+    ])),
+];
+
+/// The format of a [`UtcOffset`](crate::UtcOffset), such as `+01:02:03`.
+pub const UTC_OFFSET: &[FormatItem<'_>] = &[
+    FormatItem::Component(Component::OffsetHour(modifier::OffsetHour::default())),
+    FormatItem::Literal(b":"),
+    FormatItem::Component(Component::OffsetMinute(modifier::OffsetMinute::default())),
+    FormatItem::Literal(b":"),
+    FormatItem::Component(Component::OffsetSecond(modifier::OffsetSecond::default())),
+];
+
+/// The format of a [`PrimitiveDateTime`](crate::PrimitiveDateTime), such as
+/// `2023-01-02 03:04:05.6`.
+pub const PRIMITIVE_DATE_TIME: &[FormatItem<'_>] = &[
+    FormatItem::Compound(DATE),
+    FormatItem::Literal(b" "),
+    FormatItem::Compound(TIME),
+];
+
+/// The format of an [`OffsetDateTime`](crate::OffsetDateTime), such as
+/// `2023-01-02 03:04:05.6 +01:02:03`.
+pub const OFFSET_DATE_TIME: &[FormatItem<'_>] = &[
+    FormatItem::Compound(DATE),
+    FormatItem::Literal(b" "),
+    FormatItem::Compound(TIME),
+    FormatItem::Literal(b" "),
+    FormatItem::Compound(UTC_OFFSET),
+];
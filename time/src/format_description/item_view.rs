@@ -0,0 +1,53 @@
+//! A uniform view of borrowed and owned format items.
+
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::format_description::{Component, FormatItem};
+
+/// A single format item, borrowed from either a [`FormatItem`] or an [`OwnedFormatItem`].
+///
+/// Both types have the same structure, so formatting, parsing and compilation are written once
+/// against this view rather than once per type.
+pub(crate) enum ItemView<'a, T> {
+    /// Bytes that are formatted as-is.
+    Literal(&'a [u8]),
+    /// A single non-literal item.
+    Component(Component),
+    /// A series of items.
+    Compound(&'a [T]),
+    /// An item that may or may not be present when parsing.
+    Optional(&'a T),
+    /// A series of items, of which the first successful one is used.
+    First(&'a [T]),
+}
+
+/// A format item that can be viewed as an [`ItemView`].
+pub(crate) trait AsItemView: Sized {
+    /// Obtain a view of the item.
+    fn view(&self) -> ItemView<'_, Self>;
+}
+
+impl AsItemView for FormatItem<'_> {
+    fn view(&self) -> ItemView<'_, Self> {
+        match *self {
+            Self::Literal(literal) => ItemView::Literal(literal),
+            Self::Component(component) => ItemView::Component(component),
+            Self::Compound(items) => ItemView::Compound(items),
+            Self::Optional(item) => ItemView::Optional(item),
+            Self::First(items) => ItemView::First(items),
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl AsItemView for OwnedFormatItem {
+    fn view(&self) -> ItemView<'_, Self> {
+        match self {
+            Self::Literal(literal) => ItemView::Literal(literal),
+            Self::Component(component) => ItemView::Component(*component),
+            Self::Compound(items) => ItemView::Compound(items),
+            Self::Optional(item) => ItemView::Optional(item),
+            Self::First(items) => ItemView::First(items),
+        }
+    }
+}
@@ -8,7 +8,11 @@
 //! e.g. [`well_known::Rfc3339`].
 
 mod borrowed_format_item;
+#[cfg(feature = "alloc")]
+mod compiled;
 mod component;
+pub mod display;
+pub(crate) mod item_view;
 pub mod modifier;
 #[cfg(feature = "alloc")]
 mod owned_format_item;
@@ -17,20 +21,37 @@ mod parse;
 
 pub use borrowed_format_item::BorrowedFormatItem as FormatItem;
 #[cfg(feature = "alloc")]
+pub use compiled::CompiledFormat;
+#[cfg(feature = "alloc")]
+pub(crate) use compiled::FormatOp;
+#[cfg(feature = "alloc")]
 pub use owned_format_item::OwnedFormatItem;
 
 pub use self::component::Component;
 #[cfg(feature = "alloc")]
-pub use self::parse::{parse, parse_borrowed, parse_owned};
+pub use self::parse::{
+    parse, parse_borrowed, parse_borrowed_with_limits, parse_owned, parse_owned_with_limits,
+    ParseLimits,
+};
 
 /// Well-known formats, typically standards.
 pub mod well_known {
     pub mod iso8601;
+    mod iso8601_duration;
+    mod js_date;
     mod rfc2822;
     mod rfc3339;
+    mod rfc7231;
+    mod systemd;
+    mod unix;
 
     #[doc(inline)]
-    pub use iso8601::Iso8601;
+    pub use iso8601::{Iso8601, Iso8601Dynamic};
+    pub use iso8601_duration::Iso8601Duration;
+    pub use js_date::JsDate;
     pub use rfc2822::Rfc2822;
-    pub use rfc3339::Rfc3339;
+    pub use rfc3339::{Rfc3339, Rfc3339Lenient, Rfc3339NoZ};
+    pub use rfc7231::Rfc7231;
+    pub use systemd::Systemd;
+    pub use unix::Unix;
 }
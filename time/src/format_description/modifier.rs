@@ -3,17 +3,29 @@
 use core::num::NonZeroU16;
 
 // region: date modifiers
+/// The representation of the day of the month.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DayRepr {
+    /// The number of the day (e.g. "3").
+    Numerical,
+    /// The number of the day followed by its English ordinal suffix (e.g. "3rd").
+    Ordinal,
+}
+
 /// Day of the month.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Day {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// What form of representation should be used?
+    pub repr: DayRepr,
 }
 
 /// The representation of a month.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum MonthRepr {
     /// The number of the month (January is 1, December is 12).
     Numerical,
@@ -21,11 +33,19 @@ pub enum MonthRepr {
     Long,
     /// The short form of the month name (e.g. "Jan").
     Short,
+    /// A user-provided name for each month, starting with January.
+    ///
+    /// The names are written as-is when formatting. When parsing, the first name that matches is
+    /// used, so no name should be a prefix of a later one. If the component is not case sensitive,
+    /// only ASCII characters are compared case-insensitively.
+    ///
+    /// This representation cannot be expressed in the format description syntax.
+    CustomNames(&'static [&'static str; 12]),
 }
 
 /// Month of the year.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Month {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
@@ -37,7 +57,7 @@ pub struct Month {
 
 /// Ordinal day of the year.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Ordinal {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
@@ -45,7 +65,7 @@ pub struct Ordinal {
 
 /// The representation used for the day of the week.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WeekdayRepr {
     /// The short form of the weekday (e.g. "Mon").
     Short,
@@ -59,11 +79,19 @@ pub enum WeekdayRepr {
     ///
     /// Monday is either 0 or 1, depending on the other modifier's value.
     Monday,
+    /// A user-provided name for each day of the week, starting with Monday.
+    ///
+    /// The names are written as-is when formatting. When parsing, the first name that matches is
+    /// used, so no name should be a prefix of a later one. If the component is not case sensitive,
+    /// only ASCII characters are compared case-insensitively.
+    ///
+    /// This representation cannot be expressed in the format description syntax.
+    CustomNames(&'static [&'static str; 7]),
 }
 
 /// Day of the week.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Weekday {
     /// What form of representation should be used?
     pub repr: WeekdayRepr,
@@ -75,7 +103,7 @@ pub struct Weekday {
 
 /// The representation used for the week number.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum WeekNumberRepr {
     /// Week 1 is the week that contains January 4.
     Iso,
@@ -87,7 +115,7 @@ pub enum WeekNumberRepr {
 
 /// Week within the year.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct WeekNumber {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
@@ -97,7 +125,7 @@ pub struct WeekNumber {
 
 /// The representation used for a year value.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum YearRepr {
     /// The full value of the year.
     Full,
@@ -107,7 +135,7 @@ pub enum YearRepr {
 
 /// Year of the date.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Year {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
@@ -123,7 +151,7 @@ pub struct Year {
 // region: time modifiers
 /// Hour of the day.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Hour {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
@@ -133,7 +161,7 @@ pub struct Hour {
 
 /// Minute within the hour.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Minute {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
@@ -141,7 +169,7 @@ pub struct Minute {
 
 /// AM/PM part of the time.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Period {
     /// Is the period uppercase or lowercase?
     pub is_uppercase: bool,
@@ -153,7 +181,7 @@ pub struct Period {
 
 /// Second within the minute.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Second {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
@@ -161,7 +189,7 @@ pub struct Second {
 
 /// The number of digits present in a subsecond representation.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum SubsecondDigits {
     /// Exactly one digit.
     One,
@@ -188,7 +216,7 @@ pub enum SubsecondDigits {
 
 /// Subsecond within the second.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Subsecond {
     /// How many digits are present in the component?
     pub digits: SubsecondDigits,
@@ -198,36 +226,72 @@ pub struct Subsecond {
 // region: offset modifiers
 /// Hour of the UTC offset.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct OffsetHour {
     /// Whether the `+` sign is present on positive values.
     pub sign_is_mandatory: bool,
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// Whether `Z` is used in place of the hour when the offset is UTC. When parsing, both `Z` and
+    /// the numerical form are accepted.
+    pub z_when_utc: bool,
 }
 
 /// Minute within the hour of the UTC offset.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct OffsetMinute {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// Whether the component is omitted when the value is zero. When formatting, an
+    /// [`Optional`](crate::format_description::FormatItem::Optional) item containing an omitted
+    /// component is omitted in its entirety, allowing a separator to be omitted along with it. When
+    /// parsing, a missing value is treated as zero.
+    pub omit_when_zero: bool,
 }
 
 /// Second within the minute of the UTC offset.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct OffsetSecond {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
 }
+
+/// The separator between the hour and minute of a UTC offset.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OffsetSeparator {
+    /// A colon (`:`), as in `+09:30`.
+    Colon,
+    /// No separator, as in `+0930`.
+    None,
+}
+
+/// The hour and minute of the UTC offset, including the separator between them.
+///
+/// Unlike the individual components, `Z` is used in place of the entire offset when UTC, so no
+/// minute is written after it. Any seconds of the offset are not included.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Offset {
+    /// Whether the `+` sign is present on positive values.
+    pub sign_is_mandatory: bool,
+    /// Whether `Z` is used in place of the offset when it is UTC. When parsing, both `Z` and the
+    /// numerical form are accepted.
+    pub z_when_utc: bool,
+    /// The separator between the hour and minute. When parsing, the separator is optional
+    /// regardless of this value, so both `+09:30` and `+0930` are accepted.
+    pub separator: OffsetSeparator,
+}
 // endregion offset modifiers
 
 /// Type of padding to ensure a minimum width.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Padding {
-    /// A space character (` `) should be used as padding.
+    /// A space character (` `) should be used as padding. Any sign is placed after the padding,
+    /// directly before the digits.
     Space,
     /// A zero character (`0`) should be used as padding.
     Zero,
@@ -240,7 +304,7 @@ pub enum Padding {
 ///
 /// This has no effect when formatting.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Ignore {
     /// The number of bytes to ignore.
     pub count: NonZeroU16,
@@ -257,7 +321,7 @@ impl Ignore {
 
 /// The precision of a Unix timestamp.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum UnixTimestampPrecision {
     /// Seconds since the Unix epoch.
     Second,
@@ -271,12 +335,16 @@ pub enum UnixTimestampPrecision {
 
 /// A Unix timestamp.
 #[non_exhaustive]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct UnixTimestamp {
     /// The precision of the timestamp.
     pub precision: UnixTimestampPrecision,
     /// Whether the `+` sign must be present for a non-negative timestamp.
     pub sign_is_mandatory: bool,
+    /// Whether the timestamp is represented as a decimal number of seconds. When set, the
+    /// fractional part has as many digits as the precision requires: three for milliseconds, six
+    /// for microseconds, and nine for nanoseconds. A precision of seconds has no fractional part.
+    pub decimal: bool,
 }
 
 /// Generate the provided code if and only if `pub` is present.
@@ -319,8 +387,15 @@ macro_rules! impl_const_default {
 }
 
 impl_const_default! {
-    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub Day => Self { padding: Padding::Zero };
+    /// Creates a modifier that indicates the value uses the
+    /// [`Numerical`](Self::Numerical) representation.
+    DayRepr => Self::Numerical;
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and uses
+    /// the [`Numerical`](DayRepr::Numerical) representation.
+    @pub Day => Self {
+        padding: Padding::Zero,
+        repr: DayRepr::Numerical,
+    };
     /// Creates a modifier that indicates the value uses the
     /// [`Numerical`](Self::Numerical) representation.
     MonthRepr => Self::Numerical;
@@ -386,24 +461,41 @@ impl_const_default! {
     /// digits](SubsecondDigits::OneOrMore).
     @pub Subsecond => Self { digits: SubsecondDigits::OneOrMore };
     /// Creates a modifier that indicates the value uses the `+` sign for all positive values
-    /// and is [padded with zeroes](Padding::Zero).
+    /// and is [padded with zeroes](Padding::Zero). UTC is not represented as `Z`.
     @pub OffsetHour => Self {
         sign_is_mandatory: true,
         padding: Padding::Zero,
+        z_when_utc: false,
+    };
+    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and is
+    /// present even when zero.
+    @pub OffsetMinute => Self {
+        padding: Padding::Zero,
+        omit_when_zero: false,
     };
-    /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub OffsetMinute => Self { padding: Padding::Zero };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
     @pub OffsetSecond => Self { padding: Padding::Zero };
+    /// Creates a modifier that indicates the value uses a [colon](Self::Colon).
+    OffsetSeparator => Self::Colon;
+    /// Creates a modifier that indicates the value uses the `+` sign for all positive values,
+    /// separates the hour and minute with a [colon](OffsetSeparator::Colon), and does not represent
+    /// UTC as `Z`.
+    @pub Offset => Self {
+        sign_is_mandatory: true,
+        z_when_utc: false,
+        separator: OffsetSeparator::Colon,
+    };
     /// Creates a modifier that indicates the value is [padded with zeroes](Self::Zero).
     Padding => Self::Zero;
     /// Creates a modifier that indicates the value represents the [number of seconds](Self::Second)
     /// since the Unix epoch.
     UnixTimestampPrecision => Self::Second;
     /// Creates a modifier that indicates the value represents the [number of
-    /// seconds](UnixTimestampPrecision::Second) since the Unix epoch. The sign is not mandatory.
+    /// seconds](UnixTimestampPrecision::Second) since the Unix epoch. The sign is not mandatory,
+    /// and the value is an integer.
     @pub UnixTimestamp => Self {
         precision: UnixTimestampPrecision::Second,
         sign_is_mandatory: false,
+        decimal: false,
     };
 }
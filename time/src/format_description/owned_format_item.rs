@@ -4,13 +4,20 @@ use alloc::boxed::Box;
 use alloc::string::String;
 use alloc::vec::Vec;
 use core::fmt;
+use core::hash::{Hash, Hasher};
 
 use crate::error;
+use crate::format_description::borrowed_format_item::write_literal;
 use crate::format_description::{Component, FormatItem};
 
 /// A complete description of how to format and parse a type.
+///
+/// Two items are equal if they format and parse identically in structure: nested
+/// [`Compound`](Self::Compound)s are flattened, adjacent [`Literal`](Self::Literal)s are merged,
+/// and empty literals are ignored before comparing. The [`Hash`] implementation is consistent with
+/// this.
 #[non_exhaustive]
-#[derive(Clone, PartialEq, Eq)]
+#[derive(Clone)]
 pub enum OwnedFormatItem {
     /// Bytes that are formatted as-is.
     ///
@@ -28,8 +35,8 @@ pub enum OwnedFormatItem {
     /// This variant has no effect on formatting, as the value is guaranteed to be present.
     Optional(Box<Self>),
     /// A series of `FormatItem`s where, when parsing, the first successful parse is used. When
-    /// formatting, the first element of the [`Vec`] is used. An empty [`Vec`] is a no-op when
-    /// formatting or parsing.
+    /// formatting, the first element that can be formatted is used; if none can be, the error of
+    /// the last one is returned. An empty slice is a no-op when formatting or parsing.
     First(Box<[Self]>),
 }
 
@@ -45,6 +52,30 @@ impl fmt::Debug for OwnedFormatItem {
     }
 }
 
+impl fmt::Display for OwnedFormatItem {
+    /// Write the item in the syntax accepted by version 2 of
+    /// [`parse_owned`](crate::format_description::parse_owned). Parsing the output results in an
+    /// item that is equal to this one.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => write_literal(f, literal),
+            Self::Component(component) => fmt::Display::fmt(component, f),
+            Self::Compound(items) => items.iter().try_for_each(|item| fmt::Display::fmt(item, f)),
+            Self::Optional(item) => write!(f, "[optional [{item}]]"),
+            Self::First(items) => {
+                f.write_str("[first ")?;
+                for (idx, item) in items.iter().enumerate() {
+                    if idx != 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "[{item}]")?;
+                }
+                f.write_str("]")
+            }
+        }
+    }
+}
+
 // region: conversions from FormatItem
 impl From<FormatItem<'_>> for OwnedFormatItem {
     fn from(item: FormatItem<'_>) -> Self {
@@ -136,6 +167,63 @@ impl TryFrom<OwnedFormatItem> for Vec<OwnedFormatItem> {
 // endregion from variants
 
 // region: equality
+/// The canonical form of an [`OwnedFormatItem`], used for equality and hashing.
+#[derive(PartialEq, Eq, Hash)]
+enum Normalized {
+    /// Any number of adjacent literals, merged. Never empty.
+    Literal(Vec<u8>),
+    /// A component, unchanged.
+    Component(Component),
+    /// The normalized contents of an optional item.
+    Optional(Vec<Self>),
+    /// The normalized contents of each alternative.
+    First(Vec<Vec<Self>>),
+}
+
+impl OwnedFormatItem {
+    /// Obtain the canonical form of the item.
+    fn normalize(&self) -> Vec<Normalized> {
+        let mut normalized = Vec::new();
+        self.normalize_into(&mut normalized);
+        normalized
+    }
+
+    /// Append the canonical form of the item to `normalized`.
+    fn normalize_into(&self, normalized: &mut Vec<Normalized>) {
+        match self {
+            Self::Literal(literal) if literal.is_empty() => {}
+            Self::Literal(literal) => match normalized.last_mut() {
+                Some(Normalized::Literal(previous)) => previous.extend_from_slice(literal),
+                _ => normalized.push(Normalized::Literal(literal.to_vec())),
+            },
+            Self::Component(component) => normalized.push(Normalized::Component(*component)),
+            Self::Compound(items) => {
+                for item in items.iter() {
+                    item.normalize_into(normalized);
+                }
+            }
+            Self::Optional(item) => normalized.push(Normalized::Optional(item.normalize())),
+            Self::First(items) => normalized.push(Normalized::First(
+                items.iter().map(Self::normalize).collect(),
+            )),
+        }
+    }
+}
+
+impl PartialEq for OwnedFormatItem {
+    fn eq(&self, rhs: &Self) -> bool {
+        self.normalize() == rhs.normalize()
+    }
+}
+
+impl Eq for OwnedFormatItem {}
+
+impl Hash for OwnedFormatItem {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.normalize().hash(state);
+    }
+}
+
 impl PartialEq<Component> for OwnedFormatItem {
     fn eq(&self, rhs: &Component) -> bool {
         matches!(self, Self::Component(component) if component == rhs)
@@ -127,6 +127,18 @@ fn parse_inner<
             Err(err) => return Some(Err(err)),
         };
 
+        let start = match &next {
+            lexer::Token::Literal(Spanned { value: _, span })
+            | lexer::Token::ComponentPart {
+                kind: _,
+                value: Spanned { value: _, span },
+            } => span.start,
+            lexer::Token::Bracket { kind: _, location } => *location,
+        };
+        if let Err(err) = tokens.count_item(start) {
+            return Some(Err(err));
+        }
+
         Some(match next {
             lexer::Token::Literal(Spanned { value: _, span: _ }) if NESTED => {
                 bug!("literal should not be present in nested description")
@@ -241,8 +253,21 @@ fn parse_component<
         };
 
         let mut nested_format_descriptions = Vec::new();
-        while let Ok(description) = parse_nested::<_, VERSION>(whitespace.span.end, tokens) {
-            nested_format_descriptions.push(description);
+        loop {
+            match parse_nested::<_, VERSION>(whitespace.span.end, tokens) {
+                Ok(description) => nested_format_descriptions.push(description),
+                // Exceeding a limit must not be treated as the end of the sequence, as the error
+                // would otherwise be reported as an unclosed bracket.
+                Err(
+                    err @ Error {
+                        public:
+                            crate::error::InvalidFormatDescription::NestingTooDeep { .. }
+                            | crate::error::InvalidFormatDescription::TooManyItems { .. },
+                        ..
+                    },
+                ) => return Err(err),
+                Err(_) => break,
+            }
         }
 
         let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
@@ -361,7 +386,10 @@ fn parse_nested<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, const V
             },
         });
     };
-    let items = parse_inner::<_, true, VERSION>(tokens).collect::<Result<_, _>>()?;
+    tokens.enter_nested(opening_bracket)?;
+    let items = parse_inner::<_, true, VERSION>(tokens).collect::<Result<_, _>>();
+    tokens.exit_nested();
+    let items = items?;
     let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
         return Err(Error {
             _inner: unused(opening_bracket.error("unclosed bracket")),
@@ -2,12 +2,21 @@
 
 use core::iter;
 
-use super::{unused, Error, Location, Spanned, SpannedValue};
+use super::{unused, Error, Location, ParseLimits, Spanned, SpannedValue};
 
 /// An iterator over the lexed tokens.
+///
+/// The limits are not enforced by the lexer itself. They are tracked here so that the AST parser
+/// can enforce them as it consumes tokens, regardless of how deeply it is nested.
 pub(super) struct Lexed<I: Iterator> {
     /// The internal iterator.
-    iter: core::iter::Peekable<I>,
+    iter: iter::Peekable<I>,
+    /// The limits to enforce.
+    limits: ParseLimits,
+    /// The number of nested format descriptions currently being parsed.
+    depth: usize,
+    /// The number of items that have been parsed, including those in nested format descriptions.
+    items: usize,
 }
 
 impl<I: Iterator> Iterator for Lexed<I> {
@@ -92,6 +101,43 @@ impl<'iter, 'token: 'iter, I: Iterator<Item = Result<Token<'token>, Error>> + 'i
             None
         }
     }
+
+    /// Record that a nested format description beginning at the provided location is being
+    /// entered, returning an error if this exceeds the maximum depth.
+    pub(super) fn enter_nested(&mut self, location: Location) -> Result<(), Error> {
+        if self.depth >= self.limits.max_depth {
+            return Err(Error {
+                _inner: unused(location.error("nested format description is too deep")),
+                public: crate::error::InvalidFormatDescription::NestingTooDeep {
+                    max_depth: self.limits.max_depth,
+                    index: location.byte as _,
+                },
+            });
+        }
+        self.depth += 1;
+        Ok(())
+    }
+
+    /// Record that the innermost nested format description has been exited.
+    pub(super) fn exit_nested(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// Record that an item beginning at the provided location is being parsed, returning an error
+    /// if this exceeds the maximum number of items.
+    pub(super) fn count_item(&mut self, location: Location) -> Result<(), Error> {
+        if self.items >= self.limits.max_items {
+            return Err(Error {
+                _inner: unused(location.error("too many items in format description")),
+                public: crate::error::InvalidFormatDescription::TooManyItems {
+                    max_items: self.limits.max_items,
+                    index: location.byte as _,
+                },
+            });
+        }
+        self.items += 1;
+        Ok(())
+    }
 }
 
 /// A token emitted by the lexer. There is no semantic meaning at this stage.
@@ -154,10 +200,11 @@ fn attach_location<'item>(
 ///   other characters result in a lex error.
 pub(super) fn lex<const VERSION: usize>(
     mut input: &[u8],
+    limits: ParseLimits,
 ) -> Lexed<impl Iterator<Item = Result<Token<'_>, Error>>> {
     validate_version!(VERSION);
 
-    let mut depth: u8 = 0;
+    let mut depth: usize = 0;
     let mut iter = attach_location(input.iter()).peekable();
     let mut second_bracket_location = None;
 
@@ -295,5 +342,8 @@ pub(super) fn lex<const VERSION: usize>(
 
     Lexed {
         iter: iter.peekable(),
+        limits,
+        depth: 0,
+        items: 0,
     }
 }
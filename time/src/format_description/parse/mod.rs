@@ -45,6 +45,60 @@ pub fn parse(
     parse_borrowed::<1>(s)
 }
 
+/// Limits enforced while parsing a format description at runtime.
+///
+/// Format descriptions provided by untrusted sources can be arbitrarily large or deeply nested.
+/// These limits bound the resources used to parse them, with an error being returned if either is
+/// exceeded. The defaults are far larger than any reasonable format description needs. The length
+/// of the input is not limited, as the memory used for a literal is at most proportional to it.
+///
+/// ```rust
+/// # use time::error::InvalidFormatDescription;
+/// # use time::format_description::{self, ParseLimits};
+/// let limits = ParseLimits::DEFAULT.set_max_depth(1);
+/// assert!(format_description::parse_owned_with_limits::<2>("[optional [x]]", limits).is_ok());
+/// assert!(matches!(
+///     format_description::parse_owned_with_limits::<2>("[optional [[optional [x]]]]", limits),
+///     Err(InvalidFormatDescription::NestingTooDeep { .. })
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseLimits {
+    /// The maximum number of nested format descriptions an item may be within.
+    pub(crate) max_depth: usize,
+    /// The maximum number of items, including those in nested format descriptions.
+    pub(crate) max_items: usize,
+}
+
+impl ParseLimits {
+    /// The limits used by [`parse`], [`parse_borrowed`], and [`parse_owned`]. A maximum depth of
+    /// 32 and a maximum of 4096 items are permitted.
+    pub const DEFAULT: Self = Self {
+        max_depth: 32,
+        max_items: 4096,
+    };
+
+    /// Set the maximum number of nested format descriptions an item may be within. Items that are
+    /// not within an `[optional]` or `[first]` item have a depth of zero.
+    ///
+    /// Each level of nesting uses stack space when parsing the format description and when
+    /// formatting or parsing a value with it, so very large values may overflow the stack.
+    pub const fn set_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    /// Set the maximum number of items, including those in nested format descriptions.
+    pub const fn set_max_items(self, max_items: usize) -> Self {
+        Self { max_items, ..self }
+    }
+}
+
+impl Default for ParseLimits {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 /// Parse a sequence of items from the format description.
 ///
 /// The syntax for the format description can be found in [the
@@ -53,9 +107,20 @@ pub fn parse(
 pub fn parse_borrowed<const VERSION: usize>(
     s: &str,
 ) -> Result<Vec<crate::format_description::FormatItem<'_>>, crate::error::InvalidFormatDescription>
+{
+    parse_borrowed_with_limits::<VERSION>(s, ParseLimits::DEFAULT)
+}
+
+/// Parse a sequence of items from the format description, enforcing the provided limits.
+///
+/// This is otherwise identical to [`parse_borrowed`].
+pub fn parse_borrowed_with_limits<const VERSION: usize>(
+    s: &str,
+    limits: ParseLimits,
+) -> Result<Vec<crate::format_description::FormatItem<'_>>, crate::error::InvalidFormatDescription>
 {
     validate_version!(VERSION);
-    let mut lexed = lexer::lex::<VERSION>(s.as_bytes());
+    let mut lexed = lexer::lex::<VERSION>(s.as_bytes(), limits);
     let ast = ast::parse::<_, VERSION>(&mut lexed);
     let format_items = format_item::parse(ast);
     Ok(format_items
@@ -75,14 +140,22 @@ pub fn parse_borrowed<const VERSION: usize>(
 /// [`OwnedFormatItem`]: crate::format_description::OwnedFormatItem
 pub fn parse_owned<const VERSION: usize>(
     s: &str,
+) -> Result<crate::format_description::OwnedFormatItem, crate::error::InvalidFormatDescription> {
+    parse_owned_with_limits::<VERSION>(s, ParseLimits::DEFAULT)
+}
+
+/// Parse a sequence of items from the format description, enforcing the provided limits.
+///
+/// This is otherwise identical to [`parse_owned`].
+pub fn parse_owned_with_limits<const VERSION: usize>(
+    s: &str,
+    limits: ParseLimits,
 ) -> Result<crate::format_description::OwnedFormatItem, crate::error::InvalidFormatDescription> {
     validate_version!(VERSION);
-    let mut lexed = lexer::lex::<VERSION>(s.as_bytes());
+    let mut lexed = lexer::lex::<VERSION>(s.as_bytes(), limits);
     let ast = ast::parse::<_, VERSION>(&mut lexed);
     let format_items = format_item::parse(ast);
-    let items = format_items
-        .map(|res| res.map(Into::into))
-        .collect::<Result<Box<_>, _>>()?;
+    let items = format_items.collect::<Result<Box<_>, _>>()?;
     Ok(items.into())
 }
 
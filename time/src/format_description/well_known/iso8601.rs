@@ -16,6 +16,7 @@ const PARSING_ONLY: EncodedConfig = Config {
         decimal_digits: None,
     },
     offset_precision: OffsetPrecision::Hour,
+    strict_time_precision: false,
 }
 .encode();
 
@@ -32,8 +33,8 @@ const DEFAULT_CONFIG: EncodedConfig = Config::DEFAULT.encode();
 /// Example: 1997-11-21T09:55:06.000000000-06:00
 ///
 /// # Examples
-#[cfg_attr(feature = "formatting", doc = "```rust")]
-#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
 /// # use time::format_description::well_known::Iso8601;
 /// # use time_macros::datetime;
 /// assert_eq!(
@@ -48,7 +49,7 @@ pub struct Iso8601<const CONFIG: EncodedConfig = DEFAULT_CONFIG>;
 impl<const CONFIG: EncodedConfig> core::fmt::Debug for Iso8601<CONFIG> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.debug_struct("Iso8601")
-            .field("config", &Config::decode(CONFIG))
+            .field("config", &Self::CONFIG)
             .finish()
     }
 }
@@ -65,6 +66,7 @@ impl Iso8601<DEFAULT_CONFIG> {
     /// - The date uses the calendar format.
     /// - The time has precision to the second and nine decimal digits.
     /// - The UTC offset has precision to the minute.
+    /// - When parsing, the time may have reduced precision, such as `13` or `13:45`.
     ///
     /// If you need different behavior, use [`Config::DEFAULT`] and [`Config`]'s methods to create
     /// a custom configuration.
@@ -72,16 +74,69 @@ impl Iso8601<DEFAULT_CONFIG> {
 }
 
 impl Iso8601<PARSING_ONLY> {
-    /// An [`Iso8601`] that can only be used for parsing. Using this to format a value is
-    /// unspecified behavior.
+    /// An [`Iso8601`] that can only be used for parsing. Using this to format a value returns
+    /// [`error::Format::InvalidFormatDescription`](crate::error::Format::InvalidFormatDescription).
     pub const PARSING: Self = Self;
 }
 
+/// The format described in [ISO 8601](https://www.iso.org/iso-8601-date-and-time-format.html),
+/// with a configuration chosen at runtime.
+///
+/// [`Iso8601`] requires its configuration as a const parameter. When the configuration is only
+/// known at runtime, such as when it is read from a file, this type can be used instead.
+/// Formatting and parsing behave identically to an [`Iso8601`] with the same configuration.
+///
+/// # Examples
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::{iso8601::Config, Iso8601Dynamic};
+/// # use time_macros::datetime;
+/// let year_is_six_digits = true; // e.g. read from a configuration file
+/// let format =
+///     Iso8601Dynamic::from_config(Config::DEFAULT.set_year_is_six_digits(year_is_six_digits));
+/// assert_eq!(
+///     datetime!(1997-11-12 9:55:06 -6:00).format(&format)?,
+///     "+001997-11-12T09:55:06.000000000-06:00"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Iso8601Dynamic {
+    /// The configuration used for formatting and parsing.
+    pub(crate) config: Config,
+}
+
+impl Iso8601Dynamic {
+    /// An [`Iso8601Dynamic`] with the default configuration. This is equivalent to
+    /// [`Iso8601::DEFAULT`].
+    pub const DEFAULT: Self = Self::from_config(Config::DEFAULT);
+
+    /// Create an [`Iso8601Dynamic`] using the provided configuration.
+    ///
+    /// If [`Config::set_formatted_components`] was passed [`FormattedComponents::None`], the
+    /// value can only be used for parsing. Using it to format a value returns
+    /// [`error::Format::InvalidFormatDescription`](crate::error::Format::InvalidFormatDescription).
+    pub const fn from_config(config: Config) -> Self {
+        Self { config }
+    }
+
+    /// Obtain the configuration.
+    pub const fn config(self) -> Config {
+        self.config
+    }
+}
+
+impl<const CONFIG: EncodedConfig> From<Iso8601<CONFIG>> for Iso8601Dynamic {
+    fn from(_: Iso8601<CONFIG>) -> Self {
+        Self::from_config(Iso8601::<CONFIG>::CONFIG)
+    }
+}
+
 /// Which components to format.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FormattedComponents {
-    /// The configuration can only be used for parsing. Using this to format a value is
-    /// unspecified behavior.
+    /// The configuration can only be used for parsing. Using this to format a value returns
+    /// [`error::Format::InvalidFormatDescription`](crate::error::Format::InvalidFormatDescription).
     None,
     /// Format only the date.
     Date,
@@ -134,18 +189,20 @@ pub enum TimePrecision {
 /// The precision for the UTC offset.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OffsetPrecision {
-    /// Format only the offset hour. Requires the offset minute to be zero.
+    /// Format only the offset hour. Requires the offset minute and second to be zero.
     Hour,
-    /// Format both the offset hour and minute.
+    /// Format both the offset hour and minute. Requires the offset second to be zero.
     Minute,
+    /// Format the offset hour, minute, and second, such as `+00:19:32`.
+    ///
+    /// Offsets with a nonzero second are rare, but are needed for some historical times, such as
+    /// those in the Netherlands prior to 1937.
+    Second,
 }
 
-/// Configuration for [`Iso8601`].
-// This is only used as a const generic, so there's no need to have a number of implementations on
-// it.
-#[allow(missing_copy_implementations)]
+/// Configuration for [`Iso8601`] and [`Iso8601Dynamic`].
 #[doc(alias = "EncodedConfig")] // People will likely search for `EncodedConfig`, so show them this.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Config {
     /// Which components, if any, will be formatted.
     pub(crate) formatted_components: FormattedComponents,
@@ -159,9 +216,45 @@ pub struct Config {
     pub(crate) time_precision: TimePrecision,
     /// The precision for the UTC offset.
     pub(crate) offset_precision: OffsetPrecision,
+    /// Whether parsing requires the time to be given to the second.
+    pub(crate) strict_time_precision: bool,
 }
 
 impl Config {
+    /// Whether the date should be formatted.
+    #[cfg(feature = "formatting-core")]
+    pub(crate) const fn format_date(&self) -> bool {
+        matches!(
+            self.formatted_components,
+            FormattedComponents::Date
+                | FormattedComponents::DateTime
+                | FormattedComponents::DateTimeOffset
+        )
+    }
+
+    /// Whether the time should be formatted.
+    #[cfg(feature = "formatting-core")]
+    pub(crate) const fn format_time(&self) -> bool {
+        matches!(
+            self.formatted_components,
+            FormattedComponents::Time
+                | FormattedComponents::DateTime
+                | FormattedComponents::DateTimeOffset
+                | FormattedComponents::TimeOffset
+        )
+    }
+
+    /// Whether the UTC offset should be formatted.
+    #[cfg(feature = "formatting-core")]
+    pub(crate) const fn format_offset(&self) -> bool {
+        matches!(
+            self.formatted_components,
+            FormattedComponents::Offset
+                | FormattedComponents::DateTimeOffset
+                | FormattedComponents::TimeOffset
+        )
+    }
+
     /// A configuration for the [`Iso8601`] format.
     ///
     /// The following is the default behavior:
@@ -184,6 +277,7 @@ impl Config {
             decimal_digits: NonZeroU8::new(9),
         },
         offset_precision: OffsetPrecision::Minute,
+        strict_time_precision: false,
     };
 
     /// Set whether the format the date, time, and/or UTC offset.
@@ -230,4 +324,16 @@ impl Config {
             ..self
         }
     }
+
+    /// Set whether parsing requires the time to be given to the second.
+    ///
+    /// When this is `false`, the minute and second may be omitted or replaced by a decimal
+    /// fraction of the preceding component, with any omitted components assumed to be zero. This
+    /// has no effect on formatting, which is controlled by [`Config::set_time_precision`].
+    pub const fn set_strict_time_precision(self, strict_time_precision: bool) -> Self {
+        Self {
+            strict_time_precision,
+            ..self
+        }
+    }
 }
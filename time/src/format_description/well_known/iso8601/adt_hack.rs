@@ -2,7 +2,7 @@
 
 use core::num::NonZeroU8;
 
-#[cfg(feature = "formatting")]
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
 use super::Iso8601;
 use super::{Config, DateKind, FormattedComponents as FC, OffsetPrecision, TimePrecision};
 
@@ -17,35 +17,12 @@ pub type DoNotRelyOnWhatThisIs = u128;
 /// notice.
 pub type EncodedConfig = DoNotRelyOnWhatThisIs;
 
-#[cfg(feature = "formatting")]
 impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     /// The user-provided configuration for the ISO 8601 format.
-    const CONFIG: Config = Config::decode(CONFIG);
-    /// Whether the date should be formatted.
-    pub(crate) const FORMAT_DATE: bool = matches!(
-        Self::CONFIG.formatted_components,
-        FC::Date | FC::DateTime | FC::DateTimeOffset
-    );
-    /// Whether the time should be formatted.
-    pub(crate) const FORMAT_TIME: bool = matches!(
-        Self::CONFIG.formatted_components,
-        FC::Time | FC::DateTime | FC::DateTimeOffset | FC::TimeOffset
-    );
-    /// Whether the UTC offset should be formatted.
-    pub(crate) const FORMAT_OFFSET: bool = matches!(
-        Self::CONFIG.formatted_components,
-        FC::Offset | FC::DateTimeOffset | FC::TimeOffset
-    );
-    /// Whether the year is six digits.
-    pub(crate) const YEAR_IS_SIX_DIGITS: bool = Self::CONFIG.year_is_six_digits;
-    /// Whether the format contains separators (such as `-` or `:`).
-    pub(crate) const USE_SEPARATORS: bool = Self::CONFIG.use_separators;
-    /// Which format to use for the date.
-    pub(crate) const DATE_KIND: DateKind = Self::CONFIG.date_kind;
-    /// The precision and number of decimal digits to use for the time.
-    pub(crate) const TIME_PRECISION: TimePrecision = Self::CONFIG.time_precision;
-    /// The precision for the UTC offset.
-    pub(crate) const OFFSET_PRECISION: OffsetPrecision = Self::CONFIG.offset_precision;
+    ///
+    /// This is an associated constant rather than a call to [`Config::decode`] so that an invalid
+    /// configuration is rejected at compile time rather than causing a panic at runtime.
+    pub(crate) const CONFIG: Config = Config::decode(CONFIG);
 }
 
 impl Config {
@@ -89,7 +66,9 @@ impl Config {
         bytes[6] = match self.offset_precision {
             OffsetPrecision::Hour => 0,
             OffsetPrecision::Minute => 1,
+            OffsetPrecision::Second => 2,
         };
+        bytes[7] = self.strict_time_precision as _;
 
         EncodedConfig::from_be_bytes(bytes)
     }
@@ -140,11 +119,17 @@ impl Config {
         let offset_precision = match bytes[6] {
             0 => OffsetPrecision::Hour,
             1 => OffsetPrecision::Minute,
+            2 => OffsetPrecision::Second,
+            _ => panic!("invalid configuration"),
+        };
+        let strict_time_precision = match bytes[7] {
+            0 => false,
+            1 => true,
             _ => panic!("invalid configuration"),
         };
 
         // No `for` loops in `const fn`.
-        let mut idx = 7; // first unused byte
+        let mut idx = 8; // first unused byte
         while idx < EncodedConfig::BITS as usize / 8 {
             assert!(bytes[idx] == 0, "invalid configuration");
             idx += 1;
@@ -157,6 +142,7 @@ impl Config {
             date_kind,
             time_precision,
             offset_precision,
+            strict_time_precision,
         }
     }
 }
@@ -175,6 +161,7 @@ mod tests {
                 && a.date_kind == b.date_kind
                 && a.time_precision == b.time_precision
                 && a.offset_precision == b.offset_precision
+                && a.strict_time_precision == b.strict_time_precision
         }};
     }
 
@@ -224,6 +211,9 @@ mod tests {
         }));
         assert_roundtrip!(Config::DEFAULT.set_offset_precision(OffsetPrecision::Hour));
         assert_roundtrip!(Config::DEFAULT.set_offset_precision(OffsetPrecision::Minute));
+        assert_roundtrip!(Config::DEFAULT.set_offset_precision(OffsetPrecision::Second));
+        assert_roundtrip!(Config::DEFAULT.set_strict_time_precision(false));
+        assert_roundtrip!(Config::DEFAULT.set_strict_time_precision(true));
     }
 
     macro_rules! assert_decode_fail {
@@ -244,7 +234,8 @@ mod tests {
         assert_decode_fail!(0x00_00_02_00_00_00_00_00_00_00_00_00_00_00_00_00);
         assert_decode_fail!(0x00_00_00_03_00_00_00_00_00_00_00_00_00_00_00_00);
         assert_decode_fail!(0x00_00_00_00_03_00_00_00_00_00_00_00_00_00_00_00);
-        assert_decode_fail!(0x00_00_00_00_00_00_02_00_00_00_00_00_00_00_00_00);
-        assert_decode_fail!(0x00_00_00_00_00_00_00_01_00_00_00_00_00_00_00_00);
+        assert_decode_fail!(0x00_00_00_00_00_00_03_00_00_00_00_00_00_00_00_00);
+        assert_decode_fail!(0x00_00_00_00_00_00_00_02_00_00_00_00_00_00_00_00);
+        assert_decode_fail!(0x00_00_00_00_00_00_00_00_01_00_00_00_00_00_00_00);
     }
 }
@@ -0,0 +1,120 @@
+//! The duration format defined in ISO 8601.
+
+use core::num::NonZeroU8;
+
+/// The duration format described in [ISO 8601](https://www.iso.org/iso-8601-date-and-time-format.html),
+/// such as `PT1H30M`.
+///
+/// Example: P1DT2H30M15.5S
+///
+/// When formatting, the largest unit used is the day, as the lengths of months and years vary. A
+/// day is treated as exactly 24 hours; [`Iso8601Duration::set_use_days`] can be used to write hours
+/// instead. Components that are zero are omitted, with a zero duration written as `PT0S`. A
+/// negative duration is written with a leading minus sign, as in `-PT5M`.
+///
+/// When parsing, weeks, days, hours, minutes, and seconds are accepted, in that order. The last
+/// component present may have a fractional part, using either `.` or `,` as the decimal mark. A
+/// leading `+` or `-` is permitted. Years and months are rejected, as they do not have a fixed
+/// length, while weeks and days are treated as exactly 7 days and 24 hours respectively. The
+/// configuration has no effect on parsing.
+///
+/// # Examples
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Iso8601Duration;
+/// # use time::ext::NumericalDuration;
+/// let duration = 1.days() + 2.hours() + 30.minutes() + 15.5.seconds();
+/// assert_eq!(duration.format(&Iso8601Duration::DEFAULT)?, "P1DT2H30M15.5S");
+/// assert_eq!((-5).minutes().format(&Iso8601Duration::DEFAULT)?, "-PT5M");
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Iso8601Duration;
+/// # use time::Duration;
+/// # use time::ext::NumericalDuration;
+/// assert_eq!(
+///     Duration::parse("PT1H30M", &Iso8601Duration::DEFAULT)?,
+///     1.hours() + 30.minutes()
+/// );
+/// assert_eq!(Duration::parse("P2W", &Iso8601Duration::DEFAULT)?, 2.weeks());
+/// assert_eq!(Duration::parse("PT1,5H", &Iso8601Duration::DEFAULT)?, 90.minutes());
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Iso8601Duration {
+    /// The number of decimal digits used for the seconds, if fixed.
+    pub(crate) decimal_digits: Option<NonZeroU8>,
+    /// Whether whole days are written separately from the hours.
+    pub(crate) use_days: bool,
+}
+
+impl Iso8601Duration {
+    /// A configuration that writes whole days and formats seconds with as many decimal digits as
+    /// are needed to represent the value exactly, omitting the fractional part when it is zero.
+    pub const DEFAULT: Self = Self {
+        decimal_digits: None,
+        use_days: true,
+    };
+
+    /// Set the number of decimal digits used for the seconds. `None` uses as many as needed, while
+    /// `Some` always uses exactly that many, truncating any excess precision.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the number of digits exceeds nine.
+    ///
+    #[cfg_attr(feature = "formatting-core", doc = "```rust")]
+    #[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+    /// # use core::num::NonZeroU8;
+    /// # use time::format_description::well_known::Iso8601Duration;
+    /// # use time::ext::NumericalDuration;
+    /// let format = Iso8601Duration::DEFAULT.set_decimal_digits(NonZeroU8::new(3));
+    /// assert_eq!(90.5.seconds().format(&format)?, "PT1M30.500S");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn set_decimal_digits(self, decimal_digits: Option<NonZeroU8>) -> Self {
+        if let Some(digits) = decimal_digits {
+            assert!(digits.get() <= 9, "at most nine decimal digits may be used");
+        }
+        Self {
+            decimal_digits,
+            ..self
+        }
+    }
+
+    /// Set whether whole days are written separately from the hours. When `false`, the output
+    /// matches the alternate form of the `Display` implementation of [`Duration`].
+    ///
+    /// [`Duration`]: crate::Duration
+    ///
+    #[cfg_attr(feature = "formatting-core", doc = "```rust")]
+    #[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+    /// # use time::format_description::well_known::Iso8601Duration;
+    /// # use time::ext::NumericalDuration;
+    /// let format = Iso8601Duration::DEFAULT.set_use_days(false);
+    /// assert_eq!(26.hours().format(&format)?, "PT26H");
+    /// assert_eq!(26.hours().format(&Iso8601Duration::DEFAULT)?, "P1DT2H");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn set_use_days(self, use_days: bool) -> Self {
+        Self { use_days, ..self }
+    }
+
+    /// Obtain the number of decimal digits used for the seconds, if fixed.
+    pub const fn decimal_digits(self) -> Option<NonZeroU8> {
+        self.decimal_digits
+    }
+
+    /// Obtain whether whole days are written separately from the hours.
+    pub const fn use_days(self) -> bool {
+        self.use_days
+    }
+}
+
+impl Default for Iso8601Duration {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
@@ -0,0 +1,37 @@
+//! The format produced by JavaScript's `Date.prototype.toString()`.
+
+/// The format produced by JavaScript's
+/// [`Date.prototype.toString()`](https://tc39.es/ecma262/#sec-date.prototype.tostring).
+///
+/// Example: Wed May 01 2024 13:45:30 GMT+0200 (Central European Summer Time)
+///
+/// When parsing, the parenthesized time zone name is optional and ignored, as its contents are
+/// implementation-defined and do not affect the offset. When formatting, it is omitted.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::JsDate, OffsetDateTime};
+/// use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse(
+///         "Wed May 01 2024 13:45:30 GMT+0200 (Central European Summer Time)",
+///         &JsDate
+///     )?,
+///     datetime!(2024-05-01 13:45:30 +02:00)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::JsDate;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(2024-05-01 13:45:30 -07:00).format(&JsDate)?,
+///     "Wed May 01 2024 13:45:30 GMT-0700"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JsDate;
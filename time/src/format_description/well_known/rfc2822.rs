@@ -16,8 +16,8 @@
 /// # Ok::<_, time::Error>(())
 /// ```
 ///
-#[cfg_attr(feature = "formatting", doc = "```rust")]
-#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
 /// # use time::format_description::well_known::Rfc2822;
 /// # use time_macros::datetime;
 /// assert_eq!(
@@ -4,6 +4,8 @@
 ///
 /// Format example: 1985-04-12T23:20:50.52Z
 ///
+/// UTC is formatted as `Z`. Use [`Rfc3339NoZ`] to format it as `+00:00` instead.
+///
 /// # Examples
 #[cfg_attr(feature = "parsing", doc = "```rust")]
 #[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
@@ -16,8 +18,8 @@
 /// # Ok::<_, time::Error>(())
 /// ```
 ///
-#[cfg_attr(feature = "formatting", doc = "```rust")]
-#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
 /// # use time::format_description::well_known::Rfc3339;
 /// # use time_macros::datetime;
 /// assert_eq!(
@@ -28,3 +30,74 @@
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Rfc3339;
+
+/// The format described in [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6), with UTC
+/// always formatted as a numeric offset.
+///
+/// Format example: 1985-04-12T23:20:50.52+00:00
+///
+/// This is identical to [`Rfc3339`], except that UTC is formatted as `+00:00` rather than `Z`. Some
+/// consumers of RFC 3339 timestamps do not accept `Z`. Parsing accepts both forms.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::Rfc3339NoZ, OffsetDateTime};
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("1985-04-12T23:20:50.52Z", &Rfc3339NoZ)?,
+///     datetime!(1985-04-12 23:20:50.52 +00:00)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc3339NoZ;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(1985-04-12 23:20:50.52 +00:00).format(&Rfc3339NoZ)?,
+///     "1985-04-12T23:20:50.52+00:00"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc3339NoZ;
+
+/// The format described in [RFC 3339](https://tools.ietf.org/html/rfc3339#section-5.6), with
+/// relaxed parsing.
+///
+/// Format example: 1985-04-12T23:20:50.52Z
+///
+/// Parsing accepts everything that [`Rfc3339`] does, as well as the following variations permitted
+/// by ISO 8601:
+///
+/// - a space rather than `T` between the date and time, and
+/// - a comma rather than a period before the fractional second.
+///
+/// As with [`Rfc3339`], `T` and `Z` may be lowercase. Formatting is identical to [`Rfc3339`].
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::Rfc3339Lenient, OffsetDateTime};
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("1985-04-12 23:20:50,52z", &Rfc3339Lenient)?,
+///     datetime!(1985-04-12 23:20:50.52 +00:00)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc3339Lenient;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(1985-04-12 23:20:50.52 +00:00).format(&Rfc3339Lenient)?,
+///     "1985-04-12T23:20:50.52Z"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc3339Lenient;
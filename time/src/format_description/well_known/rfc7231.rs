@@ -0,0 +1,46 @@
+//! The HTTP date format defined in RFC 7231.
+
+/// The format of the HTTP `Date`, `Last-Modified`, `If-Modified-Since`, and similar headers, as
+/// described in [RFC 7231](https://datatracker.ietf.org/doc/html/rfc7231#section-7.1.1.1).
+///
+/// Example: Sun, 06 Nov 1994 08:49:37 GMT
+///
+/// When formatting, the preferred `IMF-fixdate` form is always written. As HTTP dates are always
+/// in UTC, formatting a value with any other offset is an error. When parsing, the obsolete
+/// RFC 850 (`Sunday, 06-Nov-94 08:49:37 GMT`) and asctime (`Sun Nov  6 08:49:37 1994`) forms are
+/// accepted as well, all of which produce a UTC offset. Names are case-sensitive, as required by
+/// the RFC. A two-digit year is interpreted as in RFC 2822: values below 50 are in the 2000s and
+/// all others are in the 1900s.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::Rfc7231, OffsetDateTime};
+/// use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &Rfc7231)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &Rfc7231)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &Rfc7231)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc7231;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(1994-11-06 08:49:37 UTC).format(&Rfc7231)?,
+///     "Sun, 06 Nov 1994 08:49:37 GMT"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc7231;
@@ -0,0 +1,39 @@
+//! The timestamp format used by systemd and `journalctl`.
+
+/// The timestamp format used by systemd and `journalctl`, with microsecond precision.
+///
+/// Example: 2024-05-01 13:45:30.123456 UTC
+///
+/// The offset is written as `UTC` when it is zero and as `+HHMM` or `-HHMM` otherwise. When
+/// parsing, the fractional second is optional and, if present, must have exactly six digits; both
+/// forms of a zero offset are accepted. When formatting, six fractional digits are always written,
+/// truncating any sub-microsecond precision.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::Systemd, OffsetDateTime};
+/// use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("2024-05-01 13:45:30.123456 UTC", &Systemd)?,
+///     datetime!(2024-05-01 13:45:30.123_456 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("2024-05-01 15:45:30 +0200", &Systemd)?,
+///     datetime!(2024-05-01 15:45:30 +02:00)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Systemd;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(2024-05-01 13:45:30.123_456_789 -07:00).format(&Systemd)?,
+///     "2024-05-01 13:45:30.123456 -0700"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Systemd;
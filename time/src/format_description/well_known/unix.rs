@@ -0,0 +1,37 @@
+//! The number of seconds since the Unix epoch.
+
+/// The number of seconds since the Unix epoch, with an optional fractional part.
+///
+/// Example: 1714567890.25
+///
+/// When parsing, a sign and up to nine fractional digits are accepted. The resulting value is
+/// always in UTC. When formatting, the fractional part is omitted if it is zero and is otherwise
+/// written without trailing zeros.
+///
+/// Timestamps with a fixed precision and no fractional part can be handled with the
+/// `[unix_timestamp]` component of a format description.
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::Unix, OffsetDateTime};
+/// use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("1714567890.25", &Unix)?,
+///     datetime!(2024-05-01 12:51:30.25 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting-core", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting-core"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Unix;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(2024-05-01 14:51:30.25 +02:00).format(&Unix)?,
+///     "1714567890.25"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unix;
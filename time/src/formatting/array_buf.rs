@@ -0,0 +1,139 @@
+//! A fixed-capacity buffer holding formatted output.
+
+use core::fmt;
+use core::ops::Deref;
+
+use crate::formatting::{FmtWriter, Formattable};
+use crate::{error, Date, Time, UtcOffset};
+
+/// Formatted output stored inline, without allocating.
+///
+/// This is returned by the `format_to_array` methods, such as
+/// [`OffsetDateTime::format_to_array`](crate::OffsetDateTime::format_to_array). The contents are
+/// always valid UTF-8 and can be accessed as a `&str` via [`Deref`]. If the output does not fit
+/// within `N` bytes, [`error::Format::InsufficientCapacity`] is returned instead.
+///
+/// ```rust
+/// # use time::format_description::well_known::Rfc3339;
+/// # use time_macros::datetime;
+/// let output = datetime!(2020-01-02 03:04:05 UTC).format_to_array::<32>(&Rfc3339)?;
+/// assert_eq!(&*output, "2020-01-02T03:04:05Z");
+/// assert_eq!(output.len(), 20);
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Clone, Copy)]
+pub struct ArrayBuf<const N: usize> {
+    /// The buffer, of which the first `len` bytes are valid UTF-8.
+    buf: [u8; N],
+    /// The number of bytes in use.
+    len: usize,
+}
+
+impl<const N: usize> ArrayBuf<N> {
+    /// Format the provided components into a new buffer.
+    pub(crate) fn format(
+        format: &(impl Formattable + ?Sized),
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<Self, error::Format> {
+        let mut buf = [0; N];
+        let mut writer = Writer {
+            buf: &mut buf,
+            len: 0,
+        };
+        format.format_into(&mut FmtWriter(&mut writer), date, time, offset)?;
+        let len = writer.len;
+        if len > N {
+            return Err(error::Format::InsufficientCapacity {
+                capacity: N,
+                required: len,
+            });
+        }
+        Ok(Self { buf, len })
+    }
+
+    /// Obtain the formatted output as a string slice.
+    pub fn as_str(&self) -> &str {
+        // SAFETY: The buffer was only ever written to with complete string slices, and it is only
+        // kept when all of them fit. The contents are therefore valid UTF-8.
+        unsafe { core::str::from_utf8_unchecked(self.as_bytes()) }
+    }
+
+    /// Obtain the formatted output as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl<const N: usize> Deref for ArrayBuf<N> {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for ArrayBuf<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for ArrayBuf<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> PartialEq for ArrayBuf<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for ArrayBuf<N> {}
+
+impl<const N: usize> PartialEq<str> for ArrayBuf<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for ArrayBuf<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> fmt::Display for ArrayBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for ArrayBuf<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Writes to a fixed-capacity buffer. Any string that does not fit is discarded, but is still
+/// counted as written so that the total size of the output is known.
+struct Writer<'a> {
+    /// The buffer being written to.
+    buf: &'a mut [u8],
+    /// The number of bytes written, including those that did not fit.
+    len: usize,
+}
+
+impl fmt::Write for Writer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let end = self.len + s.len();
+        if let Some(dest) = self.buf.get_mut(self.len..end) {
+            dest.copy_from_slice(s.as_bytes());
+        }
+        self.len = end;
+        Ok(())
+    }
+}
@@ -1,14 +1,24 @@
 //! A trait that can be used to format an item from its components.
 
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 use core::ops::Deref;
-use std::io;
 
-use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
-use crate::format_description::{FormatItem, OwnedFormatItem};
+use crate::format_description::well_known::iso8601::{Config, EncodedConfig, FormattedComponents};
+use crate::format_description::well_known::{
+    Iso8601, Iso8601Dynamic, JsDate, Rfc2822, Rfc3339, Rfc3339Lenient, Rfc3339NoZ, Rfc7231,
+    Systemd, Unix,
+};
+use crate::format_description::item_view::{AsItemView, ItemView};
+use crate::format_description::FormatItem;
+#[cfg(feature = "alloc")]
+use crate::format_description::{CompiledFormat, FormatOp, OwnedFormatItem};
 use crate::formatting::{
-    format_component, format_number_pad_zero, iso8601, write, MONTH_NAMES, WEEKDAY_NAMES,
+    check_component, component_is_omitted, format_component, format_number_pad_none,
+    format_number_pad_zero, iso8601, require_date, require_offset, require_time, write, Output,
 };
+#[cfg(feature = "alloc")]
+use crate::formatting::LossyString;
 use crate::{error, Date, Time, UtcOffset};
 
 /// A type that describes a format.
@@ -21,11 +31,22 @@ use crate::{error, Date, Time, UtcOffset};
 pub trait Formattable: sealed::Sealed {}
 impl Formattable for FormatItem<'_> {}
 impl Formattable for [FormatItem<'_>] {}
+#[cfg(feature = "alloc")]
 impl Formattable for OwnedFormatItem {}
+#[cfg(feature = "alloc")]
 impl Formattable for [OwnedFormatItem] {}
+#[cfg(feature = "alloc")]
+impl Formattable for CompiledFormat {}
 impl Formattable for Rfc3339 {}
+impl Formattable for Rfc3339NoZ {}
+impl Formattable for Rfc3339Lenient {}
 impl Formattable for Rfc2822 {}
+impl Formattable for Rfc7231 {}
+impl Formattable for JsDate {}
+impl Formattable for Systemd {}
+impl Formattable for Unix {}
 impl<const CONFIG: EncodedConfig> Formattable for Iso8601<CONFIG> {}
+impl Formattable for Iso8601Dynamic {}
 impl<T: Deref> Formattable for T where T::Target: Formattable {}
 
 /// Seal the trait to prevent downstream users from implementing it.
@@ -38,98 +59,296 @@ mod sealed {
         /// Format the item into the provided output, returning the number of bytes written.
         fn format_into(
             &self,
-            output: &mut impl io::Write,
+            output: &mut impl Output,
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
         ) -> Result<usize, error::Format>;
 
         /// Format the item directly to a `String`.
+        #[cfg(feature = "alloc")]
         fn format(
             &self,
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
         ) -> Result<String, error::Format> {
-            let mut buf = Vec::new();
-            self.format_into(&mut buf, date, time, offset)?;
-            Ok(String::from_utf8_lossy(&buf).into_owned())
+            let mut buf = String::new();
+            self.format_into(&mut LossyString(&mut buf), date, time, offset)?;
+            Ok(buf)
         }
     }
 }
 
 // region: custom formats
+/// Whether a component that is formatted as part of this item is omitted. An optional item
+/// containing such a component is omitted in its entirety, including any literals.
+fn contains_omitted_component<T: AsItemView>(item: &T, offset: Option<UtcOffset>) -> bool {
+    match item.view() {
+        ItemView::Literal(_) | ItemView::Optional(_) => false,
+        ItemView::Component(component) => component_is_omitted(component, offset),
+        ItemView::Compound(items) => items
+            .iter()
+            .any(|item| contains_omitted_component(item, offset)),
+        ItemView::First(items) => items
+            .first()
+            .map_or(false, |item| contains_omitted_component(item, offset)),
+    }
+}
+
+/// Format a [`FormatItem`] or [`OwnedFormatItem`] into the provided output, returning the number
+/// of bytes written.
+fn format_item<T: AsItemView + sealed::Sealed>(
+    item: &T,
+    output: &mut impl Output,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<usize, error::Format> {
+    Ok(match item.view() {
+        ItemView::Literal(literal) => write(output, literal)?,
+        ItemView::Component(component) => format_component(output, component, date, time, offset)?,
+        ItemView::Compound(items) => format_items(items, output, date, time, offset)?,
+        ItemView::Optional(item) if contains_omitted_component(item, offset) => 0,
+        ItemView::Optional(item) => item.format_into(output, date, time, offset)?,
+        ItemView::First(items) => format_first(items, output, date, time, offset)?,
+    })
+}
+
+/// Check that the item can be formatted with the provided information, returning the error that
+/// formatting it would otherwise produce. Nothing is formatted, so this is cheap to repeat for
+/// nested items.
+fn check_item<T: AsItemView>(
+    item: &T,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<(), error::Format> {
+    match item.view() {
+        ItemView::Literal(_) => Ok(()),
+        ItemView::Component(component) => check_component(component, date, time, offset),
+        ItemView::Compound(items) => items
+            .iter()
+            .try_for_each(|item| check_item(item, date, time, offset)),
+        ItemView::Optional(item) if contains_omitted_component(item, offset) => Ok(()),
+        ItemView::Optional(item) => check_item(item, date, time, offset),
+        ItemView::First(items) => first_formattable(items, date, time, offset).map(drop),
+    }
+}
+
+/// Find the first of the provided items that can be formatted. If no item can be formatted, the
+/// error of the last one is returned. An empty slice yields `None`.
+fn first_formattable<T: AsItemView>(
+    items: &[T],
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<Option<&T>, error::Format> {
+    let mut result = Ok(None);
+    for item in items {
+        match check_item(item, date, time, offset) {
+            Ok(()) => return Ok(Some(item)),
+            Err(err) => result = Err(err),
+        }
+    }
+    result
+}
+
+/// Format the first of the provided [`FormatItem`]s or [`OwnedFormatItem`]s that can be
+/// formatted, returning the number of bytes written.
+///
+/// Each item is checked before anything is written, so nothing is written for an item that
+/// fails. If no item can be formatted, the error of the last one is returned. An empty slice
+/// formats nothing.
+fn format_first<T: AsItemView + sealed::Sealed>(
+    items: &[T],
+    output: &mut impl Output,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<usize, error::Format> {
+    match first_formattable(items, date, time, offset)? {
+        Some(item) => item.format_into(output, date, time, offset),
+        None => Ok(0),
+    }
+}
+
+/// Format a sequence of [`FormatItem`]s or [`OwnedFormatItem`]s into the provided output,
+/// returning the number of bytes written.
+fn format_items<T: sealed::Sealed>(
+    items: &[T],
+    output: &mut impl Output,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+    for item in items {
+        bytes += item.format_into(output, date, time, offset)?;
+    }
+    Ok(bytes)
+}
+
+/// Format the operations of a [`CompiledFormat`] into the provided output, returning the number of
+/// bytes written.
+#[cfg(feature = "alloc")]
+fn format_ops(
+    ops: &[FormatOp],
+    output: &mut impl Output,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+    let mut idx = 0;
+    while let Some(op) = ops.get(idx) {
+        idx += 1;
+        match op {
+            FormatOp::Literal(literal) => bytes += write(output, literal)?,
+            FormatOp::Component(component) => {
+                bytes += format_component(output, *component, date, time, offset)?;
+            }
+            // The contents of an optional section directly follow it, so they are formatted by
+            // continuing on unless the section is omitted.
+            FormatOp::Optional { len, components } => {
+                if components
+                    .iter()
+                    .any(|&component| component_is_omitted(component, offset))
+                {
+                    idx += len;
+                }
+            }
+            // As with `FormatItem::First`, each alternative is tried in turn, and the first that
+            // can be formatted is written to the output.
+            FormatOp::First { len } => {
+                if let Some(alternative) =
+                    first_alternative(&ops[idx..][..*len], date, time, offset)?
+                {
+                    bytes += format_ops(alternative, output, date, time, offset)?;
+                }
+                idx += len;
+            }
+            FormatOp::Alternative { .. } => bug!("alternatives are skipped by `FormatOp::First`"),
+        }
+    }
+    Ok(bytes)
+}
+
+/// Check that the operations of a [`CompiledFormat`] can be formatted with the provided
+/// information, returning the error that formatting them would otherwise produce.
+#[cfg(feature = "alloc")]
+fn check_ops(
+    ops: &[FormatOp],
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<(), error::Format> {
+    let mut idx = 0;
+    while let Some(op) = ops.get(idx) {
+        idx += 1;
+        match op {
+            FormatOp::Literal(_) => {}
+            FormatOp::Component(component) => check_component(*component, date, time, offset)?,
+            FormatOp::Optional { len, components } => {
+                if components
+                    .iter()
+                    .any(|&component| component_is_omitted(component, offset))
+                {
+                    idx += len;
+                }
+            }
+            FormatOp::First { len } => {
+                first_alternative(&ops[idx..][..*len], date, time, offset)?;
+                idx += len;
+            }
+            FormatOp::Alternative { .. } => bug!("alternatives are skipped by `FormatOp::First`"),
+        }
+    }
+    Ok(())
+}
+
+/// Find the first of the alternatives of a [`FormatOp::First`] that can be formatted. If no
+/// alternative can be formatted, the error of the last one is returned. If there are no
+/// alternatives, `None` is returned.
+#[cfg(feature = "alloc")]
+fn first_alternative(
+    mut alternatives: &[FormatOp],
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<Option<&[FormatOp]>, error::Format> {
+    let mut result = Ok(None);
+    while let [FormatOp::Alternative { len }, rest @ ..] = alternatives {
+        let (alternative, rest) = rest.split_at(*len);
+        match check_ops(alternative, date, time, offset) {
+            Ok(()) => return Ok(Some(alternative)),
+            Err(err) => result = Err(err),
+        }
+        alternatives = rest;
+    }
+    result
+}
+
 impl<'a> sealed::Sealed for FormatItem<'a> {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
     ) -> Result<usize, error::Format> {
-        Ok(match *self {
-            Self::Literal(literal) => write(output, literal)?,
-            Self::Component(component) => format_component(output, component, date, time, offset)?,
-            Self::Compound(items) => items.format_into(output, date, time, offset)?,
-            Self::Optional(item) => item.format_into(output, date, time, offset)?,
-            Self::First(items) => match items {
-                [] => 0,
-                [item, ..] => item.format_into(output, date, time, offset)?,
-            },
-        })
+        format_item(self, output, date, time, offset)
     }
 }
 
 impl<'a> sealed::Sealed for [FormatItem<'a>] {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
     ) -> Result<usize, error::Format> {
-        let mut bytes = 0;
-        for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
-        }
-        Ok(bytes)
+        format_items(self, output, date, time, offset)
     }
 }
 
+#[cfg(feature = "alloc")]
 impl sealed::Sealed for OwnedFormatItem {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
     ) -> Result<usize, error::Format> {
-        match self {
-            Self::Literal(literal) => Ok(write(output, literal)?),
-            Self::Component(component) => format_component(output, *component, date, time, offset),
-            Self::Compound(items) => items.format_into(output, date, time, offset),
-            Self::Optional(item) => item.format_into(output, date, time, offset),
-            Self::First(items) => match &**items {
-                [] => Ok(0),
-                [item, ..] => item.format_into(output, date, time, offset),
-            },
-        }
+        format_item(self, output, date, time, offset)
     }
 }
 
+#[cfg(feature = "alloc")]
 impl sealed::Sealed for [OwnedFormatItem] {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
     ) -> Result<usize, error::Format> {
-        let mut bytes = 0;
-        for item in self.iter() {
-            bytes += item.format_into(output, date, time, offset)?;
-        }
-        Ok(bytes)
+        format_items(self, output, date, time, offset)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for CompiledFormat {
+    fn format_into(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        format_ops(&self.ops, output, date, time, offset)
     }
 }
 
@@ -139,7 +358,7 @@ where
 {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
@@ -153,34 +372,31 @@ where
 impl sealed::Sealed for Rfc2822 {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
     ) -> Result<usize, error::Format> {
-        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
-        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
-        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+        let date = require_date(date)?;
+        let time = require_time(time)?;
+        let offset = require_offset(offset)?;
 
         let mut bytes = 0;
 
         let (year, month, day) = date.to_calendar_date();
 
-        if year < 1900 {
+        if !(1900..10_000).contains(&year) {
             return Err(error::Format::InvalidComponent("year"));
         }
         if offset.seconds_past_minute() != 0 {
             return Err(error::Format::InvalidComponent("offset_second"));
         }
 
-        bytes += write(
-            output,
-            &WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize][..3],
-        )?;
+        bytes += write(output, date.weekday().short_name().as_bytes())?;
         bytes += write(output, b", ")?;
         bytes += format_number_pad_zero::<2>(output, day)?;
         bytes += write(output, b" ")?;
-        bytes += write(output, &MONTH_NAMES[month as usize - 1][..3])?;
+        bytes += write(output, month.short_name().as_bytes())?;
         bytes += write(output, b" ")?;
         bytes += format_number_pad_zero::<4>(output, year as u32)?;
         bytes += write(output, b" ")?;
@@ -198,21 +414,214 @@ impl sealed::Sealed for Rfc2822 {
     }
 }
 
-impl sealed::Sealed for Rfc3339 {
+impl sealed::Sealed for Rfc7231 {
+    fn format_into(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let date = require_date(date)?;
+        let time = require_time(time)?;
+        let offset = require_offset(offset)?;
+
+        let mut bytes = 0;
+
+        let (year, month, day) = date.to_calendar_date();
+
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        if offset != UtcOffset::UTC {
+            return Err(error::Format::InvalidComponent("offset"));
+        }
+
+        bytes += write(output, date.weekday().short_name().as_bytes())?;
+        bytes += write(output, b", ")?;
+        bytes += format_number_pad_zero::<2>(output, day)?;
+        bytes += write(output, b" ")?;
+        bytes += write(output, month.short_name().as_bytes())?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<4>(output, year as u32)?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<2>(output, time.hour())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.minute())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.second())?;
+        bytes += write(output, b" GMT")?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for JsDate {
+    fn format_into(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let date = require_date(date)?;
+        let time = require_time(time)?;
+        let offset = require_offset(offset)?;
+
+        let mut bytes = 0;
+
+        let (year, month, day) = date.to_calendar_date();
+
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        if offset.seconds_past_minute() != 0 {
+            return Err(error::Format::InvalidComponent("offset_second"));
+        }
+
+        bytes += write(output, date.weekday().short_name().as_bytes())?;
+        bytes += write(output, b" ")?;
+        bytes += write(output, month.short_name().as_bytes())?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<2>(output, day)?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<4>(output, year as u32)?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<2>(output, time.hour())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.minute())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.second())?;
+        bytes += write(output, b" GMT")?;
+        bytes += write(output, if offset.is_negative() { b"-" } else { b"+" })?;
+        bytes += format_number_pad_zero::<2>(output, offset.whole_hours().unsigned_abs())?;
+        bytes += format_number_pad_zero::<2>(output, offset.minutes_past_hour().unsigned_abs())?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for Systemd {
+    fn format_into(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let date = require_date(date)?;
+        let time = require_time(time)?;
+        let offset = require_offset(offset)?;
+
+        let mut bytes = 0;
+
+        let (year, month, day) = date.to_calendar_date();
+
+        if !(0..10_000).contains(&year) {
+            return Err(error::Format::InvalidComponent("year"));
+        }
+        if offset.seconds_past_minute() != 0 {
+            return Err(error::Format::InvalidComponent("offset_second"));
+        }
+
+        bytes += format_number_pad_zero::<4>(output, year as u32)?;
+        bytes += write(output, b"-")?;
+        bytes += format_number_pad_zero::<2>(output, month as u8)?;
+        bytes += write(output, b"-")?;
+        bytes += format_number_pad_zero::<2>(output, day)?;
+        bytes += write(output, b" ")?;
+        bytes += format_number_pad_zero::<2>(output, time.hour())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.minute())?;
+        bytes += write(output, b":")?;
+        bytes += format_number_pad_zero::<2>(output, time.second())?;
+        bytes += write(output, b".")?;
+        bytes += format_number_pad_zero::<6>(output, time.microsecond())?;
+
+        if offset == UtcOffset::UTC {
+            bytes += write(output, b" UTC")?;
+            return Ok(bytes);
+        }
+
+        bytes += write(output, if offset.is_negative() { b" -" } else { b" +" })?;
+        bytes += format_number_pad_zero::<2>(output, offset.whole_hours().unsigned_abs())?;
+        bytes += format_number_pad_zero::<2>(output, offset.minutes_past_hour().unsigned_abs())?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for Unix {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let date = require_date(date)?;
+        let time = require_time(time)?;
+        let offset = require_offset(offset)?;
+
+        let mut bytes = 0;
+
+        let timestamp = date
+            .with_time(time)
+            .assume_offset(offset)
+            .unix_timestamp_nanos();
+        let seconds = (timestamp / 1_000_000_000).unsigned_abs();
+        let nanos = (timestamp % 1_000_000_000).unsigned_abs() as u32;
+
+        if timestamp < 0 {
+            bytes += write(output, b"-")?;
+        }
+        bytes += format_number_pad_none(output, seconds)?;
+
+        #[allow(clippy::if_not_else)]
+        if nanos != 0 {
+            bytes += write(output, b".")?;
+            bytes += if nanos % 10 != 0 {
+                format_number_pad_zero::<9>(output, nanos)
+            } else if (nanos / 10) % 10 != 0 {
+                format_number_pad_zero::<8>(output, nanos / 10)
+            } else if (nanos / 100) % 10 != 0 {
+                format_number_pad_zero::<7>(output, nanos / 100)
+            } else if (nanos / 1_000) % 10 != 0 {
+                format_number_pad_zero::<6>(output, nanos / 1_000)
+            } else if (nanos / 10_000) % 10 != 0 {
+                format_number_pad_zero::<5>(output, nanos / 10_000)
+            } else if (nanos / 100_000) % 10 != 0 {
+                format_number_pad_zero::<4>(output, nanos / 100_000)
+            } else if (nanos / 1_000_000) % 10 != 0 {
+                format_number_pad_zero::<3>(output, nanos / 1_000_000)
+            } else if (nanos / 10_000_000) % 10 != 0 {
+                format_number_pad_zero::<2>(output, nanos / 10_000_000)
+            } else {
+                format_number_pad_zero::<1>(output, nanos / 100_000_000)
+            }?;
+        }
+
+        Ok(bytes)
+    }
+}
+
+impl Rfc3339 {
+    /// Format the components as RFC 3339. UTC is formatted as `Z` if `utc_as_z` is true and as
+    /// `+00:00` otherwise.
+    fn format_into_with(
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
+        utc_as_z: bool,
     ) -> Result<usize, error::Format> {
-        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
-        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
-        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+        let date = require_date(date)?;
+        let time = require_time(time)?;
+        let offset = require_offset(offset)?;
 
         let mut bytes = 0;
 
-        let year = date.year();
+        let (year, month, day) = date.to_calendar_date();
 
         if !(0..10_000).contains(&year) {
             return Err(error::Format::InvalidComponent("year"));
@@ -223,9 +632,9 @@ impl sealed::Sealed for Rfc3339 {
 
         bytes += format_number_pad_zero::<4>(output, year as u32)?;
         bytes += write(output, b"-")?;
-        bytes += format_number_pad_zero::<2>(output, date.month() as u8)?;
+        bytes += format_number_pad_zero::<2>(output, month as u8)?;
         bytes += write(output, b"-")?;
-        bytes += format_number_pad_zero::<2>(output, date.day())?;
+        bytes += format_number_pad_zero::<2>(output, day)?;
         bytes += write(output, b"T")?;
         bytes += format_number_pad_zero::<2>(output, time.hour())?;
         bytes += write(output, b":")?;
@@ -258,7 +667,7 @@ impl sealed::Sealed for Rfc3339 {
             }?;
         }
 
-        if offset == UtcOffset::UTC {
+        if utc_as_z && offset == UtcOffset::UTC {
             bytes += write(output, b"Z")?;
             return Ok(bytes);
         }
@@ -272,36 +681,92 @@ impl sealed::Sealed for Rfc3339 {
     }
 }
 
-impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+impl sealed::Sealed for Rfc3339 {
     fn format_into(
         &self,
-        output: &mut impl io::Write,
+        output: &mut impl Output,
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
     ) -> Result<usize, error::Format> {
-        let mut bytes = 0;
+        Self::format_into_with(output, date, time, offset, true)
+    }
+}
 
-        if Self::FORMAT_DATE {
-            let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
-            bytes += iso8601::format_date::<CONFIG>(output, date)?;
-        }
-        if Self::FORMAT_TIME {
-            let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
-            bytes += iso8601::format_time::<CONFIG>(output, time)?;
-        }
-        if Self::FORMAT_OFFSET {
-            let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
-            bytes += iso8601::format_offset::<CONFIG>(output, offset)?;
-        }
+impl sealed::Sealed for Rfc3339NoZ {
+    fn format_into(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        Rfc3339::format_into_with(output, date, time, offset, false)
+    }
+}
 
-        if bytes == 0 {
-            // The only reason there would be no bytes written is if the format was only for
-            // parsing.
-            panic!("attempted to format a parsing-only format description");
-        }
+impl sealed::Sealed for Rfc3339Lenient {
+    fn format_into(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        Rfc3339.format_into(output, date, time, offset)
+    }
+}
 
-        Ok(bytes)
+/// Format the value as ISO 8601 using the provided configuration.
+fn format_iso8601(
+    config: &Config,
+    output: &mut impl Output,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<usize, error::Format> {
+    if config.formatted_components == FormattedComponents::None {
+        return Err(error::Format::InvalidFormatDescription);
+    }
+
+    let mut bytes = 0;
+    if config.format_date() {
+        let date = require_date(date)?;
+        bytes += iso8601::format_date(output, date, config)?;
+    }
+    if config.format_time() {
+        let time = require_time(time)?;
+        bytes += iso8601::format_time(output, time, config)?;
+    }
+    if config.format_offset() {
+        let offset = require_offset(offset)?;
+        bytes += iso8601::format_offset(output, offset, config)?;
+    }
+
+    Ok(bytes)
+}
+
+impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+    fn format_into(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        format_iso8601(&Self::CONFIG, output, date, time, offset)
+    }
+}
+
+impl sealed::Sealed for Iso8601Dynamic {
+    fn format_into(
+        &self,
+        output: &mut impl Output,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        format_iso8601(&self.config, output, date, time, offset)
     }
 }
 // endregion well-known formats
@@ -0,0 +1,102 @@
+//! A value that is formatted lazily when displayed.
+
+use core::cell::Cell;
+use core::fmt;
+
+use crate::formatting::{FmtWriter, Formattable};
+use crate::{error, Date, Time, UtcOffset};
+
+/// A value paired with a [format description](crate::format_description), which is formatted
+/// directly into the output when displayed rather than allocating a `String`.
+///
+/// This is returned by the `display` methods, such as
+/// [`OffsetDateTime::display`](crate::OffsetDateTime::display).
+///
+/// ```rust
+/// # use core::fmt::Write;
+/// # use time::format_description::well_known::Rfc3339;
+/// # use time_macros::datetime;
+/// let mut line = String::new();
+/// write!(line, "[{}] started", datetime!(2020-01-02 03:04:05 UTC).display(&Rfc3339))?;
+/// assert_eq!(line, "[2020-01-02T03:04:05Z] started");
+/// # Ok::<_, core::fmt::Error>(())
+/// ```
+///
+/// If formatting fails, [`fmt::Error`] is returned from the `Display` implementation, and the
+/// underlying error can be obtained with [`Formatted::take_error`]. Note that the standard library
+/// treats an error from `Display` as a bug, so using this with methods such as `to_string` or
+/// `format!` will panic if formatting fails.
+pub struct Formatted<'a, F: ?Sized> {
+    /// The date to format, if any.
+    date: Option<Date>,
+    /// The time to format, if any.
+    time: Option<Time>,
+    /// The UTC offset to format, if any.
+    offset: Option<UtcOffset>,
+    /// The format description to use.
+    format: &'a F,
+    /// The error that occurred the last time the value was displayed, if any.
+    error: Cell<Option<error::Format>>,
+}
+
+impl<'a, F: Formattable + ?Sized> Formatted<'a, F> {
+    /// Pair the provided components with a format description.
+    pub(crate) const fn new(
+        format: &'a F,
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Self {
+        Self {
+            date,
+            time,
+            offset,
+            format,
+            error: Cell::new(None),
+        }
+    }
+
+    /// Obtain the error that caused the most recent attempt at displaying the value to fail, if
+    /// any. The error is removed, so subsequent calls return `None` until it fails again.
+    ///
+    /// ```rust
+    /// # use core::fmt::Write;
+    /// # use time::error;
+    /// # use time_macros::{format_description, time};
+    /// let formatted = time!(12:00).display(format_description!("[year]"));
+    /// assert!(write!(String::new(), "{formatted}").is_err());
+    /// assert!(matches!(
+    ///     formatted.take_error(),
+    ///     Some(error::Format::InsufficientTypeInformation { .. })
+    /// ));
+    /// assert!(formatted.take_error().is_none());
+    /// ```
+    pub fn take_error(&self) -> Option<error::Format> {
+        self.error.take()
+    }
+}
+
+impl<F: Formattable + ?Sized> fmt::Display for Formatted<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self
+            .format
+            .format_into(&mut FmtWriter(f), self.date, self.time, self.offset)
+        {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                self.error.set(Some(err));
+                Err(fmt::Error)
+            }
+        }
+    }
+}
+
+impl<F: ?Sized> fmt::Debug for Formatted<'_, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Formatted")
+            .field("date", &self.date)
+            .field("time", &self.time)
+            .field("offset", &self.offset)
+            .finish_non_exhaustive()
+    }
+}
@@ -1,26 +1,31 @@
 //! Helpers for implementing formatting for ISO 8601.
 
-use std::io;
+use core::num::NonZeroU8;
 
 use crate::convert::*;
+use crate::duration::Iso8601Components;
 use crate::format_description::well_known::iso8601::{
-    DateKind, EncodedConfig, OffsetPrecision, TimePrecision,
+    Config, DateKind, OffsetPrecision, TimePrecision,
 };
-use crate::format_description::well_known::Iso8601;
-use crate::formatting::{format_float, format_number_pad_zero, write, write_if, write_if_else};
-use crate::{error, Date, Time, UtcOffset};
+use crate::format_description::well_known::Iso8601Duration;
+use crate::formatting::{
+    format_float, format_number_pad_none, format_number_pad_zero, write, write_if, write_if_else,
+    DigitCount, Output,
+};
+use crate::{error, Date, Duration, Time, UtcOffset};
 
 /// Format the date portion of ISO 8601.
-pub(super) fn format_date<const CONFIG: EncodedConfig>(
-    output: &mut impl io::Write,
+pub(super) fn format_date(
+    output: &mut impl Output,
     date: Date,
+    config: &Config,
 ) -> Result<usize, error::Format> {
     let mut bytes = 0;
 
-    match Iso8601::<CONFIG>::DATE_KIND {
+    match config.date_kind {
         DateKind::Calendar => {
             let (year, month, day) = date.to_calendar_date();
-            if Iso8601::<CONFIG>::YEAR_IS_SIX_DIGITS {
+            if config.year_is_six_digits {
                 bytes += write_if_else(output, year < 0, b"-", b"+")?;
                 bytes += format_number_pad_zero::<6>(output, year.unsigned_abs())?;
             } else if !(0..=9999).contains(&year) {
@@ -28,14 +33,14 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
             } else {
                 bytes += format_number_pad_zero::<4>(output, year as u32)?;
             }
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-")?;
+            bytes += write_if(output, config.use_separators, b"-")?;
             bytes += format_number_pad_zero::<2>(output, month as u8)?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-")?;
+            bytes += write_if(output, config.use_separators, b"-")?;
             bytes += format_number_pad_zero::<2>(output, day)?;
         }
         DateKind::Week => {
             let (year, week, day) = date.to_iso_week_date();
-            if Iso8601::<CONFIG>::YEAR_IS_SIX_DIGITS {
+            if config.year_is_six_digits {
                 bytes += write_if_else(output, year < 0, b"-", b"+")?;
                 bytes += format_number_pad_zero::<6>(output, year.unsigned_abs())?;
             } else if !(0..=9999).contains(&year) {
@@ -43,14 +48,14 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
             } else {
                 bytes += format_number_pad_zero::<4>(output, year as u32)?;
             }
-            bytes += write_if_else(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-W", b"W")?;
+            bytes += write_if_else(output, config.use_separators, b"-W", b"W")?;
             bytes += format_number_pad_zero::<2>(output, week)?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-")?;
+            bytes += write_if(output, config.use_separators, b"-")?;
             bytes += format_number_pad_zero::<1>(output, day.number_from_monday())?;
         }
         DateKind::Ordinal => {
             let (year, day) = date.to_ordinal_date();
-            if Iso8601::<CONFIG>::YEAR_IS_SIX_DIGITS {
+            if config.year_is_six_digits {
                 bytes += write_if_else(output, year < 0, b"-", b"+")?;
                 bytes += format_number_pad_zero::<6>(output, year.unsigned_abs())?;
             } else if !(0..=9999).contains(&year) {
@@ -58,7 +63,7 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
             } else {
                 bytes += format_number_pad_zero::<4>(output, year as u32)?;
             }
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b"-")?;
+            bytes += write_if(output, config.use_separators, b"-")?;
             bytes += format_number_pad_zero::<3>(output, day)?;
         }
     }
@@ -67,22 +72,19 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
 }
 
 /// Format the time portion of ISO 8601.
-pub(super) fn format_time<const CONFIG: EncodedConfig>(
-    output: &mut impl io::Write,
+pub(super) fn format_time(
+    output: &mut impl Output,
     time: Time,
+    config: &Config,
 ) -> Result<usize, error::Format> {
     let mut bytes = 0;
 
     // The "T" can only be omitted in extended format where there is no date being formatted.
-    bytes += write_if(
-        output,
-        Iso8601::<CONFIG>::USE_SEPARATORS || Iso8601::<CONFIG>::FORMAT_DATE,
-        b"T",
-    )?;
+    bytes += write_if(output, config.use_separators || config.format_date(), b"T")?;
 
     let (hours, minutes, seconds, nanoseconds) = time.as_hms_nano();
 
-    match Iso8601::<CONFIG>::TIME_PRECISION {
+    match config.time_precision {
         TimePrecision::Hour { decimal_digits } => {
             let hours = (hours as f64)
                 + (minutes as f64) / Minute.per(Hour) as f64
@@ -92,7 +94,7 @@ pub(super) fn format_time<const CONFIG: EncodedConfig>(
         }
         TimePrecision::Minute { decimal_digits } => {
             bytes += format_number_pad_zero::<2>(output, hours)?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b":")?;
+            bytes += write_if(output, config.use_separators, b":")?;
             let minutes = (minutes as f64)
                 + (seconds as f64) / Second.per(Minute) as f64
                 + (nanoseconds as f64) / Nanosecond.per(Minute) as f64;
@@ -100,9 +102,9 @@ pub(super) fn format_time<const CONFIG: EncodedConfig>(
         }
         TimePrecision::Second { decimal_digits } => {
             bytes += format_number_pad_zero::<2>(output, hours)?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b":")?;
+            bytes += write_if(output, config.use_separators, b":")?;
             bytes += format_number_pad_zero::<2>(output, minutes)?;
-            bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b":")?;
+            bytes += write_if(output, config.use_separators, b":")?;
             let seconds = (seconds as f64) + (nanoseconds as f64) / Nanosecond.per(Second) as f64;
             bytes += format_float(output, seconds, 2, decimal_digits)?;
         }
@@ -112,29 +114,89 @@ pub(super) fn format_time<const CONFIG: EncodedConfig>(
 }
 
 /// Format the UTC offset portion of ISO 8601.
-pub(super) fn format_offset<const CONFIG: EncodedConfig>(
-    output: &mut impl io::Write,
+pub(super) fn format_offset(
+    output: &mut impl Output,
     offset: UtcOffset,
+    config: &Config,
 ) -> Result<usize, error::Format> {
-    if Iso8601::<CONFIG>::FORMAT_TIME && offset.is_utc() {
-        return Ok(write(output, b"Z")?);
+    if config.format_time() && offset.is_utc() {
+        return write(output, b"Z");
     }
 
     let mut bytes = 0;
 
+    // Components that are not formatted must be zero, as they would otherwise be silently dropped.
     let (hours, minutes, seconds) = offset.as_hms();
-    if seconds != 0 {
+    if config.offset_precision == OffsetPrecision::Hour && minutes != 0 {
+        return Err(error::Format::InvalidComponent("offset_minute"));
+    }
+    if config.offset_precision != OffsetPrecision::Second && seconds != 0 {
         return Err(error::Format::InvalidComponent("offset_second"));
     }
+
     bytes += write_if_else(output, offset.is_negative(), b"-", b"+")?;
     bytes += format_number_pad_zero::<2>(output, hours.unsigned_abs())?;
 
-    if Iso8601::<CONFIG>::OFFSET_PRECISION == OffsetPrecision::Hour && minutes != 0 {
-        return Err(error::Format::InvalidComponent("offset_minute"));
-    } else if Iso8601::<CONFIG>::OFFSET_PRECISION == OffsetPrecision::Minute {
-        bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b":")?;
+    if config.offset_precision != OffsetPrecision::Hour {
+        bytes += write_if(output, config.use_separators, b":")?;
         bytes += format_number_pad_zero::<2>(output, minutes.unsigned_abs())?;
     }
+    if config.offset_precision == OffsetPrecision::Second {
+        bytes += write_if(output, config.use_separators, b":")?;
+        bytes += format_number_pad_zero::<2>(output, seconds.unsigned_abs())?;
+    }
+
+    Ok(bytes)
+}
+
+/// Format a [`Duration`] using the ISO 8601 duration format, such as `P1DT2H30M`.
+pub(crate) fn format_duration(
+    output: &mut impl Output,
+    duration: Duration,
+    format: &Iso8601Duration,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+
+    let components =
+        duration.iso8601_components(format.use_days, format.decimal_digits.map(NonZeroU8::get));
+    let write_seconds = components.has_seconds();
+    let Iso8601Components {
+        days,
+        hours,
+        minutes,
+        seconds,
+        fraction,
+        digits,
+    } = components;
+
+    bytes += write_if(output, duration.is_negative(), b"-")?;
+    bytes += write(output, b"P")?;
+    if days != 0 {
+        bytes += format_number_pad_none(output, days)?;
+        bytes += write(output, b"D")?;
+    }
+    if hours != 0 || minutes != 0 || write_seconds {
+        bytes += write(output, b"T")?;
+    }
+    if hours != 0 {
+        bytes += format_number_pad_none(output, hours)?;
+        bytes += write(output, b"H")?;
+    }
+    if minutes != 0 {
+        bytes += format_number_pad_none(output, minutes)?;
+        bytes += write(output, b"M")?;
+    }
+    if write_seconds {
+        bytes += format_number_pad_none(output, seconds)?;
+        if digits != 0 {
+            bytes += write(output, b".")?;
+            for _ in fraction.num_digits()..digits {
+                bytes += write(output, b"0")?;
+            }
+            bytes += format_number_pad_none(output, fraction)?;
+        }
+        bytes += write(output, b"S")?;
+    }
 
     Ok(bytes)
 }
@@ -1,42 +1,24 @@
 //! Formatting for various types.
 
+mod array_buf;
 pub(crate) mod formattable;
+mod formatted;
 mod iso8601;
+mod output;
 
 use core::num::NonZeroU8;
-use std::io;
 
+pub use self::array_buf::ArrayBuf;
 pub use self::formattable::Formattable;
+pub use self::formatted::Formatted;
+pub(crate) use self::iso8601::format_duration as format_iso8601_duration;
+#[cfg(feature = "alloc")]
+pub(crate) use self::output::LossyString;
+pub(crate) use self::output::{Discard, FmtWriter, Output, SliceWriter};
 use crate::convert::*;
 use crate::format_description::{modifier, Component};
-use crate::{error, Date, OffsetDateTime, Time, UtcOffset};
-
-#[allow(clippy::missing_docs_in_private_items)]
-const MONTH_NAMES: [&[u8]; 12] = [
-    b"January",
-    b"February",
-    b"March",
-    b"April",
-    b"May",
-    b"June",
-    b"July",
-    b"August",
-    b"September",
-    b"October",
-    b"November",
-    b"December",
-];
-
-#[allow(clippy::missing_docs_in_private_items)]
-const WEEKDAY_NAMES: [&[u8]; 7] = [
-    b"Monday",
-    b"Tuesday",
-    b"Wednesday",
-    b"Thursday",
-    b"Friday",
-    b"Saturday",
-    b"Sunday",
-];
+use crate::util::ordinal_suffix;
+use crate::{error, Date, OffsetDateTime, Period, Time, UtcOffset};
 
 // region: extension trait
 /// A trait that indicates the formatted width of the value can be determined.
@@ -119,23 +101,27 @@ impl DigitCount for u32 {
 // endregion extension trait
 
 /// Write all bytes to the output, returning the number of bytes written.
-pub(crate) fn write(output: &mut impl io::Write, bytes: &[u8]) -> io::Result<usize> {
+pub(crate) fn write(output: &mut impl Output, bytes: &[u8]) -> Result<usize, error::Format> {
     output.write_all(bytes)?;
     Ok(bytes.len())
 }
 
 /// If `pred` is true, write all bytes to the output, returning the number of bytes written.
-pub(crate) fn write_if(output: &mut impl io::Write, pred: bool, bytes: &[u8]) -> io::Result<usize> {
+pub(crate) fn write_if(
+    output: &mut impl Output,
+    pred: bool,
+    bytes: &[u8],
+) -> Result<usize, error::Format> {
     if pred { write(output, bytes) } else { Ok(0) }
 }
 
 /// If `pred` is true, write `true_bytes` to the output. Otherwise, write `false_bytes`.
 pub(crate) fn write_if_else(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     pred: bool,
     true_bytes: &[u8],
     false_bytes: &[u8],
-) -> io::Result<usize> {
+) -> Result<usize, error::Format> {
     write(output, if pred { true_bytes } else { false_bytes })
 }
 
@@ -144,11 +130,11 @@ pub(crate) fn write_if_else(
 /// This method accepts the number of digits before and after the decimal. The value will be padded
 /// with zeroes to the left if necessary.
 pub(crate) fn format_float(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     value: f64,
     digits_before_decimal: u8,
     digits_after_decimal: Option<NonZeroU8>,
-) -> io::Result<usize> {
+) -> Result<usize, error::Format> {
     match digits_after_decimal {
         Some(digits_after_decimal) => {
             let digits_after_decimal = digits_after_decimal.get() as usize;
@@ -157,7 +143,7 @@ pub(crate) fn format_float(
             Ok(width)
         }
         None => {
-            let value = value.trunc() as u64;
+            let value = value as u64;
             let width = digits_before_decimal as usize;
             write!(output, "{value:0>width$}")?;
             Ok(width)
@@ -169,10 +155,10 @@ pub(crate) fn format_float(
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number<const WIDTH: u8>(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     value: impl itoa::Integer + DigitCount + Copy,
     padding: modifier::Padding,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     match padding {
         modifier::Padding::Space => format_number_pad_space::<WIDTH>(output, value),
         modifier::Padding::Zero => format_number_pad_zero::<WIDTH>(output, value),
@@ -180,13 +166,37 @@ pub(crate) fn format_number<const WIDTH: u8>(
     }
 }
 
+/// Format a number with the provided sign, padding, and width. The sign may be empty.
+///
+/// When padding with spaces, the padding precedes the sign so that the sign is adjacent to the
+/// digits. Otherwise the sign is written first.
+fn format_signed_number<const WIDTH: u8>(
+    output: &mut impl Output,
+    sign: &[u8],
+    value: impl itoa::Integer + DigitCount + Copy,
+    padding: modifier::Padding,
+) -> Result<usize, error::Format> {
+    let mut bytes = 0;
+    if padding == modifier::Padding::Space {
+        for _ in 0..(WIDTH.saturating_sub(value.num_digits())) {
+            bytes += write(output, b" ")?;
+        }
+        bytes += write(output, sign)?;
+        bytes += format_number_pad_none(output, value)?;
+    } else {
+        bytes += write(output, sign)?;
+        bytes += format_number::<WIDTH>(output, value, padding)?;
+    }
+    Ok(bytes)
+}
+
 /// Format a number with the provided width and spaces as padding.
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number_pad_space<const WIDTH: u8>(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     value: impl itoa::Integer + DigitCount + Copy,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let mut bytes = 0;
     for _ in 0..(WIDTH.saturating_sub(value.num_digits())) {
         bytes += write(output, b" ")?;
@@ -199,9 +209,9 @@ pub(crate) fn format_number_pad_space<const WIDTH: u8>(
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number_pad_zero<const WIDTH: u8>(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     value: impl itoa::Integer + DigitCount + Copy,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let mut bytes = 0;
     for _ in 0..(WIDTH.saturating_sub(value.num_digits())) {
         bytes += write(output, b"0")?;
@@ -214,100 +224,174 @@ pub(crate) fn format_number_pad_zero<const WIDTH: u8>(
 ///
 /// If the sign is mandatory, the sign must be written by the caller.
 pub(crate) fn format_number_pad_none(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     value: impl itoa::Integer + Copy,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     write(output, itoa::Buffer::new().format(value).as_bytes())
 }
 
+/// Obtain the date, returning an error if the value being formatted does not have one.
+pub(crate) fn require_date(date: Option<Date>) -> Result<Date, error::Format> {
+    date.ok_or(error::Format::InsufficientTypeInformation {
+        missing: error::MissingInformation::Date,
+    })
+}
+
+/// Obtain the time, returning an error if the value being formatted does not have one.
+pub(crate) fn require_time(time: Option<Time>) -> Result<Time, error::Format> {
+    time.ok_or(error::Format::InsufficientTypeInformation {
+        missing: error::MissingInformation::Time,
+    })
+}
+
+/// Obtain the offset, returning an error if the value being formatted does not have one.
+pub(crate) fn require_offset(offset: Option<UtcOffset>) -> Result<UtcOffset, error::Format> {
+    offset.ok_or(error::Format::InsufficientTypeInformation {
+        missing: error::MissingInformation::Offset,
+    })
+}
+
 /// Format the provided component into the designated output. An `Err` will be returned if the
 /// component requires information that it does not provide or if the value cannot be output to the
 /// stream.
 pub(crate) fn format_component(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     component: Component,
     date: Option<Date>,
     time: Option<Time>,
     offset: Option<UtcOffset>,
 ) -> Result<usize, error::Format> {
     use Component::*;
-    Ok(match (component, date, time, offset) {
-        (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier)?,
-        (Month(modifier), Some(date), ..) => fmt_month(output, date, modifier)?,
-        (Ordinal(modifier), Some(date), ..) => fmt_ordinal(output, date, modifier)?,
-        (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier)?,
-        (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier)?,
-        (Year(modifier), Some(date), ..) => fmt_year(output, date, modifier)?,
-        (Hour(modifier), _, Some(time), _) => fmt_hour(output, time, modifier)?,
-        (Minute(modifier), _, Some(time), _) => fmt_minute(output, time, modifier)?,
-        (Period(modifier), _, Some(time), _) => fmt_period(output, time, modifier)?,
-        (Second(modifier), _, Some(time), _) => fmt_second(output, time, modifier)?,
-        (Subsecond(modifier), _, Some(time), _) => fmt_subsecond(output, time, modifier)?,
-        (OffsetHour(modifier), .., Some(offset)) => fmt_offset_hour(output, offset, modifier)?,
-        (OffsetMinute(modifier), .., Some(offset)) => fmt_offset_minute(output, offset, modifier)?,
-        (OffsetSecond(modifier), .., Some(offset)) => fmt_offset_second(output, offset, modifier)?,
-        (Ignore(_), ..) => 0,
-        (UnixTimestamp(modifier), Some(date), Some(time), Some(offset)) => {
-            fmt_unix_timestamp(output, date, time, offset, modifier)?
-        }
-        _ => return Err(error::Format::InsufficientTypeInformation),
+    Ok(match component {
+        Day(modifier) => fmt_day(output, require_date(date)?, modifier)?,
+        Month(modifier) => fmt_month(output, require_date(date)?, modifier)?,
+        Ordinal(modifier) => fmt_ordinal(output, require_date(date)?, modifier)?,
+        Weekday(modifier) => fmt_weekday(output, require_date(date)?, modifier)?,
+        WeekNumber(modifier) => fmt_week_number(output, require_date(date)?, modifier)?,
+        Year(modifier) => fmt_year(output, require_date(date)?, modifier)?,
+        Hour(modifier) => fmt_hour(output, require_time(time)?, modifier)?,
+        Minute(modifier) => fmt_minute(output, require_time(time)?, modifier)?,
+        Period(modifier) => fmt_period(output, require_time(time)?, modifier)?,
+        Second(modifier) => fmt_second(output, require_time(time)?, modifier)?,
+        Subsecond(modifier) => fmt_subsecond(output, require_time(time)?, modifier)?,
+        OffsetHour(modifier) => fmt_offset_hour(output, require_offset(offset)?, modifier)?,
+        OffsetMinute(modifier) => fmt_offset_minute(output, require_offset(offset)?, modifier)?,
+        OffsetSecond(modifier) => fmt_offset_second(output, require_offset(offset)?, modifier)?,
+        Offset(modifier) => fmt_offset(output, require_offset(offset)?, modifier)?,
+        Ignore(_) => 0,
+        UnixTimestamp(modifier) => fmt_unix_timestamp(
+            output,
+            require_date(date)?,
+            require_time(time)?,
+            require_offset(offset)?,
+            modifier,
+        )?,
     })
 }
 
+/// Check that the value being formatted provides the information needed by the component,
+/// returning the error that formatting the component would otherwise produce.
+pub(crate) fn check_component(
+    component: Component,
+    date: Option<Date>,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<(), error::Format> {
+    use Component::*;
+    match component {
+        Day(_) | Month(_) | Ordinal(_) | Weekday(_) | WeekNumber(_) | Year(_) => {
+            require_date(date)?;
+        }
+        Hour(_) | Minute(_) | Period(_) | Second(_) | Subsecond(_) => {
+            require_time(time)?;
+        }
+        OffsetHour(_) | OffsetMinute(_) | OffsetSecond(_) | Offset(_) => {
+            require_offset(offset)?;
+        }
+        Ignore(_) => {}
+        UnixTimestamp(_) => {
+            require_date(date)?;
+            require_time(time)?;
+            require_offset(offset)?;
+        }
+    }
+    Ok(())
+}
+
+/// Whether the component is omitted entirely when formatted with the provided offset.
+pub(crate) const fn component_is_omitted(
+    component: Component,
+    offset: Option<UtcOffset>,
+) -> bool {
+    match (component, offset) {
+        (Component::OffsetMinute(modifier), Some(offset)) => {
+            modifier.omit_when_zero && offset.minutes_past_hour() == 0
+        }
+        _ => false,
+    }
+}
+
 // region: date formatters
 /// Format the day into the designated output.
 fn fmt_day(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
-    modifier::Day { padding }: modifier::Day,
-) -> Result<usize, io::Error> {
-    format_number::<2>(output, date.day(), padding)
+    modifier::Day { padding, repr }: modifier::Day,
+) -> Result<usize, error::Format> {
+    let bytes = format_number::<2>(output, date.day(), padding)?;
+    match repr {
+        modifier::DayRepr::Numerical => Ok(bytes),
+        modifier::DayRepr::Ordinal => {
+            Ok(bytes + write(output, ordinal_suffix(date.day()).as_bytes())?)
+        }
+    }
 }
 
 /// Format the month into the designated output.
 fn fmt_month(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Month {
         padding,
         repr,
         case_sensitive: _, // no effect on formatting
     }: modifier::Month,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     match repr {
         modifier::MonthRepr::Numerical => format_number::<2>(output, date.month() as u8, padding),
-        modifier::MonthRepr::Long => write(output, MONTH_NAMES[date.month() as usize - 1]),
-        modifier::MonthRepr::Short => write(output, &MONTH_NAMES[date.month() as usize - 1][..3]),
+        modifier::MonthRepr::Long => write(output, date.month().name().as_bytes()),
+        modifier::MonthRepr::Short => write(output, date.month().short_name().as_bytes()),
+        modifier::MonthRepr::CustomNames(names) => {
+            write(output, names[date.month() as usize - 1].as_bytes())
+        }
     }
 }
 
 /// Format the ordinal into the designated output.
 fn fmt_ordinal(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Ordinal { padding }: modifier::Ordinal,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<3>(output, date.ordinal(), padding)
 }
 
 /// Format the weekday into the designated output.
 fn fmt_weekday(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Weekday {
         repr,
         one_indexed,
         case_sensitive: _, // no effect on formatting
     }: modifier::Weekday,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     match repr {
-        modifier::WeekdayRepr::Short => write(
+        modifier::WeekdayRepr::Short => write(output, date.weekday().short_name().as_bytes()),
+        modifier::WeekdayRepr::Long => write(output, date.weekday().name().as_bytes()),
+        modifier::WeekdayRepr::CustomNames(names) => write(
             output,
-            &WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize][..3],
-        ),
-        modifier::WeekdayRepr::Long => write(
-            output,
-            WEEKDAY_NAMES[date.weekday().number_days_from_monday() as usize],
+            names[date.weekday().number_days_from_monday() as usize].as_bytes(),
         ),
         modifier::WeekdayRepr::Sunday => format_number::<1>(
             output,
@@ -324,10 +408,10 @@ fn fmt_weekday(
 
 /// Format the week number into the designated output.
 fn fmt_week_number(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::WeekNumber { padding, repr }: modifier::WeekNumber,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<2>(
         output,
         match repr {
@@ -341,7 +425,7 @@ fn fmt_week_number(
 
 /// Format the year into the designated output.
 fn fmt_year(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     modifier::Year {
         padding,
@@ -349,7 +433,7 @@ fn fmt_year(
         iso_week_based,
         sign_is_mandatory,
     }: modifier::Year,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let full_year = if iso_week_based {
         date.iso_year_week().0
     } else {
@@ -361,85 +445,84 @@ fn fmt_year(
     };
     let format_number = match repr {
         #[cfg(feature = "large-dates")]
-        modifier::YearRepr::Full if value.abs() >= 100_000 => format_number::<6>,
+        modifier::YearRepr::Full if value.abs() >= 100_000 => format_signed_number::<6>,
         #[cfg(feature = "large-dates")]
-        modifier::YearRepr::Full if value.abs() >= 10_000 => format_number::<5>,
-        modifier::YearRepr::Full => format_number::<4>,
-        modifier::YearRepr::LastTwo => format_number::<2>,
+        modifier::YearRepr::Full if value.abs() >= 10_000 => format_signed_number::<5>,
+        modifier::YearRepr::Full => format_signed_number::<4>,
+        modifier::YearRepr::LastTwo => format_signed_number::<2>,
     };
-    let mut bytes = 0;
-    if repr != modifier::YearRepr::LastTwo {
-        if full_year < 0 {
-            bytes += write(output, b"-")?;
-        } else if sign_is_mandatory || cfg!(feature = "large-dates") && full_year >= 10_000 {
-            bytes += write(output, b"+")?;
-        }
-    }
-    bytes += format_number(output, value.unsigned_abs(), padding)?;
-    Ok(bytes)
+    let sign: &[u8] = if repr == modifier::YearRepr::LastTwo {
+        b""
+    } else if full_year < 0 {
+        b"-"
+    } else if sign_is_mandatory || cfg!(feature = "large-dates") && full_year >= 10_000 {
+        b"+"
+    } else {
+        b""
+    };
+    format_number(output, sign, value.unsigned_abs(), padding)
 }
 // endregion date formatters
 
 // region: time formatters
 /// Format the hour into the designated output.
 fn fmt_hour(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Hour {
         padding,
         is_12_hour_clock,
     }: modifier::Hour,
-) -> Result<usize, io::Error> {
-    let value = match (time.hour(), is_12_hour_clock) {
-        (hour, false) => hour,
-        (0 | 12, true) => 12,
-        (hour, true) if hour < 12 => hour,
-        (hour, true) => hour - 12,
+) -> Result<usize, error::Format> {
+    let value = if is_12_hour_clock {
+        time.hour_12().0
+    } else {
+        time.hour()
     };
     format_number::<2>(output, value, padding)
 }
 
 /// Format the minute into the designated output.
 fn fmt_minute(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Minute { padding }: modifier::Minute,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<2>(output, time.minute(), padding)
 }
 
 /// Format the period into the designated output.
 fn fmt_period(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Period {
         is_uppercase,
         case_sensitive: _, // no effect on formatting
     }: modifier::Period,
-) -> Result<usize, io::Error> {
-    match (time.hour() >= 12, is_uppercase) {
-        (false, false) => write(output, b"am"),
-        (false, true) => write(output, b"AM"),
-        (true, false) => write(output, b"pm"),
-        (true, true) => write(output, b"PM"),
+) -> Result<usize, error::Format> {
+    match (time.period(), is_uppercase) {
+        (Period::Am, false) => write(output, b"am"),
+        (Period::Am, true) => write(output, b"AM"),
+        (Period::Pm, false) => write(output, b"pm"),
+        (Period::Pm, true) => write(output, b"PM"),
     }
 }
 
 /// Format the second into the designated output.
 fn fmt_second(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     time: Time,
     modifier::Second { padding }: modifier::Second,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<2>(output, time.second(), padding)
 }
 
 /// Format the subsecond into the designated output.
-fn fmt_subsecond<W: io::Write>(
+fn fmt_subsecond<W: Output>(
     output: &mut W,
     time: Time,
     modifier::Subsecond { digits }: modifier::Subsecond,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     use modifier::SubsecondDigits::*;
     let nanos = time.nanosecond();
 
@@ -468,65 +551,131 @@ fn fmt_subsecond<W: io::Write>(
 // region: offset formatters
 /// Format the offset hour into the designated output.
 fn fmt_offset_hour(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetHour {
         padding,
         sign_is_mandatory,
+        z_when_utc,
     }: modifier::OffsetHour,
-) -> Result<usize, io::Error> {
-    let mut bytes = 0;
-    if offset.is_negative() {
-        bytes += write(output, b"-")?;
-    } else if sign_is_mandatory {
-        bytes += write(output, b"+")?;
+) -> Result<usize, error::Format> {
+    if z_when_utc && offset.is_utc() {
+        return write(output, b"Z");
     }
-    bytes += format_number::<2>(output, offset.whole_hours().unsigned_abs(), padding)?;
-    Ok(bytes)
+
+    let sign: &[u8] = if offset.is_negative() {
+        b"-"
+    } else if sign_is_mandatory {
+        b"+"
+    } else {
+        b""
+    };
+    format_signed_number::<2>(output, sign, offset.whole_hours().unsigned_abs(), padding)
 }
 
 /// Format the offset minute into the designated output.
 fn fmt_offset_minute(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
-    modifier::OffsetMinute { padding }: modifier::OffsetMinute,
-) -> Result<usize, io::Error> {
+    modifier::OffsetMinute {
+        padding,
+        omit_when_zero,
+    }: modifier::OffsetMinute,
+) -> Result<usize, error::Format> {
+    if omit_when_zero && offset.minutes_past_hour() == 0 {
+        return Ok(0);
+    }
     format_number::<2>(output, offset.minutes_past_hour().unsigned_abs(), padding)
 }
 
 /// Format the offset second into the designated output.
 fn fmt_offset_second(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     offset: UtcOffset,
     modifier::OffsetSecond { padding }: modifier::OffsetSecond,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     format_number::<2>(output, offset.seconds_past_minute().unsigned_abs(), padding)
 }
+
+/// Format the offset hour and minute into the designated output.
+fn fmt_offset(
+    output: &mut impl Output,
+    offset: UtcOffset,
+    modifier::Offset {
+        sign_is_mandatory,
+        z_when_utc,
+        separator,
+    }: modifier::Offset,
+) -> Result<usize, error::Format> {
+    if z_when_utc && offset.is_utc() {
+        return write(output, b"Z");
+    }
+
+    let sign: &[u8] = if offset.is_negative() {
+        b"-"
+    } else if sign_is_mandatory {
+        b"+"
+    } else {
+        b""
+    };
+    let mut bytes = write(output, sign)?;
+    bytes += format_number_pad_zero::<2>(output, offset.whole_hours().unsigned_abs())?;
+    bytes += match separator {
+        modifier::OffsetSeparator::Colon => write(output, b":")?,
+        modifier::OffsetSeparator::None => 0,
+    };
+    bytes += format_number_pad_zero::<2>(output, offset.minutes_past_hour().unsigned_abs())?;
+    Ok(bytes)
+}
 // endregion offset formatters
 
 /// Format the Unix timestamp into the designated output.
 fn fmt_unix_timestamp(
-    output: &mut impl io::Write,
+    output: &mut impl Output,
     date: Date,
     time: Time,
     offset: UtcOffset,
     modifier::UnixTimestamp {
         precision,
         sign_is_mandatory,
+        decimal,
     }: modifier::UnixTimestamp,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::Format> {
     let date_time = date
         .with_time(time)
         .assume_offset(offset)
         .to_offset(UtcOffset::UTC);
 
+    let mut bytes = 0;
     if date_time < OffsetDateTime::UNIX_EPOCH {
-        write(output, b"-")?;
+        bytes += write(output, b"-")?;
     } else if sign_is_mandatory {
-        write(output, b"+")?;
+        bytes += write(output, b"+")?;
+    }
+
+    if decimal {
+        let nanos = date_time.unix_timestamp_nanos().unsigned_abs();
+        let nanos_per_second = Nanosecond.per(Second) as u128;
+        bytes += format_number_pad_none(output, nanos / nanos_per_second)?;
+        let subsecond = (nanos % nanos_per_second) as u32;
+        bytes += match precision {
+            modifier::UnixTimestampPrecision::Second => 0,
+            modifier::UnixTimestampPrecision::Millisecond => {
+                write(output, b".")?
+                    + format_number_pad_zero::<3>(output, subsecond / Nanosecond.per(Millisecond))?
+            }
+            modifier::UnixTimestampPrecision::Microsecond => {
+                let value = subsecond / Nanosecond.per(Microsecond) as u32;
+                write(output, b".")? + format_number_pad_zero::<6>(output, value)?
+            }
+            modifier::UnixTimestampPrecision::Nanosecond => {
+                write(output, b".")? + format_number_pad_zero::<9>(output, subsecond)?
+            }
+        };
+        return Ok(bytes);
     }
 
-    match precision {
+    bytes += match precision {
         modifier::UnixTimestampPrecision::Second => {
             format_number_pad_none(output, date_time.unix_timestamp().unsigned_abs())
         }
@@ -541,5 +690,6 @@ fn fmt_unix_timestamp(
         modifier::UnixTimestampPrecision::Nanosecond => {
             format_number_pad_none(output, date_time.unix_timestamp_nanos().unsigned_abs())
         }
-    }
+    }?;
+    Ok(bytes)
 }
@@ -0,0 +1,143 @@
+//! Destinations for formatted output.
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::fmt;
+use core::str::Utf8Error;
+#[cfg(feature = "std")]
+use std::io;
+
+use crate::formatting::Formattable;
+use crate::{error, Date, Time, UtcOffset};
+
+/// A destination that formatted output can be written to.
+///
+/// This is implemented for every [`io::Write`] when the `std` feature is enabled. Any
+/// [`fmt::Write`] can be used via [`FmtWriter`], which does not require the standard library.
+pub trait Output {
+    /// Write all of the provided bytes to the output.
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::Format>;
+
+    /// Write the formatted arguments to the output. This allows the use of [`write!`].
+    fn write_fmt(&mut self, args: fmt::Arguments<'_>) -> Result<(), error::Format> {
+        /// Forwards text to the output, retaining the first error that occurs.
+        struct Adapter<'a, O: ?Sized> {
+            /// The output being written to.
+            output: &'a mut O,
+            /// The error returned by the output, if any.
+            error: Option<error::Format>,
+        }
+
+        impl<O: Output + ?Sized> fmt::Write for Adapter<'_, O> {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.output.write_all(s.as_bytes()).map_err(|err| {
+                    self.error = Some(err);
+                    fmt::Error
+                })
+            }
+        }
+
+        let mut adapter = Adapter {
+            output: self,
+            error: None,
+        };
+        fmt::write(&mut adapter, args)
+            .map_err(|err| adapter.error.take().unwrap_or(error::Format::Fmt(err)))
+    }
+}
+
+#[cfg(feature = "std")]
+impl<W: io::Write + ?Sized> Output for W {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::Format> {
+        io::Write::write_all(self, bytes).map_err(error::Format::StdIo)
+    }
+}
+
+/// An output that discards everything written to it.
+///
+/// Formatting into this determines the length of the output without writing it anywhere.
+pub(crate) struct Discard;
+
+impl Output for Discard {
+    fn write_all(&mut self, _: &[u8]) -> Result<(), error::Format> {
+        Ok(())
+    }
+}
+
+/// Writes to a caller-provided byte slice. Any bytes that do not fit are discarded, but formatting
+/// continues so that the total size of the output is known.
+pub(crate) struct SliceWriter<'a> {
+    /// The buffer being written to.
+    buf: &'a mut [u8],
+    /// The number of bytes written, including those that did not fit.
+    len: usize,
+}
+
+impl SliceWriter<'_> {
+    /// Format the provided components into the buffer, returning the number of bytes written.
+    pub(crate) fn format(
+        format: &(impl Formattable + ?Sized),
+        buf: &mut [u8],
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::Format> {
+        let mut writer = SliceWriter { buf, len: 0 };
+        format.format_into(&mut writer, date, time, offset)?;
+        if writer.len > writer.buf.len() {
+            return Err(error::Format::InsufficientCapacity {
+                capacity: writer.buf.len(),
+                required: writer.len,
+            });
+        }
+        Ok(writer.len)
+    }
+}
+
+impl Output for SliceWriter<'_> {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::Format> {
+        let end = self.len.saturating_add(bytes.len());
+        if let Some(dest) = self.buf.get_mut(self.len..end) {
+            dest.copy_from_slice(bytes);
+        }
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// An adapter allowing any [`fmt::Write`] to be used as the destination of formatted output.
+///
+/// As a `fmt::Write` only accepts valid UTF-8, writing any invalid sequence is an error.
+pub(crate) struct FmtWriter<'a, W: ?Sized>(pub(crate) &'a mut W);
+
+impl<W: fmt::Write + ?Sized> Output for FmtWriter<'_, W> {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::Format> {
+        let s = core::str::from_utf8(bytes).map_err(invalid_utf8)?;
+        self.0.write_str(s).map_err(error::Format::Fmt)
+    }
+}
+
+/// The error returned when the output is not valid UTF-8.
+#[cfg(feature = "std")]
+fn invalid_utf8(err: Utf8Error) -> error::Format {
+    error::Format::StdIo(io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+/// The error returned when the output is not valid UTF-8.
+#[cfg(not(feature = "std"))]
+const fn invalid_utf8(_: Utf8Error) -> error::Format {
+    error::Format::Fmt(fmt::Error)
+}
+
+/// Writes to a `String`, replacing any invalid UTF-8 with U+FFFD. This is the behavior of the
+/// `format` methods that return a `String`.
+#[cfg(feature = "alloc")]
+pub(crate) struct LossyString<'a>(pub(crate) &'a mut String);
+
+#[cfg(feature = "alloc")]
+impl Output for LossyString<'_> {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::Format> {
+        self.0.push_str(&String::from_utf8_lossy(bytes));
+        Ok(())
+    }
+}
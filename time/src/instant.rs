@@ -167,7 +167,7 @@ impl Add<Duration> for Instant {
         if duration.is_positive() {
             Self(self.0 + duration.unsigned_abs())
         } else if duration.is_negative() {
-            #[allow(clippy::unchecked_duration_subtraction)]
+            #[allow(clippy::unchecked_time_subtraction)]
             Self(self.0 - duration.unsigned_abs())
         } else {
             debug_assert!(duration.is_zero());
@@ -204,7 +204,7 @@ impl Sub<Duration> for Instant {
     /// underlying data structure.
     fn sub(self, duration: Duration) -> Self::Output {
         if duration.is_positive() {
-            #[allow(clippy::unchecked_duration_subtraction)]
+            #[allow(clippy::unchecked_time_subtraction)]
             Self(self.0 - duration.unsigned_abs())
         } else if duration.is_negative() {
             Self(self.0 + duration.unsigned_abs())
@@ -231,7 +231,7 @@ impl Sub<StdDuration> for Instant {
     /// This function may panic if the resulting point in time cannot be represented by the
     /// underlying data structure.
     fn sub(self, duration: StdDuration) -> Self::Output {
-        #[allow(clippy::unchecked_duration_subtraction)]
+        #[allow(clippy::unchecked_time_subtraction)]
         Self(self.0 - duration)
     }
 }
@@ -239,6 +239,15 @@ impl Sub<StdDuration> for Instant {
 impl_sub_assign!(Instant: Duration, StdDuration);
 impl_sub_assign!(StdInstant: Duration);
 
+impl_forward_ref_binop!(Add add for Instant: Duration, StdDuration);
+impl_forward_ref_binop!(Add add for StdInstant: Duration);
+impl_forward_ref_binop!(Sub sub for Instant: Instant, StdInstant, Duration, StdDuration);
+impl_forward_ref_binop!(Sub sub for StdInstant: Instant, Duration);
+impl_forward_ref_op_assign!(AddAssign add_assign for Instant: Duration, StdDuration);
+impl_forward_ref_op_assign!(AddAssign add_assign for StdInstant: Duration);
+impl_forward_ref_op_assign!(SubAssign sub_assign for Instant: Duration, StdDuration);
+impl_forward_ref_op_assign!(SubAssign sub_assign for StdInstant: Duration);
+
 impl PartialEq<StdInstant> for Instant {
     fn eq(&self, rhs: &StdInstant) -> bool {
         self.0.eq(rhs)
@@ -18,13 +18,21 @@
 //!
 //!   Enables macros that provide compile-time verification of values and intuitive syntax.
 //!
-//! - `formatting` (_implicitly enables `std`_)
+//! - `formatting` (_implicitly enables `formatting-core` and `std`_)
 //!
-//!   Enables formatting of most structs.
+//!   Enables formatting of most structs. The most common well-known formats are also available
+//!   without importing anything, such as `OffsetDateTime::format_rfc3339`.
+//!
+//! - `formatting-core`
+//!
+//!   Enables formatting without requiring the standard library. Formatting into a
+//!   [`core::fmt::Write`] is always available. Formatting into a `std::io::Write` requires the
+//!   `std` feature, and formatting into a `String` requires the `alloc` feature.
 //!
 //! - `parsing`
 //!
-//!   Enables parsing of most structs.
+//!   Enables parsing of most structs. The most common well-known formats are also available
+//!   without importing anything, such as `OffsetDateTime::parse_rfc3339`.
 //!
 //! - `local-offset` (_implicitly enables `std`_)
 //!
@@ -43,7 +51,8 @@
 //!
 //!   Enables [serde](https://docs.rs/serde) support for all types except [`Instant`].
 //!
-//! - `serde-human-readable` (_implicitly enables `serde`, `formatting`, and `parsing`_)
+//! - `serde-human-readable` (_implicitly enables `serde`, `alloc`, `formatting-core`, and
+//!   `parsing`_)
 //!
 //!   Allows serde representations to use a human-readable format. This is determined by the
 //!   serializer, not the user. If this feature is not enabled or if the serializer requests a
@@ -52,6 +61,20 @@
 //!   Libraries should never enable this feature, as the decision of what format to use should be up
 //!   to the user.
 //!
+//! - `serde-well-known-default` (_implicitly enables `serde-human-readable`_)
+//!
+//!   Changes the human-readable representation of [`OffsetDateTime`] from the default of
+//!   `2023-01-02 03:04:05.0 +00:00:00` to
+//!   [RFC 3339](https://datatracker.ietf.org/doc/html/rfc3339), such as `2023-01-02T03:04:05Z`.
+//!   Deserialization accepts both forms, allowing previously serialized values to be read.
+//!   Serializing a value that RFC 3339 cannot represent, such as one with a year outside
+//!   `0..=9999` or an offset with a nonzero second, fails. The human-readable representations of
+//!   [`Date`] and [`Time`] are already ISO 8601 and are not affected.
+//!
+//!   Both representations are stable, and the representation selected by this feature will not
+//!   change without a breaking release. As with `serde-human-readable`, libraries should never
+//!   enable this feature, as doing so affects every crate in the dependency graph.
+//!
 //! - `serde-well-known` (_implicitly enables `serde-human-readable`_)
 //!
 //!   _This feature flag is deprecated and will be removed in a future breaking release. Use the
@@ -86,7 +109,6 @@
     clippy::obfuscated_if_else,
     clippy::std_instead_of_core,
     clippy::undocumented_unsafe_blocks,
-    illegal_floating_point_literal_pattern,
     late_bound_lifetime_arguments,
     path_statements,
     patterns_in_fns_without_body,
@@ -147,6 +169,7 @@ macro_rules! __impl_assign {
         #[allow(unused_qualifications)]
         $(#[$attr])*
         impl core::ops::$op<$t> for $target {
+            #[track_caller]
             fn $fn(&mut self, rhs: $t) {
                 *self = *self $sym rhs;
             }
@@ -182,6 +205,76 @@ macro_rules! impl_div_assign {
     };
 }
 
+/// Implement a binary operator for references to the provided types, forwarding to the by-value
+/// implementation. This mirrors the implementations the standard library provides for primitives.
+macro_rules! impl_forward_ref_binop {
+    ($op:ident $fn:ident for $target:ty : $($(#[$attr:meta])* $t:ty),+ $(,)?) => {$(
+        #[allow(unused_qualifications)]
+        $(#[$attr])*
+        impl<'a> core::ops::$op<$t> for &'a $target {
+            type Output = <$target as core::ops::$op<$t>>::Output;
+
+            #[track_caller]
+            fn $fn(self, rhs: $t) -> Self::Output {
+                core::ops::$op::$fn(*self, rhs)
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        $(#[$attr])*
+        impl core::ops::$op<&$t> for $target {
+            type Output = <$target as core::ops::$op<$t>>::Output;
+
+            #[track_caller]
+            fn $fn(self, rhs: &$t) -> Self::Output {
+                core::ops::$op::$fn(self, *rhs)
+            }
+        }
+
+        #[allow(unused_qualifications)]
+        $(#[$attr])*
+        impl core::ops::$op<&$t> for &$target {
+            type Output = <$target as core::ops::$op<$t>>::Output;
+
+            #[track_caller]
+            fn $fn(self, rhs: &$t) -> Self::Output {
+                core::ops::$op::$fn(*self, *rhs)
+            }
+        }
+    )+};
+}
+
+/// Implement a unary operator for references to the provided types, forwarding to the by-value
+/// implementation.
+macro_rules! impl_forward_ref_unop {
+    ($op:ident $fn:ident for $($target:ty),+ $(,)?) => {$(
+        #[allow(unused_qualifications)]
+        impl core::ops::$op for &$target {
+            type Output = <$target as core::ops::$op>::Output;
+
+            #[track_caller]
+            fn $fn(self) -> Self::Output {
+                core::ops::$op::$fn(*self)
+            }
+        }
+    )+};
+}
+
+/// Implement an assignment operator taking a reference to the provided types, forwarding to the
+/// by-value implementation.
+macro_rules! impl_forward_ref_op_assign {
+    ($op:ident $fn:ident for $target:ty : $($(#[$attr:meta])* $t:ty),+ $(,)?) => {$(
+        #[allow(unused_qualifications)]
+        $(#[$attr])*
+        impl core::ops::$op<&$t> for $target {
+            #[track_caller]
+            fn $fn(&mut self, rhs: &$t) {
+                core::ops::$op::$fn(self, *rhs);
+            }
+        }
+    )+};
+}
+
 /// Division of integers, rounding the resulting value towards negative infinity.
 macro_rules! div_floor {
     ($a:expr, $b:expr) => {{
@@ -235,22 +328,21 @@ macro_rules! cascade {
 
 /// Returns `Err(error::ComponentRange)` if the value is not in range.
 macro_rules! ensure_value_in_range {
-    ($value:ident in $start:expr => $end:expr) => {{
-        let _start = $start;
-        let _end = $end;
-        #[allow(trivial_numeric_casts, unused_comparisons)]
-        if $value < _start || $value > _end {
-            return Err(crate::error::ComponentRange {
-                name: stringify!($value),
-                minimum: _start as _,
-                maximum: _end as _,
-                value: $value as _,
-                conditional_range: false,
-            });
-        }
-    }};
+    ($value:ident in $start:expr => $end:expr) => {
+        ensure_value_in_range!(
+            $value in $start => $end,
+            given crate::error::ConditionalRange::No
+        )
+    };
+
+    ($value:ident conditionally in $start:expr => $end:expr) => {
+        ensure_value_in_range!(
+            $value in $start => $end,
+            given crate::error::ConditionalRange::Yes
+        )
+    };
 
-    ($value:ident conditionally in $start:expr => $end:expr) => {{
+    ($value:ident in $start:expr => $end:expr, given $conditional_range:expr) => {{
         let _start = $start;
         let _end = $end;
         #[allow(trivial_numeric_casts, unused_comparisons)]
@@ -260,7 +352,7 @@ macro_rules! ensure_value_in_range {
                 minimum: _start as _,
                 maximum: _end as _,
                 value: $value as _,
-                conditional_range: true,
+                conditional_range: $conditional_range,
             });
         }
     }};
@@ -291,6 +383,27 @@ macro_rules! const_try_opt {
     };
 }
 
+/// Compare pairs of values lexicographically, returning the resulting [`Ordering`]. Unlike
+/// [`Ord::cmp`], this is usable in `const` contexts.
+///
+/// [`Ordering`]: core::cmp::Ordering
+macro_rules! const_cmp {
+    ($($lhs:expr => $rhs:expr),+ $(,)?) => {{
+        let mut ordering = core::cmp::Ordering::Equal;
+        $(if let core::cmp::Ordering::Equal = ordering {
+            let (lhs, rhs) = ($lhs, $rhs);
+            ordering = if lhs < rhs {
+                core::cmp::Ordering::Less
+            } else if lhs > rhs {
+                core::cmp::Ordering::Greater
+            } else {
+                core::cmp::Ordering::Equal
+            };
+        })+
+        ordering
+    }};
+}
+
 /// Try to unwrap an expression, panicking if not possible.
 ///
 /// This is similar to `$e.expect($message)`, but is usable in `const` contexts.
@@ -317,15 +430,17 @@ mod date_time;
 mod duration;
 pub mod error;
 pub mod ext;
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
 pub mod format_description;
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 pub mod formatting;
 #[cfg(feature = "std")]
 mod instant;
 #[cfg(feature = "macros")]
 pub mod macros;
 mod month;
+#[cfg(all(feature = "std", target_has_atomic = "64"))]
+pub mod now;
 mod offset_date_time;
 #[cfg(feature = "parsing")]
 pub mod parsing;
@@ -350,14 +465,14 @@ use time_core::convert;
 
 pub use crate::date::Date;
 use crate::date_time::DateTime;
-pub use crate::duration::Duration;
+pub use crate::duration::{Duration, Unit};
 pub use crate::error::Error;
 #[cfg(feature = "std")]
 pub use crate::instant::Instant;
 pub use crate::month::Month;
 pub use crate::offset_date_time::OffsetDateTime;
 pub use crate::primitive_date_time::PrimitiveDateTime;
-pub use crate::time::Time;
+pub use crate::time::{Period, Time};
 pub use crate::utc_offset::UtcOffset;
 pub use crate::weekday::Weekday;
 
@@ -371,3 +486,27 @@ pub type Result<T> = core::result::Result<T, Error>;
 const fn expect_failed(message: &str) -> ! {
     panic!("{}", message)
 }
+
+/// Panic with a message describing an arithmetic operation that overflowed, including the values of
+/// both operands. This is a separate function to reduce the code size of the operator
+/// implementations, as the operands are only formatted when the panic occurs.
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn arithmetic_overflow(
+    message: &str,
+    lhs: &dyn core::fmt::Debug,
+    operator: char,
+    rhs: &dyn core::fmt::Debug,
+) -> ! {
+    panic!("{message}: {lhs:?} {operator} {rhs:?}")
+}
+
+/// Panic with a message describing a unary operation that overflowed, including the value of the
+/// operand. As with [`arithmetic_overflow`], the operand is only formatted when the panic occurs.
+#[inline(never)]
+#[cold]
+#[track_caller]
+fn unary_overflow(message: &str, operator: char, operand: &dyn core::fmt::Debug) -> ! {
+    panic!("{message}: {operator}{operand:?}")
+}
@@ -49,6 +49,30 @@ pub use time_macros::date;
 /// # Ok::<_, time::Error>(())
 /// ```
 pub use time_macros::datetime;
+/// Construct a [`Duration`](crate::Duration) with a statically known value.
+///
+/// The resulting expression can be used in `const` or `static` declarations.
+///
+/// The duration is written as one or more values, each followed by a unit: `w` (weeks), `d`
+/// (days), `h` (hours), `m` (minutes), `s` (seconds), `ms` (milliseconds), `us` (microseconds),
+/// or `ns` (nanoseconds). The unit may be separated from the value by a space. Units must be in
+/// descending order and may not be repeated. A leading `-` negates the entire duration.
+///
+/// Values may have a fractional part, provided that the result is an exact number of
+/// nanoseconds. An error is raised at compile time if this is not the case or if the duration is
+/// out of range.
+///
+/// ```rust
+/// # use time::{Duration, macros::duration};
+/// assert_eq!(duration!(2 h 30 m), Duration::minutes(150));
+/// assert_eq!(duration!(2h 30m), Duration::minutes(150));
+/// assert_eq!(duration!(1 w 1 d), Duration::days(8));
+/// assert_eq!(duration!(1.5 s), Duration::milliseconds(1_500));
+/// assert_eq!(duration!(-1 m 30 s), Duration::seconds(-90));
+/// assert_eq!(duration!(250 ms), Duration::milliseconds(250));
+/// assert_eq!(duration!(3 us 4 ns), Duration::nanoseconds(3_004));
+/// ```
+pub use time_macros::duration;
 /// Equivalent of performing [`format_description::parse()`] at compile time.
 ///
 /// Using the macro instead of the function results in a static slice rather than a [`Vec`],
@@ -70,7 +94,7 @@ pub use time_macros::datetime;
 /// be found in [the book](https://time-rs.github.io/book/api/format-description.html).
 ///
 /// [`format_description::parse()`]: crate::format_description::parse()
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
 pub use time_macros::format_description;
 /// Construct a [`UtcOffset`](crate::UtcOffset) with a statically known value.
 ///
@@ -28,7 +28,7 @@ pub enum Month {
 
 impl Month {
     /// Create a `Month` from its numerical value.
-    pub(crate) const fn from_number(n: NonZeroU8) -> Result<Self, error::ComponentRange> {
+    pub(crate) const fn from_nonzero_number(n: NonZeroU8) -> Result<Self, error::ComponentRange> {
         match n.get() {
             1 => Ok(January),
             2 => Ok(February),
@@ -47,11 +47,46 @@ impl Month {
                 minimum: 1,
                 maximum: 12,
                 value: n as _,
-                conditional_range: false,
+                conditional_range: error::ConditionalRange::No,
             }),
         }
     }
 
+    /// Create a `Month` from its one-indexed number, where January is 1. This is the inverse of
+    /// [`Month::number`], and is also available via its `TryFrom<u8>` implementation.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::from_number(1), Ok(Month::January));
+    /// assert_eq!(Month::from_number(12), Ok(Month::December));
+    /// assert!(Month::from_number(0).is_err());
+    /// assert!(Month::from_number(13).is_err());
+    /// ```
+    pub const fn from_number(month: u8) -> Result<Self, error::ComponentRange> {
+        match NonZeroU8::new(month) {
+            Some(month) => Self::from_nonzero_number(month),
+            None => Err(error::ComponentRange {
+                name: "month",
+                minimum: 1,
+                maximum: 12,
+                value: 0,
+                conditional_range: error::ConditionalRange::No,
+            }),
+        }
+    }
+
+    /// Get the one-indexed number of the month, where January is 1. This is the same value
+    /// obtained via `u8::from`.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.number(), 1);
+    /// assert_eq!(Month::December.number(), 12);
+    /// ```
+    pub const fn number(self) -> u8 {
+        self as _
+    }
+
     /// Get the previous month.
     ///
     /// ```rust
@@ -97,11 +132,93 @@ impl Month {
             December => January,
         }
     }
-}
 
-impl fmt::Display for Month {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
+    /// Get the previous month, along with whether the year was crossed.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::February.overflowing_previous(), (Month::January, false));
+    /// assert_eq!(Month::January.overflowing_previous(), (Month::December, true));
+    /// ```
+    pub const fn overflowing_previous(self) -> (Self, bool) {
+        (self.previous(), matches!(self, January))
+    }
+
+    /// Get the next month, along with whether the year was crossed.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::November.overflowing_next(), (Month::December, false));
+    /// assert_eq!(Month::December.overflowing_next(), (Month::January, true));
+    /// ```
+    pub const fn overflowing_next(self) -> (Self, bool) {
+        (self.next(), matches!(self, December))
+    }
+
+    /// Get the n-th next month, along with the number of years crossed.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::March.nth_next_with_years(2), (Month::May, 0));
+    /// assert_eq!(Month::August.nth_next_with_years(18), (Month::February, 2));
+    /// ```
+    pub const fn nth_next_with_years(self, n: u32) -> (Self, u32) {
+        let mut years = n / 12;
+        let mut month = self as u32 - 1 + n % 12;
+        if month >= 12 {
+            years += 1;
+            month -= 12;
+        }
+        (Self::from_zero_indexed(month as _), years)
+    }
+
+    /// Get the month n months away, along with the number of years crossed. A negative value of
+    /// `n` moves backwards, in which case the number of years is negative or zero.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::August.nth_with_years(18), (Month::February, 2));
+    /// assert_eq!(Month::March.nth_with_years(-2), (Month::January, 0));
+    /// assert_eq!(Month::March.nth_with_years(-3), (Month::December, -1));
+    /// ```
+    pub const fn nth_with_years(self, n: i32) -> (Self, i32) {
+        let month = self as i64 - 1 + n as i64;
+        (
+            Self::from_zero_indexed(month.rem_euclid(12) as _),
+            month.div_euclid(12) as _,
+        )
+    }
+
+    /// Get the month from its zero-indexed number, where January is 0.
+    const fn from_zero_indexed(n: u8) -> Self {
+        match n {
+            0 => January,
+            1 => February,
+            2 => March,
+            3 => April,
+            4 => May,
+            5 => June,
+            6 => July,
+            7 => August,
+            8 => September,
+            9 => October,
+            10 => November,
+            val => {
+                debug_assert!(val == 11);
+                December
+            }
+        }
+    }
+
+    /// Get the full English name of the month. This is the same value used by the `Display`
+    /// implementation.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.name(), "January");
+    /// ```
+    pub const fn name(self) -> &'static str {
+        match self {
             January => "January",
             February => "February",
             March => "March",
@@ -114,7 +231,37 @@ impl fmt::Display for Month {
             October => "October",
             November => "November",
             December => "December",
-        })
+        }
+    }
+
+    /// Get the abbreviated English name of the month, which is the first three letters of its full
+    /// name. This is the value used by `[month repr:short]` and RFC 2822.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// assert_eq!(Month::January.short_name(), "Jan");
+    /// ```
+    pub const fn short_name(self) -> &'static str {
+        match self {
+            January => "Jan",
+            February => "Feb",
+            March => "Mar",
+            April => "Apr",
+            May => "May",
+            June => "Jun",
+            July => "Jul",
+            August => "Aug",
+            September => "Sep",
+            October => "Oct",
+            November => "Nov",
+            December => "Dec",
+        }
+    }
+}
+
+impl fmt::Display for Month {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
     }
 }
 
@@ -142,7 +289,7 @@ impl FromStr for Month {
 
 impl From<Month> for u8 {
     fn from(month: Month) -> Self {
-        month as _
+        month.number()
     }
 }
 
@@ -150,15 +297,6 @@ impl TryFrom<u8> for Month {
     type Error = error::ComponentRange;
 
     fn try_from(value: u8) -> Result<Self, Self::Error> {
-        match NonZeroU8::new(value) {
-            Some(value) => Self::from_number(value),
-            None => Err(error::ComponentRange {
-                name: "month",
-                minimum: 1,
-                maximum: 12,
-                value: 0,
-                conditional_range: false,
-            }),
-        }
+        Self::from_number(value)
     }
 }
@@ -0,0 +1,87 @@
+//! Clocks providing stronger guarantees than [`OffsetDateTime::now_utc`].
+
+use core::sync::atomic::{AtomicU64, Ordering};
+
+use crate::OffsetDateTime;
+
+/// A clock issuing strictly increasing date-times from the system's real-time clock.
+///
+/// [`OffsetDateTime::now_utc`] reads the system's real-time clock, which may have a coarse
+/// precision and may step backwards (such as when it is adjusted). Successive calls can therefore
+/// return equal or decreasing values. `HybridClock` combines the real-time clock with the last
+/// value it issued: whenever the real-time clock has not advanced past that value, the returned
+/// date-time is one nanosecond after it. Values are strictly increasing across all threads sharing
+/// the clock, making them suitable as identifiers or for ordering events within a process.
+///
+/// While the real-time clock is behind the last issued value, the clock advances by one nanosecond
+/// per call. Once the real-time clock catches up, its value is used again. The divergence from the
+/// real-time clock is therefore bounded by how far the real-time clock stepped backwards, plus one
+/// nanosecond per call made in the meantime.
+///
+/// Values before the Unix epoch are never issued. Values are strictly increasing until the year
+/// 2554. Once the last representable value has been issued, the clock saturates and repeats it.
+///
+/// ```rust
+/// # use time::now::HybridClock;
+/// static CLOCK: HybridClock = HybridClock::new();
+///
+/// let first = CLOCK.next();
+/// let second = CLOCK.next();
+/// assert!(first < second);
+/// ```
+#[derive(Debug, Default)]
+pub struct HybridClock {
+    /// The number of nanoseconds since the Unix epoch of the last issued value.
+    last: AtomicU64,
+}
+
+impl HybridClock {
+    /// Create a new `HybridClock` that has not issued any values.
+    ///
+    /// ```rust
+    /// # use time::now::HybridClock;
+    /// let clock = HybridClock::new();
+    /// assert!(clock.next().year() >= 2019);
+    /// ```
+    pub const fn new() -> Self {
+        Self {
+            last: AtomicU64::new(0),
+        }
+    }
+
+    /// Obtain the current date and time in UTC, which is guaranteed to be strictly after any value
+    /// previously returned by this clock. The only exception is once the clock has saturated in the
+    /// year 2554, after which the final representable value is returned.
+    ///
+    /// ```rust
+    /// # use time::now::HybridClock;
+    /// # use time_macros::offset;
+    /// let clock = HybridClock::new();
+    /// let value = clock.next();
+    /// assert_eq!(value.offset(), offset!(UTC));
+    /// assert!(clock.next() > value);
+    /// ```
+    pub fn next(&self) -> OffsetDateTime {
+        let now = OffsetDateTime::now_utc().unix_timestamp_nanos();
+        let now = u64::try_from(now).unwrap_or(if now < 0 { 0 } else { u64::MAX });
+
+        let mut last = self.last.load(Ordering::Relaxed);
+        loop {
+            let next = if now > last {
+                now
+            } else {
+                last.saturating_add(1)
+            };
+            match self
+                .last
+                .compare_exchange_weak(last, next, Ordering::Relaxed, Ordering::Relaxed)
+            {
+                Ok(_) => {
+                    return OffsetDateTime::from_unix_timestamp_nanos(next as _)
+                        .expect("value is in range");
+                }
+                Err(actual) => last = actual,
+            }
+        }
+    }
+}
@@ -1,23 +1,33 @@
 //! The [`OffsetDateTime`] struct and its associated `impl`s.
 
-#[cfg(feature = "std")]
 use core::cmp::Ordering;
 #[cfg(feature = "std")]
 use core::convert::From;
 use core::fmt;
 use core::hash::Hash;
+use core::num::NonZeroU8;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
+#[cfg(feature = "parsing")]
+use core::str::FromStr;
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(all(feature = "formatting-core", feature = "std"))]
 use std::io;
 #[cfg(feature = "std")]
 use std::time::SystemTime;
 
+use crate::date::{MAX_YEAR, MIN_YEAR};
 use crate::date_time::offset_kind;
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(any(all(feature = "formatting-core", feature = "alloc"), feature = "parsing"))]
+use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{ArrayBuf, Formattable, Formatted};
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{Parsable, Parsed};
+#[cfg(all(feature = "parsing", feature = "alloc"))]
+use crate::parsing::ParseManyResult;
+use crate::util::days_in_year_month;
 use crate::{error, Date, DateTime, Duration, Month, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
 /// The actual type doing all the work.
@@ -42,6 +52,16 @@ impl OffsetDateTime {
     // region: now
     /// Create a new `OffsetDateTime` with the current date and time in UTC.
     ///
+    /// The value is obtained from the system's real-time clock. Its precision depends on the
+    /// platform, and the clock may be adjusted at any time, including backwards. As a result,
+    /// successive calls may return equal or decreasing values. Use [`Instant`](crate::Instant) to
+    /// measure elapsed time.
+    #[cfg_attr(
+        target_has_atomic = "64",
+        doc = "Use [`HybridClock`](crate::now::HybridClock) when strictly increasing date-times \
+               are needed."
+    )]
+    ///
     /// ```rust
     /// # use time::OffsetDateTime;
     /// # use time_macros::offset;
@@ -98,6 +118,21 @@ impl OffsetDateTime {
     }
 
     // region: constructors
+    /// Create an `OffsetDateTime` from the provided [`Date`], [`Time`], and [`UtcOffset`]. The date
+    /// and time are those in the provided offset.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::{date, datetime, offset, time};
+    /// assert_eq!(
+    ///     OffsetDateTime::from_parts(date!(2019-01-01), time!(0:00), offset!(+1)),
+    ///     datetime!(2019-01-01 0:00 +1),
+    /// );
+    /// ```
+    pub const fn from_parts(date: Date, time: Time, offset: UtcOffset) -> Self {
+        PrimitiveDateTime::new(date, time).assume_offset(offset)
+    }
+
     /// Create an `OffsetDateTime` from the provided Unix timestamp. Calling `.offset()` on the
     /// resulting value is guaranteed to return UTC.
     ///
@@ -150,6 +185,31 @@ impl OffsetDateTime {
             timestamp
         ))))
     }
+
+    /// Construct an `OffsetDateTime` from the provided [Julian date], which may have a fractional
+    /// part. Calling `.offset()` on the resulting value is guaranteed to return UTC.
+    ///
+    /// The result is rounded to the nearest microsecond. Note that for dates in the modern era, an
+    /// `f64` Julian date is only precise to within a few tens of microseconds.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::from_julian_date(2_451_545.0),
+    ///     Ok(datetime!(2000-01-01 12:00 UTC)),
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::from_julian_date(2_440_587.5),
+    ///     Ok(OffsetDateTime::UNIX_EPOCH),
+    /// );
+    /// assert!(OffsetDateTime::from_julian_date(f64::NAN).is_err());
+    /// ```
+    ///
+    /// [Julian date]: https://en.wikipedia.org/wiki/Julian_day
+    pub fn from_julian_date(julian_date: f64) -> Result<Self, error::ComponentRange> {
+        Ok(Self(Inner::from_julian_date(julian_date)?))
+    }
     // endregion constructors
 
     // region: getters
@@ -221,6 +281,38 @@ impl OffsetDateTime {
         self.0.time()
     }
 
+    /// Get the [`Date`], [`Time`], and [`UtcOffset`], with the date and time being those in the
+    /// stored offset. This is the inverse of [`OffsetDateTime::from_parts`].
+    ///
+    /// ```rust
+    /// # use time_macros::{date, datetime, offset, time};
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 0:00 +1).into_parts(),
+    ///     (date!(2019-01-01), time!(0:00), offset!(+1)),
+    /// );
+    /// ```
+    pub const fn into_parts(self) -> (Date, Time, UtcOffset) {
+        (self.date(), self.time(), self.offset())
+    }
+
+    /// Get the year, month, and day, the clock hour, minute, second, and nanosecond, and the
+    /// [`UtcOffset`], with the date and time being those in the stored offset.
+    ///
+    /// The month and day are derived from the ordinal date once, rather than once per getter.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2019-01-02 3:04:05.006 +7).to_calendar_date_time(),
+    ///     ((2019, Month::January, 2), (3, 4, 5, 6_000_000), offset!(+7)),
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)] // consistent with `to_calendar_date` and `to_hms_nano`
+    pub const fn to_calendar_date_time(self) -> ((i32, Month, u8), (u8, u8, u8, u32), UtcOffset) {
+        (self.to_calendar_date(), self.to_hms_nano(), self.offset())
+    }
+
     // region: date getters
     /// Get the year of the date in the stored offset.
     ///
@@ -292,6 +384,78 @@ impl OffsetDateTime {
         self.0.ordinal()
     }
 
+    /// Check if the date is the first day of its month in the stored offset.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert!(datetime!(2019-02-01 0:00 UTC).is_first_day_of_month());
+    /// assert!(
+    ///     !datetime!(2019-02-01 0:00 UTC)
+    ///         .to_offset(offset!(-1))
+    ///         .is_first_day_of_month()
+    /// );
+    /// ```
+    pub const fn is_first_day_of_month(self) -> bool {
+        self.0.is_first_day_of_month()
+    }
+
+    /// Check if the date is the last day of its month in the stored offset. This takes leap years
+    /// into account.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert!(datetime!(2020-02-29 0:00 UTC).is_last_day_of_month());
+    /// assert!(
+    ///     !datetime!(2020-02-29 23:00 UTC)
+    ///         .to_offset(offset!(+1))
+    ///         .is_last_day_of_month()
+    /// );
+    /// ```
+    pub const fn is_last_day_of_month(self) -> bool {
+        self.0.is_last_day_of_month()
+    }
+
+    /// Check if the date is the first day of its year in the stored offset.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert!(datetime!(2020-01-01 0:00 UTC).is_first_day_of_year());
+    /// assert!(
+    ///     !datetime!(2020-01-01 0:00 UTC)
+    ///         .to_offset(offset!(-1))
+    ///         .is_first_day_of_year()
+    /// );
+    /// ```
+    pub const fn is_first_day_of_year(self) -> bool {
+        self.0.is_first_day_of_year()
+    }
+
+    /// Check if the date is the last day of its year in the stored offset.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// assert!(datetime!(2019-12-31 0:00 UTC).is_last_day_of_year());
+    /// assert!(
+    ///     !datetime!(2019-12-31 23:00 UTC)
+    ///         .to_offset(offset!(+1))
+    ///         .is_last_day_of_year()
+    /// );
+    /// ```
+    pub const fn is_last_day_of_year(self) -> bool {
+        self.0.is_last_day_of_year()
+    }
+
+    /// Check if the date is 29 February in the stored offset.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert!(datetime!(2020-02-29 0:00 UTC).is_leap_day());
+    /// assert!(!datetime!(2020-02-28 0:00 UTC).is_leap_day());
+    /// ```
+    pub const fn is_leap_day(self) -> bool {
+        self.0.is_leap_day()
+    }
+
     /// Get the ISO week number of the date in the stored offset.
     ///
     /// The returned value will always be in the range `1..=53`.
@@ -307,6 +471,18 @@ impl OffsetDateTime {
         self.0.iso_week()
     }
 
+    /// Get the ISO 8601 year and week number of the date in the stored offset.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2019-10-04 0:00 UTC).iso_year_week(), (2019, 40));
+    /// assert_eq!(datetime!(2019-12-30 0:00 UTC).iso_year_week(), (2020, 1));
+    /// assert_eq!(datetime!(2021-01-01 0:00 UTC).iso_year_week(), (2020, 53));
+    /// ```
+    pub const fn iso_year_week(self) -> (i32, u8) {
+        self.0.iso_year_week()
+    }
+
     /// Get the week number where week 1 begins on the first Sunday.
     ///
     /// The returned value will always be in the range `0..=53`.
@@ -409,6 +585,9 @@ impl OffsetDateTime {
 
     /// Get the Julian day for the date. The time is not taken into account for this calculation.
     ///
+    /// As with [`Date::to_julian_day`], this is the Julian day number of the date in the stored
+    /// offset. Use [`to_julian_date`](Self::to_julian_date) to include the time of day.
+    ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
     /// freely available [here](https://www.researchgate.net/publication/316558298_Date_Algorithms).
     ///
@@ -422,6 +601,23 @@ impl OffsetDateTime {
     pub const fn to_julian_day(self) -> i32 {
         self.0.to_julian_day()
     }
+
+    /// Get the [Julian date], including the fraction of the day that has elapsed. The value is
+    /// computed in UTC, regardless of the stored offset.
+    ///
+    /// Julian days begin at noon, so midnight falls halfway through a Julian day.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2000-01-01 12:00 UTC).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(2000-01-01 0:00 UTC).to_julian_date(), 2_451_544.5);
+    /// assert_eq!(datetime!(2000-01-01 13:00 +1).to_julian_date(), 2_451_545.0);
+    /// ```
+    ///
+    /// [Julian date]: https://en.wikipedia.org/wiki/Julian_day
+    pub fn to_julian_date(self) -> f64 {
+        self.0.to_julian_date()
+    }
     // endregion date getters
 
     // region: time getters
@@ -631,6 +827,124 @@ impl OffsetDateTime {
     pub const fn checked_sub(self, duration: Duration) -> Option<Self> {
         Some(Self(const_try_opt!(self.0.checked_sub(duration))))
     }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::{datetime, offset};
+    /// let datetime = Date::MAX.midnight().assume_offset(offset!(+10));
+    /// assert_eq!(datetime.checked_add_std(1.std_days()), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30 +10).checked_add_std(27.std_hours()),
+    ///     Some(datetime!(2019 - 11 - 26 18:30 +10))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        Some(Self(const_try_opt!(self.0.checked_add_std(duration))))
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::{datetime, offset};
+    /// let datetime = Date::MIN.midnight().assume_offset(offset!(+10));
+    /// assert_eq!(datetime.checked_sub_std(1.std_days()), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30 +10).checked_sub_std(27.std_hours()),
+    ///     Some(datetime!(2019 - 11 - 24 12:30 +10))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        Some(Self(const_try_opt!(self.0.checked_sub_std(duration))))
+    }
+
+    /// Computes the same wall-clock time `days` days later, keeping the offset. Returns `None` if
+    /// the resulting date is out of range.
+    ///
+    /// Unlike adding a [`Duration`] of whole days, this does not account for the offset changing in
+    /// between (such as for daylight saving time). When the offset of the resulting date is
+    /// resolved afterwards, the wall-clock time is preserved rather than the elapsed time.
+    ///
+    /// ```
+    /// # use time::Date;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2024 - 03 - 09 12:00 -5).checked_add_days_wall_clock(1),
+    ///     Some(datetime!(2024 - 03 - 10 12:00 -5))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2024 - 03 - 09 12:00 -5).checked_add_days_wall_clock(-366),
+    ///     Some(datetime!(2023 - 03 - 09 12:00 -5))
+    /// );
+    ///
+    /// let datetime = Date::MAX.midnight().assume_offset(offset!(+10));
+    /// assert_eq!(datetime.checked_add_days_wall_clock(1), None);
+    /// ```
+    pub const fn checked_add_days_wall_clock(self, days: i64) -> Option<Self> {
+        let julian_day = const_try_opt!((self.to_julian_day() as i64).checked_add(days));
+        if julian_day < i32::MIN as i64 || julian_day > i32::MAX as i64 {
+            return None;
+        }
+
+        match Date::from_julian_day(julian_day as _) {
+            Ok(date) => Some(self.replace_date(date)),
+            Err(_) => None,
+        }
+    }
+
+    /// Computes the same wall-clock time `months` months later, keeping the offset. If the day does
+    /// not exist in the resulting month, the last day of that month is used. Returns `None` if the
+    /// resulting date is out of range.
+    ///
+    /// Like [`checked_add_days_wall_clock`](Self::checked_add_days_wall_clock), this preserves the
+    /// wall-clock time rather than the elapsed time.
+    ///
+    /// ```
+    /// # use time::Date;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2024 - 01 - 31 12:00 -5).checked_add_months_wall_clock(1),
+    ///     Some(datetime!(2024 - 02 - 29 12:00 -5))
+    /// );
+    /// assert_eq!(
+    ///     datetime!(2024 - 01 - 31 12:00 -5).checked_add_months_wall_clock(-2),
+    ///     Some(datetime!(2023 - 11 - 30 12:00 -5))
+    /// );
+    ///
+    /// let datetime = Date::MAX.midnight().assume_offset(offset!(+10));
+    /// assert_eq!(datetime.checked_add_months_wall_clock(1), None);
+    /// ```
+    pub const fn checked_add_months_wall_clock(self, months: i64) -> Option<Self> {
+        let (year, month, day) = self.to_calendar_date();
+        let months = const_try_opt!((year as i64 * 12 + month as i64 - 1).checked_add(months));
+
+        let year = months.div_euclid(12);
+        if year < MIN_YEAR as i64 || year > MAX_YEAR as i64 {
+            return None;
+        }
+        let year = year as i32;
+        let month = match Month::from_nonzero_number(const_try_opt!(NonZeroU8::new(
+            months.rem_euclid(12) as u8 + 1
+        ))) {
+            Ok(month) => month,
+            Err(_) => return None,
+        };
+        let days_in_month = days_in_year_month(year, month);
+        let day = if day > days_in_month {
+            days_in_month
+        } else {
+            day
+        };
+
+        match Date::from_calendar_date(year, month, day) {
+            Ok(date) => Some(self.replace_date(date)),
+            Err(_) => None,
+        }
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -732,6 +1046,119 @@ impl OffsetDateTime {
         Self(self.0.saturating_sub(duration))
     }
     // endregion: saturating arithmetic
+
+    // region: comparison
+    /// Compare two `OffsetDateTime`s by the instant they represent. This is equivalent to
+    /// [`Ord::cmp`], but usable in `const` contexts.
+    pub(crate) const fn cmp_const(self, other: Self) -> Ordering {
+        self.0.cmp_const(other.0)
+    }
+
+    /// Returns the earlier of the two values. Unlike [`Ord::min`], this is usable in `const`
+    /// contexts.
+    ///
+    /// Values are compared by the instant they represent, disregarding their offsets. If both
+    /// represent the same instant, `self` is returned, retaining its offset.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// let (low, high) = (datetime!(2019-01-01 0:00 UTC), datetime!(2019-01-02 0:00 UTC));
+    /// assert_eq!(low.min(high), low);
+    /// assert_eq!(high.min(low), low);
+    ///
+    /// let same_instant = datetime!(2019-01-01 1:00 +1);
+    /// assert_eq!(same_instant.min(low).offset(), offset!(+1));
+    /// ```
+    pub const fn min(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the later of the two values. Unlike [`Ord::max`], this is usable in `const`
+    /// contexts.
+    ///
+    /// Values are compared by the instant they represent, disregarding their offsets. If both
+    /// represent the same instant, `other` is returned, retaining its offset, as with
+    /// [`Ord::max`].
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, offset};
+    /// let (low, high) = (datetime!(2019-01-01 0:00 UTC), datetime!(2019-01-02 0:00 UTC));
+    /// assert_eq!(low.max(high), high);
+    /// assert_eq!(high.max(low), high);
+    ///
+    /// let same_instant = datetime!(2019-01-02 1:00 +1);
+    /// assert_eq!(same_instant.max(high).offset(), offset!(UTC));
+    /// assert_eq!(high.max(same_instant).offset(), offset!(+1));
+    /// ```
+    pub const fn max(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restrict the value to the range `min..=max`. Unlike [`Ord::clamp`], this is usable in
+    /// `const` contexts.
+    ///
+    /// Values are compared by the instant they represent, disregarding their offsets. If `self`
+    /// represents the same instant as `min` or `max`, `self` is returned, retaining its offset.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let low = datetime!(2019-01-01 0:00 UTC);
+    /// let middle = datetime!(2019-01-01 12:00 UTC);
+    /// let high = datetime!(2019-01-02 0:00 UTC);
+    /// assert_eq!(low.clamp(middle, high), middle);
+    /// assert_eq!(middle.clamp(low, high), middle);
+    /// assert_eq!(high.clamp(low, middle), middle);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `min` is greater than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            min.cmp_const(max).is_le(),
+            "`min` must be less than or equal to `max`"
+        );
+        if self.cmp_const(min).is_lt() {
+            min
+        } else if self.cmp_const(max).is_gt() {
+            max
+        } else {
+            self
+        }
+    }
+
+    /// Returns `true` if `self` represents an earlier instant than `other`. The offsets of the
+    /// values are not considered.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert!(datetime!(2019-01-01 0:00 UTC).is_before(datetime!(2019-01-01 1:00 UTC)));
+    /// assert!(!datetime!(2019-01-01 1:00 +1).is_before(datetime!(2019-01-01 0:00 UTC)));
+    /// ```
+    pub const fn is_before(self, other: Self) -> bool {
+        self.cmp_const(other).is_lt()
+    }
+
+    /// Returns `true` if `self` represents a later instant than `other`. The offsets of the values
+    /// are not considered.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert!(datetime!(2019-01-01 1:00 UTC).is_after(datetime!(2019-01-01 0:00 UTC)));
+    /// assert!(!datetime!(2019-01-01 1:00 +1).is_after(datetime!(2019-01-01 0:00 UTC)));
+    /// ```
+    pub const fn is_after(self, other: Self) -> bool {
+        self.cmp_const(other).is_gt()
+    }
+    // endregion comparison
 }
 
 // region: replacement
@@ -950,10 +1377,11 @@ impl OffsetDateTime {
 // endregion replacement
 
 // region: formatting & parsing
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl OffsetDateTime {
     /// Format the `OffsetDateTime` using the provided [format
     /// description](crate::format_description).
+    #[cfg(feature = "std")]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -962,6 +1390,126 @@ impl OffsetDateTime {
         self.0.format_into(output, format)
     }
 
+    /// Format the `OffsetDateTime` using the provided [format
+    /// description](crate::format_description), writing to any [`fmt::Write`], such as a
+    /// [`fmt::Formatter`]. This allows a format description to be reused from a `Display`
+    /// implementation, or in `no_std` environments, without allocating.
+    ///
+    /// ```rust
+    /// # use core::fmt;
+    /// # use time::OffsetDateTime;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// struct Wrapper(OffsetDateTime);
+    ///
+    /// impl fmt::Display for Wrapper {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         self.0
+    ///             .format_into_fmt(f, &Rfc3339)
+    ///             .map(|_| ())
+    ///             .map_err(|_| fmt::Error)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Wrapper(datetime!(2020-01-02 03:04:05 UTC)).to_string(),
+    ///     "2020-01-02T03:04:05Z"
+    /// );
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        self.0.format_into_fmt(output, format)
+    }
+
+    /// Pair the `OffsetDateTime` with a [format description](crate::format_description), returning
+    /// a value that is formatted directly into the output when displayed. See [`Formatted`] for
+    /// details.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// let datetime = datetime!(2020-01-02 03:04:05 +06:07);
+    /// assert_eq!(
+    ///     format!("at {}", datetime.display(&Rfc3339)),
+    ///     "at 2020-01-02T03:04:05+06:07"
+    /// );
+    /// ```
+    pub const fn display<F: Formattable + ?Sized>(self, format: &F) -> Formatted<'_, F> {
+        self.0.display(format)
+    }
+
+    /// Format the `OffsetDateTime` using the provided [format
+    /// description](crate::format_description), storing the output in a fixed-capacity
+    /// [`ArrayBuf`] rather than allocating.
+    ///
+    /// ```rust
+    /// # use time::error;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// let datetime = datetime!(2020-01-02 03:04:05 UTC);
+    /// assert_eq!(datetime.format_to_array::<20>(&Rfc3339)?, "2020-01-02T03:04:05Z");
+    /// assert!(matches!(
+    ///     datetime.format_to_array::<16>(&Rfc3339),
+    ///     Err(error::Format::InsufficientCapacity {
+    ///         capacity: 16,
+    ///         required: 20
+    ///     })
+    /// ));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_array<const N: usize>(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<ArrayBuf<N>, error::Format> {
+        self.0.format_to_array(format)
+    }
+
+    /// Format the `OffsetDateTime` using the provided [format
+    /// description](crate::format_description), writing to a caller-provided byte slice. The
+    /// number of bytes written is returned.
+    ///
+    /// If the output does not fit, [`error::Format::InsufficientCapacity`] is returned with the
+    /// number of bytes required, and the contents of the buffer are unspecified. The required
+    /// size can be determined in advance with [`OffsetDateTime::formatted_len`].
+    ///
+    /// ```rust
+    /// # use time::error;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// let datetime = datetime!(2020-01-02 03:04:05 UTC);
+    /// let mut buf = [0; 20];
+    /// assert_eq!(datetime.format_into_slice(&mut buf, &Rfc3339)?, 20);
+    /// assert_eq!(&buf, b"2020-01-02T03:04:05Z");
+    /// assert!(matches!(
+    ///     datetime.format_into_slice(&mut buf[..16], &Rfc3339),
+    ///     Err(error::Format::InsufficientCapacity {
+    ///         capacity: 16,
+    ///         required: 20
+    ///     })
+    /// ));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        buf: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        self.0.format_into_slice(buf, format)
+    }
+
+    /// Determine the number of bytes that formatting the `OffsetDateTime` using the provided
+    /// [format description](crate::format_description) would produce, without writing it
+    /// anywhere.
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        self.0.formatted_len(format)
+    }
+
     /// Format the `OffsetDateTime` using the provided [format
     /// description](crate::format_description).
     ///
@@ -978,9 +1526,58 @@ impl OffsetDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
         self.0.format(format)
     }
+
+    /// Format the `OffsetDateTime` using the well-known [RFC 3339 format](Rfc3339). This is
+    /// equivalent to `self.format(&Rfc3339)`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05.5 +06:07).format_rfc3339()?,
+    ///     "2020-01-02T03:04:05.5+06:07"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_rfc3339(self) -> Result<String, error::Format> {
+        self.format(&Rfc3339)
+    }
+
+    /// Format the `OffsetDateTime` using the well-known [RFC 2822 format](Rfc2822). This is
+    /// equivalent to `self.format(&Rfc2822)`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05 +06:07).format_rfc2822()?,
+    ///     "Thu, 02 Jan 2020 03:04:05 +0607"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_rfc2822(self) -> Result<String, error::Format> {
+        self.format(&Rfc2822)
+    }
+
+    /// Format the `OffsetDateTime` using the well-known [ISO 8601 format](Iso8601) with its
+    /// default configuration. This is equivalent to `self.format(&Iso8601::DEFAULT)`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05.5 +06:07).format_iso8601()?,
+    ///     "2020-01-02T03:04:05.500000000+06:07"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn format_iso8601(self) -> Result<String, error::Format> {
+        self.format(&Iso8601::DEFAULT)
+    }
 }
 
 #[cfg(feature = "parsing")]
@@ -1007,14 +1604,205 @@ impl OffsetDateTime {
     ) -> Result<Self, error::Parse> {
         Inner::parse(input, description).map(Self)
     }
+
+    /// Parse an `OffsetDateTime` from the input using the well-known [RFC 3339 format](Rfc3339).
+    /// This is equivalent to `OffsetDateTime::parse(input, &Rfc3339)`.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_rfc3339("2020-01-02T03:04:05.5+06:07")?,
+    ///     datetime!(2020-01-02 03:04:05.5 +06:07)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_rfc3339(input: &str) -> Result<Self, error::Parse> {
+        Self::parse(input, &Rfc3339)
+    }
+
+    /// Parse an `OffsetDateTime` from the input using the well-known [RFC 2822 format](Rfc2822).
+    /// This is equivalent to `OffsetDateTime::parse(input, &Rfc2822)`.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_rfc2822("Thu, 02 Jan 2020 03:04:05 +0607")?,
+    ///     datetime!(2020-01-02 03:04:05 +06:07)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_rfc2822(input: &str) -> Result<Self, error::Parse> {
+        Self::parse(input, &Rfc2822)
+    }
+
+    /// Parse an `OffsetDateTime` from the input using the well-known [ISO 8601 format](Iso8601).
+    /// This is equivalent to `OffsetDateTime::parse(input, &Iso8601::DEFAULT)`.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_iso8601("2020-01-02T03:04:05.5+06:07")?,
+    ///     datetime!(2020-01-02 03:04:05.5 +06:07)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_iso8601(input: &str) -> Result<Self, error::Parse> {
+        Self::parse(input, &Iso8601::DEFAULT)
+    }
+
+    /// Parse an `OffsetDateTime` from the input using the provided [format
+    /// description](crate::format_description), using `default_offset` if the input does not
+    /// contain a UTC offset.
+    ///
+    /// The default is only used when no component of the offset was parsed. If the input contains
+    /// an offset, that offset is used.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::{datetime, format_description, offset};
+    /// let format = format_description!(
+    ///     "[year]-[month]-[day] [hour]:[minute][optional [ [offset_hour sign:mandatory]]]"
+    /// );
+    /// let default = offset!(+1);
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_default_offset("2020-01-02 03:04", &format, default)?,
+    ///     datetime!(2020-01-02 03:04 +1)
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse_with_default_offset("2020-01-02 03:04 -05", &format, default)?,
+    ///     datetime!(2020-01-02 03:04 -5)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_with_default_offset(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+        default_offset: UtcOffset,
+    ) -> Result<Self, error::Parse> {
+        let mut parsed = Parsed::new();
+        if !description.parse_into(input.as_bytes(), &mut parsed)?.is_empty() {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+
+        if parsed.offset_hour().is_none()
+            && parsed.offset_minute_signed().is_none()
+            && parsed.offset_second_signed().is_none()
+        {
+            let (hours, minutes, seconds) = default_offset.as_hms();
+            parsed = match parsed
+                .with_offset_hour(hours)
+                .and_then(|parsed| parsed.with_offset_minute_signed(minutes))
+                .and_then(|parsed| parsed.with_offset_second_signed(seconds))
+            {
+                Some(parsed) => parsed,
+                None => bug!("the components of a valid offset should always be accepted"),
+            };
+        }
+
+        Ok(Self::try_from(parsed)?)
+    }
+
+    /// Parse an `OffsetDateTime` from the input using the provided [format
+    /// description](crate::format_description), storing the intermediate information in `parsed`.
+    ///
+    /// `parsed` is cleared before parsing, so nothing from a previous call is used. Afterwards, it
+    /// holds the information parsed from this input, even if an error is returned.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time::parsing::Parsed;
+    /// # use time_macros::datetime;
+    /// let mut parsed = Parsed::new();
+    /// for (input, expected) in [
+    ///     ("2020-01-02T03:04:05Z", datetime!(2020-01-02 03:04:05 UTC)),
+    ///     ("2021-06-07T08:09:10+01:00", datetime!(2021-06-07 08:09:10 +01:00)),
+    /// ] {
+    ///     assert_eq!(OffsetDateTime::parse_reusing(input, &Rfc3339, &mut parsed)?, expected);
+    /// }
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_reusing(
+        input: &str,
+        description: &(impl Parsable + ?Sized),
+        parsed: &mut Parsed,
+    ) -> Result<Self, error::Parse> {
+        parsed.clear();
+        if !description.parse_into(input.as_bytes(), parsed)?.is_empty() {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+        Ok(Self::try_from(*parsed)?)
+    }
+
+    /// Parse each input as an `OffsetDateTime` using the provided [format
+    /// description](crate::format_description), collecting both the successes and the failures.
+    ///
+    /// Parsing does not stop at the first error. The index of each input that fails to parse is
+    /// recorded alongside the error, which does not allocate. A single [`Parsed`] is reused for
+    /// every input rather than being created anew for each one.
+    ///
+    /// [`Parsed`]: crate::parsing::Parsed
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// let rows = ["2020-01-02T03:04:05Z", "not a datetime", "2021-06-07T08:09:10+01:00"];
+    /// let result = OffsetDateTime::parse_many(rows, &Rfc3339);
+    /// assert_eq!(
+    ///     result.values,
+    ///     [datetime!(2020-01-02 03:04:05 UTC), datetime!(2021-06-07 08:09:10 +01:00)]
+    /// );
+    /// assert_eq!(result.errors.len(), 1);
+    /// assert_eq!(result.errors[0].0, 1);
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_many<'a>(
+        inputs: impl IntoIterator<Item = &'a str>,
+        description: &(impl Parsable + ?Sized),
+    ) -> ParseManyResult<Self> {
+        let mut parsed = Parsed::new();
+        ParseManyResult::collect(inputs, |input| {
+            description
+                .parse_date_time_reusing(input.as_bytes(), &mut parsed)
+                .map(Self)
+        })
+    }
 }
 
+/// Write the date, time, and offset separated by spaces, such as
+/// `2023-01-02 03:04:05.6 +01:00:00`. This format is stable and is described by
+/// [`format_description::display::OFFSET_DATE_TIME`].
+///
+/// [`format_description::display::OFFSET_DATE_TIME`]: crate::format_description::display::OFFSET_DATE_TIME
 impl fmt::Display for OffsetDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
+/// Parse a date, time, and offset in the format produced by its [`Display`](fmt::Display)
+/// implementation.
+///
+/// ```rust
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     "2023-01-02 03:04:05.6 +01:00:00".parse(),
+///     Ok(datetime!(2023-01-02 3:04:05.6 +1))
+/// );
+/// ```
+#[cfg(feature = "parsing")]
+impl FromStr for OffsetDateTime {
+    type Err = error::Parse;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, crate::format_description::display::OFFSET_DATE_TIME)
+    }
+}
+
 impl fmt::Debug for OffsetDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self, f)
@@ -1026,6 +1814,7 @@ impl fmt::Debug for OffsetDateTime {
 impl Add<Duration> for OffsetDateTime {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, rhs: Duration) -> Self::Output {
         Self(self.0.add(rhs))
     }
@@ -1034,18 +1823,21 @@ impl Add<Duration> for OffsetDateTime {
 impl Add<StdDuration> for OffsetDateTime {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, rhs: StdDuration) -> Self::Output {
         Self(self.0.add(rhs))
     }
 }
 
 impl AddAssign<Duration> for OffsetDateTime {
+    #[track_caller]
     fn add_assign(&mut self, rhs: Duration) {
         self.0.add_assign(rhs);
     }
 }
 
 impl AddAssign<StdDuration> for OffsetDateTime {
+    #[track_caller]
     fn add_assign(&mut self, rhs: StdDuration) {
         self.0.add_assign(rhs);
     }
@@ -1054,6 +1846,7 @@ impl AddAssign<StdDuration> for OffsetDateTime {
 impl Sub<Duration> for OffsetDateTime {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, rhs: Duration) -> Self::Output {
         Self(self.0.sub(rhs))
     }
@@ -1062,23 +1855,28 @@ impl Sub<Duration> for OffsetDateTime {
 impl Sub<StdDuration> for OffsetDateTime {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, rhs: StdDuration) -> Self::Output {
         Self(self.0.sub(rhs))
     }
 }
 
 impl SubAssign<Duration> for OffsetDateTime {
+    #[track_caller]
     fn sub_assign(&mut self, rhs: Duration) {
         self.0.sub_assign(rhs);
     }
 }
 
 impl SubAssign<StdDuration> for OffsetDateTime {
+    #[track_caller]
     fn sub_assign(&mut self, rhs: StdDuration) {
         self.0.sub_assign(rhs);
     }
 }
 
+/// The difference between any two `OffsetDateTime`s is always representable as a [`Duration`], so
+/// this never panics, even when one value is at each extreme of the supported range.
 impl Sub for OffsetDateTime {
     type Output = Duration;
 
@@ -1105,6 +1903,17 @@ impl Sub<OffsetDateTime> for SystemTime {
     }
 }
 
+impl_forward_ref_binop!(Add add for OffsetDateTime: Duration, StdDuration);
+impl_forward_ref_binop!(Sub sub for OffsetDateTime:
+    Duration,
+    StdDuration,
+    OffsetDateTime,
+    #[cfg(feature = "std")] SystemTime,
+);
+impl_forward_ref_binop!(Sub sub for SystemTime: #[cfg(feature = "std")] OffsetDateTime);
+impl_forward_ref_op_assign!(AddAssign add_assign for OffsetDateTime: Duration, StdDuration);
+impl_forward_ref_op_assign!(SubAssign sub_assign for OffsetDateTime: Duration, StdDuration);
+
 #[cfg(feature = "std")]
 impl PartialEq<SystemTime> for OffsetDateTime {
     fn eq(&self, rhs: &SystemTime) -> bool {
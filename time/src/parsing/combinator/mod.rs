@@ -155,6 +155,53 @@ pub(crate) fn n_to_m_digits_padded<'a, const N: u8, const M: u8, T: Integer>(
     }
 }
 
+/// Consume an optional sign followed by between `n` and `m` digits with the provided padding,
+/// returning the sign and the numerical value.
+///
+/// When padded with spaces, the sign may either precede the padding or directly precede the digits.
+pub(crate) fn signed_n_to_m_digits_padded<const N: u8, const M: u8, T: Integer>(
+    input: &[u8],
+    padding: Padding,
+) -> Option<ParsedItem<'_, (Option<u8>, T)>> {
+    debug_assert!(M >= N);
+
+    if padding == Padding::Space {
+        debug_assert!(N > 0);
+
+        let mut remaining = input;
+        let mut pad_width = 0;
+        while pad_width < N - 1 {
+            match ascii_char::<b' '>(remaining) {
+                Some(parsed) => remaining = parsed.0,
+                None => break,
+            }
+            pad_width += 1;
+        }
+
+        if pad_width != 0 {
+            if let Some(ParsedItem(digits, sign)) = sign(remaining) {
+                let mut remaining = digits;
+                for _ in pad_width..N {
+                    remaining = any_digit(remaining)?.0;
+                }
+                for _ in N..M {
+                    match any_digit(remaining) {
+                        Some(parsed) => remaining = parsed.0,
+                        None => break,
+                    }
+                }
+
+                return ParsedItem(remaining, &digits[..(digits.len() - remaining.len())])
+                    .flat_map(|value| value.parse_bytes())
+                    .map(|parsed| parsed.map(|value| (Some(sign), value)));
+            }
+        }
+    }
+
+    let ParsedItem(input, sign) = opt(sign)(input);
+    Some(n_to_m_digits_padded::<N, M, T>(padding)(input)?.map(|value| (sign, value)))
+}
+
 /// Consume exactly one digit.
 pub(crate) const fn any_digit(input: &[u8]) -> Option<ParsedItem<'_, u8>> {
     match input {
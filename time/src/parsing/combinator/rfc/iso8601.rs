@@ -6,7 +6,7 @@ use core::num::{NonZeroU16, NonZeroU8};
 
 use crate::parsing::combinator::{any_digit, ascii_char, exactly_n_digits, first_match, sign};
 use crate::parsing::ParsedItem;
-use crate::{Month, Weekday};
+use crate::Weekday;
 
 /// What kind of format is being parsed. This is used to ensure each part of the format (date, time,
 /// offset) is the same kind.
@@ -69,25 +69,9 @@ pub(crate) fn year(input: &[u8]) -> Option<ParsedItem<'_, i32>> {
     })
 }
 
-/// Parse a month.
-pub(crate) fn month(input: &[u8]) -> Option<ParsedItem<'_, Month>> {
-    first_match(
-        [
-            (b"01".as_slice(), Month::January),
-            (b"02".as_slice(), Month::February),
-            (b"03".as_slice(), Month::March),
-            (b"04".as_slice(), Month::April),
-            (b"05".as_slice(), Month::May),
-            (b"06".as_slice(), Month::June),
-            (b"07".as_slice(), Month::July),
-            (b"08".as_slice(), Month::August),
-            (b"09".as_slice(), Month::September),
-            (b"10".as_slice(), Month::October),
-            (b"11".as_slice(), Month::November),
-            (b"12".as_slice(), Month::December),
-        ],
-        true,
-    )(input)
+/// Parse a month. The value is not checked to be a valid month.
+pub(crate) fn month(input: &[u8]) -> Option<ParsedItem<'_, NonZeroU8>> {
+    exactly_n_digits::<2, _>(input)
 }
 
 /// Parse a week number.
@@ -131,6 +115,11 @@ pub(crate) fn min(input: &[u8]) -> Option<ParsedItem<'_, u8>> {
     exactly_n_digits::<2, _>(input)
 }
 
+/// Parse the second.
+pub(crate) fn sec(input: &[u8]) -> Option<ParsedItem<'_, u8>> {
+    exactly_n_digits::<2, _>(input)
+}
+
 /// Parse a floating point number as its integer and optional fractional parts.
 ///
 /// The number must have two digits before the decimal point. If a decimal point is present, at
@@ -4,26 +4,25 @@ use core::num::{NonZeroU16, NonZeroU8};
 
 use crate::convert::*;
 use crate::format_description::modifier;
-#[cfg(feature = "large-dates")]
-use crate::parsing::combinator::n_to_m_digits_padded;
 use crate::parsing::combinator::{
-    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, n_to_m_digits, opt, sign,
+    any_digit, ascii_char, exactly_n_digits, exactly_n_digits_padded, first_match, n_to_m_digits,
+    opt, sign, signed_n_to_m_digits_padded,
 };
 use crate::parsing::ParsedItem;
-use crate::{Month, Weekday};
+use crate::util::ordinal_suffix;
+use crate::{Month, Period, Weekday};
 
 // region: date components
 /// Parse the "year" component of a `Date`.
 pub(crate) fn parse_year(input: &[u8], modifiers: modifier::Year) -> Option<ParsedItem<'_, i32>> {
     match modifiers.repr {
         modifier::YearRepr::Full => {
-            let ParsedItem(input, sign) = opt(sign)(input);
             #[cfg(not(feature = "large-dates"))]
-            let ParsedItem(input, year) =
-                exactly_n_digits_padded::<4, u32>(modifiers.padding)(input)?;
+            let ParsedItem(input, (sign, year)) =
+                signed_n_to_m_digits_padded::<4, 4, u32>(input, modifiers.padding)?;
             #[cfg(feature = "large-dates")]
-            let ParsedItem(input, year) =
-                n_to_m_digits_padded::<4, 6, u32>(modifiers.padding)(input)?;
+            let ParsedItem(input, (sign, year)) =
+                signed_n_to_m_digits_padded::<4, 6, u32>(input, modifiers.padding)?;
             match sign {
                 Some(b'-') => Some(ParsedItem(input, -(year as i32))),
                 None if modifiers.sign_is_mandatory || year >= 10_000 => None,
@@ -46,7 +45,7 @@ pub(crate) fn parse_month(
         match modifiers.repr {
             modifier::MonthRepr::Numerical => {
                 return exactly_n_digits_padded::<2, _>(modifiers.padding)(input)?
-                    .flat_map(|n| Month::from_number(n).ok());
+                    .flat_map(|n| Month::from_nonzero_number(n).ok());
             }
             modifier::MonthRepr::Long => [
                 (b"January".as_slice(), January),
@@ -76,6 +75,20 @@ pub(crate) fn parse_month(
                 (b"Nov".as_slice(), November),
                 (b"Dec".as_slice(), December),
             ],
+            modifier::MonthRepr::CustomNames(names) => [
+                (names[0].as_bytes(), January),
+                (names[1].as_bytes(), February),
+                (names[2].as_bytes(), March),
+                (names[3].as_bytes(), April),
+                (names[4].as_bytes(), May),
+                (names[5].as_bytes(), June),
+                (names[6].as_bytes(), July),
+                (names[7].as_bytes(), August),
+                (names[8].as_bytes(), September),
+                (names[9].as_bytes(), October),
+                (names[10].as_bytes(), November),
+                (names[11].as_bytes(), December),
+            ],
         },
         modifiers.case_sensitive,
     )(input)?;
@@ -115,6 +128,15 @@ pub(crate) fn parse_weekday(
                 (b"Saturday".as_slice(), Weekday::Saturday),
                 (b"Sunday".as_slice(), Weekday::Sunday),
             ],
+            (modifier::WeekdayRepr::CustomNames(names), _) => [
+                (names[0].as_bytes(), Weekday::Monday),
+                (names[1].as_bytes(), Weekday::Tuesday),
+                (names[2].as_bytes(), Weekday::Wednesday),
+                (names[3].as_bytes(), Weekday::Thursday),
+                (names[4].as_bytes(), Weekday::Friday),
+                (names[5].as_bytes(), Weekday::Saturday),
+                (names[6].as_bytes(), Weekday::Sunday),
+            ],
             (modifier::WeekdayRepr::Sunday, false) => [
                 (b"1".as_slice(), Weekday::Monday),
                 (b"2".as_slice(), Weekday::Tuesday),
@@ -169,20 +191,18 @@ pub(crate) fn parse_day(
     input: &[u8],
     modifiers: modifier::Day,
 ) -> Option<ParsedItem<'_, NonZeroU8>> {
-    exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
+    let ParsedItem(input, day) = exactly_n_digits_padded::<2, NonZeroU8>(modifiers.padding)(input)?;
+    match modifiers.repr {
+        modifier::DayRepr::Numerical => Some(ParsedItem(input, day)),
+        modifier::DayRepr::Ordinal => Some(ParsedItem(
+            input.strip_prefix(ordinal_suffix(day.get()).as_bytes())?,
+            day,
+        )),
+    }
 }
 // endregion date components
 
 // region: time components
-/// Indicate whether the hour is "am" or "pm".
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub(crate) enum Period {
-    #[allow(clippy::missing_docs_in_private_items)]
-    Am,
-    #[allow(clippy::missing_docs_in_private_items)]
-    Pm,
-}
-
 /// Parse the "hour" component of a `Time`.
 pub(crate) fn parse_hour(input: &[u8], modifiers: modifier::Hour) -> Option<ParsedItem<'_, u8>> {
     exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
@@ -266,8 +286,14 @@ pub(crate) fn parse_offset_hour(
     input: &[u8],
     modifiers: modifier::OffsetHour,
 ) -> Option<ParsedItem<'_, (i8, bool)>> {
-    let ParsedItem(input, sign) = opt(sign)(input);
-    let ParsedItem(input, hour) = exactly_n_digits_padded::<2, u8>(modifiers.padding)(input)?;
+    if modifiers.z_when_utc {
+        if let Some(ParsedItem(input, ())) = ascii_char::<b'Z'>(input) {
+            return Some(ParsedItem(input, (0, false)));
+        }
+    }
+
+    let ParsedItem(input, (sign, hour)) =
+        signed_n_to_m_digits_padded::<2, 2, u8>(input, modifiers.padding)?;
     match sign {
         Some(b'-') => Some(ParsedItem(input, (-(hour as i8), true))),
         None if modifiers.sign_is_mandatory => None,
@@ -280,10 +306,11 @@ pub(crate) fn parse_offset_minute(
     input: &[u8],
     modifiers: modifier::OffsetMinute,
 ) -> Option<ParsedItem<'_, i8>> {
-    Some(
-        exactly_n_digits_padded::<2, u8>(modifiers.padding)(input)?
-            .map(|offset_minute| offset_minute as _),
-    )
+    match exactly_n_digits_padded::<2, u8>(modifiers.padding)(input) {
+        Some(item) => Some(item.map(|offset_minute| offset_minute as _)),
+        None if modifiers.omit_when_zero => Some(ParsedItem(input, 0)),
+        None => None,
+    }
 }
 
 /// Parse the "second" component of a `UtcOffset`.
@@ -296,6 +323,31 @@ pub(crate) fn parse_offset_second(
             .map(|offset_second| offset_second as _),
     )
 }
+
+/// Parse the hour and minute of a `UtcOffset`, with or without a separator.
+///
+/// Returns the hour, minute, and whether the value is negative. As with the individual components,
+/// the sign is not applied to the minute.
+pub(crate) fn parse_offset(
+    input: &[u8],
+    modifiers: modifier::Offset,
+) -> Option<ParsedItem<'_, (i8, i8, bool)>> {
+    if modifiers.z_when_utc {
+        if let Some(ParsedItem(input, ())) = ascii_char::<b'Z'>(input) {
+            return Some(ParsedItem(input, (0, 0, false)));
+        }
+    }
+
+    let ParsedItem(input, (sign, hour)) =
+        signed_n_to_m_digits_padded::<2, 2, u8>(input, modifier::Padding::Zero)?;
+    let ParsedItem(input, _) = opt(ascii_char::<b':'>)(input);
+    let ParsedItem(input, minute) = exactly_n_digits::<2, u8>(input)?;
+    match sign {
+        Some(b'-') => Some(ParsedItem(input, (-(hour as i8), minute as _, true))),
+        None if modifiers.sign_is_mandatory => None,
+        _ => Some(ParsedItem(input, (hour as i8, minute as _, false))),
+    }
+}
 // endregion offset components
 
 /// Ignore the given number of bytes.
@@ -314,15 +366,19 @@ pub(crate) fn parse_unix_timestamp(
     modifiers: modifier::UnixTimestamp,
 ) -> Option<ParsedItem<'_, i128>> {
     let ParsedItem(input, sign) = opt(sign)(input);
-    let ParsedItem(input, nano_timestamp) = match modifiers.precision {
-        modifier::UnixTimestampPrecision::Second => {
-            n_to_m_digits::<1, 14, u128>(input)?.map(|val| val * Nanosecond.per(Second) as u128)
+    let ParsedItem(input, nano_timestamp) = if modifiers.decimal {
+        parse_decimal_unix_timestamp(input, modifiers.precision)?
+    } else {
+        match modifiers.precision {
+            modifier::UnixTimestampPrecision::Second => {
+                n_to_m_digits::<1, 14, u128>(input)?.map(|val| val * Nanosecond.per(Second) as u128)
+            }
+            modifier::UnixTimestampPrecision::Millisecond => n_to_m_digits::<1, 17, u128>(input)?
+                .map(|val| val * Nanosecond.per(Millisecond) as u128),
+            modifier::UnixTimestampPrecision::Microsecond => n_to_m_digits::<1, 20, u128>(input)?
+                .map(|val| val * Nanosecond.per(Microsecond) as u128),
+            modifier::UnixTimestampPrecision::Nanosecond => n_to_m_digits::<1, 23, _>(input)?,
         }
-        modifier::UnixTimestampPrecision::Millisecond => n_to_m_digits::<1, 17, u128>(input)?
-            .map(|val| val * Nanosecond.per(Millisecond) as u128),
-        modifier::UnixTimestampPrecision::Microsecond => n_to_m_digits::<1, 20, u128>(input)?
-            .map(|val| val * Nanosecond.per(Microsecond) as u128),
-        modifier::UnixTimestampPrecision::Nanosecond => n_to_m_digits::<1, 23, _>(input)?,
     };
 
     match sign {
@@ -331,3 +387,31 @@ pub(crate) fn parse_unix_timestamp(
         _ => Some(ParsedItem(input, nano_timestamp as _)),
     }
 }
+
+/// Parse the magnitude of a Unix timestamp written as a decimal number of seconds, returning the
+/// number of nanoseconds. The number of fractional digits must match the precision exactly.
+fn parse_decimal_unix_timestamp(
+    input: &[u8],
+    precision: modifier::UnixTimestampPrecision,
+) -> Option<ParsedItem<'_, u128>> {
+    let ParsedItem(input, seconds) = n_to_m_digits::<1, 14, u128>(input)?;
+    let ParsedItem(input, subsecond) = match precision {
+        modifier::UnixTimestampPrecision::Second => ParsedItem(input, 0),
+        modifier::UnixTimestampPrecision::Millisecond => {
+            let input = ascii_char::<b'.'>(input)?.into_inner();
+            exactly_n_digits::<3, u128>(input)?.map(|val| val * Nanosecond.per(Millisecond) as u128)
+        }
+        modifier::UnixTimestampPrecision::Microsecond => {
+            let input = ascii_char::<b'.'>(input)?.into_inner();
+            exactly_n_digits::<6, u128>(input)?.map(|val| val * Nanosecond.per(Microsecond) as u128)
+        }
+        modifier::UnixTimestampPrecision::Nanosecond => {
+            let input = ascii_char::<b'.'>(input)?.into_inner();
+            exactly_n_digits::<9, u128>(input)?
+        }
+    };
+    Some(ParsedItem(
+        input,
+        seconds * Nanosecond.per(Second) as u128 + subsecond,
+    ))
+}
@@ -0,0 +1,67 @@
+//! The value of a single component parsed from the input.
+
+use core::num::{NonZeroU16, NonZeroU8};
+
+use crate::{Month, Period, Weekday};
+
+/// The value of a single component, as returned by
+/// [`Component::parse_value`](crate::format_description::Component::parse_value).
+///
+/// Values are only checked as far as the component's modifiers require. For example, an hour of
+/// `99` is returned as-is; it is rejected only when converting into a type such as [`Time`].
+///
+/// [`Time`]: crate::Time
+#[non_exhaustive]
+#[allow(variant_size_differences)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ParsedComponentValue {
+    /// Day of the month.
+    Day(NonZeroU8),
+    /// Month of the year.
+    Month(Month),
+    /// Ordinal day of the year.
+    Ordinal(NonZeroU16),
+    /// Day of the week.
+    Weekday(Weekday),
+    /// Week within the year, using the representation indicated by the modifier.
+    WeekNumber(u8),
+    /// Year of the date. If the modifier indicates only the last two digits are present, this is
+    /// the value of those two digits.
+    Year(i32),
+    /// Hour of the day, using the clock indicated by the modifier.
+    Hour(u8),
+    /// Minute within the hour.
+    Minute(u8),
+    /// AM/PM part of the time.
+    Period(Period),
+    /// Second within the minute.
+    Second(u8),
+    /// Subsecond within the second, in nanoseconds.
+    Subsecond(u32),
+    /// Hour of the UTC offset.
+    OffsetHour {
+        /// The signed value of the hour.
+        value: i8,
+        /// Whether a negative sign was present. This is needed to distinguish `-00` from `+00`,
+        /// as the sign also applies to the minute and second of the offset.
+        is_negative: bool,
+    },
+    /// Minute within the hour of the UTC offset. The sign of the offset is not applied.
+    OffsetMinute(i8),
+    /// Second within the minute of the UTC offset. The sign of the offset is not applied.
+    OffsetSecond(i8),
+    /// Hour and minute of the UTC offset.
+    Offset {
+        /// The signed value of the hour.
+        hour: i8,
+        /// The minute within the hour. The sign of the offset is not applied.
+        minute: i8,
+        /// Whether a negative sign was present. This is needed to distinguish `-00:30` from
+        /// `+00:30`.
+        is_negative: bool,
+    },
+    /// The bytes were ignored.
+    Ignore,
+    /// A Unix timestamp, in nanoseconds.
+    UnixTimestamp(i128),
+}
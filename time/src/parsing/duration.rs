@@ -0,0 +1,165 @@
+//! Parse a [`Duration`] from its human-readable or ISO 8601 representation.
+
+use crate::convert::*;
+use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+use crate::parsing::combinator::{any_digit, ascii_char, first_match, n_to_m_digits};
+use crate::format_description::well_known::Iso8601Duration;
+use crate::parsing::ParsedItem;
+use crate::{error, Duration};
+
+/// Parse a duration in the format emitted by its `Display` implementation, such as `1d2h3m4s`.
+///
+/// Weeks (`w`) are accepted in addition to the units emitted by `Display`. Each unit may appear at
+/// most once, and units must be in descending order of size.
+pub(crate) fn parse_human(input: &[u8]) -> Result<Duration, error::Parse> {
+    let (mut input, is_negative) = match ascii_char::<b'-'>(input) {
+        Some(ParsedItem(input, ())) => (input, true),
+        None => (input, false),
+    };
+
+    let mut nanoseconds: u128 = 0;
+    // The size of the previous unit in nanoseconds, used to enforce the order of units.
+    let mut previous_unit = None;
+
+    loop {
+        if previous_unit.is_some() && any_digit(input).is_none() {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+
+        let ParsedItem(remaining, value) =
+            n_to_m_digits::<1, 39, u128>(input).ok_or(InvalidComponent("value"))?;
+        // `ms` must be checked before `m`, as the latter is a prefix of the former.
+        let ParsedItem(remaining, unit) = first_match(
+            [
+                (b"w".as_slice(), Nanosecond.per(Week) as u128),
+                (b"d".as_slice(), Nanosecond.per(Day) as u128),
+                (b"h".as_slice(), Nanosecond.per(Hour) as u128),
+                (b"ms".as_slice(), Nanosecond.per(Millisecond) as u128),
+                (b"m".as_slice(), Nanosecond.per(Minute) as u128),
+                (b"s".as_slice(), Nanosecond.per(Second) as u128),
+                ("µs".as_bytes(), Nanosecond.per(Microsecond) as u128),
+                (b"ns".as_slice(), 1),
+            ],
+            true,
+        )(remaining)
+        .ok_or(InvalidComponent("unit"))?;
+
+        if previous_unit.map_or(false, |previous_unit| unit >= previous_unit) {
+            return Err(InvalidComponent("unit").into());
+        }
+        previous_unit = Some(unit);
+
+        nanoseconds = value
+            .checked_mul(unit)
+            .and_then(|value| nanoseconds.checked_add(value))
+            .ok_or(InvalidComponent("value"))?;
+
+        input = remaining;
+        if input.is_empty() {
+            break;
+        }
+    }
+
+    let seconds = (nanoseconds / Nanosecond.per(Second) as u128) as i128;
+    let nanoseconds = (nanoseconds % Nanosecond.per(Second) as u128) as i32;
+    let (seconds, nanoseconds) = if is_negative {
+        (-seconds, -nanoseconds)
+    } else {
+        (seconds, nanoseconds)
+    };
+    let seconds = i64::try_from(seconds).map_err(|_| InvalidComponent("value"))?;
+
+    Ok(Duration::new_unchecked(seconds, nanoseconds))
+}
+
+impl Iso8601Duration {
+    /// Parse a duration in the ISO 8601 format, such as `P1DT2H30M`.
+    ///
+    /// Weeks, days, hours, minutes, and seconds are accepted, in that order. Only the last
+    /// component present may have a fractional part, for which any precision beyond a nanosecond
+    /// is truncated. The configuration has no effect.
+    pub(crate) fn parse_duration(&self, input: &[u8]) -> Result<Duration, error::Parse> {
+        let (input, is_negative) = match input {
+            [b'-', input @ ..] => (input, true),
+            [b'+', input @ ..] => (input, false),
+            _ => (input, false),
+        };
+        let mut input = ascii_char::<b'P'>(input)
+            .ok_or(InvalidLiteral)?
+            .into_inner();
+
+        let mut nanoseconds: u128 = 0;
+        // The size of the previous unit in nanoseconds, used to enforce the order of units.
+        let mut previous_unit = None;
+        let mut in_time = false;
+
+        loop {
+            if !in_time {
+                if let Some(ParsedItem(remaining, ())) = ascii_char::<b'T'>(input) {
+                    in_time = true;
+                    input = remaining;
+                }
+            }
+
+            let ParsedItem(remaining, value) =
+                n_to_m_digits::<1, 39, u128>(input).ok_or(InvalidComponent("value"))?;
+            input = remaining;
+
+            // Digits beyond the eighteenth are below nanosecond precision for every unit.
+            let (mut numerator, mut denominator) = (0_u64, 1_u64);
+            if let [b'.' | b',', remaining @ ..] = input {
+                if any_digit(remaining).is_none() {
+                    return Err(InvalidComponent("value").into());
+                }
+                input = remaining;
+                while let Some(ParsedItem(remaining, digit)) = any_digit(input) {
+                    if denominator < 1_000_000_000_000_000_000 {
+                        numerator = numerator * 10 + (digit - b'0') as u64;
+                        denominator *= 10;
+                    }
+                    input = remaining;
+                }
+            }
+
+            let (remaining, unit) = match (in_time, input) {
+                (false, [b'W', remaining @ ..]) => (remaining, Nanosecond.per(Week) as u128),
+                (false, [b'D', remaining @ ..]) => (remaining, Nanosecond.per(Day) as u128),
+                (true, [b'H', remaining @ ..]) => (remaining, Nanosecond.per(Hour) as u128),
+                (true, [b'M', remaining @ ..]) => (remaining, Nanosecond.per(Minute) as u128),
+                (true, [b'S', remaining @ ..]) => (remaining, Nanosecond.per(Second) as u128),
+                _ => return Err(InvalidComponent("unit").into()),
+            };
+
+            if previous_unit.map_or(false, |previous_unit| unit >= previous_unit) {
+                return Err(InvalidComponent("unit").into());
+            }
+            previous_unit = Some(unit);
+
+            nanoseconds = value
+                .checked_mul(unit)
+                .and_then(|value| value.checked_add(numerator as u128 * unit / denominator as u128))
+                .and_then(|value| nanoseconds.checked_add(value))
+                .ok_or(InvalidComponent("value"))?;
+
+            input = remaining;
+            if input.is_empty() {
+                break;
+            }
+            // Only the last component may have a fractional part.
+            if denominator != 1 {
+                return Err(error::Parse::UnexpectedTrailingCharacters);
+            }
+        }
+
+        let seconds = (nanoseconds / Nanosecond.per(Second) as u128) as i128;
+        let nanoseconds = (nanoseconds % Nanosecond.per(Second) as u128) as i32;
+        let (seconds, nanoseconds) = if is_negative {
+            (-seconds, -nanoseconds)
+        } else {
+            (seconds, nanoseconds)
+        };
+        let seconds = i64::try_from(seconds).map_err(|_| InvalidComponent("value"))?;
+
+        Ok(Duration::new_unchecked(seconds, nanoseconds))
+    }
+}
@@ -3,15 +3,16 @@
 use crate::convert::*;
 use crate::error;
 use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
-use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::Iso8601;
+use crate::error::TryFromParsed::ComponentRange;
+use crate::format_description::well_known::iso8601::Config;
 use crate::parsing::combinator::rfc::iso8601::{
-    day, dayk, dayo, float, hour, min, month, week, year, ExtendedKind,
+    day, dayk, dayo, float, hour, min, month, sec, week, year, ExtendedKind,
 };
 use crate::parsing::combinator::{ascii_char, sign};
 use crate::parsing::{Parsed, ParsedItem};
+use crate::Month;
 
-impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
+impl Config {
     // Basic: [year][month][day]
     // Extended: [year]["-"][month]["-"][day]
     // Basic: [year][dayo]
@@ -34,8 +35,10 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                 None => ExtendedKind::Basic, // no separator before mandatory month/ordinal/week
             };
 
-            let mut ret_error = match (|| {
+            let mut ret_error = match (|| -> Result<_, error::Parse> {
                 let ParsedItem(mut input, month) = month(input).ok_or(InvalidComponent("month"))?;
+                // If no other format matches, the out-of-range value is the most helpful error.
+                let month = Month::from_nonzero_number(month).map_err(ComponentRange)?;
                 if extended_kind.is_extended() {
                     input = ascii_char::<b'-'>(input)
                         .ok_or(InvalidLiteral)?
@@ -94,10 +97,10 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                 }
                 Err((false, _err)) => {}
                 // This error is more accurate than the one from year-month-day.
-                Err((true, err)) => ret_error = err,
+                Err((true, err)) => ret_error = err.into(),
             }
 
-            Err(ret_error.into())
+            Err(ret_error)
         }
     }
 
@@ -105,8 +108,10 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     // Extended: ["T"][hour][":"][min][":"][sec]
     // Reduced precision: components after [hour] (including their preceding separator) can be
     // omitted. ["T"] can be omitted if there is no date present.
-    /// Parse a time in the basic or extended format. Reduced precision is permitted.
+    /// Parse a time in the basic or extended format. Reduced precision is permitted unless the
+    /// configuration requires the time to be given to the second.
     pub(crate) fn parse_time<'a>(
+        &'a self,
         parsed: &'a mut Parsed,
         extended_kind: &'a mut ExtendedKind,
         date_is_present: bool,
@@ -121,6 +126,9 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
             let ParsedItem(mut input, hour) = float(input).ok_or(InvalidComponent("hour"))?;
             match hour {
                 (hour, None) => parsed.set_hour_24(hour).ok_or(InvalidComponent("hour"))?,
+                (_, Some(_)) if self.strict_time_precision => {
+                    return Err(InvalidComponent("minute").into());
+                }
                 (hour, Some(fractional_part)) => {
                     *parsed = parsed
                         .with_hour_24(hour)
@@ -141,11 +149,13 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                 }
             };
 
+            let mut separator_is_present = false;
             if let Some(ParsedItem(new_input, ())) = ascii_char::<b':'>(input) {
                 extended_kind
                     .coerce_extended()
                     .ok_or(InvalidComponent("minute"))?;
                 input = new_input;
+                separator_is_present = true;
             };
 
             let mut input = match float(input) {
@@ -156,6 +166,9 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                         .ok_or(InvalidComponent("minute"))?;
                     input
                 }
+                Some(ParsedItem(_, (_, Some(_)))) if self.strict_time_precision => {
+                    return Err(InvalidComponent("second").into());
+                }
                 Some(ParsedItem(input, (minute, Some(fractional_part)))) => {
                     // `None` is valid behavior, so don't error if this fails.
                     extended_kind.coerce_basic();
@@ -172,7 +185,7 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                     return Ok(input);
                 }
                 // colon was present, so minutes are required
-                None if extended_kind.is_extended() => {
+                None if separator_is_present || self.strict_time_precision => {
                     return Err(error::Parse::ParseFromDescription(InvalidComponent(
                         "minute",
                     )));
@@ -193,6 +206,9 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
             if extended_kind.is_extended() {
                 match ascii_char::<b':'>(input) {
                     Some(ParsedItem(new_input, ())) => input = new_input,
+                    None if self.strict_time_precision => {
+                        return Err(InvalidComponent("second").into());
+                    }
                     None => {
                         *parsed = parsed
                             .with_second(0)
@@ -211,7 +227,7 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                     second,
                     round(fractional_part * Nanosecond.per(Second) as f64) as _,
                 ),
-                None if extended_kind.is_extended() => {
+                None if extended_kind.is_extended() || self.strict_time_precision => {
                     return Err(error::Parse::ParseFromDescription(InvalidComponent(
                         "second",
                     )));
@@ -229,10 +245,11 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
         }
     }
 
-    // Basic: [±][hour][min] or ["Z"]
-    // Extended: [±][hour][":"][min] or ["Z"]
+    // Basic: [±][hour][min] or [±][hour][min][sec] or ["Z"]
+    // Extended: [±][hour][":"][min] or [±][hour][":"][min][":"][sec] or ["Z"]
     // Reduced precision: [±][hour] or ["Z"]
-    /// Parse a UTC offset in the basic or extended format. Reduced precision is supported.
+    /// Parse a UTC offset in the basic or extended format. Reduced precision and offsets with
+    /// seconds are supported.
     pub(crate) fn parse_offset<'a>(
         parsed: &'a mut Parsed,
         extended_kind: &'a mut ExtendedKind,
@@ -262,15 +279,25 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
                 })
                 .ok_or(InvalidComponent("offset hour"))?;
 
+            let mut separator_is_present = false;
             if extended_kind.maybe_extended() {
                 if let Some(ParsedItem(new_input, ())) = ascii_char::<b':'>(input) {
                     extended_kind
                         .coerce_extended()
                         .ok_or(InvalidComponent("offset minute"))?;
                     input = new_input;
+                    separator_is_present = true;
                 };
             }
 
+            // The minute may be omitted entirely, as in `+01`, but not after a separator.
+            if !separator_is_present && min(input).is_none() {
+                parsed
+                    .set_offset_minute_signed(0)
+                    .ok_or(InvalidComponent("offset minute"))?;
+                return Ok(input);
+            }
+
             let input = min(input)
                 .and_then(|parsed_item| {
                     parsed_item.consume_value(|min| {
@@ -288,6 +315,30 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
             // returning `None` is valid behavior.
             extended_kind.coerce_basic();
 
+            // The second is optional, but must be preceded by a separator in the extended format.
+            let input = if separator_is_present {
+                match ascii_char::<b':'>(input) {
+                    Some(ParsedItem(input, ())) => input,
+                    None => return Ok(input),
+                }
+            } else if sec(input).is_some() {
+                input
+            } else {
+                return Ok(input);
+            };
+
+            let input = sec(input)
+                .and_then(|parsed_item| {
+                    parsed_item.consume_value(|sec| {
+                        parsed.set_offset_second_signed(if sign == b'-' {
+                            -(sec as i8)
+                        } else {
+                            sec as _
+                        })
+                    })
+                })
+                .ok_or(InvalidComponent("offset second"))?;
+
             Ok(input)
         }
     }
@@ -2,14 +2,60 @@
 
 pub(crate) mod combinator;
 pub(crate) mod component;
+mod component_value;
+pub(crate) mod duration;
 mod iso8601;
 pub(crate) mod parsable;
-mod parsed;
+pub(crate) mod parsed;
 pub(crate) mod shim;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
+#[cfg(feature = "alloc")]
+use crate::error;
+
+pub use self::component_value::ParsedComponentValue;
 pub use self::parsable::Parsable;
 pub use self::parsed::Parsed;
 
+/// The outcome of parsing many inputs at once, such as with
+/// [`OffsetDateTime::parse_many`](crate::OffsetDateTime::parse_many).
+///
+/// Successfully parsed values are kept in the order they were encountered. Each failure is
+/// recorded alongside the zero-based index of the input that caused it.
+#[cfg(feature = "alloc")]
+#[non_exhaustive]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseManyResult<T> {
+    /// The values that were parsed successfully.
+    pub values: Vec<T>,
+    /// The index of each input that could not be parsed, along with the error encountered.
+    pub errors: Vec<(usize, error::Parse)>,
+}
+
+#[cfg(feature = "alloc")]
+impl<T> ParseManyResult<T> {
+    /// Parse each input with the provided function, collecting the successes and failures.
+    pub(crate) fn collect<'a>(
+        inputs: impl IntoIterator<Item = &'a str>,
+        mut parse: impl FnMut(&'a str) -> Result<T, error::Parse>,
+    ) -> Self {
+        let inputs = inputs.into_iter();
+        let mut result = Self {
+            values: Vec::with_capacity(inputs.size_hint().0),
+            errors: Vec::new(),
+        };
+        for (index, input) in inputs.enumerate() {
+            match parse(input) {
+                Ok(value) => result.values.push(value),
+                Err(err) => result.errors.push((index, err)),
+            }
+        }
+        result
+    }
+}
+
 /// An item that has been parsed. Represented as a `(remaining, value)` pair.
 #[derive(Debug)]
 pub(crate) struct ParsedItem<'a, T>(pub(crate) &'a [u8], pub(crate) T);
@@ -4,11 +4,15 @@ use core::ops::Deref;
 
 use crate::date_time::{maybe_offset_from_offset, MaybeOffset};
 use crate::error::TryFromParsed;
-use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use crate::format_description::well_known::iso8601::{Config, EncodedConfig};
+use crate::format_description::well_known::{
+    Iso8601, Iso8601Dynamic, JsDate, Rfc2822, Rfc3339, Rfc3339Lenient, Rfc3339NoZ, Rfc7231,
+    Systemd, Unix,
+};
 use crate::format_description::FormatItem;
 #[cfg(feature = "alloc")]
-use crate::format_description::OwnedFormatItem;
+use crate::format_description::{CompiledFormat, OwnedFormatItem};
+use crate::parsing::parsed::rename_offset_component;
 use crate::parsing::{Parsed, ParsedItem};
 use crate::{error, Date, DateTime, Month, Time, UtcOffset, Weekday};
 
@@ -21,9 +25,18 @@ impl Parsable for [FormatItem<'_>] {}
 impl Parsable for OwnedFormatItem {}
 #[cfg(feature = "alloc")]
 impl Parsable for [OwnedFormatItem] {}
+#[cfg(feature = "alloc")]
+impl Parsable for CompiledFormat {}
 impl Parsable for Rfc2822 {}
 impl Parsable for Rfc3339 {}
+impl Parsable for Rfc3339NoZ {}
+impl Parsable for Rfc3339Lenient {}
+impl Parsable for Rfc7231 {}
+impl Parsable for JsDate {}
+impl Parsable for Systemd {}
+impl Parsable for Unix {}
 impl<const CONFIG: EncodedConfig> Parsable for Iso8601<CONFIG> {}
+impl Parsable for Iso8601Dynamic {}
 impl<T: Deref> Parsable for T where T::Target: Parsable {}
 
 /// Seal the trait to prevent downstream users from implementing it, while still allowing it to
@@ -78,6 +91,23 @@ mod sealed {
         ) -> Result<DateTime<O>, error::Parse> {
             Ok(self.parse(input)?.try_into()?)
         }
+
+        /// Parse a [`DateTime`] from the format description, using `parsed` as scratch space. Its
+        /// contents afterwards are unspecified.
+        ///
+        /// This allows a single [`Parsed`] to be reused when parsing many inputs. Formats that
+        /// parse a `DateTime` directly do so without using `parsed`.
+        fn parse_date_time_reusing<O: MaybeOffset>(
+            &self,
+            input: &[u8],
+            parsed: &mut Parsed,
+        ) -> Result<DateTime<O>, error::Parse> {
+            parsed.clear();
+            if !self.parse_into(input, parsed)?.is_empty() {
+                return Err(error::Parse::UnexpectedTrailingCharacters);
+            }
+            Ok((*parsed).try_into()?)
+        }
     }
 }
 
@@ -124,6 +154,17 @@ impl sealed::Sealed for [OwnedFormatItem] {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl sealed::Sealed for CompiledFormat {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        Ok(parsed.parse_item(input, self)?)
+    }
+}
+
 impl<T: Deref> sealed::Sealed for T
 where
     T::Target: sealed::Sealed,
@@ -145,6 +186,10 @@ impl sealed::Sealed for Rfc2822 {
         input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
+        parse_rfc2822_into(input, parsed, false)
+    }
+
+    fn parse_date_time<O: MaybeOffset>(&self, input: &[u8]) -> Result<DateTime<O>, error::Parse> {
         use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
         use crate::parsing::combinator::rfc::rfc2822::{cfws, fws};
         use crate::parsing::combinator::{
@@ -155,7 +200,7 @@ impl sealed::Sealed for Rfc2822 {
         let comma = ascii_char::<b','>;
 
         let input = opt(fws)(input).into_inner();
-        let input = first_match(
+        let ParsedItem(input, weekday) = first_match(
             [
                 (b"Mon".as_slice(), Weekday::Monday),
                 (b"Tue".as_slice(), Weekday::Tuesday),
@@ -167,161 +212,6 @@ impl sealed::Sealed for Rfc2822 {
             ],
             false,
         )(input)
-        .and_then(|item| item.consume_value(|value| parsed.set_weekday(value)))
-        .ok_or(InvalidComponent("weekday"))?;
-        let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = n_to_m_digits::<1, 2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
-            .ok_or(InvalidComponent("day"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = first_match(
-            [
-                (b"Jan".as_slice(), Month::January),
-                (b"Feb".as_slice(), Month::February),
-                (b"Mar".as_slice(), Month::March),
-                (b"Apr".as_slice(), Month::April),
-                (b"May".as_slice(), Month::May),
-                (b"Jun".as_slice(), Month::June),
-                (b"Jul".as_slice(), Month::July),
-                (b"Aug".as_slice(), Month::August),
-                (b"Sep".as_slice(), Month::September),
-                (b"Oct".as_slice(), Month::October),
-                (b"Nov".as_slice(), Month::November),
-                (b"Dec".as_slice(), Month::December),
-            ],
-            false,
-        )(input)
-        .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
-        .ok_or(InvalidComponent("month"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = match exactly_n_digits::<4, u32>(input) {
-            Some(item) => {
-                let input = item
-                    .flat_map(|year| if year >= 1900 { Some(year) } else { None })
-                    .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
-                    .ok_or(InvalidComponent("year"))?;
-                fws(input).ok_or(InvalidLiteral)?.into_inner()
-            }
-            None => {
-                let input = exactly_n_digits::<2, u32>(input)
-                    .and_then(|item| {
-                        item.map(|year| if year < 50 { year + 2000 } else { year + 1900 })
-                            .map(|year| year as _)
-                            .consume_value(|value| parsed.set_year(value))
-                    })
-                    .ok_or(InvalidComponent("year"))?;
-                cfws(input).ok_or(InvalidLiteral)?.into_inner()
-            }
-        };
-
-        let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
-            .ok_or(InvalidComponent("hour"))?;
-        let input = opt(cfws)(input).into_inner();
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = opt(cfws)(input).into_inner();
-        let input = exactly_n_digits::<2, _>(input)
-            .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
-            .ok_or(InvalidComponent("minute"))?;
-
-        let input = if let Some(input) = colon(opt(cfws)(input).into_inner()) {
-            let input = input.into_inner(); // discard the colon
-            let input = opt(cfws)(input).into_inner();
-            let input = exactly_n_digits::<2, _>(input)
-                .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
-                .ok_or(InvalidComponent("second"))?;
-            cfws(input).ok_or(InvalidLiteral)?.into_inner()
-        } else {
-            cfws(input).ok_or(InvalidLiteral)?.into_inner()
-        };
-
-        // The RFC explicitly allows leap seconds.
-        parsed.set_flag(Parsed::LEAP_SECOND_ALLOWED_FLAG, true);
-
-        #[allow(clippy::unnecessary_lazy_evaluations)] // rust-lang/rust-clippy#8522
-        let zone_literal = first_match(
-            [
-                (b"UT".as_slice(), 0),
-                (b"GMT".as_slice(), 0),
-                (b"EST".as_slice(), -5),
-                (b"EDT".as_slice(), -4),
-                (b"CST".as_slice(), -6),
-                (b"CDT".as_slice(), -5),
-                (b"MST".as_slice(), -7),
-                (b"MDT".as_slice(), -6),
-                (b"PST".as_slice(), -8),
-                (b"PDT".as_slice(), -7),
-            ],
-            false,
-        )(input)
-        .or_else(|| match input {
-            [
-                b'a'..=b'i' | b'k'..=b'z' | b'A'..=b'I' | b'K'..=b'Z',
-                rest @ ..,
-            ] => Some(ParsedItem(rest, 0)),
-            _ => None,
-        });
-        if let Some(zone_literal) = zone_literal {
-            let input = zone_literal
-                .consume_value(|value| parsed.set_offset_hour(value))
-                .ok_or(InvalidComponent("offset hour"))?;
-            parsed
-                .set_offset_minute_signed(0)
-                .ok_or(InvalidComponent("offset minute"))?;
-            parsed
-                .set_offset_second_signed(0)
-                .ok_or(InvalidComponent("offset second"))?;
-            return Ok(input);
-        }
-
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
-        let input = exactly_n_digits::<2, u8>(input)
-            .and_then(|item| {
-                item.map(|offset_hour| {
-                    if offset_sign == b'-' {
-                        -(offset_hour as i8)
-                    } else {
-                        offset_hour as _
-                    }
-                })
-                .consume_value(|value| parsed.set_offset_hour(value))
-            })
-            .ok_or(InvalidComponent("offset hour"))?;
-        let input = exactly_n_digits::<2, u8>(input)
-            .and_then(|item| {
-                item.consume_value(|value| parsed.set_offset_minute_signed(value as _))
-            })
-            .ok_or(InvalidComponent("offset minute"))?;
-
-        Ok(input)
-    }
-
-    fn parse_date_time<O: MaybeOffset>(&self, input: &[u8]) -> Result<DateTime<O>, error::Parse> {
-        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
-        use crate::parsing::combinator::rfc::rfc2822::{cfws, fws};
-        use crate::parsing::combinator::{
-            ascii_char, exactly_n_digits, first_match, n_to_m_digits, opt, sign,
-        };
-
-        let colon = ascii_char::<b':'>;
-        let comma = ascii_char::<b','>;
-
-        let input = opt(fws)(input).into_inner();
-        // This parses the weekday, but we don't actually use the value anywhere. Because of this,
-        // just return `()` to avoid unnecessary generated code.
-        let ParsedItem(input, ()) = first_match(
-            [
-                (b"Mon".as_slice(), ()),
-                (b"Tue".as_slice(), ()),
-                (b"Wed".as_slice(), ()),
-                (b"Thu".as_slice(), ()),
-                (b"Fri".as_slice(), ()),
-                (b"Sat".as_slice(), ()),
-                (b"Sun".as_slice(), ()),
-            ],
-            false,
-        )(input)
         .ok_or(InvalidComponent("weekday"))?;
         let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
         let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
@@ -424,9 +314,18 @@ impl sealed::Sealed for Rfc2822 {
                     })
                 })
                 .ok_or(InvalidComponent("offset hour"))?;
-            let ParsedItem(input, offset_minute) =
-                exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset minute"))?;
-            (input, offset_hour, offset_minute as i8)
+            let ParsedItem(input, offset_minute) = exactly_n_digits::<2, u8>(input)
+                .map(|item| {
+                    item.map(|offset_minute| {
+                        if offset_sign == b'-' {
+                            -(offset_minute as i8)
+                        } else {
+                            offset_minute as _
+                        }
+                    })
+                })
+                .ok_or(InvalidComponent("offset minute"))?;
+            (input, offset_hour, offset_minute)
         };
 
         if !input.is_empty() {
@@ -447,7 +346,8 @@ impl sealed::Sealed for Rfc2822 {
         let dt = (|| {
             let date = Date::from_calendar_date(year as _, month, day)?;
             let time = Time::from_hms_nano(hour, minute, second, nanosecond)?;
-            let offset = UtcOffset::from_hms(offset_hour, offset_minute, 0)?;
+            let offset =
+                UtcOffset::from_hms(offset_hour, offset_minute, 0).map_err(rename_offset_component)?;
             Ok(DateTime {
                 date,
                 time,
@@ -456,6 +356,15 @@ impl sealed::Sealed for Rfc2822 {
         })()
         .map_err(TryFromParsed::ComponentRange)?;
 
+        if dt.date.weekday() != weekday {
+            return Err(error::Parse::TryFromParsed(
+                TryFromParsed::ConflictingComponents {
+                    a: "day",
+                    b: "weekday",
+                },
+            ));
+        }
+
         if leap_second_input && !dt.is_valid_leap_second_stand_in() {
             return Err(error::Parse::TryFromParsed(TryFromParsed::ComponentRange(
                 error::ComponentRange {
@@ -463,28 +372,476 @@ impl sealed::Sealed for Rfc2822 {
                     minimum: 0,
                     maximum: 59,
                     value: 60,
-                    conditional_range: true,
+                    conditional_range: error::ConditionalRange::Yes,
                 },
             )));
         }
 
         Ok(dt)
     }
+
+    fn parse_date_time_reusing<O: MaybeOffset>(
+        &self,
+        input: &[u8],
+        _: &mut Parsed,
+    ) -> Result<DateTime<O>, error::Parse> {
+        self.parse_date_time(input)
+    }
 }
 
-impl sealed::Sealed for Rfc3339 {
+/// Parse an RFC 2822 value into `parsed`.
+///
+/// When `lenient` is set, the leading day of the week may be omitted and a trailing comment or
+/// whitespace after the offset is consumed. This is used when reading values that were written by
+/// non-conforming software, such as the `Date` headers found in mail archives.
+pub(crate) fn parse_rfc2822_into<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+    lenient: bool,
+) -> Result<&'a [u8], error::Parse> {
+    use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+    use crate::parsing::combinator::rfc::rfc2822::{cfws, fws};
+    use crate::parsing::combinator::{
+        ascii_char, exactly_n_digits, first_match, n_to_m_digits, opt, sign,
+    };
+
+    let colon = ascii_char::<b':'>;
+    let comma = ascii_char::<b','>;
+    let trailing = |input: &'a [u8]| {
+        if lenient {
+            opt(cfws)(input).into_inner()
+        } else {
+            input
+        }
+    };
+
+    let input = opt(fws)(input).into_inner();
+    let weekday = first_match(
+        [
+            (b"Mon".as_slice(), Weekday::Monday),
+            (b"Tue".as_slice(), Weekday::Tuesday),
+            (b"Wed".as_slice(), Weekday::Wednesday),
+            (b"Thu".as_slice(), Weekday::Thursday),
+            (b"Fri".as_slice(), Weekday::Friday),
+            (b"Sat".as_slice(), Weekday::Saturday),
+            (b"Sun".as_slice(), Weekday::Sunday),
+        ],
+        false,
+    )(input);
+    let input = match weekday {
+        Some(item) => {
+            let input = item
+                .consume_value(|value| parsed.set_weekday(value))
+                .ok_or(InvalidComponent("weekday"))?;
+            let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
+            cfws(input).ok_or(InvalidLiteral)?.into_inner()
+        }
+        None if lenient => input,
+        None => return Err(InvalidComponent("weekday").into()),
+    };
+    let input = n_to_m_digits::<1, 2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+        .ok_or(InvalidComponent("day"))?;
+    let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+    let input = first_match(
+        [
+            (b"Jan".as_slice(), Month::January),
+            (b"Feb".as_slice(), Month::February),
+            (b"Mar".as_slice(), Month::March),
+            (b"Apr".as_slice(), Month::April),
+            (b"May".as_slice(), Month::May),
+            (b"Jun".as_slice(), Month::June),
+            (b"Jul".as_slice(), Month::July),
+            (b"Aug".as_slice(), Month::August),
+            (b"Sep".as_slice(), Month::September),
+            (b"Oct".as_slice(), Month::October),
+            (b"Nov".as_slice(), Month::November),
+            (b"Dec".as_slice(), Month::December),
+        ],
+        false,
+    )(input)
+    .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+    .ok_or(InvalidComponent("month"))?;
+    let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+    let input = match exactly_n_digits::<4, u32>(input) {
+        Some(item) => {
+            let input = item
+                .flat_map(|year| if year >= 1900 { Some(year) } else { None })
+                .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
+                .ok_or(InvalidComponent("year"))?;
+            fws(input).ok_or(InvalidLiteral)?.into_inner()
+        }
+        None => {
+            let input = exactly_n_digits::<2, u32>(input)
+                .and_then(|item| {
+                    item.map(|year| if year < 50 { year + 2000 } else { year + 1900 })
+                        .map(|year| year as _)
+                        .consume_value(|value| parsed.set_year(value))
+                })
+                .ok_or(InvalidComponent("year"))?;
+            cfws(input).ok_or(InvalidLiteral)?.into_inner()
+        }
+    };
+
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+        .ok_or(InvalidComponent("hour"))?;
+    let input = opt(cfws)(input).into_inner();
+    let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+    let input = opt(cfws)(input).into_inner();
+    let input = exactly_n_digits::<2, _>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+        .ok_or(InvalidComponent("minute"))?;
+
+    let input = if let Some(input) = colon(opt(cfws)(input).into_inner()) {
+        let input = input.into_inner(); // discard the colon
+        let input = opt(cfws)(input).into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+            .ok_or(InvalidComponent("second"))?;
+        cfws(input).ok_or(InvalidLiteral)?.into_inner()
+    } else {
+        cfws(input).ok_or(InvalidLiteral)?.into_inner()
+    };
+
+    // The RFC explicitly allows leap seconds.
+    parsed.set_flag(Parsed::LEAP_SECOND_ALLOWED_FLAG, true);
+
+    #[allow(clippy::unnecessary_lazy_evaluations)] // rust-lang/rust-clippy#8522
+    let zone_literal = first_match(
+        [
+            (b"UT".as_slice(), 0),
+            (b"GMT".as_slice(), 0),
+            (b"EST".as_slice(), -5),
+            (b"EDT".as_slice(), -4),
+            (b"CST".as_slice(), -6),
+            (b"CDT".as_slice(), -5),
+            (b"MST".as_slice(), -7),
+            (b"MDT".as_slice(), -6),
+            (b"PST".as_slice(), -8),
+            (b"PDT".as_slice(), -7),
+        ],
+        false,
+    )(input)
+    .or_else(|| match input {
+        [
+            b'a'..=b'i' | b'k'..=b'z' | b'A'..=b'I' | b'K'..=b'Z',
+            rest @ ..,
+        ] => Some(ParsedItem(rest, 0)),
+        _ => None,
+    });
+    if let Some(zone_literal) = zone_literal {
+        let input = zone_literal
+            .consume_value(|value| parsed.set_offset_hour(value))
+            .ok_or(InvalidComponent("offset hour"))?;
+        parsed
+            .set_offset_minute_signed(0)
+            .ok_or(InvalidComponent("offset minute"))?;
+        parsed
+            .set_offset_second_signed(0)
+            .ok_or(InvalidComponent("offset second"))?;
+        return Ok(trailing(input));
+    }
+
+    let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+    let input = exactly_n_digits::<2, u8>(input)
+        .and_then(|item| {
+            item.map(|offset_hour| {
+                if offset_sign == b'-' {
+                    -(offset_hour as i8)
+                } else {
+                    offset_hour as _
+                }
+            })
+            .consume_value(|value| parsed.set_offset_hour(value))
+        })
+        .ok_or(InvalidComponent("offset hour"))?;
+    let input = exactly_n_digits::<2, u8>(input)
+        .and_then(|item| {
+            item.map(|offset_minute| {
+                if offset_sign == b'-' {
+                    -(offset_minute as i8)
+                } else {
+                    offset_minute as _
+                }
+            })
+            .consume_value(|value| parsed.set_offset_minute_signed(value))
+        })
+        .ok_or(InvalidComponent("offset minute"))?;
+
+    Ok(trailing(input))
+}
+
+impl sealed::Sealed for Rfc7231 {
     fn parse_into<'a>(
         &self,
         input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
         use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
-        use crate::parsing::combinator::{
-            any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits, first_match};
+
+        /// The three forms of an HTTP date.
+        enum Form {
+            /// `Sun, 06 Nov 1994 08:49:37 GMT`
+            ImfFixdate,
+            /// `Sunday, 06-Nov-94 08:49:37 GMT`
+            Rfc850,
+            /// `Sun Nov  6 08:49:37 1994`
+            Asctime,
+        }
+
+        let colon = ascii_char::<b':'>;
+        let dash = ascii_char::<b'-'>;
+        let space = ascii_char::<b' '>;
+        let month = |input| {
+            first_match(
+                [
+                    (b"Jan".as_slice(), Month::January),
+                    (b"Feb".as_slice(), Month::February),
+                    (b"Mar".as_slice(), Month::March),
+                    (b"Apr".as_slice(), Month::April),
+                    (b"May".as_slice(), Month::May),
+                    (b"Jun".as_slice(), Month::June),
+                    (b"Jul".as_slice(), Month::July),
+                    (b"Aug".as_slice(), Month::August),
+                    (b"Sep".as_slice(), Month::September),
+                    (b"Oct".as_slice(), Month::October),
+                    (b"Nov".as_slice(), Month::November),
+                    (b"Dec".as_slice(), Month::December),
+                ],
+                true,
+            )(input)
+        };
+
+        // The full names must be tried first, as the abbreviations are prefixes of them.
+        let input = first_match(
+            [
+                (b"Monday".as_slice(), (Weekday::Monday, true)),
+                (b"Tuesday".as_slice(), (Weekday::Tuesday, true)),
+                (b"Wednesday".as_slice(), (Weekday::Wednesday, true)),
+                (b"Thursday".as_slice(), (Weekday::Thursday, true)),
+                (b"Friday".as_slice(), (Weekday::Friday, true)),
+                (b"Saturday".as_slice(), (Weekday::Saturday, true)),
+                (b"Sunday".as_slice(), (Weekday::Sunday, true)),
+                (b"Mon".as_slice(), (Weekday::Monday, false)),
+                (b"Tue".as_slice(), (Weekday::Tuesday, false)),
+                (b"Wed".as_slice(), (Weekday::Wednesday, false)),
+                (b"Thu".as_slice(), (Weekday::Thursday, false)),
+                (b"Fri".as_slice(), (Weekday::Friday, false)),
+                (b"Sat".as_slice(), (Weekday::Saturday, false)),
+                (b"Sun".as_slice(), (Weekday::Sunday, false)),
+            ],
+            true,
+        )(input)
+        .and_then(|item| {
+            item.flat_map(|(weekday, is_full_name)| {
+                parsed.set_weekday(weekday)?;
+                Some(is_full_name)
+            })
+        })
+        .ok_or(InvalidComponent("weekday"))?;
+        let ParsedItem(input, form) = match input {
+            ParsedItem([b',', b' ', input @ ..], true) => ParsedItem(input, Form::Rfc850),
+            ParsedItem([b',', b' ', input @ ..], false) => ParsedItem(input, Form::ImfFixdate),
+            ParsedItem([b' ', input @ ..], false) => ParsedItem(input, Form::Asctime),
+            _ => return Err(InvalidLiteral.into()),
+        };
+
+        let input = match form {
+            Form::ImfFixdate | Form::Rfc850 => {
+                let separator = |input| match form {
+                    Form::Rfc850 => dash(input),
+                    _ => space(input),
+                };
+                let input = exactly_n_digits::<2, _>(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                    .ok_or(InvalidComponent("day"))?;
+                let input = separator(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = month(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+                    .ok_or(InvalidComponent("month"))?;
+                let input = separator(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = match form {
+                    Form::Rfc850 => exactly_n_digits::<2, u32>(input).and_then(|item| {
+                        item.map(|year| if year < 50 { year + 2000 } else { year + 1900 })
+                            .consume_value(|value| parsed.set_year(value as _))
+                    }),
+                    _ => exactly_n_digits::<4, u32>(input)
+                        .and_then(|item| item.consume_value(|value| parsed.set_year(value as _))),
+                }
+                .ok_or(InvalidComponent("year"))?;
+                space(input).ok_or(InvalidLiteral)?.into_inner()
+            }
+            Form::Asctime => {
+                let input = month(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+                    .ok_or(InvalidComponent("month"))?;
+                let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+                let input = match space(input) {
+                    Some(ParsedItem(input, ())) => exactly_n_digits::<1, _>(input),
+                    None => exactly_n_digits::<2, _>(input),
+                }
+                .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                .ok_or(InvalidComponent("day"))?;
+                space(input).ok_or(InvalidLiteral)?.into_inner()
+            }
+        };
+
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+            .ok_or(InvalidComponent("hour"))?;
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+            .ok_or(InvalidComponent("minute"))?;
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+            .ok_or(InvalidComponent("second"))?;
+
+        // The RFC explicitly allows leap seconds.
+        parsed.set_flag(Parsed::LEAP_SECOND_ALLOWED_FLAG, true);
+
+        let input = match form {
+            Form::ImfFixdate | Form::Rfc850 => input.strip_prefix(b" GMT").ok_or(InvalidLiteral)?,
+            Form::Asctime => {
+                let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+                exactly_n_digits::<4, u32>(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
+                    .ok_or(InvalidComponent("year"))?
+            }
+        };
+
+        parsed
+            .set_offset_hour(0)
+            .ok_or(InvalidComponent("offset hour"))?;
+        parsed
+            .set_offset_minute_signed(0)
+            .ok_or(InvalidComponent("offset minute"))?;
+        parsed
+            .set_offset_second_signed(0)
+            .ok_or(InvalidComponent("offset second"))?;
+
+        Ok(input)
+    }
+}
+
+impl sealed::Sealed for JsDate {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits, first_match, sign};
+
+        let colon = ascii_char::<b':'>;
+        let space = ascii_char::<b' '>;
+
+        let input = first_match(
+            [
+                (b"Mon".as_slice(), Weekday::Monday),
+                (b"Tue".as_slice(), Weekday::Tuesday),
+                (b"Wed".as_slice(), Weekday::Wednesday),
+                (b"Thu".as_slice(), Weekday::Thursday),
+                (b"Fri".as_slice(), Weekday::Friday),
+                (b"Sat".as_slice(), Weekday::Saturday),
+                (b"Sun".as_slice(), Weekday::Sunday),
+            ],
+            false,
+        )(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_weekday(value)))
+        .ok_or(InvalidComponent("weekday"))?;
+        let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = first_match(
+            [
+                (b"Jan".as_slice(), Month::January),
+                (b"Feb".as_slice(), Month::February),
+                (b"Mar".as_slice(), Month::March),
+                (b"Apr".as_slice(), Month::April),
+                (b"May".as_slice(), Month::May),
+                (b"Jun".as_slice(), Month::June),
+                (b"Jul".as_slice(), Month::July),
+                (b"Aug".as_slice(), Month::August),
+                (b"Sep".as_slice(), Month::September),
+                (b"Oct".as_slice(), Month::October),
+                (b"Nov".as_slice(), Month::November),
+                (b"Dec".as_slice(), Month::December),
+            ],
+            false,
+        )(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+        .ok_or(InvalidComponent("month"))?;
+        let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+            .ok_or(InvalidComponent("day"))?;
+        let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<4, u32>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
+            .ok_or(InvalidComponent("year"))?;
+        let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+            .ok_or(InvalidComponent("hour"))?;
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+            .ok_or(InvalidComponent("minute"))?;
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+            .ok_or(InvalidComponent("second"))?;
+        let input = input.strip_prefix(b" GMT").ok_or(InvalidLiteral)?;
+
+        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let apply_sign = |value: u8| {
+            if offset_sign == b'-' {
+                -(value as i8)
+            } else {
+                value as _
+            }
         };
+        let input = exactly_n_digits::<2, u8>(input)
+            .and_then(|item| {
+                item.map(apply_sign)
+                    .consume_value(|value| parsed.set_offset_hour(value))
+            })
+            .ok_or(InvalidComponent("offset hour"))?;
+        let input = exactly_n_digits::<2, u8>(input)
+            .and_then(|item| {
+                item.map(apply_sign)
+                    .consume_value(|value| parsed.set_offset_minute_signed(value))
+            })
+            .ok_or(InvalidComponent("offset minute"))?;
+
+        // The time zone name is implementation-defined, so it is skipped without validation.
+        match input.strip_prefix(b" (") {
+            Some(zone_name) => {
+                let end = zone_name
+                    .iter()
+                    .position(|&byte| byte == b')')
+                    .ok_or(InvalidLiteral)?;
+                Ok(&zone_name[end + 1..])
+            }
+            None => Ok(input),
+        }
+    }
+}
+
+impl sealed::Sealed for Systemd {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits, sign};
 
         let dash = ascii_char::<b'-'>;
         let colon = ascii_char::<b':'>;
+        let space = ascii_char::<b' '>;
 
         let input = exactly_n_digits::<4, u32>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
@@ -498,7 +855,125 @@ impl sealed::Sealed for Rfc3339 {
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
             .ok_or(InvalidComponent("day"))?;
-        let input = ascii_char_ignore_case::<b'T'>(input)
+        let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
+            .ok_or(InvalidComponent("hour"))?;
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+            .ok_or(InvalidComponent("minute"))?;
+        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+            .ok_or(InvalidComponent("second"))?;
+        let input = match ascii_char::<b'.'>(input) {
+            Some(ParsedItem(input, ())) => exactly_n_digits::<6, u32>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_subsecond(value * 1_000)))
+                .ok_or(InvalidComponent("subsecond"))?,
+            None => input,
+        };
+        let input = space(input).ok_or(InvalidLiteral)?.into_inner();
+
+        if let Some(input) = input.strip_prefix(b"UTC") {
+            parsed
+                .set_offset_hour(0)
+                .ok_or(InvalidComponent("offset hour"))?;
+            parsed
+                .set_offset_minute_signed(0)
+                .ok_or(InvalidComponent("offset minute"))?;
+            return Ok(input);
+        }
+
+        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let apply_sign = |value: u8| {
+            if offset_sign == b'-' {
+                -(value as i8)
+            } else {
+                value as _
+            }
+        };
+        let input = exactly_n_digits::<2, u8>(input)
+            .and_then(|item| {
+                item.map(apply_sign)
+                    .consume_value(|value| parsed.set_offset_hour(value))
+            })
+            .ok_or(InvalidComponent("offset hour"))?;
+        let input = exactly_n_digits::<2, u8>(input)
+            .and_then(|item| {
+                item.map(apply_sign)
+                    .consume_value(|value| parsed.set_offset_minute_signed(value))
+            })
+            .ok_or(InvalidComponent("offset minute"))?;
+
+        Ok(input)
+    }
+}
+
+impl sealed::Sealed for Unix {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::InvalidComponent;
+        use crate::parsing::combinator::{ascii_char, n_to_m_digits, opt, sign};
+
+        let ParsedItem(input, timestamp_sign) = opt(sign)(input);
+        let ParsedItem(input, seconds) =
+            n_to_m_digits::<1, 14, u128>(input).ok_or(InvalidComponent("unix_timestamp"))?;
+        let ParsedItem(input, nanoseconds) = match ascii_char::<b'.'>(input) {
+            Some(ParsedItem(fraction, ())) => {
+                let ParsedItem(input, value) = n_to_m_digits::<1, 9, u32>(fraction)
+                    .ok_or(InvalidComponent("unix_timestamp"))?;
+                let digits = (fraction.len() - input.len()) as u32;
+                ParsedItem(input, value * 10_u32.pow(9 - digits))
+            }
+            None => ParsedItem(input, 0),
+        };
+
+        let timestamp = seconds as i128 * 1_000_000_000 + nanoseconds as i128;
+        parsed
+            .set_unix_timestamp_nanos(if timestamp_sign == Some(b'-') {
+                -timestamp
+            } else {
+                timestamp
+            })
+            .ok_or(InvalidComponent("unix_timestamp"))?;
+
+        Ok(input)
+    }
+}
+
+impl Rfc3339 {
+    /// Parse the input as RFC 3339 into the provided [`Parsed`]. If `lenient` is true, the
+    /// relaxations permitted by [`Rfc3339Lenient`] are accepted.
+    fn parse_into_with<'a>(
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        lenient: bool,
+    ) -> Result<&'a [u8], error::Parse> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::{
+            any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
+        };
+
+        let dash = ascii_char::<b'-'>;
+        let colon = ascii_char::<b':'>;
+
+        let input = exactly_n_digits::<4, u32>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_year(value as _)))
+            .ok_or(InvalidComponent("year"))?;
+        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+        let ParsedItem(input, month) =
+            exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("month"))?;
+        let month = Month::from_nonzero_number(month).map_err(TryFromParsed::ComponentRange)?;
+        parsed.set_month(month).ok_or(InvalidComponent("month"))?;
+        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = exactly_n_digits::<2, _>(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+            .ok_or(InvalidComponent("day"))?;
+        let input = Self::date_time_separator(input, lenient)
             .ok_or(InvalidLiteral)?
             .into_inner();
         let input = exactly_n_digits::<2, _>(input)
@@ -512,7 +987,7 @@ impl sealed::Sealed for Rfc3339 {
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
             .ok_or(InvalidComponent("second"))?;
-        let input = if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
+        let input = if let Some(ParsedItem(input, ())) = Self::decimal_mark(input, lenient) {
             let ParsedItem(mut input, mut value) = any_digit(input)
                 .ok_or(InvalidComponent("subsecond"))?
                 .map(|v| (v - b'0') as u32 * 100_000_000);
@@ -578,7 +1053,12 @@ impl sealed::Sealed for Rfc3339 {
         Ok(input)
     }
 
-    fn parse_date_time<O: MaybeOffset>(&self, input: &[u8]) -> Result<DateTime<O>, error::Parse> {
+    /// Parse the input as RFC 3339 into a [`DateTime`]. If `lenient` is true, the relaxations
+    /// permitted by [`Rfc3339Lenient`] are accepted.
+    fn parse_date_time_with<O: MaybeOffset>(
+        input: &[u8],
+        lenient: bool,
+    ) -> Result<DateTime<O>, error::Parse> {
         use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
         use crate::parsing::combinator::{
             any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
@@ -595,7 +1075,7 @@ impl sealed::Sealed for Rfc3339 {
         let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
         let ParsedItem(input, day) =
             exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("day"))?;
-        let input = ascii_char_ignore_case::<b'T'>(input)
+        let input = Self::date_time_separator(input, lenient)
             .ok_or(InvalidLiteral)?
             .into_inner();
         let ParsedItem(input, hour) =
@@ -607,7 +1087,7 @@ impl sealed::Sealed for Rfc3339 {
         let ParsedItem(input, mut second) =
             exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("second"))?;
         let ParsedItem(input, mut nanosecond) =
-            if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
+            if let Some(ParsedItem(input, ())) = Self::decimal_mark(input, lenient) {
                 let ParsedItem(mut input, mut value) = any_digit(input)
                     .ok_or(InvalidComponent("subsecond"))?
                     .map(|v| (v - b'0') as u32 * 100_000_000);
@@ -648,15 +1128,7 @@ impl sealed::Sealed for Rfc3339 {
                     0,
                 )
                 .map(|offset| ParsedItem(input, offset))
-                .map_err(|mut err| {
-                    // Provide the user a more accurate error.
-                    if err.name == "hours" {
-                        err.name = "offset hour";
-                    } else if err.name == "minutes" {
-                        err.name = "offset minute";
-                    }
-                    err
-                })
+                .map_err(rename_offset_component)
                 .map_err(TryFromParsed::ComponentRange)?
             }
         };
@@ -676,7 +1148,7 @@ impl sealed::Sealed for Rfc3339 {
             false
         };
 
-        let date = Month::from_number(month)
+        let date = Month::from_nonzero_number(month)
             .and_then(|month| Date::from_calendar_date(year as _, month, day))
             .map_err(TryFromParsed::ComponentRange)?;
         let time = Time::from_hms_nano(hour, minute, second, nanosecond)
@@ -691,70 +1163,196 @@ impl sealed::Sealed for Rfc3339 {
                     minimum: 0,
                     maximum: 59,
                     value: 60,
-                    conditional_range: true,
+                    conditional_range: error::ConditionalRange::Yes,
                 },
             )));
         }
 
         Ok(dt)
     }
+
+    /// Parse the separator between the date and time. This is `T` or `t`, with a space also being
+    /// accepted if `lenient` is true.
+    fn date_time_separator(input: &[u8], lenient: bool) -> Option<ParsedItem<'_, ()>> {
+        use crate::parsing::combinator::{ascii_char, ascii_char_ignore_case};
+
+        ascii_char_ignore_case::<b'T'>(input).or_else(|| {
+            if lenient {
+                ascii_char::<b' '>(input)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Parse the decimal mark preceding the fractional second. This is `.`, with `,` also being
+    /// accepted if `lenient` is true.
+    fn decimal_mark(input: &[u8], lenient: bool) -> Option<ParsedItem<'_, ()>> {
+        use crate::parsing::combinator::ascii_char;
+
+        ascii_char::<b'.'>(input).or_else(|| {
+            if lenient {
+                ascii_char::<b','>(input)
+            } else {
+                None
+            }
+        })
+    }
 }
 
-impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+impl sealed::Sealed for Rfc3339 {
     fn parse_into<'a>(
         &self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
-        use crate::parsing::combinator::rfc::iso8601::ExtendedKind;
+        Self::parse_into_with(input, parsed, false)
+    }
 
-        let mut extended_kind = ExtendedKind::Unknown;
-        let mut date_is_present = false;
-        let mut time_is_present = false;
-        let mut offset_is_present = false;
-        let mut first_error = None;
+    fn parse_date_time<O: MaybeOffset>(&self, input: &[u8]) -> Result<DateTime<O>, error::Parse> {
+        Self::parse_date_time_with(input, false)
+    }
 
-        match Self::parse_date(parsed, &mut extended_kind)(input) {
-            Ok(new_input) => {
-                input = new_input;
-                date_is_present = true;
-            }
-            Err(err) => {
-                first_error.get_or_insert(err);
+    fn parse_date_time_reusing<O: MaybeOffset>(
+        &self,
+        input: &[u8],
+        _: &mut Parsed,
+    ) -> Result<DateTime<O>, error::Parse> {
+        self.parse_date_time(input)
+    }
+}
+
+impl sealed::Sealed for Rfc3339NoZ {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        Rfc3339.parse_into(input, parsed)
+    }
+
+    fn parse_date_time<O: MaybeOffset>(&self, input: &[u8]) -> Result<DateTime<O>, error::Parse> {
+        Rfc3339.parse_date_time(input)
+    }
+
+    fn parse_date_time_reusing<O: MaybeOffset>(
+        &self,
+        input: &[u8],
+        _: &mut Parsed,
+    ) -> Result<DateTime<O>, error::Parse> {
+        self.parse_date_time(input)
+    }
+}
+
+impl sealed::Sealed for Rfc3339Lenient {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        Rfc3339::parse_into_with(input, parsed, true)
+    }
+
+    fn parse_date_time<O: MaybeOffset>(&self, input: &[u8]) -> Result<DateTime<O>, error::Parse> {
+        Rfc3339::parse_date_time_with(input, true)
+    }
+
+    fn parse_date_time_reusing<O: MaybeOffset>(
+        &self,
+        input: &[u8],
+        _: &mut Parsed,
+    ) -> Result<DateTime<O>, error::Parse> {
+        self.parse_date_time(input)
+    }
+}
+
+/// Parse a value as ISO 8601 using the provided configuration.
+fn parse_iso8601_into<'a>(
+    config: &Config,
+    mut input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<&'a [u8], error::Parse> {
+    use crate::parsing::combinator::rfc::iso8601::ExtendedKind;
+
+    let mut extended_kind = ExtendedKind::Unknown;
+    let mut date_is_present = false;
+    let mut time_is_present = false;
+    let mut offset_is_present = false;
+    let mut first_error = None;
+    let mut date_range_error = None;
+
+    let date = Config::parse_date(parsed, &mut extended_kind)(input);
+    match date {
+        Ok(new_input) => {
+            input = new_input;
+            date_is_present = true;
+        }
+        // A date was present, but one of its components was out of range. When the date uses
+        // the extended format, falling back to parsing a time would only obscure the error.
+        Err(err @ error::Parse::TryFromParsed(_)) if extended_kind.is_extended() => {
+            return Err(err);
+        }
+        // In the basic format, digits that look like an out-of-range date may be a time, such
+        // as `123456`. The error is only reported if they are not.
+        Err(err @ error::Parse::TryFromParsed(_)) => date_range_error = Some(err),
+        Err(err) => {
+            first_error.get_or_insert(err);
+        }
+    }
+
+    match config.parse_time(parsed, &mut extended_kind, date_is_present)(input) {
+        Ok(new_input) => {
+            input = new_input;
+            time_is_present = true;
+        }
+        Err(err) => {
+            if let Some(date_range_error) = date_range_error {
+                return Err(date_range_error);
             }
+            first_error.get_or_insert(err);
         }
+    }
 
-        match Self::parse_time(parsed, &mut extended_kind, date_is_present)(input) {
+    // If a date and offset are present, a time must be as well.
+    if !date_is_present || time_is_present {
+        match Config::parse_offset(parsed, &mut extended_kind)(input) {
             Ok(new_input) => {
                 input = new_input;
-                time_is_present = true;
+                offset_is_present = true;
             }
             Err(err) => {
                 first_error.get_or_insert(err);
             }
         }
+    }
 
-        // If a date and offset are present, a time must be as well.
-        if !date_is_present || time_is_present {
-            match Self::parse_offset(parsed, &mut extended_kind)(input) {
-                Ok(new_input) => {
-                    input = new_input;
-                    offset_is_present = true;
-                }
-                Err(err) => {
-                    first_error.get_or_insert(err);
-                }
-            }
+    if !date_is_present && !time_is_present && !offset_is_present {
+        match first_error {
+            Some(err) => return Err(err),
+            None => bug!("an error should be present if no components were parsed"),
         }
+    }
 
-        if !date_is_present && !time_is_present && !offset_is_present {
-            match first_error {
-                Some(err) => return Err(err),
-                None => bug!("an error should be present if no components were parsed"),
-            }
-        }
+    Ok(input)
+}
 
-        Ok(input)
+impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        parse_iso8601_into(&Self::CONFIG, input, parsed)
+    }
+}
+
+impl sealed::Sealed for Iso8601Dynamic {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        parse_iso8601_into(&self.config, input, parsed)
     }
 }
 // endregion well-known formats
@@ -4,18 +4,23 @@ use core::mem::MaybeUninit;
 use core::num::{NonZeroU16, NonZeroU8};
 
 use crate::date_time::{maybe_offset_from_offset, offset_kind, DateTime, MaybeOffset};
-use crate::error::TryFromParsed::InsufficientInformation;
+use crate::error::MissingComponents;
+use crate::error::TryFromParsed::{ConflictingComponents, InsufficientInformation};
 use crate::format_description::modifier::{WeekNumberRepr, YearRepr};
+use crate::format_description::item_view::{AsItemView, ItemView};
 #[cfg(feature = "alloc")]
-use crate::format_description::OwnedFormatItem;
+use crate::format_description::{CompiledFormat, FormatOp, OwnedFormatItem};
 use crate::format_description::{Component, FormatItem};
 use crate::parsing::component::{
-    parse_day, parse_hour, parse_ignore, parse_minute, parse_month, parse_offset_hour,
-    parse_offset_minute, parse_offset_second, parse_ordinal, parse_period, parse_second,
-    parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday, parse_year, Period,
+    parse_day, parse_hour, parse_ignore, parse_minute, parse_month, parse_offset,
+    parse_offset_hour, parse_offset_minute, parse_offset_second, parse_ordinal, parse_period,
+    parse_second, parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday,
+    parse_year,
 };
 use crate::parsing::ParsedItem;
-use crate::{error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use crate::{
+    error, Date, Month, OffsetDateTime, Period, PrimitiveDateTime, Time, UtcOffset, Weekday,
+};
 
 /// Sealed to prevent downstream implementations.
 mod sealed {
@@ -32,36 +37,45 @@ mod sealed {
     }
 }
 
+/// Parse a [`FormatItem`] or [`OwnedFormatItem`], returning the remaining input on success.
+fn parse_item_view<'a, T: AsItemView + sealed::AnyFormatItem>(
+    item: &T,
+    parsed: &mut Parsed,
+    input: &'a [u8],
+) -> Result<&'a [u8], error::ParseFromDescription> {
+    match item.view() {
+        ItemView::Literal(literal) => Parsed::parse_literal(input, literal),
+        ItemView::Component(component) => parsed.parse_component(input, component),
+        ItemView::Compound(compound) => parsed.parse_items(input, compound),
+        ItemView::Optional(item) => parsed.parse_item(input, item).or(Ok(input)),
+        ItemView::First(items) => {
+            let mut first_err = None;
+
+            for item in items.iter() {
+                match parsed.parse_item(input, item) {
+                    Ok(remaining_input) => return Ok(remaining_input),
+                    Err(err) if first_err.is_none() => first_err = Some(err),
+                    Err(_) => {}
+                }
+            }
+
+            match first_err {
+                Some(err) => Err(err),
+                // This location will be reached if the slice is empty, skipping the `for` loop.
+                // As this case is expected to be uncommon, there's no need to check up front.
+                None => Ok(input),
+            }
+        }
+    }
+}
+
 impl sealed::AnyFormatItem for FormatItem<'_> {
     fn parse_item<'a>(
         &self,
         parsed: &mut Parsed,
         input: &'a [u8],
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
-            Self::Component(component) => parsed.parse_component(input, *component),
-            Self::Compound(compound) => parsed.parse_items(input, compound),
-            Self::Optional(item) => parsed.parse_item(input, *item).or(Ok(input)),
-            Self::First(items) => {
-                let mut first_err = None;
-
-                for item in items.iter() {
-                    match parsed.parse_item(input, item) {
-                        Ok(remaining_input) => return Ok(remaining_input),
-                        Err(err) if first_err.is_none() => first_err = Some(err),
-                        Err(_) => {}
-                    }
-                }
-
-                match first_err {
-                    Some(err) => Err(err),
-                    // This location will be reached if the slice is empty, skipping the `for` loop.
-                    // As this case is expected to be uncommon, there's no need to check up front.
-                    None => Ok(input),
-                }
-            }
-        }
+        parse_item_view(self, parsed, input)
     }
 }
 
@@ -72,30 +86,18 @@ impl sealed::AnyFormatItem for OwnedFormatItem {
         parsed: &mut Parsed,
         input: &'a [u8],
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
-            Self::Component(component) => parsed.parse_component(input, *component),
-            Self::Compound(compound) => parsed.parse_items(input, compound),
-            Self::Optional(item) => parsed.parse_item(input, item.as_ref()).or(Ok(input)),
-            Self::First(items) => {
-                let mut first_err = None;
-
-                for item in items.iter() {
-                    match parsed.parse_item(input, item) {
-                        Ok(remaining_input) => return Ok(remaining_input),
-                        Err(err) if first_err.is_none() => first_err = Some(err),
-                        Err(_) => {}
-                    }
-                }
+        parse_item_view(self, parsed, input)
+    }
+}
 
-                match first_err {
-                    Some(err) => Err(err),
-                    // This location will be reached if the slice is empty, skipping the `for` loop.
-                    // As this case is expected to be uncommon, there's no need to check up front.
-                    None => Ok(input),
-                }
-            }
-        }
+#[cfg(feature = "alloc")]
+impl sealed::AnyFormatItem for CompiledFormat {
+    fn parse_item<'a>(
+        &self,
+        parsed: &mut Parsed,
+        input: &'a [u8],
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        parsed.parse_ops(input, &self.ops)
     }
 }
 
@@ -183,6 +185,21 @@ impl Parsed {
     /// default value? If the latter, the value should be considered to have no meaning.
     const OFFSET_IS_NEGATIVE_FLAG_IS_INITIALIZED: Flag = 1 << 15;
     const UNIX_TIMESTAMP_NANOS_FLAG: Flag = 1 << 16;
+    /// Indicates whether components that disagree with one another are permitted, in which case
+    /// only some of them are used when converting to the final value.
+    const CONFLICTS_ALLOWED_FLAG: Flag = 1 << 17;
+    /// Indicates whether a date component was successfully parsed from the input.
+    const DATE_PARSED_FLAG: Flag = 1 << 18;
+    /// Indicates whether a time component was successfully parsed from the input.
+    const TIME_PARSED_FLAG: Flag = 1 << 19;
+    /// Indicates whether an offset component was successfully parsed from the input.
+    const OFFSET_PARSED_FLAG: Flag = 1 << 20;
+}
+
+impl Default for Parsed {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Parsed {
@@ -214,6 +231,98 @@ impl Parsed {
         }
     }
 
+    /// Remove all information, leaving the struct as if it were newly created.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// let mut parsed = Parsed::new();
+    /// parsed.set_minute(30);
+    /// parsed.clear();
+    /// assert_eq!(parsed.minute(), None);
+    /// ```
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Whether components that disagree with one another are permitted. See
+    /// [`Parsed::set_conflicts_allowed`] for details.
+    pub const fn conflicts_allowed(&self) -> bool {
+        self.get_flag(Self::CONFLICTS_ALLOWED_FLAG)
+    }
+
+    /// Set whether components that disagree with one another are permitted.
+    ///
+    /// When converting to the final value, components that describe the same value in different
+    /// ways (such as a weekday alongside a calendar date, or a 12-hour and 24-hour hour) must
+    /// agree. Otherwise, [`TryFromParsed::ConflictingComponents`] is returned. Permitting
+    /// conflicts restores the previous behavior, where only some of the components are used and
+    /// the rest are ignored.
+    ///
+    /// [`TryFromParsed::ConflictingComponents`]: crate::error::TryFromParsed::ConflictingComponents
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// # use time::Date;
+    /// # use time_macros::{date, format_description};
+    /// let mut parsed = Parsed::new();
+    /// parsed.parse_items(
+    ///     b"Monday 2023-05-10",
+    ///     format_description!("[weekday] [year]-[month]-[day]"),
+    /// )?;
+    /// assert!(Date::try_from(parsed).is_err());
+    ///
+    /// parsed.set_conflicts_allowed(true);
+    /// assert_eq!(Date::try_from(parsed)?, date!(2023-05-10));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn set_conflicts_allowed(&mut self, allowed: bool) {
+        self.set_flag(Self::CONFLICTS_ALLOWED_FLAG, allowed);
+    }
+
+    /// Whether any date component has been parsed from the input.
+    ///
+    /// This is only set when parsing a [`Component`], including a Unix timestamp. Values provided
+    /// via the setters or by a well-known format are not tracked. It is therefore possible to
+    /// distinguish a parsed value from one filled in afterwards as a default.
+    pub const fn date_was_parsed(&self) -> bool {
+        self.get_flag(Self::DATE_PARSED_FLAG)
+    }
+
+    /// Whether any time component has been parsed from the input.
+    ///
+    /// This is only set when parsing a [`Component`], including a Unix timestamp. Values provided
+    /// via the setters or by a well-known format are not tracked.
+    pub const fn time_was_parsed(&self) -> bool {
+        self.get_flag(Self::TIME_PARSED_FLAG)
+    }
+
+    /// Whether any offset component has been parsed from the input.
+    ///
+    /// This is only set when parsing a [`Component`]. A Unix timestamp is considered to have an
+    /// explicit offset of UTC. Values provided via the setters or by a well-known format are not
+    /// tracked.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// # use time_macros::format_description;
+    /// let format =
+    ///     format_description!("[hour]:[minute][optional [ [offset_hour]:[offset_minute]]]");
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.parse_items(b"12:30 +02:00", format)?;
+    /// assert!(parsed.offset_was_parsed());
+    ///
+    /// let mut parsed = Parsed::new();
+    /// parsed.parse_items(b"12:30", format)?;
+    /// assert!(!parsed.offset_was_parsed());
+    /// parsed.set_offset_hour(0);
+    /// assert!(!parsed.offset_was_parsed());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn offset_was_parsed(&self) -> bool {
+        self.get_flag(Self::OFFSET_PARSED_FLAG)
+    }
+
     /// Parse a single [`FormatItem`] or [`OwnedFormatItem`], mutating the struct. The remaining
     /// input is returned as the `Ok` value.
     ///
@@ -247,6 +356,70 @@ impl Parsed {
         Ok(input)
     }
 
+    /// Parse the operations of a [`CompiledFormat`], mutating the struct. The remaining input is
+    /// returned as the `Ok` value.
+    ///
+    /// As with [`Parsed::parse_items`], `self` will not be mutated if parsing fails.
+    #[cfg(feature = "alloc")]
+    fn parse_ops<'a>(
+        &mut self,
+        mut input: &'a [u8],
+        ops: &[FormatOp],
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        let mut this = *self;
+        let mut idx = 0;
+        while let Some(op) = ops.get(idx) {
+            idx += 1;
+            match op {
+                FormatOp::Literal(literal) => input = Self::parse_literal(input, literal)?,
+                FormatOp::Component(component) => {
+                    input = this.parse_component(input, *component)?;
+                }
+                FormatOp::Optional { len, .. } => {
+                    if let Ok(remaining_input) = this.parse_ops(input, &ops[idx..][..*len]) {
+                        input = remaining_input;
+                    }
+                    idx += len;
+                }
+                FormatOp::First { len } => {
+                    input = this.parse_alternatives(input, &ops[idx..][..*len])?;
+                    idx += len;
+                }
+                FormatOp::Alternative { .. } => {
+                    bug!("alternatives are consumed by `FormatOp::First`")
+                }
+            }
+        }
+        *self = this;
+        Ok(input)
+    }
+
+    /// Parse the alternatives of a [`FormatOp::First`], using the first that succeeds. If none
+    /// succeed, the error from the first alternative is returned.
+    #[cfg(feature = "alloc")]
+    fn parse_alternatives<'a>(
+        &mut self,
+        input: &'a [u8],
+        mut ops: &[FormatOp],
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        let mut first_err = None;
+
+        while let [FormatOp::Alternative { len }, rest @ ..] = ops {
+            let (alternative, rest) = rest.split_at(*len);
+            match self.parse_ops(input, alternative) {
+                Ok(remaining_input) => return Ok(remaining_input),
+                Err(err) if first_err.is_none() => first_err = Some(err),
+                Err(_) => {}
+            }
+            ops = rest;
+        }
+
+        match first_err {
+            Some(err) => Err(err),
+            None => Ok(input),
+        }
+    }
+
     /// Parse a literal byte sequence. The remaining input is returned as the `Ok` value.
     pub fn parse_literal<'a>(
         input: &'a [u8],
@@ -266,7 +439,7 @@ impl Parsed {
     ) -> Result<&'a [u8], error::ParseFromDescription> {
         use error::ParseFromDescription::InvalidComponent;
 
-        match component {
+        let remaining = match component {
             Component::Day(modifiers) => parse_day(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_day(value)))
                 .ok_or(InvalidComponent("day")),
@@ -348,6 +521,16 @@ impl Parsed {
                     parsed.consume_value(|value| self.set_offset_second_signed(value))
                 })
                 .ok_or(InvalidComponent("offset second")),
+            Component::Offset(modifiers) => parse_offset(input, modifiers)
+                .and_then(|parsed| {
+                    parsed.consume_value(|(hour, minute, is_negative)| {
+                        self.set_flag(Self::OFFSET_IS_NEGATIVE_FLAG_IS_INITIALIZED, true);
+                        self.set_flag(Self::OFFSET_IS_NEGATIVE_FLAG, is_negative);
+                        self.set_offset_hour(hour)?;
+                        self.set_offset_minute_signed(minute)
+                    })
+                })
+                .ok_or(InvalidComponent("offset")),
             Component::Ignore(modifiers) => parse_ignore(input, modifiers)
                 .map(ParsedItem::<()>::into_inner)
                 .ok_or(InvalidComponent("ignore")),
@@ -356,7 +539,31 @@ impl Parsed {
                     parsed.consume_value(|value| self.set_unix_timestamp_nanos(value))
                 })
                 .ok_or(InvalidComponent("unix_timestamp")),
-        }
+        }?;
+
+        let flag = match component {
+            Component::Day(_)
+            | Component::Month(_)
+            | Component::Ordinal(_)
+            | Component::Weekday(_)
+            | Component::WeekNumber(_)
+            | Component::Year(_) => Self::DATE_PARSED_FLAG,
+            Component::Hour(_)
+            | Component::Minute(_)
+            | Component::Period(_)
+            | Component::Second(_)
+            | Component::Subsecond(_) => Self::TIME_PARSED_FLAG,
+            Component::OffsetHour(_)
+            | Component::OffsetMinute(_)
+            | Component::OffsetSecond(_)
+            | Component::Offset(_) => Self::OFFSET_PARSED_FLAG,
+            Component::UnixTimestamp(_) => {
+                Self::DATE_PARSED_FLAG | Self::TIME_PARSED_FLAG | Self::OFFSET_PARSED_FLAG
+            }
+            Component::Ignore(_) => return Ok(remaining),
+        };
+        self.set_flag(flag, true);
+        Ok(remaining)
     }
 
     /// Get the value of the provided flag.
@@ -661,6 +868,279 @@ impl Parsed {
     }
 }
 
+/// Default methods
+///
+/// These methods fill in components that were not present in the input, leaving any existing
+/// components untouched. This allows a partial parse, such as one of a time alone, to be converted
+/// into a more complete type.
+impl Parsed {
+    /// Use the components of `defaults` for any that are not already present.
+    ///
+    /// Each component is considered individually. Combining components that describe the same
+    /// value in different ways (such as a calendar date and an ISO week date) may result in the
+    /// components conflicting when converting to the final value, unless conflicts are
+    /// [allowed](Parsed::set_conflicts_allowed), in which case some of them are ignored.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// # use time::Month;
+    /// let parsed = Parsed::new().with_month(Month::May).expect("valid value");
+    /// let defaults = Parsed::new()
+    ///     .with_year(2023)
+    ///     .and_then(|parsed| parsed.with_month(Month::January))
+    ///     .expect("valid values");
+    /// let parsed = parsed.or_defaults_from(&defaults);
+    /// assert_eq!(parsed.year(), Some(2023));
+    /// assert_eq!(parsed.month(), Some(Month::May));
+    /// ```
+    pub fn or_defaults_from(mut self, defaults: &Self) -> Self {
+        /// Copy each of the listed components from `defaults` if not already present.
+        macro_rules! fill {
+            ($($(@$flag:ident)? $name:ident),+ $(,)?) => {$(
+                fill!(! $(@$flag)? $name);
+            )*};
+            (! $name:ident) => {
+                if self.$name.is_none() {
+                    self.$name = defaults.$name;
+                }
+            };
+            (! @$flag:ident $name:ident) => {
+                if !self.get_flag(Self::$flag) && defaults.get_flag(Self::$flag) {
+                    self.$name = defaults.$name;
+                    self.set_flag(Self::$flag, true);
+                }
+            };
+        }
+
+        // The sign of the offset is determined when parsing the offset hour, so it must come from
+        // the same place as the offset hour.
+        if !self.get_flag(Self::OFFSET_HOUR_FLAG) && defaults.get_flag(Self::OFFSET_HOUR_FLAG) {
+            for flag in [
+                Self::OFFSET_IS_NEGATIVE_FLAG,
+                Self::OFFSET_IS_NEGATIVE_FLAG_IS_INITIALIZED,
+            ] {
+                self.set_flag(flag, defaults.get_flag(flag));
+            }
+        }
+
+        fill! {
+            @YEAR_FLAG year,
+            @YEAR_LAST_TWO_FLAG year_last_two,
+            @ISO_YEAR_FLAG iso_year,
+            @ISO_YEAR_LAST_TWO_FLAG iso_year_last_two,
+            month,
+            @SUNDAY_WEEK_NUMBER_FLAG sunday_week_number,
+            @MONDAY_WEEK_NUMBER_FLAG monday_week_number,
+            iso_week_number,
+            weekday,
+            ordinal,
+            day,
+            @HOUR_24_FLAG hour_24,
+            hour_12,
+            hour_12_is_pm,
+            @MINUTE_FLAG minute,
+            @SECOND_FLAG second,
+            @SUBSECOND_FLAG subsecond,
+            @OFFSET_HOUR_FLAG offset_hour,
+            @OFFSET_MINUTE_FLAG offset_minute,
+            @OFFSET_SECOND_FLAG offset_second,
+            @UNIX_TIMESTAMP_NANOS_FLAG unix_timestamp_nanos,
+        }
+
+        self
+    }
+
+    /// Use the year, month, and day of `date` for any of those components that are not already
+    /// present. If the components already present are sufficient to construct a [`Date`], `self`
+    /// is returned unchanged.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// # use time::{Date, Month};
+    /// # use time_macros::date;
+    /// let parsed = Parsed::new()
+    ///     .with_month(Month::May)
+    ///     .and_then(|parsed| parsed.with_day(10.try_into().ok()?))
+    ///     .expect("valid values")
+    ///     .with_date(date!(2023-01-01));
+    /// assert_eq!(Date::try_from(parsed), Ok(date!(2023-05-10)));
+    /// ```
+    pub fn with_date(self, date: Date) -> Self {
+        if Date::try_from(self).is_ok() {
+            return self;
+        }
+
+        let (year, month, day) = date.to_calendar_date();
+        let mut defaults = Self {
+            flags: Self::YEAR_FLAG,
+            year: MaybeUninit::new(year),
+            ..Self::new()
+        };
+        // Components such as the ordinal only need the year. Adding the month and day in that
+        // case would conflict with them.
+        let with_year = self.or_defaults_from(&defaults);
+        if Date::try_from(with_year).is_ok() {
+            return with_year;
+        }
+
+        defaults.month = Some(month);
+        defaults.day = NonZeroU8::new(day);
+        self.or_defaults_from(&defaults)
+    }
+
+    /// Use the hour, minute, second, and subsecond of `time` for any of those components that are
+    /// not already present. If the components already present are sufficient to construct a
+    /// [`Time`], `self` is returned unchanged.
+    ///
+    /// The hour is only used if neither a 24-hour nor a 12-hour value is present.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// let parsed = Parsed::new()
+    ///     .with_hour_24(13)
+    ///     .expect("valid value")
+    ///     .with_time(time!(0:30));
+    /// assert_eq!(Time::try_from(parsed), Ok(time!(13:30)));
+    /// ```
+    pub fn with_time(self, time: Time) -> Self {
+        if Time::try_from(self).is_ok() {
+            return self;
+        }
+
+        let (hour, minute, second, nanosecond) = time.as_hms_nano();
+        let mut defaults = Self {
+            flags: Self::HOUR_24_FLAG
+                | Self::MINUTE_FLAG
+                | Self::SECOND_FLAG
+                | Self::SUBSECOND_FLAG,
+            hour_24: MaybeUninit::new(hour),
+            minute: MaybeUninit::new(minute),
+            second: MaybeUninit::new(second),
+            subsecond: MaybeUninit::new(nanosecond),
+            ..Self::new()
+        };
+        // The 24-hour value takes precedence, so adding it would discard the 12-hour value.
+        if self.hour_12().is_some() {
+            defaults.set_flag(Self::HOUR_24_FLAG, false);
+        }
+        self.or_defaults_from(&defaults)
+    }
+
+    /// Use the components of `offset` for any of the offset components that are not already
+    /// present. If the components already present are sufficient to construct a [`UtcOffset`],
+    /// `self` is returned unchanged.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::{date, datetime, format_description, offset};
+    /// let mut parsed = Parsed::new();
+    /// parsed.parse_items(b"13:30", format_description!("[hour]:[minute]"))?;
+    /// let parsed = parsed
+    ///     .with_date(date!(2023-05-10))
+    ///     .with_offset(offset!(UTC));
+    /// assert_eq!(
+    ///     OffsetDateTime::try_from(parsed)?,
+    ///     datetime!(2023-05-10 13:30 UTC)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn with_offset(self, offset: UtcOffset) -> Self {
+        if UtcOffset::try_from(self).is_ok() {
+            return self;
+        }
+
+        let (hours, minutes, seconds) = offset.as_hms();
+        self.or_defaults_from(&Self {
+            flags: Self::OFFSET_HOUR_FLAG | Self::OFFSET_MINUTE_FLAG | Self::OFFSET_SECOND_FLAG,
+            offset_hour: MaybeUninit::new(hours),
+            offset_minute: MaybeUninit::new(minutes),
+            offset_second: MaybeUninit::new(seconds),
+            ..Self::new()
+        })
+    }
+}
+
+/// Missing component methods
+///
+/// When converting to a type fails with [`TryFromParsed::InsufficientInformation`], these methods
+/// report which components were required but not present. Each is empty if the corresponding part
+/// of the value can be constructed.
+///
+/// [`TryFromParsed::InsufficientInformation`]: error::TryFromParsed::InsufficientInformation
+impl Parsed {
+    /// Obtain the components that are missing to construct a [`Date`].
+    ///
+    /// A date can be constructed from several combinations of components. If none are complete,
+    /// those used alongside an ordinal or week number that is present are reported, falling back
+    /// to the calendar date.
+    ///
+    /// ```rust
+    /// # use time::{format_description, OffsetDateTime, parsing::Parsed};
+    /// let format = format_description::parse("[month]-[day] [hour]:[minute] [offset_hour]")?;
+    /// let mut parsed = Parsed::new();
+    /// assert!(OffsetDateTime::parse_reusing("01-02 03:04 +05", &format, &mut parsed).is_err());
+    /// assert!(parsed.missing_date_components().iter().eq(["year"]));
+    /// assert!(parsed.missing_time_components().is_empty());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn missing_date_components(&self) -> MissingComponents {
+        let has_year = self.year().is_some();
+        let has_weekday = self.weekday().is_some();
+        let has_week_number =
+            self.sunday_week_number().is_some() || self.monday_week_number().is_some();
+        if (has_year && self.ordinal().is_some())
+            || (has_year && self.month().is_some() && self.day().is_some())
+            || (self.iso_year().is_some() && self.iso_week_number().is_some() && has_weekday)
+            || (has_year && has_week_number && has_weekday)
+        {
+            MissingComponents::NONE
+        } else if self.ordinal().is_some() {
+            MissingComponents::YEAR
+        } else if self.iso_week_number().is_some() {
+            MissingComponents::NONE
+                .union_if(MissingComponents::ISO_YEAR, self.iso_year().is_none())
+                .union_if(MissingComponents::WEEKDAY, !has_weekday)
+        } else if has_week_number {
+            MissingComponents::NONE
+                .union_if(MissingComponents::YEAR, !has_year)
+                .union_if(MissingComponents::WEEKDAY, !has_weekday)
+        } else {
+            MissingComponents::NONE
+                .union_if(MissingComponents::YEAR, !has_year)
+                .union_if(MissingComponents::MONTH, self.month().is_none())
+                .union_if(MissingComponents::DAY, self.day().is_none())
+        }
+    }
+
+    /// Obtain the components that are missing to construct a [`Time`].
+    pub const fn missing_time_components(&self) -> MissingComponents {
+        let hour = match (self.hour_24(), self.hour_12(), self.hour_12_is_pm()) {
+            (Some(_), _, _) | (None, Some(_), Some(_)) => MissingComponents::NONE,
+            (None, Some(_), None) => MissingComponents::HOUR_12_IS_PM,
+            (None, None, Some(_)) => MissingComponents::HOUR_12,
+            (None, None, None) => MissingComponents::HOUR_24,
+        };
+        // An hour on the 12-hour clock may be provided without any smaller components.
+        let hour_alone = self.hour_24().is_none()
+            && self.hour_12().is_some()
+            && self.second().is_none()
+            && self.subsecond().is_none();
+        hour.union_if(
+            MissingComponents::MINUTE,
+            self.minute().is_none() && !hour_alone,
+        )
+    }
+
+    /// Obtain the components that are missing to construct a [`UtcOffset`].
+    pub const fn missing_offset_components(&self) -> MissingComponents {
+        MissingComponents::NONE
+            .union_if(MissingComponents::OFFSET_HOUR, self.offset_hour().is_none())
+    }
+}
+
 impl TryFrom<Parsed> for Date {
     type Error = error::TryFromParsed;
 
@@ -680,44 +1160,84 @@ impl TryFrom<Parsed> for Date {
         }
 
         /// Get the value needed to adjust the ordinal day for Sunday and Monday-based week
-        /// numbering.
-        const fn adjustment(year: i32) -> i16 {
-            match Date::__from_ordinal_date_unchecked(year, 1).weekday() {
-                Weekday::Monday => 7,
-                Weekday::Tuesday => 1,
-                Weekday::Wednesday => 2,
-                Weekday::Thursday => 3,
-                Weekday::Friday => 4,
-                Weekday::Saturday => 5,
-                Weekday::Sunday => 6,
+        /// numbering, given the number of days January 1 falls after the start of the week.
+        const fn adjustment(jan_1_days_into_week: u8) -> i16 {
+            match jan_1_days_into_week {
+                0 => 7,
+                days => days as _,
             }
         }
 
+        /// Get the weekday of January 1 of the given year.
+        const fn jan_1_weekday(year: i32) -> Weekday {
+            Date::__from_ordinal_date_unchecked(year, 1).weekday()
+        }
+
         // TODO Only the basics have been covered. There are many other valid values that are not
         // currently constructed from the information known.
 
-        match_! {
-            (year, ordinal) => Ok(Self::from_ordinal_date(year, ordinal.get())?),
-            (year, month, day) => Ok(Self::from_calendar_date(year, month, day.get())?),
-            (iso_year, iso_week_number, weekday) => Ok(Self::from_iso_week_date(
-                iso_year,
-                iso_week_number.get(),
-                weekday,
-            )?),
-            (year, sunday_week_number, weekday) => Ok(Self::from_ordinal_date(
-                year,
-                (sunday_week_number as i16 * 7 + weekday.number_days_from_sunday() as i16
-                    - adjustment(year)
-                    + 1) as u16,
-            )?),
-            (year, monday_week_number, weekday) => Ok(Self::from_ordinal_date(
-                year,
-                (monday_week_number as i16 * 7 + weekday.number_days_from_monday() as i16
-                    - adjustment(year)
-                    + 1) as u16,
-            )?),
-            _ => Err(InsufficientInformation),
+        // The name of the component used alongside the year is retained, as it is reported if any
+        // other component conflicts with the resulting date.
+        let (date, basis) = match_! {
+            (year, ordinal) => (Self::from_ordinal_date(year, ordinal.get())?, "ordinal"),
+            (year, month, day) => (Self::from_calendar_date(year, month, day.get())?, "day"),
+            (iso_year, iso_week_number, weekday) => (
+                Self::from_iso_week_date(iso_year, iso_week_number.get(), weekday)?,
+                "iso_week_number",
+            ),
+            (year, sunday_week_number, weekday) => (
+                Self::from_ordinal_date(
+                    year,
+                    (sunday_week_number as i16 * 7 + weekday.number_days_from_sunday() as i16
+                        - adjustment(jan_1_weekday(year).number_days_from_sunday())
+                        + 1) as u16,
+                )?,
+                "sunday_week_number",
+            ),
+            (year, monday_week_number, weekday) => (
+                Self::from_ordinal_date(
+                    year,
+                    (monday_week_number as i16 * 7 + weekday.number_days_from_monday() as i16
+                        - adjustment(jan_1_weekday(year).number_days_from_monday())
+                        + 1) as u16,
+                )?,
+                "monday_week_number",
+            ),
+            _ => return Err(InsufficientInformation),
+        };
+
+        if parsed.conflicts_allowed() {
+            return Ok(date);
         }
+
+        /// Ensure that each of the components, if present, agrees with the date.
+        macro_rules! check {
+            ($($name:ident $(.$get:ident())? == $value:expr),+ $(,)?) => {$(
+                if matches!(parsed.$name(), Some(value) if value$(.$get())? != $value) {
+                    return Err(ConflictingComponents {
+                        a: basis,
+                        b: stringify!($name),
+                    });
+                }
+            )+};
+        }
+
+        let (iso_year, iso_week_number, _) = date.to_iso_week_date();
+        check! {
+            year == date.year(),
+            year_last_two == date.year().rem_euclid(100) as u8,
+            iso_year == iso_year,
+            iso_year_last_two == iso_year.rem_euclid(100) as u8,
+            month == date.month(),
+            day.get() == date.day(),
+            ordinal.get() == date.ordinal(),
+            weekday == date.weekday(),
+            iso_week_number.get() == iso_week_number,
+            sunday_week_number == date.sunday_based_week(),
+            monday_week_number == date.monday_based_week(),
+        }
+
+        Ok(date)
     }
 }
 
@@ -725,6 +1245,8 @@ impl TryFrom<Parsed> for Time {
     type Error = error::TryFromParsed;
 
     fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+        // The 12-hour value and the period are stored independently, so the order in which they
+        // appeared in the input has no bearing on the result.
         let hour = match (parsed.hour_24(), parsed.hour_12(), parsed.hour_12_is_pm()) {
             (Some(hour), _, _) => hour,
             (_, Some(hour), Some(false)) if hour.get() == 12 => 0,
@@ -733,6 +1255,21 @@ impl TryFrom<Parsed> for Time {
             (_, Some(hour), Some(true)) => hour.get() + 12,
             _ => return Err(InsufficientInformation),
         };
+        if let (Some(hour_24), false) = (parsed.hour_24(), parsed.conflicts_allowed()) {
+            if matches!(parsed.hour_12(), Some(hour_12) if hour_12.get() != (hour_24 + 11) % 12 + 1)
+            {
+                return Err(ConflictingComponents {
+                    a: "hour_24",
+                    b: "hour_12",
+                });
+            }
+            if matches!(parsed.hour_12_is_pm(), Some(is_pm) if is_pm != (hour_24 >= 12)) {
+                return Err(ConflictingComponents {
+                    a: "hour_24",
+                    b: "hour_12_is_pm",
+                });
+            }
+        }
         if parsed.hour_24().is_none()
             && parsed.hour_12().is_some()
             && parsed.hour_12_is_pm().is_some()
@@ -757,20 +1294,23 @@ impl TryFrom<Parsed> for UtcOffset {
         let minute = parsed.offset_minute_signed().unwrap_or(0);
         let second = parsed.offset_second_signed().unwrap_or(0);
 
-        Self::from_hms(hour, minute, second).map_err(|mut err| {
-            // Provide the user a more accurate error.
-            if err.name == "hours" {
-                err.name = "offset hour";
-            } else if err.name == "minutes" {
-                err.name = "offset minute";
-            } else if err.name == "seconds" {
-                err.name = "offset second";
-            }
-            err.into()
-        })
+        Self::from_hms(hour, minute, second)
+            .map_err(|err| rename_offset_component(err).into())
     }
 }
 
+/// Rename the component of an error returned by [`UtcOffset::from_hms`], providing the user a more
+/// accurate error when parsing.
+pub(crate) fn rename_offset_component(mut err: error::ComponentRange) -> error::ComponentRange {
+    err.name = match err.name {
+        "hours" => "offset hour",
+        "minutes" => "offset minute",
+        "seconds" => "offset second",
+        _ => err.name,
+    };
+    err
+}
+
 impl TryFrom<Parsed> for PrimitiveDateTime {
     type Error = <DateTime<offset_kind::None> as TryFrom<Parsed>>::Error;
 
@@ -831,7 +1371,7 @@ impl<O: MaybeOffset> TryFrom<Parsed> for DateTime<O> {
                     minimum: 0,
                     maximum: 59,
                     value: 60,
-                    conditional_range: true,
+                    conditional_range: error::ConditionalRange::Yes,
                 },
             ));
         }
@@ -1,14 +1,20 @@
 //! The [`PrimitiveDateTime`] struct and its associated `impl`s.
 
+use core::cmp::Ordering;
 use core::fmt;
 use core::ops::{Add, AddAssign, Sub, SubAssign};
+#[cfg(feature = "parsing")]
+use core::str::FromStr;
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(all(feature = "formatting-core", feature = "std"))]
 use std::io;
 
+use crate::date::{MAX_YEAR, MIN_YEAR};
 use crate::date_time::offset_kind;
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{ArrayBuf, Formattable, Formatted};
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::{error, Date, DateTime, Duration, Month, OffsetDateTime, Time, UtcOffset, Weekday};
@@ -17,6 +23,20 @@ use crate::{error, Date, DateTime, Duration, Month, OffsetDateTime, Time, UtcOff
 type Inner = DateTime<offset_kind::None>;
 
 /// Combined date and time.
+///
+/// A `PrimitiveDateTime` can be compared directly with a [`Date`], which is treated as midnight at
+/// the start of that day.
+///
+/// ```rust
+/// # use time_macros::{date, datetime};
+/// assert!(datetime!(2023-01-02 0:00) == date!(2023-01-02));
+/// assert!(datetime!(2023-01-02 0:00:01) > date!(2023-01-02));
+/// assert!(date!(2023-01-01) < datetime!(2023-01-01 12:00));
+/// ```
+///
+/// There is intentionally no comparison with [`OffsetDateTime`], as a `PrimitiveDateTime` does not
+/// identify a single point in time. Attach an offset first using
+/// [`assume_utc`](Self::assume_utc) or [`assume_offset`](Self::assume_offset).
 #[derive(Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct PrimitiveDateTime(#[allow(clippy::missing_docs_in_private_items)] pub(crate) Inner);
 
@@ -79,7 +99,8 @@ impl PrimitiveDateTime {
     /// ```
     pub const MAX: Self = Self(Inner::MAX);
 
-    /// Create a new `PrimitiveDateTime` from the provided [`Date`] and [`Time`].
+    /// Create a new `PrimitiveDateTime` from the provided [`Date`] and [`Time`]. This is also
+    /// available as [`Date::with_time`].
     ///
     /// ```rust
     /// # use time::PrimitiveDateTime;
@@ -93,6 +114,45 @@ impl PrimitiveDateTime {
         Self(Inner::new(date, time))
     }
 
+    /// Create a `PrimitiveDateTime` from the provided [`Date`] and [`Time`]. This is equivalent to
+    /// [`PrimitiveDateTime::new`], and is the inverse of [`PrimitiveDateTime::into_parts`].
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{date, datetime, time};
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_parts(date!(2019-01-01), time!(12:34)),
+    ///     datetime!(2019-01-01 12:34),
+    /// );
+    /// ```
+    pub const fn from_parts(date: Date, time: Time) -> Self {
+        Self::new(date, time)
+    }
+
+    /// Create a `PrimitiveDateTime` from the provided [Julian date], which may have a fractional
+    /// part. The Julian date is interpreted as though the value were in UTC.
+    ///
+    /// The result is rounded to the nearest microsecond. Note that for dates in the modern era, an
+    /// `f64` Julian date is only precise to within a few tens of microseconds.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_julian_date(2_451_545.0),
+    ///     Ok(datetime!(2000-01-01 12:00)),
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_julian_date(2_451_544.75),
+    ///     Ok(datetime!(2000-01-01 6:00)),
+    /// );
+    /// ```
+    ///
+    /// [Julian date]: https://en.wikipedia.org/wiki/Julian_day
+    pub fn from_julian_date(julian_date: f64) -> Result<Self, error::ComponentRange> {
+        Ok(Self(Inner::from_julian_date(julian_date)?))
+    }
+
     // region: component getters
     /// Get the [`Date`] component of the `PrimitiveDateTime`.
     ///
@@ -112,6 +172,37 @@ impl PrimitiveDateTime {
     pub const fn time(self) -> Time {
         self.0.time()
     }
+
+    /// Get the [`Date`] and [`Time`] components of the `PrimitiveDateTime`. This is the inverse of
+    /// [`PrimitiveDateTime::from_parts`].
+    ///
+    /// ```rust
+    /// # use time_macros::{date, datetime, time};
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 12:34).into_parts(),
+    ///     (date!(2019-01-01), time!(12:34)),
+    /// );
+    /// ```
+    pub const fn into_parts(self) -> (Date, Time) {
+        (self.date(), self.time())
+    }
+
+    /// Get the year, month, and day, and the clock hour, minute, second, and nanosecond.
+    ///
+    /// The month and day are derived from the ordinal date once, rather than once per getter.
+    ///
+    /// ```rust
+    /// # use time::Month;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-02 3:04:05.006).to_calendar_date_time(),
+    ///     ((2019, Month::January, 2), (3, 4, 5, 6_000_000)),
+    /// );
+    /// ```
+    #[allow(clippy::type_complexity)] // consistent with `to_calendar_date` and `as_hms_nano`
+    pub const fn to_calendar_date_time(self) -> ((i32, Month, u8), (u8, u8, u8, u32)) {
+        (self.to_calendar_date(), self.as_hms_nano())
+    }
     // endregion component getters
 
     // region: date getters
@@ -165,6 +256,61 @@ impl PrimitiveDateTime {
         self.0.ordinal()
     }
 
+    /// Check if the date is the first day of its month.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert!(datetime!(2019-02-01 0:00).is_first_day_of_month());
+    /// assert!(!datetime!(2019-01-31 23:59).is_first_day_of_month());
+    /// ```
+    pub const fn is_first_day_of_month(self) -> bool {
+        self.0.is_first_day_of_month()
+    }
+
+    /// Check if the date is the last day of its month. This takes leap years into account.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert!(datetime!(2019-02-28 0:00).is_last_day_of_month());
+    /// assert!(!datetime!(2020-02-28 0:00).is_last_day_of_month());
+    /// ```
+    pub const fn is_last_day_of_month(self) -> bool {
+        self.0.is_last_day_of_month()
+    }
+
+    /// Check if the date is the first day of its year.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert!(datetime!(2020-01-01 0:00).is_first_day_of_year());
+    /// assert!(!datetime!(2019-12-31 23:59).is_first_day_of_year());
+    /// ```
+    pub const fn is_first_day_of_year(self) -> bool {
+        self.0.is_first_day_of_year()
+    }
+
+    /// Check if the date is the last day of its year.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert!(datetime!(2020-12-31 0:00).is_last_day_of_year());
+    /// assert!(!datetime!(2020-12-30 23:59).is_last_day_of_year());
+    /// ```
+    pub const fn is_last_day_of_year(self) -> bool {
+        self.0.is_last_day_of_year()
+    }
+
+    /// Check if the date is 29 February.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert!(datetime!(2020-02-29 0:00).is_leap_day());
+    /// assert!(!datetime!(2019-03-01 0:00).is_leap_day());
+    /// ```
+    pub const fn is_leap_day(self) -> bool {
+        self.0.is_leap_day()
+    }
+
     /// Get the ISO week number.
     ///
     /// The returned value will always be in the range `1..=53`.
@@ -181,6 +327,18 @@ impl PrimitiveDateTime {
         self.0.iso_week()
     }
 
+    /// Get the ISO 8601 year and week number.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2019-10-04 0:00).iso_year_week(), (2019, 40));
+    /// assert_eq!(datetime!(2019-12-30 0:00).iso_year_week(), (2020, 1));
+    /// assert_eq!(datetime!(2021-01-01 0:00).iso_year_week(), (2020, 53));
+    /// ```
+    pub const fn iso_year_week(self) -> (i32, u8) {
+        self.0.iso_year_week()
+    }
+
     /// Get the week number where week 1 begins on the first Sunday.
     ///
     /// The returned value will always be in the range `0..=53`.
@@ -289,6 +447,9 @@ impl PrimitiveDateTime {
 
     /// Get the Julian day for the date. The time is not taken into account for this calculation.
     ///
+    /// As with [`Date::to_julian_day`], this is the Julian day number of the date. Use
+    /// [`to_julian_date`](Self::to_julian_date) to include the time of day.
+    ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
     /// freely available [here](https://www.researchgate.net/publication/316558298_Date_Algorithms).
     ///
@@ -302,6 +463,22 @@ impl PrimitiveDateTime {
     pub const fn to_julian_day(self) -> i32 {
         self.0.to_julian_day()
     }
+
+    /// Get the [Julian date], including the fraction of the day that has elapsed. The value is
+    /// computed as though the `PrimitiveDateTime` were in UTC.
+    ///
+    /// Julian days begin at noon, so midnight falls halfway through a Julian day.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(datetime!(2000-01-01 12:00).to_julian_date(), 2_451_545.0);
+    /// assert_eq!(datetime!(2000-01-01 18:00).to_julian_date(), 2_451_545.25);
+    /// ```
+    ///
+    /// [Julian date]: https://en.wikipedia.org/wiki/Julian_day
+    pub fn to_julian_date(self) -> f64 {
+        self.0.to_julian_date()
+    }
     // endregion date getters
 
     // region: time getters
@@ -443,10 +620,53 @@ impl PrimitiveDateTime {
     }
     // endregion time getters
 
+    // region: packed encoding
+    /// Encode the date and time as a pair of integers, for use in fixed-width storage.
+    ///
+    /// The values are those of [`Date::to_packed_u32`] and [`Time::to_packed_u64`], whose layouts
+    /// are stable and will not change.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 1:02:03.004).to_packed(),
+    ///     (0x000F_C601, 3_723_004_000_000),
+    /// );
+    /// ```
+    pub const fn to_packed(self) -> (u32, u64) {
+        (self.date().to_packed_u32(), self.time().to_packed_u64())
+    }
+
+    /// Attempt to decode a date and time that were encoded with [`PrimitiveDateTime::to_packed`].
+    ///
+    /// An error is returned if either value is invalid. See [`Date::from_packed_u32`] and
+    /// [`Time::from_packed_u64`] for details.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_packed((0x000F_C601, 3_723_004_000_000)),
+    ///     Ok(datetime!(2019-01-01 1:02:03.004)),
+    /// );
+    /// assert!(PrimitiveDateTime::from_packed((0x000F_C601, u64::MAX)).is_err());
+    /// ```
+    pub const fn from_packed(packed: (u32, u64)) -> Result<Self, error::ComponentRange> {
+        Ok(Self::new(
+            const_try!(Date::from_packed_u32(packed.0)),
+            const_try!(Time::from_packed_u64(packed.1)),
+        ))
+    }
+    // endregion packed encoding
+
     // region: attach offset
     /// Assuming that the existing `PrimitiveDateTime` represents a moment in the provided
     /// [`UtcOffset`], return an [`OffsetDateTime`].
     ///
+    /// This never panics. At the extremes of the supported range, the equivalent moment in UTC may
+    /// not be representable; converting such a value to another offset panics. Use
+    /// [`assume_offset_checked`](Self::assume_offset_checked) to reject these values up front.
+    ///
     /// ```rust
     /// # use time_macros::{datetime, offset};
     /// assert_eq!(
@@ -466,6 +686,33 @@ impl PrimitiveDateTime {
         OffsetDateTime(self.0.assume_offset(offset))
     }
 
+    /// Assuming that the existing `PrimitiveDateTime` represents a moment in the provided
+    /// [`UtcOffset`], return an [`OffsetDateTime`]. Returns `None` if the equivalent moment in UTC
+    /// is outside the supported range.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, offset};
+    /// assert_eq!(
+    ///     datetime!(2019-01-01 0:00).assume_offset_checked(offset!(-1)),
+    ///     Some(datetime!(2019-01-01 0:00 -1)),
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::MAX.assume_offset_checked(offset!(-1)),
+    ///     None,
+    /// );
+    /// ```
+    pub const fn assume_offset_checked(self, offset: UtcOffset) -> Option<OffsetDateTime> {
+        let datetime = self.0.assume_offset(offset);
+        let (year, _, _) = datetime.to_offset_raw(UtcOffset::UTC);
+
+        if year < MIN_YEAR || year > MAX_YEAR {
+            None
+        } else {
+            Some(OffsetDateTime(datetime))
+        }
+    }
+
     /// Assuming that the existing `PrimitiveDateTime` represents a moment in UTC, return an
     /// [`OffsetDateTime`].
     ///
@@ -521,6 +768,40 @@ impl PrimitiveDateTime {
     pub const fn checked_sub(self, duration: Duration) -> Option<Self> {
         Some(Self(const_try_opt!(self.0.checked_sub(duration))))
     }
+
+    /// Computes `self + duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::datetime;
+    /// let datetime = Date::MAX.midnight();
+    /// assert_eq!(datetime.checked_add_std(1.std_days()), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30).checked_add_std(27.std_hours()),
+    ///     Some(datetime!(2019 - 11 - 26 18:30))
+    /// );
+    /// ```
+    pub const fn checked_add_std(self, duration: StdDuration) -> Option<Self> {
+        Some(Self(const_try_opt!(self.0.checked_add_std(duration))))
+    }
+
+    /// Computes `self - duration`, returning `None` if an overflow occurred.
+    ///
+    /// ```
+    /// # use time::{Date, ext::NumericalStdDuration};
+    /// # use time_macros::datetime;
+    /// let datetime = Date::MIN.midnight();
+    /// assert_eq!(datetime.checked_sub_std(1.std_days()), None);
+    ///
+    /// assert_eq!(
+    ///     datetime!(2019 - 11 - 25 15:30).checked_sub_std(27.std_hours()),
+    ///     Some(datetime!(2019 - 11 - 24 12:30))
+    /// );
+    /// ```
+    pub const fn checked_sub_std(self, duration: StdDuration) -> Option<Self> {
+        Some(Self(const_try_opt!(self.0.checked_sub_std(duration))))
+    }
     // endregion: checked arithmetic
 
     // region: saturating arithmetic
@@ -572,6 +853,82 @@ impl PrimitiveDateTime {
         Self(self.0.saturating_sub(duration))
     }
     // endregion: saturating arithmetic
+
+    // region: comparison
+    /// Compare two `PrimitiveDateTime`s. This is equivalent to [`Ord::cmp`], but usable in `const`
+    /// contexts.
+    pub(crate) const fn cmp_const(self, other: Self) -> Ordering {
+        self.0.cmp_const(other.0)
+    }
+
+    /// Returns the earlier of the two values. Unlike [`Ord::min`], this is usable in `const`
+    /// contexts.
+    ///
+    /// If the two values are equal, `self` is returned.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let (low, high) = (datetime!(2019-01-01 0:00), datetime!(2019-01-02 0:00));
+    /// assert_eq!(low.min(high), low);
+    /// assert_eq!(high.min(low), low);
+    /// ```
+    pub const fn min(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the later of the two values. Unlike [`Ord::max`], this is usable in `const`
+    /// contexts.
+    ///
+    /// If the two values are equal, `other` is returned, as with [`Ord::max`].
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let (low, high) = (datetime!(2019-01-01 0:00), datetime!(2019-01-02 0:00));
+    /// assert_eq!(low.max(high), high);
+    /// assert_eq!(high.max(low), high);
+    /// ```
+    pub const fn max(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restrict the value to the range `min..=max`. Unlike [`Ord::clamp`], this is usable in
+    /// `const` contexts.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// let low = datetime!(2019-01-01 0:00);
+    /// let middle = datetime!(2019-01-01 12:00);
+    /// let high = datetime!(2019-01-02 0:00);
+    /// assert_eq!(low.clamp(middle, high), middle);
+    /// assert_eq!(middle.clamp(low, high), middle);
+    /// assert_eq!(high.clamp(low, middle), middle);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `min` is greater than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            min.cmp_const(max).is_le(),
+            "`min` must be less than or equal to `max`"
+        );
+        if self.cmp_const(min).is_lt() {
+            min
+        } else if self.cmp_const(max).is_gt() {
+            max
+        } else {
+            self
+        }
+    }
+    // endregion comparison
 }
 
 // region: replacement
@@ -752,10 +1109,11 @@ impl PrimitiveDateTime {
 // endregion replacement
 
 // region: formatting & parsing
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl PrimitiveDateTime {
     /// Format the `PrimitiveDateTime` using the provided [format
     /// description](crate::format_description).
+    #[cfg(feature = "std")]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -764,6 +1122,112 @@ impl PrimitiveDateTime {
         self.0.format_into(output, format)
     }
 
+    /// Format the `PrimitiveDateTime` using the provided [format
+    /// description](crate::format_description), writing to any [`fmt::Write`], such as a
+    /// [`fmt::Formatter`]. This allows a format description to be reused from a `Display`
+    /// implementation, or in `no_std` environments, without allocating.
+    ///
+    /// ```rust
+    /// # use core::fmt;
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, format_description};
+    /// struct Wrapper(PrimitiveDateTime);
+    ///
+    /// impl fmt::Display for Wrapper {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         self.0
+    ///             .format_into_fmt(f, format_description!("[year]-[month]-[day] [hour]:[minute]"))
+    ///             .map(|_| ())
+    ///             .map_err(|_| fmt::Error)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Wrapper(datetime!(2020-01-02 03:04)).to_string(),
+    ///     "2020-01-02 03:04"
+    /// );
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        self.0.format_into_fmt(output, format)
+    }
+
+    /// Pair the `PrimitiveDateTime` with a [format description](crate::format_description),
+    /// returning a value that is formatted directly into the output when displayed. See
+    /// [`Formatted`] for details.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, format_description};
+    /// let datetime = datetime!(2020-01-02 03:04:05);
+    /// assert_eq!(
+    ///     format!(
+    ///         "at {}",
+    ///         datetime.display(format_description!("[year]-[month]-[day] [hour]:[minute]"))
+    ///     ),
+    ///     "at 2020-01-02 03:04"
+    /// );
+    /// ```
+    pub const fn display<F: Formattable + ?Sized>(self, format: &F) -> Formatted<'_, F> {
+        self.0.display(format)
+    }
+
+    /// Format the `PrimitiveDateTime` using the provided [format
+    /// description](crate::format_description), storing the output in a fixed-capacity
+    /// [`ArrayBuf`] rather than allocating.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]");
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04).format_to_array::<16>(format)?,
+    ///     "2020-01-02 03:04"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_array<const N: usize>(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<ArrayBuf<N>, error::Format> {
+        self.0.format_to_array(format)
+    }
+
+    /// Format the `PrimitiveDateTime` using the provided [format
+    /// description](crate::format_description), writing to a caller-provided byte slice. The
+    /// number of bytes written is returned.
+    ///
+    /// If the output does not fit, [`error::Format::InsufficientCapacity`] is returned with the
+    /// number of bytes required, and the contents of the buffer are unspecified. The required
+    /// size can be determined in advance with [`PrimitiveDateTime::formatted_len`].
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]");
+    /// let mut buf = [0; 32];
+    /// let len = datetime!(2020-01-02 03:04).format_into_slice(&mut buf, format)?;
+    /// assert_eq!(&buf[..len], b"2020-01-02 03:04");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        buf: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        self.0.format_into_slice(buf, format)
+    }
+
+    /// Determine the number of bytes that formatting the `PrimitiveDateTime` using the provided
+    /// [format description](crate::format_description) would produce, without writing it
+    /// anywhere.
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        self.0.formatted_len(format)
+    }
+
     /// Format the `PrimitiveDateTime` using the provided [format
     /// description](crate::format_description).
     ///
@@ -777,6 +1241,7 @@ impl PrimitiveDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
         self.0.format(format)
     }
@@ -805,12 +1270,34 @@ impl PrimitiveDateTime {
     }
 }
 
+/// Write the date and time separated by a space, such as `2023-01-02 03:04:05.6`. This format is
+/// stable and is described by [`format_description::display::PRIMITIVE_DATE_TIME`].
+///
+/// [`format_description::display::PRIMITIVE_DATE_TIME`]: crate::format_description::display::PRIMITIVE_DATE_TIME
 impl fmt::Display for PrimitiveDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.0.fmt(f)
     }
 }
 
+/// Parse a date and time in the format produced by its [`Display`](fmt::Display) implementation.
+///
+/// ```rust
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     "2023-01-02 03:04:05.6".parse(),
+///     Ok(datetime!(2023-01-02 3:04:05.6))
+/// );
+/// ```
+#[cfg(feature = "parsing")]
+impl FromStr for PrimitiveDateTime {
+    type Err = error::Parse;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, crate::format_description::display::PRIMITIVE_DATE_TIME)
+    }
+}
+
 impl fmt::Debug for PrimitiveDateTime {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self, f)
@@ -822,6 +1309,7 @@ impl fmt::Debug for PrimitiveDateTime {
 impl Add<Duration> for PrimitiveDateTime {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, duration: Duration) -> Self::Output {
         Self(self.0.add(duration))
     }
@@ -830,18 +1318,21 @@ impl Add<Duration> for PrimitiveDateTime {
 impl Add<StdDuration> for PrimitiveDateTime {
     type Output = Self;
 
+    #[track_caller]
     fn add(self, duration: StdDuration) -> Self::Output {
         Self(self.0.add(duration))
     }
 }
 
 impl AddAssign<Duration> for PrimitiveDateTime {
+    #[track_caller]
     fn add_assign(&mut self, duration: Duration) {
         self.0.add_assign(duration);
     }
 }
 
 impl AddAssign<StdDuration> for PrimitiveDateTime {
+    #[track_caller]
     fn add_assign(&mut self, duration: StdDuration) {
         self.0.add_assign(duration);
     }
@@ -850,6 +1341,7 @@ impl AddAssign<StdDuration> for PrimitiveDateTime {
 impl Sub<Duration> for PrimitiveDateTime {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, duration: Duration) -> Self::Output {
         Self(self.0.sub(duration))
     }
@@ -858,23 +1350,28 @@ impl Sub<Duration> for PrimitiveDateTime {
 impl Sub<StdDuration> for PrimitiveDateTime {
     type Output = Self;
 
+    #[track_caller]
     fn sub(self, duration: StdDuration) -> Self::Output {
         Self(self.0.sub(duration))
     }
 }
 
 impl SubAssign<Duration> for PrimitiveDateTime {
+    #[track_caller]
     fn sub_assign(&mut self, duration: Duration) {
         self.0.sub_assign(duration);
     }
 }
 
 impl SubAssign<StdDuration> for PrimitiveDateTime {
+    #[track_caller]
     fn sub_assign(&mut self, duration: StdDuration) {
         self.0.sub_assign(duration);
     }
 }
 
+/// The difference between any two `PrimitiveDateTime`s is always representable as a [`Duration`],
+/// so this never panics, even for [`PrimitiveDateTime::MAX`] `-` [`PrimitiveDateTime::MIN`].
 impl Sub for PrimitiveDateTime {
     type Output = Duration;
 
@@ -882,4 +1379,33 @@ impl Sub for PrimitiveDateTime {
         self.0.sub(rhs.0)
     }
 }
+
+impl_forward_ref_binop!(Add add for PrimitiveDateTime: Duration, StdDuration);
+impl_forward_ref_binop!(Sub sub for PrimitiveDateTime: Duration, StdDuration, PrimitiveDateTime);
+impl_forward_ref_op_assign!(AddAssign add_assign for PrimitiveDateTime: Duration, StdDuration);
+impl_forward_ref_op_assign!(SubAssign sub_assign for PrimitiveDateTime: Duration, StdDuration);
+
+impl PartialEq<Date> for PrimitiveDateTime {
+    fn eq(&self, rhs: &Date) -> bool {
+        self.eq(&rhs.midnight())
+    }
+}
+
+impl PartialEq<PrimitiveDateTime> for Date {
+    fn eq(&self, rhs: &PrimitiveDateTime) -> bool {
+        self.midnight().eq(rhs)
+    }
+}
+
+impl PartialOrd<Date> for PrimitiveDateTime {
+    fn partial_cmp(&self, rhs: &Date) -> Option<Ordering> {
+        Some(self.cmp(&rhs.midnight()))
+    }
+}
+
+impl PartialOrd<PrimitiveDateTime> for Date {
+    fn partial_cmp(&self, rhs: &PrimitiveDateTime) -> Option<Ordering> {
+        Some(self.midnight().cmp(rhs))
+    }
+}
 // endregion trait impls
@@ -130,8 +130,12 @@ impl Arbitrary for PrimitiveDateTime {
 
 impl Arbitrary for UtcOffset {
     fn arbitrary(g: &mut Gen) -> Self {
-        let seconds =
-            arbitrary_between!(i32; g, -(Second.per(Day) as i32 - 1), Second.per(Day) as i32 - 1);
+        let seconds = arbitrary_between!(
+            i32;
+            g,
+            Self::MIN.whole_seconds(),
+            Self::MAX.whole_seconds()
+        );
         Self::__from_hms_unchecked(
             (seconds / Second.per(Hour) as i32) as _,
             ((seconds % Second.per(Hour) as i32) / Minute.per(Hour) as i32) as _,
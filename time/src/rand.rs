@@ -27,7 +27,8 @@ impl Distribution<Date> for Standard {
 
 impl Distribution<UtcOffset> for Standard {
     fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> UtcOffset {
-        let seconds = rng.gen_range(-(Second.per(Day) as i32 - 1)..=(Second.per(Day) as i32 - 1));
+        let seconds =
+            rng.gen_range(UtcOffset::MIN.whole_seconds()..=UtcOffset::MAX.whole_seconds());
         UtcOffset::__from_hms_unchecked(
             (seconds / Second.per(Hour) as i32) as _,
             ((seconds % Second.per(Hour) as i32) / Minute.per(Hour) as i32) as _,
@@ -0,0 +1,172 @@
+//! Always use the compact representation of a type, regardless of whether the serializer or
+//! deserializer is human-readable.
+//!
+//! Use these modules in combination with serde's [`#[with]`][with] attribute. Each module also
+//! contains an `option` submodule for use with `Option<T>`.
+//!
+//! The representation is the same as the one used by the `Serialize` and `Deserialize`
+//! implementations of each type when the format is not human-readable. Deserialization only
+//! accepts this representation.
+//!
+//! ```rust
+//! # use serde::{Deserialize, Serialize};
+//! # use time::OffsetDateTime;
+//! # use time_macros::datetime;
+//! #[derive(Serialize, Deserialize)]
+//! struct Sample {
+//!     #[serde(with = "time::serde::binary::offset_date_time")]
+//!     at: OffsetDateTime,
+//! }
+//!
+//! let json = serde_json::to_string(&Sample { at: datetime!(2023-01-02 03:04:05 UTC) })?;
+//! assert_eq!(json, r#"{"at":[2023,2,3,4,5,0,0,0,0]}"#);
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use core::marker::PhantomData;
+
+use serde::{Deserializer, Serialize, Serializer};
+
+use super::Visitor;
+use crate::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// Generate a module for a single type, using the provided compact representation.
+macro_rules! binary {
+    (
+        $(#[$attr:meta])*
+        mod $module:ident for $ty:ident;
+        fn serialize($value:ident) $serialize:block
+        fn deserialize($deserializer:ident) $deserialize:block
+    ) => {
+        $(#[$attr])*
+        pub mod $module {
+            #[allow(clippy::wildcard_imports)]
+            use super::*;
+
+            #[doc = concat!("Serialize a [`", stringify!($ty), "`] using its compact representation.")]
+            pub fn serialize<S: Serializer>(
+                $value: &$ty,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $serialize.serialize(serializer)
+            }
+
+            #[doc = concat!(
+                "Deserialize a [`", stringify!($ty), "`] from its compact representation."
+            )]
+            pub fn deserialize<'a, D: Deserializer<'a>>(
+                $deserializer: D,
+            ) -> Result<$ty, D::Error> {
+                $deserialize
+            }
+
+            option_module!($ty, "compact");
+        }
+    };
+}
+
+binary! {
+    /// Use the compact representation of a [`Date`]: a tuple of the year and ordinal.
+    mod date for Date;
+    fn serialize(date) { (date.year(), date.ordinal()) }
+    fn deserialize(deserializer) {
+        deserializer.deserialize_tuple(2, Visitor::<Date>(PhantomData))
+    }
+}
+
+binary! {
+    /// Use the compact representation of a [`Duration`]: a tuple of the whole seconds and the
+    /// subsecond nanoseconds.
+    mod duration for Duration;
+    fn serialize(duration) { (duration.whole_seconds(), duration.subsec_nanoseconds()) }
+    fn deserialize(deserializer) {
+        deserializer.deserialize_tuple(2, Visitor::<Duration>(PhantomData))
+    }
+}
+
+binary! {
+    /// Use the compact representation of an [`OffsetDateTime`]: a tuple of the year, ordinal,
+    /// hour, minute, second, nanosecond, and the hours, minutes, and seconds of the offset.
+    mod offset_date_time for OffsetDateTime;
+    fn serialize(datetime) {
+        (
+            datetime.year(),
+            datetime.ordinal(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+            datetime.nanosecond(),
+            datetime.offset().whole_hours(),
+            datetime.offset().minutes_past_hour(),
+            datetime.offset().seconds_past_minute(),
+        )
+    }
+    fn deserialize(deserializer) {
+        deserializer.deserialize_tuple(9, Visitor::<OffsetDateTime>(PhantomData))
+    }
+}
+
+binary! {
+    /// Use the compact representation of a [`PrimitiveDateTime`]: a tuple of the year, ordinal,
+    /// hour, minute, second, and nanosecond.
+    mod primitive_date_time for PrimitiveDateTime;
+    fn serialize(datetime) {
+        (
+            datetime.year(),
+            datetime.ordinal(),
+            datetime.hour(),
+            datetime.minute(),
+            datetime.second(),
+            datetime.nanosecond(),
+        )
+    }
+    fn deserialize(deserializer) {
+        deserializer.deserialize_tuple(6, Visitor::<PrimitiveDateTime>(PhantomData))
+    }
+}
+
+binary! {
+    /// Use the compact representation of a [`Time`]: a tuple of the hour, minute, second, and
+    /// nanosecond.
+    mod time for Time;
+    fn serialize(time) { (time.hour(), time.minute(), time.second(), time.nanosecond()) }
+    fn deserialize(deserializer) {
+        deserializer.deserialize_tuple(4, Visitor::<Time>(PhantomData))
+    }
+}
+
+binary! {
+    /// Use the compact representation of a [`UtcOffset`]: a tuple of its hours, minutes, and
+    /// seconds.
+    mod utc_offset for UtcOffset;
+    fn serialize(offset) {
+        (
+            offset.whole_hours(),
+            offset.minutes_past_hour(),
+            offset.seconds_past_minute(),
+        )
+    }
+    fn deserialize(deserializer) {
+        deserializer.deserialize_tuple(3, Visitor::<UtcOffset>(PhantomData))
+    }
+}
+
+binary! {
+    /// Use the compact representation of a [`Weekday`]: its number, counting from Monday as 1.
+    mod weekday for Weekday;
+    fn serialize(weekday) { weekday.number_from_monday() }
+    fn deserialize(deserializer) {
+        deserializer.deserialize_u8(Visitor::<Weekday>(PhantomData))
+    }
+}
+
+binary! {
+    /// Use the compact representation of a [`Month`]: its number, counting from January as 1.
+    mod month for Month;
+    fn serialize(month) { *month as u8 }
+    fn deserialize(deserializer) {
+        deserializer.deserialize_u8(Visitor::<Month>(PhantomData))
+    }
+}
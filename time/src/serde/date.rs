@@ -0,0 +1,118 @@
+//! Serde helpers for [`Date`](crate::Date) values.
+
+/// Treat a [`Date`](crate::Date) as a [Unix timestamp] for the purposes of serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// A `Date` does not identify a single instant, so a policy is needed to map between the two.
+/// When serializing, the timestamp emitted is that of midnight UTC at the start of the date. When
+/// deserializing, the timestamp is truncated to the UTC date it falls on. As a result, any
+/// timestamp within the same UTC day deserializes to the same `Date`, and serializing that `Date`
+/// always produces the timestamp of midnight.
+///
+/// Also works with [`Option<Date>`] and `Vec<Date>`.
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use time::Date;
+/// # use time_macros::date;
+/// #[derive(Serialize, Deserialize)]
+/// struct Birthday {
+///     #[serde(with = "time::serde::date::timestamp")]
+///     date: Date,
+/// }
+///
+/// let json = serde_json::to_string(&Birthday { date: date!(2000 - 01 - 01) })?;
+/// assert_eq!(json, r#"{"date":946684800}"#);
+///
+/// // Any time during the day maps to the same date.
+/// let birthday: Birthday = serde_json::from_str(r#"{"date":946771199}"#)?;
+/// assert_eq!(birthday.date, date!(2000 - 01 - 01));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+///
+/// [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod timestamp {
+    use serde::{de, Deserializer, Serializer};
+
+    use crate::serde::timestamp::Timestamp;
+    use crate::serde::{AsWellKnown, FromWellKnown};
+    use crate::{Date, OffsetDateTime};
+
+    /// Serialize a [`Date`] as the Unix timestamp of midnight UTC on that date.
+    ///
+    /// Also works with [`Option<Date>`] and `Vec<Date>`.
+    #[inline(always)]
+    pub fn serialize<S: Serializer, T>(t: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsWellKnown<Timestamp>,
+    {
+        t.serialize_from_wellknown(serializer)
+    }
+
+    /// Deserialize a [`Date`] from a Unix timestamp, truncating it to the UTC date.
+    ///
+    /// Also works with [`Option<Date>`] and `Vec<Date>`.
+    #[inline(always)]
+    pub fn deserialize<'a, D: Deserializer<'a>, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromWellKnown<Timestamp>,
+    {
+        T::deserialize_from_well_known(deserializer)
+    }
+
+    impl AsWellKnown<Timestamp> for Date {
+        type IntoWellKnownError = core::convert::Infallible;
+
+        type WellKnownSer<'s> = i64 where Self: 's;
+
+        fn as_well_known<'s>(&'s self) -> Result<Self::WellKnownSer<'s>, Self::IntoWellKnownError> {
+            Ok(self.midnight().assume_utc().unix_timestamp())
+        }
+    }
+
+    impl FromWellKnown<Timestamp> for Date {
+        type FromWellKnownError = crate::error::ComponentRange;
+
+        type WellKnownDeser<'de> = i64;
+
+        fn fmt_err<E: de::Error>(e: Self::FromWellKnownError) -> E {
+            E::invalid_value(de::Unexpected::Signed(e.value), &e)
+        }
+
+        fn from_well_known<'de>(
+            wk: Self::WellKnownDeser<'de>,
+        ) -> Result<Self, Self::FromWellKnownError> {
+            OffsetDateTime::from_unix_timestamp(wk).map(OffsetDateTime::date)
+        }
+    }
+
+    /// Treat an [`Option<Date>`] as a [Unix timestamp] for the purposes of serde, using the same
+    /// policy as the parent module.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
+    /// [with]: https://serde.rs/field-attrs.html#with
+    pub mod option {
+        #[allow(clippy::wildcard_imports)]
+        use super::*;
+        pub use crate::serde::is_none;
+
+        /// Serialize an [`Option<Date>`] as the Unix timestamp of midnight UTC on that date.
+        pub fn serialize<S: Serializer>(
+            option: &Option<Date>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            super::serialize(option, serializer)
+        }
+
+        /// Deserialize an [`Option<Date>`] from a Unix timestamp, truncating it to the UTC date.
+        pub fn deserialize<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Option<Date>, D::Error> {
+            super::deserialize(deserializer)
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Treat a [`Duration`] as an integer number of a given unit for the purposes of serde.
+//!
+//! Use the submodules in combination with serde's [`#[with]`][with] attribute.
+//!
+//! [`seconds`] uses an `i64`, which is able to represent the whole seconds of any `Duration`. The
+//! other units use an `i128`, as the number of milliseconds, microseconds, or nanoseconds in a
+//! `Duration` may exceed the range of an `i64`. Self-describing formats such as JSON write either
+//! as a plain integer.
+//!
+//! When serializing, any part of the duration smaller than the unit is truncated towards zero.
+//! When deserializing, values outside the range of a `Duration` are rejected.
+//!
+//! ```rust
+//! # use serde::{Deserialize, Serialize};
+//! # use time::Duration;
+//! # use time::ext::NumericalDuration;
+//! #[derive(Serialize, Deserialize)]
+//! struct Timeout {
+//!     #[serde(with = "time::serde::duration::milliseconds")]
+//!     after: Duration,
+//! }
+//!
+//! let json = serde_json::to_string(&Timeout { after: 1.5.seconds() })?;
+//! assert_eq!(json, r#"{"after":1500}"#);
+//!
+//! let timeout: Timeout = serde_json::from_str(r#"{"after":-250}"#)?;
+//! assert_eq!(timeout.after, (-250).milliseconds());
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use crate::error::ConversionRange;
+use crate::Duration;
+
+/// Create a `Duration` from a number of units, where there are `per_second` units in a second.
+fn from_units(value: i128, per_second: i128) -> Result<Duration, ConversionRange> {
+    let seconds = i64::try_from(value / per_second).map_err(|_| ConversionRange)?;
+    let nanoseconds = (value % per_second) * (1_000_000_000 / per_second);
+    Ok(Duration::new(seconds, nanoseconds as _))
+}
+
+/// Generate a module treating a `Duration` as an integer number of the given unit.
+macro_rules! duration_module {
+    (
+        $(#[$doc:meta])*
+        mod $module:ident: $repr:ident as $ty:ty = $whole:ident / $per_second:literal;
+        unit = $unit:literal;
+    ) => {
+        $(#[$doc])*
+        ///
+        /// Also works with [`Option<Duration>`] and `Vec<Duration>`.
+        ///
+        /// [with]: https://serde.rs/field-attrs.html#with
+        pub mod $module {
+            use serde::{Deserializer, Serializer};
+
+            use super::from_units;
+            use crate::error::ConversionRange;
+            use crate::serde::{AsWellKnown, FromWellKnown};
+            use crate::Duration;
+
+            #[doc = concat!("Serialize a [`Duration`] as its whole number of ", $unit, ".")]
+            ///
+            /// Also works with [`Option<Duration>`] and `Vec<Duration>`.
+            #[inline(always)]
+            pub fn serialize<S: Serializer, T>(t: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: AsWellKnown<$repr>,
+            {
+                t.serialize_from_wellknown(serializer)
+            }
+
+            #[doc = concat!("Deserialize a [`Duration`] from a number of ", $unit, ".")]
+            ///
+            /// Also works with [`Option<Duration>`] and `Vec<Duration>`.
+            #[inline(always)]
+            pub fn deserialize<'a, D: Deserializer<'a>, T>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: FromWellKnown<$repr>,
+            {
+                T::deserialize_from_well_known(deserializer)
+            }
+
+            #[doc = concat!("The representation of a `Duration` as a number of ", $unit, ".")]
+            pub struct $repr;
+
+            impl AsWellKnown<$repr> for Duration {
+                type IntoWellKnownError = core::convert::Infallible;
+
+                type WellKnownSer<'s> = $ty where Self: 's;
+
+                fn as_well_known<'s>(
+                    &'s self,
+                ) -> Result<Self::WellKnownSer<'s>, Self::IntoWellKnownError> {
+                    Ok(self.$whole())
+                }
+            }
+
+            impl FromWellKnown<$repr> for Duration {
+                type FromWellKnownError = ConversionRange;
+
+                type WellKnownDeser<'de> = $ty;
+
+                fn from_well_known<'de>(
+                    wk: Self::WellKnownDeser<'de>,
+                ) -> Result<Self, Self::FromWellKnownError> {
+                    from_units(wk.into(), $per_second)
+                }
+            }
+
+            #[doc = concat!(
+                "Treat an [`Option<Duration>`] as a number of ", $unit, " for the purposes of ",
+                "serde, using the same representation as the parent module."
+            )]
+            ///
+            /// Use this module in combination with serde's [`#[with]`][with] attribute.
+            ///
+            /// [with]: https://serde.rs/field-attrs.html#with
+            pub mod option {
+                #[allow(clippy::wildcard_imports)]
+                use super::*;
+                pub use crate::serde::is_none;
+
+                #[doc = concat!(
+                    "Serialize an [`Option<Duration>`] as its whole number of ", $unit, "."
+                )]
+                pub fn serialize<S: Serializer>(
+                    option: &Option<Duration>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::serialize(option, serializer)
+                }
+
+                #[doc = concat!("Deserialize an [`Option<Duration>`] from a number of ", $unit, ".")]
+                pub fn deserialize<'a, D: Deserializer<'a>>(
+                    deserializer: D,
+                ) -> Result<Option<Duration>, D::Error> {
+                    super::deserialize(deserializer)
+                }
+            }
+        }
+    };
+}
+
+duration_module! {
+    /// Treat a [`Duration`] as a whole number of seconds, represented as an `i64`, for the
+    /// purposes of serde.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    mod seconds: Seconds as i64 = whole_seconds / 1;
+    unit = "seconds";
+}
+
+duration_module! {
+    /// Treat a [`Duration`] as a whole number of milliseconds, represented as an `i128`, for the
+    /// purposes of serde.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    mod milliseconds: Milliseconds as i128 = whole_milliseconds / 1_000;
+    unit = "milliseconds";
+}
+
+duration_module! {
+    /// Treat a [`Duration`] as a whole number of microseconds, represented as an `i128`, for the
+    /// purposes of serde.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    mod microseconds: Microseconds as i128 = whole_microseconds / 1_000_000;
+    unit = "microseconds";
+}
+
+duration_module! {
+    /// Treat a [`Duration`] as a whole number of nanoseconds, represented as an `i128`, for the
+    /// purposes of serde.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    mod nanoseconds: Nanoseconds as i128 = whole_nanoseconds / 1_000_000_000;
+    unit = "nanoseconds";
+}
@@ -0,0 +1,166 @@
+//! Always use the human-readable representation of a type, regardless of whether the serializer or
+//! deserializer is human-readable.
+//!
+//! Use these modules in combination with serde's [`#[with]`][with] attribute. Each module also
+//! contains an `option` submodule for use with `Option<T>`.
+//!
+//! The representation is the same as the one used by the `Serialize` and `Deserialize`
+//! implementations of each type when the format is human-readable. Every value is represented as a
+//! string, and deserialization only accepts a string.
+//!
+//! ```rust
+//! # use serde::{Deserialize, Serialize};
+//! # use time::OffsetDateTime;
+//! # use time_macros::datetime;
+//! #[derive(Serialize, Deserialize, Debug, PartialEq)]
+//! struct Sample {
+//!     #[serde(with = "time::serde::human::offset_date_time")]
+//!     at: OffsetDateTime,
+//! }
+//!
+//! let sample = Sample { at: datetime!(2023-01-02 03:04:05 UTC) };
+//! let bytes = bincode::serialize(&sample).expect("serializing should succeed");
+//! assert_eq!(bincode::deserialize::<Sample>(&bytes).ok(), Some(sample));
+//! ```
+//!
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use core::marker::PhantomData;
+
+use serde::ser::Error as _;
+use serde::{Deserializer, Serializer};
+
+use super::{
+    Visitor, DATE_FORMAT, OFFSET_DATE_TIME_FORMAT, PRIMITIVE_DATE_TIME_FORMAT, TIME_FORMAT,
+    UTC_OFFSET_FORMAT,
+};
+use crate::format_description::well_known::Rfc3339;
+use crate::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+
+/// Generate a module for a single type, using the provided human-readable representation.
+macro_rules! human {
+    (
+        $(#[$attr:meta])*
+        mod $module:ident for $ty:ident;
+        fn serialize($value:ident, $serializer:ident) $serialize:block
+    ) => {
+        $(#[$attr])*
+        pub mod $module {
+            #[allow(clippy::wildcard_imports)]
+            use super::*;
+
+            #[doc = concat!(
+                "Serialize a [`", stringify!($ty), "`] using its human-readable representation."
+            )]
+            pub fn serialize<S: Serializer>(
+                $value: &$ty,
+                $serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                $serialize
+            }
+
+            #[doc = concat!(
+                "Deserialize a [`", stringify!($ty), "`] from its human-readable representation."
+            )]
+            pub fn deserialize<'a, D: Deserializer<'a>>(
+                deserializer: D,
+            ) -> Result<$ty, D::Error> {
+                deserializer.deserialize_str(Visitor::<$ty>(PhantomData))
+            }
+
+            option_module!($ty, "human-readable");
+        }
+    };
+}
+
+/// Format a value using the provided format description, serializing the resulting string.
+macro_rules! serialize_formatted {
+    ($serializer:ident, $value:ident, $format:ident, $name:literal) => {
+        match $value.format(&$format) {
+            Ok(s) => $serializer.serialize_str(&s),
+            Err(_) => Err(S::Error::custom(concat!("failed formatting `", $name, "`"))),
+        }
+    };
+}
+
+human! {
+    /// Use the human-readable representation of a [`Date`], such as `2023-01-02`.
+    mod date for Date;
+    fn serialize(date, serializer) {
+        serialize_formatted!(serializer, date, DATE_FORMAT, "Date")
+    }
+}
+
+human! {
+    /// Use the human-readable representation of a [`Duration`]: the number of seconds as a
+    /// decimal, such as `1.500000000`.
+    mod duration for Duration;
+    fn serialize(duration, serializer) {
+        // The sign is written separately, as the whole seconds are zero for durations shorter
+        // than a second.
+        serializer.collect_str(&format_args!(
+            "{}{}.{:>09}",
+            if duration.is_negative() { "-" } else { "" },
+            duration.whole_seconds().unsigned_abs(),
+            duration.subsec_nanoseconds().unsigned_abs()
+        ))
+    }
+}
+
+human! {
+    /// Use the human-readable representation of an [`OffsetDateTime`], such as
+    /// `2023-01-02 03:04:05.0 +00:00:00`.
+    ///
+    /// When the `serde-well-known-default` feature is enabled, [RFC 3339](Rfc3339) is used
+    /// instead, such as `2023-01-02T03:04:05Z`, and both forms are accepted when deserializing.
+    mod offset_date_time for OffsetDateTime;
+    fn serialize(datetime, serializer) {
+        if cfg!(feature = "serde-well-known-default") {
+            serialize_formatted!(serializer, datetime, Rfc3339, "OffsetDateTime")
+        } else {
+            serialize_formatted!(serializer, datetime, OFFSET_DATE_TIME_FORMAT, "OffsetDateTime")
+        }
+    }
+}
+
+human! {
+    /// Use the human-readable representation of a [`PrimitiveDateTime`], such as
+    /// `2023-01-02 03:04:05.0`.
+    mod primitive_date_time for PrimitiveDateTime;
+    fn serialize(datetime, serializer) {
+        serialize_formatted!(
+            serializer,
+            datetime,
+            PRIMITIVE_DATE_TIME_FORMAT,
+            "PrimitiveDateTime"
+        )
+    }
+}
+
+human! {
+    /// Use the human-readable representation of a [`Time`], such as `03:04:05.0`.
+    mod time for Time;
+    fn serialize(time, serializer) {
+        serialize_formatted!(serializer, time, TIME_FORMAT, "Time")
+    }
+}
+
+human! {
+    /// Use the human-readable representation of a [`UtcOffset`], such as `+01:02:03`.
+    mod utc_offset for UtcOffset;
+    fn serialize(offset, serializer) {
+        serialize_formatted!(serializer, offset, UTC_OFFSET_FORMAT, "UtcOffset")
+    }
+}
+
+human! {
+    /// Use the human-readable representation of a [`Weekday`]: its name, such as `Monday`.
+    mod weekday for Weekday;
+    fn serialize(weekday, serializer) { serializer.serialize_str(weekday.name()) }
+}
+
+human! {
+    /// Use the human-readable representation of a [`Month`]: its name, such as `January`.
+    mod month for Month;
+    fn serialize(month, serializer) { serializer.serialize_str(month.name()) }
+}
@@ -2,17 +2,22 @@
 //!
 //! Use this module in combination with serde's [`#[with]`][with] attribute.
 //!
+//! Values are serialized with six-digit years, the extended format, and full precision.
+//! Deserialization is not tied to that configuration: any value produced by formatting with
+//! [`Iso8601`] or [`Iso8601Dynamic`](crate::format_description::well_known::Iso8601Dynamic) is
+//! accepted, provided it includes a date, time, and offset.
+//!
 //! [ISO 8601 format]: https://www.iso.org/iso-8601-date-and-time-format.html
 //! [with]: https://serde.rs/field-attrs.html#with
 
 #[cfg(feature = "parsing")]
 use core::marker::PhantomData;
 
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
 use serde::ser::Error as _;
 #[cfg(feature = "parsing")]
 use serde::Deserializer;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
 use serde::{Serialize, Serializer};
 
 #[cfg(feature = "parsing")]
@@ -25,8 +30,10 @@ use crate::OffsetDateTime;
 pub(crate) const SERDE_CONFIG: EncodedConfig =
     Config::DEFAULT.set_year_is_six_digits(true).encode();
 
+well_known_string!(Iso8601<CONFIG> = Iso8601::<CONFIG>, const CONFIG: EncodedConfig);
+
 /// Serialize an [`OffsetDateTime`] using the well-known ISO 8601 format.
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
 pub fn serialize<S: Serializer>(
     datetime: &OffsetDateTime,
     serializer: S,
@@ -53,9 +60,10 @@ pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDat
 pub mod option {
     #[allow(clippy::wildcard_imports)]
     use super::*;
+    pub use crate::serde::is_none;
 
     /// Serialize an [`Option<OffsetDateTime>`] using the well-known ISO 8601 format.
-    #[cfg(feature = "formatting")]
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
     pub fn serialize<S: Serializer>(
         option: &Option<OffsetDateTime>,
         serializer: S,
@@ -75,3 +83,109 @@ pub mod option {
         deserializer.deserialize_option(Visitor::<Option<Iso8601<SERDE_CONFIG>>>(PhantomData))
     }
 }
+
+primitive_module!("ISO 8601");
+
+/// Use the well-known [ISO 8601 duration
+/// format](crate::format_description::well_known::Iso8601Duration) when serializing and
+/// deserializing a [`Duration`](crate::Duration), such as `PT1H30M`.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// Values are serialized using
+/// [`Iso8601Duration::DEFAULT`](crate::format_description::well_known::Iso8601Duration::DEFAULT).
+/// Any ISO 8601 duration accepted by
+/// [`Duration::parse`](crate::Duration::parse) is accepted when deserializing.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+#[cfg(feature = "alloc")]
+pub mod duration {
+    use alloc::string::String;
+
+    #[cfg(feature = "parsing")]
+    use serde::Deserializer;
+    #[cfg(feature = "formatting-core")]
+    use serde::Serializer;
+
+    use crate::format_description::well_known::Iso8601Duration;
+    #[cfg(feature = "formatting-core")]
+    use crate::serde::AsWellKnown;
+    #[cfg(feature = "parsing")]
+    use crate::serde::FromWellKnown;
+    use crate::Duration;
+
+    /// Serialize a [`Duration`] using the well-known ISO 8601 duration format.
+    ///
+    /// Also works with [`Option<Duration>`] and `Vec<Duration>`.
+    #[cfg(feature = "formatting-core")]
+    pub fn serialize<S: Serializer, T>(t: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsWellKnown<Iso8601Duration>,
+    {
+        t.serialize_from_wellknown(serializer)
+    }
+
+    /// Deserialize a [`Duration`] from its ISO 8601 duration representation.
+    ///
+    /// Also works with [`Option<Duration>`] and `Vec<Duration>`.
+    #[cfg(feature = "parsing")]
+    pub fn deserialize<'a, D: Deserializer<'a>, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromWellKnown<Iso8601Duration>,
+    {
+        T::deserialize_from_well_known(deserializer)
+    }
+
+    #[cfg(feature = "formatting-core")]
+    impl AsWellKnown<Iso8601Duration> for Duration {
+        type IntoWellKnownError = crate::error::Format;
+
+        type WellKnownSer<'s> = String where Self: 's;
+
+        fn as_well_known<'s>(&'s self) -> Result<Self::WellKnownSer<'s>, Self::IntoWellKnownError> {
+            self.format(&Iso8601Duration::DEFAULT)
+        }
+    }
+
+    #[cfg(feature = "parsing")]
+    impl FromWellKnown<Iso8601Duration> for Duration {
+        type FromWellKnownError = crate::error::Parse;
+
+        type WellKnownDeser<'de> = String;
+
+        fn from_well_known<'de>(
+            wk: Self::WellKnownDeser<'de>,
+        ) -> Result<Self, Self::FromWellKnownError> {
+            Self::parse(&wk, &Iso8601Duration::DEFAULT)
+        }
+    }
+
+    /// Use the well-known ISO 8601 duration format when serializing and deserializing an
+    /// [`Option<Duration>`].
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [with]: https://serde.rs/field-attrs.html#with
+    pub mod option {
+        #[allow(clippy::wildcard_imports)]
+        use super::*;
+        pub use crate::serde::is_none;
+
+        /// Serialize an [`Option<Duration>`] using the well-known ISO 8601 duration format.
+        #[cfg(feature = "formatting-core")]
+        pub fn serialize<S: Serializer>(
+            option: &Option<Duration>,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            super::serialize(option, serializer)
+        }
+
+        /// Deserialize an [`Option<Duration>`] from its ISO 8601 duration representation.
+        #[cfg(feature = "parsing")]
+        pub fn deserialize<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Option<Duration>, D::Error> {
+            super::deserialize(deserializer)
+        }
+    }
+}
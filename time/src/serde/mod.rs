@@ -13,19 +13,246 @@ macro_rules! item {
     };
 }
 
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+/// Implement [`AsWellKnown`] and [`FromWellKnown`] for `OffsetDateTime` using a well-known format,
+/// with the value represented as a string.
+#[cfg(any(all(feature = "formatting-core", feature = "alloc"), feature = "parsing"))]
+macro_rules! well_known_string {
+    ($format:ty = $value:expr $(, const $config:ident: $config_ty:ty)?) => {
+        #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+        impl$(<const $config: $config_ty>)? crate::serde::AsWellKnown<$format> for OffsetDateTime {
+            type IntoWellKnownError = crate::error::Format;
+
+            type WellKnownSer<'s> = alloc::string::String where Self: 's;
+
+            fn as_well_known<'s>(
+                &'s self,
+            ) -> Result<Self::WellKnownSer<'s>, Self::IntoWellKnownError> {
+                self.format(&$value)
+            }
+        }
+
+        #[cfg(all(feature = "parsing", feature = "alloc"))]
+        impl$(<const $config: $config_ty>)? crate::serde::FromWellKnown<$format>
+            for OffsetDateTime
+        {
+            type FromWellKnownError = crate::error::Parse;
+
+            type WellKnownDeser<'de> = alloc::string::String;
+
+            fn from_well_known<'de>(
+                wk: Self::WellKnownDeser<'de>,
+            ) -> Result<Self, Self::FromWellKnownError> {
+                Self::parse(&wk, &$value)
+            }
+        }
+    };
+}
+
+/// Generate a `primitive` submodule, which uses the representation of the parent module for a
+/// `PrimitiveDateTime` that is assumed to be in UTC.
+#[cfg(any(all(feature = "formatting-core", feature = "alloc"), feature = "parsing"))]
+macro_rules! primitive_module {
+    ($repr:literal) => {
+        #[doc = concat!(
+            "Use the well-known ", $repr, " format when serializing and deserializing a ",
+            "[`PrimitiveDateTime`](crate::PrimitiveDateTime) that is assumed to be in UTC."
+        )]
+        ///
+        /// Use this module in combination with serde's [`#[with]`][with] attribute.
+        ///
+        /// When serializing, the value is written with a UTC offset. When deserializing, the
+        /// offset must be zero. Any other offset is an error; the value is not converted to UTC.
+        ///
+        /// [with]: https://serde.rs/field-attrs.html#with
+        pub mod primitive {
+            #[cfg(feature = "parsing")]
+            use serde::{de, Deserializer};
+            #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+            use serde::Serializer;
+
+            #[cfg(feature = "parsing")]
+            use crate::OffsetDateTime;
+            use crate::PrimitiveDateTime;
+
+            /// Drop the offset of a deserialized value, which must be UTC.
+            #[cfg(feature = "parsing")]
+            fn into_primitive<E: de::Error>(
+                datetime: OffsetDateTime,
+            ) -> Result<PrimitiveDateTime, E> {
+                if datetime.offset().is_utc() {
+                    Ok(PrimitiveDateTime::new(datetime.date(), datetime.time()))
+                } else {
+                    Err(E::invalid_value(
+                        de::Unexpected::Other("a non-UTC offset"),
+                        &concat!("an ", $repr, "-formatted `PrimitiveDateTime` with a UTC offset"),
+                    ))
+                }
+            }
+
+            #[doc = concat!(
+                "Serialize a [`PrimitiveDateTime`] using the well-known ", $repr, " format, ",
+                "assuming it is in UTC."
+            )]
+            #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+            pub fn serialize<S: Serializer>(
+                datetime: &PrimitiveDateTime,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                super::serialize(&datetime.assume_utc(), serializer)
+            }
+
+            #[doc = concat!(
+                "Deserialize a [`PrimitiveDateTime`] from its ", $repr, " representation, ",
+                "which must have a UTC offset."
+            )]
+            #[cfg(feature = "parsing")]
+            pub fn deserialize<'a, D: Deserializer<'a>>(
+                deserializer: D,
+            ) -> Result<PrimitiveDateTime, D::Error> {
+                super::deserialize(deserializer).and_then(into_primitive)
+            }
+
+            #[doc = concat!(
+                "Use the well-known ", $repr, " format when serializing and deserializing an ",
+                "[`Option<PrimitiveDateTime>`] that is assumed to be in UTC."
+            )]
+            ///
+            /// Use this module in combination with serde's [`#[with]`][with] attribute.
+            ///
+            /// [with]: https://serde.rs/field-attrs.html#with
+            pub mod option {
+                #[allow(clippy::wildcard_imports)]
+                use super::*;
+                pub use crate::serde::is_none;
+
+                #[doc = concat!(
+                    "Serialize an [`Option<PrimitiveDateTime>`] using the well-known ", $repr,
+                    " format, assuming it is in UTC."
+                )]
+                #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+                pub fn serialize<S: Serializer>(
+                    option: &Option<PrimitiveDateTime>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::super::option::serialize(
+                        &option.map(PrimitiveDateTime::assume_utc),
+                        serializer,
+                    )
+                }
+
+                #[doc = concat!(
+                    "Deserialize an [`Option<PrimitiveDateTime>`] from its ", $repr,
+                    " representation, which must have a UTC offset."
+                )]
+                #[cfg(feature = "parsing")]
+                pub fn deserialize<'a, D: Deserializer<'a>>(
+                    deserializer: D,
+                ) -> Result<Option<PrimitiveDateTime>, D::Error> {
+                    super::super::option::deserialize(deserializer)?
+                        .map(into_primitive)
+                        .transpose()
+                }
+            }
+        }
+    };
+}
+
+/// Generate an `option` submodule that uses the representation of the parent module for the
+/// value, if present.
+macro_rules! option_module {
+    ($ty:ident, $repr:literal) => {
+        #[doc = concat!(
+            "Use the ", $repr, " representation of an [`Option<", stringify!($ty), ">`]."
+        )]
+        pub mod option {
+            use core::fmt;
+
+            use serde::{de, Deserializer, Serialize, Serializer};
+
+            pub use crate::serde::is_none;
+            use crate::$ty;
+
+            #[doc = concat!(
+                "Serialize an [`Option<", stringify!($ty), ">`] using the ", $repr,
+                " representation."
+            )]
+            pub fn serialize<S: Serializer>(
+                option: &Option<$ty>,
+                serializer: S,
+            ) -> Result<S::Ok, S::Error> {
+                /// Serialize the value using the representation of the parent module.
+                struct Value<'a>(&'a $ty);
+
+                impl Serialize for Value<'_> {
+                    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                        super::serialize(self.0, serializer)
+                    }
+                }
+
+                match option {
+                    Some(value) => serializer.serialize_some(&Value(value)),
+                    None => serializer.serialize_none(),
+                }
+            }
+
+            #[doc = concat!(
+                "Deserialize an [`Option<", stringify!($ty), ">`] from its ", $repr,
+                " representation."
+            )]
+            pub fn deserialize<'a, D: Deserializer<'a>>(
+                deserializer: D,
+            ) -> Result<Option<$ty>, D::Error> {
+                /// Deserialize the value using the representation of the parent module.
+                struct Visitor;
+
+                impl<'a> de::Visitor<'a> for Visitor {
+                    type Value = Option<$ty>;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str(concat!("an `Option<", stringify!($ty), ">`"))
+                    }
+
+                    fn visit_some<D: Deserializer<'a>>(
+                        self,
+                        deserializer: D,
+                    ) -> Result<Self::Value, D::Error> {
+                        super::deserialize(deserializer).map(Some)
+                    }
+
+                    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+                        Ok(None)
+                    }
+                }
+
+                deserializer.deserialize_option(Visitor)
+            }
+        }
+    };
+}
+
+pub mod binary;
+pub mod date;
+pub mod duration;
+#[cfg(feature = "serde-human-readable")]
+pub mod human;
+#[cfg(any(all(feature = "formatting-core", feature = "alloc"), feature = "parsing"))]
 pub mod iso8601;
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+#[cfg(any(all(feature = "formatting-core", feature = "alloc"), feature = "parsing"))]
 pub mod rfc2822;
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+#[cfg(any(all(feature = "formatting-core", feature = "alloc"), feature = "parsing"))]
 pub mod rfc3339;
+#[cfg(any(all(feature = "formatting-core", feature = "alloc"), feature = "parsing"))]
+pub mod rfc3339_no_z;
+pub mod system_time_compat;
 pub mod timestamp;
 mod visitor;
+pub mod weekday;
 
 use core::marker::PhantomData;
 
-#[cfg(feature = "serde-human-readable")]
-use serde::ser::Error as _;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// Generate a custom serializer and deserializer from a format string or an existing format.
 ///
@@ -37,25 +264,49 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// Invoked as `serde::format_description!(mod_name, Date, FORMAT)` where `FORMAT` is either a
 /// `"<format string>"` or something that implements
 #[cfg_attr(
-    all(feature = "formatting", feature = "parsing"),
+    all(feature = "formatting-core", feature = "parsing"),
     doc = "[`Formattable`](crate::formatting::Formattable) and \
            [`Parsable`](crate::parsing::Parsable)."
 )]
 #[cfg_attr(
-    all(feature = "formatting", not(feature = "parsing")),
+    all(feature = "formatting-core", not(feature = "parsing")),
     doc = "[`Formattable`](crate::formatting::Formattable)."
 )]
 #[cfg_attr(
-    all(not(feature = "formatting"), feature = "parsing"),
+    all(not(feature = "formatting-core"), feature = "parsing"),
     doc = "[`Parsable`](crate::parsing::Parsable)."
 )]
+/// Alternatively, `FORMAT` can be `lazy path::to::function`, where the function returns a
+/// `Result` containing a `&'static` reference to a format description. This allows using a format
+/// description that is only known at runtime, such as an
+/// [`OwnedFormatItem`](crate::format_description::OwnedFormatItem) loaded from configuration. The
+/// function is called whenever a value is serialized or deserialized, so it should cache its
+/// result. If it returns an error, that error is returned by the serializer or deserializer.
+///
+/// The type may also be `Duration`, in which case `FORMAT` must be a path to an
+/// [`Iso8601Duration`](crate::format_description::well_known::Iso8601Duration) (or `lazy`
+/// followed by a function returning one). A format string cannot be used, as format
+/// descriptions only have components for dates and times.
+///
 /// This puts a module named `mod_name` in the current scope that can be used to format `Date`
-/// structs. A submodule (`mod_name::option`) is also generated for `Option<Date>`. Both
-/// modules are only visible in the current scope.
+/// structs. Submodules are also generated for `Option<Date>` (`mod_name::option`), `Vec<Date>`
+/// (`mod_name::vec`), and `Option<Vec<Date>>` (`mod_name::option_vec`). All of these modules are
+/// only visible in the current scope. Each element of a `Vec` is written as a string in the given
+/// format; if one fails to parse, the error includes its index.
 ///
 /// The returned `Option` will contain a deserialized value if present and `None` if the field
 /// is present but the value is `null` (or the equivalent in other formats). To return `None`
-/// when the field is not present, you should use `#[serde(default)]` on the field.
+/// when the field is not present, you should use `#[serde(default)]` on the field. To leave the
+/// field out when serializing `None`, also use `#[serde(skip_serializing_if =
+/// "mod_name::option::is_none")]`.
+///
+/// To tell a missing field apart from `null`, use an `Option<Option<Date>>` with
+/// `mod_name::option::serialize_or_default` and `mod_name::option::deserialize_or_default`,
+/// along with `#[serde(default, skip_serializing_if = "Option::is_none")]`. A missing field is
+/// then `None`, `null` is `Some(None)`, and a value is `Some(Some(_))`. Formats that are not
+/// self-describing, such as bincode, cannot read a struct with a field left out, so omit
+/// `skip_serializing_if` when using them; `None` is then written as `null` and read back as
+/// `Some(None)`.
 ///
 /// # Examples
 ///
@@ -64,15 +315,15 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// ```rust,no_run
 /// # use time::OffsetDateTime;
 #[cfg_attr(
-    all(feature = "formatting", feature = "parsing"),
+    all(feature = "formatting-core", feature = "parsing"),
     doc = "use ::serde::{Serialize, Deserialize};"
 )]
 #[cfg_attr(
-    all(feature = "formatting", not(feature = "parsing")),
+    all(feature = "formatting-core", not(feature = "parsing")),
     doc = "use ::serde::Serialize;"
 )]
 #[cfg_attr(
-    all(not(feature = "formatting"), feature = "parsing"),
+    all(not(feature = "formatting-core"), feature = "parsing"),
     doc = "use ::serde::Deserialize;"
 )]
 /// use time::serde;
@@ -82,15 +333,15 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 ///
 /// # #[allow(dead_code)]
 #[cfg_attr(
-    all(feature = "formatting", feature = "parsing"),
+    all(feature = "formatting-core", feature = "parsing"),
     doc = "#[derive(Serialize, Deserialize)]"
 )]
 #[cfg_attr(
-    all(feature = "formatting", not(feature = "parsing")),
+    all(feature = "formatting-core", not(feature = "parsing")),
     doc = "#[derive(Serialize)]"
 )]
 #[cfg_attr(
-    all(not(feature = "formatting"), feature = "parsing"),
+    all(not(feature = "formatting-core"), feature = "parsing"),
     doc = "#[derive(Deserialize)]"
 )]
 /// struct SerializesWithCustom {
@@ -98,6 +349,23 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 ///     dt: OffsetDateTime,
 ///     #[serde(with = "my_format::option")]
 ///     maybe_dt: Option<OffsetDateTime>,
+///     #[serde(
+///         default,
+///         with = "my_format::option",
+///         skip_serializing_if = "my_format::option::is_none"
+///     )]
+///     omitted_if_none_dt: Option<OffsetDateTime>,
+///     #[serde(
+///         default,
+///         skip_serializing_if = "Option::is_none",
+///         serialize_with = "my_format::option::serialize_or_default",
+///         deserialize_with = "my_format::option::deserialize_or_default"
+///     )]
+///     maybe_missing_dt: Option<Option<OffsetDateTime>>,
+///     #[serde(with = "my_format::vec")]
+///     history: Vec<OffsetDateTime>,
+///     #[serde(with = "my_format::option_vec")]
+///     maybe_history: Option<Vec<OffsetDateTime>>,
 /// }
 /// ```
 /// 
@@ -105,15 +373,15 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// ```rust,no_run
 /// # use time::OffsetDateTime;
 #[cfg_attr(
-    all(feature = "formatting", feature = "parsing"),
+    all(feature = "formatting-core", feature = "parsing"),
     doc = "use ::serde::{Serialize, Deserialize};"
 )]
 #[cfg_attr(
-    all(feature = "formatting", not(feature = "parsing")),
+    all(feature = "formatting-core", not(feature = "parsing")),
     doc = "use ::serde::Serialize;"
 )]
 #[cfg_attr(
-    all(not(feature = "formatting"), feature = "parsing"),
+    all(not(feature = "formatting-core"), feature = "parsing"),
     doc = "use ::serde::Deserialize;"
 )]
 /// use time::serde;
@@ -128,15 +396,15 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 ///
 /// # #[allow(dead_code)]
 #[cfg_attr(
-    all(feature = "formatting", feature = "parsing"),
+    all(feature = "formatting-core", feature = "parsing"),
     doc = "#[derive(Serialize, Deserialize)]"
 )]
 #[cfg_attr(
-    all(feature = "formatting", not(feature = "parsing")),
+    all(feature = "formatting-core", not(feature = "parsing")),
     doc = "#[derive(Serialize)]"
 )]
 #[cfg_attr(
-    all(not(feature = "formatting"), feature = "parsing"),
+    all(not(feature = "formatting-core"), feature = "parsing"),
     doc = "#[derive(Deserialize)]"
 )]
 /// struct SerializesWithCustom {
@@ -156,23 +424,25 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// ```rust,no_run
 /// # use time::OffsetDateTime;
 #[cfg_attr(
-    all(feature = "formatting", feature = "parsing"),
+    all(feature = "formatting-core", feature = "parsing"),
     doc = "use ::serde::{Serialize, Deserialize};"
 )]
 #[cfg_attr(
-    all(feature = "formatting", not(feature = "parsing")),
+    all(feature = "formatting-core", not(feature = "parsing")),
     doc = "use ::serde::Serialize;"
 )]
 #[cfg_attr(
-    all(not(feature = "formatting"), feature = "parsing"),
+    all(not(feature = "formatting-core"), feature = "parsing"),
     doc = "use ::serde::Deserialize;"
 )]
 /// use time::serde;
 /// use time::format_description::well_known::{iso8601, Iso8601};
 ///
+/// # #[allow(dead_code)]
 /// const CONFIG: iso8601::EncodedConfig = iso8601::Config::DEFAULT
 ///     .set_year_is_six_digits(false)
 ///     .encode();
+/// # #[allow(dead_code)]
 /// const FORMAT: Iso8601<CONFIG> = Iso8601::<CONFIG>;
 ///
 /// // Makes a module `mod my_format { ... }`.
@@ -180,15 +450,15 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 ///
 /// # #[allow(dead_code)]
 #[cfg_attr(
-    all(feature = "formatting", feature = "parsing"),
+    all(feature = "formatting-core", feature = "parsing"),
     doc = "#[derive(Serialize, Deserialize)]"
 )]
 #[cfg_attr(
-    all(feature = "formatting", not(feature = "parsing")),
+    all(feature = "formatting-core", not(feature = "parsing")),
     doc = "#[derive(Serialize)]"
 )]
 #[cfg_attr(
-    all(not(feature = "formatting"), feature = "parsing"),
+    all(not(feature = "formatting-core"), feature = "parsing"),
     doc = "#[derive(Deserialize)]"
 )]
 /// struct SerializesWithCustom {
@@ -200,37 +470,121 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// # fn main() {}
 /// ```
 /// 
+/// Use a format description that is only known at runtime:
+/// ```rust,no_run
+/// # use time::OffsetDateTime;
+#[cfg_attr(
+    all(feature = "formatting-core", feature = "parsing"),
+    doc = "use ::serde::{Serialize, Deserialize};"
+)]
+#[cfg_attr(
+    all(feature = "formatting-core", not(feature = "parsing")),
+    doc = "use ::serde::Serialize;"
+)]
+#[cfg_attr(
+    all(not(feature = "formatting-core"), feature = "parsing"),
+    doc = "use ::serde::Deserialize;"
+)]
+/// use std::sync::Mutex;
+///
+/// use time::error::InvalidFormatDescription;
+/// use time::format_description::{self, OwnedFormatItem};
+/// use time::serde;
+///
+/// # #[allow(dead_code)]
+/// fn load_format() -> Result<&'static OwnedFormatItem, InvalidFormatDescription> {
+///     static FORMAT: Mutex<Option<&'static OwnedFormatItem>> = Mutex::new(None);
+///
+///     let mut cached = FORMAT.lock().expect("lock should not be poisoned");
+///     if let Some(format) = *cached {
+///         return Ok(format);
+///     }
+///     # let format_from_config = "[year]-[month]-[day] [hour]:[minute] [offset_hour]";
+///     let format = format_description::parse_owned::<2>(format_from_config)?;
+///     let format = &*Box::leak(Box::new(format));
+///     *cached = Some(format);
+///     Ok(format)
+/// }
+///
+/// // Makes a module `mod my_format { ... }`.
+/// serde::format_description!(my_format, OffsetDateTime, lazy load_format);
+///
+/// # #[allow(dead_code)]
+#[cfg_attr(
+    all(feature = "formatting-core", feature = "parsing"),
+    doc = "#[derive(Serialize, Deserialize)]"
+)]
+#[cfg_attr(
+    all(feature = "formatting-core", not(feature = "parsing")),
+    doc = "#[derive(Serialize)]"
+)]
+#[cfg_attr(
+    all(not(feature = "formatting-core"), feature = "parsing"),
+    doc = "#[derive(Deserialize)]"
+)]
+/// struct SerializesWithCustom {
+///     #[serde(with = "my_format")]
+///     dt: OffsetDateTime,
+///     #[serde(with = "my_format::option")]
+///     maybe_dt: Option<OffsetDateTime>,
+/// }
+/// # fn main() {}
+/// ```
+///
 /// [`format_description::parse()`]: crate::format_description::parse()
-#[cfg(all(feature = "macros", any(feature = "formatting", feature = "parsing"),))]
+#[cfg(all(feature = "macros", any(feature = "formatting-core", feature = "parsing"),))]
 pub use time_macros::serde_format_description as format_description;
 
 use self::visitor::Visitor;
 #[cfg(feature = "parsing")]
-use crate::format_description::{modifier, Component, FormatItem};
+use crate::format_description::display::{
+    DATE as DATE_FORMAT, OFFSET_DATE_TIME as OFFSET_DATE_TIME_FORMAT,
+    PRIMITIVE_DATE_TIME as PRIMITIVE_DATE_TIME_FORMAT, TIME as TIME_FORMAT,
+    UTC_OFFSET as UTC_OFFSET_FORMAT,
+};
 use crate::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
 
-// region: Date
-/// The format used when serializing and deserializing a human-readable `Date`.
-#[cfg(feature = "parsing")]
-const DATE_FORMAT: &[FormatItem<'_>] = &[
-    FormatItem::Component(Component::Year(modifier::Year::default())),
-    FormatItem::Literal(b"-"),
-    FormatItem::Component(Component::Month(modifier::Month::default())),
-    FormatItem::Literal(b"-"),
-    FormatItem::Component(Component::Day(modifier::Day::default())),
-];
+/// Check whether the value is `None`.
+///
+/// Every `option` module re-exports this function, so that a field can be left out when it is
+/// `None` by naming the same module in serde's [`skip_serializing_if`][skip] attribute. Combined
+/// with `#[serde(default)]`, a missing field is then read back as `None`.
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use time::Duration;
+/// #[derive(Serialize, Deserialize, Debug, PartialEq)]
+/// struct Job {
+///     #[serde(
+///         default,
+///         with = "time::serde::duration::seconds::option",
+///         skip_serializing_if = "time::serde::duration::seconds::option::is_none"
+///     )]
+///     timeout: Option<Duration>,
+/// }
+///
+/// assert_eq!(serde_json::to_string(&Job { timeout: None })?, "{}");
+/// assert_eq!(serde_json::from_str::<Job>("{}")?, Job { timeout: None });
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+///
+/// Only do so in self-describing formats; others, such as bincode, are unable to read a struct with
+/// a field left out.
+///
+/// [skip]: https://serde.rs/field-attrs.html#skip_serializing_if
+pub const fn is_none<T>(option: &Option<T>) -> bool {
+    option.is_none()
+}
 
+// region: Date
 impl Serialize for Date {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
-            let Ok(s) = self.format(&DATE_FORMAT) else {
-                return Err(S::Error::custom("failed formatting `Date`"));
-            };
-            return serializer.serialize_str(&s);
+            return human::date::serialize(self, serializer);
         }
 
-        (self.year(), self.ordinal()).serialize(serializer)
+        binary::date::serialize(self, serializer)
     }
 }
 
@@ -239,7 +593,7 @@ impl<'a> Deserialize<'a> for Date {
         if cfg!(feature = "serde-human-readable") && deserializer.is_human_readable() {
             deserializer.deserialize_any(Visitor::<Self>(PhantomData))
         } else {
-            deserializer.deserialize_tuple(2, Visitor::<Self>(PhantomData))
+            binary::date::deserialize(deserializer)
         }
     }
 }
@@ -250,14 +604,10 @@ impl Serialize for Duration {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
-            return serializer.collect_str(&format_args!(
-                "{}.{:>09}",
-                self.whole_seconds(),
-                self.subsec_nanoseconds().abs()
-            ));
+            return human::duration::serialize(self, serializer);
         }
 
-        (self.whole_seconds(), self.subsec_nanoseconds()).serialize(serializer)
+        binary::duration::serialize(self, serializer)
     }
 }
 
@@ -266,45 +616,21 @@ impl<'a> Deserialize<'a> for Duration {
         if cfg!(feature = "serde-human-readable") && deserializer.is_human_readable() {
             deserializer.deserialize_any(Visitor::<Self>(PhantomData))
         } else {
-            deserializer.deserialize_tuple(2, Visitor::<Self>(PhantomData))
+            binary::duration::deserialize(deserializer)
         }
     }
 }
 // endregion Duration
 
 // region: OffsetDateTime
-/// The format used when serializing and deserializing a human-readable `OffsetDateTime`.
-#[cfg(feature = "parsing")]
-const OFFSET_DATE_TIME_FORMAT: &[FormatItem<'_>] = &[
-    FormatItem::Compound(DATE_FORMAT),
-    FormatItem::Literal(b" "),
-    FormatItem::Compound(TIME_FORMAT),
-    FormatItem::Literal(b" "),
-    FormatItem::Compound(UTC_OFFSET_FORMAT),
-];
-
 impl Serialize for OffsetDateTime {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
-            let Ok(s) = self.format(&OFFSET_DATE_TIME_FORMAT) else {
-                return Err(S::Error::custom("failed formatting `OffsetDateTime`"));
-            };
-            return serializer.serialize_str(&s);
+            return human::offset_date_time::serialize(self, serializer);
         }
 
-        (
-            self.year(),
-            self.ordinal(),
-            self.hour(),
-            self.minute(),
-            self.second(),
-            self.nanosecond(),
-            self.offset().whole_hours(),
-            self.offset().minutes_past_hour(),
-            self.offset().seconds_past_minute(),
-        )
-            .serialize(serializer)
+        binary::offset_date_time::serialize(self, serializer)
     }
 }
 
@@ -313,40 +639,21 @@ impl<'a> Deserialize<'a> for OffsetDateTime {
         if cfg!(feature = "serde-human-readable") && deserializer.is_human_readable() {
             deserializer.deserialize_any(Visitor::<Self>(PhantomData))
         } else {
-            deserializer.deserialize_tuple(9, Visitor::<Self>(PhantomData))
+            binary::offset_date_time::deserialize(deserializer)
         }
     }
 }
 // endregion OffsetDateTime
 
 // region: PrimitiveDateTime
-/// The format used when serializing and deserializing a human-readable `PrimitiveDateTime`.
-#[cfg(feature = "parsing")]
-const PRIMITIVE_DATE_TIME_FORMAT: &[FormatItem<'_>] = &[
-    FormatItem::Compound(DATE_FORMAT),
-    FormatItem::Literal(b" "),
-    FormatItem::Compound(TIME_FORMAT),
-];
-
 impl Serialize for PrimitiveDateTime {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
-            let Ok(s) = self.format(&PRIMITIVE_DATE_TIME_FORMAT) else {
-                return Err(S::Error::custom("failed formatting `PrimitiveDateTime`"));
-            };
-            return serializer.serialize_str(&s);
+            return human::primitive_date_time::serialize(self, serializer);
         }
 
-        (
-            self.year(),
-            self.ordinal(),
-            self.hour(),
-            self.minute(),
-            self.second(),
-            self.nanosecond(),
-        )
-            .serialize(serializer)
+        binary::primitive_date_time::serialize(self, serializer)
     }
 }
 
@@ -355,38 +662,21 @@ impl<'a> Deserialize<'a> for PrimitiveDateTime {
         if cfg!(feature = "serde-human-readable") && deserializer.is_human_readable() {
             deserializer.deserialize_any(Visitor::<Self>(PhantomData))
         } else {
-            deserializer.deserialize_tuple(6, Visitor::<Self>(PhantomData))
+            binary::primitive_date_time::deserialize(deserializer)
         }
     }
 }
 // endregion PrimitiveDateTime
 
 // region: Time
-/// The format used when serializing and deserializing a human-readable `Time`.
-#[cfg(feature = "parsing")]
-const TIME_FORMAT: &[FormatItem<'_>] = &[
-    FormatItem::Component(Component::Hour(<modifier::Hour>::default())),
-    FormatItem::Literal(b":"),
-    FormatItem::Component(Component::Minute(<modifier::Minute>::default())),
-    FormatItem::Literal(b":"),
-    FormatItem::Component(Component::Second(<modifier::Second>::default())),
-    FormatItem::Optional(&FormatItem::Compound(&[
-        FormatItem::Literal(b"."),
-        FormatItem::Component(Component::Subsecond(<modifier::Subsecond>::default())),
-    ])),
-];
-
 impl Serialize for Time {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
-            let Ok(s) = self.format(&TIME_FORMAT) else {
-                return Err(S::Error::custom("failed formatting `Time`"));
-            };
-            return serializer.serialize_str(&s);
+            return human::time::serialize(self, serializer);
         }
 
-        (self.hour(), self.minute(), self.second(), self.nanosecond()).serialize(serializer)
+        binary::time::serialize(self, serializer)
     }
 }
 
@@ -395,39 +685,21 @@ impl<'a> Deserialize<'a> for Time {
         if cfg!(feature = "serde-human-readable") && deserializer.is_human_readable() {
             deserializer.deserialize_any(Visitor::<Self>(PhantomData))
         } else {
-            deserializer.deserialize_tuple(4, Visitor::<Self>(PhantomData))
+            binary::time::deserialize(deserializer)
         }
     }
 }
 // endregion Time
 
 // region: UtcOffset
-/// The format used when serializing and deserializing a human-readable `UtcOffset`.
-#[cfg(feature = "parsing")]
-const UTC_OFFSET_FORMAT: &[FormatItem<'_>] = &[
-    FormatItem::Component(Component::OffsetHour(modifier::OffsetHour::default())),
-    FormatItem::Literal(b":"),
-    FormatItem::Component(Component::OffsetMinute(modifier::OffsetMinute::default())),
-    FormatItem::Literal(b":"),
-    FormatItem::Component(Component::OffsetSecond(modifier::OffsetSecond::default())),
-];
-
 impl Serialize for UtcOffset {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
-            let Ok(s) = self.format(&UTC_OFFSET_FORMAT) else {
-                return Err(S::Error::custom("failed formatting `UtcOffset`"));
-            };
-            return serializer.serialize_str(&s);
+            return human::utc_offset::serialize(self, serializer);
         }
 
-        (
-            self.whole_hours(),
-            self.minutes_past_hour(),
-            self.seconds_past_minute(),
-        )
-            .serialize(serializer)
+        binary::utc_offset::serialize(self, serializer)
     }
 }
 
@@ -436,7 +708,7 @@ impl<'a> Deserialize<'a> for UtcOffset {
         if cfg!(feature = "serde-human-readable") && deserializer.is_human_readable() {
             deserializer.deserialize_any(Visitor::<Self>(PhantomData))
         } else {
-            deserializer.deserialize_tuple(3, Visitor::<Self>(PhantomData))
+            binary::utc_offset::deserialize(deserializer)
         }
     }
 }
@@ -447,21 +719,19 @@ impl Serialize for Weekday {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
-            #[cfg(not(feature = "std"))]
-            use alloc::string::ToString;
-            return self.to_string().serialize(serializer);
+            return human::weekday::serialize(self, serializer);
         }
 
-        self.number_from_monday().serialize(serializer)
+        binary::weekday::serialize(self, serializer)
     }
 }
 
 impl<'a> Deserialize<'a> for Weekday {
     fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
-        if cfg!(feature = "serde-human-readable") && deserializer.is_human_readable() {
+        if deserializer.is_human_readable() {
             deserializer.deserialize_any(Visitor::<Self>(PhantomData))
         } else {
-            deserializer.deserialize_u8(Visitor::<Self>(PhantomData))
+            binary::weekday::deserialize(deserializer)
         }
     }
 }
@@ -472,21 +742,19 @@ impl Serialize for Month {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
-            #[cfg(not(feature = "std"))]
-            use alloc::string::String;
-            return self.to_string().serialize(serializer);
+            return human::month::serialize(self, serializer);
         }
 
-        (*self as u8).serialize(serializer)
+        binary::month::serialize(self, serializer)
     }
 }
 
 impl<'a> Deserialize<'a> for Month {
     fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
-        if cfg!(feature = "serde-human-readable") && deserializer.is_human_readable() {
+        if deserializer.is_human_readable() {
             deserializer.deserialize_any(Visitor::<Self>(PhantomData))
         } else {
-            deserializer.deserialize_u8(Visitor::<Self>(PhantomData))
+            binary::month::deserialize(deserializer)
         }
     }
 }
@@ -525,6 +793,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<W, T> AsWellKnown<W> for [T]
 where
     T: AsWellKnown<W>,
@@ -556,6 +825,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<W, T> AsWellKnown<W> for Vec<T>
 where
     T: AsWellKnown<W>,
@@ -588,7 +858,7 @@ where
 }
 
 pub trait FromWellKnown<WellKnown>: Sized {
-    type FromWellKnownError: std::fmt::Display;
+    type FromWellKnownError: core::fmt::Display;
     type WellKnownDeser<'de>: Deserialize<'de> + 'de;
 
     fn from_well_known<'de>(
@@ -623,6 +893,7 @@ where
     }
 }
 
+#[cfg(feature = "alloc")]
 impl<T, W> FromWellKnown<W> for Vec<T>
 where
     T: FromWellKnown<W>,
@@ -638,3 +909,120 @@ where
         wk.into_iter().map(T::from_well_known).collect()
     }
 }
+
+#[doc(hidden)]
+pub use serde as __serde;
+
+/// Implement serde support for a newtype wrapping a time type, using one of the inner type's
+/// well-known representations.
+///
+/// Invoked as `serde::delegate_well_known!(Newtype => Inner, Format)`, where `Newtype` is a tuple
+/// struct with a single field of type `Inner`, and `Inner` implements both [`AsWellKnown<Format>`]
+/// and [`FromWellKnown<Format>`]. This must be invoked where the field of `Newtype` is visible.
+///
+/// The following implementations are generated for `Newtype`, each delegating to the inner value:
+///
+/// - [`AsWellKnown<Format>`]
+/// - [`FromWellKnown<Format>`]
+/// - [`Serialize`], using the well-known representation
+/// - [`Deserialize`], using the well-known representation
+///
+/// Because `Serialize` and `Deserialize` are implemented directly, no `#[serde(with)]` attribute
+/// is needed when using the newtype, including when it is wrapped in `Option` or `Vec`.
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use time::format_description::well_known::Rfc3339;
+/// # use time::OffsetDateTime;
+/// # use time_macros::datetime;
+/// struct CreatedAt(OffsetDateTime);
+/// time::serde::delegate_well_known!(CreatedAt => OffsetDateTime, Rfc3339);
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Record {
+///     created_at: CreatedAt,
+///     updated_at: Option<CreatedAt>,
+/// }
+///
+/// let record = Record {
+///     created_at: CreatedAt(datetime!(2023-01-02 03:04:05 UTC)),
+///     updated_at: None,
+/// };
+/// let json = serde_json::to_string(&record)?;
+/// assert_eq!(json, r#"{"created_at":"2023-01-02T03:04:05Z","updated_at":null}"#);
+///
+/// let record: Record = serde_json::from_str(&json)?;
+/// assert_eq!(record.created_at.0, datetime!(2023-01-02 03:04:05 UTC));
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __serde_delegate_well_known {
+    ($newtype:ty => $inner:ty, $format:ty $(,)?) => {
+        impl $crate::serde::AsWellKnown<$format> for $newtype {
+            type IntoWellKnownError =
+                <$inner as $crate::serde::AsWellKnown<$format>>::IntoWellKnownError;
+
+            type WellKnownSer<'s> =
+                <$inner as $crate::serde::AsWellKnown<$format>>::WellKnownSer<'s>
+            where
+                Self: 's;
+
+            fn as_well_known<'s>(
+                &'s self,
+            ) -> ::core::result::Result<Self::WellKnownSer<'s>, Self::IntoWellKnownError> {
+                <$inner as $crate::serde::AsWellKnown<$format>>::as_well_known(&self.0)
+            }
+
+            fn fmt_err<E: $crate::serde::__serde::ser::Error>(
+                error: Self::IntoWellKnownError,
+            ) -> E {
+                <$inner as $crate::serde::AsWellKnown<$format>>::fmt_err(error)
+            }
+        }
+
+        impl $crate::serde::FromWellKnown<$format> for $newtype {
+            type FromWellKnownError =
+                <$inner as $crate::serde::FromWellKnown<$format>>::FromWellKnownError;
+
+            type WellKnownDeser<'de> =
+                <$inner as $crate::serde::FromWellKnown<$format>>::WellKnownDeser<'de>;
+
+            fn from_well_known<'de>(
+                wk: Self::WellKnownDeser<'de>,
+            ) -> ::core::result::Result<Self, Self::FromWellKnownError> {
+                <$inner as $crate::serde::FromWellKnown<$format>>::from_well_known(wk).map(Self)
+            }
+
+            fn fmt_err<E: $crate::serde::__serde::de::Error>(
+                error: Self::FromWellKnownError,
+            ) -> E {
+                <$inner as $crate::serde::FromWellKnown<$format>>::fmt_err(error)
+            }
+        }
+
+        impl $crate::serde::__serde::Serialize for $newtype {
+            fn serialize<S: $crate::serde::__serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                <Self as $crate::serde::AsWellKnown<$format>>::serialize_from_wellknown(
+                    self, serializer,
+                )
+            }
+        }
+
+        impl<'de> $crate::serde::__serde::Deserialize<'de> for $newtype {
+            fn deserialize<D: $crate::serde::__serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> ::core::result::Result<Self, D::Error> {
+                <Self as $crate::serde::FromWellKnown<$format>>::deserialize_from_well_known(
+                    deserializer,
+                )
+            }
+        }
+    };
+}
+
+#[doc(inline)]
+pub use crate::__serde_delegate_well_known as delegate_well_known;
@@ -8,11 +8,11 @@
 #[cfg(feature = "parsing")]
 use core::marker::PhantomData;
 
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
 use serde::ser::Error as _;
 #[cfg(feature = "parsing")]
 use serde::Deserializer;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
 use serde::{Serialize, Serializer};
 
 #[cfg(feature = "parsing")]
@@ -20,8 +20,10 @@ use super::Visitor;
 use crate::format_description::well_known::Rfc2822;
 use crate::OffsetDateTime;
 
+well_known_string!(Rfc2822 = Rfc2822);
+
 /// Serialize an [`OffsetDateTime`] using the well-known RFC2822 format.
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
 pub fn serialize<S: Serializer>(
     datetime: &OffsetDateTime,
     serializer: S,
@@ -48,9 +50,10 @@ pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDat
 pub mod option {
     #[allow(clippy::wildcard_imports)]
     use super::*;
+    pub use crate::serde::is_none;
 
     /// Serialize an [`Option<OffsetDateTime>`] using the well-known RFC2822 format.
-    #[cfg(feature = "formatting")]
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
     pub fn serialize<S: Serializer>(
         option: &Option<OffsetDateTime>,
         serializer: S,
@@ -70,3 +73,223 @@ pub mod option {
         deserializer.deserialize_option(Visitor::<Option<Rfc2822>>(PhantomData))
     }
 }
+
+primitive_module!("RFC2822");
+
+/// Use the well-known [RFC2822 format] when serializing and deserializing a
+/// `Vec<OffsetDateTime>`.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [RFC2822 format]: https://tools.ietf.org/html/rfc2822#section-3.3
+/// [with]: https://serde.rs/field-attrs.html#with
+#[cfg(feature = "alloc")]
+pub mod vec {
+    #[cfg(feature = "parsing")]
+    use alloc::vec::Vec;
+
+    #[cfg(feature = "parsing")]
+    use serde::Deserializer;
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+    use serde::Serializer;
+
+    use crate::format_description::well_known::Rfc2822;
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+    use crate::serde::AsWellKnown;
+    #[cfg(feature = "parsing")]
+    use crate::serde::FromWellKnown;
+    use crate::OffsetDateTime;
+
+    /// Serialize a `Vec<OffsetDateTime>` using the well-known RFC2822 format.
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+    pub fn serialize<S: Serializer>(
+        datetimes: &[OffsetDateTime],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        AsWellKnown::<Rfc2822>::serialize_from_wellknown(datetimes, serializer)
+    }
+
+    /// Deserialize a `Vec<OffsetDateTime>` from its RFC2822 representation.
+    #[cfg(feature = "parsing")]
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Vec<OffsetDateTime>, D::Error> {
+        <Vec<OffsetDateTime> as FromWellKnown<Rfc2822>>::deserialize_from_well_known(deserializer)
+    }
+}
+
+/// Use the well-known [RFC2822 format] when serializing an [`OffsetDateTime`], while also accepting
+/// common deviations from it when deserializing.
+///
+/// Deserialization first attempts to parse the value as RFC2822. If that fails, the value is parsed
+/// again with the following allowances, which are commonly needed when reading mail archives:
+///
+/// - The leading day of the week may be omitted.
+/// - The day of the week may disagree with the date, in which case it is ignored.
+/// - A comment or whitespace may follow the offset, such as `+0000 (UTC)`.
+///
+/// Comments elsewhere in the value and the obsolete zone names such as `GMT` and `EST` are accepted
+/// by both forms. If both attempts fail, the error from parsing RFC2822 is returned. Serialization
+/// is identical to that of the parent module, so values are always written in canonical form.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [RFC2822 format]: https://tools.ietf.org/html/rfc2822#section-3.3
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod lenient {
+    #[cfg(feature = "parsing")]
+    use core::fmt;
+    #[cfg(feature = "parsing")]
+    use core::marker::PhantomData;
+
+    #[cfg(feature = "parsing")]
+    use serde::{de, Deserializer};
+
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+    pub use super::serialize;
+    #[cfg(feature = "parsing")]
+    use super::Visitor;
+    #[cfg(feature = "parsing")]
+    use crate::format_description::well_known::Rfc2822;
+    #[cfg(feature = "parsing")]
+    use crate::parsing::parsable::parse_rfc2822_into;
+    #[cfg(feature = "parsing")]
+    use crate::parsing::Parsed;
+    #[cfg(feature = "parsing")]
+    use crate::{error, OffsetDateTime};
+
+    /// Marker for a visitor that accepts RFC2822 values with common deviations.
+    #[cfg(feature = "parsing")]
+    struct Lenient;
+
+    /// Parse an RFC2822 value, falling back to the lenient form if it is not strictly valid.
+    #[cfg(feature = "parsing")]
+    fn parse(value: &str) -> Result<OffsetDateTime, error::Parse> {
+        OffsetDateTime::parse(value, &Rfc2822).or_else(|err| {
+            let mut parsed = Parsed::new();
+            parsed.set_conflicts_allowed(true);
+            match parse_rfc2822_into(value.as_bytes(), &mut parsed, true) {
+                Ok([]) => OffsetDateTime::try_from(parsed).map_err(|_| err),
+                _ => Err(err),
+            }
+        })
+    }
+
+    #[cfg(feature = "parsing")]
+    impl<'a> de::Visitor<'a> for Visitor<Lenient> {
+        type Value = OffsetDateTime;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an RFC2822-formatted `OffsetDateTime`")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<OffsetDateTime, E> {
+            parse(value).map_err(E::custom)
+        }
+    }
+
+    #[cfg(feature = "parsing")]
+    impl<'a> de::Visitor<'a> for Visitor<Option<Lenient>> {
+        type Value = Option<OffsetDateTime>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an RFC2822-formatted `Option<OffsetDateTime>`")
+        }
+
+        fn visit_some<D: Deserializer<'a>>(
+            self,
+            deserializer: D,
+        ) -> Result<Option<OffsetDateTime>, D::Error> {
+            deserializer
+                .deserialize_str(Visitor::<Lenient>(PhantomData))
+                .map(Some)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Option<OffsetDateTime>, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+    }
+
+    /// Deserialize an [`OffsetDateTime`] from its RFC2822 representation, permitting common
+    /// deviations from the format.
+    #[cfg(feature = "parsing")]
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        deserializer.deserialize_str(Visitor::<Lenient>(PhantomData))
+    }
+
+    /// Use the well-known [RFC2822 format] when serializing an [`Option<OffsetDateTime>`], while
+    /// also accepting common deviations from it when deserializing.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [RFC2822 format]: https://tools.ietf.org/html/rfc2822#section-3.3
+    /// [with]: https://serde.rs/field-attrs.html#with
+    pub mod option {
+        #[cfg(feature = "parsing")]
+        use core::marker::PhantomData;
+
+        #[cfg(feature = "parsing")]
+        use serde::Deserializer;
+
+        #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+        pub use super::super::option::serialize;
+        #[cfg(feature = "parsing")]
+        use super::{Lenient, Visitor};
+        #[cfg(feature = "parsing")]
+        use crate::OffsetDateTime;
+        pub use crate::serde::is_none;
+
+        /// Deserialize an [`Option<OffsetDateTime>`] from its RFC2822 representation, permitting
+        /// common deviations from the format.
+        #[cfg(feature = "parsing")]
+        pub fn deserialize<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Option<OffsetDateTime>, D::Error> {
+            deserializer.deserialize_option(Visitor::<Option<Lenient>>(PhantomData))
+        }
+    }
+
+    /// Use the well-known [RFC2822 format] when serializing a `Vec<OffsetDateTime>`, while also
+    /// accepting common deviations from it when deserializing.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [RFC2822 format]: https://tools.ietf.org/html/rfc2822#section-3.3
+    /// [with]: https://serde.rs/field-attrs.html#with
+    #[cfg(feature = "alloc")]
+    pub mod vec {
+        #[cfg(feature = "parsing")]
+        use alloc::string::String;
+        #[cfg(feature = "parsing")]
+        use alloc::vec::Vec;
+
+        #[cfg(feature = "parsing")]
+        use serde::de::Error as _;
+        #[cfg(feature = "parsing")]
+        use serde::{Deserialize, Deserializer};
+
+        #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+        pub use super::super::vec::serialize;
+        #[cfg(feature = "parsing")]
+        use crate::OffsetDateTime;
+
+        /// Deserialize a `Vec<OffsetDateTime>` from its RFC2822 representation, permitting common
+        /// deviations from the format.
+        #[cfg(feature = "parsing")]
+        pub fn deserialize<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Vec<OffsetDateTime>, D::Error> {
+            Vec::<String>::deserialize(deserializer)?
+                .iter()
+                .map(|value| super::parse(value))
+                .collect::<Result<_, _>>()
+                .map_err(D::Error::custom)
+        }
+    }
+}
@@ -8,11 +8,11 @@
 #[cfg(feature = "parsing")]
 use core::marker::PhantomData;
 
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
 use serde::ser::Error as _;
 #[cfg(feature = "parsing")]
 use serde::Deserializer;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
 use serde::{Serialize, Serializer};
 
 #[cfg(feature = "parsing")]
@@ -20,8 +20,10 @@ use super::Visitor;
 use crate::format_description::well_known::Rfc3339;
 use crate::OffsetDateTime;
 
+well_known_string!(Rfc3339 = Rfc3339);
+
 /// Serialize an [`OffsetDateTime`] using the well-known RFC3339 format.
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
 pub fn serialize<S: Serializer>(
     datetime: &OffsetDateTime,
     serializer: S,
@@ -41,16 +43,20 @@ pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDat
 /// Use the well-known [RFC3339 format] when serializing and deserializing an
 /// [`Option<OffsetDateTime>`].
 ///
-/// Use this module in combination with serde's [`#[with]`][with] attribute.
+/// Use this module in combination with serde's [`#[with]`][with] attribute. A missing field is an
+/// error unless `#[serde(default)]` is also used. To tell a missing field apart from `null`, use
+/// an `Option<Option<OffsetDateTime>>` with [`serialize_or_default`](option::serialize_or_default)
+/// and [`deserialize_or_default`](option::deserialize_or_default).
 ///
 /// [RFC3339 format]: https://tools.ietf.org/html/rfc3339#section-5.6
 /// [with]: https://serde.rs/field-attrs.html#with
 pub mod option {
     #[allow(clippy::wildcard_imports)]
     use super::*;
+    pub use crate::serde::is_none;
 
     /// Serialize an [`Option<OffsetDateTime>`] using the well-known RFC3339 format.
-    #[cfg(feature = "formatting")]
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
     pub fn serialize<S: Serializer>(
         option: &Option<OffsetDateTime>,
         serializer: S,
@@ -69,4 +75,148 @@ pub mod option {
     ) -> Result<Option<OffsetDateTime>, D::Error> {
         deserializer.deserialize_option(Visitor::<Option<Rfc3339>>(PhantomData))
     }
+
+    /// Serialize an `Option<Option<OffsetDateTime>>` using the well-known RFC3339 format. `None`
+    /// and `Some(None)` are both serialized as `None`.
+    ///
+    /// This is intended to be used with `#[serde(skip_serializing_if = "Option::is_none")]`, so
+    /// that `None` is not written at all. Only do so in self-describing formats; others, such as
+    /// bincode, are unable to read a struct with a field left out.
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+    pub fn serialize_or_default<S: Serializer>(
+        option: &Option<Option<OffsetDateTime>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serialize(&option.flatten(), serializer)
+    }
+
+    /// Deserialize an `Option<Option<OffsetDateTime>>` from its RFC3339 representation.
+    ///
+    /// This is intended to be used with `#[serde(default)]`. A missing field is then `None`, while
+    /// `null` is `Some(None)`.
+    #[cfg(feature = "parsing")]
+    pub fn deserialize_or_default<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<Option<OffsetDateTime>>, D::Error> {
+        deserialize(deserializer).map(Some)
+    }
+}
+
+primitive_module!("RFC3339");
+
+/// Use the well-known [RFC3339 format] when serializing an [`OffsetDateTime`], while also accepting
+/// [ISO 8601] values when deserializing.
+///
+/// Deserialization first attempts to parse the value as RFC3339. If that fails, it is parsed as ISO
+/// 8601, which permits values such as six-digit years, decimal commas, and offsets without a colon.
+/// If both fail, the error from parsing RFC3339 is returned. Serialization is identical to that of
+/// the parent module.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [RFC3339 format]: https://tools.ietf.org/html/rfc3339#section-5.6
+/// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod lenient {
+    #[cfg(feature = "parsing")]
+    use core::fmt;
+    #[cfg(feature = "parsing")]
+    use core::marker::PhantomData;
+
+    #[cfg(feature = "parsing")]
+    use serde::{de, Deserializer};
+
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+    pub use super::serialize;
+    #[cfg(feature = "parsing")]
+    use super::Visitor;
+    #[cfg(feature = "parsing")]
+    use crate::format_description::well_known::{Iso8601, Rfc3339};
+    #[cfg(feature = "parsing")]
+    use crate::OffsetDateTime;
+
+    /// Marker for a visitor that accepts both RFC3339 and ISO 8601.
+    #[cfg(feature = "parsing")]
+    struct Lenient;
+
+    #[cfg(feature = "parsing")]
+    impl<'a> de::Visitor<'a> for Visitor<Lenient> {
+        type Value = OffsetDateTime;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an RFC3339- or ISO 8601-formatted `OffsetDateTime`")
+        }
+
+        fn visit_str<E: de::Error>(self, value: &str) -> Result<OffsetDateTime, E> {
+            OffsetDateTime::parse(value, &Rfc3339)
+                .or_else(|err| OffsetDateTime::parse(value, &Iso8601::DEFAULT).map_err(|_| err))
+                .map_err(E::custom)
+        }
+    }
+
+    #[cfg(feature = "parsing")]
+    impl<'a> de::Visitor<'a> for Visitor<Option<Lenient>> {
+        type Value = Option<OffsetDateTime>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            formatter.write_str("an RFC3339- or ISO 8601-formatted `Option<OffsetDateTime>`")
+        }
+
+        fn visit_some<D: Deserializer<'a>>(
+            self,
+            deserializer: D,
+        ) -> Result<Option<OffsetDateTime>, D::Error> {
+            deserializer
+                .deserialize_str(Visitor::<Lenient>(PhantomData))
+                .map(Some)
+        }
+
+        fn visit_none<E: de::Error>(self) -> Result<Option<OffsetDateTime>, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+    }
+
+    /// Deserialize an [`OffsetDateTime`] from its RFC3339 or ISO 8601 representation.
+    #[cfg(feature = "parsing")]
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<OffsetDateTime, D::Error> {
+        deserializer.deserialize_str(Visitor::<Lenient>(PhantomData))
+    }
+
+    /// Use the well-known [RFC3339 format] when serializing an [`Option<OffsetDateTime>`], while
+    /// also accepting [ISO 8601] values when deserializing.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// [RFC3339 format]: https://tools.ietf.org/html/rfc3339#section-5.6
+    /// [ISO 8601]: https://www.iso.org/iso-8601-date-and-time-format.html
+    /// [with]: https://serde.rs/field-attrs.html#with
+    pub mod option {
+        #[cfg(feature = "parsing")]
+        use core::marker::PhantomData;
+
+        #[cfg(feature = "parsing")]
+        use serde::Deserializer;
+
+        #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+        pub use super::super::option::serialize;
+        #[cfg(feature = "parsing")]
+        use super::{Lenient, Visitor};
+        #[cfg(feature = "parsing")]
+        use crate::OffsetDateTime;
+        pub use crate::serde::is_none;
+
+        /// Deserialize an [`Option<OffsetDateTime>`] from its RFC3339 or ISO 8601 representation.
+        #[cfg(feature = "parsing")]
+        pub fn deserialize<'a, D: Deserializer<'a>>(
+            deserializer: D,
+        ) -> Result<Option<OffsetDateTime>, D::Error> {
+            deserializer.deserialize_option(Visitor::<Option<Lenient>>(PhantomData))
+        }
+    }
 }
@@ -0,0 +1,79 @@
+//! Use the well-known [RFC3339 format] when serializing and deserializing an [`OffsetDateTime`],
+//! with UTC serialized as `+00:00` rather than `Z`.
+//!
+//! Deserialization accepts both forms. Use this module in combination with serde's
+//! [`#[with]`][with] attribute.
+//!
+//! [RFC3339 format]: https://tools.ietf.org/html/rfc3339#section-5.6
+//! [with]: https://serde.rs/field-attrs.html#with
+
+#[cfg(feature = "parsing")]
+use core::marker::PhantomData;
+
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use serde::ser::Error as _;
+#[cfg(feature = "parsing")]
+use serde::Deserializer;
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use serde::{Serialize, Serializer};
+
+#[cfg(feature = "parsing")]
+use super::Visitor;
+use crate::format_description::well_known::Rfc3339NoZ;
+use crate::OffsetDateTime;
+
+well_known_string!(Rfc3339NoZ = Rfc3339NoZ);
+
+/// Serialize an [`OffsetDateTime`] using the well-known RFC3339 format, with UTC as `+00:00`.
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+pub fn serialize<S: Serializer>(
+    datetime: &OffsetDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    datetime
+        .format(&Rfc3339NoZ)
+        .map_err(S::Error::custom)?
+        .serialize(serializer)
+}
+
+/// Deserialize an [`OffsetDateTime`] from its RFC3339 representation.
+#[cfg(feature = "parsing")]
+pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<OffsetDateTime, D::Error> {
+    deserializer.deserialize_str(Visitor::<Rfc3339NoZ>(PhantomData))
+}
+
+/// Use the well-known [RFC3339 format] when serializing and deserializing an
+/// [`Option<OffsetDateTime>`], with UTC serialized as `+00:00` rather than `Z`.
+///
+/// Deserialization accepts both forms. Use this module in combination with serde's
+/// [`#[with]`][with] attribute.
+///
+/// [RFC3339 format]: https://tools.ietf.org/html/rfc3339#section-5.6
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+    pub use crate::serde::is_none;
+
+    /// Serialize an [`Option<OffsetDateTime>`] using the well-known RFC3339 format, with UTC as
+    /// `+00:00`.
+    #[cfg(all(feature = "formatting-core", feature = "alloc"))]
+    pub fn serialize<S: Serializer>(
+        option: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        option
+            .map(|odt| odt.format(&Rfc3339NoZ))
+            .transpose()
+            .map_err(S::Error::custom)?
+            .serialize(serializer)
+    }
+
+    /// Deserialize an [`Option<OffsetDateTime>`] from its RFC3339 representation.
+    #[cfg(feature = "parsing")]
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        deserializer.deserialize_option(Visitor::<Option<Rfc3339NoZ>>(PhantomData))
+    }
+}
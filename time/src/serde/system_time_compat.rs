@@ -0,0 +1,280 @@
+//! Treat an [`OffsetDateTime`] as a [`SystemTime`] for the purposes of serde.
+//!
+//! Use this module in combination with serde's [`#[with]`][with] attribute.
+//!
+//! Values are represented the same way serde represents a [`SystemTime`]: a struct named
+//! `SystemTime` with the fields `secs_since_epoch` and `nanos_since_epoch`. This allows reading
+//! and writing data produced by programs that serialize a [`SystemTime`] directly.
+//!
+//! serde is unable to serialize a [`SystemTime`] before the Unix epoch. This module instead
+//! writes a negative `secs_since_epoch` in that case, with `nanos_since_epoch` counting forwards
+//! from it, so `1969-12-31 23:59:59.75 UTC` has `-1` seconds and `750_000_000` nanoseconds. The
+//! seconds are otherwise written as an unsigned integer, so non-negative values are identical to
+//! serde's output in every format. Negative seconds can be read back from self-describing formats,
+//! but are rejected by serde's own [`SystemTime`] implementation.
+//!
+//! When deserializing, the offset is assumed to be UTC. As with serde, `nanos_since_epoch` may
+//! exceed one second, in which case the excess is carried into the seconds.
+//!
+//! Also works with [`Option<OffsetDateTime>`] and `Vec<OffsetDateTime>`.
+//!
+//! ```rust
+//! # use serde::{Deserialize, Serialize};
+//! # use time::OffsetDateTime;
+//! # use time_macros::datetime;
+//! #[derive(Serialize, Deserialize)]
+//! struct Event {
+//!     #[serde(with = "time::serde::system_time_compat")]
+//!     at: OffsetDateTime,
+//! }
+//!
+//! let json = r#"{"at":{"secs_since_epoch":1714567890,"nanos_since_epoch":123}}"#;
+//! let event: Event = serde_json::from_str(json)?;
+//! assert_eq!(event.at, datetime!(2024-05-01 12:51:30.000_000_123 UTC));
+//! assert_eq!(serde_json::to_string(&event)?, json);
+//! # Ok::<_, serde_json::Error>(())
+//! ```
+//!
+//! [`SystemTime`]: https://doc.rust-lang.org/std/time/struct.SystemTime.html
+//! [with]: https://serde.rs/field-attrs.html#with
+
+use core::fmt;
+
+use serde::de::{self, MapAccess, SeqAccess, Visitor};
+use serde::ser::SerializeStruct;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{AsWellKnown, FromWellKnown};
+use crate::convert::*;
+use crate::OffsetDateTime;
+
+/// Serialize an [`OffsetDateTime`] as the fields of a `SystemTime`.
+///
+/// Also works with [`Option<OffsetDateTime>`] and `Vec<OffsetDateTime>`.
+#[inline(always)]
+pub fn serialize<S: Serializer, T>(t: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsWellKnown<SystemTimeCompat>,
+{
+    t.serialize_from_wellknown(serializer)
+}
+
+/// Deserialize an [`OffsetDateTime`] from the fields of a `SystemTime`.
+///
+/// Also works with [`Option<OffsetDateTime>`] and `Vec<OffsetDateTime>`.
+#[inline(always)]
+pub fn deserialize<'a, D: Deserializer<'a>, T>(deserializer: D) -> Result<T, D::Error>
+where
+    T: FromWellKnown<SystemTimeCompat>,
+{
+    T::deserialize_from_well_known(deserializer)
+}
+
+/// The representation of a `SystemTime` used by serde.
+pub struct SystemTimeCompat;
+
+/// The name of the struct, as used by serde.
+const NAME: &str = "SystemTime";
+/// The names of the fields, as used by serde.
+const FIELDS: &[&str] = &["secs_since_epoch", "nanos_since_epoch"];
+
+/// The offset of an instant from the Unix epoch, split as serde splits a `SystemTime`.
+#[derive(Debug, Clone, Copy)]
+pub struct EpochOffset {
+    /// The whole seconds since the Unix epoch, rounded towards negative infinity.
+    secs_since_epoch: i64,
+    /// The nanoseconds past `secs_since_epoch`.
+    nanos_since_epoch: u32,
+}
+
+impl Serialize for EpochOffset {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct(NAME, 2)?;
+        if self.secs_since_epoch >= 0 {
+            state.serialize_field(FIELDS[0], &(self.secs_since_epoch as u64))?;
+        } else {
+            state.serialize_field(FIELDS[0], &self.secs_since_epoch)?;
+        }
+        state.serialize_field(FIELDS[1], &self.nanos_since_epoch)?;
+        state.end()
+    }
+}
+
+/// The seconds since the Unix epoch, which may be negative.
+struct Secs(i64);
+
+impl<'a> Deserialize<'a> for Secs {
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        /// A visitor for seconds that may be signed or unsigned.
+        struct SecsVisitor;
+
+        impl<'a> Visitor<'a> for SecsVisitor {
+            type Value = Secs;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("an integer number of seconds")
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> Result<Secs, E> {
+                Ok(Secs(value))
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> Result<Secs, E> {
+                i64::try_from(value)
+                    .map(Secs)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+            }
+        }
+
+        // Hint unsigned, as that is what serde writes for a `SystemTime`. Self-describing formats
+        // disregard the hint and permit negative values.
+        deserializer.deserialize_u64(SecsVisitor)
+    }
+}
+
+impl<'a> Deserialize<'a> for EpochOffset {
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        /// The fields of the struct.
+        enum Field {
+            /// `secs_since_epoch`
+            Secs,
+            /// `nanos_since_epoch`
+            Nanos,
+        }
+
+        impl<'a> Deserialize<'a> for Field {
+            fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+                /// A visitor for the field names.
+                struct FieldVisitor;
+
+                impl<'a> Visitor<'a> for FieldVisitor {
+                    type Value = Field;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                        formatter.write_str("`secs_since_epoch` or `nanos_since_epoch`")
+                    }
+
+                    fn visit_str<E: de::Error>(self, value: &str) -> Result<Field, E> {
+                        match value {
+                            "secs_since_epoch" => Ok(Field::Secs),
+                            "nanos_since_epoch" => Ok(Field::Nanos),
+                            _ => Err(E::unknown_field(value, FIELDS)),
+                        }
+                    }
+                }
+
+                deserializer.deserialize_identifier(FieldVisitor)
+            }
+        }
+
+        /// A visitor for the struct.
+        struct EpochOffsetVisitor;
+
+        impl<'a> Visitor<'a> for EpochOffsetVisitor {
+            type Value = EpochOffset;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str("struct SystemTime")
+            }
+
+            fn visit_seq<A: SeqAccess<'a>>(self, mut seq: A) -> Result<EpochOffset, A::Error> {
+                let Secs(secs_since_epoch) = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+                let nanos_since_epoch = seq
+                    .next_element()?
+                    .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+                Ok(EpochOffset {
+                    secs_since_epoch,
+                    nanos_since_epoch,
+                })
+            }
+
+            fn visit_map<A: MapAccess<'a>>(self, mut map: A) -> Result<EpochOffset, A::Error> {
+                let mut secs_since_epoch = None;
+                let mut nanos_since_epoch = None;
+                while let Some(key) = map.next_key()? {
+                    match key {
+                        Field::Secs => {
+                            if secs_since_epoch.is_some() {
+                                return Err(de::Error::duplicate_field(FIELDS[0]));
+                            }
+                            let Secs(secs) = map.next_value()?;
+                            secs_since_epoch = Some(secs);
+                        }
+                        Field::Nanos => {
+                            if nanos_since_epoch.is_some() {
+                                return Err(de::Error::duplicate_field(FIELDS[1]));
+                            }
+                            nanos_since_epoch = Some(map.next_value()?);
+                        }
+                    }
+                }
+                Ok(EpochOffset {
+                    secs_since_epoch: secs_since_epoch
+                        .ok_or_else(|| de::Error::missing_field(FIELDS[0]))?,
+                    nanos_since_epoch: nanos_since_epoch
+                        .ok_or_else(|| de::Error::missing_field(FIELDS[1]))?,
+                })
+            }
+        }
+
+        deserializer.deserialize_struct(NAME, FIELDS, EpochOffsetVisitor)
+    }
+}
+
+impl AsWellKnown<SystemTimeCompat> for OffsetDateTime {
+    type IntoWellKnownError = core::convert::Infallible;
+
+    type WellKnownSer<'s> = EpochOffset where Self: 's;
+
+    fn as_well_known<'s>(&'s self) -> Result<Self::WellKnownSer<'s>, Self::IntoWellKnownError> {
+        let nanos = self.unix_timestamp_nanos();
+        Ok(EpochOffset {
+            secs_since_epoch: nanos.div_euclid(Nanosecond.per(Second) as _) as _,
+            nanos_since_epoch: nanos.rem_euclid(Nanosecond.per(Second) as _) as _,
+        })
+    }
+}
+
+impl FromWellKnown<SystemTimeCompat> for OffsetDateTime {
+    type FromWellKnownError = crate::error::ComponentRange;
+
+    type WellKnownDeser<'de> = EpochOffset;
+
+    fn from_well_known<'de>(
+        wk: Self::WellKnownDeser<'de>,
+    ) -> Result<Self, Self::FromWellKnownError> {
+        Self::from_unix_timestamp_nanos(
+            wk.secs_since_epoch as i128 * Nanosecond.per(Second) as i128
+                + wk.nanos_since_epoch as i128,
+        )
+    }
+}
+
+/// Treat an [`Option<OffsetDateTime>`] as a `SystemTime` for the purposes of serde, using the same
+/// representation as the parent module.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod option {
+    #[allow(clippy::wildcard_imports)]
+    use super::*;
+    pub use crate::serde::is_none;
+
+    /// Serialize an [`Option<OffsetDateTime>`] as the fields of a `SystemTime`.
+    pub fn serialize<S: Serializer>(
+        option: &Option<OffsetDateTime>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize(option, serializer)
+    }
+
+    /// Deserialize an [`Option<OffsetDateTime>`] from the fields of a `SystemTime`.
+    pub fn deserialize<'a, D: Deserializer<'a>>(
+        deserializer: D,
+    ) -> Result<Option<OffsetDateTime>, D::Error> {
+        super::deserialize(deserializer)
+    }
+}
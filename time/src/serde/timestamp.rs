@@ -13,6 +13,7 @@
 use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
 use super::{AsWellKnown, FromWellKnown};
+pub use crate::serde::is_none;
 use crate::{OffsetDateTime, PrimitiveDateTime};
 
 /// Serialize an [`OffsetDateTime`] and [`PrimitiveDateTime`] as its Unix timestamp
@@ -37,10 +38,43 @@ where
     T::deserialize_from_well_known(deserializer)
 }
 
+/// Serialize an `Option<Option<OffsetDateTime>>` or `Option<Option<PrimitiveDateTime>>` as its
+/// Unix timestamp. `None` and `Some(None)` are both serialized as `None`.
+///
+/// This is intended to be used with `#[serde(skip_serializing_if = "Option::is_none")]`, so that
+/// `None` is not written at all. Only do so in self-describing formats; others, such as bincode,
+/// are unable to read a struct with a field left out.
+pub fn serialize_or_default<S: Serializer, T>(
+    option: &Option<Option<T>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    T: AsWellKnown<Timestamp>,
+{
+    match option {
+        Some(option) => option.serialize_from_wellknown(serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Deserialize an `Option<Option<OffsetDateTime>>` or `Option<Option<PrimitiveDateTime>>` from its
+/// Unix timestamp.
+///
+/// This is intended to be used with `#[serde(default)]`. A missing field is then `None`, while
+/// `null` is `Some(None)`.
+pub fn deserialize_or_default<'a, D: Deserializer<'a>, T>(
+    deserializer: D,
+) -> Result<Option<Option<T>>, D::Error>
+where
+    T: FromWellKnown<Timestamp>,
+{
+    Option::<T>::deserialize_from_well_known(deserializer).map(Some)
+}
+
 pub struct Timestamp;
 
 impl AsWellKnown<Timestamp> for OffsetDateTime {
-    type IntoWellKnownError = std::convert::Infallible;
+    type IntoWellKnownError = core::convert::Infallible;
 
     type WellKnownSer<'s> = i64 where Self: 's;
 
@@ -54,19 +88,19 @@ impl FromWellKnown<Timestamp> for OffsetDateTime {
 
     type WellKnownDeser<'de> = i64;
 
-    fn fmt_err<E: serde::de::Error>(e: Self::FromWellKnownError) -> E {
-        E::invalid_value(serde::de::Unexpected::Signed(e.value), &e)
+    fn fmt_err<E: de::Error>(e: Self::FromWellKnownError) -> E {
+        E::invalid_value(de::Unexpected::Signed(e.value), &e)
     }
 
     fn from_well_known<'de>(
         wk: Self::WellKnownDeser<'de>,
     ) -> Result<Self, Self::FromWellKnownError> {
-        OffsetDateTime::from_unix_timestamp(wk)
+        Self::from_unix_timestamp(wk)
     }
 }
 
 impl AsWellKnown<Timestamp> for PrimitiveDateTime {
-    type IntoWellKnownError = std::convert::Infallible;
+    type IntoWellKnownError = core::convert::Infallible;
 
     type WellKnownSer<'s> = i64 where Self: 's;
 
@@ -87,14 +121,17 @@ impl FromWellKnown<Timestamp> for PrimitiveDateTime {
     }
 }
 
-// Treat an [`OffsetDateTime`] as a [Unix timestamp (milliseconds)] for the purposes of serde.
-//
-// Use this module in combination with serde's [`#[with]`][with] attribute.
-//
-// When deserializing, the offset is assumed to be UTC.
-//
-// [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
-// [with]: https://serde.rs/field-attrs.html#with
+/// Treat an [`OffsetDateTime`] as a [Unix timestamp] in milliseconds for the purposes of serde.
+///
+/// Prefer [`milliseconds`] for new code. It has the same integer representation and also accepts
+/// timestamps provided as floats.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// When deserializing, the offset is assumed to be UTC.
+///
+/// [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
+/// [with]: https://serde.rs/field-attrs.html#with
 pub mod millis {
     use super::*;
 
@@ -124,7 +161,7 @@ pub mod millis {
     pub struct TimestampMillis;
 
     impl AsWellKnown<TimestampMillis> for OffsetDateTime {
-        type IntoWellKnownError = std::convert::Infallible;
+        type IntoWellKnownError = core::convert::Infallible;
 
         type WellKnownSer<'s> = i64 where Self: 's;
 
@@ -138,8 +175,8 @@ pub mod millis {
 
         type WellKnownDeser<'de> = i64;
 
-        fn fmt_err<E: serde::de::Error>(e: Self::FromWellKnownError) -> E {
-            E::invalid_value(serde::de::Unexpected::Signed(e.value), &e)
+        fn fmt_err<E: de::Error>(e: Self::FromWellKnownError) -> E {
+            E::invalid_value(de::Unexpected::Signed(e.value), &e)
         }
 
         fn from_well_known<'de>(
@@ -148,12 +185,12 @@ pub mod millis {
             let secs = timestamp / 1_000;
             let millis = timestamp % 1000;
 
-            Ok(OffsetDateTime::from_unix_timestamp(secs)? + crate::Duration::milliseconds(millis))
+            Ok(Self::from_unix_timestamp(secs)? + crate::Duration::milliseconds(millis))
         }
     }
 
     impl AsWellKnown<TimestampMillis> for PrimitiveDateTime {
-        type IntoWellKnownError = std::convert::Infallible;
+        type IntoWellKnownError = core::convert::Infallible;
 
         type WellKnownSer<'s> = i64 where Self: 's;
 
@@ -177,6 +214,317 @@ pub mod millis {
     }
 }
 
+/// Deserialization of a Unix timestamp in a unit smaller than a second.
+mod units {
+    use core::fmt;
+
+    use serde::{de, Deserialize, Deserializer};
+
+    use crate::error::ComponentRange;
+    use crate::{OffsetDateTime, PrimitiveDateTime};
+
+    /// An [`OffsetDateTime`] deserialized from a Unix timestamp in units of `NANOS_PER_UNIT`
+    /// nanoseconds, given as either an integer or a float.
+    pub struct Units<const NANOS_PER_UNIT: u32>(pub(super) OffsetDateTime);
+
+    impl<'de, const NANOS_PER_UNIT: u32> Deserialize<'de> for Units<NANOS_PER_UNIT> {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            if deserializer.is_human_readable() {
+                // Let the format decide, so that floats are accepted where they can be written.
+                deserializer.deserialize_any(Visitor)
+            } else if NANOS_PER_UNIT >= 1_000_000 {
+                deserializer.deserialize_i64(Visitor)
+            } else {
+                deserializer.deserialize_i128(Visitor)
+            }
+        }
+    }
+
+    /// A serde visitor for [`Units`].
+    struct Visitor<const NANOS_PER_UNIT: u32>;
+
+    impl<const NANOS_PER_UNIT: u32> Visitor<NANOS_PER_UNIT> {
+        /// The name of the unit, for use in error messages.
+        const UNIT: &'static str = match NANOS_PER_UNIT {
+            1_000_000 => "milliseconds",
+            1_000 => "microseconds",
+            _ => "nanoseconds",
+        };
+
+        /// The range of nanoseconds representable by an `OffsetDateTime`.
+        const fn range() -> core::ops::RangeInclusive<i128> {
+            PrimitiveDateTime::MIN.assume_utc().unix_timestamp_nanos()
+                ..=PrimitiveDateTime::MAX.assume_utc().unix_timestamp_nanos()
+        }
+
+        /// Construct the value from a number of nanoseconds, which was provided as `unexpected`.
+        fn nanos_to_value<E: de::Error>(
+            self,
+            nanoseconds: Option<i128>,
+            unexpected: de::Unexpected<'_>,
+        ) -> Result<Units<NANOS_PER_UNIT>, E> {
+            match nanoseconds {
+                Some(nanoseconds) if Self::range().contains(&nanoseconds) => {
+                    OffsetDateTime::from_unix_timestamp_nanos(nanoseconds)
+                        .map(Units)
+                        .map_err(ComponentRange::into_de_error)
+                }
+                _ => Err(E::invalid_value(unexpected, &self)),
+            }
+        }
+    }
+
+    impl<'de, const NANOS_PER_UNIT: u32> de::Visitor<'de> for Visitor<NANOS_PER_UNIT> {
+        type Value = Units<NANOS_PER_UNIT>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+            let range = Self::range();
+            write!(
+                formatter,
+                "a Unix timestamp in {} in the range {}..={}",
+                Self::UNIT,
+                range.start() / i128::from(NANOS_PER_UNIT),
+                range.end() / i128::from(NANOS_PER_UNIT),
+            )
+        }
+
+        fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+            self.visit_i128(value.into())
+        }
+
+        fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+            self.visit_i128(value.into())
+        }
+
+        fn visit_i128<E: de::Error>(self, value: i128) -> Result<Self::Value, E> {
+            let unexpected = i64::try_from(value)
+                .map_or(de::Unexpected::Other("integer"), de::Unexpected::Signed);
+            self.nanos_to_value(value.checked_mul(NANOS_PER_UNIT.into()), unexpected)
+        }
+
+        fn visit_u128<E: de::Error>(self, value: u128) -> Result<Self::Value, E> {
+            match i128::try_from(value) {
+                Ok(value) => self.visit_i128(value),
+                Err(_) => self.nanos_to_value(None, de::Unexpected::Other("integer")),
+            }
+        }
+
+        fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+            let nanoseconds = if value.is_finite() {
+                // Split off the whole units before scaling, as the product would not be precise
+                // to the nanosecond. The casts saturate, so anything beyond the range of an `i128`
+                // is caught by the checked arithmetic or the range check.
+                let whole = value as i128;
+                let fraction = (value - whole as f64) * f64::from(NANOS_PER_UNIT);
+                let truncated = fraction as i128;
+                // Round to the nearest nanosecond, with ties away from zero.
+                let rounded = match fraction - truncated as f64 {
+                    remainder if remainder >= 0.5 => truncated.saturating_add(1),
+                    remainder if remainder <= -0.5 => truncated.saturating_sub(1),
+                    _ => truncated,
+                };
+                whole
+                    .checked_mul(NANOS_PER_UNIT.into())
+                    .and_then(|whole| whole.checked_add(rounded))
+            } else {
+                None
+            };
+            self.nanos_to_value(nanoseconds, de::Unexpected::Float(value))
+        }
+    }
+}
+
+/// Generate a module treating an `OffsetDateTime` as a Unix timestamp in the given unit.
+macro_rules! timestamp_module {
+    (
+        $(#[$doc:meta])*
+        mod $module:ident: $repr:ident as $ty:ty = $nanos_per_unit:literal;
+        unit = $unit:literal;
+    ) => {
+        $(#[$doc])*
+        ///
+        /// When deserializing, the offset is assumed to be UTC. Human-readable formats may provide
+        /// the timestamp as a float, which is rounded to the nearest nanosecond, with ties rounded
+        /// away from zero. Some formats, including JSON as read by `serde_json`, provide integers
+        /// that do not fit in 64 bits as floats, so such timestamps may lose precision. Timestamps
+        /// outside the range of an `OffsetDateTime` are rejected.
+        ///
+        /// Also works with [`PrimitiveDateTime`](crate::PrimitiveDateTime),
+        /// [`Option<OffsetDateTime>`] and `Vec<OffsetDateTime>`.
+        ///
+        /// [Unix timestamp]: https://en.wikipedia.org/wiki/Unix_time
+        /// [with]: https://serde.rs/field-attrs.html#with
+        pub mod $module {
+            use serde::{Deserializer, Serializer};
+
+            use super::units::Units;
+            use crate::serde::{AsWellKnown, FromWellKnown};
+            use crate::{OffsetDateTime, PrimitiveDateTime};
+
+            #[doc = concat!(
+                "Serialize an [`OffsetDateTime`] as its Unix timestamp in ", $unit, "."
+            )]
+            ///
+            /// Also works with [`Option<OffsetDateTime>`] and `Vec<OffsetDateTime>`.
+            #[inline(always)]
+            pub fn serialize<S: Serializer, T>(t: &T, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                T: AsWellKnown<$repr>,
+            {
+                t.serialize_from_wellknown(serializer)
+            }
+
+            #[doc = concat!(
+                "Deserialize an [`OffsetDateTime`] from its Unix timestamp in ", $unit, "."
+            )]
+            ///
+            /// Also works with [`Option<OffsetDateTime>`] and `Vec<OffsetDateTime>`.
+            #[inline(always)]
+            pub fn deserialize<'a, D: Deserializer<'a>, T>(deserializer: D) -> Result<T, D::Error>
+            where
+                T: FromWellKnown<$repr>,
+            {
+                T::deserialize_from_well_known(deserializer)
+            }
+
+            #[doc = concat!("The representation of a Unix timestamp in ", $unit, ".")]
+            #[derive(Debug, Clone, Copy)]
+            pub struct $repr;
+
+            impl AsWellKnown<$repr> for OffsetDateTime {
+                type IntoWellKnownError = core::convert::Infallible;
+
+                type WellKnownSer<'s> = $ty where Self: 's;
+
+                // Milliseconds always fit in an `i64`, and the cast is a no-op for other units.
+                #[allow(trivial_numeric_casts)]
+                fn as_well_known<'s>(
+                    &'s self,
+                ) -> Result<Self::WellKnownSer<'s>, Self::IntoWellKnownError> {
+                    Ok((self.unix_timestamp_nanos() / $nanos_per_unit) as $ty)
+                }
+            }
+
+            impl FromWellKnown<$repr> for OffsetDateTime {
+                type FromWellKnownError = core::convert::Infallible;
+
+                type WellKnownDeser<'de> = Units<$nanos_per_unit>;
+
+                fn from_well_known<'de>(
+                    wk: Self::WellKnownDeser<'de>,
+                ) -> Result<Self, Self::FromWellKnownError> {
+                    Ok(wk.0)
+                }
+            }
+
+            impl AsWellKnown<$repr> for PrimitiveDateTime {
+                type IntoWellKnownError = core::convert::Infallible;
+
+                type WellKnownSer<'s> = $ty where Self: 's;
+
+                // Milliseconds always fit in an `i64`, and the cast is a no-op for other units.
+                #[allow(trivial_numeric_casts)]
+                fn as_well_known<'s>(
+                    &'s self,
+                ) -> Result<Self::WellKnownSer<'s>, Self::IntoWellKnownError> {
+                    Ok((self.assume_utc().unix_timestamp_nanos() / $nanos_per_unit) as $ty)
+                }
+            }
+
+            impl FromWellKnown<$repr> for PrimitiveDateTime {
+                type FromWellKnownError = core::convert::Infallible;
+
+                type WellKnownDeser<'de> = Units<$nanos_per_unit>;
+
+                fn from_well_known<'de>(
+                    wk: Self::WellKnownDeser<'de>,
+                ) -> Result<Self, Self::FromWellKnownError> {
+                    Ok(wk.0.date().with_time(wk.0.time()))
+                }
+            }
+
+            #[doc = concat!(
+                "Treat an [`Option<OffsetDateTime>`] as a Unix timestamp in ", $unit, " for the ",
+                "purposes of serde, using the same representation as the parent module."
+            )]
+            ///
+            /// Use this module in combination with serde's [`#[with]`][with] attribute.
+            ///
+            /// [with]: https://serde.rs/field-attrs.html#with
+            pub mod option {
+                #[allow(clippy::wildcard_imports)]
+                use super::*;
+                pub use crate::serde::is_none;
+
+                #[doc = concat!(
+                    "Serialize an [`Option<OffsetDateTime>`] as its Unix timestamp in ", $unit, "."
+                )]
+                pub fn serialize<S: Serializer>(
+                    option: &Option<OffsetDateTime>,
+                    serializer: S,
+                ) -> Result<S::Ok, S::Error> {
+                    super::serialize(option, serializer)
+                }
+
+                #[doc = concat!(
+                    "Deserialize an [`Option<OffsetDateTime>`] from its Unix timestamp in ", $unit,
+                    "."
+                )]
+                pub fn deserialize<'a, D: Deserializer<'a>>(
+                    deserializer: D,
+                ) -> Result<Option<OffsetDateTime>, D::Error> {
+                    super::deserialize(deserializer)
+                }
+            }
+        }
+    };
+}
+
+timestamp_module! {
+    /// Treat an [`OffsetDateTime`] as a [Unix timestamp] in milliseconds, represented as an `i64`,
+    /// for the purposes of serde. This matches JavaScript's `Date.now()`.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    ///
+    /// ```rust
+    /// # use serde::{Deserialize, Serialize};
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// #[derive(Serialize, Deserialize)]
+    /// struct Event {
+    ///     #[serde(with = "time::serde::timestamp::milliseconds")]
+    ///     at: OffsetDateTime,
+    /// }
+    ///
+    /// let json = serde_json::to_string(&Event { at: datetime!(2024-05-01 12:51:30.123456 UTC) })?;
+    /// assert_eq!(json, r#"{"at":1714567890123}"#);
+    ///
+    /// let event: Event = serde_json::from_str(r#"{"at":1714567890123.5}"#)?;
+    /// assert_eq!(event.at, datetime!(2024-05-01 12:51:30.1235 UTC));
+    /// # Ok::<_, serde_json::Error>(())
+    /// ```
+    mod milliseconds: Milliseconds as i64 = 1_000_000;
+    unit = "milliseconds";
+}
+
+timestamp_module! {
+    /// Treat an [`OffsetDateTime`] as a [Unix timestamp] in microseconds, represented as an
+    /// `i128`, for the purposes of serde.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    mod microseconds: Microseconds as i128 = 1_000;
+    unit = "microseconds";
+}
+
+timestamp_module! {
+    /// Treat an [`OffsetDateTime`] as a [Unix timestamp] in nanoseconds, represented as an
+    /// `i128`, for the purposes of serde.
+    ///
+    /// Use this module in combination with serde's [`#[with]`][with] attribute.
+    mod nanoseconds: Nanoseconds as i128 = 1;
+    unit = "nanoseconds";
+}
+
 /// Treat an `Option<OffsetDateTime>` as a [Unix timestamp] for the purposes of
 /// serde.
 ///
@@ -20,6 +20,9 @@ use crate::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcO
 /// A serde visitor for various types.
 pub(super) struct Visitor<T: ?Sized>(pub(super) PhantomData<T>);
 
+/// A [`Weekday`] represented by its number of days from Sunday, with Sunday being zero.
+pub(super) enum WeekdayFromSunday {}
+
 impl<'a> de::Visitor<'a> for Visitor<Date> {
     type Value = Date;
 
@@ -51,14 +54,22 @@ impl<'a> de::Visitor<'a> for Visitor<Duration> {
             de::Error::invalid_value(de::Unexpected::Str(value), &"a decimal point")
         })?;
 
+        // Check the sign of the input rather than the parsed value, as "-0" is parsed as zero.
+        let is_negative = seconds.starts_with('-');
         let seconds = seconds
             .parse()
             .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(seconds), &"seconds"))?;
-        let mut nanoseconds = nanoseconds.parse().map_err(|_| {
-            de::Error::invalid_value(de::Unexpected::Str(nanoseconds), &"nanoseconds")
-        })?;
-
-        if seconds < 0 {
+        // The sign is only permitted on the seconds; `-0.-1` would otherwise be positive.
+        let mut nanoseconds = nanoseconds
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+            .then(|| nanoseconds.parse().ok())
+            .flatten()
+            .ok_or_else(|| {
+                de::Error::invalid_value(de::Unexpected::Str(nanoseconds), &"nanoseconds")
+            })?;
+
+        if is_negative {
             nanoseconds *= -1;
         }
 
@@ -81,6 +92,12 @@ impl<'a> de::Visitor<'a> for Visitor<OffsetDateTime> {
 
     #[cfg(feature = "parsing")]
     fn visit_str<E: de::Error>(self, value: &str) -> Result<OffsetDateTime, E> {
+        // The default representation always contains a space, while RFC 3339 never does. It is
+        // still accepted so that previously serialized values can be read, and any error is
+        // reported for the representation the input was meant to be in.
+        if cfg!(feature = "serde-well-known-default") && !value.contains(' ') {
+            return OffsetDateTime::parse(value, &Rfc3339).map_err(E::custom);
+        }
         OffsetDateTime::parse(value, &OFFSET_DATE_TIME_FORMAT).map_err(E::custom)
     }
 
@@ -174,6 +191,27 @@ impl<'a> de::Visitor<'a> for Visitor<UtcOffset> {
     }
 }
 
+/// Find the weekday with the given name, either in full or as its three-letter abbreviation. The
+/// comparison is case-insensitive.
+fn weekday_from_name(name: &str) -> Option<Weekday> {
+    core::iter::successors(Some(Weekday::Monday), |weekday| Some(weekday.next()))
+        .take(7)
+        .find(|weekday| {
+            name.eq_ignore_ascii_case(weekday.name())
+                || name.eq_ignore_ascii_case(weekday.short_name())
+        })
+}
+
+/// Find the month with the given name, either in full or as its three-letter abbreviation. The
+/// comparison is case-insensitive.
+fn month_from_name(name: &str) -> Option<Month> {
+    core::iter::successors(Some(Month::January), |month| Some(month.next()))
+        .take(12)
+        .find(|month| {
+            name.eq_ignore_ascii_case(month.name()) || name.eq_ignore_ascii_case(month.short_name())
+        })
+}
+
 impl<'a> de::Visitor<'a> for Visitor<Weekday> {
     type Value = Weekday;
 
@@ -182,35 +220,68 @@ impl<'a> de::Visitor<'a> for Visitor<Weekday> {
     }
 
     fn visit_str<E: de::Error>(self, value: &str) -> Result<Weekday, E> {
-        match value {
-            "Monday" => Ok(Weekday::Monday),
-            "Tuesday" => Ok(Weekday::Tuesday),
-            "Wednesday" => Ok(Weekday::Wednesday),
-            "Thursday" => Ok(Weekday::Thursday),
-            "Friday" => Ok(Weekday::Friday),
-            "Saturday" => Ok(Weekday::Saturday),
-            "Sunday" => Ok(Weekday::Sunday),
-            _ => Err(E::invalid_value(de::Unexpected::Str(value), &"a `Weekday`")),
-        }
+        weekday_from_name(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &"a `Weekday`"))
     }
 
     fn visit_u64<E: de::Error>(self, value: u64) -> Result<Weekday, E> {
-        match value {
-            1 => Ok(Weekday::Monday),
-            2 => Ok(Weekday::Tuesday),
-            3 => Ok(Weekday::Wednesday),
-            4 => Ok(Weekday::Thursday),
-            5 => Ok(Weekday::Friday),
-            6 => Ok(Weekday::Saturday),
-            7 => Ok(Weekday::Sunday),
-            _ => Err(E::invalid_value(
-                de::Unexpected::Unsigned(value),
+        u8::try_from(value)
+            .ok()
+            .and_then(|value| Weekday::from_number_from_monday(value).ok())
+            .ok_or_else(|| {
+                E::invalid_value(
+                    de::Unexpected::Unsigned(value),
+                    &"a value in the range 1..=7",
+                )
+            })
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Weekday, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(
+                de::Unexpected::Signed(value),
                 &"a value in the range 1..=7",
             )),
         }
     }
 }
 
+impl<'a> de::Visitor<'a> for Visitor<WeekdayFromSunday> {
+    type Value = Weekday;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a `Weekday`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Weekday, E> {
+        weekday_from_name(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &"a `Weekday`"))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Weekday, E> {
+        u8::try_from(value)
+            .ok()
+            .and_then(|value| Weekday::from_number_days_from_sunday(value).ok())
+            .ok_or_else(|| {
+                E::invalid_value(
+                    de::Unexpected::Unsigned(value),
+                    &"a value in the range 0..=6",
+                )
+            })
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Weekday, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(
+                de::Unexpected::Signed(value),
+                &"a value in the range 0..=6",
+            )),
+        }
+    }
+}
+
 impl<'a> de::Visitor<'a> for Visitor<Month> {
     type Value = Month;
 
@@ -219,39 +290,27 @@ impl<'a> de::Visitor<'a> for Visitor<Month> {
     }
 
     fn visit_str<E: de::Error>(self, value: &str) -> Result<Month, E> {
-        match value {
-            "January" => Ok(Month::January),
-            "February" => Ok(Month::February),
-            "March" => Ok(Month::March),
-            "April" => Ok(Month::April),
-            "May" => Ok(Month::May),
-            "June" => Ok(Month::June),
-            "July" => Ok(Month::July),
-            "August" => Ok(Month::August),
-            "September" => Ok(Month::September),
-            "October" => Ok(Month::October),
-            "November" => Ok(Month::November),
-            "December" => Ok(Month::December),
-            _ => Err(E::invalid_value(de::Unexpected::Str(value), &"a `Month`")),
-        }
+        month_from_name(value)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Str(value), &"a `Month`"))
     }
 
     fn visit_u64<E: de::Error>(self, value: u64) -> Result<Month, E> {
-        match value {
-            1 => Ok(Month::January),
-            2 => Ok(Month::February),
-            3 => Ok(Month::March),
-            4 => Ok(Month::April),
-            5 => Ok(Month::May),
-            6 => Ok(Month::June),
-            7 => Ok(Month::July),
-            8 => Ok(Month::August),
-            9 => Ok(Month::September),
-            10 => Ok(Month::October),
-            11 => Ok(Month::November),
-            12 => Ok(Month::December),
-            _ => Err(E::invalid_value(
-                de::Unexpected::Unsigned(value),
+        u8::try_from(value)
+            .ok()
+            .and_then(|value| Month::from_number(value).ok())
+            .ok_or_else(|| {
+                E::invalid_value(
+                    de::Unexpected::Unsigned(value),
+                    &"a value in the range 1..=12",
+                )
+            })
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Month, E> {
+        match u64::try_from(value) {
+            Ok(value) => self.visit_u64(value),
+            Err(_) => Err(E::invalid_value(
+                de::Unexpected::Signed(value),
                 &"a value in the range 1..=12",
             )),
         }
@@ -292,7 +351,7 @@ macro_rules! well_known {
                 deserializer: D,
             ) -> Result<Option<OffsetDateTime>, D::Error> {
                 deserializer
-                    .deserialize_any(Visitor::<$($ty)+>(PhantomData))
+                    .deserialize_str(Visitor::<$($ty)+>(PhantomData))
                     .map(Some)
             }
 
@@ -309,6 +368,7 @@ macro_rules! well_known {
 
 well_known!("an", "RFC2822", Rfc2822);
 well_known!("an", "RFC3339", Rfc3339);
+well_known!("an", "RFC3339", Rfc3339NoZ);
 well_known!(
     "an",
     "ISO 8601",
@@ -0,0 +1,53 @@
+//! Serde helpers for [`Weekday`](crate::Weekday) values.
+
+/// Treat a [`Weekday`](crate::Weekday) as its number of days from Sunday for the purposes of serde.
+///
+/// Use this module in combination with serde's [`#[with]`][with] attribute.
+///
+/// Sunday is represented as `0` and Saturday as `6`, matching
+/// [`Weekday::number_days_from_sunday`](crate::Weekday::number_days_from_sunday). When
+/// deserializing, the full or abbreviated name of the weekday is also accepted, ignoring case.
+///
+/// ```rust
+/// # use serde::{Deserialize, Serialize};
+/// # use time::Weekday;
+/// #[derive(Serialize, Deserialize)]
+/// struct Schedule {
+///     #[serde(with = "time::serde::weekday::number_days_from_sunday")]
+///     day: Weekday,
+/// }
+///
+/// let json = serde_json::to_string(&Schedule { day: Weekday::Sunday })?;
+/// assert_eq!(json, r#"{"day":0}"#);
+///
+/// let schedule: Schedule = serde_json::from_str(r#"{"day":"wed"}"#)?;
+/// assert_eq!(schedule.day, Weekday::Wednesday);
+/// # Ok::<_, serde_json::Error>(())
+/// ```
+///
+/// [with]: https://serde.rs/field-attrs.html#with
+pub mod number_days_from_sunday {
+    use core::marker::PhantomData;
+
+    use serde::{Deserializer, Serializer};
+
+    use crate::serde::visitor::{Visitor, WeekdayFromSunday};
+    use crate::Weekday;
+
+    /// Serialize a [`Weekday`] as its number of days from Sunday.
+    pub fn serialize<S: Serializer>(weekday: &Weekday, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u8(weekday.number_days_from_sunday())
+    }
+
+    /// Deserialize a [`Weekday`] from its number of days from Sunday or its name.
+    pub fn deserialize<'a, D: Deserializer<'a>>(deserializer: D) -> Result<Weekday, D::Error> {
+        let visitor = Visitor::<WeekdayFromSunday>(PhantomData);
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_u8(visitor)
+        }
+    }
+
+    option_module!(Weekday, "number of days from Sunday");
+}
@@ -1,7 +1,7 @@
 #![cfg(all( // require `--all-features` to be passed
     feature = "default",
     feature = "alloc",
-    feature = "formatting",
+    feature = "formatting-core",
     feature = "large-dates",
     feature = "local-offset",
     feature = "macros",
@@ -83,16 +83,11 @@ fn default() {
 fn debug() {
     let _ = format!("{:?}", duration::Padding::Optimize);
     let _ = format!("{:?}", parsing::ParsedItem(b"", 0));
-    let _ = format!("{:?}", parsing::component::Period::Am);
     let _ = format!("{:?}", iso8601::ExtendedKind::Basic);
 }
 
 #[test]
 fn clone() {
-    assert_eq!(
-        parsing::component::Period::Am.clone(),
-        parsing::component::Period::Am
-    );
     // does not impl Debug
     assert!(crate::time::Padding::Optimize.clone() == crate::time::Padding::Optimize);
     // does not impl PartialEq
@@ -1,14 +1,19 @@
 //! The [`Time`] struct and its associated `impl`s.
 
+use core::cmp::Ordering;
 use core::fmt;
 use core::ops::{Add, Sub};
+#[cfg(feature = "parsing")]
+use core::str::FromStr;
 use core::time::Duration as StdDuration;
-#[cfg(feature = "formatting")]
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(all(feature = "formatting-core", feature = "std"))]
 use std::io;
 
 use crate::convert::*;
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{ArrayBuf, Discard, FmtWriter, Formattable, Formatted, SliceWriter};
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::util::DateAdjustment;
@@ -23,6 +28,14 @@ pub(crate) enum Padding {
     Optimize,
 }
 
+/// Whether a time of day is before or after noon, as used by the 12-hour clock.
+#[allow(clippy::missing_docs_in_private_items)] // variants
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Period {
+    Am,
+    Pm,
+}
+
 /// The clock time within a given date. Nanosecond precision.
 ///
 /// All minutes are assumed to have exactly 60 seconds; no attempt is made to handle leap seconds
@@ -106,6 +119,83 @@ impl Time {
         Ok(Self::__from_hms_nanos_unchecked(hour, minute, second, 0))
     }
 
+    /// Create a `Time` from the hour, minute, and second, clamping any out-of-range value to the
+    /// largest valid one.
+    ///
+    /// Unlike [`Time::from_hms`], this never fails. This is intended for handling user input.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// assert_eq!(Time::from_hms_clamped(1, 2, 3), time!(1:02:03));
+    /// assert_eq!(Time::from_hms_clamped(24, 0, 0), time!(23:00));
+    /// assert_eq!(Time::from_hms_clamped(12, 75, 99), time!(12:59:59));
+    /// ```
+    pub const fn from_hms_clamped(hour: u8, minute: u8, second: u8) -> Self {
+        Self::from_hms_clamped_with_flag(hour, minute, second).0
+    }
+
+    /// Create a `Time` from the hour, minute, and second, clamping any out-of-range value to the
+    /// largest valid one. The returned boolean is `true` if any value was clamped.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// assert_eq!(Time::from_hms_clamped_with_flag(0, 60, 0), (time!(0:59), true));
+    /// assert_eq!(Time::from_hms_clamped_with_flag(0, 59, 0), (time!(0:59), false));
+    /// ```
+    pub const fn from_hms_clamped_with_flag(hour: u8, minute: u8, second: u8) -> (Self, bool) {
+        /// Clamp the value to be at most `max`, returning whether it was clamped.
+        const fn clamp(value: u8, max: u8) -> (u8, bool) {
+            if value > max {
+                (max, true)
+            } else {
+                (value, false)
+            }
+        }
+
+        let (hour, hour_clamped) = clamp(hour, Hour.per(Day) - 1);
+        let (minute, minute_clamped) = clamp(minute, Minute.per(Hour) - 1);
+        let (second, second_clamped) = clamp(second, Second.per(Minute) - 1);
+        (
+            Self::__from_hms_nanos_unchecked(hour, minute, second, 0),
+            hour_clamped || minute_clamped || second_clamped,
+        )
+    }
+
+    /// Attempt to create a `Time` from an hour on the 12-hour clock, the minute, the second, and
+    /// whether the time is before or after noon. Midnight is 12 AM and noon is 12 PM.
+    ///
+    /// ```rust
+    /// # use time::{Period, Time};
+    /// # use time_macros::time;
+    /// assert_eq!(Time::from_hms_12(12, 0, 0, Period::Am), Ok(time!(0:00)));
+    /// assert_eq!(Time::from_hms_12(1, 2, 3, Period::Pm), Ok(time!(13:02:03)));
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::{Period, Time};
+    /// assert!(Time::from_hms_12(0, 0, 0, Period::Am).is_err()); // 0 isn't a valid hour.
+    /// assert!(Time::from_hms_12(13, 0, 0, Period::Pm).is_err()); // 13 isn't a valid hour.
+    /// ```
+    pub const fn from_hms_12(
+        hour: u8,
+        minute: u8,
+        second: u8,
+        period: Period,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(hour in 1 => 12);
+        ensure_value_in_range!(minute in 0 => Minute.per(Hour) - 1);
+        ensure_value_in_range!(second in 0 => Second.per(Minute) - 1);
+        let hour = match (hour, period) {
+            (12, Period::Am) => 0,
+            (12, Period::Pm) => 12,
+            (hour, Period::Am) => hour,
+            (hour, Period::Pm) => hour + 12,
+        };
+        Ok(Self::__from_hms_nanos_unchecked(hour, minute, second, 0))
+    }
+
     /// Attempt to create a `Time` from the hour, minute, second, and millisecond.
     ///
     /// ```rust
@@ -274,6 +364,42 @@ impl Time {
         self.hour
     }
 
+    /// Get the hour on the 12-hour clock, along with whether it is before or after noon.
+    ///
+    /// The returned hour will always be in the range `1..=12`.
+    ///
+    /// ```rust
+    /// # use time::Period;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(0:00).hour_12(), (12, Period::Am));
+    /// assert_eq!(time!(11:59).hour_12(), (11, Period::Am));
+    /// assert_eq!(time!(12:00).hour_12(), (12, Period::Pm));
+    /// assert_eq!(time!(23:59).hour_12(), (11, Period::Pm));
+    /// ```
+    pub const fn hour_12(self) -> (u8, Period) {
+        let hour = match self.hour % 12 {
+            0 => 12,
+            hour => hour,
+        };
+        (hour, self.period())
+    }
+
+    /// Get whether the time is before or after noon.
+    ///
+    /// ```rust
+    /// # use time::Period;
+    /// # use time_macros::time;
+    /// assert_eq!(time!(11:59:59.999_999_999).period(), Period::Am);
+    /// assert_eq!(time!(12:00).period(), Period::Pm);
+    /// ```
+    pub const fn period(self) -> Period {
+        if self.hour < 12 {
+            Period::Am
+        } else {
+            Period::Pm
+        }
+    }
+
     /// Get the minute within the hour.
     ///
     /// The returned value will always be in the range `0..60`.
@@ -340,6 +466,49 @@ impl Time {
     }
     // endregion getters
 
+    // region: packed encoding
+    /// Encode the time as a `u64`, for use in fixed-width storage.
+    ///
+    /// The value is the number of nanoseconds since midnight. This layout is stable and will not
+    /// change. Values sort in chronological order.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// assert_eq!(time!(0:00).to_packed_u64(), 0);
+    /// assert_eq!(time!(1:02:03.004).to_packed_u64(), 3_723_004_000_000);
+    /// assert_eq!(time!(23:59:59.999_999_999).to_packed_u64(), 86_399_999_999_999);
+    /// ```
+    pub const fn to_packed_u64(self) -> u64 {
+        (self.hour as u64 * Second.per(Hour) as u64
+            + self.minute as u64 * Second.per(Minute) as u64
+            + self.second as u64)
+            * Nanosecond.per(Second) as u64
+            + self.nanosecond as u64
+    }
+
+    /// Attempt to decode a time that was encoded with [`Time::to_packed_u64`].
+    ///
+    /// An error is returned if the value is not less than the number of nanoseconds in a day.
+    ///
+    /// ```rust
+    /// # use time::Time;
+    /// # use time_macros::time;
+    /// assert_eq!(Time::from_packed_u64(3_723_004_000_000), Ok(time!(1:02:03.004)));
+    /// assert!(Time::from_packed_u64(86_400_000_000_000).is_err());
+    /// ```
+    pub const fn from_packed_u64(packed: u64) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(packed in 0 => Nanosecond.per(Day) - 1);
+
+        let seconds = packed / Nanosecond.per(Second) as u64;
+        Ok(Self::__from_hms_nanos_unchecked(
+            (seconds / Second.per(Hour) as u64) as _,
+            (seconds % Second.per(Hour) as u64 / Second.per(Minute) as u64) as _,
+            (seconds % Second.per(Minute) as u64) as _,
+            (packed % Nanosecond.per(Second) as u64) as _,
+        ))
+    }
+    // endregion packed encoding
+
     // region: arithmetic helpers
     /// Add the sub-day time of the [`Duration`] to the `Time`. Wraps on overflow, returning whether
     /// the date is different.
@@ -458,6 +627,84 @@ impl Time {
     }
     // endregion arithmetic helpers
 
+    // region: comparison
+    /// Compare two `Time`s. This is equivalent to [`Ord::cmp`], but usable in `const` contexts.
+    pub(crate) const fn cmp_const(self, other: Self) -> Ordering {
+        const_cmp!(
+            self.hour => other.hour,
+            self.minute => other.minute,
+            self.second => other.second,
+            self.nanosecond => other.nanosecond,
+        )
+    }
+
+    /// Returns the earlier of the two times. Unlike [`Ord::min`], this is usable in `const`
+    /// contexts.
+    ///
+    /// If the two values are equal, `self` is returned.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// let (low, high) = (time!(1:00), time!(23:59));
+    /// assert_eq!(low.min(high), low);
+    /// assert_eq!(high.min(low), low);
+    /// ```
+    pub const fn min(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            other
+        } else {
+            self
+        }
+    }
+
+    /// Returns the later of the two times. Unlike [`Ord::max`], this is usable in `const`
+    /// contexts.
+    ///
+    /// If the two values are equal, `other` is returned, as with [`Ord::max`].
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// let (low, high) = (time!(1:00), time!(23:59));
+    /// assert_eq!(low.max(high), high);
+    /// assert_eq!(high.max(low), high);
+    /// ```
+    pub const fn max(self, other: Self) -> Self {
+        if self.cmp_const(other).is_gt() {
+            self
+        } else {
+            other
+        }
+    }
+
+    /// Restrict the time to the range `min..=max`. Unlike [`Ord::clamp`], this is usable in
+    /// `const` contexts.
+    ///
+    /// ```rust
+    /// # use time_macros::time;
+    /// let (low, middle, high) = (time!(1:00), time!(12:30), time!(23:59));
+    /// assert_eq!(low.clamp(middle, high), middle);
+    /// assert_eq!(middle.clamp(low, high), middle);
+    /// assert_eq!(high.clamp(low, middle), middle);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// This function panics if `min` is greater than `max`.
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(
+            min.cmp_const(max).is_le(),
+            "`min` must be less than or equal to `max`"
+        );
+        if self.cmp_const(min).is_lt() {
+            min
+        } else if self.cmp_const(max).is_gt() {
+            max
+        } else {
+            self
+        }
+    }
+    // endregion comparison
+
     // region: replacement
     /// Replace the clock hour.
     ///
@@ -594,17 +841,111 @@ impl Time {
 }
 
 // region: formatting & parsing
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl Time {
     /// Format the `Time` using the provided [format description](crate::format_description).
+    #[cfg(feature = "std")]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
         format: &(impl Formattable + ?Sized),
-    ) -> Result<usize, crate::error::Format> {
+    ) -> Result<usize, error::Format> {
         format.format_into(output, None, Some(self), None)
     }
 
+    /// Format the `Time` using the provided [format description](crate::format_description),
+    /// writing to any [`fmt::Write`], such as a [`fmt::Formatter`]. This allows a format
+    /// description to be reused from a `Display` implementation, or in `no_std` environments,
+    /// without allocating.
+    ///
+    /// ```rust
+    /// # use core::fmt;
+    /// # use time::Time;
+    /// # use time_macros::{format_description, time};
+    /// struct Wrapper(Time);
+    ///
+    /// impl fmt::Display for Wrapper {
+    ///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    ///         self.0
+    ///             .format_into_fmt(f, format_description!("[hour]:[minute]"))
+    ///             .map(|_| ())
+    ///             .map_err(|_| fmt::Error)
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Wrapper(time!(12:34)).to_string(), "12:34");
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut FmtWriter(output), None, Some(self), None)
+    }
+
+    /// Pair the `Time` with a [format description](crate::format_description), returning a value
+    /// that is formatted directly into the output when displayed. See [`Formatted`] for details.
+    ///
+    /// ```rust
+    /// # use time_macros::{format_description, time};
+    /// let time = time!(12:34);
+    /// assert_eq!(
+    ///     format!("at {}", time.display(format_description!("[hour]:[minute]"))),
+    ///     "at 12:34"
+    /// );
+    /// ```
+    pub const fn display<F: Formattable + ?Sized>(self, format: &F) -> Formatted<'_, F> {
+        Formatted::new(format, None, Some(self), None)
+    }
+
+    /// Format the `Time` using the provided [format description](crate::format_description),
+    /// storing the output in a fixed-capacity [`ArrayBuf`] rather than allocating.
+    ///
+    /// ```rust
+    /// # use time_macros::{format_description, time};
+    /// let output = time!(12:34).format_to_array::<8>(format_description!("[hour]:[minute]"))?;
+    /// assert_eq!(output, "12:34");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_array<const N: usize>(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<ArrayBuf<N>, error::Format> {
+        ArrayBuf::format(format, None, Some(self), None)
+    }
+
+    /// Format the `Time` using the provided [format description](crate::format_description),
+    /// writing to a caller-provided byte slice. The number of bytes written is returned.
+    ///
+    /// If the output does not fit, [`error::Format::InsufficientCapacity`] is returned with the
+    /// number of bytes required, and the contents of the buffer are unspecified. The required
+    /// size can be determined in advance with [`Time::formatted_len`].
+    ///
+    /// ```rust
+    /// # use time_macros::{format_description, time};
+    /// let format = format_description!("[hour]:[minute]");
+    /// let mut buf = [0; 8];
+    /// let len = time!(12:34).format_into_slice(&mut buf, format)?;
+    /// assert_eq!(&buf[..len], b"12:34");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_slice(
+        self,
+        buf: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        SliceWriter::format(format, buf, None, Some(self), None)
+    }
+
+    /// Determine the number of bytes that formatting the `Time` using the provided [format
+    /// description](crate::format_description) would produce, without writing it anywhere.
+    pub fn formatted_len(
+        self,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut Discard, None, Some(self), None)
+    }
+
     /// Format the `Time` using the provided [format description](crate::format_description).
     ///
     /// ```rust
@@ -614,10 +955,11 @@ impl Time {
     /// assert_eq!(time!(12:00).format(&format)?, "12:00:00");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn format(
         self,
         format: &(impl Formattable + ?Sized),
-    ) -> Result<String, crate::error::Format> {
+    ) -> Result<String, error::Format> {
         format.format(None, Some(self), None)
     }
 }
@@ -642,6 +984,9 @@ impl Time {
     }
 }
 
+/// Write the time as `HH:MM:SS.F`, such as `03:04:05.6`. The fractional second has as many digits
+/// as needed, with at least one. This format is stable and is described by
+/// [`format_description::display::TIME`](crate::format_description::display::TIME).
 impl fmt::Display for Time {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let (value, width) = match self.nanosecond() {
@@ -657,12 +1002,31 @@ impl fmt::Display for Time {
         };
         write!(
             f,
-            "{}:{:02}:{:02}.{value:0width$}",
+            "{:02}:{:02}:{:02}.{value:0width$}",
             self.hour, self.minute, self.second,
         )
     }
 }
 
+/// Parse a time in the format produced by its [`Display`](fmt::Display) implementation. The
+/// fractional second may be omitted.
+///
+/// ```rust
+/// # use time::Time;
+/// # use time_macros::time;
+/// assert_eq!("03:04:05.6".parse(), Ok(time!(3:04:05.6)));
+/// assert_eq!("03:04:05".parse(), Ok(time!(3:04:05)));
+/// assert!("3:04:05".parse::<Time>().is_err());
+/// ```
+#[cfg(feature = "parsing")]
+impl FromStr for Time {
+    type Err = error::Parse;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, crate::format_description::display::TIME)
+    }
+}
+
 impl fmt::Debug for Time {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         fmt::Display::fmt(self, f)
@@ -774,4 +1138,9 @@ impl Sub for Time {
         Duration::new_unchecked(seconds, nanoseconds)
     }
 }
+
+impl_forward_ref_binop!(Add add for Time: Duration, StdDuration);
+impl_forward_ref_binop!(Sub sub for Time: Duration, StdDuration, Time);
+impl_forward_ref_op_assign!(AddAssign add_assign for Time: Duration, StdDuration);
+impl_forward_ref_op_assign!(SubAssign sub_assign for Time: Duration, StdDuration);
 // endregion trait impls
@@ -2,15 +2,21 @@
 
 use core::fmt;
 use core::ops::Neg;
-#[cfg(feature = "formatting")]
+#[cfg(feature = "parsing")]
+use core::str::FromStr;
+#[cfg(all(feature = "formatting-core", feature = "alloc"))]
+use alloc::string::String;
+#[cfg(all(feature = "formatting-core", feature = "std"))]
 use std::io;
 
 use crate::convert::*;
 use crate::error;
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{FmtWriter, Formattable, Formatted};
+#[cfg(feature = "parsing")]
+use crate::parsing::parsed::rename_offset_component;
 #[cfg(feature = "parsing")]
-use crate::parsing::Parsable;
+use crate::parsing::{Parsable, ParsedItem};
 #[cfg(feature = "local-offset")]
 use crate::sys::local_offset_at;
 #[cfg(feature = "local-offset")]
@@ -18,8 +24,10 @@ use crate::OffsetDateTime;
 
 /// An offset from UTC.
 ///
-/// This struct can store values up to ±23:59:59. If you need support outside this range, please
-/// file an issue with your use case.
+/// This struct can store values from [`UtcOffset::MIN`] (-23:59:59) to [`UtcOffset::MAX`]
+/// (+23:59:59) inclusive. Every constructor, deserializer, and parser rejects values outside this
+/// range with an [`error::ComponentRange`]. If you need support outside this range, please file an
+/// issue with your use case.
 // All three components _must_ have the same sign.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct UtcOffset {
@@ -41,6 +49,24 @@ impl UtcOffset {
     /// ```
     pub const UTC: Self = Self::__from_hms_unchecked(0, 0, 0);
 
+    /// The smallest valid `UtcOffset`, -23:59:59.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::offset;
+    /// assert_eq!(UtcOffset::MIN, offset!(-23:59:59));
+    /// ```
+    pub const MIN: Self = Self::__from_hms_unchecked(-23, -59, -59);
+
+    /// The largest valid `UtcOffset`, +23:59:59.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::offset;
+    /// assert_eq!(UtcOffset::MAX, offset!(+23:59:59));
+    /// ```
+    pub const MAX: Self = Self::__from_hms_unchecked(23, 59, 59);
+
     // region: constructors
     /// Create a `UtcOffset` representing an offset of the hours, minutes, and seconds provided, the
     /// validity of which must be guaranteed by the caller. All three parameters must have the same
@@ -74,7 +100,8 @@ impl UtcOffset {
     /// provided.
     ///
     /// The sign of all three components should match. If they do not, all smaller components will
-    /// have their signs flipped.
+    /// have their signs flipped. To reject inconsistent signs instead, use
+    /// [`UtcOffset::from_hms_strict`].
     ///
     /// ```rust
     /// # use time::UtcOffset;
@@ -87,7 +114,7 @@ impl UtcOffset {
         mut minutes: i8,
         mut seconds: i8,
     ) -> Result<Self, error::ComponentRange> {
-        ensure_value_in_range!(hours in -23 => 23);
+        ensure_value_in_range!(hours in Self::MIN.hours => Self::MAX.hours);
         ensure_value_in_range!(
             minutes in -(Minute.per(Hour) as i8 - 1) => Minute.per(Hour) as i8 - 1
         );
@@ -109,17 +136,68 @@ impl UtcOffset {
         Ok(Self::__from_hms_unchecked(hours, minutes, seconds))
     }
 
+    /// Create a `UtcOffset` representing an offset by the number of hours, minutes, and seconds
+    /// provided, requiring that all non-zero components have the same sign.
+    ///
+    /// Unlike [`UtcOffset::from_hms`], components with a sign differing from that of a larger
+    /// component are rejected rather than flipped. The returned error names the first component
+    /// with an inconsistent sign.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// assert_eq!(UtcOffset::from_hms_strict(-5, -30, 0)?.as_hms(), (-5, -30, 0));
+    /// assert_eq!(UtcOffset::from_hms_strict(0, -30, 0)?.as_hms(), (0, -30, 0));
+    /// assert_eq!(
+    ///     UtcOffset::from_hms_strict(-5, 30, 0).unwrap_err().name(),
+    ///     "minutes"
+    /// );
+    /// assert_eq!(
+    ///     UtcOffset::from_hms_strict(0, 30, -15).unwrap_err().name(),
+    ///     "seconds"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn from_hms_strict(
+        hours: i8,
+        minutes: i8,
+        seconds: i8,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(hours in Self::MIN.hours => Self::MAX.hours);
+        if hours < 0 {
+            ensure_value_in_range!(minutes conditionally in -(Minute.per(Hour) as i8 - 1) => 0);
+        } else if hours > 0 {
+            ensure_value_in_range!(minutes conditionally in 0 => Minute.per(Hour) as i8 - 1);
+        } else {
+            ensure_value_in_range!(
+                minutes in -(Minute.per(Hour) as i8 - 1) => Minute.per(Hour) as i8 - 1
+            );
+        }
+        if hours < 0 || minutes < 0 {
+            ensure_value_in_range!(seconds conditionally in -(Second.per(Minute) as i8 - 1) => 0);
+        } else if hours > 0 || minutes > 0 {
+            ensure_value_in_range!(seconds conditionally in 0 => Second.per(Minute) as i8 - 1);
+        } else {
+            ensure_value_in_range!(
+                seconds in -(Second.per(Minute) as i8 - 1) => Second.per(Minute) as i8 - 1
+            );
+        }
+
+        Ok(Self::__from_hms_unchecked(hours, minutes, seconds))
+    }
+
     /// Create a `UtcOffset` representing an offset by the number of seconds provided.
     ///
+    /// The value must be in the range `-86_399..=86_399`, which is the number of seconds in
+    /// [`UtcOffset::MIN`] and [`UtcOffset::MAX`].
+    ///
     /// ```rust
     /// # use time::UtcOffset;
     /// assert_eq!(UtcOffset::from_whole_seconds(3_723)?.as_hms(), (1, 2, 3));
+    /// assert!(UtcOffset::from_whole_seconds(-86_400).is_err());
     /// # Ok::<_, time::Error>(())
     /// ```
     pub const fn from_whole_seconds(seconds: i32) -> Result<Self, error::ComponentRange> {
-        ensure_value_in_range!(
-            seconds in -24 * Second.per(Hour) as i32 - 1 => 24 * Second.per(Hour) as i32 - 1
-        );
+        ensure_value_in_range!(seconds in Self::MIN.whole_seconds() => Self::MAX.whole_seconds());
 
         Ok(Self::__from_hms_unchecked(
             (seconds / Second.per(Hour) as i32) as _,
@@ -281,9 +359,10 @@ impl UtcOffset {
 }
 
 // region: formatting & parsing
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl UtcOffset {
     /// Format the `UtcOffset` using the provided [format description](crate::format_description).
+    #[cfg(feature = "std")]
     pub fn format_into(
         self,
         output: &mut impl io::Write,
@@ -292,6 +371,43 @@ impl UtcOffset {
         format.format_into(output, None, None, Some(self))
     }
 
+    /// Format the `UtcOffset` using the provided [format description](crate::format_description),
+    /// writing to any [`fmt::Write`], such as a [`fmt::Formatter`].
+    ///
+    /// ```rust
+    /// # use time_macros::{format_description, offset};
+    /// let mut output = String::new();
+    /// offset!(+1).format_into_fmt(
+    ///     &mut output,
+    ///     format_description!("[offset_hour sign:mandatory]:[offset_minute]"),
+    /// )?;
+    /// assert_eq!(output, "+01:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut impl fmt::Write,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::Format> {
+        format.format_into(&mut FmtWriter(output), None, None, Some(self))
+    }
+
+    /// Pair the `UtcOffset` with a [format description](crate::format_description), returning a
+    /// value that is formatted directly into the output when displayed. See [`Formatted`] for
+    /// details.
+    ///
+    /// ```rust
+    /// # use time_macros::{format_description, offset};
+    /// let offset = offset!(+1);
+    /// assert_eq!(
+    ///     format!("UTC{}", offset.display(format_description!("[offset_hour sign:mandatory]"))),
+    ///     "UTC+01"
+    /// );
+    /// ```
+    pub const fn display<F: Formattable + ?Sized>(self, format: &F) -> Formatted<'_, F> {
+        Formatted::new(format, None, None, Some(self))
+    }
+
     /// Format the `UtcOffset` using the provided [format description](crate::format_description).
     ///
     /// ```rust
@@ -301,6 +417,7 @@ impl UtcOffset {
     /// assert_eq!(offset!(+1).format(&format)?, "+01:00");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "alloc")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
         format.format(None, None, Some(self))
     }
@@ -326,16 +443,91 @@ impl UtcOffset {
     }
 }
 
+/// By default, the offset is displayed with hours, minutes, and seconds, such as `+02:00:00`. This
+/// form is stable and is described by
+/// [`format_description::display::UTC_OFFSET`](crate::format_description::display::UTC_OFFSET).
+///
+/// If a precision is specified, seconds are only displayed if they are non-zero, such as `+02:00`
+/// and `+02:00:30`. The alternate flag (`#`) displays the offset without separators, such as
+/// `+0200`, again only including seconds if they are non-zero. The seconds are never omitted if
+/// they are non-zero.
+///
+/// ```rust
+/// # use time_macros::offset;
+/// assert_eq!(offset!(+2).to_string(), "+02:00:00");
+/// assert_eq!(format!("{:.0}", offset!(+2)), "+02:00");
+/// assert_eq!(format!("{:#}", offset!(+2)), "+0200");
+/// assert_eq!(format!("{:.0}", offset!(-0:01:15)), "-00:01:15");
+/// assert_eq!(format!("{:#}", offset!(-0:01:15)), "-000115");
+/// ```
 impl fmt::Display for UtcOffset {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let separator = if f.alternate() { "" } else { ":" };
         write!(
             f,
-            "{}{:02}:{:02}:{:02}",
+            "{}{:02}{separator}{:02}",
             if self.is_negative() { '-' } else { '+' },
             self.hours.abs(),
             self.minutes.abs(),
-            self.seconds.abs()
-        )
+        )?;
+        if self.seconds != 0 || (f.precision().is_none() && !f.alternate()) {
+            write!(f, "{separator}{:02}", self.seconds.abs())?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse an offset in any of the forms produced by its [`Display`](fmt::Display) implementation:
+/// `+HH:MM:SS`, `+HH:MM`, `+HHMM`, and `+HHMMSS`. The sign is mandatory.
+///
+/// ```rust
+/// # use time_macros::offset;
+/// assert_eq!("+02:00:30".parse(), Ok(offset!(+2:00:30)));
+/// assert_eq!("-02:30".parse(), Ok(offset!(-2:30)));
+/// assert_eq!("+0200".parse(), Ok(offset!(+2)));
+/// assert!("02:00".parse::<time::UtcOffset>().is_err());
+/// ```
+#[cfg(feature = "parsing")]
+impl FromStr for UtcOffset {
+    type Err = error::Parse;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits, sign};
+
+        let colon = ascii_char::<b':'>;
+
+        let ParsedItem(input, sign) = sign(s.as_bytes()).ok_or(InvalidComponent("offset hour"))?;
+        let ParsedItem(input, hours) =
+            exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset hour"))?;
+        let (input, has_separators) = match colon(input) {
+            Some(item) => (item.into_inner(), true),
+            None => (input, false),
+        };
+        let ParsedItem(input, minutes) =
+            exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset minute"))?;
+        let ParsedItem(input, seconds) = if input.is_empty() {
+            ParsedItem(input, 0)
+        } else {
+            let input = if has_separators {
+                colon(input).ok_or(InvalidLiteral)?.into_inner()
+            } else {
+                input
+            };
+            exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset second"))?
+        };
+        if !input.is_empty() {
+            return Err(error::Parse::UnexpectedTrailingCharacters);
+        }
+
+        let (hours, minutes, seconds) = if sign == b'-' {
+            (-(hours as i8), -(minutes as i8), -(seconds as i8))
+        } else {
+            (hours as _, minutes as _, seconds as _)
+        };
+        Self::from_hms(hours, minutes, seconds).map_err(|err| {
+            error::TryFromParsed::ComponentRange(rename_offset_component(err)).into()
+        })
     }
 }
 
@@ -353,3 +545,5 @@ impl Neg for UtcOffset {
         Self::__from_hms_unchecked(-self.hours, -self.minutes, -self.seconds)
     }
 }
+
+impl_forward_ref_unop!(Neg neg for UtcOffset);
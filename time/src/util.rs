@@ -2,7 +2,7 @@
 
 pub use time_core::util::{days_in_year, is_leap_year, weeks_in_year};
 
-use crate::Month;
+use crate::{error, Date, Month};
 
 /// Whether to adjust the date, and in which direction. Useful when implementing arithmetic.
 pub(crate) enum DateAdjustment {
@@ -30,6 +30,38 @@ pub const fn days_in_year_month(year: i32, month: Month) -> u8 {
     }
 }
 
+/// Get an iterator over every day in the month of a given year, in order.
+///
+/// ```rust
+/// # use time::{Month, util};
+/// # use time_macros::date;
+/// let days = util::days_of_month(2023, Month::February)?;
+/// assert_eq!(days.len(), 28);
+/// assert_eq!(days.last(), Some(date!(2023 - 02 - 28)));
+/// # Ok::<_, time::Error>(())
+/// ```
+pub fn days_of_month(
+    year: i32,
+    month: Month,
+) -> Result<impl DoubleEndedIterator<Item = Date> + ExactSizeIterator, error::ComponentRange> {
+    Ok(Date::from_calendar_date(year, month, 1)?.month_days())
+}
+
+/// Get the English ordinal suffix for the given day of the month, such as "st" for the 1st and "th"
+/// for the 11th.
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
+pub(crate) const fn ordinal_suffix(day: u8) -> &'static str {
+    match day {
+        11..=13 => "th",
+        _ => match day % 10 {
+            1 => "st",
+            2 => "nd",
+            3 => "rd",
+            _ => "th",
+        },
+    }
+}
+
 #[cfg(feature = "local-offset")]
 /// Utility functions relating to the local UTC offset.
 pub mod local_offset {
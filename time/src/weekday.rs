@@ -88,13 +88,15 @@ impl Weekday {
         }
     }
 
-    /// Get the one-indexed number of days from Monday.
+    /// Get the one-indexed number of days from Monday. This is the same value obtained via
+    /// `u8::from`.
     ///
     /// ```rust
     /// # use time::Weekday;
     /// assert_eq!(Weekday::Monday.number_from_monday(), 1);
     /// ```
     #[doc(alias = "iso_weekday_number")]
+    #[doc(alias = "to_monday_one_based")]
     pub const fn number_from_monday(self) -> u8 {
         self.number_days_from_monday() + 1
     }
@@ -105,6 +107,7 @@ impl Weekday {
     /// # use time::Weekday;
     /// assert_eq!(Weekday::Monday.number_from_sunday(), 2);
     /// ```
+    #[doc(alias = "to_sunday_one_based")]
     pub const fn number_from_sunday(self) -> u8 {
         self.number_days_from_sunday() + 1
     }
@@ -115,6 +118,7 @@ impl Weekday {
     /// # use time::Weekday;
     /// assert_eq!(Weekday::Monday.number_days_from_monday(), 0);
     /// ```
+    #[doc(alias = "to_monday_zero_based")]
     pub const fn number_days_from_monday(self) -> u8 {
         self as _
     }
@@ -125,6 +129,7 @@ impl Weekday {
     /// # use time::Weekday;
     /// assert_eq!(Weekday::Monday.number_days_from_sunday(), 1);
     /// ```
+    #[doc(alias = "to_sunday_zero_based")]
     pub const fn number_days_from_sunday(self) -> u8 {
         match self {
             Monday => 1,
@@ -136,11 +141,77 @@ impl Weekday {
             Sunday => 0,
         }
     }
-}
 
-impl Display for Weekday {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(match self {
+    /// Create a `Weekday` from its one-indexed number of days from Monday. This is the inverse of
+    /// [`Weekday::number_from_monday`], and is also available via its `TryFrom<u8>`
+    /// implementation.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_from_monday(1), Ok(Weekday::Monday));
+    /// assert_eq!(Weekday::from_number_from_monday(7), Ok(Weekday::Sunday));
+    /// assert!(Weekday::from_number_from_monday(0).is_err());
+    /// ```
+    #[doc(alias = "from_monday_one_based")]
+    pub const fn from_number_from_monday(weekday: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(weekday in 1 => 7);
+        Ok(Monday.nth_next(weekday - 1))
+    }
+
+    /// Create a `Weekday` from its one-indexed number of days from Sunday. This is the inverse of
+    /// [`Weekday::number_from_sunday`].
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_from_sunday(1), Ok(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_number_from_sunday(7), Ok(Weekday::Saturday));
+    /// assert!(Weekday::from_number_from_sunday(0).is_err());
+    /// ```
+    #[doc(alias = "from_sunday_one_based")]
+    pub const fn from_number_from_sunday(weekday: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(weekday in 1 => 7);
+        Ok(Sunday.nth_next(weekday - 1))
+    }
+
+    /// Create a `Weekday` from its zero-indexed number of days from Monday. This is the inverse of
+    /// [`Weekday::number_days_from_monday`].
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_days_from_monday(0), Ok(Weekday::Monday));
+    /// assert_eq!(Weekday::from_number_days_from_monday(6), Ok(Weekday::Sunday));
+    /// assert!(Weekday::from_number_days_from_monday(7).is_err());
+    /// ```
+    #[doc(alias = "from_monday_zero_based")]
+    pub const fn from_number_days_from_monday(weekday: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(weekday in 0 => 6);
+        Ok(Monday.nth_next(weekday))
+    }
+
+    /// Create a `Weekday` from its zero-indexed number of days from Sunday. This is the inverse of
+    /// [`Weekday::number_days_from_sunday`].
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::from_number_days_from_sunday(0), Ok(Weekday::Sunday));
+    /// assert_eq!(Weekday::from_number_days_from_sunday(6), Ok(Weekday::Saturday));
+    /// assert!(Weekday::from_number_days_from_sunday(7).is_err());
+    /// ```
+    #[doc(alias = "from_sunday_zero_based")]
+    pub const fn from_number_days_from_sunday(weekday: u8) -> Result<Self, error::ComponentRange> {
+        ensure_value_in_range!(weekday in 0 => 6);
+        Ok(Sunday.nth_next(weekday))
+    }
+
+    /// Get the full English name of the weekday. This is the same value used by the `Display`
+    /// implementation.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::Monday.name(), "Monday");
+    /// ```
+    pub const fn name(self) -> &'static str {
+        match self {
             Monday => "Monday",
             Tuesday => "Tuesday",
             Wednesday => "Wednesday",
@@ -148,7 +219,32 @@ impl Display for Weekday {
             Friday => "Friday",
             Saturday => "Saturday",
             Sunday => "Sunday",
-        })
+        }
+    }
+
+    /// Get the abbreviated English name of the weekday, which is the first three letters of its
+    /// full name. This is the value used by `[weekday repr:short]` and RFC 2822.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// assert_eq!(Weekday::Monday.short_name(), "Mon");
+    /// ```
+    pub const fn short_name(self) -> &'static str {
+        match self {
+            Monday => "Mon",
+            Tuesday => "Tue",
+            Wednesday => "Wed",
+            Thursday => "Thu",
+            Friday => "Fri",
+            Saturday => "Sat",
+            Sunday => "Sun",
+        }
+    }
+}
+
+impl Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
     }
 }
 
@@ -168,3 +264,32 @@ impl FromStr for Weekday {
         }
     }
 }
+
+/// Convert a `Weekday` to its one-indexed number of days from Monday, matching ISO 8601.
+///
+/// ```rust
+/// # use time::Weekday;
+/// assert_eq!(u8::from(Weekday::Monday), 1);
+/// assert_eq!(u8::from(Weekday::Sunday), 7);
+/// ```
+impl From<Weekday> for u8 {
+    fn from(weekday: Weekday) -> Self {
+        weekday.number_from_monday()
+    }
+}
+
+/// Create a `Weekday` from its one-indexed number of days from Monday, matching ISO 8601.
+///
+/// ```rust
+/// # use time::Weekday;
+/// assert_eq!(Weekday::try_from(1), Ok(Weekday::Monday));
+/// assert_eq!(Weekday::try_from(7), Ok(Weekday::Sunday));
+/// assert!(Weekday::try_from(0).is_err());
+/// ```
+impl TryFrom<u8> for Weekday {
+    type Error = error::ComponentRange;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Self::from_number_from_monday(value)
+    }
+}